@@ -0,0 +1,77 @@
+//! In-memory asset storage.
+//!
+//! Some platforms cannot read assets by themselves; on the web, for instance, the page pushes assets to the running
+//! example. Such platforms keep the pushed assets in an [`AssetStore`], which serves them to
+//! [`PlatformServices::fetch_bytes`] and turns replaced assets into [`InputAction::AssetChanged`].
+//!
+//! [`PlatformServices::fetch_bytes`]: crate::PlatformServices::fetch_bytes
+
+use crate::InputAction;
+use std::collections::HashMap;
+
+/// Assets pushed to the platform, by name.
+#[derive(Clone, Debug, Default)]
+pub struct AssetStore {
+  assets: HashMap<String, Vec<u8>>,
+}
+
+impl AssetStore {
+  /// Create an empty store.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Add the asset `name`, replacing it if it was already added.
+  ///
+  /// If the asset is replaced, the returned action notifies the running example about it.
+  pub fn add(&mut self, name: String, bytes: Vec<u8>) -> Option<InputAction> {
+    self
+      .assets
+      .insert(name.clone(), bytes.clone())
+      .map(|_| InputAction::AssetChanged { name, bytes })
+  }
+
+  /// Get the content of the asset `name`, if it was added.
+  pub fn get(&self, name: &str) -> Option<&[u8]> {
+    self.assets.get(name).map(Vec::as_slice)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const VS: &str = "void main() {\n  gl_Position = vec4(0., 0., 0., 1.);\n}";
+
+  #[test]
+  fn get_added_asset() {
+    let mut assets = AssetStore::new();
+
+    assert!(assets.add("vs.glsl".to_owned(), VS.into()).is_none());
+    assert_eq!(assets.get("vs.glsl"), Some(VS.as_bytes()));
+  }
+
+  #[test]
+  fn get_missing_asset() {
+    let assets = AssetStore::new();
+
+    assert_eq!(assets.get("vs.glsl"), None);
+  }
+
+  #[test]
+  fn replaced_asset_notifies_change() {
+    let mut assets = AssetStore::new();
+    assets.add("vs.glsl".to_owned(), Vec::new());
+
+    match assets.add("vs.glsl".to_owned(), VS.into()) {
+      Some(InputAction::AssetChanged { name, bytes }) => {
+        assert_eq!(name, "vs.glsl");
+        assert_eq!(bytes, VS.as_bytes());
+      }
+
+      action => panic!("expected an asset change, got {:?}", action),
+    }
+
+    assert_eq!(assets.get("vs.glsl"), Some(VS.as_bytes()));
+  }
+}
@@ -0,0 +1,120 @@
+//! Drag gesture synthesis.
+//!
+//! Platforms only report raw cursor positions and primary button presses. Examples that want to orbit or pan a camera
+//! are more interested in how much the cursor moved while the primary action is held. [`DragTracker`] turns the former
+//! into [`InputAction::Drag`] so that every platform synthesizes drags the same way.
+
+use crate::InputAction;
+
+/// Synthesize [`InputAction::Drag`] from cursor and primary actions.
+///
+/// Feed every action the platform generates to [`DragTracker::track`]; whenever the cursor moves while the primary
+/// action is held, a drag carrying the delta since the last known cursor position is returned.
+#[derive(Clone, Debug, Default)]
+pub struct DragTracker {
+  cursor: Option<[f32; 2]>,
+  dragging: bool,
+}
+
+impl DragTracker {
+  /// Create a tracker with no known cursor position and no drag in progress.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Track an action, returning a [`InputAction::Drag`] if the action moved the cursor while dragging.
+  pub fn track(&mut self, action: &InputAction) -> Option<InputAction> {
+    match *action {
+      InputAction::PrimaryPressed => {
+        self.dragging = true;
+        None
+      }
+
+      InputAction::PrimaryReleased => {
+        self.dragging = false;
+        None
+      }
+
+      InputAction::CursorMoved { x, y } => {
+        let last = self.cursor.replace([x, y]);
+
+        // without a previous position (first move, or the cursor re-entered the surface), we cannot compute a delta;
+        // emitting one would make the view jump
+        match last {
+          Some([px, py]) if self.dragging => Some(InputAction::Drag {
+            dx: x - px,
+            dy: y - py,
+          }),
+          _ => None,
+        }
+      }
+
+      _ => None,
+    }
+  }
+
+  /// Forget the last known cursor position.
+  ///
+  /// Platforms call this when the cursor leaves the rendering surface, so that the next cursor move — which might be
+  /// far away from where the cursor left — doesn’t generate a huge drag.
+  pub fn cursor_left(&mut self) {
+    self.cursor = None;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn drags(tracker: &mut DragTracker, actions: &[InputAction]) -> Vec<(f32, f32)> {
+    actions
+      .iter()
+      .filter_map(|action| tracker.track(action))
+      .map(|action| match action {
+        InputAction::Drag { dx, dy } => (dx, dy),
+        _ => panic!("expected a drag, got {:?}", action),
+      })
+      .collect()
+  }
+
+  #[test]
+  fn press_move_move_drags_twice() {
+    let mut tracker = DragTracker::new();
+    let actions = [
+      InputAction::CursorMoved { x: 10., y: 10. },
+      InputAction::PrimaryPressed,
+      InputAction::CursorMoved { x: 12., y: 15. },
+      InputAction::CursorMoved { x: 20., y: 13. },
+    ];
+
+    assert_eq!(drags(&mut tracker, &actions), vec![(2., 5.), (8., -2.)]);
+  }
+
+  #[test]
+  fn no_drag_when_released() {
+    let mut tracker = DragTracker::new();
+    let actions = [
+      InputAction::CursorMoved { x: 10., y: 10. },
+      InputAction::PrimaryPressed,
+      InputAction::PrimaryReleased,
+      InputAction::CursorMoved { x: 12., y: 15. },
+    ];
+
+    assert!(drags(&mut tracker, &actions).is_empty());
+  }
+
+  #[test]
+  fn drag_starting_off_surface_does_not_jump() {
+    let mut tracker = DragTracker::new();
+    tracker.track(&InputAction::CursorMoved { x: 10., y: 10. });
+    tracker.cursor_left();
+
+    let actions = [
+      InputAction::PrimaryPressed,
+      InputAction::CursorMoved { x: 300., y: 200. },
+      InputAction::CursorMoved { x: 301., y: 198. },
+    ];
+
+    assert_eq!(drags(&mut tracker, &actions), vec![(1., -2.)]);
+  }
+}
@@ -123,14 +123,14 @@ impl Example for LocalExample {
         |_, mut shd_gate| {
           shd_gate.shade(program, |mut iface, _, mut rdr_gate| {
             let mut query = iface.query().unwrap();
-            let time_u = query.ask::<f32>("t");
-            let triangle_pos_u = query.ask::<Vec2<f32>>("triangle_pos");
+            let time_u = query.ask_opt::<f32>("t");
+            let triangle_pos_u = query.ask_opt::<Vec2<f32>>("triangle_pos");
 
-            if let Ok(ref time_u) = time_u {
+            if let Some(ref time_u) = time_u {
               iface.set(time_u, t);
             }
 
-            if let Ok(ref triangle_pos_u) = triangle_pos_u {
+            if let Some(ref triangle_pos_u) = triangle_pos_u {
               iface.set(triangle_pos_u, triangle_pos);
             }
 
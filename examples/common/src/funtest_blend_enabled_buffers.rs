@@ -0,0 +1,107 @@
+//! Render to two color attachments with a single additive blending configuration, enabled for the first attachment
+//! only. The first attachment must then hold the sum of the clear color and the fragment color, while the second one
+//! must hold the fragment color only.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+layout (location = 0) out vec4 frag_blended;
+layout (location = 1) out vec4 frag_opaque;
+
+void main() {
+  frag_blended = vec4(.2, .2, .2, 1.);
+  frag_opaque = vec4(.2, .2, .2, 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, (NormRGBA8UI, NormRGBA8UI), ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let render_st = RenderState::default()
+      .set_blending(Blending {
+        equation: Equation::Additive,
+        src: Factor::One,
+        dst: Factor::One,
+      })
+      .set_blend_enabled_buffers(0b01);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0.4, 0.4, 0.4, 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let (blended, opaque) = self.framebuffer.color_slot();
+    let blended = blended.get_raw_texels().expect("blended texels");
+    let opaque = opaque.get_raw_texels().expect("opaque texels");
+
+    // 0.4 + 0.2 on the blended attachment, 0.2 on the other one
+    assert_eq!(blended, [153, 153, 153, 255]);
+    assert_eq!(opaque, [51, 51, 51, 255]);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,108 @@
+//! Additively blend two quads with colors above `1` into a floating-point framebuffer and check that the read back
+//! color is their unclamped sum, as HDR compositing needs.
+//!
+//! WebGL2 needs `EXT_float_blend` to blend into 32-bit floating-point attachments, so this test is only registered
+//! on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::RGBA32F,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.5, 2., 0.75, 1.25);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let render_state = RenderState::default().set_blending(Blending {
+      equation: Equation::Additive,
+      src: Factor::One,
+      dst: Factor::One,
+    });
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&render_state, |mut tess_gate| {
+              tess_gate.render(quad)?;
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("HDR blending render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    assert_eq!(texels, [3., 4., 1.5, 2.5]);
+
+    LoopFeedback::Exit
+  }
+}
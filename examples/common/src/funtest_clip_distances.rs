@@ -0,0 +1,145 @@
+//! Render a quad clipped by a plane defined via `gl_ClipDistance[0]` and check that only the correct half ends up in
+//! the framebuffer, then disable the clip distance and check the whole quad is rendered again.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// keep the right half of the screen (x ≥ 0) only
+const VS: &str = "
+in vec2 co;
+in vec3 color;
+
+out vec3 v_color;
+
+void main() {
+  gl_Position = vec4(co, 0., 1.);
+  gl_ClipDistance[0] = co.x;
+  v_color = color;
+}";
+
+const FS: &str = include_str!("simple-fs.glsl");
+
+const QUAD: [Vertex; 4] = [
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([1., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([1., 1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  quad: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_vertices(&QUAD[..])
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let clipped = self.render_columns(context, 0b1);
+    log::info!("clipped columns: {:?}", clipped);
+    assert_eq!(clipped, [false, false, true, true]);
+
+    let unclipped = self.render_columns(context, 0);
+    log::info!("unclipped columns: {:?}", unclipped);
+    assert_eq!(unclipped, [true; 4]);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the quad with the given clip planes and return, for each column of the framebuffer, whether it was
+  /// entirely covered.
+  fn render_columns(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    clip_planes: u32,
+  ) -> [bool; 4] {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let render_st = RenderState::default().set_clip_planes(clip_planes);
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    let mut columns = [true; 4];
+    for (i, texel) in texels.chunks(4).enumerate() {
+      columns[i % 4] &= texel[0] == 255;
+    }
+
+    columns
+  }
+}
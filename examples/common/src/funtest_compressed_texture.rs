@@ -0,0 +1,174 @@
+//! Upload a known BC1 block into a compressed texture, sample it in a shader and check the expected color comes out.
+//!
+//! BC1 support depends on the running context; the test is skipped if the format is not supported.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned, BC1},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture, TextureError},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+in vec2 v_uv;
+
+out vec4 frag;
+
+uniform sampler2D source_texture;
+
+void main() {
+  frag = texture(source_texture, v_uv);
+}";
+
+// a single 4×4 block: color0 is pure red (RGB565 0xf800), color1 is black and every texel uses color0
+const RED_BLOCK: [u8; 8] = [0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  source_texture: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  texture: Option<Texture<Dim2, BC1>>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let texture = match context.new_texture::<Dim2, BC1>(
+      [4, 4],
+      sampler,
+      TexelUpload::base_level_without_mipmaps(&[]),
+    ) {
+      Ok(mut texture) => {
+        // compressed texels must cover the whole level
+        assert_eq!(
+          texture.upload_compressed(0, &RED_BLOCK[..4]),
+          Err(TextureError::compressed_size_mismatch(8, 4))
+        );
+
+        // regular uploads are not possible
+        assert!(matches!(
+          texture.upload(TexelUpload::base_level_without_mipmaps(&[0; 16])),
+          Err(TextureError::CannotUploadTexels(_))
+        ));
+
+        texture
+          .upload_compressed(0, &RED_BLOCK)
+          .expect("compressed upload");
+
+        Some(texture)
+      }
+
+      Err(TextureError::UnsupportedPixelFormat(pf)) => {
+        log::warn!("{:?} not supported by the running context; skipping", pf);
+        None
+      }
+
+      Err(e) => panic!("compressed texture creation: {}", e),
+    };
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    // only compressed pixel formats accept compressed texels
+    let mut uncompressed = context
+      .new_texture::<Dim2, NormRGBA8UI>(
+        [4, 4],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&[]),
+      )
+      .expect("uncompressed texture");
+    assert!(matches!(
+      uncompressed.upload_compressed(0, &RED_BLOCK),
+      Err(TextureError::CannotUploadTexels(_))
+    ));
+
+    LocalExample {
+      program,
+      quad,
+      texture,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let texture = match self.texture.as_mut() {
+      Some(texture) => texture,
+      None => return LoopFeedback::Exit,
+    };
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |pipeline, mut shd_gate| {
+        let bound_texture = pipeline.bind_texture(texture)?;
+
+        shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.source_texture, bound_texture.binding());
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    log::info!("sampled texels: {:?}", texels);
+
+    for texel in texels.chunks(4) {
+      assert_eq!(texel, [255, 0, 0, 255]);
+    }
+
+    LoopFeedback::Exit
+  }
+}
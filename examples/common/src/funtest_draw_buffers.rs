@@ -0,0 +1,164 @@
+//! Render to two color attachments, then render again writing only to the second one with draw buffers selection. The
+//! first attachment must keep the contents of the first pass, while the second one must hold the contents of the
+//! second pass.
+//!
+//! Selecting an attachment the framebuffer doesn’t have must be reported as a backend error.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::error_check::BackendErrorKind;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS_RED: &str = "
+layout (location = 0) out vec4 frag0;
+layout (location = 1) out vec4 frag1;
+
+void main() {
+  frag0 = vec4(1., 0., 0., 1.);
+  frag1 = vec4(1., 0., 0., 1.);
+}";
+
+const FS_GREEN: &str = "
+layout (location = 0) out vec4 frag0;
+layout (location = 1) out vec4 frag1;
+
+void main() {
+  frag0 = vec4(0., 1., 0., 1.);
+  frag1 = vec4(0., 1., 0., 1.);
+}";
+
+pub struct LocalExample {
+  red: Program<(), (), ()>,
+  green: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, (NormRGBA8UI, NormRGBA8UI), ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let red = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS_RED)
+      .expect("red program creation")
+      .ignore_warnings();
+
+    let green = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS_GREEN)
+      .expect("green program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      red,
+      green,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let red = &mut self.red;
+    let green = &mut self.green;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(red, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("first pass");
+
+    // second pass, without clearing and writing to the second attachment only
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color(None),
+        |_, mut shd_gate| {
+          shd_gate.shade(green, |_, _, mut rdr_gate| {
+            let rdr_st = RenderState::default().set_draw_buffers(&[None, Some(1)]);
+            rdr_gate.render(&rdr_st, |mut tess_gate| tess_gate.render(quad))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("second pass");
+
+    let (first, second) = self.framebuffer.color_slot();
+    let first = first.get_raw_texels().expect("first attachment texels");
+    let second = second.get_raw_texels().expect("second attachment texels");
+
+    assert_eq!(first, [255, 0, 0, 255]);
+    assert_eq!(second, [0, 255, 0, 255]);
+
+    // the framebuffer has no third attachment
+    context.set_error_checking(true);
+    let _ = context.take_backend_errors();
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(green, |_, _, mut rdr_gate| {
+            let rdr_st = RenderState::default().set_draw_buffers(&[Some(2)]);
+            rdr_gate.render(&rdr_st, |mut tess_gate| tess_gate.render(quad))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("out of range pass");
+
+    let errors = context.take_backend_errors();
+    log::info!("reported backend errors: {:?}", errors);
+    assert!(errors
+      .iter()
+      .any(|e| e.kind == BackendErrorKind::InvalidValue));
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,220 @@
+//! Build a dynamic framebuffer with two color formats picked at runtime, render to both attachments at once and read
+//! them back. The second attachment is then sampled in another render, and accessing an attachment with the wrong
+//! pixel type, as well as creating a framebuffer with a depth format as color attachment, must fail.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::{Attachment, DynamicFramebuffer, Framebuffer, FramebufferError},
+  pipeline::{PipelineError, PipelineState, TextureBinding},
+  pixel::{Depth32F, Floating, NormRGBA8UI, Pixel as _, PixelFormat, R32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler, TextureError},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const MRT_FS: &str = "
+layout (location = 0) out vec4 frag_color;
+layout (location = 1) out float frag_value;
+
+void main() {
+  frag_color = vec4(1., .2, 0., 1.);
+  frag_value = .25;
+}";
+
+const SAMPLE_FS: &str = "
+out vec4 frag;
+
+uniform sampler2D value;
+
+void main() {
+  frag = vec4(texelFetch(value, ivec2(0, 0), 0).r, 0., 0., 1.);
+}";
+
+// formats of the attachments, as an editor would let its users pick them
+const ATTACHMENT_FORMATS: [&str; 2] = ["rgba8", "r32f"];
+
+#[derive(Debug, UniformInterface)]
+struct SampleInterface {
+  value: Uniform<TextureBinding<Dim2, Floating>>,
+}
+
+pub struct LocalExample {
+  mrt_program: Program<(), (), ()>,
+  sample_program: Program<(), (), SampleInterface>,
+  quad: Tess<()>,
+  framebuffer: DynamicFramebuffer<Dim2>,
+  output: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+fn format_from_name(name: &str) -> PixelFormat {
+  match name {
+    "rgba8" => NormRGBA8UI::pixel_format(),
+    "r32f" => R32F::pixel_format(),
+    _ => panic!("unknown attachment format: {}", name),
+  }
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let mrt_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, MRT_FS)
+      .expect("MRT program")
+      .ignore_warnings();
+
+    let sample_program = context
+      .new_shader_program::<(), (), SampleInterface>()
+      .from_strings(VS, None, None, SAMPLE_FS)
+      .expect("sample program")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let color_formats = ATTACHMENT_FORMATS
+      .iter()
+      .map(|name| format_from_name(name))
+      .collect();
+    let framebuffer = context
+      .new_dynamic_framebuffer::<Dim2>([1, 1], 0, Sampler::default(), color_formats, None)
+      .expect("dynamic framebuffer");
+    assert_eq!(framebuffer.color_slot_count(), 2);
+
+    // a depth format cannot be used as color attachment
+    let incompatible = context.new_dynamic_framebuffer::<Dim2>(
+      [1, 1],
+      0,
+      Sampler::default(),
+      vec![NormRGBA8UI::pixel_format(), Depth32F::pixel_format()],
+      None,
+    );
+    assert_eq!(
+      incompatible.err(),
+      Some(FramebufferError::incompatible_attachment_format(
+        Attachment::Color(1),
+        Depth32F::pixel_format()
+      ))
+    );
+
+    let output = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("output framebuffer");
+
+    LocalExample {
+      mrt_program,
+      sample_program,
+      quad,
+      framebuffer,
+      output,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let mrt_program = &mut self.mrt_program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .dynamic_pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(mrt_program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("MRT render");
+
+    let colors = self.framebuffer.color_slot(0).expect("color slot");
+    assert_eq!(
+      colors.get_raw_texels::<R32F>().err(),
+      Some(TextureError::pixel_format_mismatch(
+        NormRGBA8UI::pixel_format(),
+        R32F::pixel_format()
+      ))
+    );
+    let colors = colors
+      .get_raw_texels::<NormRGBA8UI>()
+      .expect("color texels");
+    assert_eq!(colors, [255, 51, 0, 255]);
+
+    let values = self
+      .framebuffer
+      .color_slot(1)
+      .expect("value slot")
+      .get_raw_texels::<R32F>()
+      .expect("value texels");
+    assert_eq!(values, [0.25]);
+
+    // sample the second attachment
+    let sample_program = &mut self.sample_program;
+    let (colors, values) = self.framebuffer.color_slots().split_at_mut(1);
+    let mut mismatch = None;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.output,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          mismatch = pipeline
+            .bind_dynamic_texture::<Dim2, R32F>(&mut colors[0])
+            .err();
+          let value = pipeline.bind_dynamic_texture::<Dim2, R32F>(&mut values[0])?;
+
+          shd_gate.shade(sample_program, |mut iface, uni, mut rdr_gate| {
+            iface.set_texture(&uni.value, &value);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("sample render");
+
+    assert_eq!(
+      mismatch,
+      Some(PipelineError::texture_format_mismatch(
+        NormRGBA8UI::pixel_format(),
+        R32F::pixel_format()
+      ))
+    );
+
+    let texels = self
+      .output
+      .color_slot()
+      .get_raw_texels()
+      .expect("output texels");
+    // 0.25 * 255
+    assert_eq!(texels, [64, 0, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,166 @@
+//! Stream differently-sized geometry into a single dynamic tessellation over several frames and check that no backend
+//! error is reported and that the last frame only shows the last uploaded geometry.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{DynamicTess, Mode},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+const SIZE: u32 = 8;
+
+/// Geometry to stream for each frame: number of strips and whether they cover the whole viewport or only its left half.
+///
+/// The last frame only covers the left half right after a frame covering everything with many more vertices, so that
+/// any stale data left in the storage would show up in the right half.
+const FRAMES: [(usize, bool); 5] = [(1, true), (16, false), (64, true), (3, false), (2, false)];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  tess: DynamicTess<Vertex, u16>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+  frame: usize,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    context.set_error_checking(true);
+
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let tess = context
+      .new_dynamic_tess(Mode::Triangle)
+      .expect("dynamic tess");
+    assert_eq!(tess.vert_nb(), 0);
+    assert_eq!(tess.idx_nb(), 0);
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      tess,
+      framebuffer,
+      frame: 0,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let (strips, full_width) = FRAMES[self.frame];
+    let (vertices, indices) = strips_geometry(strips, full_width);
+
+    self
+      .tess
+      .set_data(&vertices, &indices)
+      .expect("dynamic tess upload");
+    assert_eq!(self.tess.vert_nb(), vertices.len());
+    assert_eq!(self.tess.idx_nb(), indices.len());
+
+    let program = &mut self.program;
+    let tess = &self.tess;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(tess)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let errors = context.take_backend_errors();
+    assert!(
+      errors.is_empty(),
+      "frame {}: unexpected errors: {:?}",
+      self.frame,
+      errors
+    );
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for y in 0..SIZE {
+      for x in 0..SIZE {
+        let covered = full_width || x < SIZE / 2;
+        let red = texels[((y * SIZE + x) * 4) as usize];
+
+        assert_eq!(
+          red != 0,
+          covered,
+          "frame {}: unexpected texel at ({}, {})",
+          self.frame,
+          x,
+          y
+        );
+      }
+    }
+
+    self.frame += 1;
+
+    if self.frame == FRAMES.len() {
+      LoopFeedback::Exit
+    } else {
+      LoopFeedback::Continue(self)
+    }
+  }
+}
+
+/// Cut the viewport (or its left half) into `strips` horizontal white quads.
+fn strips_geometry(strips: usize, full_width: bool) -> (Vec<Vertex>, Vec<u16>) {
+  let right = if full_width { 1. } else { 0. };
+  let height = 2. / strips as f32;
+  let mut vertices = Vec::with_capacity(strips * 4);
+  let mut indices = Vec::with_capacity(strips * 6);
+
+  for i in 0..strips {
+    let bottom = -1. + i as f32 * height;
+    let top = bottom + height;
+    let first = vertices.len() as u16;
+
+    for &pos in &[[-1., bottom], [right, bottom], [right, top], [-1., top]] {
+      vertices.push(Vertex {
+        pos: VertexPosition::new(pos),
+        rgb: VertexColor::new([1., 1., 1.]),
+      });
+    }
+
+    indices.extend_from_slice(&[first, first + 1, first + 2, first, first + 2, first + 3]);
+  }
+
+  (vertices, indices)
+}
@@ -0,0 +1,81 @@
+//! Build a program forcing early fragment tests and check it’s reported as such, then build one that also uses
+//! `discard` and check that a warning is emitted.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  shader::{ProgramError, ProgramWarning},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+void main() {
+  gl_Position = vec4(0., 0., 0., 1.);
+}";
+
+const FS: &str = "
+#extension GL_ARB_shader_image_load_store : require
+
+layout(early_fragment_tests) in;
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+const FS_DISCARD: &str = "
+#extension GL_ARB_shader_image_load_store : require
+
+layout(early_fragment_tests) in;
+
+out vec4 frag;
+
+void main() {
+  if (gl_FragCoord.x < 10.) {
+    discard;
+  }
+
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let built = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("early fragment tests program");
+    assert!(built.program.uses_early_fragment_tests());
+    assert!(built.warnings.is_empty(), "{:?}", built.warnings);
+
+    let built = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS_DISCARD)
+      .expect("early fragment tests program with discard");
+    assert!(built.program.uses_early_fragment_tests());
+    log::info!("warnings: {:?}", built.warnings);
+    assert!(built.warnings.iter().any(|w| matches!(
+      w,
+      ProgramError::Warning(ProgramWarning::EarlyFragmentTestsConflict(construct)) if construct == "discard"
+    )));
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,129 @@
+//! Render a quad made of a front-facing triangle pair on its left half and a back-facing pair on its right half into
+//! a two-texel framebuffer, with each face culling mode. `Back` must only show the left texel, `Front` only the right
+//! one and `FrontAndBack` must cull the whole quad, leaving the framebuffer blank.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  face_culling::FaceCullingMode,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// left half wound counter-clockwise, right half wound clockwise
+const VS: &str = "
+const vec2[12] POSITIONS = vec2[](
+  vec2(-1., -1.), vec2( 0., -1.), vec2( 0.,  1.),
+  vec2(-1., -1.), vec2( 0.,  1.), vec2(-1.,  1.),
+  vec2( 0., -1.), vec2( 0.,  1.), vec2( 1.,  1.),
+  vec2( 0., -1.), vec2( 1.,  1.), vec2( 1., -1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 1., 1., 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(12)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([2, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let expected = [
+      (FaceCullingMode::Back, [true, false]),
+      (FaceCullingMode::Front, [false, true]),
+      (FaceCullingMode::FrontAndBack, [false, false]),
+    ];
+
+    for &(mode, visible) in &expected {
+      let rendered = self.render(context, mode);
+      log::info!("{:?}: {:?}", mode, rendered);
+
+      assert_eq!(rendered, visible, "face culling mode {:?}", mode);
+    }
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the quad with the given face culling mode and check which texels were covered.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    mode: FaceCullingMode,
+  ) -> [bool; 2] {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let render_st = RenderState::default().set_face_culling(mode);
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 0.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    [texels[0] != 0, texels[4] != 0]
+  }
+}
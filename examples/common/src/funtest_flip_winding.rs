@@ -0,0 +1,199 @@
+//! Flip the winding order of an indexed triangle, both at build time and once built, and check that back-face culling
+//! discards it accordingly.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  face_culling::{FaceCulling, FaceCullingMode, FaceCullingOrder},
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess, TessError, TessMapError},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+// counter-clockwise triangle covering the whole viewport
+const VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([3., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 3.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+];
+
+const INDICES: [u8; 3] = [0, 1, 2];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  triangle: Tess<Vertex, u8>,
+  flipped_triangle: Tess<Vertex, u8>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_indices(&INDICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let flipped_triangle = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_indices(&INDICES[..])
+      .set_mode(Mode::Triangle)
+      .flip_winding(true)
+      .build()
+      .expect("flipped triangle");
+
+    // flipping is only allowed on indexed triangles
+    let mut points = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_indices(&INDICES[..])
+      .set_mode(Mode::Point)
+      .build()
+      .expect("points");
+    assert_eq!(
+      points.flip_winding(),
+      Err(TessMapError::ForbiddenWindingFlip(Mode::Point))
+    );
+
+    let mut direct_triangle = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("direct triangle");
+    assert_eq!(
+      direct_triangle.flip_winding(),
+      Err(TessMapError::MissingIndices)
+    );
+
+    let line_flip = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_indices(&INDICES[..])
+      .set_mode(Mode::LineStrip)
+      .flip_winding(true)
+      .build();
+    assert_eq!(
+      line_flip.err(),
+      Some(TessError::ForbiddenWindingFlip(Mode::LineStrip))
+    );
+
+    let direct_flip = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .flip_winding(true)
+      .build();
+    assert_eq!(direct_flip.err(), Some(TessError::MissingIndices));
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangle,
+      flipped_triangle,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    assert!(
+      self.is_visible(context, false),
+      "counter-clockwise triangle should be visible"
+    );
+    assert!(
+      !self.is_visible(context, true),
+      "triangle flipped at build time should be culled"
+    );
+
+    self.triangle.flip_winding().expect("flip winding");
+    log::info!("flipped indices: {:?}", &*self.triangle.indices().unwrap());
+
+    assert!(
+      !self.is_visible(context, false),
+      "flipped triangle should be culled"
+    );
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render one of the triangles with back-face culling and check whether it ended up in the framebuffer.
+  fn is_visible(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    flipped: bool,
+  ) -> bool {
+    let program = &mut self.program;
+    let triangle = if flipped {
+      &self.flipped_triangle
+    } else {
+      &self.triangle
+    };
+    let render_st = RenderState::default().set_face_culling(FaceCulling::new(
+      FaceCullingOrder::CCW,
+      FaceCullingMode::Back,
+    ));
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(triangle))
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    texels[0] != 0
+  }
+}
@@ -0,0 +1,223 @@
+//! Render a distinct solid color into each of the four Z-slices of a 3D texture by attaching them in turn to a
+//! framebuffer, then sample each slice back in a shader to check the colors ended up in the right slices. The slices
+//! are rendered with explicit draw buffers, which must accept the attached slice as color attachment 0.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::{Framebuffer, FramebufferError},
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{types::Vec4, Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Dim3, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FILL_FS: &str = "
+out vec4 frag;
+
+uniform vec4 color;
+
+void main() {
+  frag = color;
+}";
+
+const SAMPLE_FS: &str = "
+precision highp sampler3D;
+
+in vec2 v_uv;
+
+out vec4 frag;
+
+uniform sampler3D volume;
+uniform float z;
+
+void main() {
+  frag = texture(volume, vec3(v_uv, z));
+}";
+
+const COLORS: [[u8; 4]; 4] = [
+  [255, 0, 0, 255],
+  [0, 255, 0, 255],
+  [0, 0, 255, 255],
+  [255, 255, 0, 255],
+];
+
+#[derive(UniformInterface)]
+struct FillInterface {
+  color: Uniform<Vec4<f32>>,
+}
+
+#[derive(UniformInterface)]
+struct SampleInterface {
+  volume: Uniform<TextureBinding<Dim3, NormUnsigned>>,
+  z: Uniform<f32>,
+}
+
+pub struct LocalExample {
+  fill_program: Program<(), (), FillInterface>,
+  sample_program: Program<(), (), SampleInterface>,
+  quad: Tess<()>,
+  volume: Texture<Dim3, NormRGBA8UI>,
+  slice_framebuffer: Framebuffer<Dim2, (), ()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let fill_program = context
+      .new_shader_program::<(), (), FillInterface>()
+      .from_strings(VS, None, None, FILL_FS)
+      .expect("fill program creation")
+      .ignore_warnings();
+
+    let sample_program = context
+      .new_shader_program::<(), (), SampleInterface>()
+      .from_strings(VS, None, None, SAMPLE_FS)
+      .expect("sample program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let volume = context
+      .new_texture::<Dim3, NormRGBA8UI>(
+        [2, 2, COLORS.len() as u32],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&[]),
+      )
+      .expect("3D texture");
+
+    let slice_framebuffer = context
+      .new_framebuffer::<Dim2, (), ()>([2, 2], 0, Sampler::default())
+      .expect("slice framebuffer");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([2, 2], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      fill_program,
+      sample_program,
+      quad,
+      volume,
+      slice_framebuffer,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context.set_error_checking(true);
+
+    let slices = COLORS.len() as u32;
+
+    assert_eq!(
+      self
+        .slice_framebuffer
+        .attach_3d_slice(&self.volume, slices)
+        .err(),
+      Some(FramebufferError::layer_out_of_bounds(slices, slices))
+    );
+
+    // render a solid color in each slice
+    for (z, color) in COLORS.iter().enumerate() {
+      let slice = self
+        .slice_framebuffer
+        .attach_3d_slice(&self.volume, z as u32)
+        .expect("slice attachment");
+
+      let color = Vec4::new(
+        color[0] as f32 / 255.,
+        color[1] as f32 / 255.,
+        color[2] as f32 / 255.,
+        color[3] as f32 / 255.,
+      );
+      let program = &mut self.fill_program;
+      let quad = &self.quad;
+
+      context
+        .new_pipeline_gate()
+        .pipeline(&slice, &PipelineState::default(), |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.color, color);
+
+            let render_st = RenderState::default().set_draw_buffers(&[Some(0)]);
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+          })
+        })
+        .assume()
+        .into_result()
+        .expect("slice render");
+    }
+
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected backend errors: {:?}", errors);
+
+    // sample each slice back
+    for (z, color) in COLORS.iter().enumerate() {
+      let program = &mut self.sample_program;
+      let quad = &self.quad;
+      let volume = &mut self.volume;
+      let depth = (z as f32 + 0.5) / slices as f32;
+
+      context
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default(),
+          |pipeline, mut shd_gate| {
+            let bound_volume = pipeline.bind_texture(volume)?;
+
+            shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+              iface.set(&uni.volume, bound_volume.binding());
+              iface.set(&uni.z, depth);
+
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(quad)
+              })
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("sample render");
+
+      let texels = self
+        .framebuffer
+        .color_slot()
+        .get_raw_texels()
+        .expect("texels");
+      log::info!("slice {}: {:?}", z, texels);
+
+      for texel in texels.chunks(4) {
+        assert_eq!(texel, color);
+      }
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,59 @@
+//! Check that the back buffer passed to the example is reported as such while offscreen framebuffers are not, and that
+//! attaching a texture to the back buffer fails with a dedicated error while it works with an offscreen framebuffer.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::{Framebuffer, FramebufferError},
+  pixel::NormRGBA8UI,
+  texture::{Dim2, Dim3, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+pub struct LocalExample {
+  volume: Texture<Dim3, NormRGBA8UI>,
+  offscreen: Framebuffer<Dim2, (), ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let volume = context
+      .new_texture::<Dim3, NormRGBA8UI>(
+        [2, 2, 2],
+        Sampler::default(),
+        TexelUpload::base_level_without_mipmaps(&[]),
+      )
+      .expect("3D texture");
+
+    let offscreen = context
+      .new_framebuffer::<Dim2, (), ()>([2, 2], 0, Sampler::default())
+      .expect("offscreen framebuffer");
+
+    LocalExample { volume, offscreen }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    mut back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    assert!(back_buffer.is_back_buffer());
+    assert!(!self.offscreen.is_back_buffer());
+
+    assert_eq!(
+      back_buffer.attach_3d_slice(&self.volume, 0).err(),
+      Some(FramebufferError::unsupported_on_back_buffer())
+    );
+    self
+      .offscreen
+      .attach_3d_slice(&self.volume, 1)
+      .expect("offscreen attachment");
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,119 @@
+//! Render a red quad over the upper half of a blue framebuffer, capture it to a PNG file with
+//! `shared::capture_framebuffer` and load the file back: it must have the size of the framebuffer, and its first row —
+//! the upper one, as images start at the upper-left corner — must be red.
+//!
+//! Captures are written with the filesystem, so this test is only registered on desktop.
+
+use crate::{shared::capture_framebuffer, Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] CO = vec2[](vec2(-1., 0.), vec2(1., 0.), vec2(1., 1.), vec2(-1., 1.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+// not square, so that swapped dimensions are noticed
+const SIZE: [u32; 2] = [8, 4];
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>(SIZE, 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 1., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("capture render");
+
+    let path = std::env::temp_dir().join("luminance-funtest-framebuffer-capture.png");
+    capture_framebuffer(&self.framebuffer, &path).expect("framebuffer capture");
+
+    let image = image::open(&path).expect("capture loading").to_rgba8();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(image.dimensions(), (SIZE[0], SIZE[1]));
+
+    for (x, y, pixel) in image.enumerate_pixels() {
+      let expected = if y < SIZE[1] / 2 {
+        [255, 0, 0, 255]
+      } else {
+        [0, 0, 255, 255]
+      };
+
+      assert_eq!(pixel.0, expected, "pixel ({}, {})", x, y);
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,72 @@
+//! Clear a G-buffer-like framebuffer with a distinct color per attachment: the albedo to black and the normals to
+//! `(0.5, 0.5, 1.)`, while the velocity attachment, without a color of its own, falls back to the clear color. Each
+//! attachment is read back and must hold its own clear value.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{NormRGBA8UI, RG32F, RGBA32F},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const ALBEDO_CLEAR: [f32; 4] = [0., 0., 0., 1.];
+const NORMAL_CLEAR: [f32; 4] = [0.5, 0.5, 1., 1.];
+const FALLBACK_CLEAR: [f32; 4] = [0.25, -0.25, 0., 0.];
+
+pub struct LocalExample {
+  gbuffer: Framebuffer<Dim2, (NormRGBA8UI, RGBA32F, RG32F), ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let gbuffer = context
+      .new_framebuffer::<Dim2, (NormRGBA8UI, RGBA32F, RG32F), ()>([2, 2], 0, Sampler::default())
+      .expect("G-buffer");
+
+    LocalExample { gbuffer }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let pipeline_st = PipelineState::default()
+      .set_clear_color(FALLBACK_CLEAR)
+      .set_clear_colors(vec![ALBEDO_CLEAR, NORMAL_CLEAR]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.gbuffer, &pipeline_st, |_, _| Ok(()))
+      .assume()
+      .into_result()
+      .expect("G-buffer clear");
+
+    let (albedo, normal, velocity) = self.gbuffer.color_slot();
+
+    let albedo = albedo.get_raw_texels().expect("albedo texels");
+    for texel in albedo.chunks(4) {
+      assert_eq!(texel, [0, 0, 0, 255], "albedo");
+    }
+
+    let normal = normal.get_raw_texels().expect("normal texels");
+    for texel in normal.chunks(4) {
+      assert_eq!(texel, NORMAL_CLEAR, "normal");
+    }
+
+    let velocity = velocity.get_raw_texels().expect("velocity texels");
+    for texel in velocity.chunks(2) {
+      assert_eq!(texel, &FALLBACK_CLEAR[..2], "velocity");
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,83 @@
+//! Clear the whole framebuffer to green, then clear only its left half to red and check that the right half retained
+//! its previous content.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  scissor::ScissorRegion,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const WIDTH: u32 = 8;
+const HEIGHT: u32 = 4;
+
+pub struct LocalExample {
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([WIDTH, HEIGHT], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample { framebuffer }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 1., 0., 1.]),
+        |_, _| Ok(()),
+      )
+      .assume()
+      .into_result()
+      .expect("green clear");
+
+    // the height goes past the framebuffer and must be clamped
+    let left_half = ScissorRegion {
+      x: 0,
+      y: 0,
+      width: WIDTH / 2,
+      height: HEIGHT * 10,
+    };
+    self
+      .framebuffer
+      .clear_region(context, left_half, [1., 0., 0., 1.], None)
+      .expect("red partial clear");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let x = i as u32 % WIDTH;
+      let expected: [u8; 4] = if x < WIDTH / 2 {
+        [255, 0, 0, 255]
+      } else {
+        [0, 255, 0, 255]
+      };
+
+      assert_eq!(texel, expected, "texel {} ({}, {})", i, x, i as u32 / WIDTH);
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,54 @@
+//! Check the number of color attachments reported by framebuffers with different color slots, and access the
+//! attachments of a MRT framebuffer by index.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pixel::{NormR8UI, NormRGB8UI, NormRGBA8UI},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let mut mrt = context
+      .new_framebuffer::<Dim2, (NormRGB8UI, NormR8UI, NormRGBA8UI), ()>(
+        [16, 16],
+        0,
+        Sampler::default(),
+      )
+      .expect("MRT framebuffer");
+    assert_eq!(mrt.color_slot_count(), 3);
+    assert_eq!(mrt.color_slot_n::<0>().size(), [16, 16]);
+    assert_eq!(mrt.color_slot_n::<2>().size(), [16, 16]);
+
+    let single = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([16, 16], 0, Sampler::default())
+      .expect("single color framebuffer");
+    assert_eq!(single.color_slot_count(), 1);
+
+    let no_color = context
+      .new_framebuffer::<Dim2, (), ()>([16, 16], 0, Sampler::default())
+      .expect("colorless framebuffer");
+    assert_eq!(no_color.color_slot_count(), 0);
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    assert_eq!(back_buffer.color_slot_count(), 0);
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,107 @@
+//! Check that `default_framebuffer_is_srgb` tells whether the back buffer stores sRGB-encoded colors, by rendering a
+//! linear mid-gray with sRGB encoding enabled and reading it back with raw OpenGL calls: an sRGB back buffer stores it
+//! encoded (about `188`), while a linear one stores it as-is (about `128`).
+//!
+//! The desktop runner creates an sRGB-capable window when passed `--srgb`; run this test with and without it.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(vec3(.5), 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    LocalExample { program, quad }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let srgb = context.default_framebuffer_is_srgb();
+    log::info!("default framebuffer is sRGB: {}", srgb);
+
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default().enable_srgb(true),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("back buffer render");
+
+    let mut texel = [0u8; 4];
+    unsafe {
+      gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+      gl::ReadBuffer(gl::BACK);
+      gl::ReadPixels(
+        0,
+        0,
+        1,
+        1,
+        gl::RGBA,
+        gl::UNSIGNED_BYTE,
+        texel.as_mut_ptr() as _,
+      );
+
+      // OpenGL was used behind luminance’s back
+      context.backend().state().borrow_mut().invalidate_all();
+    }
+
+    let encoded = texel[0] > 160;
+    assert_eq!(srgb, encoded, "read back {:?}", texel);
+
+    LoopFeedback::Exit
+  }
+}
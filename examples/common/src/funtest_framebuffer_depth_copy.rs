@@ -0,0 +1,216 @@
+//! Render depth into a framebuffer, copy it into a depth texture, then render a new frame into the same framebuffer
+//! sampling the copied depth while writing new depth. The sampled depth must match the snapshot, and the framebuffer
+//! must hold the new depth.
+//!
+//! Rendering to floating-point color attachments requires an extension in WebGL2, so this test is only registered on
+//! desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Depth32F, Floating, R32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+// depth is 0.5 for the first frame
+const VS_FIRST: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+// depth is 0.25 for the second frame
+const VS_SECOND: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], -.5, 1.);
+}";
+
+const FS_FIRST: &str = "
+out float frag;
+
+void main() {
+  frag = 0.;
+}";
+
+// forward the depth of the previous frame
+const FS_SECOND: &str = "
+out float frag;
+
+uniform sampler2D previous_depth;
+
+void main() {
+  frag = texelFetch(previous_depth, ivec2(gl_FragCoord.xy), 0).r;
+}";
+
+const SIZE: [u32; 2] = [2, 2];
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  previous_depth: Uniform<TextureBinding<Dim2, Floating>>,
+}
+
+pub struct LocalExample {
+  first: Program<(), (), ()>,
+  second: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, R32F, Depth32F>,
+  previous_depth: Texture<Dim2, Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let first = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS_FIRST, None, None, FS_FIRST)
+      .expect("first program creation")
+      .ignore_warnings();
+
+    let second = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS_SECOND, None, None, FS_SECOND)
+      .expect("second program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer(SIZE, 0, Sampler::default())
+      .expect("framebuffer");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+    let previous_depth = context
+      .new_texture_raw(
+        SIZE,
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&[0.; 4]),
+      )
+      .expect("previous depth texture");
+
+    LocalExample {
+      first,
+      second,
+      quad,
+      framebuffer,
+      previous_depth,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let first = &mut self.first;
+    let second = &mut self.second;
+    let quad = &self.quad;
+    let previous_depth = &mut self.previous_depth;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(first, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("first frame");
+
+    self
+      .framebuffer
+      .copy_depth_to_texture(previous_depth)
+      .expect("depth copy");
+
+    let snapshot = previous_depth.get_raw_texels().expect("snapshot texels");
+    log::info!("depth snapshot: {:?}", snapshot);
+    assert_eq!(snapshot, [0.5; 4]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let previous_depth = pipeline.bind_texture(previous_depth)?;
+
+          shd_gate.shade(second, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.previous_depth, previous_depth.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("second frame");
+
+    let sampled = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("sampled depth");
+    let depth = self
+      .framebuffer
+      .depth_stencil_slot()
+      .get_raw_texels()
+      .expect("new depth");
+    log::info!("sampled depth: {:?}, new depth: {:?}", sampled, depth);
+
+    assert_eq!(sampled, snapshot);
+    assert_eq!(depth, [0.25; 4]);
+
+    // the texture must have the size of the framebuffer
+    let mut small = context
+      .new_texture_raw::<Dim2, Depth32F>(
+        [1, 1],
+        Sampler::default(),
+        TexelUpload::base_level_without_mipmaps(&[0.]),
+      )
+      .expect("small depth texture");
+    assert!(self.framebuffer.copy_depth_to_texture(&mut small).is_err());
+
+    LoopFeedback::Exit
+  }
+}
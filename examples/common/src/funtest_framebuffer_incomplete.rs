@@ -0,0 +1,48 @@
+//! Create a framebuffer which attachments have a zero size, which makes them incomplete, and check that the backend
+//! reports the specific incomplete status instead of a generic error. A regular framebuffer created afterwards must
+//! still be complete.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::{Framebuffer, FramebufferError, IncompleteReason},
+  pixel::NormRGBA8UI,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let err = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([0, 0], 0, Sampler::default())
+      .err()
+      .expect("zero-sized framebuffer must be incomplete");
+
+    log::info!("zero-sized framebuffer: {}", err);
+    assert_eq!(
+      err,
+      FramebufferError::incomplete(IncompleteReason::IncompleteAttachment)
+    );
+
+    context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([16, 16], 0, Sampler::default())
+      .expect("complete framebuffer");
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,123 @@
+//! Clear an `R32UI` framebuffer with an integer clear value too large to be represented exactly by a float, render an
+//! ID into its left half and read the IDs back: texels where nothing was drawn must hold the exact clear ID.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::R32UI,
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// a quad covering the left half of the framebuffer
+const VS: &str = "
+const vec2[4] CO = vec2[](vec2(-1., -1.), vec2(0., -1.), vec2(-1., 1.), vec2(0., 1.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out uint frag;
+
+uniform uint id;
+
+void main() {
+  frag = id;
+}";
+
+// 2^24 + 1 is the first integer a f32 cannot represent
+const CLEAR_ID: u32 = (1 << 24) + 1;
+const DRAWN_ID: u32 = 7;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  id: Uniform<u32>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, R32UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleStrip)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, R32UI, ()>([4, 2], 0, Sampler::default())
+      .expect("integer framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    // the float clear color is overridden by the integer one for integer attachments
+    let pipeline_st = PipelineState::default()
+      .set_clear_color([1., 1., 1., 1.])
+      .set_clear_color_integer([CLEAR_ID, 0, 0, 0]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.id, DRAWN_ID);
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("ID render");
+
+    let ids = self
+      .framebuffer
+      .read_color([0, 0], [4, 2])
+      .expect("IDs read back");
+
+    for y in 0..2 {
+      for x in 0..4 {
+        let expected = if x < 2 { DRAWN_ID } else { CLEAR_ID };
+        assert_eq!(ids[y * 4 + x], expected, "ID at ({}, {})", x, y);
+      }
+    }
+
+    LoopFeedback::Exit
+  }
+}
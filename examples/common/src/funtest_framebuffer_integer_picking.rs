@@ -0,0 +1,141 @@
+//! Render two quads with the object IDs 1 and 2 into an integer color attachment, as done for GPU picking, then read
+//! back the IDs at chosen texels. The attachment is cleared with the ID 0, which requires clearing integer buffers
+//! specifically.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::R32UI,
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// a quad covering the left half of the framebuffer, except its top row; x_offset moves it to the right half
+const VS: &str = "
+const vec2[4] CO = vec2[](vec2(-1., -1.), vec2(0., -1.), vec2(-1., .5), vec2(0., .5));
+
+uniform float x_offset;
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID] + vec2(x_offset, 0.), 0., 1.);
+}";
+
+const FS: &str = "
+out uint frag;
+
+uniform uint id;
+
+void main() {
+  frag = id;
+}";
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  x_offset: Uniform<f32>,
+  id: Uniform<u32>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, R32UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleStrip)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, R32UI, ()>([8, 4], 0, Sampler::default())
+      .expect("integer framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    // render twice, so that the second render proves the clear reset the IDs of the first one
+    for _ in 0..2 {
+      context
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+          |_, mut shd_gate| {
+            shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+              for (x_offset, id) in [(0., 1), (1., 2)] {
+                iface.set(&uni.x_offset, x_offset);
+                iface.set(&uni.id, id);
+
+                rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                  tess_gate.render(quad)
+                })?;
+              }
+
+              Ok(())
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("ID render");
+    }
+
+    let ids = self
+      .framebuffer
+      .read_color([0, 0], [8, 4])
+      .expect("IDs read back");
+    assert_eq!(ids.len(), 8 * 4);
+
+    for y in 0..4 {
+      for x in 0..8 {
+        let expected = match (x, y) {
+          (_, 3) => 0,
+          (0..=3, _) => 1,
+          _ => 2,
+        };
+
+        assert_eq!(ids[y * 8 + x], expected, "ID at ({}, {})", x, y);
+      }
+    }
+
+    // pick single texels, as done under a cursor
+    assert_eq!(self.framebuffer.read_color([1, 1], [1, 1]), Ok(vec![1]));
+    assert_eq!(self.framebuffer.read_color([6, 2], [1, 1]), Ok(vec![2]));
+    assert_eq!(self.framebuffer.read_color([5, 3], [1, 1]), Ok(vec![0]));
+
+    LoopFeedback::Exit
+  }
+}
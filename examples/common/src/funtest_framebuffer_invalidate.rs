@@ -0,0 +1,130 @@
+//! Render into a framebuffer with a depth attachment, invalidate the depth attachment and check that no error is
+//! reported and that the color attachment is left untouched.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::{Attachment, Framebuffer, FramebufferError},
+  pipeline::PipelineState,
+  pixel::{Depth32F, NormRGBA8UI},
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+// triangle covering the whole viewport
+const VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([3., -1.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 3.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  triangle: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth32F>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangle,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    mut back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context.set_error_checking(true);
+
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(triangle)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    // depth is not needed anymore
+    self
+      .framebuffer
+      .invalidate(&[Attachment::Depth])
+      .expect("depth invalidation");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for texel in texels.chunks(4) {
+      assert_eq!(texel, [0, 0, 255, 255]);
+    }
+
+    // the back buffer has a single color buffer
+    back_buffer
+      .invalidate(&[Attachment::Depth, Attachment::Stencil])
+      .expect("back buffer invalidation");
+    assert_eq!(
+      back_buffer.invalidate(&[Attachment::Color(1)]),
+      Err(FramebufferError::UnsupportedAttachment)
+    );
+
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected backend errors: {:?}", errors);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,182 @@
+//! Create a framebuffer with raw OpenGL calls, as an engine embedding luminance would do, wrap it with
+//! `Framebuffer::from_raw_handle` and render into it with luminance. The pixels are read back with raw OpenGL calls too:
+//! the left half of the framebuffer must be covered by a red quad over a blue clear color. Dropping the wrapper must
+//! not delete the framebuffer, which is still owned by its creator.
+//!
+//! This test only exists for the OpenGL backend.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{NormRGBA8UI, Pixel},
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] CO = vec2[](vec2(-1., -1.), vec2(0., -1.), vec2(0., 1.), vec2(-1., 1.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+const SIZE: usize = 16;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  // raw framebuffer and its color texture, owned by the test
+  raw_framebuffer: u32,
+  raw_texture: u32,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let (raw_framebuffer, raw_texture) = unsafe {
+      let mut texture = 0;
+      gl::GenTextures(1, &mut texture);
+      gl::BindTexture(gl::TEXTURE_2D, texture);
+      gl::TexImage2D(
+        gl::TEXTURE_2D,
+        0,
+        gl::RGBA8 as _,
+        SIZE as _,
+        SIZE as _,
+        0,
+        gl::RGBA,
+        gl::UNSIGNED_BYTE,
+        std::ptr::null(),
+      );
+      gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::NEAREST as _);
+      gl::BindTexture(gl::TEXTURE_2D, 0);
+
+      let mut framebuffer = 0;
+      gl::GenFramebuffers(1, &mut framebuffer);
+      gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer);
+      gl::FramebufferTexture2D(
+        gl::FRAMEBUFFER,
+        gl::COLOR_ATTACHMENT0,
+        gl::TEXTURE_2D,
+        texture,
+        0,
+      );
+      gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+
+      // OpenGL was used behind luminance’s back
+      context.backend().state().borrow_mut().invalidate_all();
+
+      (framebuffer, texture)
+    };
+
+    LocalExample {
+      program,
+      quad,
+      raw_framebuffer,
+      raw_texture,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let framebuffer = unsafe {
+      Framebuffer::from_raw_handle(
+        context,
+        self.raw_framebuffer,
+        [SIZE as u32, SIZE as u32],
+        &[NormRGBA8UI::pixel_format()],
+      )
+    }
+    .expect("raw framebuffer wrapping");
+    assert!(!framebuffer.is_back_buffer());
+
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 1., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("raw framebuffer render");
+
+    drop(framebuffer);
+
+    let mut texels = vec![0u8; SIZE * SIZE * 4];
+    unsafe {
+      assert_eq!(gl::IsFramebuffer(self.raw_framebuffer), gl::TRUE);
+
+      gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.raw_framebuffer);
+      gl::ReadPixels(
+        0,
+        0,
+        SIZE as _,
+        SIZE as _,
+        gl::RGBA,
+        gl::UNSIGNED_BYTE,
+        texels.as_mut_ptr() as _,
+      );
+      gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+
+      gl::DeleteFramebuffers(1, &self.raw_framebuffer);
+      gl::DeleteTextures(1, &self.raw_texture);
+
+      context.backend().state().borrow_mut().invalidate_all();
+    }
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let (x, y) = (i % SIZE, i / SIZE);
+      let expected = if x < SIZE / 2 {
+        [255, 0, 0, 255]
+      } else {
+        [0, 0, 255, 255]
+      };
+
+      assert_eq!(texel, expected, "pixel ({}, {})", x, y);
+    }
+
+    LoopFeedback::Exit
+  }
+}
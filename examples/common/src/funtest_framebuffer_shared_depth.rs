@@ -0,0 +1,235 @@
+//! Create two color framebuffers sharing a single depth attachment with `new_sharing_depth`, as done to ping-pong an
+//! effect, and render depth-tested quads alternately into both. Each framebuffer must be occluded by the depth the
+//! other one wrote, even once the framebuffer the depth was created with is dropped.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  depth_stencil::Comparison,
+  framebuffer::{Framebuffer, FramebufferError},
+  pipeline::PipelineState,
+  pixel::{Depth32F, NormRGBA8UI},
+  render_state::RenderState,
+  shader::{
+    types::{Vec2, Vec4},
+    Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// a quad spanning the whole height, between x_range.x and x_range.y horizontally
+const VS: &str = "
+uniform vec2 x_range;
+uniform float z;
+
+void main() {
+  float x = (gl_VertexID & 1) == 0 ? x_range.x : x_range.y;
+  float y = gl_VertexID < 2 ? -1. : 1.;
+  gl_Position = vec4(x, y, z, 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec4 color;
+
+void main() {
+  frag = color;
+}";
+
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+const RED: [u8; 4] = [255, 0, 0, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  x_range: Uniform<Vec2<f32>>,
+  z: Uniform<f32>,
+  color: Uniform<Vec4<f32>>,
+}
+
+type ColorFramebuffer = Framebuffer<Dim2, NormRGBA8UI, Depth32F>;
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  first: ColorFramebuffer,
+  second: ColorFramebuffer,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleStrip)
+      .build()
+      .expect("quad");
+
+    let mut first = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth32F>([4, 1], 0, Sampler::default())
+      .expect("first framebuffer");
+
+    let second = ColorFramebuffer::new_sharing_depth(
+      context,
+      [4, 1],
+      0,
+      Sampler::default(),
+      first.depth_stencil_slot(),
+    )
+    .expect("second framebuffer");
+
+    // the shared depth must have the size of the framebuffer
+    let mismatched = ColorFramebuffer::new_sharing_depth(
+      context,
+      [2, 1],
+      0,
+      Sampler::default(),
+      first.depth_stencil_slot(),
+    );
+    assert!(matches!(
+      mismatched,
+      Err(FramebufferError::SizeMismatch {
+        framebuffer: [2, 1],
+        texture: [4, 1],
+      })
+    ));
+
+    LocalExample {
+      program,
+      quad,
+      first,
+      second,
+    }
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let LocalExample {
+      mut program,
+      quad,
+      mut first,
+      mut second,
+    } = self;
+
+    // a near red quad on the left half of the first framebuffer, clearing the shared depth
+    let texels = render(
+      context,
+      &mut program,
+      &quad,
+      &mut first,
+      true,
+      [-1., 0.],
+      0.2,
+      [1., 0., 0., 1.],
+    );
+    assert_eq!(texels, [RED, RED, BLACK, BLACK]);
+
+    // a far green quad on the whole second framebuffer is occluded by the red quad of the first one
+    let texels = render(
+      context,
+      &mut program,
+      &quad,
+      &mut second,
+      false,
+      [-1., 1.],
+      0.6,
+      [0., 1., 0., 1.],
+    );
+    assert_eq!(texels, [BLACK, BLACK, GREEN, GREEN]);
+
+    // back to the first framebuffer: a blue quad in between is only visible where the green quad was rendered
+    let texels = render(
+      context,
+      &mut program,
+      &quad,
+      &mut first,
+      false,
+      [-1., 1.],
+      0.4,
+      [0., 0., 1., 1.],
+    );
+    assert_eq!(texels, [RED, RED, BLUE, BLUE]);
+
+    // the depth outlives the framebuffer it was created with
+    drop(first);
+
+    let texels = render(
+      context,
+      &mut program,
+      &quad,
+      &mut second,
+      false,
+      [-1., 1.],
+      0.3,
+      [0., 0., 1., 1.],
+    );
+    assert_eq!(texels, [BLACK, BLACK, BLUE, BLUE]);
+
+    LoopFeedback::Exit
+  }
+}
+
+/// Render a depth-tested quad into `framebuffer` and read back its texels.
+///
+/// The color is cleared at each render, but the depth is only cleared if `clear_depth` is set.
+#[allow(clippy::too_many_arguments)]
+fn render(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  program: &mut Program<(), (), ShaderInterface>,
+  quad: &Tess<()>,
+  framebuffer: &mut ColorFramebuffer,
+  clear_depth: bool,
+  x_range: [f32; 2],
+  z: f32,
+  color: [f32; 4],
+) -> Vec<[u8; 4]> {
+  let pipeline_st = PipelineState::default()
+    .set_clear_color([0., 0., 0., 1.])
+    .set_clear_depth(if clear_depth { Some(1.) } else { None });
+  let render_st = RenderState::default().set_depth_test(Comparison::Less);
+
+  context
+    .new_pipeline_gate()
+    .pipeline(framebuffer, &pipeline_st, |_, mut shd_gate| {
+      shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+        iface.set(&uni.x_range, Vec2::new(x_range[0], x_range[1]));
+        iface.set(&uni.z, z);
+        iface.set(
+          &uni.color,
+          Vec4::new(color[0], color[1], color[2], color[3]),
+        );
+
+        rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+      })
+    })
+    .assume()
+    .into_result()
+    .expect("depth-tested render");
+
+  framebuffer
+    .color_slot()
+    .get_raw_texels()
+    .expect("texels")
+    .chunks(4)
+    .map(|texel| [texel[0], texel[1], texel[2], texel[3]])
+    .collect()
+}
@@ -0,0 +1,315 @@
+//! Write a stencil pattern into a framebuffer with a combined depth / stencil slot, then sample its stencil component
+//! into an unsigned color output and check that the pattern is read back. Once the stencil view is dropped, the depth
+//! component is sampled again to ensure the default depth view is restored.
+//!
+//! Sampling stencil components requires OpenGL 4.3 or `GL_ARB_stencil_texturing`; the test is skipped otherwise.
+//! WebGL2 cannot sample them at all, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest},
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Depth24Stencil8, NormRGBA8UI, NormUnsigned, Unsigned, R32F, R32UI},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler},
+  Backend,
+};
+
+// quad covering the left half of the framebuffer only
+const LEFT_HALF_VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 0., -1.),
+  vec2( 0.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FULLSCREEN_VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const WHITE_FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.);
+}";
+
+const STENCIL_FS: &str = "
+out uint frag;
+
+uniform usampler2D stencil;
+
+void main() {
+  frag = texelFetch(stencil, ivec2(gl_FragCoord.xy), 0).r;
+}";
+
+const DEPTH_FS: &str = "
+out float frag;
+
+uniform sampler2D depth;
+
+void main() {
+  frag = texelFetch(depth, ivec2(gl_FragCoord.xy), 0).r;
+}";
+
+const SIZE: u32 = 4;
+const CLEAR_STENCIL: u32 = 42;
+const QUAD_STENCIL: u32 = 200;
+
+#[derive(Debug, UniformInterface)]
+struct StencilInterface {
+  stencil: Uniform<TextureBinding<Dim2, Unsigned>>,
+}
+
+#[derive(Debug, UniformInterface)]
+struct DepthInterface {
+  depth: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  supported: bool,
+  write_program: Program<(), (), ()>,
+  stencil_program: Program<(), (), StencilInterface>,
+  depth_program: Program<(), (), DepthInterface>,
+  left_half: Tess<()>,
+  fullscreen: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth24Stencil8>,
+  stencil_output: Framebuffer<Dim2, R32UI, ()>,
+  depth_output: Framebuffer<Dim2, R32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let supported = unsafe {
+      context
+        .backend()
+        .state()
+        .borrow_mut()
+        .supports_stencil_texturing()
+    };
+
+    let write_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(LEFT_HALF_VS, None, None, WHITE_FS)
+      .expect("write program")
+      .ignore_warnings();
+
+    let stencil_program = context
+      .new_shader_program::<(), (), StencilInterface>()
+      .from_strings(FULLSCREEN_VS, None, None, STENCIL_FS)
+      .expect("stencil program")
+      .ignore_warnings();
+
+    let depth_program = context
+      .new_shader_program::<(), (), DepthInterface>()
+      .from_strings(FULLSCREEN_VS, None, None, DEPTH_FS)
+      .expect("depth program")
+      .ignore_warnings();
+
+    let left_half = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .expect("left half quad");
+
+    let fullscreen = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .expect("fullscreen quad");
+
+    // stencil values cannot be filtered
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth24Stencil8>([SIZE, SIZE], 0, sampler)
+      .expect("depth / stencil framebuffer");
+    let stencil_output = context
+      .new_framebuffer::<Dim2, R32UI, ()>([SIZE, SIZE], 0, sampler)
+      .expect("stencil output framebuffer");
+    let depth_output = context
+      .new_framebuffer::<Dim2, R32F, ()>([SIZE, SIZE], 0, sampler)
+      .expect("depth output framebuffer");
+
+    LocalExample {
+      supported,
+      write_program,
+      stencil_program,
+      depth_program,
+      left_half,
+      fullscreen,
+      framebuffer,
+      stencil_output,
+      depth_output,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    if !self.supported {
+      log::warn!("stencil texturing not supported by the running context; skipping");
+      return LoopFeedback::Exit;
+    }
+
+    // write the stencil pattern: the left half gets QUAD_STENCIL, the right half keeps CLEAR_STENCIL
+    let write_program = &mut self.write_program;
+    let left_half = &self.left_half;
+    let render_st = RenderState::default()
+      .set_stencil_test(StencilTest::new(
+        Comparison::Always,
+        QUAD_STENCIL as u8,
+        0xFF,
+      ))
+      .set_stencil_operations(
+        StencilOperations::default().on_depth_stencil_pass(StencilOp::Replace),
+      );
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_stencil(CLEAR_STENCIL as i32),
+        |_, mut shd_gate| {
+          shd_gate.shade(write_program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(left_half))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("stencil write");
+
+    // sample the stencil view
+    let stencil_program = &mut self.stencil_program;
+    let fullscreen = &self.fullscreen;
+    let depth_stencil = self.framebuffer.depth_stencil_slot();
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.stencil_output,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let stencil = pipeline.bind_texture_stencil(depth_stencil)?;
+
+          shd_gate.shade(stencil_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.stencil, stencil.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(fullscreen)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("stencil sampling");
+
+    let stencils = self
+      .stencil_output
+      .color_slot()
+      .get_raw_texels()
+      .expect("stencil texels");
+    log::info!("sampled stencil values: {:?}", stencils);
+
+    for (i, &stencil) in stencils.iter().enumerate() {
+      let x = i as u32 % SIZE;
+      let expected = if x < SIZE / 2 {
+        QUAD_STENCIL
+      } else {
+        CLEAR_STENCIL
+      };
+
+      assert_eq!(
+        stencil,
+        expected,
+        "stencil {} ({}, {})",
+        i,
+        x,
+        i as u32 / SIZE
+      );
+    }
+
+    // sample the depth view, which must have been restored when the stencil view was dropped
+    let depth_program = &mut self.depth_program;
+    let depth_stencil = self.framebuffer.depth_stencil_slot();
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.depth_output,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let depth = pipeline.bind_texture(depth_stencil)?;
+
+          shd_gate.shade(depth_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.depth, depth.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(fullscreen)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("depth sampling");
+
+    let depths = self
+      .depth_output
+      .color_slot()
+      .get_raw_texels()
+      .expect("depth texels");
+    log::info!("sampled depth values: {:?}", depths);
+
+    for (i, &depth) in depths.iter().enumerate() {
+      let x = i as u32 % SIZE;
+      // the quad lies at z = 0 in NDC, which maps to 0.5 in window space; the rest is cleared to 1
+      let expected = if x < SIZE / 2 { 0.5 } else { 1. };
+
+      assert!(
+        (depth - expected).abs() < 1e-3,
+        "depth {} ({}, {}): {} (expected {})",
+        i,
+        x,
+        i as u32 / SIZE,
+        depth,
+        expected
+      );
+    }
+
+    LoopFeedback::Exit
+  }
+}
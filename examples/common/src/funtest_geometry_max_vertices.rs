@@ -0,0 +1,90 @@
+//! Build a program which geometry shader declares one more output vertex than the backend supports: it must fail with
+//! `ProgramError::GeometryOutputTooLarge` instead of a link error. A geometry shader within the limit must still build.
+//!
+//! WebGL2 has no geometry shaders, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  shader::{Program, ProgramError},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+void main() {
+  gl_Position = vec4(0., 0., 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.);
+}";
+
+// geometry shader declaring a given number of output vertices
+fn geometry_shader(max_vertices: usize) -> String {
+  format!(
+    "
+layout (points) in;
+layout (points, max_vertices = {}) out;
+
+void main() {{
+  gl_Position = gl_in[0].gl_Position;
+  EmitVertex();
+  EndPrimitive();
+}}",
+    max_vertices
+  )
+}
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let max = unsafe {
+      context
+        .backend()
+        .state()
+        .borrow_mut()
+        .get_max_geometry_output_vertices()
+    };
+
+    let too_large = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, geometry_shader(max + 1).as_str(), FS)
+      .map(|_| ());
+    assert!(
+      matches!(
+        too_large,
+        Err(ProgramError::GeometryOutputTooLarge { requested, max: limit })
+          if requested == max + 1 && limit == max
+      ),
+      "{:?}",
+      too_large
+    );
+
+    let _: Program<(), (), ()> = context
+      .new_shader_program()
+      .from_strings(VS, None, geometry_shader(max).as_str(), FS)
+      .expect("program within the geometry output limit")
+      .ignore_warnings();
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,100 @@
+//! This functional test deliberately triggers backend errors and checks that they are reported when error checking is
+//! enabled, instead of being silently swallowed.
+//!
+//! The errors are triggered by rendering a patch tessellation with an out-of-range number of vertices per patch and no
+//! tessellation stages in the shader program.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+void main() {
+  gl_Position = vec4(0., 0., 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  tess: Tess<()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    context.set_error_checking(true);
+    assert!(context.is_error_checking());
+
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .unwrap()
+      .ignore_warnings();
+
+    // way above the minimum guaranteed maximum number of vertices per patch (32)
+    let tess = context
+      .new_tess()
+      .set_mode(Mode::Patch(4096))
+      .set_render_vertex_nb(4096)
+      .build()
+      .unwrap();
+
+    // nothing wrong should have happened so far
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+
+    LocalExample { program, tess }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let tess = &self.tess;
+
+    let _ = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(tess)
+            })
+          })
+        },
+      )
+      .assume();
+
+    let errors = context.take_backend_errors();
+
+    for error in &errors {
+      log::info!("reported backend error: {}", error);
+    }
+
+    assert!(!errors.is_empty(), "backend errors were swallowed");
+
+    LoopFeedback::Exit
+  }
+}
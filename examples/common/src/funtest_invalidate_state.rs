@@ -0,0 +1,136 @@
+//! Render a red quad over the left half of a framebuffer cleared in blue, then change the OpenGL state with raw calls,
+//! as foreign code sharing the context would do, and invalidate the state cached by luminance with
+//! `GraphicsContext::invalidate_state`. Rendering again must give the exact same texels, despite the foreign state
+//! changes.
+//!
+//! This test only exists for the OpenGL backend.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] CO = vec2[](vec2(-1., -1.), vec2(0., -1.), vec2(0., 1.), vec2(-1., 1.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+const SIZE: usize = 16;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([SIZE as u32, SIZE as u32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for pass in 0..2 {
+      let program = &mut self.program;
+      let quad = &self.quad;
+
+      context
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default().set_clear_color([0., 0., 1., 1.]),
+          |_, mut shd_gate| {
+            shd_gate.shade(program, |_, _, mut rdr_gate| {
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(quad)
+              })
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("render");
+
+      let texels = self
+        .framebuffer
+        .color_slot()
+        .get_raw_texels()
+        .expect("texels");
+
+      for (i, texel) in texels.chunks(4).enumerate() {
+        let (x, y) = (i % SIZE, i / SIZE);
+        let expected = if x < SIZE / 2 {
+          [255, 0, 0, 255]
+        } else {
+          [0, 0, 255, 255]
+        };
+
+        assert_eq!(texel, expected, "pass {}, pixel ({}, {})", pass, x, y);
+      }
+
+      // change the state behind luminance’s back; without invalidation, luminance would think its cached state is
+      // still current and skip the calls restoring it
+      unsafe {
+        gl::ClearColor(0., 1., 0., 1.);
+        gl::Enable(gl::SCISSOR_TEST);
+        gl::Scissor(0, 0, 1, 1);
+        gl::Enable(gl::CULL_FACE);
+        gl::CullFace(gl::FRONT_AND_BACK);
+        gl::Viewport(0, 0, 1, 1);
+        gl::UseProgram(0);
+        gl::BindVertexArray(0);
+        gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+      }
+
+      context.invalidate_state();
+    }
+
+    LoopFeedback::Exit
+  }
+}
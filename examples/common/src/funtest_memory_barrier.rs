@@ -0,0 +1,166 @@
+//! Write to an offscreen framebuffer in a first render, issue a memory barrier and read the written texels back by
+//! sampling them in a second render.
+//!
+//! luminance doesn’t expose shader storage buffers nor image stores yet, so the writes are regular framebuffer writes;
+//! the test checks that barriers are accepted between renders without altering the results. Contexts that cannot issue
+//! memory barriers (OpenGL < 4.2) must report them as unsupported.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  memory_barrier::{MemoryBarrierBits, MemoryBarrierError},
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const WRITE_FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(.2, .4, .6, 1.);
+}";
+
+const READ_FS: &str = "
+out vec4 frag;
+
+uniform sampler2D written;
+
+void main() {
+  frag = texelFetch(written, ivec2(0, 0), 0).bgra;
+}";
+
+#[derive(Debug, UniformInterface)]
+struct ReadInterface {
+  written: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  write_program: Program<(), (), ()>,
+  read_program: Program<(), (), ReadInterface>,
+  quad: Tess<()>,
+  written: Framebuffer<Dim2, NormRGBA8UI, ()>,
+  output: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let write_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, WRITE_FS)
+      .expect("write program")
+      .ignore_warnings();
+
+    let read_program = context
+      .new_shader_program::<(), (), ReadInterface>()
+      .from_strings(VS, None, None, READ_FS)
+      .expect("read program")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let written = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("written framebuffer");
+
+    let output = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("output framebuffer");
+
+    LocalExample {
+      write_program,
+      read_program,
+      quad,
+      written,
+      output,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let write_program = &mut self.write_program;
+    let read_program = &mut self.read_program;
+    let quad = &self.quad;
+    let written = &mut self.written;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(written, &PipelineState::default(), |_, mut shd_gate| {
+        shd_gate.shade(write_program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("write render");
+
+    let bits = MemoryBarrierBits::FRAMEBUFFER
+      | MemoryBarrierBits::TEXTURE_FETCH
+      | MemoryBarrierBits::SHADER_STORAGE;
+    match context.memory_barrier(bits) {
+      Ok(()) => log::info!("memory barrier issued: {}", bits),
+
+      Err(MemoryBarrierError::Unsupported(unsupported)) => {
+        assert_eq!(unsupported, bits);
+        log::warn!("memory barriers not supported by the running context; skipping");
+      }
+
+      Err(e) => panic!("memory barrier: {}", e),
+    }
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.output,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let texture = pipeline.bind_texture(written.color_slot())?;
+
+          shd_gate.shade(read_program, |mut iface, uni, mut rdr_gate| {
+            iface.set_texture(&uni.written, &texture);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("read render");
+
+    let texels = self
+      .output
+      .color_slot()
+      .get_raw_texels()
+      .expect("output texels");
+
+    // the written color, swizzled by the read shader
+    assert_eq!(texels, [153, 102, 51, 255]);
+
+    LoopFeedback::Exit
+  }
+}
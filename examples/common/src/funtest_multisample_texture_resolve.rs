@@ -0,0 +1,210 @@
+//! Render a triangle into a 4× multisample texture, resolve it by hand by averaging the samples with `texelFetch` and
+//! check that edge texels end up partially covered.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Floating, RGBA32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Dim2Multisample, Sampler, TextureError},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+const RESOLVE_VS: &str = include_str!("copy-vs.glsl");
+
+const RESOLVE_FS: &str = "
+out vec4 frag;
+
+uniform sampler2DMS source_texture;
+uniform int samples;
+
+void main() {
+  ivec2 coord = ivec2(gl_FragCoord.xy);
+  vec4 color = vec4(0.);
+
+  for (int i = 0; i < samples; ++i) {
+    color += texelFetch(source_texture, coord, i);
+  }
+
+  frag = color / float(samples);
+}";
+
+const SAMPLES: usize = 4;
+const SIZE: u32 = 4;
+
+// white triangle covering the lower-left half of the viewport; its hypotenuse cuts the anti-diagonal texels in half
+const VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([1., -1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 1.]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+];
+
+#[derive(UniformInterface)]
+struct ResolveInterface {
+  #[uniform(name = "source_texture")]
+  texture: Uniform<TextureBinding<Dim2Multisample<SAMPLES>, Floating>>,
+  samples: Uniform<i32>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  resolve_program: Program<(), (), ResolveInterface>,
+  triangle: Tess<Vertex>,
+  quad: Tess<()>,
+  multisample_framebuffer: Framebuffer<Dim2Multisample<SAMPLES>, RGBA32F, ()>,
+  resolved_framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let built = context
+      .new_shader_program::<(), (), ResolveInterface>()
+      .from_strings(RESOLVE_VS, None, None, RESOLVE_FS)
+      .expect("resolve program creation");
+    assert!(built.warnings.is_empty(), "{:?}", built.warnings);
+    let resolve_program = built.program;
+
+    let triangle = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let multisample_framebuffer = context
+      .new_framebuffer::<Dim2Multisample<SAMPLES>, RGBA32F, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("multisample framebuffer");
+
+    let resolved_framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("resolved framebuffer");
+
+    LocalExample {
+      program,
+      resolve_program,
+      triangle,
+      quad,
+      multisample_framebuffer,
+      resolved_framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let resolve_program = &mut self.resolve_program;
+    let triangle = &self.triangle;
+    let quad = &self.quad;
+    let multisample_framebuffer = &mut self.multisample_framebuffer;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+    let mut builder = context.new_pipeline_gate();
+
+    builder
+      .pipeline(multisample_framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(triangle)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("multisample render");
+
+    // multisample texels cannot be read back directly
+    assert!(matches!(
+      multisample_framebuffer.color_slot().get_raw_texels(),
+      Err(TextureError::CannotRetrieveTexels(_))
+    ));
+
+    builder
+      .pipeline(
+        &self.resolved_framebuffer,
+        &pipeline_st,
+        |pipeline, mut shd_gate| {
+          let bound_texture = pipeline.bind_texture(multisample_framebuffer.color_slot())?;
+
+          shd_gate.shade(resolve_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.texture, bound_texture.binding());
+            iface.set(&uni.samples, SAMPLES as i32);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("resolve render");
+
+    let texels = self
+      .resolved_framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("resolved texels");
+    let red = |x: u32, y: u32| texels[((y * SIZE + x) * 4) as usize];
+
+    log::info!(
+      "resolved red channel: {:?}",
+      texels.chunks(4).map(|t| t[0]).collect::<Vec<_>>()
+    );
+
+    assert_eq!(red(0, 0), 1., "fully covered texel");
+    assert_eq!(red(3, 3), 0., "uncovered texel");
+
+    for x in 0..SIZE {
+      let y = SIZE - 1 - x;
+      let edge = red(x, y);
+      assert!(
+        edge > 0. && edge < 1.,
+        "edge texel ({}, {}) should be partially covered, got {}",
+        x,
+        y,
+        edge
+      );
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,187 @@
+//! Render an asymmetric pattern with a top-left origin and check that viewports, scissor regions and texel readbacks
+//! all agree on where the top-left corner is.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{Origin, PipelineState, Viewport},
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  scissor::ScissorRegion,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+const BLACK: [u8; 4] = [0, 0, 0, 255];
+const WHITE: [u8; 4] = [255, 255, 255, 255];
+const GREEN: [u8; 4] = [0, 255, 0, 255];
+
+// expected texels, top row first
+const EXPECTED: [[[u8; 4]; 4]; 4] = [
+  [WHITE, WHITE, BLACK, BLACK],
+  [WHITE, WHITE, BLACK, BLACK],
+  [BLACK, BLACK, BLACK, BLACK],
+  [BLACK, BLACK, BLACK, GREEN],
+];
+
+// triangle covering the whole viewport
+fn fullscreen_triangle(rgb: [f32; 3]) -> [Vertex; 3] {
+  [
+    Vertex {
+      pos: VertexPosition::new([-1., -1.]),
+      rgb: VertexColor::new(rgb),
+    },
+    Vertex {
+      pos: VertexPosition::new([3., -1.]),
+      rgb: VertexColor::new(rgb),
+    },
+    Vertex {
+      pos: VertexPosition::new([-1., 3.]),
+      rgb: VertexColor::new(rgb),
+    },
+  ]
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  white: Tess<Vertex>,
+  green: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let white = context
+      .new_tess()
+      .set_vertices(&fullscreen_triangle([1., 1., 1.])[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("white triangle");
+
+    let green = context
+      .new_tess()
+      .set_vertices(&fullscreen_triangle([0., 1., 0.])[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("green triangle");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      white,
+      green,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let white = &self.white;
+    let green = &self.green;
+
+    // white in the top-left quadrant, through the viewport
+    let pipeline_st = PipelineState::default()
+      .set_clear_color([0., 0., 0., 1.])
+      .set_origin(Origin::TopLeft)
+      .set_viewport(Viewport::Specific {
+        x: 0,
+        y: 0,
+        width: 2,
+        height: 2,
+      });
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(white)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("viewport render");
+
+    // green in the bottom-right texel, through the scissor region
+    let pipeline_st = PipelineState::default()
+      .set_clear_color(None)
+      .set_origin(Origin::TopLeft);
+    let render_st = RenderState::default().set_scissor(ScissorRegion {
+      x: 3,
+      y: 3,
+      width: 1,
+      height: 1,
+    });
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(green))
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("scissor render");
+
+    let expected = EXPECTED
+      .iter()
+      .flatten()
+      .flatten()
+      .copied()
+      .collect::<Vec<_>>();
+
+    let top_left = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels_with_origin(Origin::TopLeft)
+      .expect("top-left texels");
+    log::info!("top-left texels: {:?}", top_left);
+    assert_eq!(top_left, expected);
+
+    // the default readback starts with the bottom row
+    let bottom_left = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("bottom-left texels");
+    let expected_bottom_left = EXPECTED
+      .iter()
+      .rev()
+      .flatten()
+      .flatten()
+      .copied()
+      .collect::<Vec<_>>();
+    assert_eq!(bottom_left, expected_bottom_left);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,135 @@
+//! Render a quad — two triangles — three times while a pipeline statistics query is active, and check the number of
+//! primitives generated and samples passed.
+//!
+//! Beginning a query while another one is active must fail.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  query::QueryError,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 1., 1., 1.);
+}";
+
+const SIZE: u32 = 4;
+const RENDER_NB: u64 = 3;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer([SIZE, SIZE], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let mut query = context
+      .new_pipeline_stats_query()
+      .expect("pipeline statistics query");
+    let mut other = context
+      .new_pipeline_stats_query()
+      .expect("other pipeline statistics query");
+
+    assert!(matches!(query.stats(), Err(QueryError::NoPipelineStats)));
+
+    query.begin().expect("query beginning");
+    assert!(matches!(
+      other.begin(),
+      Err(QueryError::PipelineStatsQueryActive)
+    ));
+
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              for _ in 0..RENDER_NB {
+                tess_gate.render(quad)?;
+              }
+
+              Ok(())
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("pipeline");
+
+    query.end().expect("query end");
+    assert!(matches!(
+      query.end(),
+      Err(QueryError::PipelineStatsQueryInactive)
+    ));
+
+    let stats = query.stats().expect("pipeline statistics");
+    log::info!("pipeline statistics: {:?}", stats);
+
+    assert_eq!(query.primitives_generated().unwrap(), Some(2 * RENDER_NB));
+    assert_eq!(
+      query.samples_passed().unwrap(),
+      Some(RENDER_NB * (SIZE * SIZE) as u64)
+    );
+    assert_eq!(query.vertices_submitted().unwrap(), None);
+
+    // the query is not active anymore, so another one can begin
+    other.begin().expect("other query beginning");
+    other.end().expect("other query end");
+
+    LoopFeedback::Exit
+  }
+}
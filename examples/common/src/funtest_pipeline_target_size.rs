@@ -0,0 +1,128 @@
+//! Run a full-screen pass into framebuffers of different sizes, passing `Pipeline::target_size` to the shader as its
+//! resolution. The shader mirrors the pixel coordinates with that resolution, so that the corner opposite to the origin
+//! is `(0, 0)` only if the resolution matches the framebuffer size, which is checked on readback for every pixel.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::RGBA32F,
+  render_state::RenderState,
+  shader::{types::Vec2, Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[3] CO = vec2[](vec2(-1., -1.), vec2(3., -1.), vec2(-1., 3.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec2 resolution;
+
+void main() {
+  frag = vec4(resolution - 1. - floor(gl_FragCoord.xy), 0., 1.);
+}";
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  resolution: Uniform<Vec2<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  triangle: Tess<()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_render_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    LocalExample { program, triangle }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for size in [[7, 5], [3, 2]] {
+      self.check_pattern(context, size);
+    }
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  fn check_pattern(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    [w, h]: [u32; 2],
+  ) {
+    let mut framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([w, h], 0, Sampler::default())
+      .expect("framebuffer");
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &framebuffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          assert_eq!(pipeline.target_size(), [w, h]);
+          let [w, h] = pipeline.target_size();
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.resolution, Vec2::new(w as f32, h as f32));
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(triangle)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("full-screen pass");
+
+    let texels = framebuffer.color_slot().get_raw_texels().expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let (x, y) = (i as u32 % w, i as u32 / w);
+      let expected = [(w - 1 - x) as f32, (h - 1 - y) as f32, 0., 1.];
+
+      assert_eq!(
+        texel, expected,
+        "pixel ({}, {}) of a {}×{} framebuffer",
+        x, y, w, h
+      );
+    }
+  }
+}
@@ -0,0 +1,220 @@
+//! Render four 8×8 point sprites sampling a disc texture with `gl_PointCoord`, the point size being set by the vertex
+//! shader. Every pixel of the framebuffer is checked: the texels of the disc must cover the pixels around each point
+//! with the color of the point, while the transparent corners of the sprites are discarded.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = "
+in vec2 co;
+in vec3 color;
+
+out vec3 v_color;
+
+void main() {
+  gl_Position = vec4(co, 0., 1.);
+  gl_PointSize = 8.;
+  v_color = color;
+}";
+
+const FS: &str = "
+in vec3 v_color;
+
+out vec4 frag;
+
+uniform sampler2D tex;
+
+void main() {
+  vec4 texel = texture(tex, gl_PointCoord);
+
+  if (texel.a == 0.) {
+    discard;
+  }
+
+  frag = vec4(v_color * texel.rgb, 1.);
+}";
+
+const SIZE: usize = 32;
+const SPRITE_SIZE: usize = 8;
+
+// points are centered on pixel corners, so that each sprite covers exactly SPRITE_SIZE × SPRITE_SIZE pixels
+const POINTS: [([usize; 2], [u8; 3]); 4] = [
+  ([8, 8], [255, 0, 0]),
+  ([24, 8], [0, 255, 0]),
+  ([8, 24], [0, 0, 255]),
+  ([24, 24], [255, 255, 0]),
+];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  tex: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  points: Tess<Vertex>,
+  sprite: Texture<Dim2, NormRGBA8UI>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let vertices = POINTS
+      .iter()
+      .map(|&([x, y], color)| {
+        Vertex::new(
+          VertexPosition::new([
+            x as f32 / SIZE as f32 * 2. - 1.,
+            y as f32 / SIZE as f32 * 2. - 1.,
+          ]),
+          VertexColor::new(color.map(|c| c as f32 / 255.)),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let points = context
+      .new_tess()
+      .set_vertices(vertices)
+      .set_mode(Mode::Point)
+      .build()
+      .expect("points");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let sprite_texels = (0..SPRITE_SIZE * SPRITE_SIZE)
+      .flat_map(|i| {
+        let alpha = if in_disc(i % SPRITE_SIZE, i / SPRITE_SIZE) {
+          255
+        } else {
+          0
+        };
+
+        [255, 255, 255, alpha]
+      })
+      .collect::<Vec<_>>();
+
+    let sprite = context
+      .new_texture_raw(
+        [SPRITE_SIZE as u32, SPRITE_SIZE as u32],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&sprite_texels),
+      )
+      .expect("sprite texture");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([SIZE as u32, SIZE as u32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      points,
+      sprite,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let points = &self.points;
+    let sprite = &mut self.sprite;
+    let render_st = RenderState::default()
+      .set_depth_test(None)
+      .set_program_point_size(true);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |pipeline, mut shd_gate| {
+          let bound_sprite = pipeline.bind_texture(sprite)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set_texture(&uni.tex, &bound_sprite);
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(points))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("point sprites render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    let mut covered = 0;
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let (x, y) = (i % SIZE, i / SIZE);
+      let expected = POINTS
+        .iter()
+        .find_map(|&([px, py], [r, g, b])| {
+          let half = SPRITE_SIZE / 2;
+          let inside = (px - half..px + half).contains(&x) && (py - half..py + half).contains(&y);
+
+          // the sprite is symmetric, so the orientation of gl_PointCoord doesn’t matter
+          if inside && in_disc(x + half - px, y + half - py) {
+            Some([r, g, b, 255])
+          } else {
+            None
+          }
+        })
+        .unwrap_or([0, 0, 0, 255]);
+
+      if expected != [0, 0, 0, 255] {
+        covered += 1;
+      }
+
+      assert_eq!(texel, expected, "pixel ({}, {})", x, y);
+    }
+
+    log::info!("{} pixels covered by the sprites", covered);
+    assert!(covered > 0);
+
+    LoopFeedback::Exit
+  }
+}
+
+// whether the center of the sprite texel (x, y) is in the disc inscribed in the sprite
+fn in_disc(x: usize, y: usize) -> bool {
+  let half = SPRITE_SIZE as f32 * 0.5;
+  let dx = x as f32 + 0.5 - half;
+  let dy = y as f32 + 0.5 - half;
+
+  dx * dx + dy * dy < half * half
+}
@@ -0,0 +1,176 @@
+//! Compile a program from its sources, dump its binary and reload it in a fresh program, then check that both render
+//! the same image. Also check that a rejected binary can fall back to compiling the sources again.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{Program, ProgramError, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+
+const FS: &str = "
+in vec3 v_color;
+
+out vec4 frag;
+
+uniform float intensity;
+
+void main() {
+  frag = vec4(v_color * intensity, 1.);
+}";
+
+const TRI_VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([0.5, -0.5]),
+    rgb: VertexColor::new([0., 1., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0.0, 0.5]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-0.5, -0.5]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  intensity: Uniform<f32>,
+}
+
+pub struct LocalExample {
+  source_program: Program<Semantics, (), ShaderInterface>,
+  binary_program: Program<Semantics, (), ShaderInterface>,
+  triangle: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let source_program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation from sources")
+      .ignore_warnings();
+
+    let (format, binary) = source_program
+      .get_binary()
+      .expect("program binary retrieval");
+    log::info!(
+      "program binary: format {:#x}, {} bytes",
+      format,
+      binary.len()
+    );
+
+    let binary_program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_binary(format, &binary)
+      .expect("program creation from binary")
+      .ignore_warnings();
+
+    // a garbage binary must be rejected, and we can still fall back to the sources
+    let rejected = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_binary(format, &[0xde, 0xad, 0xbe, 0xef]);
+    assert!(matches!(rejected, Err(ProgramError::BinaryRejected(_))));
+
+    rejected
+      .or_else(|_| {
+        context
+          .new_shader_program::<Semantics, (), ShaderInterface>()
+          .from_strings(VS, None, None, FS)
+      })
+      .expect("fallback program creation from sources");
+
+    let triangle = context
+      .new_tess()
+      .set_vertices(&TRI_VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([16, 16], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      source_program,
+      binary_program,
+      triangle,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let from_source = self.render(context, false);
+    let from_binary = self.render(context, true);
+
+    assert!(
+      from_source.iter().any(|&texel| texel != 0 && texel != 255),
+      "the triangle should have been rendered"
+    );
+    assert_eq!(from_source, from_binary);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the triangle with one of the programs and read back the framebuffer.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    from_binary: bool,
+  ) -> Vec<u8> {
+    let program = if from_binary {
+      &mut self.binary_program
+    } else {
+      &mut self.source_program
+    };
+    let triangle = &self.triangle;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.intensity, 0.5);
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(triangle)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
@@ -0,0 +1,155 @@
+//! Render a quad at a known distance from the viewer with both a perspective and an orthographic projection, and check
+//! that the linearized depth readback matches that distance.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{Depth32F, DepthProjection},
+  render_state::RenderState,
+  shader::{types::Mat44, Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+use std::f32::consts::FRAC_PI_2;
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-10., -10.),
+  vec2( 10., -10.),
+  vec2( 10.,  10.),
+  vec2(-10.,  10.)
+);
+
+uniform mat4 projection;
+uniform float distance;
+
+void main() {
+  gl_Position = projection * vec4(POSITIONS[gl_VertexID], -distance, 1.);
+}";
+
+const FS: &str = "
+void main() {
+}";
+
+const NEAR: f32 = 1.;
+const FAR: f32 = 10.;
+const DISTANCE: f32 = 5.;
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  distance: Uniform<f32>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, (), Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, (), Depth32F>([8, 8], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let perspective = Mat44::<f32>::from_perspective(FRAC_PI_2, 1., NEAR, FAR);
+    let orthographic = Mat44([
+      [0.1, 0., 0., 0.],
+      [0., 0.1, 0., 0.],
+      [0., 0., -2. / (FAR - NEAR), 0.],
+      [0., 0., -(FAR + NEAR) / (FAR - NEAR), 1.],
+    ]);
+
+    for (projection, kind) in [
+      (perspective, DepthProjection::Perspective),
+      (orthographic, DepthProjection::Orthographic),
+    ] {
+      self.render_quad(context, projection);
+
+      let depths = self
+        .framebuffer
+        .read_depth_linear([2, 2], [4, 4], NEAR, FAR, kind)
+        .expect("depth readback");
+      log::info!("{:?} linearized depths: {:?}", kind, depths);
+
+      assert_eq!(depths.len(), 16);
+      for depth in depths {
+        assert!(
+          (depth - DISTANCE).abs() < 1e-2,
+          "{:?}: expected {}, got {}",
+          kind,
+          DISTANCE,
+          depth
+        );
+      }
+    }
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  fn render_quad(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    projection: Mat44<f32>,
+  ) {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.projection, projection);
+            iface.set(&uni.distance, DISTANCE);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("depth render");
+  }
+}
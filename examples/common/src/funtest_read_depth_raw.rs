@@ -0,0 +1,170 @@
+//! Render a quad at a known window-space depth into framebuffers with various depth formats, and check that the raw
+//! depth readback yields exactly the expected normalized integers. The stencil buffer is cleared to a non-zero value
+//! to ensure stencil bits don’t leak into the depth values of packed depth/stencil formats.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::{backend::depth_stencil_slot::DepthStencilSlot, UniformInterface};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{quantize_depth, Depth24, Depth24Stencil8, Depth32F, NormDepthPixel},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+uniform float depth;
+
+void main() {
+  // map the window-space depth back to NDC
+  gl_Position = vec4(POSITIONS[gl_VertexID], depth * 2. - 1., 1.);
+}";
+
+const FS: &str = "
+void main() {
+}";
+
+// exactly representable, both in window space and in NDC
+const DEPTH: f32 = 0.25;
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  depth: Uniform<f32>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  depth24: Framebuffer<Dim2, (), Depth24>,
+  depth24_stencil8: Framebuffer<Dim2, (), Depth24Stencil8>,
+  depth32f: Framebuffer<Dim2, (), Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .expect("quad");
+
+    let depth24 = context
+      .new_framebuffer::<Dim2, (), Depth24>([8, 8], 0, Sampler::default())
+      .expect("depth24 framebuffer");
+    let depth24_stencil8 = context
+      .new_framebuffer::<Dim2, (), Depth24Stencil8>([8, 8], 0, Sampler::default())
+      .expect("depth24-stencil8 framebuffer");
+    let depth32f = context
+      .new_framebuffer::<Dim2, (), Depth32F>([8, 8], 0, Sampler::default())
+      .expect("depth32f framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      depth24,
+      depth24_stencil8,
+      depth32f,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    render_quad(context, &mut self.program, &self.quad, &self.depth24);
+    let depths = self
+      .depth24
+      .read_depth_raw([2, 2], [4, 4])
+      .expect("depth24 readback");
+    check_depths::<Depth24>(&depths);
+
+    render_quad(
+      context,
+      &mut self.program,
+      &self.quad,
+      &self.depth24_stencil8,
+    );
+    let depths = self
+      .depth24_stencil8
+      .read_depth_raw([2, 2], [4, 4])
+      .expect("depth24-stencil8 readback");
+    check_depths::<Depth24Stencil8>(&depths);
+
+    render_quad(context, &mut self.program, &self.quad, &self.depth32f);
+    let depths = self
+      .depth32f
+      .read_depth_raw([2, 2], [4, 4])
+      .expect("depth32f readback");
+    check_depths::<Depth32F>(&depths);
+
+    LoopFeedback::Exit
+  }
+}
+
+fn render_quad<DS>(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  program: &mut Program<(), (), ShaderInterface>,
+  quad: &Tess<()>,
+  framebuffer: &Framebuffer<Dim2, (), DS>,
+) where
+  DS: DepthStencilSlot<Backend, Dim2>,
+{
+  let pipeline_st = PipelineState::default().set_clear_stencil(0xff);
+
+  context
+    .new_pipeline_gate()
+    .pipeline(framebuffer, &pipeline_st, |_, mut shd_gate| {
+      shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+        iface.set(&uni.depth, DEPTH);
+
+        rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+          tess_gate.render(quad)
+        })
+      })
+    })
+    .assume()
+    .into_result()
+    .expect("depth render");
+}
+
+fn check_depths<P>(depths: &[u32])
+where
+  P: NormDepthPixel,
+{
+  let expected = quantize_depth(DEPTH, P::DEPTH_BITS);
+  log::info!(
+    "{}-bit depths: {:?} (expected {})",
+    P::DEPTH_BITS,
+    depths,
+    expected
+  );
+
+  assert_eq!(depths.len(), 16);
+  for &depth in depths {
+    assert_eq!(depth, expected, "{}-bit depth", P::DEPTH_BITS);
+  }
+}
@@ -0,0 +1,204 @@
+//! Render a depth prepass with the bundled depth-only program, then a color pass testing depth with
+//! `Comparison::Equal`. Two overlapping quads are rendered back to front, so that every pixel covered by both would be
+//! shaded twice without the prepass. The color pass accumulates a constant color additively: every pixel must have been
+//! shaded exactly once — i.e. no overdraw — and only the nearest quad must be visible.
+
+use crate::{
+  shared::{Semantics, VertexPosition3},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::{UniformInterface, Vertex};
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  depth_stencil::{Comparison, Write},
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{Depth32F, NormRGBA8UI},
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3},
+    DepthOnlyInterface, Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// the color pass must compute the exact same positions as the depth-only program for the depth test to pass
+const VS: &str = "
+in vec3 co3;
+
+uniform mat4 transform;
+
+void main() {
+  gl_Position = transform * vec4(co3, 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec3 color;
+
+void main() {
+  frag = vec4(color, 1.);
+}";
+
+const SIZE: usize = 16;
+
+// color accumulated by each shaded fragment; the back quad is red and the front quad is green
+const BACK_COLOR: [f32; 3] = [0.25, 0., 0.];
+const FRONT_COLOR: [f32; 3] = [0., 0.25, 0.];
+
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+struct DepthVertex {
+  pos: VertexPosition3,
+}
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  transform: Uniform<Mat44<f32>>,
+  color: Uniform<Vec3<f32>>,
+}
+
+pub struct LocalExample {
+  depth_program: Program<Semantics, (), DepthOnlyInterface>,
+  color_program: Program<Semantics, (), ShaderInterface>,
+  // the back quad covers the whole framebuffer; the front quad only covers its left half
+  back: Tess<DepthVertex>,
+  front: Tess<DepthVertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let depth_program =
+      Program::<Semantics, (), DepthOnlyInterface>::depth_only(context, Semantics::Position3)
+        .expect("depth-only program creation")
+        .ignore_warnings();
+
+    let color_program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("color program creation")
+      .ignore_warnings();
+
+    let back = quad(context, [-1., 1.], 0.5);
+    let front = quad(context, [-1., 0.], -0.5);
+
+    let framebuffer = context
+      .new_framebuffer([SIZE as u32, SIZE as u32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      depth_program,
+      color_program,
+      back,
+      front,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let depth_program = &mut self.depth_program;
+    let color_program = &mut self.color_program;
+    let back = &self.back;
+    let front = &self.front;
+    let transform = Mat44::<f32>::from_translation(Vec3::new(0., 0., 0.125));
+
+    let color_st = RenderState::default()
+      .set_depth_test(Comparison::Equal)
+      .set_depth_write(Write::Off)
+      .set_blending(Blending {
+        equation: Equation::Additive,
+        src: Factor::One,
+        dst: Factor::One,
+      });
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(depth_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.transform, transform);
+
+            rdr_gate.render(&RenderState::depth_prepass(), |mut tess_gate| {
+              tess_gate.render(back)?;
+              tess_gate.render(front)
+            })
+          })?;
+
+          shd_gate.shade(color_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.transform, transform);
+
+            for (tess, color) in [(back, BACK_COLOR), (front, FRONT_COLOR)] {
+              iface.set(&uni.color, Vec3::from(color));
+              rdr_gate.render(&color_st, |mut tess_gate| tess_gate.render(tess))?;
+            }
+
+            Ok(())
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("depth prepass render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let (x, y) = (i % SIZE, i / SIZE);
+
+      // a single 0.25 contribution; two would be 128
+      let expected = if x < SIZE / 2 {
+        [0, 64, 0, 255]
+      } else {
+        [64, 0, 0, 255]
+      };
+
+      assert_eq!(texel, expected, "pixel ({}, {})", x, y);
+    }
+
+    LoopFeedback::Exit
+  }
+}
+
+// a quad spanning the whole framebuffer vertically and [x_min; x_max] horizontally, at depth z
+fn quad(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  [x_min, x_max]: [f32; 2],
+  z: f32,
+) -> Tess<DepthVertex> {
+  let vertices = [
+    [x_min, -1., z],
+    [x_max, -1., z],
+    [x_max, 1., z],
+    [x_min, 1., z],
+  ]
+  .map(|pos| DepthVertex {
+    pos: VertexPosition3::new(pos),
+  });
+
+  context
+    .new_tess()
+    .set_vertices(&vertices[..])
+    .set_mode(Mode::TriangleFan)
+    .build()
+    .expect("quad")
+}
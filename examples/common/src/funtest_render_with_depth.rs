@@ -0,0 +1,168 @@
+//! Render two overlapping quads in a depth prepass, then again in a color pass overriding the depth comparison with
+//! `Equal`. Each color fragment adds the same amount of red, so any overdraw would show as twice that amount where
+//! the quads overlap. Once the color pass is done, the base comparison — `Less` — must be restored, which is read
+//! back from the graphics API directly: rendering with the base state again would re-specify the comparison anyway.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  depth_stencil::{Comparison, Write},
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{Depth32F, NormRGBA8UI},
+  render_state::RenderState,
+  shader::{
+    types::{Vec2, Vec4},
+    Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// a quad spanning the whole height, between x_range.x and x_range.y horizontally
+const VS: &str = "
+uniform vec2 x_range;
+uniform float z;
+
+void main() {
+  float x = (gl_VertexID & 1) == 0 ? x_range.x : x_range.y;
+  float y = gl_VertexID < 2 ? -1. : 1.;
+  gl_Position = vec4(x, y, z, 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec4 color;
+
+void main() {
+  frag = color;
+}";
+
+// the near quad covers the first three texels, the far one the last three
+const QUADS: [([f32; 2], f32); 2] = [([-1., 0.5], 0.2), ([-0.5, 1.], 0.6)];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  x_range: Uniform<Vec2<f32>>,
+  z: Uniform<f32>,
+  color: Uniform<Vec4<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleStrip)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth32F>([4, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    // every pass adds its color; the prepass color is black, so it only fills the depth buffer
+    let additive = RenderState::default().set_blending(Blending {
+      equation: Equation::Additive,
+      src: Factor::One,
+      dst: Factor::One,
+    });
+    let prepass_st = additive.clone().set_depth_test(Comparison::Less);
+    let color_st = additive
+      .set_depth_test(Comparison::Less)
+      .set_depth_write(Write::Off);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.color, Vec4::new(0., 0., 0., 0.));
+
+            for &(x_range, z) in &QUADS {
+              iface.set(&uni.x_range, Vec2::new(x_range[0], x_range[1]));
+              iface.set(&uni.z, z);
+
+              rdr_gate.render(&prepass_st, |mut tess_gate| tess_gate.render(quad))?;
+            }
+
+            iface.set(&uni.color, Vec4::new(0.25, 0., 0., 0.));
+
+            for &(x_range, z) in &QUADS {
+              iface.set(&uni.x_range, Vec2::new(x_range[0], x_range[1]));
+              iface.set(&uni.z, z);
+
+              rdr_gate.render_with_depth(Comparison::Equal, &color_st, |mut tess_gate| {
+                tess_gate.render(quad)
+              })?;
+            }
+
+            Ok(())
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("depth passes");
+
+    let snapshot = *context.state_guard().snapshot();
+    assert!(snapshot.depth_test);
+    assert_eq!(snapshot.depth_test_comparison, Comparison::Less);
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let red = texel[0];
+      assert!(
+        (63..=65).contains(&red),
+        "texel {}: red is {}, expected a single contribution",
+        i,
+        red
+      );
+    }
+
+    LoopFeedback::Exit
+  }
+}
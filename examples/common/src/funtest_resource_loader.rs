@@ -0,0 +1,154 @@
+//! Generate texels on a worker thread and send them to a resource loader. The texture is uploaded on the main thread by
+//! processing the pending resources every frame, then sampled to check that it holds the generated texels.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::{
+    resource_loader::{ResourceId, ResourceLoader, TextureData},
+    GraphicsContext,
+  },
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+use std::thread::{self, JoinHandle};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+uniform sampler2D tex;
+
+void main() {
+  frag = texelFetch(tex, ivec2(1, 1), 0);
+}";
+
+// number of frames to wait for the texture before giving up
+const MAX_FRAMES: usize = 100;
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  tex: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  loader: ResourceLoader<TextureData<Dim2, NormRGBA8UI>>,
+  worker: Option<JoinHandle<ResourceId>>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+  frames: usize,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let loader = ResourceLoader::new();
+    let sender = loader.sender();
+
+    // each texel encodes its own coordinates
+    let worker = thread::spawn(move || {
+      let texels = (0..4)
+        .flat_map(|y| (0..4).map(move |x| [x * 64, y * 64, 0, 255]))
+        .collect();
+
+      sender
+        .send(TextureData::new([4, 4], Sampler::default(), texels))
+        .expect("send texture data")
+    });
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      loader,
+      worker: Some(worker),
+      framebuffer,
+      frames: 0,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    self.frames += 1;
+    assert!(self.frames <= MAX_FRAMES, "texture never loaded");
+
+    let mut loaded = self.loader.process_pending(context);
+    let texture = match loaded.pop() {
+      Some(texture) => texture,
+      None => return LoopFeedback::Continue(self),
+    };
+
+    let id = self
+      .worker
+      .take()
+      .expect("worker")
+      .join()
+      .expect("worker thread");
+    assert_eq!(texture.id, id);
+
+    let mut texture = texture.result.expect("texture upload");
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let texture = pipeline.bind_texture(&mut texture)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set_texture(&uni.tex, &texture);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    assert_eq!(texels, [64, 64, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
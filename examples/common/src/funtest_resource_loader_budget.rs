@@ -0,0 +1,83 @@
+//! Send several textures to a resource loader with a small upload budget and check that they are uploaded over several
+//! calls to `process_pending` rather than all at once. A texture larger than the whole budget must still be uploaded,
+//! on its own, and flagged as over budget.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::{
+    resource_loader::{ResourceLoader, TextureData},
+    GraphicsContext,
+  },
+  framebuffer::Framebuffer,
+  pixel::NormRGBA8UI,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// enough for two 2×2 RGBA8 textures (16 bytes each) per call, but not for a 4×4 one (64 bytes)
+const BUDGET: usize = 40;
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let mut loader = ResourceLoader::new();
+    loader.set_budget(BUDGET);
+
+    let sender = loader.sender();
+    for size in [2, 2, 2, 2, 4] {
+      let texels = vec![[255, 0, 0, 255]; size * size];
+      sender
+        .send(TextureData::<Dim2, NormRGBA8UI>::new(
+          [size as u32, size as u32],
+          Sampler::default(),
+          texels,
+        ))
+        .expect("send texture data");
+    }
+
+    // for each call, the sizes of the uploaded textures and whether they were over budget
+    let mut calls = Vec::new();
+    loop {
+      let loaded = loader.process_pending(context);
+
+      if loaded.is_empty() {
+        break;
+      }
+
+      let textures = loaded
+        .into_iter()
+        .map(|resource| {
+          let texture = resource.result.expect("texture upload");
+          (texture.size(), resource.over_budget)
+        })
+        .collect::<Vec<_>>();
+      calls.push(textures);
+    }
+
+    assert_eq!(
+      calls,
+      [
+        vec![([2, 2], false), ([2, 2], false)],
+        vec![([2, 2], false), ([2, 2], false)],
+        vec![([4, 4], true)],
+      ]
+    );
+    assert_eq!(loader.pending_nb(), 0);
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
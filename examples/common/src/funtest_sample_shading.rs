@@ -0,0 +1,201 @@
+//! Render a checker pattern of half a pixel into a 4× multisample texture, with and without sample shading, resolve it
+//! by averaging the samples and check that sample shading reduces aliasing.
+//!
+//! Without sample shading, the fragment shader runs once per pixel, at its center, so every sample of a pixel gets the
+//! same color and the resolved texels are either black or white. With sample shading at `1.`, it runs once per sample,
+//! at the sample location, so the resolved texels blend both colors and get closer to grey.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Floating, RGBA32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Dim2Multisample, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const CHECKER_FS: &str = "
+out vec4 frag;
+
+void main() {
+  ivec2 cell = ivec2(floor(gl_FragCoord.xy * 2.));
+  float c = float((cell.x + cell.y) % 2);
+  frag = vec4(c, c, c, 1.);
+}";
+
+const RESOLVE_FS: &str = "
+out vec4 frag;
+
+uniform sampler2DMS source_texture;
+uniform int samples;
+
+void main() {
+  ivec2 coord = ivec2(gl_FragCoord.xy);
+  vec4 color = vec4(0.);
+
+  for (int i = 0; i < samples; ++i) {
+    color += texelFetch(source_texture, coord, i);
+  }
+
+  frag = color / float(samples);
+}";
+
+const SAMPLES: usize = 4;
+const SIZE: u32 = 8;
+
+#[derive(UniformInterface)]
+struct ResolveInterface {
+  #[uniform(name = "source_texture")]
+  texture: Uniform<TextureBinding<Dim2Multisample<SAMPLES>, Floating>>,
+  samples: Uniform<i32>,
+}
+
+pub struct LocalExample {
+  checker_program: Program<(), (), ()>,
+  resolve_program: Program<(), (), ResolveInterface>,
+  quad: Tess<()>,
+  multisample_framebuffer: Framebuffer<Dim2Multisample<SAMPLES>, RGBA32F, ()>,
+  resolved_framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let checker_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, CHECKER_FS)
+      .expect("checker program creation")
+      .ignore_warnings();
+
+    let resolve_program = context
+      .new_shader_program::<(), (), ResolveInterface>()
+      .from_strings(VS, None, None, RESOLVE_FS)
+      .expect("resolve program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let multisample_framebuffer = context
+      .new_framebuffer::<Dim2Multisample<SAMPLES>, RGBA32F, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("multisample framebuffer");
+
+    let resolved_framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("resolved framebuffer");
+
+    LocalExample {
+      checker_program,
+      resolve_program,
+      quad,
+      multisample_framebuffer,
+      resolved_framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context.set_error_checking(true);
+    let _ = context.take_backend_errors();
+
+    let aliased = self.aliasing(context, &RenderState::default());
+    let shaded = self.aliasing(context, &RenderState::default().set_min_sample_shading(1.));
+
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected backend errors: {:?}", errors);
+
+    log::info!(
+      "aliasing without sample shading: {}, with sample shading: {}",
+      aliased,
+      shaded
+    );
+
+    // without sample shading, texels are either black or white
+    assert_eq!(aliased, 0.5);
+    assert!(
+      shaded < aliased,
+      "sample shading should reduce aliasing ({} ≥ {})",
+      shaded,
+      aliased
+    );
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the checker with the given render state, resolve it and return the mean distance of the resolved texels to
+  /// grey — the perfectly anti-aliased color of the checker.
+  fn aliasing(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    checker_st: &RenderState,
+  ) -> f32 {
+    let checker_program = &mut self.checker_program;
+    let resolve_program = &mut self.resolve_program;
+    let quad = &self.quad;
+    let multisample_framebuffer = &mut self.multisample_framebuffer;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+    let mut builder = context.new_pipeline_gate();
+
+    builder
+      .pipeline(multisample_framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(checker_program, |_, _, mut rdr_gate| {
+          rdr_gate.render(checker_st, |mut tess_gate| tess_gate.render(quad))
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("checker render");
+
+    builder
+      .pipeline(
+        &self.resolved_framebuffer,
+        &pipeline_st,
+        |pipeline, mut shd_gate| {
+          let bound_texture = pipeline.bind_texture(multisample_framebuffer.color_slot())?;
+
+          shd_gate.shade(resolve_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.texture, bound_texture.binding());
+            iface.set(&uni.samples, SAMPLES as i32);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("resolve render");
+
+    let texels = self
+      .resolved_framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("resolved texels");
+    let reds = texels.chunks(4).map(|t| t[0]).collect::<Vec<_>>();
+
+    log::debug!("resolved red channel: {:?}", reds);
+
+    reds.iter().map(|r| (r - 0.5).abs()).sum::<f32>() / reds.len() as f32
+  }
+}
@@ -0,0 +1,230 @@
+//! Bind three shader data (time, transform and color) to the same shader and check that the rendered result depends
+//! on all three of them. Then, bind as many shader data as possible and check that exceeding the backend limit fails
+//! with a clear error instead of silently aliasing bindings.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineError, PipelineState, ShaderDataBinding},
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3, Vec4},
+    Program, ShaderData, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2( 1.,  1.),
+  vec2(-1.,  1.)
+);
+
+uniform Transform {
+  mat4 m[1];
+} transform;
+
+void main() {
+  gl_Position = transform.m[0] * vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform Time {
+  float t[1];
+} time;
+
+uniform Color {
+  vec4 c[1];
+} color;
+
+void main() {
+  frag = vec4(color.c[0].rgb * time.t[0], 1.);
+}";
+
+const SIZE: u32 = 4;
+
+// upper bound on the number of shader data to bind before giving up on hitting the backend limit
+const MAX_TRIED_BINDINGS: usize = 1024;
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  #[uniform(name = "Time")]
+  time: Uniform<ShaderDataBinding<f32>>,
+  #[uniform(name = "Transform")]
+  transform: Uniform<ShaderDataBinding<Mat44<f32>>>,
+  #[uniform(name = "Color")]
+  color: Uniform<ShaderDataBinding<Vec4<f32>>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+  time: ShaderData<f32>,
+  transform: ShaderData<Mat44<f32>>,
+  color: ShaderData<Vec4<f32>>,
+  extra: Vec<ShaderData<f32>>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_mode(Mode::TriangleFan)
+      .set_render_vertex_nb(4)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([SIZE, SIZE], 0, Sampler::default())
+      .expect("framebuffer");
+
+    let time = context.new_shader_data([0.8]).expect("time shader data");
+
+    // move the quad to the right so that it covers the right half of the framebuffer only
+    let transform = context
+      .new_shader_data([Mat44::<f32>::from_translation(Vec3::new(1., 0., 0.))])
+      .expect("transform shader data");
+
+    let color = context
+      .new_shader_data([Vec4::new(1., 0.5, 0., 1.)])
+      .expect("color shader data");
+
+    let extra = (0..MAX_TRIED_BINDINGS)
+      .map(|_| context.new_shader_data([0.]).expect("extra shader data"))
+      .collect();
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+      time,
+      transform,
+      color,
+      extra,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let time = &mut self.time;
+    let transform = &mut self.transform;
+    let color = &mut self.color;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |pipeline, mut shd_gate| {
+          let time = pipeline.bind_shader_data(time)?;
+          let transform = pipeline.bind_shader_data(transform)?;
+          let color = pipeline.bind_shader_data(color)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.time, time.binding());
+            iface.set(&uni.transform, transform.binding());
+            iface.set(&uni.color, color.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render with multiple shader data");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let x = i as u32 % SIZE;
+      let expected: [u8; 4] = if x < SIZE / 2 {
+        [0, 0, 0, 255]
+      } else {
+        // 255 * (1, 0.5, 0) * 0.8
+        [204, 102, 0, 255]
+      };
+
+      assert_eq!(texel, expected, "texel {} ({}, {})", i, x, i as u32 / SIZE);
+    }
+
+    // bind shader data until the backend refuses to
+    let extra = &mut self.extra;
+    let mut bound_nb = 0;
+    let mut error = None;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, _| {
+          let mut bound = Vec::new();
+
+          for shader_data in extra.iter_mut() {
+            match pipeline.bind_shader_data(shader_data) {
+              Ok(b) => bound.push(b),
+              Err(e) => {
+                error = Some(e);
+                break;
+              }
+            }
+          }
+
+          bound_nb = bound.len();
+          Ok(())
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("binding limit pipeline");
+
+    log::info!("bound {} shader data before failing", bound_nb);
+
+    match error {
+      Some(PipelineError::TooManyShaderDataBindings { max }) => {
+        assert_eq!(bound_nb, max as usize, "bound shader data");
+      }
+
+      Some(e) => panic!("unexpected pipeline error: {}", e),
+
+      None => log::warn!(
+        "backend accepted {} shader data bindings; limit not reached",
+        MAX_TRIED_BINDINGS
+      ),
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,132 @@
+//! Build a program whose fragment shader includes a shared function from a set of virtual files, render with it and
+//! check the output color. Then build a program including a file that doesn’t exist and check that the error points at
+//! the `#include` directive.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{preprocessor::IncludeError, Program, ProgramError, StageError, StageType},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+use std::collections::HashMap;
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const COLOR: &str = "
+vec4 shared_color() {
+  return vec4(.2, .4, .6, 1.);
+}";
+
+const FS: &str = "out vec4 frag;
+#include \"color.glsl\"
+
+void main() {
+  frag = shared_color();
+}";
+
+const MISSING_FS: &str = "out vec4 frag;
+#include \"missing.glsl\"
+
+void main() {
+  frag = vec4(1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let files: HashMap<_, _> = [("color.glsl".to_owned(), COLOR.to_owned())]
+      .into_iter()
+      .collect();
+
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings_with_includes(VS, None, None, FS, &files)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let missing = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings_with_includes(VS, None, None, MISSING_FS, &files)
+      .err()
+      .expect("missing include");
+    log::info!("missing include: {}", missing);
+
+    assert_eq!(
+      missing,
+      ProgramError::StageError(StageError::include_failed(
+        StageType::FragmentShader,
+        IncludeError::not_found("missing.glsl", "fragment shader", 2)
+      ))
+    );
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    // the color returned by the included function
+    assert_eq!(texels, [51, 102, 153, 255]);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,176 @@
+//! Build a 3-sprite batch, check its vertex and index counts, and render it side by side into a framebuffer. Each sprite
+//! has constant texture coordinates, output as color by the shader, so that every sprite can be told apart. The batch
+//! is then updated with fewer sprites, which must reuse its storage and leave the last slot empty, and with more
+//! sprites, which must grow it.
+
+use crate::{
+  shared::Semantics,
+  sprite_batch::{Sprite, SpriteBatch},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+in vec2 co;
+in vec2 uv;
+
+out vec2 v_uv;
+
+void main() {
+  gl_Position = vec4(co, 0., 1.);
+  v_uv = uv;
+}";
+
+const FS: &str = "
+in vec2 v_uv;
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(v_uv, 0., 1.);
+}";
+
+// each sprite covers two columns of the framebuffer
+const WIDTH: usize = 6;
+const HEIGHT: usize = 2;
+
+// red, green and yellow
+const COLORS: [[f32; 2]; 3] = [[1., 0.], [0., 1.], [1., 1.]];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  batch: SpriteBatch,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let batch = SpriteBatch::new(context, &sprites(3)).expect("sprite batch");
+    assert_eq!(batch.len(), 3);
+    assert_eq!(batch.tess().vert_nb(), 12);
+    assert_eq!(batch.tess().idx_nb(), 18);
+
+    let framebuffer = context
+      .new_framebuffer([WIDTH as u32, HEIGHT as u32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      batch,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    self.check_render(context, 3);
+
+    // fewer sprites reuse the storage
+    let update_count = self.batch.tess().update_count();
+    self.batch.update(context, &sprites(2)).expect("shrink");
+    assert_eq!(self.batch.capacity(), 3);
+    assert_eq!(self.batch.tess().update_count(), update_count + 1);
+    self.check_render(context, 2);
+
+    // more sprites than the capacity reallocate the storage
+    self.batch.update(context, &sprites(3)).expect("regrow");
+    self
+      .batch
+      .update(context, &sprites(1))
+      .expect("shrink again");
+    let mut grown = sprites(3);
+    grown.push(grown[0]);
+    self.batch.update(context, &grown).expect("grow");
+    assert_eq!(self.batch.capacity(), 6);
+    assert_eq!(self.batch.tess().vert_nb(), 24);
+    assert_eq!(self.batch.tess().idx_nb(), 36);
+    self.check_render(context, 3);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the batch and check that the first `visible` sprites — and only them — are visible.
+  fn check_render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    visible: usize,
+  ) {
+    let program = &mut self.program;
+    let batch = &self.batch;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(batch.view())
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("sprite batch render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let (x, y) = (i % WIDTH, i / WIDTH);
+      let sprite = x / 2;
+      let expected = if sprite < visible {
+        let [r, g] = COLORS[sprite];
+        [(r * 255.) as u8, (g * 255.) as u8, 0, 255]
+      } else {
+        [0, 0, 0, 255]
+      };
+
+      assert_eq!(texel, expected, "pixel ({}, {})", x, y);
+    }
+  }
+}
+
+// the first n sprites, each one covering a third of the framebuffer horizontally and all of it vertically
+fn sprites(n: usize) -> Vec<Sprite> {
+  COLORS
+    .iter()
+    .take(n)
+    .enumerate()
+    .map(|(i, &uv)| Sprite {
+      position: [-1. + 2. * i as f32 / 3., -1.],
+      size: [2. / 3., 2.],
+      uv: [uv, uv],
+    })
+    .collect()
+}
@@ -0,0 +1,135 @@
+//! Render inside a state guard with a render state differing from the context one — blending, depth test and
+//! comparison, depth write, scissor and a smaller viewport — then check that the render state read back from the
+//! context once the guard is dropped is the one saved before rendering.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  depth_stencil::{Comparison, Write},
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::{Depth32F, NormRGBA8UI},
+  render_state::RenderState,
+  scissor::ScissorRegion,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 1., -1.),
+  vec2(-1.,  1.),
+  vec2( 1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(.5, .5, .5, .5);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth32F>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleStrip)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth32F>([3, 5], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let before = *context.state_guard().snapshot();
+    log::info!("state before rendering: {:#?}", before);
+
+    let rdr_st = RenderState::default()
+      .set_blending_separate(
+        Blending {
+          equation: Equation::Subtract,
+          src: Factor::SrcAlpha,
+          dst: Factor::DestColor,
+        },
+        Blending {
+          equation: Equation::Max,
+          src: Factor::One,
+          dst: Factor::One,
+        },
+      )
+      .set_depth_test(Comparison::GreaterOrEqual)
+      .set_depth_write(Write::Off)
+      .set_scissor(ScissorRegion {
+        x: 1,
+        y: 1,
+        width: 1,
+        height: 2,
+      });
+
+    {
+      let program = &mut self.program;
+      let quad = &self.quad;
+      let mut guard = context.state_guard();
+
+      guard
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default(),
+          |_, mut shd_gate| {
+            shd_gate.shade(program, |_, _, mut rdr_gate| {
+              rdr_gate.render(&rdr_st, |mut tess_gate| tess_gate.render(quad))
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("render in guard");
+    }
+
+    let after = *context.state_guard().snapshot();
+    log::info!("state after rendering: {:#?}", after);
+
+    assert_eq!(before, after, "render state not restored by the guard");
+
+    LoopFeedback::Exit
+  }
+}
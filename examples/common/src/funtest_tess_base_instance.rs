@@ -0,0 +1,167 @@
+//! Render the instances `[2, 5)` out of 5 instances with a base instance. Each instance covers the pixel at the
+//! column of its index in the instance buffer, so only the last three pixels must be covered.
+//!
+//! If the backend doesn’t support base instances, the render must fail with [`TessError::UnsupportedBaseInstance`]
+//! and nothing must be rendered.
+
+use crate::{
+  shared::{
+    Instance, Semantics, Vertex, VertexColor, VertexInstancePosition, VertexPosition, VertexWeight,
+  },
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineError, PipelineState},
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess, TessError, TessView},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const INSTANCES_NB: usize = 5;
+const BASE_INSTANCE: usize = 2;
+
+const VS: &str = "
+in vec2 co;
+in vec3 color;
+in vec2 position;
+in float weight;
+
+out vec3 v_color;
+
+void main() {
+  gl_Position = vec4(co + position, 0., 1.);
+  v_color = color * weight;
+}";
+
+const FS: &str = "
+in vec3 v_color;
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(v_color, 1.);
+}";
+
+// a thin triangle covering the center of a single pixel once moved by an instance
+const TRI_VERTICES: [Vertex; 3] = [
+  Vertex::new(
+    VertexPosition::new([-0.1, -1.]),
+    VertexColor::new([1., 0., 0.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.1, -1.]),
+    VertexColor::new([1., 0., 0.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0., 3.]),
+    VertexColor::new([1., 0., 0.]),
+  ),
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  triangles: Tess<Vertex, (), Instance>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    // instance i covers the pixel at column i, with a red channel of (i + 1) * 51
+    let instances = (0..INSTANCES_NB)
+      .map(|i| {
+        Instance::new(
+          VertexInstancePosition::new([-1. + (2. * i as f32 + 1.) / INSTANCES_NB as f32, 0.]),
+          VertexWeight::new((i + 1) as f32 / INSTANCES_NB as f32),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let triangles = context
+      .new_tess()
+      .set_vertices(&TRI_VERTICES[..])
+      .set_instances(instances)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangles");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([INSTANCES_NB as u32, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangles,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let view = TessView::inst_whole(&self.triangles, INSTANCES_NB - BASE_INSTANCE)
+      .with_base_instance(BASE_INSTANCE);
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(view)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result();
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    log::info!("texels: {:?}", texels);
+
+    if let Err(PipelineError::Tess(TessError::UnsupportedBaseInstance(base_instance))) = render {
+      log::warn!("base instances are not supported");
+      assert_eq!(base_instance, BASE_INSTANCE);
+      assert!(texels.chunks(4).all(|texel| texel == [0, 0, 0, 255]));
+      return LoopFeedback::Exit;
+    }
+
+    render.expect("base instance render");
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      let red = if i < BASE_INSTANCE {
+        0
+      } else {
+        (i as u8 + 1) * 51
+      };
+
+      assert_eq!(texel, [red, 0, 0, 255], "instance {}", i);
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,145 @@
+//! Render the normals of a cube with the debug shaders of the backend, without any user shader drawing anything, and
+//! check that lines were emitted: the cube is seen from the front, so its side normals must be rendered as lines
+//! sticking out of the cube.
+
+use crate::{
+  shared::{cube, CubeVertex, Semantics, VertexIndex},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  tess_gate::DebugMode,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+in vec3 co3;
+
+void main() {
+  gl_Position = vec4(co3, 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(0.);
+}";
+
+const SIZE: u32 = 64;
+const IDENTITY: [[f32; 4]; 4] = [
+  [1., 0., 0., 0.],
+  [0., 1., 0., 0.],
+  [0., 0., 1., 0.],
+  [0., 0., 0., 1.],
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  cube: Tess<CubeVertex, VertexIndex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let (vertices, indices) = cube(1.);
+    let cube = context
+      .new_tess()
+      .set_vertices(&vertices[..])
+      .set_indices(&indices[..])
+      .set_mode(Mode::TriangleStrip)
+      .set_primitive_restart_index(VertexIndex::MAX)
+      .build()
+      .expect("cube");
+
+    let framebuffer = context
+      .new_framebuffer([SIZE, SIZE], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      cube,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let cube = &self.cube;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 0.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render_debug(
+              cube,
+              DebugMode::Normals {
+                position: Semantics::Position3,
+                normal: Semantics::Normal,
+                length: 0.4,
+              },
+              IDENTITY,
+            )
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    // normalized device coordinates of the lit texels
+    let lit = texels
+      .chunks(4)
+      .enumerate()
+      .filter(|(_, texel)| texel.iter().any(|&c| c != 0))
+      .map(|(i, _)| {
+        let to_ndc = |v: u32| (v as f32 + 0.5) / SIZE as f32 * 2. - 1.;
+        (to_ndc(i as u32 % SIZE), to_ndc(i as u32 / SIZE))
+      })
+      .collect::<Vec<_>>();
+
+    log::info!("{} texels lit by the normals", lit.len());
+
+    // the side normals go from the faces of the cube (at ±0.5) to ±0.9
+    assert!(
+      lit.iter().any(|&(x, y)| x.abs() > 0.6 || y.abs() > 0.6),
+      "normals should have been rendered as lines out of the cube"
+    );
+    assert!(
+      lit.iter().all(|&(x, y)| x.abs() < 0.95 && y.abs() < 0.95),
+      "normals should not be longer than requested"
+    );
+
+    LoopFeedback::Exit
+  }
+}
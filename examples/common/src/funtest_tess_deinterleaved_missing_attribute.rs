@@ -0,0 +1,64 @@
+//! Build deinterleaved tessellations providing the positions but forgetting the colors, both directly and with indices,
+//! and check that building fails with the rank of the color stream instead of rendering garbage.
+
+use crate::{
+  shared::{Vertex, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{Mode, TessError},
+  texture::Dim2,
+  Backend,
+};
+
+const POSITIONS: [VertexPosition; 3] = [
+  VertexPosition::new([-0.5, -0.5]),
+  VertexPosition::new([0.5, -0.5]),
+  VertexPosition::new([0., 0.5]),
+];
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let direct = context
+      .new_deinterleaved_tess::<Vertex, ()>()
+      .set_attributes(&POSITIONS[..])
+      .set_mode(Mode::Triangle)
+      .build();
+    assert!(
+      matches!(direct, Err(TessError::MissingAttribute(1))),
+      "direct tessellation: {:?}",
+      direct.err()
+    );
+
+    let indexed = context
+      .new_deinterleaved_tess::<Vertex, ()>()
+      .set_attributes(&POSITIONS[..])
+      .set_indices([0u8, 1, 2])
+      .set_mode(Mode::Triangle)
+      .build();
+    assert!(
+      matches!(indexed, Err(TessError::MissingAttribute(1))),
+      "indexed tessellation: {:?}",
+      indexed.err()
+    );
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,124 @@
+//! Render a 3×3 grid of attributeless vertices covering the whole framebuffer, with the indices generated by
+//! `set_grid_indices`, both as strips and as a triangle list. Back faces are culled, so every texel must be covered
+//! by a counter-clockwise triangle.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  face_culling::FaceCulling,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::Tess,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const COLS: usize = 3;
+const ROWS: usize = 3;
+
+// the vertex at column x and row y is the (y * COLS + x)-th one
+const VS: &str = "
+const int COLS = 3;
+const int ROWS = 3;
+
+void main() {
+  vec2 p = vec2(gl_VertexID % COLS, gl_VertexID / COLS) / vec2(COLS - 1, ROWS - 1);
+  gl_Position = vec4(p * 2. - 1., 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 0., 0., 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  strip: Tess<(), u32>,
+  triangles: Tess<(), u32>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let strip = context
+      .new_tess()
+      .set_grid_indices(COLS, ROWS, true)
+      .build()
+      .expect("grid strip");
+
+    let triangles = context
+      .new_tess()
+      .set_grid_indices(COLS, ROWS, false)
+      .build()
+      .expect("grid triangles");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      strip,
+      triangles,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    // two triangles per quad, 2×2 quads
+    assert_eq!(self.strip.primitive_count(), 8);
+    assert_eq!(self.triangles.primitive_count(), 8);
+
+    for (name, grid) in [("strip", &self.strip), ("triangles", &self.triangles)] {
+      let program = &mut self.program;
+
+      context
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+          |_, mut shd_gate| {
+            shd_gate.shade(program, |_, _, mut rdr_gate| {
+              let render_st = RenderState::default().set_face_culling(FaceCulling::default());
+              rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(grid))
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("grid render");
+
+      let texels = self
+        .framebuffer
+        .color_slot()
+        .get_raw_texels()
+        .expect("texels");
+
+      for (i, texel) in texels.chunks(4).enumerate() {
+        assert_eq!(texel, [255, 0, 0, 255], "{}: texel {}", name, i);
+      }
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,146 @@
+//! Render only the second submesh of an indexed tessellation holding two submeshes via an index-range view, and read
+//! back its color.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess, TessView, TessViewError},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+// two triangles covering the whole viewport; the first one is red, the second one is blue
+const VERTICES: [Vertex; 6] = [
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([3., -1.]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 3.]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., -1.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([3., -1.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-1., 3.]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+];
+
+// first submesh is [0..3), second submesh is [3..6)
+const INDICES: [u16; 6] = [0, 1, 2, 3, 4, 5];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  submeshes: Tess<Vertex, u16>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let submeshes = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_indices(&INDICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("submeshes");
+
+    // index ranges are only allowed on indexed tessellations and must fit the index buffer
+    let direct = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("direct tessellation");
+    assert_eq!(
+      TessView::index_slice(&direct, 0, 3).err(),
+      Some(TessViewError::MissingIndices)
+    );
+    assert!(TessView::index_slice(&submeshes, 3, 4).is_err());
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      submeshes,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let second_submesh = TessView::index_slice(&self.submeshes, 3, 3).expect("index slice");
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(second_submesh)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    for texel in texels.chunks(4) {
+      assert_eq!(
+        texel,
+        [0u8, 0, 255, 255],
+        "only the blue submesh should be rendered"
+      );
+    }
+
+    LoopFeedback::Exit
+  }
+}
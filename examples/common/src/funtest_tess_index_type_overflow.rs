@@ -0,0 +1,103 @@
+//! Build a `u16`-indexed tessellation with 70001 vertices: the vertex at index 70000 cannot be addressed by `u16`
+//! indices, so the build must fail instead of leaving it silently unreachable. The same vertices with `u32` indices,
+//! and exactly 65536 vertices with `u16` indices, must build fine — unless `u16::MAX` is the primitive restart index,
+//! which leaves only 65535 addressable vertices.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{DynamicTess, Mode, TessError, TessIndexType},
+  texture::Dim2,
+  Backend,
+};
+
+const VERTEX: Vertex = Vertex {
+  pos: VertexPosition::new([0., 0.]),
+  rgb: VertexColor::new([1., 1., 1.]),
+};
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let vertices = vec![VERTEX; 70001];
+
+    let tess = context
+      .new_tess()
+      .set_vertices(&vertices[..])
+      .set_indices(&[0u16, 1, 2][..])
+      .set_mode(Mode::Triangle)
+      .build();
+    assert_eq!(
+      tess.err(),
+      Some(TessError::index_type_overflow(
+        TessIndexType::U16,
+        70001,
+        65536
+      ))
+    );
+
+    context
+      .new_tess()
+      .set_vertices(&vertices[..])
+      .set_indices(&[0u32, 1, 70000][..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("u32-indexed tess");
+
+    context
+      .new_tess()
+      .set_vertices(&vertices[..65536])
+      .set_indices(&[0u16, 1, 65535][..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("u16-indexed tess");
+
+    let tess = context
+      .new_tess()
+      .set_vertices(&vertices[..65536])
+      .set_indices(&[0u16, 1, 2][..])
+      .set_mode(Mode::TriangleStrip)
+      .set_primitive_restart_index(u16::MAX)
+      .build();
+    assert_eq!(
+      tess.err(),
+      Some(TessError::index_type_overflow(
+        TessIndexType::U16,
+        65536,
+        65535
+      ))
+    );
+
+    // the same guard applies to dynamic tessellations
+    let mut dynamic =
+      DynamicTess::<Vertex, u16>::new(context, Mode::Triangle).expect("dynamic tess");
+    assert_eq!(
+      dynamic.set_data(&vertices, &[0, 1, 2]),
+      Err(TessError::index_type_overflow(
+        TessIndexType::U16,
+        70001,
+        65536
+      ))
+    );
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,151 @@
+//! Build an interleaved tessellation from separate position and color arrays with `set_interleaved_from` and check
+//! that it renders exactly like a hand-interleaved reference. Mismatched lengths must be rejected.
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess, TessError},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+const POSITIONS: [[f32; 2]; 4] = [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]];
+const COLORS: [[f32; 3]; 4] = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.], [1., 1., 1.]];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  zipped: Tess<Vertex>,
+  reference: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let zipped = context
+      .new_tess()
+      .set_interleaved_from(&POSITIONS, &COLORS, |pos, rgb| Vertex {
+        pos: VertexPosition::new(pos),
+        rgb: VertexColor::new(rgb),
+      })
+      .expect("zipped vertices")
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("zipped quad");
+
+    let reference_vertices = [
+      Vertex {
+        pos: VertexPosition::new(POSITIONS[0]),
+        rgb: VertexColor::new(COLORS[0]),
+      },
+      Vertex {
+        pos: VertexPosition::new(POSITIONS[1]),
+        rgb: VertexColor::new(COLORS[1]),
+      },
+      Vertex {
+        pos: VertexPosition::new(POSITIONS[2]),
+        rgb: VertexColor::new(COLORS[2]),
+      },
+      Vertex {
+        pos: VertexPosition::new(POSITIONS[3]),
+        rgb: VertexColor::new(COLORS[3]),
+      },
+    ];
+    let reference = context
+      .new_tess()
+      .set_vertices(&reference_vertices[..])
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("reference quad");
+
+    // attribute sets must have the same length
+    let mismatched =
+      context
+        .new_tess()
+        .set_interleaved_from(&POSITIONS, &COLORS[..3], |pos, rgb| Vertex {
+          pos: VertexPosition::new(pos),
+          rgb: VertexColor::new(rgb),
+        });
+    assert!(matches!(mismatched, Err(TessError::LengthIncoherency(4))));
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([8, 8], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      zipped,
+      reference,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let zipped = self.render(context, false);
+    let reference = self.render(context, true);
+    assert_eq!(zipped, reference);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render either the zipped or the reference quad and read back the resulting texels.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    reference: bool,
+  ) -> Vec<u8> {
+    let program = &mut self.program;
+    let quad = if reference {
+      &self.reference
+    } else {
+      &self.zipped
+    };
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
@@ -0,0 +1,179 @@
+//! Render a quad which `[u8; 4]` color attribute is built normalized, once as built, once with normalization
+//! overridden at render time and once again as built, and check that the override only applies to its own render.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::{vertex::Semantics as _, Semantics, Vertex};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::RGBA32F,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Interleaved, Mode, Tess, TessView},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+in vec2 co;
+in vec4 color;
+
+out vec4 v_color;
+
+void main() {
+  gl_Position = vec4(co, 0., 1.);
+  v_color = color;
+}";
+
+const FS: &str = "
+in vec4 v_color;
+
+out vec4 frag;
+
+void main() {
+  frag = v_color;
+}";
+
+const COLOR: [u8; 4] = [255, 128, 0, 64];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+pub enum Semantics {
+  #[sem(name = "co", repr = "[f32; 2]", wrapper = "VertexPosition")]
+  Position,
+  #[sem(name = "color", repr = "[u8; 4]", wrapper = "VertexColor")]
+  Color,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+struct ColorVertex {
+  pos: VertexPosition,
+  #[vertex(normalized = "true")]
+  color: VertexColor,
+}
+
+const VERTICES: [ColorVertex; 4] = [
+  ColorVertex {
+    pos: VertexPosition::new([-1., -1.]),
+    color: VertexColor::new(COLOR),
+  },
+  ColorVertex {
+    pos: VertexPosition::new([1., -1.]),
+    color: VertexColor::new(COLOR),
+  },
+  ColorVertex {
+    pos: VertexPosition::new([1., 1.]),
+    color: VertexColor::new(COLOR),
+  },
+  ColorVertex {
+    pos: VertexPosition::new([-1., 1.]),
+    color: VertexColor::new(COLOR),
+  },
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  quad: Tess<ColorVertex>,
+  framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([2, 2], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let normalized = COLOR.map(|c| c as f32 / 255.);
+    let raw = COLOR.map(|c| c as f32);
+
+    let program = &mut self.program;
+    let framebuffer = &mut self.framebuffer;
+
+    let texels = render(context, program, framebuffer, TessView::whole(&self.quad));
+    check_texels("built normalized", &texels, normalized);
+
+    let view = TessView::whole(&self.quad).with_normalized_attrib(Semantics::Color.index(), false);
+    let texels = render(context, program, framebuffer, view);
+    check_texels("overridden raw", &texels, raw);
+
+    // the override must not leak into later renders
+    let texels = render(context, program, framebuffer, TessView::whole(&self.quad));
+    check_texels("restored normalized", &texels, normalized);
+
+    LoopFeedback::Exit
+  }
+}
+
+fn render(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  program: &mut Program<Semantics, (), ()>,
+  framebuffer: &mut Framebuffer<Dim2, RGBA32F, ()>,
+  view: TessView<ColorVertex, (), (), Interleaved>,
+) -> Vec<f32> {
+  context
+    .new_pipeline_gate()
+    .pipeline(
+      &*framebuffer,
+      &PipelineState::default(),
+      |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(view)
+          })
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .expect("render");
+
+  framebuffer.color_slot().get_raw_texels().expect("texels")
+}
+
+fn check_texels(label: &str, texels: &[f32], expected: [f32; 4]) {
+  log::info!("{} texels: {:?}", label, texels);
+
+  for texel in texels.chunks(4) {
+    for (&x, &y) in texel.iter().zip(&expected) {
+      assert!(
+        (x - y).abs() < 1e-3,
+        "{}: {:?} (expected {:?})",
+        label,
+        texel,
+        expected
+      );
+    }
+  }
+}
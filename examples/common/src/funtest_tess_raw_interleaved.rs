@@ -0,0 +1,203 @@
+//! Import a raw interleaved vertex buffer with a non-trivial stride (position, padding, color, padding) and check it
+//! renders exactly like the same vertices built from a [`Vertex`] type. Attributes that don’t fit in their stride and
+//! buffers too short for the announced number of vertices must be rejected.
+//!
+//! [`Vertex`]: luminance::vertex::Vertex
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, RawAttribDesc, RawInterleaved, Tess, TessError},
+  texture::{Dim2, Sampler},
+  vertex::{Semantics as _, VertexAttribDesc, VertexAttribDim, VertexAttribType},
+  Backend,
+};
+
+const VS: &str = include_str!("simple-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+const POSITIONS: [[f32; 2]; 4] = [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]];
+const COLORS: [[f32; 3]; 4] = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.], [1., 1., 1.]];
+
+// position (8 bytes), padding (4 bytes), color (12 bytes), padding (8 bytes)
+const STRIDE: usize = 32;
+const POSITION_OFFSET: usize = 0;
+const COLOR_OFFSET: usize = 12;
+
+fn raw_attributes() -> [RawAttribDesc; 2] {
+  [
+    RawAttribDesc {
+      index: Semantics::Position.index(),
+      attrib_desc: VertexAttribDesc {
+        ty: VertexAttribType::Floating,
+        dim: VertexAttribDim::Dim2,
+        unit_size: 4,
+        align: 4,
+      },
+      stride: STRIDE,
+      offset: POSITION_OFFSET,
+    },
+    RawAttribDesc {
+      index: Semantics::Color.index(),
+      attrib_desc: VertexAttribDesc {
+        ty: VertexAttribType::Floating,
+        dim: VertexAttribDim::Dim3,
+        unit_size: 4,
+        align: 4,
+      },
+      stride: STRIDE,
+      offset: COLOR_OFFSET,
+    },
+  ]
+}
+
+// pack the vertices the way an external format would
+fn raw_bytes() -> Vec<u8> {
+  let mut bytes = vec![0; STRIDE * POSITIONS.len()];
+
+  for (i, (pos, rgb)) in POSITIONS.iter().zip(&COLORS).enumerate() {
+    let vertex = &mut bytes[i * STRIDE..];
+
+    for (k, x) in pos.iter().enumerate() {
+      let off = POSITION_OFFSET + k * 4;
+      vertex[off..off + 4].copy_from_slice(&x.to_ne_bytes());
+    }
+
+    for (k, x) in rgb.iter().enumerate() {
+      let off = COLOR_OFFSET + k * 4;
+      vertex[off..off + 4].copy_from_slice(&x.to_ne_bytes());
+    }
+  }
+
+  bytes
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  raw: Tess<(), (), (), RawInterleaved>,
+  reference: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let raw = context
+      .new_tess()
+      .set_raw_interleaved(raw_bytes(), &raw_attributes(), POSITIONS.len())
+      .expect("raw vertices")
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("raw quad");
+
+    let reference_vertices = POSITIONS
+      .iter()
+      .zip(&COLORS)
+      .map(|(&pos, &rgb)| Vertex {
+        pos: VertexPosition::new(pos),
+        rgb: VertexColor::new(rgb),
+      })
+      .collect::<Vec<_>>();
+    let reference = context
+      .new_tess()
+      .set_vertices(reference_vertices)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("reference quad");
+
+    // the color doesn’t fit in a 16-byte stride
+    let mut narrow = raw_attributes();
+    for attribute in &mut narrow {
+      attribute.stride = 16;
+    }
+    assert!(matches!(
+      context
+        .new_tess()
+        .set_raw_interleaved(raw_bytes(), &narrow, POSITIONS.len()),
+      Err(TessError::AttributeOutOfStride(index)) if index == Semantics::Color.index()
+    ));
+
+    // not enough bytes for an extra vertex
+    assert!(matches!(
+      context
+        .new_tess()
+        .set_raw_interleaved(raw_bytes(), &raw_attributes(), POSITIONS.len() + 1),
+      Err(TessError::LengthIncoherency(5))
+    ));
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([8, 8], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      raw,
+      reference,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let raw = &self.raw;
+    let reference = &self.reference;
+    let framebuffer = &mut self.framebuffer;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(raw)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("raw render");
+
+    let raw_texels = framebuffer.color_slot().get_raw_texels().expect("texels");
+
+    context
+      .new_pipeline_gate()
+      .pipeline(framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(reference)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("reference render");
+
+    let reference_texels = framebuffer.color_slot().get_raw_texels().expect("texels");
+
+    assert_eq!(raw_texels, reference_texels);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,118 @@
+//! Render an attributeless triangle 10 times with `render_repeated`. Each repetition is offset and colored with its
+//! instance index, so the 10 repetitions must end up in 10 distinct pixels.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const REPETITIONS: usize = 10;
+
+// each repetition covers the center of the pixel at the column of its instance index
+const VS: &str = "
+const vec2[3] TRIANGLE = vec2[](vec2(-.1, -1.), vec2(.1, -1.), vec2(0., 3.));
+
+flat out int v_instance;
+
+void main() {
+  float x = -1. + (2. * float(gl_InstanceID) + 1.) / 10.;
+
+  gl_Position = vec4(TRIANGLE[gl_VertexID] + vec2(x, 0.), 0., 1.);
+  v_instance = gl_InstanceID;
+}";
+
+const FS: &str = "
+flat in int v_instance;
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(float((v_instance + 1) * 20) / 255., 0., 0., 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  triangle: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_render_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([REPETITIONS as u32, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangle,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              // rendering zero times must not render anything
+              tess_gate.render_repeated(triangle, 0)?;
+              tess_gate.render_repeated(triangle, REPETITIONS)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("repeated render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    log::info!("texels: {:?}", texels);
+
+    for (i, texel) in texels.chunks(4).enumerate() {
+      assert_eq!(texel, [(i as u8 + 1) * 20, 0, 0, 255], "repetition {}", i);
+    }
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,82 @@
+//! Build indexed tessellations with a primitive restart index: the build must fail with `Mode::Triangle` and
+//! `Mode::Point`, which cannot be restarted, and succeed with strips and fans.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{Mode, TessError},
+  texture::Dim2,
+  Backend,
+};
+
+const VERTICES: [Vertex; 4] = [
+  Vertex {
+    pos: VertexPosition::new([-0.5, -0.5]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0.5, -0.5]),
+    rgb: VertexColor::new([0., 1., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0.5, 0.5]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-0.5, 0.5]),
+    rgb: VertexColor::new([1., 1., 1.]),
+  },
+];
+
+const INDICES: [u32; 7] = [0, 1, 2, u32::MAX, 2, 3, 0];
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    for mode in [Mode::Triangle, Mode::Point] {
+      let tess = context
+        .new_tess()
+        .set_vertices(&VERTICES[..])
+        .set_indices(&INDICES[..])
+        .set_primitive_restart_index(u32::MAX)
+        .set_mode(mode)
+        .build();
+
+      assert_eq!(
+        tess.err(),
+        Some(TessError::primitive_restart_unsupported(mode))
+      );
+    }
+
+    for mode in [Mode::TriangleStrip, Mode::TriangleFan, Mode::LineStrip] {
+      context
+        .new_tess()
+        .set_vertices(&VERTICES[..])
+        .set_indices(&INDICES[..])
+        .set_primitive_restart_index(u32::MAX)
+        .set_mode(mode)
+        .build()
+        .expect("restartable tess");
+    }
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,191 @@
+//! Convert the indexed, instanced hello-world triangles from interleaved to deinterleaved storage with
+//! `to_deinterleaved` and check that the converted tessellation keeps its indices and instances and renders exactly
+//! like the interleaved one.
+
+use crate::{
+  shared::{
+    Instance, Semantics, Vertex, VertexColor, VertexInstancePosition, VertexPosition, VertexWeight,
+  },
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::tess::Deinterleaved;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("instancing-vs.glsl");
+const FS: &str = include_str!("instancing-fs.glsl");
+
+const TRI_VERTICES: [Vertex; 6] = [
+  Vertex::new(
+    VertexPosition::new([0.5, -0.5]),
+    VertexColor::new([0., 1., 0.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.0, 0.5]),
+    VertexColor::new([0., 0., 1.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([-0.5, -0.5]),
+    VertexColor::new([1., 0., 0.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([-0.5, 0.5]),
+    VertexColor::new([1., 0.2, 1.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.0, -0.5]),
+    VertexColor::new([0.2, 1., 1.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.5, 0.5]),
+    VertexColor::new([0.2, 0.2, 1.]),
+  ),
+];
+
+const TRI_INDICES: [u8; 6] = [0, 1, 2, 3, 4, 5];
+
+const INSTANCES: [Instance; 2] = [
+  Instance::new(
+    VertexInstancePosition::new([-0.5, 0.]),
+    VertexWeight::new(0.5),
+  ),
+  Instance::new(
+    VertexInstancePosition::new([0.5, 0.]),
+    VertexWeight::new(0.75),
+  ),
+];
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ()>,
+  interleaved: Tess<Vertex, u8, Instance>,
+  deinterleaved: Tess<Vertex, u8, Instance, Deinterleaved>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let mut interleaved = context
+      .new_tess()
+      .set_vertices(&TRI_VERTICES[..])
+      .set_indices(&TRI_INDICES[..])
+      .set_instances(&INSTANCES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("interleaved triangles");
+
+    let mut deinterleaved = interleaved
+      .to_deinterleaved(context)
+      .expect("deinterleaved triangles");
+
+    assert_eq!(deinterleaved.vert_nb(), interleaved.vert_nb());
+    assert_eq!(deinterleaved.idx_nb(), interleaved.idx_nb());
+    assert_eq!(deinterleaved.inst_nb(), interleaved.inst_nb());
+    assert_eq!(
+      &deinterleaved.indices().expect("indices")[..],
+      &TRI_INDICES[..]
+    );
+
+    // each field now lives in its own buffer
+    let positions = deinterleaved
+      .vertices::<VertexPosition>()
+      .expect("positions")
+      .to_vec();
+    let colors = deinterleaved
+      .vertices::<VertexColor>()
+      .expect("colors")
+      .to_vec();
+    let weights = deinterleaved
+      .instances::<VertexWeight>()
+      .expect("weights")
+      .to_vec();
+    assert_eq!(
+      positions,
+      TRI_VERTICES.iter().map(|v| v.pos).collect::<Vec<_>>()
+    );
+    assert_eq!(
+      colors,
+      TRI_VERTICES.iter().map(|v| v.rgb).collect::<Vec<_>>()
+    );
+    assert_eq!(weights, INSTANCES.iter().map(|w| w.w).collect::<Vec<_>>());
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([32, 32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      interleaved,
+      deinterleaved,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let interleaved = self.render(context, false);
+    let deinterleaved = self.render(context, true);
+    assert_eq!(interleaved, deinterleaved);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render either the interleaved or the deinterleaved triangles and read back the resulting texels.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    deinterleaved: bool,
+  ) -> Vec<u8> {
+    let program = &mut self.program;
+    let interleaved_tess = &self.interleaved;
+    let deinterleaved_tess = &self.deinterleaved;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            if deinterleaved {
+              tess_gate.render(deinterleaved_tess)
+            } else {
+              tess_gate.render(interleaved_tess)
+            }
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
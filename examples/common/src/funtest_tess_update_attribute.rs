@@ -0,0 +1,84 @@
+//! Build a deinterleaved tessellation with positions and colors, update only its positions with `update_attribute` and
+//! check that the positions changed while the colors stayed untouched. Updating an attribute with the wrong number of
+//! values must fail without changing anything.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::tess::{Deinterleaved, TessMapError};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const POSITIONS: [VertexPosition; 3] = [
+  VertexPosition::new([0.5, -0.5]),
+  VertexPosition::new([0.0, 0.5]),
+  VertexPosition::new([-0.5, -0.5]),
+];
+
+const COLORS: [VertexColor; 3] = [
+  VertexColor::new([0., 1., 0.]),
+  VertexColor::new([0., 0., 1.]),
+  VertexColor::new([1., 0., 0.]),
+];
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let mut tess: Tess<Vertex, (), (), Deinterleaved> = context
+      .new_deinterleaved_tess::<Vertex, ()>()
+      .set_attributes(&POSITIONS[..])
+      .set_attributes(&COLORS[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("deinterleaved triangle");
+
+    // move the triangle, as a simulation would do every frame
+    let moved = POSITIONS
+      .iter()
+      .map(|pos| VertexPosition::new([pos[0] + 0.25, pos[1] - 0.25]))
+      .collect::<Vec<_>>();
+
+    tess.update_attribute(&moved).expect("positions update");
+    assert_eq!(tess.update_count(), 1);
+
+    let positions = tess
+      .vertices::<VertexPosition>()
+      .expect("positions")
+      .to_vec();
+    let colors = tess.vertices::<VertexColor>().expect("colors").to_vec();
+    assert_eq!(positions, moved);
+    assert_eq!(colors, COLORS);
+
+    // the storage of an attribute is never resized
+    assert_eq!(
+      tess.update_attribute(&COLORS[..2]),
+      Err(TessMapError::attribute_length_mismatch(3, 2))
+    );
+    assert_eq!(tess.update_count(), 1);
+
+    let colors = tess.vertices::<VertexColor>().expect("colors").to_vec();
+    assert_eq!(colors, COLORS);
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,107 @@
+//! Build tessellations with various usages and check that their usage and number of updates are correctly reported.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{DynamicTess, Mode, Tess, TessUsage},
+  texture::Dim2,
+  Backend,
+};
+
+const VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([-0.5, -0.5]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0.5, -0.5]),
+    rgb: VertexColor::new([0., 1., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0., 0.5]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+];
+
+pub struct LocalExample {
+  static_tess: Tess<Vertex>,
+  dynamic_tess: Tess<Vertex>,
+  stream_tess: DynamicTess<Vertex>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let default_tess = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("default tess");
+    assert_eq!(default_tess.usage(), TessUsage::Stream);
+
+    let static_tess = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .set_usage(TessUsage::Static)
+      .build()
+      .expect("static tess");
+
+    let dynamic_tess = context
+      .new_tess()
+      .set_vertices(&VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .set_usage(TessUsage::Dynamic)
+      .build()
+      .expect("dynamic tess");
+
+    let stream_tess = context
+      .new_dynamic_tess(Mode::Triangle)
+      .expect("stream tess");
+
+    LocalExample {
+      static_tess,
+      dynamic_tess,
+      stream_tess,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    assert_eq!(self.static_tess.usage(), TessUsage::Static);
+    assert_eq!(self.static_tess.update_count(), 0);
+
+    // reading doesn’t count as an update
+    self.static_tess.vertices().expect("vertices");
+    assert_eq!(self.static_tess.update_count(), 0);
+
+    for i in 0..3 {
+      let mut vertices = self.dynamic_tess.vertices_mut().expect("vertices_mut");
+      vertices[0].pos = VertexPosition::new([-0.5 + i as f32 * 0.1, -0.5]);
+    }
+
+    assert_eq!(self.dynamic_tess.usage(), TessUsage::Dynamic);
+    assert_eq!(self.dynamic_tess.update_count(), 3);
+
+    assert_eq!(self.stream_tess.usage(), TessUsage::Stream);
+    assert_eq!(self.stream_tess.update_count(), 0);
+
+    self.stream_tess.set_data(&VERTICES, &[]).expect("set_data");
+    self.stream_tess.set_data(&VERTICES, &[]).expect("set_data");
+    assert_eq!(self.stream_tess.update_count(), 2);
+
+    LoopFeedback::Exit
+  }
+}
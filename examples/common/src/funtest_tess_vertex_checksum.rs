@@ -0,0 +1,125 @@
+//! Build the same procedural mesh twice, both interleaved and deinterleaved, and check that the vertex checksums are
+//! equal, then change a single vertex and check that the checksum changes.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  tess::{Deinterleaved, Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VERTICES_NB: usize = 64;
+
+// a ring of vertices which colors depend on their angle
+fn ring() -> Vec<Vertex> {
+  (0..VERTICES_NB)
+    .map(|i| {
+      let a = i as f32 / VERTICES_NB as f32 * std::f32::consts::TAU;
+
+      Vertex::new(
+        VertexPosition::new([a.cos() * 0.5, a.sin() * 0.5]),
+        VertexColor::new([a.cos() * 0.5 + 0.5, a.sin() * 0.5 + 0.5, 1.]),
+      )
+    })
+    .collect()
+}
+
+fn interleaved_ring(context: &mut impl GraphicsContext<Backend = Backend>) -> Tess<Vertex> {
+  context
+    .new_tess()
+    .set_vertices(ring())
+    .set_mode(Mode::LineStrip)
+    .build()
+    .expect("interleaved ring")
+}
+
+fn deinterleaved_ring(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+) -> Tess<Vertex, (), (), Deinterleaved> {
+  let (positions, colors): (Vec<_>, Vec<_>) = ring().into_iter().map(|v| (v.pos, v.rgb)).unzip();
+
+  context
+    .new_deinterleaved_tess::<Vertex, ()>()
+    .set_attributes(positions)
+    .set_attributes(colors)
+    .set_mode(Mode::LineStrip)
+    .build()
+    .expect("deinterleaved ring")
+}
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    // interleaved
+    let mut a = interleaved_ring(context);
+    let mut b = interleaved_ring(context);
+    let checksum = a.vertex_checksum().expect("checksum");
+    assert_eq!(b.vertex_checksum().expect("checksum"), checksum);
+
+    let original = {
+      let mut vertices = b.vertices_mut().expect("vertices");
+      let original = vertices[VERTICES_NB / 2];
+      vertices[VERTICES_NB / 2].rgb = VertexColor::new([1., 0., 0.]);
+      original
+    };
+    assert_ne!(b.vertex_checksum().expect("checksum"), checksum);
+
+    b.vertices_mut().expect("vertices")[VERTICES_NB / 2] = original;
+    assert_eq!(b.vertex_checksum().expect("checksum"), checksum);
+
+    // deinterleaved; every attribute buffer is hashed
+    let mut a = deinterleaved_ring(context);
+    let mut b = deinterleaved_ring(context);
+    let checksum = a.vertex_checksum().expect("deinterleaved checksum");
+    assert_eq!(
+      b.vertex_checksum().expect("deinterleaved checksum"),
+      checksum
+    );
+
+    b.vertices_mut::<VertexColor>().expect("colors")[VERTICES_NB / 2] =
+      VertexColor::new([1., 0., 0.]);
+    assert_ne!(
+      b.vertex_checksum().expect("deinterleaved checksum"),
+      checksum
+    );
+
+    // attributeless tessellations have no vertex storage
+    let mut a: Tess<()> = context
+      .new_tess()
+      .set_render_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("attributeless tessellation");
+    let mut b: Tess<()> = context
+      .new_tess()
+      .set_render_vertex_nb(6)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("attributeless tessellation");
+    assert_eq!(
+      a.vertex_checksum().expect("attributeless checksum"),
+      b.vertex_checksum().expect("attributeless checksum")
+    );
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
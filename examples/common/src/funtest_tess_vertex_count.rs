@@ -0,0 +1,173 @@
+//! Render a horizontal 10-vertex line strip across a one-pixel-high framebuffer, keeping only its first 5 vertices with
+//! `with_vertex_count`, and check that only the left part of the line is drawn. Asking for more vertices than the
+//! strip holds must draw the whole line.
+//!
+//! The count is clamped to the built vertices, not to the default number of vertices to render: a strip built with 10
+//! vertices but rendering 5 by default must be drawn whole when asking for its 10 vertices.
+
+use crate::{
+  shared::{Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineError, PipelineState},
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess, View as _},
+  tess_gate::TessGate,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+// the vertices are evenly spread over [-1; 1]
+const VS: &str = "
+void main() {
+  gl_Position = vec4(-1. + 2. * float(gl_VertexID) / 9., 0., 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.);
+}";
+
+const WIDTH: usize = 18;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  line: Tess<()>,
+  // 10 vertices, rendering only 5 by default
+  partial_line: Tess<Vertex>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let line = context
+      .new_tess()
+      .set_render_vertex_nb(10)
+      .set_mode(Mode::LineStrip)
+      .build()
+      .expect("line strip");
+
+    // positions are computed from gl_VertexID; the vertex data only gives the strip its built vertices
+    let vertices = [Vertex::new(
+      VertexPosition::new([0., 0.]),
+      VertexColor::new([1., 1., 1.]),
+    ); 10];
+    let partial_line = context
+      .new_tess()
+      .set_vertices(&vertices[..])
+      .set_render_vertex_nb(5)
+      .set_mode(Mode::LineStrip)
+      .build()
+      .expect("partial line strip");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([WIDTH as u32, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      line,
+      partial_line,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let line = &self.line;
+    let partial_line = &self.partial_line;
+
+    // the fifth vertex lands on the left edge of the pixel at WIDTH / 2 - 1; pixels around line ends are not checked
+    let drawn = render(
+      context,
+      &mut self.program,
+      &mut self.framebuffer,
+      |mut tess_gate| tess_gate.render(line.view(..).expect("line view").with_vertex_count(5)),
+    );
+    assert!(
+      drawn[1..WIDTH / 2 - 2].iter().all(|&lit| lit),
+      "{:?}",
+      drawn
+    );
+    assert!(drawn[WIDTH / 2..].iter().all(|&lit| !lit), "{:?}", drawn);
+
+    // clamped to the 10 vertices of the strip
+    let drawn = render(
+      context,
+      &mut self.program,
+      &mut self.framebuffer,
+      |mut tess_gate| tess_gate.render(line.view(..).expect("line view").with_vertex_count(100)),
+    );
+    assert!(drawn[1..WIDTH - 1].iter().all(|&lit| lit), "{:?}", drawn);
+
+    // the 10 built vertices are all drawn, even though only 5 are rendered by default
+    let drawn = render(
+      context,
+      &mut self.program,
+      &mut self.framebuffer,
+      |mut tess_gate| {
+        tess_gate.render(
+          partial_line
+            .view(..)
+            .expect("partial line view")
+            .with_vertex_count(10),
+        )
+      },
+    );
+    assert!(drawn[1..WIDTH - 1].iter().all(|&lit| lit), "{:?}", drawn);
+
+    LoopFeedback::Exit
+  }
+}
+
+/// Render a line with `render_line` and return which pixels got drawn.
+fn render(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  program: &mut Program<(), (), ()>,
+  framebuffer: &mut Framebuffer<Dim2, NormRGBA8UI, ()>,
+  render_line: impl FnOnce(TessGate) -> Result<(), PipelineError>,
+) -> Vec<bool> {
+  context
+    .new_pipeline_gate()
+    .pipeline(
+      framebuffer,
+      &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+      |_, mut shd_gate| {
+        shd_gate.shade(program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), render_line)
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .expect("line strip render");
+
+  framebuffer
+    .color_slot()
+    .get_raw_texels()
+    .expect("texels")
+    .chunks(4)
+    .map(|texel| texel[0] == 255)
+    .collect()
+}
@@ -0,0 +1,145 @@
+//! Upload 256 floats into a texture buffer and fetch the one at index 100 with `texelFetch` in a shader, along with the
+//! size of the texture buffer. Both are written to a floating-point framebuffer and read back. The texels are then
+//! replaced by fewer ones, which must change both the fetched value and the size.
+//!
+//! This test only exists for the OpenGL backend, as WebGL2 has no texture buffers.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::{pixel::Floating, UniformInterface};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBufferBinding},
+  pixel::{R32F, RGBA32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler, TextureBuffer},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[3] CO = vec2[](vec2(-1., -1.), vec2(3., -1.), vec2(-1., 3.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform samplerBuffer values;
+
+void main() {
+  frag = vec4(texelFetch(values, 100).r, float(textureSize(values)), 0., 1.);
+}";
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  values: Uniform<TextureBufferBinding<Floating>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  triangle: Tess<()>,
+  values: TextureBuffer<R32F>,
+  framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_render_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let values = context
+      .new_texture_buffer::<R32F>(&texels(256, 1.))
+      .expect("texture buffer");
+    assert_eq!(values.len(), 256);
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangle,
+      values,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    assert_eq!(self.fetch(context), [50., 256.]);
+
+    self
+      .values
+      .set_texels(&texels(128, -1.))
+      .expect("texture buffer update");
+    assert_eq!(self.values.len(), 128);
+    assert_eq!(self.fetch(context), [-50., 128.]);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Fetch the texel at index 100 and the size of the texture buffer.
+  fn fetch(&mut self, context: &mut impl GraphicsContext<Backend = Backend>) -> [f32; 2] {
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+    let values = &mut self.values;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let bound_values = pipeline.bind_texture_buffer(values)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.values, bound_values.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(triangle)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("texture buffer render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    [texels[0], texels[1]]
+  }
+}
+
+// n texels, which value is their index scaled by sign / 2
+fn texels(n: usize, sign: f32) -> Vec<f32> {
+  (0..n).map(|i| sign * i as f32 * 0.5).collect()
+}
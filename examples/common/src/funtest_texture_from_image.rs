@@ -0,0 +1,149 @@
+//! Create a texture from an image element with `Texture::from_source`, sample it in a shader and check the expected
+//! colors come out. The image is a 2×1 PNG — a red texel on the left, a green one on the right — loaded from a data
+//! URL. Image elements load asynchronously: the texture starts empty and is filled with `Texture::update_from_source`
+//! once the image is loaded.
+//!
+//! This test only exists for the WebGL2 backend.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, Texture},
+  Backend,
+};
+use web_sys::HtmlImageElement;
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+in vec2 v_uv;
+
+out vec4 frag;
+
+uniform sampler2D source_texture;
+
+void main() {
+  frag = texture(source_texture, v_uv);
+}";
+
+const IMAGE_URL: &str = "data:image/png;base64,\
+  iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAYAAAD0In+KAAAADklEQVR4nGP4z8DwHwQBEPgD/U6VwW8AAAAASUVORK5CYII=";
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  source_texture: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  image: HtmlImageElement,
+  texture: Texture<Dim2, NormRGBA8UI>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let image = HtmlImageElement::new().expect("image element");
+    image.set_src(IMAGE_URL);
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+    let texture = Texture::from_source(context, &image, sampler).expect("texture from image");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([2, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      image,
+      texture,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    // wait for the image to be loaded; updating before is a no-op
+    if !self.image.complete() {
+      self
+        .texture
+        .update_from_source(&self.image)
+        .expect("skipped update");
+      assert_eq!(self.texture.size(), [0, 0]);
+
+      return LoopFeedback::Continue(self);
+    }
+
+    self
+      .texture
+      .update_from_source(&self.image)
+      .expect("texture update");
+    assert_eq!(self.texture.size(), [2, 1]);
+
+    let texture = &mut self.texture;
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |pipeline, mut shd_gate| {
+        let bound_texture = pipeline.bind_texture(texture)?;
+
+        shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+          iface.set(&uni.source_texture, bound_texture.binding());
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    assert_eq!(texels, [255, 0, 0, 255, 0, 255, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,147 @@
+//! Upload distinct colors to the first two mipmap levels of a texture, then sample a view on level 1 over a
+//! framebuffer as large as the base level — which would normally sample the base level. The level-1 color must be read
+//! back. Once the view is dropped, sampling the texture must give the base level color again.
+//!
+//! WebGL2 cannot bind a single mipmap level, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+in vec2 v_uv;
+
+out vec4 frag;
+
+uniform sampler2D tex;
+
+void main() {
+  frag = texture(tex, v_uv);
+}";
+
+const LEVEL_0: [u8; 4] = [255, 0, 0, 255];
+const LEVEL_1: [u8; 4] = [0, 255, 0, 255];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  tex: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  texture: Texture<Dim2, NormRGBA8UI>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let level_0 = [LEVEL_0; 4 * 4];
+    let level_1 = [LEVEL_1; 2 * 2];
+    let sampler = Sampler {
+      min_filter: MinFilter::NearestMipmapNearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+    let texture = context
+      .new_texture(
+        [4, 4],
+        sampler,
+        TexelUpload::levels(&[&level_0[..], &level_1[..]]),
+      )
+      .expect("mipmapped texture");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([4, 4], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      texture,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for (use_view, expected) in [(true, LEVEL_1), (false, LEVEL_0)] {
+      let program = &mut self.program;
+      let quad = &self.quad;
+      let texture = &mut self.texture;
+
+      context
+        .new_pipeline_gate()
+        .pipeline(
+          &self.framebuffer,
+          &PipelineState::default(),
+          |pipeline, mut shd_gate| {
+            let mut view;
+            let bound = if use_view {
+              view = texture.mip_view(1).expect("level 1 view");
+              pipeline.bind_texture_view(&mut view)?
+            } else {
+              pipeline.bind_texture(texture)?
+            };
+
+            shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+              iface.set(&uni.tex, bound.binding());
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(quad)
+              })
+            })
+          },
+        )
+        .assume()
+        .into_result()
+        .expect("texture render");
+
+      let texels = self
+        .framebuffer
+        .color_slot()
+        .get_raw_texels()
+        .expect("texels");
+
+      for (i, texel) in texels.chunks(4).enumerate() {
+        assert_eq!(texel, expected, "texel {} (level view: {})", i, use_view);
+      }
+    }
+
+    assert!(self.texture.mip_view(3).is_err());
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,141 @@
+//! Sample a `4×1` texture with the mirror-clamp-to-edge wrap mode at coordinates on both sides of `[0;1]` and check
+//! that negative coordinates are mirrored once before every coordinate gets clamped to the edge. The coordinates are
+//! chosen so that mirrored repeat would read different texels.
+//!
+//! WebGL2 doesn’t support this wrap mode, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Floating, R32F, RGBA32F},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture, Wrap},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+// each fragment samples the texture at its own horizontal coordinate
+const FS: &str = "
+out vec4 frag;
+
+uniform sampler2D values;
+
+const float[6] U = float[](-2.125, -0.875, -0.375, -0.125, 0.625, 1.375);
+
+void main() {
+  frag = vec4(texture(values, vec2(U[int(gl_FragCoord.x)], 0.5)).r, 0., 0., 1.);
+}";
+
+const TEXELS: [f32; 4] = [1., 2., 3., 4.];
+
+// mirrored once around 0 and then clamped, the coordinates above hit these texels
+const EXPECTED: [f32; 6] = [4., 4., 2., 1., 3., 4.];
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  values: Uniform<TextureBinding<Dim2, Floating>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  values: Texture<Dim2, R32F>,
+  framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let sampler = Sampler {
+      wrap_s: Wrap::MirrorClampToEdge,
+      wrap_t: Wrap::MirrorClampToEdge,
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let values = context
+      .new_texture_raw::<Dim2, R32F>(
+        [4, 1],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&TEXELS),
+      )
+      .expect("values texture");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([EXPECTED.len() as u32, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      values,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let values = &mut self.values;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+        |pipeline, mut shd_gate| {
+          let values = pipeline.bind_texture(values)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.values, values.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    let sampled = texels.chunks(4).map(|texel| texel[0]).collect::<Vec<_>>();
+
+    assert_eq!(sampled, EXPECTED);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,90 @@
+//! Upload a 3×3 RGB8 texture — which rows are 9 bytes long — and read it back, checking that the texels are not
+//! garbled by the default row alignment. The same texels are then uploaded and read back with rows padded to a forced
+//! alignment of 4 bytes.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pixel::NormRGB8UI,
+  texture::{Dim2, Sampler, TexelUpload, Texture, TextureError},
+  Backend,
+};
+
+// 3×3 texels, row by row
+const TEXELS: [u8; 27] = [
+  1, 2, 3, 4, 5, 6, 7, 8, 9, //
+  10, 11, 12, 13, 14, 15, 16, 17, 18, //
+  19, 20, 21, 22, 23, 24, 25, 26, 27, //
+];
+
+// the same texels, with rows padded to 4 bytes
+const PADDED_TEXELS: [u8; 36] = [
+  1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, //
+  10, 11, 12, 13, 14, 15, 16, 17, 18, 0, 0, 0, //
+  19, 20, 21, 22, 23, 24, 25, 26, 27, 0, 0, 0, //
+];
+
+pub struct LocalExample;
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let mut texture: Texture<Dim2, NormRGB8UI> = context
+      .new_texture_raw(
+        [3, 3],
+        Sampler::default(),
+        TexelUpload::base_level_without_mipmaps(&TEXELS),
+      )
+      .expect("texture creation");
+
+    let texels = texture.get_raw_texels().expect("texels");
+    assert_eq!(texels, TEXELS);
+
+    context
+      .set_pixel_alignment(Some(4), Some(4))
+      .expect("pixel alignment");
+
+    texture
+      .upload_raw(TexelUpload::base_level_without_mipmaps(&PADDED_TEXELS))
+      .expect("padded texels upload");
+
+    let texels = texture.get_raw_texels().expect("padded texels");
+    assert_eq!(texels.len(), PADDED_TEXELS.len());
+    for (row, padded_row) in texels.chunks(12).zip(PADDED_TEXELS.chunks(12)) {
+      assert_eq!(row[..9], padded_row[..9]);
+    }
+
+    // tightly packed texels are too short for padded rows
+    assert_eq!(
+      texture.upload_raw(TexelUpload::base_level_without_mipmaps(&TEXELS)),
+      Err(TextureError::not_enough_pixels(33, 27))
+    );
+
+    assert_eq!(
+      context.set_pixel_alignment(Some(3), None),
+      Err(TextureError::invalid_pixel_alignment(3))
+    );
+
+    context
+      .set_pixel_alignment(None, None)
+      .expect("default pixel alignment");
+
+    let texels = texture.get_raw_texels().expect("texels");
+    assert_eq!(texels, TEXELS);
+
+    LocalExample
+  }
+
+  fn render_frame(
+    self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    _: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,228 @@
+//! Write a stencil pattern into a `Depth24Stencil8` framebuffer by incrementing the stencil values of the left half
+//! twice and of the whole framebuffer once, then sample its stencil component as `StencilIndex8` values through a
+//! `usampler2D` and read them back from an integer color attachment.
+//!
+//! Sampling stencil components requires OpenGL 4.3 or `GL_ARB_stencil_texturing`; the test is skipped otherwise.
+//! WebGL2 cannot sample them at all, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest},
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{Depth24Stencil8, NormRGBA8UI, Unsigned, R32UI},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler},
+  Backend,
+};
+
+// quad covering the left half of the framebuffer only
+const LEFT_HALF_VS: &str = "
+const vec2[4] POSITIONS = vec2[](
+  vec2(-1., -1.),
+  vec2( 0., -1.),
+  vec2( 0.,  1.),
+  vec2(-1.,  1.)
+);
+
+void main() {
+  gl_Position = vec4(POSITIONS[gl_VertexID], 0., 1.);
+}";
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const WHITE_FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1.);
+}";
+
+// sample the StencilIndex8 values of the stencil view
+const STENCIL_FS: &str = "
+out uint frag;
+
+uniform usampler2D stencil;
+
+void main() {
+  frag = texelFetch(stencil, ivec2(gl_FragCoord.xy), 0).r;
+}";
+
+const SIZE: u32 = 4;
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  stencil: Uniform<TextureBinding<Dim2, Unsigned>>,
+}
+
+pub struct LocalExample {
+  supported: bool,
+  left_half_program: Program<(), (), ()>,
+  fullscreen_program: Program<(), (), ()>,
+  stencil_program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, Depth24Stencil8>,
+  output: Framebuffer<Dim2, R32UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let supported = unsafe {
+      context
+        .backend()
+        .state()
+        .borrow_mut()
+        .supports_stencil_texturing()
+    };
+
+    let left_half_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(LEFT_HALF_VS, None, None, WHITE_FS)
+      .expect("left half program")
+      .ignore_warnings();
+
+    let fullscreen_program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, WHITE_FS)
+      .expect("fullscreen program")
+      .ignore_warnings();
+
+    let stencil_program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, STENCIL_FS)
+      .expect("stencil program")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    // stencil values cannot be filtered
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, Depth24Stencil8>([SIZE, SIZE], 0, sampler)
+      .expect("depth / stencil framebuffer");
+    let output = context
+      .new_framebuffer::<Dim2, R32UI, ()>([SIZE, SIZE], 0, sampler)
+      .expect("output framebuffer");
+
+    LocalExample {
+      supported,
+      left_half_program,
+      fullscreen_program,
+      stencil_program,
+      quad,
+      framebuffer,
+      output,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    if !self.supported {
+      log::warn!("stencil texturing not supported by the running context; skipping");
+      return LoopFeedback::Exit;
+    }
+
+    // write the stencil pattern: the left half ends up at 3 and the right half at 1
+    let left_half_program = &mut self.left_half_program;
+    let fullscreen_program = &mut self.fullscreen_program;
+    let quad = &self.quad;
+    let render_st = RenderState::default()
+      .set_stencil_test(StencilTest::new(Comparison::Always, 0, 0xFF))
+      .set_stencil_operations(
+        StencilOperations::default().on_depth_stencil_pass(StencilOp::Increment),
+      )
+      .set_depth_test(None);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_stencil(0),
+        |_, mut shd_gate| {
+          shd_gate.shade(left_half_program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&render_st, |mut tess_gate| {
+              tess_gate.render(quad)?;
+              tess_gate.render(quad)
+            })
+          })?;
+
+          shd_gate.shade(fullscreen_program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(quad))
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("stencil write");
+
+    // sample the stencil view
+    let stencil_program = &mut self.stencil_program;
+    let depth_stencil = self.framebuffer.depth_stencil_slot();
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.output,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let stencil = pipeline.bind_texture_stencil(depth_stencil)?;
+
+          shd_gate.shade(stencil_program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.stencil, stencil.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("stencil sampling");
+
+    let stencils = self
+      .output
+      .color_slot()
+      .get_raw_texels()
+      .expect("stencil texels");
+    log::info!("sampled stencil values: {:?}", stencils);
+
+    for (i, &stencil) in stencils.iter().enumerate() {
+      let x = i as u32 % SIZE;
+      let expected = if x < SIZE / 2 { 3 } else { 1 };
+
+      assert_eq!(
+        stencil,
+        expected,
+        "stencil {} ({}, {})",
+        i,
+        x,
+        i as u32 / SIZE
+      );
+    }
+
+    LoopFeedback::Exit
+  }
+}
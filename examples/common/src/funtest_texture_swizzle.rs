@@ -0,0 +1,133 @@
+//! Upload a single-channel `R8` texture and sample it with a `RRR1` swizzle, checking that the framebuffer reads back
+//! opaque gray texels without the shader knowing about the remapping.
+//!
+//! WebGL2 doesn’t support texture swizzling, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormR8UI, NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, Swizzle, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+// the shader forwards the sampled texel as-is
+const FS: &str = "
+out vec4 frag;
+
+uniform sampler2D mask;
+
+void main() {
+  frag = texelFetch(mask, ivec2(gl_FragCoord.xy), 0);
+}";
+
+const TEXELS: [u8; 2] = [64, 192];
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  mask: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  mask: Texture<Dim2, NormR8UI>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      swizzle: [Swizzle::Red, Swizzle::Red, Swizzle::Red, Swizzle::One],
+      ..Sampler::default()
+    };
+
+    let mask = context
+      .new_texture_raw::<Dim2, NormR8UI>(
+        [2, 1],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&TEXELS),
+      )
+      .expect("mask texture");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([2, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      mask,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let mask = &mut self.mask;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+        |pipeline, mut shd_gate| {
+          let mask = pipeline.bind_texture(mask)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.mask, mask.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    assert_eq!(texels, [64, 64, 64, 255, 192, 192, 192, 255]);
+
+    LoopFeedback::Exit
+  }
+}
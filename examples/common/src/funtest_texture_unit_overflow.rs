@@ -0,0 +1,127 @@
+//! Bind more textures than there are texture units in a single pipeline and check that binding fails with
+//! [`PipelineError::OutOfTextureUnits`] once all the units are in use, instead of reusing a unit already in use. The
+//! units must be available again in the next pipeline.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineError, PipelineState},
+  pixel::NormRGBA8UI,
+  texture::{Dim2, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+// more than any implementation supports
+const TEXTURE_NB: usize = 1024;
+
+pub struct LocalExample {
+  textures: Vec<Texture<Dim2, NormRGBA8UI>>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let textures = (0..TEXTURE_NB)
+      .map(|_| {
+        context
+          .new_texture_raw::<Dim2, NormRGBA8UI>(
+            [1, 1],
+            Sampler::default(),
+            TexelUpload::base_level_without_mipmaps(&[255, 255, 255, 255]),
+          )
+          .expect("texture")
+      })
+      .collect();
+
+    let framebuffer = context
+      .new_framebuffer([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      textures,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    // bind textures until the backend refuses to
+    let textures = &mut self.textures;
+    let mut units = Vec::new();
+    let mut error = None;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, _| {
+          let mut bound = Vec::new();
+
+          for texture in textures.iter_mut() {
+            match pipeline.bind_texture(texture) {
+              Ok(b) => bound.push(b),
+              Err(e) => {
+                error = Some(e);
+                break;
+              }
+            }
+          }
+
+          units = bound.iter().map(|b| b.binding().binding()).collect();
+          Ok(())
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("texture unit limit pipeline");
+
+    log::info!("bound {} textures before failing", units.len());
+
+    match error {
+      Some(PipelineError::OutOfTextureUnits { max }) => {
+        assert_eq!(units.len(), max as usize, "bound textures");
+      }
+
+      Some(e) => panic!("unexpected pipeline error: {}", e),
+      None => panic!(
+        "{} textures bound without running out of texture units",
+        TEXTURE_NB
+      ),
+    }
+
+    // every bound texture got its own unit
+    let mut distinct = units.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert_eq!(distinct.len(), units.len(), "texture units in use twice");
+
+    // the units are released at the end of the pipeline
+    let texture = &mut self.textures[0];
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |pipeline, _| {
+          pipeline.bind_texture(texture)?;
+          Ok(())
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("texture binding after the limit");
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,127 @@
+//! Capture a transformed position per vertex of an attributeless point tessellation with transform feedback, then
+//! read back the feedback buffer and check it contains the expected data.
+//!
+//! Declaring a feedback varying that doesn’t exist must not fail the link but produce a warning.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  feedback::{FeedbackBuffer, FeedbackMode},
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{Program, ProgramError, ProgramWarning},
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = "
+out vec4 outPosition;
+
+void main() {
+  float i = float(gl_VertexID);
+  outPosition = vec4(i, 2. * i, -i, 1.);
+
+  gl_Position = vec4(0., 0., 0., 1.);
+  gl_PointSize = 1.;
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+void main() {
+  frag = vec4(1., 1., 1., 1.);
+}";
+
+const VERT_NB: usize = 4;
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  points: Tess<()>,
+  feedback: FeedbackBuffer<[f32; 4]>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let built = context
+      .new_shader_program::<(), (), ()>()
+      .with_feedback_varyings(&["outPosition", "outMissing"], FeedbackMode::Interleaved)
+      .from_strings(VS, None, None, FS)
+      .expect("program with a missing feedback varying");
+    log::info!("warnings: {:?}", built.warnings);
+    assert!(built.warnings.iter().any(|w| matches!(
+      w,
+      ProgramError::Warning(ProgramWarning::FeedbackVarying(name)) if name == "outMissing"
+    )));
+
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .with_feedback_varyings(&["outPosition"], FeedbackMode::Interleaved)
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let points = context
+      .new_tess()
+      .set_render_vertex_nb(VERT_NB)
+      .set_mode(Mode::Point)
+      .build()
+      .expect("points");
+
+    let feedback = context
+      .new_feedback_buffer(VERT_NB)
+      .expect("feedback buffer");
+
+    LocalExample {
+      program,
+      points,
+      feedback,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let points = &self.points;
+    let feedback = &mut self.feedback;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render_feedback(points, &mut [&mut *feedback])
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render with transform feedback");
+
+    let captured = self.feedback.read().expect("feedback read back");
+    log::info!("captured: {:?}", captured);
+
+    let expected = (0..VERT_NB)
+      .map(|i| {
+        let i = i as f32;
+        [i, 2. * i, -i, 1.]
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(captured, expected);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,139 @@
+//! Render the lit quad of the `two-sided` example from the front and from the back with [`RenderState::two_sided`],
+//! and compare both readbacks. Both faces have the same color and the light comes from the viewer, so that the back
+//! face — which normal is flipped with `gl_FrontFacing` — must be exactly as bright as the front face, and brighter
+//! than the ambient light alone.
+
+use crate::{
+  shared::{unit_quad, PrimitiveVertex, Semantics},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use cgmath::{Matrix4, Rad, SquareMatrix as _};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3},
+    Program, Uniform,
+  },
+  tess::Tess,
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("two-sided-vs.glsl");
+const FS: &str = include_str!("two-sided-fs.glsl");
+
+// the quad covers the 4×4 pixels at the center of the framebuffer
+const SIZE: usize = 8;
+
+// value of a fully lit white texel, below which a face is considered unlit; ambient light alone gives 51
+const LIT: u8 = 200;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+  front_color: Uniform<Vec3<f32>>,
+  back_color: Uniform<Vec3<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  quad: Tess<PrimitiveVertex, u32>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = unit_quad(context).expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer([SIZE as u32, SIZE as u32], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let front = self.render(context, Matrix4::identity());
+    // turning the quad around shows its back face, wound clockwise
+    let back = self.render(context, Matrix4::from_angle_y(Rad(std::f32::consts::PI)));
+
+    assert_eq!(front, back, "front and back faces are lit differently");
+
+    let center = (SIZE / 2 * SIZE + SIZE / 2) * 4;
+    assert!(
+      back[center] > LIT,
+      "back face is not lit: {:?}",
+      &back[center..center + 4]
+    );
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render the quad with the given model matrix and get the texels.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    model: Matrix4<f32>,
+  ) -> Vec<u8> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, unis, mut rdr_gate| {
+            // the view looks down the -Z axis, without perspective
+            iface.set(&unis.projection, Mat44::new(Matrix4::identity()));
+            iface.set(&unis.view, Mat44::new(Matrix4::identity()));
+            iface.set(&unis.model, Mat44::new(model));
+            iface.set(&unis.front_color, Vec3::new(1., 1., 1.));
+            iface.set(&unis.back_color, Vec3::new(1., 1., 1.));
+
+            rdr_gate.render(&RenderState::two_sided(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("two-sided render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
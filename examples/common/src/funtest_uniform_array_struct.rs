@@ -0,0 +1,147 @@
+//! Look up the fields of an array of 4 light structs with `ask_array_struct` and set each field for all the lights in
+//! a single call. The fragment color sums the lights’ positions, so it can only be right if every position was set.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::Vec3, Program, UniformArrayField, UniformBuilder, UniformInterface, UniformWarning,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const LIGHTS: usize = 4;
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+struct Light {
+  vec3 position;
+  vec3 color;
+  float intensity;
+};
+
+uniform Light lights[4];
+
+void main() {
+  vec3 sum = vec3(0.);
+
+  for (int i = 0; i < 4; ++i) {
+    sum += lights[i].position * lights[i].color * lights[i].intensity;
+  }
+
+  frag = vec4(sum, 1.);
+}";
+
+struct LightsInterface {
+  positions: UniformArrayField<Vec3<f32>, LIGHTS>,
+  colors: UniformArrayField<Vec3<f32>, LIGHTS>,
+  intensities: UniformArrayField<f32, LIGHTS>,
+}
+
+impl UniformInterface<Backend> for LightsInterface {
+  fn uniform_interface<'a>(
+    builder: &mut UniformBuilder<'a>,
+    _: &mut (),
+  ) -> Result<Self, UniformWarning> {
+    Ok(LightsInterface {
+      positions: builder.ask_array_struct("lights", "position")?,
+      colors: builder.ask_array_struct("lights", "color")?,
+      intensities: builder.ask_array_struct("lights", "intensity")?,
+    })
+  }
+}
+
+pub struct LocalExample {
+  program: Program<(), (), LightsInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), LightsInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    // the i-th light contributes (i + 1) * (10, 20, 0) / 255
+    let mut positions = [Vec3::new(0., 0., 0.); LIGHTS];
+    for (i, position) in positions.iter_mut().enumerate() {
+      let k = (i + 1) as f32 / 255.;
+      *position = Vec3::new(10. * k, 20. * k, 0.);
+    }
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set_array_field(&uni.positions, positions);
+            iface.set_array_field(&uni.colors, [Vec3::new(1., 1., 1.); LIGHTS]);
+            iface.set_array_field(&uni.intensities, [1.; LIGHTS]);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    // (1 + 2 + 3 + 4) * (10, 20, 0)
+    assert_eq!(texels, [100, 200, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
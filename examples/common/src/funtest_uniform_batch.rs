@@ -0,0 +1,142 @@
+//! Set uniforms in a batch with `set_all`, checking that only the last value set on a uniform is issued, that `None`
+//! doesn’t override a value set earlier in the batch and that unbound uniforms are skipped without error. The elements
+//! of an array are set in the batch too, then one of them again, which must be issued without error.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::Vec3, Program, Uniform, UniformArrayField, UniformBuilder, UniformInterface,
+    UniformWarning,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+// unused is inactive, as it doesn’t contribute to the output
+const FS: &str = "
+out vec4 frag;
+
+uniform vec3 color;
+uniform float alpha;
+uniform float unused;
+uniform float weights[4];
+
+void main() {
+  frag = vec4(color, alpha * (weights[0] + weights[1] + weights[2] + weights[3]));
+}";
+
+struct ShaderInterface {
+  color: Uniform<Vec3<f32>>,
+  alpha: Uniform<f32>,
+  unused: Uniform<f32>,
+  weights: UniformArrayField<f32, 4>,
+}
+
+impl UniformInterface<Backend> for ShaderInterface {
+  fn uniform_interface<'a>(
+    builder: &mut UniformBuilder<'a>,
+    _: &mut (),
+  ) -> Result<Self, UniformWarning> {
+    Ok(ShaderInterface {
+      color: builder.ask("color")?,
+      alpha: builder.ask("alpha")?,
+      unused: builder.ask_or_unbound("unused"),
+      weights: builder.ask_array_elements("weights")?,
+    })
+  }
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context.set_error_checking(true);
+
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 0.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+          iface.set_all(|batch| {
+            batch.set(&uni.color, Vec3::new(1., 0., 0.));
+            batch.set(&uni.alpha, 1.);
+            batch.set(&uni.unused, 1.);
+            batch.set_array_field(&uni.weights, [0.25, 0.25, 0., 0.25]);
+            batch.set(&uni.weights.uniforms()[2], 0.25);
+
+            // the last value wins and None keeps it
+            batch.set(&uni.color, Vec3::new(0., 1., 0.));
+            batch.set_opt(&uni.color, None);
+          });
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+    assert_eq!(texels, [0, 255, 0, 255]);
+
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected backend errors: {:?}", errors);
+
+    LoopFeedback::Exit
+  }
+}
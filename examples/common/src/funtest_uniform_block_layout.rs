@@ -0,0 +1,164 @@
+//! Query the layout of a `std140` uniform block and check that the member offsets and array strides reported by the
+//! backend match the ones computed from the `luminance-std140` encodings.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec2, Vec3, Vec4},
+    BlockLayout, Program,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+use luminance_std140::{ArrElem, Std140};
+use std::mem;
+
+const VS: &str = "
+layout (std140) uniform Params {
+  float scale;
+  vec2 offset;
+  vec4 color;
+  mat4 transform;
+  float weights[3];
+  vec3 tint;
+};
+
+out vec4 v_color;
+
+void main() {
+  float w = weights[0] + weights[1] + weights[2];
+  gl_Position = transform * vec4(offset * scale, 0., 1.);
+  v_color = color * w + vec4(tint, 0.);
+}";
+
+const FS: &str = "
+in vec4 v_color;
+
+out vec4 frag;
+
+void main() {
+  frag = v_color;
+}";
+
+/// Place a member of type `T` at the next offset respecting its `std140` alignment, and return that offset.
+fn place<T>(cursor: &mut usize) -> usize
+where
+  T: Std140,
+{
+  let align = mem::align_of::<<T as Std140>::Encoded>();
+  let offset = (*cursor + align - 1) / align * align;
+  *cursor = offset + mem::size_of::<<T as Std140>::Encoded>();
+  offset
+}
+
+/// Expected `(name, offset, stride)` of the members of the `Params` block.
+fn expected_members() -> Vec<(String, usize, usize)> {
+  let mut cursor = 0;
+  let scale = place::<f32>(&mut cursor);
+  let offset = place::<Vec2<f32>>(&mut cursor);
+  let color = place::<Vec4<f32>>(&mut cursor);
+  let transform = place::<Mat44<f32>>(&mut cursor);
+  let weights = place::<ArrElem<f32>>(&mut cursor);
+  place::<ArrElem<f32>>(&mut cursor);
+  place::<ArrElem<f32>>(&mut cursor);
+  let tint = place::<Vec3<f32>>(&mut cursor);
+  let weights_stride = mem::size_of::<<ArrElem<f32> as Std140>::Encoded>();
+
+  vec![
+    ("scale".to_owned(), scale, 0),
+    ("offset".to_owned(), offset, 0),
+    ("color".to_owned(), color, 0),
+    ("transform".to_owned(), transform, 0),
+    ("weights[0]".to_owned(), weights, weights_stride),
+    ("tint".to_owned(), tint, 0),
+  ]
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  tess: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let tess = context
+      .new_tess()
+      .set_mode(Mode::Point)
+      .set_render_vertex_nb(1)
+      .build()
+      .expect("tess");
+
+    let framebuffer = context
+      .new_framebuffer([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      tess,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let tess = &self.tess;
+    let mut layout = None;
+    let mut missing = None;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |iface, _, mut rdr_gate| {
+            layout = iface.uniform_block_layout("Params");
+            missing = iface.uniform_block_layout("DoesNotExist");
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(tess)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    assert_eq!(missing, None);
+
+    let BlockLayout { size, members } = layout.expect("Params block layout");
+    log::info!("Params block: {} bytes, members: {:?}", size, members);
+
+    let expected = expected_members();
+    assert_eq!(members, expected);
+
+    // the last member is a vec3, which only occupies 12 bytes; drivers are free to pad the block after it
+    let (_, tint_offset, _) = expected[expected.len() - 1];
+    assert!(size >= tint_offset + 3 * mem::size_of::<f32>());
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,124 @@
+//! Set a GLSL `bool` uniform with an integer uniform and check that the shader takes the right branch.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::Program,
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+uniform bool flag;
+
+void main() {
+  if (flag) {
+    frag = vec4(0., 1., 0., 1.);
+  } else {
+    frag = vec4(1., 0., 0., 1.);
+  }
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let on = self.render(context, 1);
+    assert_eq!(on, [0, 255, 0, 255]);
+
+    let off = self.render(context, 0);
+    assert_eq!(off, [255, 0, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render with the given flag value and read back the resulting texel.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    flag: i32,
+  ) -> Vec<u8> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, _, mut rdr_gate| {
+            let mut query = iface.query().expect("uniform query");
+
+            // the boolean uniform can be retrieved both as a boolean and as an integer
+            query.ask::<bool>("flag").expect("bool flag uniform");
+            let flag_u = query.ask::<i32>("flag").expect("i32 flag uniform");
+
+            iface.set(&flag_u, flag);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("pipeline");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
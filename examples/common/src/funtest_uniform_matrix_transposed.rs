@@ -0,0 +1,146 @@
+//! Upload an asymmetric 4×4 matrix with `set_transposed` and check that the shader sees its transpose, by transforming
+//! a test vector with it and reading the result back from a floating-point framebuffer. The same matrix uploaded with
+//! `set` is checked too, so that both layouts are known to differ.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::RGBA32F,
+  render_state::RenderState,
+  shader::{types::Mat44, Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = "
+const vec2[3] CO = vec2[](vec2(-1., -1.), vec2(3., -1.), vec2(-1., 3.));
+
+void main() {
+  gl_Position = vec4(CO[gl_VertexID], 0., 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform mat4 m;
+
+void main() {
+  frag = m * vec4(1., 2., 3., 4.);
+}";
+
+const VECTOR: [f32; 4] = [1., 2., 3., 4.];
+
+// no two entries are equal, so that any transposition is noticed
+const MATRIX: [[f32; 4]; 4] = [
+  [1., 2., 3., 4.],
+  [5., 6., 7., 8.],
+  [9., 10., 11., 12.],
+  [13., 14., 15., 16.],
+];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  m: Uniform<Mat44<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  triangle: Tess<()>,
+  framebuffer: Framebuffer<Dim2, RGBA32F, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let triangle = context
+      .new_tess()
+      .set_render_vertex_nb(3)
+      .set_mode(Mode::Triangle)
+      .build()
+      .expect("triangle");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, RGBA32F, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      triangle,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    // the nested arrays of MATRIX are its columns when uploaded with set, and its rows when uploaded with set_transposed
+    let columns = (0..4)
+      .map(|i| (0..4).map(|j| MATRIX[j][i] * VECTOR[j]).sum())
+      .collect::<Vec<f32>>();
+    let rows = (0..4)
+      .map(|i| (0..4).map(|j| MATRIX[i][j] * VECTOR[j]).sum())
+      .collect::<Vec<f32>>();
+
+    assert_eq!(self.transform(context, false), columns);
+    assert_eq!(self.transform(context, true), rows);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Transform the test vector with the matrix uploaded either with `set` or `set_transposed`.
+  fn transform(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    transposed: bool,
+  ) -> Vec<f32> {
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            if transposed {
+              iface.set_transposed(&uni.m, Mat44::new(MATRIX));
+            } else {
+              iface.set(&uni.m, Mat44::new(MATRIX));
+            }
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(triangle)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("matrix transform render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
@@ -0,0 +1,122 @@
+//! Set a `vec3` and a `mat2` uniform and read them back from the program with `get_uniform_value`, checking that they
+//! hold the values just set. Reading an unbound uniform back must give nothing.
+//!
+//! WebGL2 doesn’t support reading uniforms back, so this test is only registered on desktop.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{
+    types::{Mat22, Vec3},
+    Program, Uniform,
+  },
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec3 color;
+uniform mat2 scale;
+
+void main() {
+  frag = vec4(color * (scale * vec2(1.)).x, 1.);
+}";
+
+const COLOR: [f32; 3] = [0.25, 0.5, 0.75];
+
+// not symmetric, so that a transposed readback is noticed
+const SCALE: [[f32; 2]; 2] = [[1., 2.], [3., 4.]];
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  color: Uniform<Vec3<f32>>,
+  scale: Uniform<Mat22<f32>>,
+  #[uniform(unbound)]
+  unused: Uniform<f32>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.color, Vec3::from(COLOR));
+            iface.set(&uni.scale, Mat22::from(SCALE));
+
+            assert_eq!(iface.get_uniform_value(&uni.color), Some(Vec3::from(COLOR)));
+            assert_eq!(
+              iface.get_uniform_value(&uni.scale),
+              Some(Mat22::from(SCALE))
+            );
+            assert_eq!(iface.get_uniform_value(&uni.unused), None);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("uniform readback render");
+
+    LoopFeedback::Exit
+  }
+}
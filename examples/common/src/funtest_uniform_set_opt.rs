@@ -0,0 +1,133 @@
+//! Look up uniforms dynamically with `ask_opt` and set them with `set_opt`, checking that inactive uniforms are skipped
+//! without error, that active ones are updated and that `None` leaves the previous value untouched.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  pixel::NormRGBA8UI,
+  render_state::RenderState,
+  shader::{types::Vec3, Program},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+// unused is inactive, as it doesn’t contribute to the output
+const FS: &str = "
+out vec4 frag;
+
+uniform vec3 color;
+uniform float unused;
+
+void main() {
+  frag = vec4(color, 1.);
+}";
+
+pub struct LocalExample {
+  program: Program<(), (), ()>,
+  quad: Tess<()>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ()>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    context.set_error_checking(true);
+
+    let red = self.render(context, Some(Vec3::new(1., 0., 0.)));
+    assert_eq!(red, [255, 0, 0, 255]);
+
+    // no value: the uniform keeps the previous one
+    let still_red = self.render(context, None);
+    assert_eq!(still_red, [255, 0, 0, 255]);
+
+    let green = self.render(context, Some(Vec3::new(0., 1., 0.)));
+    assert_eq!(green, [0, 255, 0, 255]);
+
+    let errors = context.take_backend_errors();
+    assert!(errors.is_empty(), "unexpected backend errors: {:?}", errors);
+
+    LoopFeedback::Exit
+  }
+}
+
+impl LocalExample {
+  /// Render with the given color, if any, and read back the resulting texel.
+  fn render(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    color: Option<Vec3<f32>>,
+  ) -> Vec<u8> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let pipeline_st = PipelineState::default().set_clear_color([0., 0., 0., 1.]);
+
+    context
+      .new_pipeline_gate()
+      .pipeline(&self.framebuffer, &pipeline_st, |_, mut shd_gate| {
+        shd_gate.shade(program, |mut iface, _, mut rdr_gate| {
+          let mut query = iface.query().expect("uniform query");
+
+          let color_u = query.ask_opt::<Vec3<f32>>("color").expect("color uniform");
+          assert!(query.ask_opt::<f32>("unused").is_none());
+          assert!(query.ask_opt::<f32>("missing").is_none());
+
+          // setting an unbound uniform is a no-op
+          let unused_u = query.ask_or_unbound::<f32>("unused");
+          iface.set_opt(&unused_u, Some(1.));
+
+          iface.set_opt(&color_u, color);
+
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(quad)
+          })
+        })
+      })
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels")
+  }
+}
@@ -0,0 +1,156 @@
+//! Bind a single texture and set it on two sampler uniforms with `set_texture`, checking that both samplers read from
+//! it through the same texture unit. Another texture is bound beforehand so that a sampler left at its default unit
+//! would read from that one instead.
+
+use crate::{Example, InputAction, LoopFeedback, PlatformServices};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, MagFilter, MinFilter, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = include_str!("copy-vs.glsl");
+
+// each sampler reads a different texel of the same texture
+const FS: &str = "
+out vec4 frag;
+
+uniform sampler2D left;
+uniform sampler2D right;
+
+void main() {
+  frag = texelFetch(left, ivec2(0, 0), 0) + texelFetch(right, ivec2(1, 0), 0);
+}";
+
+// red on the left, green on the right
+const TEXELS: [u8; 8] = [255, 0, 0, 255, 0, 255, 0, 255];
+
+const DECOY_TEXELS: [u8; 8] = [0, 0, 255, 255, 0, 0, 255, 255];
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  left: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+  right: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<(), (), ShaderInterface>,
+  quad: Tess<()>,
+  texture: Texture<Dim2, NormRGBA8UI>,
+  decoy: Texture<Dim2, NormRGBA8UI>,
+  framebuffer: Framebuffer<Dim2, NormRGBA8UI, ()>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<(), (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = context
+      .new_tess()
+      .set_render_vertex_nb(4)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("quad");
+
+    let sampler = Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    };
+
+    let texture = context
+      .new_texture_raw::<Dim2, NormRGBA8UI>(
+        [2, 1],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&TEXELS),
+      )
+      .expect("texture");
+
+    let decoy = context
+      .new_texture_raw::<Dim2, NormRGBA8UI>(
+        [2, 1],
+        sampler,
+        TexelUpload::base_level_without_mipmaps(&DECOY_TEXELS),
+      )
+      .expect("decoy texture");
+
+    let framebuffer = context
+      .new_framebuffer::<Dim2, NormRGBA8UI, ()>([1, 1], 0, Sampler::default())
+      .expect("framebuffer");
+
+    LocalExample {
+      program,
+      quad,
+      texture,
+      decoy,
+      framebuffer,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    _: Framebuffer<Dim2, (), ()>,
+    _: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let program = &mut self.program;
+    let quad = &self.quad;
+    let texture = &mut self.texture;
+    let decoy = &mut self.decoy;
+
+    context
+      .new_pipeline_gate()
+      .pipeline(
+        &self.framebuffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 1.]),
+        |pipeline, mut shd_gate| {
+          let decoy = pipeline.bind_texture(decoy)?;
+          let texture = pipeline.bind_texture(texture)?;
+
+          assert_ne!(
+            decoy.binding().binding(),
+            texture.binding().binding(),
+            "texture units"
+          );
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set_texture(&uni.left, &texture);
+            iface.set_texture(&uni.right, &texture);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("offscreen render");
+
+    let texels = self
+      .framebuffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("texels");
+
+    // red from the left sampler and green from the right one; any blue means a sampler read the decoy
+    assert_eq!(texels, [255, 255, 0, 255]);
+
+    LoopFeedback::Exit
+  }
+}
@@ -0,0 +1,196 @@
+//! Gamepad support.
+//!
+//! Platforms don’t all report gamepads the same way: some emit events (SDL2), others must be polled (GLFW). In order
+//! to hide those differences from the examples, platforms fill a [`GamepadState`] and turn the changes between two
+//! states into [`InputAction`]s with [`GamepadState::actions_since`].
+
+use crate::InputAction;
+
+/// Analog axes of a gamepad.
+///
+/// Sticks report values in `[-1; 1]` (right and down are positive); triggers report values in `[0; 1]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+  LeftX,
+  LeftY,
+  RightX,
+  RightY,
+  LeftTrigger,
+  RightTrigger,
+}
+
+impl Axis {
+  /// All the axes, in order.
+  pub const ALL: [Axis; 6] = [
+    Axis::LeftX,
+    Axis::LeftY,
+    Axis::RightX,
+    Axis::RightY,
+    Axis::LeftTrigger,
+    Axis::RightTrigger,
+  ];
+}
+
+/// Digital buttons of a gamepad.
+///
+/// Face buttons are named after their position, as their labels depend on the vendor.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GamepadButton {
+  South,
+  East,
+  West,
+  North,
+  LeftShoulder,
+  RightShoulder,
+  Back,
+  Start,
+  Guide,
+  LeftThumb,
+  RightThumb,
+  DPadUp,
+  DPadDown,
+  DPadLeft,
+  DPadRight,
+}
+
+impl GamepadButton {
+  /// All the buttons, in order.
+  pub const ALL: [GamepadButton; 15] = [
+    GamepadButton::South,
+    GamepadButton::East,
+    GamepadButton::West,
+    GamepadButton::North,
+    GamepadButton::LeftShoulder,
+    GamepadButton::RightShoulder,
+    GamepadButton::Back,
+    GamepadButton::Start,
+    GamepadButton::Guide,
+    GamepadButton::LeftThumb,
+    GamepadButton::RightThumb,
+    GamepadButton::DPadUp,
+    GamepadButton::DPadDown,
+    GamepadButton::DPadLeft,
+    GamepadButton::DPadRight,
+  ];
+}
+
+/// Axis values below that threshold are considered to be `0`, so that resting sticks don’t generate actions.
+pub const DEAD_ZONE: f32 = 0.15;
+
+/// State of a gamepad at a given time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadState {
+  axes: [f32; 6],
+  buttons: [bool; 15],
+}
+
+impl GamepadState {
+  /// Value of an axis.
+  pub fn axis(&self, axis: Axis) -> f32 {
+    self.axes[axis as usize]
+  }
+
+  /// Set the value of an axis, applying the [`DEAD_ZONE`].
+  pub fn set_axis(&mut self, axis: Axis, value: f32) {
+    self.axes[axis as usize] = if value.abs() < DEAD_ZONE { 0. } else { value };
+  }
+
+  /// Whether a button is pressed.
+  pub fn is_pressed(&self, button: GamepadButton) -> bool {
+    self.buttons[button as usize]
+  }
+
+  /// Set whether a button is pressed.
+  pub fn set_button(&mut self, button: GamepadButton, pressed: bool) {
+    self.buttons[button as usize] = pressed;
+  }
+
+  /// Actions describing what changed since the `previous` state.
+  pub fn actions_since<'a>(
+    &'a self,
+    previous: &'a GamepadState,
+  ) -> impl Iterator<Item = InputAction> + 'a {
+    let axes = Axis::ALL.iter().filter_map(move |&axis| {
+      let value = self.axis(axis);
+      if value != previous.axis(axis) {
+        Some(InputAction::Axis { axis, value })
+      } else {
+        None
+      }
+    });
+
+    let buttons = GamepadButton::ALL.iter().filter_map(move |&button| {
+      let pressed = self.is_pressed(button);
+      if pressed != previous.is_pressed(button) {
+        Some(InputAction::Button { button, pressed })
+      } else {
+        None
+      }
+    });
+
+    axes.chain(buttons)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unchanged_state_has_no_action() {
+    let mut state = GamepadState::default();
+    state.set_axis(Axis::LeftX, 0.5);
+    state.set_button(GamepadButton::South, true);
+
+    assert_eq!(state.actions_since(&state).count(), 0);
+  }
+
+  #[test]
+  fn changes_become_actions() {
+    let previous = GamepadState::default();
+    let mut state = previous;
+    state.set_axis(Axis::LeftY, -1.);
+    state.set_button(GamepadButton::Start, true);
+
+    let actions = state.actions_since(&previous).collect::<Vec<_>>();
+    assert!(matches!(
+      actions[..],
+      [
+        InputAction::Axis {
+          axis: Axis::LeftY,
+          value
+        },
+        InputAction::Button {
+          button: GamepadButton::Start,
+          pressed: true
+        }
+      ] if value == -1.
+    ));
+
+    // releasing the stick and the button
+    let actions = previous.actions_since(&state).collect::<Vec<_>>();
+    assert!(matches!(
+      actions[..],
+      [
+        InputAction::Axis {
+          axis: Axis::LeftY,
+          value
+        },
+        InputAction::Button {
+          button: GamepadButton::Start,
+          pressed: false
+        }
+      ] if value == 0.
+    ));
+  }
+
+  #[test]
+  fn dead_zone() {
+    let previous = GamepadState::default();
+    let mut state = previous;
+    state.set_axis(Axis::RightX, DEAD_ZONE / 2.);
+
+    assert_eq!(state.axis(Axis::RightX), 0.);
+    assert_eq!(state.actions_since(&previous).count(), 0);
+  }
+}
@@ -29,26 +29,40 @@ use luminance::{
 };
 use luminance_front::Backend;
 
+pub use crate::assets::AssetStore;
+pub use crate::drag::DragTracker;
+use crate::gamepad::{Axis, GamepadButton};
+
 // examples
+pub mod assets;
 pub mod attributeless;
 pub mod displacement_map;
+pub mod drag;
 pub mod dynamic_uniform_interface;
+pub mod gamepad;
 pub mod hello_world;
 pub mod interactive_triangle;
+pub mod many_instances;
+pub mod mesh_orbit;
 pub mod mrt;
 pub mod offscreen;
+pub mod point_sprites;
 pub mod polymorphic_hello_world;
+pub mod primitives;
 pub mod query_info;
 pub mod query_texture_texels;
 pub mod render_state;
+pub mod runtime_shaders;
 pub mod shader_data;
 pub mod shader_uniform_adapt;
 pub mod shader_uniforms;
 pub mod shared;
 pub mod skybox;
 pub mod sliced_tess;
+pub mod sprite_batch;
 pub mod stencil;
 pub mod texture;
+pub mod two_sided;
 pub mod vertex_instancing;
 
 // functional tests
@@ -57,15 +71,175 @@ pub mod funtest_360_manually_drop_framebuffer;
 #[cfg(feature = "funtest")]
 pub mod funtest_483_indices_mut_corruption;
 #[cfg(feature = "funtest")]
+pub mod funtest_blend_enabled_buffers;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_blending_hdr;
+#[cfg(feature = "funtest")]
+pub mod funtest_clip_distances;
+#[cfg(feature = "funtest")]
+pub mod funtest_compressed_texture;
+#[cfg(feature = "funtest")]
+pub mod funtest_draw_buffers;
+#[cfg(feature = "funtest")]
+pub mod funtest_dynamic_framebuffer;
+#[cfg(feature = "funtest")]
+pub mod funtest_dynamic_tess;
+#[cfg(feature = "funtest")]
+pub mod funtest_early_fragment_tests;
+#[cfg(feature = "funtest")]
+pub mod funtest_face_culling_mode;
+#[cfg(feature = "funtest")]
 pub mod funtest_flatten_slice;
+#[cfg(feature = "funtest")]
+pub mod funtest_flip_winding;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_3d_slice;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_back_buffer;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_framebuffer_capture;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_clear_colors;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_clear_region;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_color_slot_count;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_framebuffer_default_srgb;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_depth_copy;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_incomplete;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_integer_clear;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_integer_picking;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_invalidate;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_framebuffer_raw_handle;
+#[cfg(feature = "funtest")]
+pub mod funtest_framebuffer_shared_depth;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_framebuffer_stencil_sampling;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_geometry_max_vertices;
+#[cfg(feature = "funtest")]
+pub mod funtest_gl33_error_checking;
 #[cfg(all(feature = "funtest", feature = "funtest-gl33-f64-uniform"))]
 pub mod funtest_gl33_f64_uniform;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_invalidate_state;
+#[cfg(feature = "funtest")]
+pub mod funtest_memory_barrier;
+#[cfg(feature = "funtest")]
+pub mod funtest_multisample_texture_resolve;
+#[cfg(feature = "funtest")]
+pub mod funtest_pipeline_origin;
+#[cfg(feature = "funtest")]
+pub mod funtest_pipeline_stats_query;
+#[cfg(feature = "funtest")]
+pub mod funtest_pipeline_target_size;
 #[cfg(feature = "funtest")]
 pub mod funtest_pixel_array_encoding;
 #[cfg(feature = "funtest")]
+pub mod funtest_point_sprites;
+#[cfg(feature = "funtest")]
+pub mod funtest_program_binary;
+#[cfg(feature = "funtest")]
+pub mod funtest_read_depth_linear;
+#[cfg(feature = "funtest")]
+pub mod funtest_read_depth_raw;
+#[cfg(feature = "funtest")]
+pub mod funtest_render_depth_prepass;
+#[cfg(feature = "funtest")]
+pub mod funtest_render_with_depth;
+#[cfg(feature = "funtest")]
+pub mod funtest_resource_loader;
+#[cfg(feature = "funtest")]
+pub mod funtest_resource_loader_budget;
+#[cfg(feature = "funtest")]
+pub mod funtest_sample_shading;
+#[cfg(feature = "funtest")]
 pub mod funtest_scissor_test;
 #[cfg(feature = "funtest")]
+pub mod funtest_shader_data_multiple;
+#[cfg(feature = "funtest")]
+pub mod funtest_shader_include;
+#[cfg(feature = "funtest")]
+pub mod funtest_sprite_batch;
+#[cfg(feature = "funtest")]
+pub mod funtest_state_guard;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_base_instance;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_debug_normals;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_deinterleaved_missing_attribute;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_grid_indices;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_index_slice;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_index_type_overflow;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_interleaved_from;
+#[cfg(feature = "funtest")]
 pub mod funtest_tess_no_data;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_normalized_override;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_raw_interleaved;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_render_repeated;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_restart_mode;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_to_deinterleaved;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_update_attribute;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_usage;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_vertex_checksum;
+#[cfg(feature = "funtest")]
+pub mod funtest_tess_vertex_count;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_texture_buffer;
+#[cfg(all(feature = "funtest", target_family = "wasm"))]
+pub mod funtest_texture_from_image;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_texture_mip_view;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_texture_mirror_clamp;
+#[cfg(feature = "funtest")]
+pub mod funtest_texture_pixel_alignment;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_texture_stencil_index;
+#[cfg(feature = "funtest")]
+pub mod funtest_texture_swizzle;
+#[cfg(feature = "funtest")]
+pub mod funtest_texture_unit_overflow;
+#[cfg(feature = "funtest")]
+pub mod funtest_transform_feedback;
+#[cfg(feature = "funtest")]
+pub mod funtest_two_sided_lighting;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_array_struct;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_batch;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_block_layout;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_bool_from_int;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_matrix_transposed;
+#[cfg(all(feature = "funtest", not(target_family = "wasm")))]
+pub mod funtest_uniform_readback;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_set_opt;
+#[cfg(feature = "funtest")]
+pub mod funtest_uniform_set_texture;
 
 /// Example interface.
 pub trait Example<B = Backend>: Sized
@@ -130,11 +304,34 @@ pub enum InputAction {
   /// etc.
   CursorMoved { x: f32, y: f32 },
 
+  /// Cursor dragged while the primary action is held. `dx` and `dy` are the cursor deltas since the last known cursor
+  /// position. Typically used to orbit a camera around an object, pan a view, etc.
+  ///
+  /// This action is not emitted by platforms directly but synthesized from [`InputAction::PrimaryPressed`],
+  /// [`InputAction::CursorMoved`] and [`InputAction::PrimaryReleased`] via a [`DragTracker`].
+  Drag { dx: f32, dy: f32 },
+
   /// Framebuffer size changed.
   Resized { width: u32, height: u32 },
 
   /// Vertical scrolling.
   VScroll { amount: f32 },
+
+  /// Gamepad axis moved. The value is the new position of the axis; see [`Axis`] for the ranges.
+  Axis { axis: Axis, value: f32 },
+
+  /// Gamepad button pressed or released.
+  Button {
+    button: GamepadButton,
+    pressed: bool,
+  },
+
+  /// An asset previously fetched with [`PlatformServices::fetch_bytes`] changed. `bytes` is its new content.
+  AssetChanged { name: String, bytes: Vec<u8> },
+
+  /// Capture the current frame. On desktop, examples save it with [`shared::capture_framebuffer`]; on the web, the
+  /// platform downloads the content of the canvas once the frame is rendered.
+  Screenshot,
 }
 
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
@@ -149,4 +346,10 @@ pub trait PlatformServices {
 
   /// Fetch the next texture, if available.
   fn fetch_texture(&mut self) -> Result<image::RgbImage, Self::FetchError>;
+
+  /// Fetch the content of the asset named `name`, such as a shader source.
+  ///
+  /// How names map to assets is up to the platform. Once fetched, changes to the asset are notified with
+  /// [`InputAction::AssetChanged`].
+  fn fetch_bytes(&mut self, name: &str) -> Result<&[u8], Self::FetchError>;
 }
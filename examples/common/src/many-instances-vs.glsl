@@ -0,0 +1,15 @@
+in vec2 co;
+in vec3 color;
+in vec2 position;
+in float weight;
+
+out vec3 v_color;
+
+uniform Palette {
+  vec4[4] colors;
+} palette;
+
+void main() {
+  gl_Position = vec4(co * weight + position, 0., 1.);
+  v_color = color * palette.colors[gl_InstanceID % 4].rgb;
+}
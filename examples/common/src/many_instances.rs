@@ -0,0 +1,190 @@
+//! This program renders thousands of instanced quads. Each instance has its own transform — a position and a scale —
+//! stored in an instance attribute buffer, while the parameters shared by all the instances — a color palette — are
+//! stored in shader data.
+//!
+//! The instance buffer is updated every frame without rebuilding the tessellation, and scrolling changes the number
+//! of instances that are rendered with an instance view.
+//!
+//! <https://docs.rs/luminance>
+
+use crate::{
+  shared::{
+    Instance, Semantics, Vertex, VertexColor, VertexInstancePosition, VertexPosition, VertexWeight,
+  },
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, ShaderDataBinding},
+  render_state::RenderState,
+  shader::{types::Vec4, Program, ShaderData, Uniform},
+  tess::{Mode, Tess, View as _},
+  texture::Dim2,
+  Backend,
+};
+
+const VS: &str = include_str!("many-instances-vs.glsl");
+const FS: &str = include_str!("simple-fs.glsl");
+
+// instances are laid out on a GRID_SIZE × GRID_SIZE grid
+const GRID_SIZE: usize = 100;
+const MAX_INSTANCES: usize = GRID_SIZE * GRID_SIZE;
+
+const QUAD_HALF_SIZE: f32 = 1. / GRID_SIZE as f32;
+
+const QUAD_VERTICES: [Vertex; 4] = [
+  Vertex::new(
+    VertexPosition::new([-QUAD_HALF_SIZE, -QUAD_HALF_SIZE]),
+    VertexColor::new([1., 1., 1.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([QUAD_HALF_SIZE, -QUAD_HALF_SIZE]),
+    VertexColor::new([1., 1., 1.]),
+  ),
+  Vertex::new(
+    VertexPosition::new([QUAD_HALF_SIZE, QUAD_HALF_SIZE]),
+    VertexColor::new([0.5, 0.5, 0.5]),
+  ),
+  Vertex::new(
+    VertexPosition::new([-QUAD_HALF_SIZE, QUAD_HALF_SIZE]),
+    VertexColor::new([0.5, 0.5, 0.5]),
+  ),
+];
+
+const PALETTE: [Vec4<f32>; 4] = [
+  Vec4::new(1., 0.3, 0.3, 1.),
+  Vec4::new(0.3, 1., 0.3, 1.),
+  Vec4::new(0.3, 0.3, 1., 1.),
+  Vec4::new(1., 1., 0.3, 1.),
+];
+
+#[derive(Debug, UniformInterface)]
+struct ShaderInterface {
+  #[uniform(name = "Palette")]
+  palette: Uniform<ShaderDataBinding<Vec4<f32>>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  quads: Tess<Vertex, (), Instance>,
+  palette: ShaderData<Vec4<f32>>,
+  instance_count: usize,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    // the weights are animated every frame; only the positions matter here
+    let instances = (0..MAX_INSTANCES)
+      .map(|i| Instance {
+        pos: VertexInstancePosition::new(grid_position(i)),
+        w: VertexWeight::new(1.),
+      })
+      .collect::<Vec<_>>();
+
+    let quads = context
+      .new_tess()
+      .set_vertices(&QUAD_VERTICES[..])
+      .set_instances(instances)
+      .set_mode(Mode::TriangleFan)
+      .build()
+      .expect("instanced quads");
+
+    let palette = context.new_shader_data(PALETTE).expect("palette");
+
+    log::info!("scroll to change the number of instances");
+
+    Self {
+      program,
+      quads,
+      palette,
+      instance_count: MAX_INSTANCES / 4,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    t: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        // scroll amounts vary a lot between platforms, so only their direction is used
+        InputAction::VScroll { amount } => {
+          if amount > 0. {
+            self.instance_count = (self.instance_count * 2).min(MAX_INSTANCES);
+          } else if amount < 0. {
+            self.instance_count = (self.instance_count / 2).max(1);
+          }
+
+          log::info!("rendering {} instances", self.instance_count);
+        }
+
+        _ => (),
+      }
+    }
+
+    // make the rendered instances pulse; the instance buffer is updated in place
+    {
+      let mut instances = self.quads.instances_mut().expect("instances");
+
+      for (i, instance) in instances.iter_mut().take(self.instance_count).enumerate() {
+        let [x, y] = grid_position(i);
+        let w = (t * 2. + (x + y) * 4.).sin() * 0.5 + 0.5;
+        instance.w = VertexWeight::new(w);
+      }
+    }
+
+    let program = &mut self.program;
+    let quads = &self.quads;
+    let palette = &mut self.palette;
+    let instance_count = self.instance_count;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let palette = pipeline.bind_shader_data(palette).expect("bound palette");
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.palette, palette.binding());
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(quads.inst_view(.., instance_count).expect("instance view"))
+            })
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
+
+// position of the i-th instance on the grid, in clip space
+fn grid_position(i: usize) -> [f32; 2] {
+  let x = (i % GRID_SIZE) as f32 / GRID_SIZE as f32;
+  let y = (i / GRID_SIZE) as f32 / GRID_SIZE as f32;
+
+  [x * 2. - 1. + QUAD_HALF_SIZE, y * 2. - 1. + QUAD_HALF_SIZE]
+}
@@ -0,0 +1,12 @@
+in vec3 v_nor;
+in vec3 v_color;
+
+out vec3 frag;
+
+const vec3 LIGHT_DIR = normalize(vec3(0.5, 1., 0.75));
+const float AMBIENT = 0.2;
+
+void main() {
+  float diffuse = max(0., dot(normalize(v_nor), LIGHT_DIR));
+  frag = v_color * (AMBIENT + (1. - AMBIENT) * diffuse);
+}
@@ -0,0 +1,25 @@
+in vec3 co3;
+in vec3 nor;
+
+out vec3 v_nor;
+out vec3 v_color;
+
+uniform mat4 projection;
+uniform mat4 view;
+
+vec3[6] FACE_COLORS = vec3[](
+    vec3(1., 0., 0.),
+    vec3(0., 1., 0.),
+    vec3(0., 0., 1.),
+    vec3(1., 0., 1.),
+    vec3(0., 1., 1.),
+    vec3(1., 1., 0.)
+);
+
+void main() {
+  gl_Position = projection * view * vec4(co3, 1.);
+
+  // lighting is done in world space so that the light stays fixed while orbiting
+  v_nor = nor;
+  v_color = FACE_COLORS[gl_VertexID / 4];
+}
@@ -0,0 +1,156 @@
+//! This program shows how to orbit a camera around a mesh by dragging the cursor. Press the primary action (left mouse
+//! button) and move the cursor to rotate around the cube, and scroll to zoom in and out.
+//!
+//! Drags are not reported by the platforms directly but synthesized from cursor moves while the primary action is held;
+//! see [`InputAction::Drag`].
+//!
+//! <https://docs.rs/luminance>
+
+use cgmath::{perspective, Matrix4, Point3, Rad, Vector3};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{types::Mat44, Program, Uniform},
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+use crate::{
+  shared::{cube, CubeVertex, Semantics, VertexIndex},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+
+const VS: &str = include_str!("mesh-orbit-vs.glsl");
+const FS: &str = include_str!("mesh-orbit-fs.glsl");
+
+// Radians per pixel dragged.
+const ORBIT_SENSITIVITY: f32 = 0.01;
+// Distance change per scroll unit.
+const ZOOM_SENSITIVITY: f32 = 0.25;
+const MIN_DISTANCE: f32 = 1.;
+const MAX_DISTANCE: f32 = 8.;
+const FOVY: f32 = std::f32::consts::FRAC_PI_3;
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 100.;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  cube: Tess<CubeVertex, VertexIndex>,
+  projection: Matrix4<f32>,
+  // orbit camera, expressed as angles around the cube and a distance to it
+  yaw: f32,
+  pitch: f32,
+  distance: f32,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let (cube_vertices, cube_indices) = cube(0.5);
+    let cube = context
+      .new_tess()
+      .set_vertices(&cube_vertices[..])
+      .set_indices(&cube_indices[..])
+      .set_mode(Mode::TriangleStrip)
+      .set_primitive_restart_index(VertexIndex::MAX)
+      .build()
+      .expect("cube tess creation");
+
+    let projection = perspective(Rad(FOVY), 800. / 600., Z_NEAR, Z_FAR);
+
+    LocalExample {
+      program,
+      cube,
+      projection,
+      yaw: std::f32::consts::FRAC_PI_4,
+      pitch: 0.5,
+      distance: 3.,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::Resized { width, height } => {
+          self.projection = perspective(Rad(FOVY), width as f32 / height as f32, Z_NEAR, Z_FAR);
+        }
+
+        // dragging horizontally turns around the cube, dragging vertically goes over / under it
+        InputAction::Drag { dx, dy } => {
+          let limit = std::f32::consts::FRAC_PI_2 - 0.01;
+          self.yaw -= dx * ORBIT_SENSITIVITY;
+          self.pitch = (self.pitch + dy * ORBIT_SENSITIVITY).clamp(-limit, limit);
+        }
+
+        InputAction::VScroll { amount } => {
+          self.distance =
+            (self.distance - amount * ZOOM_SENSITIVITY).clamp(MIN_DISTANCE, MAX_DISTANCE);
+        }
+
+        _ => (),
+      }
+    }
+
+    let eye = Point3::new(
+      self.distance * self.pitch.cos() * self.yaw.sin(),
+      self.distance * self.pitch.sin(),
+      self.distance * self.pitch.cos() * self.yaw.cos(),
+    );
+    let view = Matrix4::look_at_rh(eye, Point3::new(0., 0., 0.), Vector3::unit_y());
+
+    let projection = Mat44::new(self.projection);
+    let view = Mat44::new(view);
+    let program = &mut self.program;
+    let cube = &self.cube;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, unis, mut rdr_gate| {
+            iface.set(&unis.projection, projection);
+            iface.set(&unis.view, view);
+
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(cube)
+            })
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
@@ -1,6 +1,9 @@
 //! This program shows how to render a single triangle into an offscreen framebuffer and how to
 //! render the content of this offscreen framebuffer into the back buffer (i.e. the screen).
 //!
+//! On desktop, the content of the offscreen framebuffer is saved to `offscreen.png` upon
+//! [`InputAction::Screenshot`].
+//!
 //! <https://docs.rs/luminance>
 
 use crate::{
@@ -28,6 +31,10 @@ const FS: &'static str = include_str!("simple-fs.glsl");
 const COPY_VS: &'static str = include_str!("copy-vs.glsl");
 const COPY_FS: &'static str = include_str!("copy-fs.glsl");
 
+// file the frame is captured to on InputAction::Screenshot
+#[cfg(not(target_family = "wasm"))]
+const SCREENSHOT_PATH: &str = "offscreen.png";
+
 // a single triangle is enough here
 const TRI_VERTICES: [Vertex; 3] = [
   // triangle – an RGB one
@@ -127,6 +134,14 @@ impl Example for LocalExample {
             .new_framebuffer([width, height], 0, Sampler::default())
             .expect("framebuffer recreation");
         }
+        #[cfg(not(target_family = "wasm"))]
+        InputAction::Screenshot => {
+          // the offscreen framebuffer holds the frame rendered during the previous call
+          match crate::shared::capture_framebuffer(&self.offscreen_buffer, SCREENSHOT_PATH) {
+            Ok(()) => log::info!("frame captured to {}", SCREENSHOT_PATH),
+            Err(e) => log::error!("{}", e),
+          }
+        }
         _ => (),
       }
     }
@@ -170,7 +185,7 @@ impl Example for LocalExample {
 
           shd_gate.shade(copy_program, |mut iface, uni, mut rdr_gate| {
             // we update the texture with the bound texture
-            iface.set(&uni.texture, bound_texture.binding());
+            iface.set_texture(&uni.texture, &bound_texture);
 
             rdr_gate.render(&RenderState::default(), |mut tess_gate| {
               // this will render the attributeless quad with the offscreen framebuffer color slot
@@ -0,0 +1,17 @@
+in vec3 v_color;
+
+out vec4 frag;
+
+uniform sampler2D tex;
+
+void main() {
+  // gl_PointCoord spans the whole point sprite, from (0, 0) to (1, 1)
+  vec4 texel = texture(tex, gl_PointCoord);
+
+  // the corners of the sprite are transparent; discarding them makes round particles
+  if (texel.a == 0.) {
+    discard;
+  }
+
+  frag = vec4(v_color * texel.rgb, texel.a);
+}
@@ -0,0 +1,17 @@
+in vec2 co;
+in vec3 color;
+
+out vec3 v_color;
+
+uniform float t;
+
+void main() {
+  // spin the cloud, particles closer to the center spinning faster
+  float r = length(co);
+  float a = t * (1. - r * .8);
+  mat2 rotation = mat2(cos(a), sin(a), -sin(a), cos(a));
+
+  gl_Position = vec4(rotation * co, 0., 1.);
+  gl_PointSize = 4. + 28. * (1. - r);
+  v_color = color;
+}
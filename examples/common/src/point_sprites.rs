@@ -0,0 +1,167 @@
+//! This program renders a spinning cloud of textured particles as point sprites.
+//!
+//! Each particle is a single vertex rendered with `Mode::Point`. The vertex shader sets the size of the particle with
+//! `gl_PointSize` — which requires the render state to enable the program point size — and the fragment shader samples
+//! a soft disc texture with `gl_PointCoord`, the coordinates of the fragment within the point sprite.
+//!
+//! <https://docs.rs/luminance>
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance::UniformInterface;
+use luminance_front::{
+  blending::{Blending, Equation, Factor},
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::{PipelineState, TextureBinding},
+  pixel::{NormRGBA8UI, NormUnsigned},
+  render_state::RenderState,
+  shader::{Program, Uniform},
+  tess::{Mode, Tess},
+  texture::{Dim2, Sampler, TexelUpload, Texture},
+  Backend,
+};
+
+const VS: &str = include_str!("point-sprites-vs.glsl");
+const FS: &str = include_str!("point-sprites-fs.glsl");
+
+const PARTICLES_NB: usize = 2000;
+
+// size of the particle texture, in texels
+const SPRITE_SIZE: u32 = 32;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  t: Uniform<f32>,
+  tex: Uniform<TextureBinding<Dim2, NormUnsigned>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  particles: Tess<Vertex>,
+  sprite: Texture<Dim2, NormRGBA8UI>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    // particles are laid out on a sunflower spiral, colored by their angle
+    let particles = (0..PARTICLES_NB)
+      .map(|i| {
+        let r = (i as f32 / PARTICLES_NB as f32).sqrt() * 0.9;
+        let a = i as f32 * 2.399_963;
+        let color = [
+          a.cos() * 0.5 + 0.5,
+          (a + 2.094).cos() * 0.5 + 0.5,
+          (a + 4.189).cos() * 0.5 + 0.5,
+        ];
+
+        Vertex::new(
+          VertexPosition::new([r * a.cos(), r * a.sin()]),
+          VertexColor::new(color),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let particles = context
+      .new_tess()
+      .set_vertices(particles)
+      .set_mode(Mode::Point)
+      .build()
+      .expect("particles");
+
+    let sprite = context
+      .new_texture_raw(
+        [SPRITE_SIZE, SPRITE_SIZE],
+        Sampler::default(),
+        TexelUpload::base_level_without_mipmaps(&soft_disc(SPRITE_SIZE)),
+      )
+      .expect("sprite texture");
+
+    LocalExample {
+      program,
+      particles,
+      sprite,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    t: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+        _ => (),
+      }
+    }
+
+    let program = &mut self.program;
+    let particles = &self.particles;
+    let sprite = &mut self.sprite;
+
+    // particles are accumulated additively, so they don’t need any sorting
+    let render_st = RenderState::default()
+      .set_depth_test(None)
+      .set_blending(Blending {
+        equation: Equation::Additive,
+        src: Factor::SrcAlpha,
+        dst: Factor::One,
+      })
+      .set_program_point_size(true);
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |pipeline, mut shd_gate| {
+          let bound_sprite = pipeline.bind_texture(sprite)?;
+
+          shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
+            iface.set(&uni.t, t);
+            iface.set_texture(&uni.tex, &bound_sprite);
+
+            rdr_gate.render(&render_st, |mut tess_gate| tess_gate.render(particles))
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
+
+// white RGBA texels of a disc which alpha fades from its center to its edge; texels outside of the disc are fully
+// transparent
+fn soft_disc(size: u32) -> Vec<u8> {
+  let half = size as f32 * 0.5;
+
+  (0..size * size)
+    .flat_map(|i| {
+      let x = (i % size) as f32 + 0.5 - half;
+      let y = (i / size) as f32 + 0.5 - half;
+      let d = (x * x + y * y).sqrt() / half;
+      let alpha = if d < 1. { (1. - d).powf(0.5) } else { 0. };
+
+      [255, 255, 255, (alpha * 255.) as u8]
+    })
+    .collect()
+}
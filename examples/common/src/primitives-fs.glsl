@@ -0,0 +1,19 @@
+in vec3 v_nor;
+in vec2 v_uv;
+
+out vec3 frag;
+
+uniform vec3 color;
+
+const vec3 LIGHT_DIR = normalize(vec3(0.5, 1., 0.75));
+const float AMBIENT = 0.2;
+const float CHECKS = 8.;
+
+void main() {
+  // checker pattern showing the texture coordinates
+  vec2 check = floor(v_uv * CHECKS);
+  float shade = mod(check.x + check.y, 2.) == 0. ? 1. : 0.6;
+
+  float diffuse = max(0., dot(normalize(v_nor), LIGHT_DIR));
+  frag = color * shade * (AMBIENT + (1. - AMBIENT) * diffuse);
+}
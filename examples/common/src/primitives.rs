@@ -0,0 +1,143 @@
+//! This program renders the primitive geometry generated by the examples’ shared helpers: a quad, a cube and a UV
+//! sphere, spinning side by side. The geometry comes with normals, used for lighting, and texture coordinates, shown as
+//! a checker pattern.
+//!
+//! <https://docs.rs/luminance>
+
+use cgmath::{perspective, Matrix4, Point3, Rad, Vector3};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3},
+    Program, Uniform,
+  },
+  tess::Tess,
+  texture::Dim2,
+  Backend,
+};
+
+use crate::{
+  shared::{unit_cube, unit_quad, uv_sphere, PrimitiveVertex, Semantics},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+
+const VS: &str = include_str!("primitives-vs.glsl");
+const FS: &str = include_str!("primitives-fs.glsl");
+
+const FOVY: f32 = std::f32::consts::FRAC_PI_3;
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 100.;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+  color: Uniform<Vec3<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  // each primitive with its position on the X axis and its color
+  primitives: Vec<(Tess<PrimitiveVertex, u32>, f32, [f32; 3])>,
+  projection: Matrix4<f32>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let primitives = vec![
+      (unit_quad(context).expect("quad"), -1.5, [1., 0.5, 0.2]),
+      (unit_cube(context).expect("cube"), 0., [0.2, 0.8, 0.4]),
+      (
+        uv_sphere(context, 32, 16).expect("sphere"),
+        1.5,
+        [0.3, 0.5, 1.],
+      ),
+    ];
+
+    let projection = perspective(Rad(FOVY), 800. / 600., Z_NEAR, Z_FAR);
+
+    LocalExample {
+      program,
+      primitives,
+      projection,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    t: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::Resized { width, height } => {
+          self.projection = perspective(Rad(FOVY), width as f32 / height as f32, Z_NEAR, Z_FAR);
+        }
+
+        _ => (),
+      }
+    }
+
+    let view = Matrix4::look_at_rh(
+      Point3::new(0., 1., 3.5),
+      Point3::new(0., 0., 0.),
+      Vector3::unit_y(),
+    );
+    let rotation = Matrix4::from_angle_y(Rad(t)) * Matrix4::from_angle_x(Rad(t * 0.5));
+
+    let projection = Mat44::new(self.projection);
+    let view = Mat44::new(view);
+    let program = &mut self.program;
+    let primitives = &self.primitives;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, unis, mut rdr_gate| {
+            iface.set(&unis.projection, projection);
+            iface.set(&unis.view, view);
+
+            for (tess, x, color) in primitives {
+              let model = Matrix4::from_translation(Vector3::new(*x, 0., 0.)) * rotation;
+              iface.set(&unis.model, Mat44::new(model));
+              iface.set(&unis.color, Vec3::from(*color));
+
+              rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+                tess_gate.render(tess)
+              })?;
+            }
+
+            Ok(())
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
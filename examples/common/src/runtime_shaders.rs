@@ -0,0 +1,158 @@
+//! This program shows how to load shaders at runtime instead of embedding them in the binary. The vertex and fragment
+//! shaders are fetched from the platform as raw bytes; whenever the platform notifies that one of them changed, the
+//! program is rebuilt, so that shaders can be live-edited while the example is running. If the edited shaders don’t
+//! compile, the error is logged and the previous program is kept.
+//!
+//! On desktop, the shaders are read from the assets directory (`--assets`, which defaults to the directory of the
+//! examples sources). On the web, copy them into the `static` directory and type their names in the assets field before
+//! running the example; hit the reload button after editing them.
+//!
+//! <https://docs.rs/luminance>
+
+use crate::{
+  shared::{Semantics, Vertex, VertexColor, VertexPosition},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{Program, ProgramError},
+  tess::{Mode, Tess},
+  texture::Dim2,
+  Backend,
+};
+
+// Names of the shaders to fetch from the platform.
+const VS_NAME: &str = "runtime-shaders-vs.glsl";
+const FS_NAME: &str = "runtime-shaders-fs.glsl";
+
+const TRI_VERTICES: [Vertex; 3] = [
+  Vertex {
+    pos: VertexPosition::new([0.5, -0.5]),
+    rgb: VertexColor::new([1., 0., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([0.0, 0.5]),
+    rgb: VertexColor::new([0., 1., 0.]),
+  },
+  Vertex {
+    pos: VertexPosition::new([-0.5, -0.5]),
+    rgb: VertexColor::new([0., 0., 1.]),
+  },
+];
+
+pub struct LocalExample {
+  vs: String,
+  fs: String,
+  program: Program<Semantics, (), ()>,
+  triangle: Tess<Vertex>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    platform: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let vs = fetch_shader(platform, VS_NAME);
+    let fs = fetch_shader(platform, FS_NAME);
+    let program = build_program(context, &vs, &fs).expect("program creation");
+
+    let triangle = context
+      .new_tess()
+      .set_vertices(&TRI_VERTICES[..])
+      .set_mode(Mode::Triangle)
+      .build()
+      .unwrap();
+
+    Self {
+      vs,
+      fs,
+      program,
+      triangle,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    _: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    let mut changed = false;
+
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::AssetChanged { name, bytes } if name == VS_NAME => {
+          self.vs = String::from_utf8_lossy(&bytes).into_owned();
+          changed = true;
+        }
+
+        InputAction::AssetChanged { name, bytes } if name == FS_NAME => {
+          self.fs = String::from_utf8_lossy(&bytes).into_owned();
+          changed = true;
+        }
+
+        _ => (),
+      }
+    }
+
+    // rebuild the program with the new shaders; keep the previous one if they don’t compile
+    if changed {
+      match build_program(context, &self.vs, &self.fs) {
+        Ok(program) => {
+          log::info!("shaders reloaded");
+          self.program = program;
+        }
+
+        Err(e) => log::error!("cannot reload shaders: {}", e),
+      }
+    }
+
+    let program = &mut self.program;
+    let triangle = &self.triangle;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(triangle)
+            })
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
+
+fn fetch_shader(platform: &mut impl PlatformServices, name: &str) -> String {
+  match platform.fetch_bytes(name) {
+    Ok(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+    Err(e) => panic!("cannot fetch shader {}: {}", name, e),
+  }
+}
+
+fn build_program(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  vs: &str,
+  fs: &str,
+) -> Result<Program<Semantics, (), ()>, ProgramError> {
+  context
+    .new_shader_program::<Semantics, (), ()>()
+    .from_strings(vs, None, None, fs)
+    .map(|built| built.ignore_warnings())
+}
@@ -1,12 +1,24 @@
+#[cfg(not(target_family = "wasm"))]
+use luminance::backend::{
+  color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot, texture::Texture as TextureBackend,
+};
 use luminance::{Semantics, Vertex};
 use luminance_front::{
   context::GraphicsContext,
   pixel::NormRGB8UI,
+  tess::{Mode, Tess, TessError},
   texture::{Dim2, Sampler, TexelUpload, Texture},
   Backend,
 };
+#[cfg(not(target_family = "wasm"))]
+use luminance_front::{
+  framebuffer::Framebuffer,
+  pixel::{ColorPixel, NormRGBA8UI, Pixel, RenderablePixel, RGBA32F},
+  texture::TextureError,
+};
 
 use crate::PlatformServices;
+use std::{error, fmt};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
 pub enum Semantics {
@@ -22,6 +34,9 @@ pub enum Semantics {
   // reference vertex normals with the nor variable in vertex shaders
   #[sem(name = "nor", repr = "[f32; 3]", wrapper = "VertexNormal")]
   Normal,
+  // reference vertex texture coordinates with the uv variable in vertex shaders
+  #[sem(name = "uv", repr = "[f32; 2]", wrapper = "VertexUV")]
+  UV,
   // reference vertex instance’s position on screen
   #[sem(
     name = "position",
@@ -112,6 +127,217 @@ pub fn cube(size: f32) -> ([CubeVertex; 24], [VertexIndex; 30]) {
   (vertices, indices)
 }
 
+/// Vertex of the primitive geometry generated by [`unit_quad`], [`unit_cube`] and [`uv_sphere`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+pub struct PrimitiveVertex {
+  pub pos: VertexPosition3,
+  pub nor: VertexNormal,
+  pub uv: VertexUV,
+}
+
+/// Vertices and indices of a quad of side 1 centered at the origin in the XY plane, facing +Z.
+///
+/// Indices describe triangles (for `Mode::Triangle`) wound counter-clockwise when seen from the front.
+pub fn unit_quad_geometry() -> (Vec<PrimitiveVertex>, Vec<u32>) {
+  let mut vertices = Vec::with_capacity(4);
+  let mut indices = Vec::with_capacity(6);
+  push_face(
+    &mut vertices,
+    &mut indices,
+    [0., 0., 1.],
+    [1., 0., 0.],
+    [0., 1., 0.],
+    0.,
+  );
+
+  (vertices, indices)
+}
+
+/// Vertices and indices of a cube of side 1 centered at the origin.
+///
+/// Each face has its own four vertices, so that normals are flat and every face is textured with the whole `[0, 1]` UV
+/// range. Indices describe triangles (for `Mode::Triangle`) wound counter-clockwise when seen from outside the cube.
+pub fn unit_cube_geometry() -> (Vec<PrimitiveVertex>, Vec<u32>) {
+  // normal, then the horizontal and vertical directions of the face as seen from outside
+  let faces = [
+    ([1., 0., 0.], [0., 0., -1.], [0., 1., 0.]),
+    ([-1., 0., 0.], [0., 0., 1.], [0., 1., 0.]),
+    ([0., 1., 0.], [1., 0., 0.], [0., 0., -1.]),
+    ([0., -1., 0.], [1., 0., 0.], [0., 0., 1.]),
+    ([0., 0., 1.], [1., 0., 0.], [0., 1., 0.]),
+    ([0., 0., -1.], [-1., 0., 0.], [0., 1., 0.]),
+  ];
+
+  let mut vertices = Vec::with_capacity(24);
+  let mut indices = Vec::with_capacity(36);
+  for (normal, right, up) in faces {
+    push_face(&mut vertices, &mut indices, normal, right, up, 0.5);
+  }
+
+  (vertices, indices)
+}
+
+// Push a square face of side 1, which center is `offset` along `normal`.
+fn push_face(
+  vertices: &mut Vec<PrimitiveVertex>,
+  indices: &mut Vec<u32>,
+  normal: [f32; 3],
+  right: [f32; 3],
+  up: [f32; 3],
+  offset: f32,
+) {
+  let base = vertices.len() as u32;
+
+  for [u, v] in [[0., 0.], [1., 0.], [1., 1.], [0., 1.]] {
+    let pos = [0, 1, 2].map(|i| normal[i] * offset + right[i] * (u - 0.5) + up[i] * (v - 0.5));
+    vertices.push(PrimitiveVertex::new(
+      pos.into(),
+      normal.into(),
+      [u, v].into(),
+    ));
+  }
+
+  indices.extend([0, 1, 2, 0, 2, 3].map(|i| base + i));
+}
+
+/// Vertices and indices of a sphere of diameter 1 centered at the origin, made of `segments` meridians and `rings`
+/// parallels.
+///
+/// The poles are on the Y axis. A seam of duplicated vertices holds both ends of the U texture coordinate, so there are
+/// `(rings + 1) * (segments + 1)` vertices. The bands around the poles have a single triangle per segment, the other
+/// bands two, so there are `6 * segments * (rings - 1)` indices, describing triangles (for `Mode::Triangle`) wound
+/// counter-clockwise when seen from outside the sphere.
+///
+/// # Panics
+///
+/// Panics if `segments < 3` or `rings < 2`, which cannot describe a closed volume.
+pub fn uv_sphere_geometry(segments: u32, rings: u32) -> (Vec<PrimitiveVertex>, Vec<u32>) {
+  assert!(
+    segments >= 3 && rings >= 2,
+    "a sphere needs at least 3 segments and 2 rings (got {} segments and {} rings)",
+    segments,
+    rings
+  );
+
+  let mut vertices = Vec::with_capacity(((rings + 1) * (segments + 1)) as usize);
+  for r in 0..=rings {
+    let v = r as f32 / rings as f32;
+    let theta = v * std::f32::consts::PI;
+
+    for s in 0..=segments {
+      let u = s as f32 / segments as f32;
+      let phi = u * std::f32::consts::TAU;
+      let normal = [
+        theta.sin() * phi.cos(),
+        theta.cos(),
+        -theta.sin() * phi.sin(),
+      ];
+
+      vertices.push(PrimitiveVertex::new(
+        normal.map(|x| x * 0.5).into(),
+        normal.into(),
+        [u, 1. - v].into(),
+      ));
+    }
+  }
+
+  let index = |r: u32, s: u32| r * (segments + 1) + s;
+  let mut indices = Vec::with_capacity((6 * segments * (rings - 1)) as usize);
+  for r in 0..rings {
+    for s in 0..segments {
+      let (a, b, c, d) = (
+        index(r, s),
+        index(r + 1, s),
+        index(r + 1, s + 1),
+        index(r, s + 1),
+      );
+
+      // the other triangle of the bands around the poles is degenerate
+      if r != rings - 1 {
+        indices.extend([a, b, c]);
+      }
+
+      if r != 0 {
+        indices.extend([a, c, d]);
+      }
+    }
+  }
+
+  (vertices, indices)
+}
+
+/// Create a [`Tess`] out of [`unit_quad_geometry`].
+pub fn unit_quad(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+) -> Result<Tess<PrimitiveVertex, u32>, TessError> {
+  primitive_tess(context, unit_quad_geometry())
+}
+
+/// Create a [`Tess`] out of [`unit_cube_geometry`].
+pub fn unit_cube(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+) -> Result<Tess<PrimitiveVertex, u32>, TessError> {
+  primitive_tess(context, unit_cube_geometry())
+}
+
+/// Create a [`Tess`] out of [`uv_sphere_geometry`].
+pub fn uv_sphere(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  segments: u32,
+  rings: u32,
+) -> Result<Tess<PrimitiveVertex, u32>, TessError> {
+  primitive_tess(context, uv_sphere_geometry(segments, rings))
+}
+
+fn primitive_tess(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  (vertices, indices): (Vec<PrimitiveVertex>, Vec<u32>),
+) -> Result<Tess<PrimitiveVertex, u32>, TessError> {
+  context
+    .new_tess()
+    .set_vertices(vertices)
+    .set_indices(indices)
+    .set_mode(Mode::Triangle)
+    .build()
+}
+
+/// Error returned by [`triangulate_polygon`] for faces with fewer than three vertices.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DegeneratePolygon(pub usize);
+
+impl fmt::Display for DegeneratePolygon {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "cannot triangulate a polygon with {} vertices (at least 3 required)",
+      self.0
+    )
+  }
+}
+
+impl error::Error for DegeneratePolygon {}
+
+/// Triangulate a convex polygon face — such as the quads and n-gons found in OBJ files — as a fan around its first
+/// vertex.
+///
+/// The returned indices describe `n - 2` triangles (for `Mode::Triangle`), each one keeping the winding of the input
+/// polygon, so that face culling behaves the same. Triangles pass through unchanged.
+pub fn triangulate_polygon(indices: &[u32]) -> Result<Vec<u32>, DegeneratePolygon> {
+  if indices.len() < 3 {
+    return Err(DegeneratePolygon(indices.len()));
+  }
+
+  let pivot = indices[0];
+  let triangles = indices[1..]
+    .windows(2)
+    .flat_map(|edge| [pivot, edge[0], edge[1]])
+    .collect();
+
+  Ok(triangles)
+}
+
 /// RGB texture.
 pub type RGBTexture = Texture<Dim2, NormRGB8UI>;
 
@@ -140,3 +366,307 @@ pub fn load_texture(
     .map_err(|e| log::error!("error while creating texture: {}", e))
     .ok()
 }
+
+/// Load the next texture once for each sampler in `samplers`, generating `mipmaps` mipmaps.
+pub fn load_texture_with_samplers(
+  context: &mut impl GraphicsContext<Backend = Backend>,
+  platform: &mut impl PlatformServices,
+  samplers: &[Sampler],
+  mipmaps: usize,
+) -> Option<Vec<RGBTexture>> {
+  let img = platform
+    .fetch_texture()
+    .map_err(|e| log::error!("error while loading image: {}", e))
+    .ok()?;
+  let (width, height) = img.dimensions();
+  let texels = img.as_raw();
+
+  samplers
+    .iter()
+    .map(|&sampler| {
+      context
+        .new_texture_raw(
+          [width, height],
+          sampler,
+          TexelUpload::base_level_with_mipmaps(texels, mipmaps),
+        )
+        .map_err(|e| log::error!("error while creating texture: {}", e))
+        .ok()
+    })
+    .collect()
+}
+
+/// Color pixel formats that can be captured into a PNG file.
+#[cfg(not(target_family = "wasm"))]
+pub trait CapturePixel: Pixel {
+  /// Convert the raw channels of a single texel into 8-bit RGBA.
+  fn to_rgba8(texel: &[Self::RawEncoding]) -> [u8; 4];
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl CapturePixel for NormRGB8UI {
+  fn to_rgba8(texel: &[u8]) -> [u8; 4] {
+    [texel[0], texel[1], texel[2], 255]
+  }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl CapturePixel for NormRGBA8UI {
+  fn to_rgba8(texel: &[u8]) -> [u8; 4] {
+    [texel[0], texel[1], texel[2], texel[3]]
+  }
+}
+
+// floating-point colors are clamped, so HDR values saturate
+#[cfg(not(target_family = "wasm"))]
+impl CapturePixel for RGBA32F {
+  fn to_rgba8(texel: &[f32]) -> [u8; 4] {
+    [0, 1, 2, 3].map(|i| (texel[i].clamp(0., 1.) * 255.).round() as u8)
+  }
+}
+
+/// Error that can occur while capturing a framebuffer.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub enum CaptureError {
+  /// The color texels couldn’t be read back.
+  Read(TextureError),
+  /// The PNG file couldn’t be written.
+  Write(image::ImageError),
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl fmt::Display for CaptureError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      CaptureError::Read(e) => write!(f, "cannot read framebuffer: {}", e),
+      CaptureError::Write(e) => write!(f, "cannot write capture: {}", e),
+    }
+  }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl error::Error for CaptureError {}
+
+#[cfg(not(target_family = "wasm"))]
+impl From<TextureError> for CaptureError {
+  fn from(e: TextureError) -> Self {
+    CaptureError::Read(e)
+  }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl From<image::ImageError> for CaptureError {
+  fn from(e: image::ImageError) -> Self {
+    CaptureError::Write(e)
+  }
+}
+
+/// Build an RGBA image out of texels read back from a framebuffer.
+///
+/// Framebuffers are read from the lower-left corner while images start at the upper-left corner, so rows are flipped.
+#[cfg(not(target_family = "wasm"))]
+pub fn capture_image<P>([width, height]: [u32; 2], texels: &[P::RawEncoding]) -> image::RgbaImage
+where
+  P: CapturePixel,
+{
+  let channels = P::pixel_format().channels_len();
+  let row_len = width as usize * channels;
+  let rgba = texels
+    .chunks(row_len)
+    .rev()
+    .flat_map(|row| row.chunks(channels).flat_map(P::to_rgba8))
+    .collect();
+
+  image::RgbaImage::from_raw(width, height, rgba).expect("capture image")
+}
+
+/// Read back the color slot of `framebuffer` and save it as a PNG file at `path`.
+#[cfg(not(target_family = "wasm"))]
+pub fn capture_framebuffer<P, DS>(
+  framebuffer: &Framebuffer<Dim2, P, DS>,
+  path: impl AsRef<std::path::Path>,
+) -> Result<(), CaptureError>
+where
+  Backend: TextureBackend<Dim2, P>,
+  P: CapturePixel
+    + ColorPixel
+    + RenderablePixel
+    + ColorSlot<Backend, Dim2, ColorTextures = Texture<Dim2, P>>,
+  P::RawEncoding: Default,
+  DS: DepthStencilSlot<Backend, Dim2>,
+{
+  let size = framebuffer.size();
+  let texels = framebuffer.read_color([0, 0], size)?;
+
+  capture_image::<P>(size, &texels).save_with_format(path, image::ImageFormat::Png)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn triangle_passes_through() {
+    assert_eq!(triangulate_polygon(&[4, 2, 7]), Ok(vec![4, 2, 7]));
+  }
+
+  #[test]
+  fn quad_into_two_triangles() {
+    assert_eq!(
+      triangulate_polygon(&[0, 1, 2, 3]),
+      Ok(vec![0, 1, 2, 0, 2, 3])
+    );
+  }
+
+  #[test]
+  fn pentagon_into_three_triangles() {
+    assert_eq!(
+      triangulate_polygon(&[10, 11, 12, 13, 14]),
+      Ok(vec![10, 11, 12, 10, 12, 13, 10, 13, 14])
+    );
+  }
+
+  #[test]
+  fn winding_is_preserved() {
+    // counter-clockwise unit square; every triangle must be counter-clockwise as well
+    let positions = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+    let triangles = triangulate_polygon(&[0, 1, 2, 3]).unwrap();
+
+    for tri in triangles.chunks(3) {
+      let [a, b, c]: [[f32; 2]; 3] = [
+        positions[tri[0] as usize],
+        positions[tri[1] as usize],
+        positions[tri[2] as usize],
+      ];
+      let area = (b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1]);
+
+      assert!(area > 0., "triangle {:?} is not counter-clockwise", tri);
+    }
+  }
+
+  fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+  }
+
+  fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+      a[1] * b[2] - a[2] * b[1],
+      a[2] * b[0] - a[0] * b[2],
+      a[0] * b[1] - a[1] * b[0],
+    ]
+  }
+
+  fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+  }
+
+  // geometric normal of each triangle, following its winding
+  fn triangle_normals<'a>(
+    vertices: &'a [PrimitiveVertex],
+    indices: &'a [u32],
+  ) -> impl Iterator<Item = ([f32; 3], [f32; 3])> + 'a {
+    indices.chunks(3).map(move |tri| {
+      let [a, b, c] = [0, 1, 2].map(|i| *vertices[tri[i] as usize].pos);
+      let centroid = [0, 1, 2].map(|i| (a[i] + b[i] + c[i]) / 3.);
+
+      (cross(sub(b, a), sub(c, a)), centroid)
+    })
+  }
+
+  #[test]
+  fn quad_counts_and_winding() {
+    let (vertices, indices) = unit_quad_geometry();
+
+    assert_eq!(vertices.len(), 4);
+    assert_eq!(indices.len(), 6);
+
+    for (normal, _) in triangle_normals(&vertices, &indices) {
+      assert!(dot(normal, [0., 0., 1.]) > 0.);
+    }
+  }
+
+  #[test]
+  fn cube_normals_point_outward() {
+    let (vertices, indices) = unit_cube_geometry();
+
+    assert_eq!(vertices.len(), 24);
+    assert_eq!(indices.len(), 36);
+
+    for v in &vertices {
+      // the normal of each vertex points out of the face it belongs to
+      assert!(dot(*v.pos, *v.nor) > 0., "{:?}", v);
+      assert!(v.pos.iter().all(|x| x.abs() <= 0.5));
+    }
+
+    for ((normal, centroid), tri) in triangle_normals(&vertices, &indices).zip(indices.chunks(3)) {
+      assert!(
+        dot(normal, centroid) > 0.,
+        "triangle {:?} faces inward",
+        tri
+      );
+      assert!(dot(normal, *vertices[tri[0] as usize].nor) > 0.);
+    }
+  }
+
+  #[test]
+  fn sphere_counts() {
+    for (segments, rings) in [(3, 2), (8, 4), (32, 16)] {
+      let (vertices, indices) = uv_sphere_geometry(segments, rings);
+
+      assert_eq!(vertices.len() as u32, (rings + 1) * (segments + 1));
+      assert_eq!(indices.len() as u32, 6 * segments * (rings - 1));
+      assert!(indices.iter().all(|&i| (i as usize) < vertices.len()));
+    }
+  }
+
+  #[test]
+  fn sphere_winding_and_normals() {
+    let (vertices, indices) = uv_sphere_geometry(16, 8);
+
+    for v in &vertices {
+      assert!((dot(*v.pos, *v.pos).sqrt() - 0.5).abs() < 1e-5);
+      assert!((dot(*v.nor, *v.nor).sqrt() - 1.).abs() < 1e-5);
+    }
+
+    for ((normal, centroid), tri) in triangle_normals(&vertices, &indices).zip(indices.chunks(3)) {
+      assert!(
+        dot(normal, centroid) > 0.,
+        "triangle {:?} faces inward",
+        tri
+      );
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn sphere_too_few_segments() {
+    uv_sphere_geometry(2, 4);
+  }
+
+  #[test]
+  fn degenerate_polygons() {
+    assert_eq!(triangulate_polygon(&[]), Err(DegeneratePolygon(0)));
+    assert_eq!(triangulate_polygon(&[0, 1]), Err(DegeneratePolygon(2)));
+  }
+
+  #[test]
+  fn capture_flips_rows() {
+    // two rows, read back from the lower-left corner
+    let texels = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let image = capture_image::<NormRGB8UI>([2, 2], &texels);
+
+    assert_eq!(image.dimensions(), (2, 2));
+    assert_eq!(image.get_pixel(0, 0).0, [7, 8, 9, 255]);
+    assert_eq!(image.get_pixel(1, 0).0, [10, 11, 12, 255]);
+    assert_eq!(image.get_pixel(0, 1).0, [1, 2, 3, 255]);
+  }
+
+  #[test]
+  fn capture_clamps_floating_colors() {
+    let image = capture_image::<RGBA32F>([1, 1], &[2., 0.5, -1., 1.]);
+
+    assert_eq!(image.get_pixel(0, 0).0, [255, 128, 0, 255]);
+  }
+}
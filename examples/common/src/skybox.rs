@@ -40,6 +40,7 @@ use luminance_front::{
 use shared::cube;
 
 use crate::{
+  gamepad::Axis,
   shared::{self, CubeVertex, Semantics, VertexIndex},
   Example, InputAction, LoopFeedback, PlatformServices,
 };
@@ -63,6 +64,9 @@ const CAMERA_SENSITIVITY_STRAFE_RIGHT: f32 = 0.1;
 const CAMERA_SENSITIVITY_STRAFE_UP: f32 = 0.1;
 const CAMERA_SENSITIVITY_STRAFE_DOWN: f32 = 0.1;
 const CAMERA_SENSITIVITY_FOVY_CHANGE: f32 = 0.1;
+// Gamepad sticks are applied every frame they’re held, so those are per-frame speeds at full tilt.
+const CAMERA_SENSITIVITY_STICK_STRAFE: f32 = 0.05;
+const CAMERA_SENSITIVITY_STICK_LOOK: f32 = 0.03;
 
 // When projecting objects from 3D to 2D, we need to encode the project with a “minimum clipping
 // distance” and a “maximum” one. Those values encode such a pair of numbers. If you want to see
@@ -135,12 +139,13 @@ pub struct LocalExample {
   env_map_program: Program<Semantics, (), EnvironmentMappingShaderInterface>,
   fullscreen_quad: Tess<()>,
   cube: Tess<CubeVertex, VertexIndex>,
-  last_cursor_pos: Option<[f32; 2]>,
-  rotate_viewport: bool,
   x_theta: f32,
   y_theta: f32,
   eye: Vector3<f32>,
   view_updated: bool,
+  // current position of the gamepad sticks, as [x, y]
+  move_stick: [f32; 2],
+  look_stick: [f32; 2],
 }
 
 impl Example for LocalExample {
@@ -198,12 +203,12 @@ impl Example for LocalExample {
 
     // A bunch of renderloop-specific variables used to track what’s happening with your keyboard and
     // mouse / trackpad.
-    let last_cursor_pos = None;
-    let rotate_viewport = false;
     let x_theta = 0.;
     let y_theta = 0.;
     let eye = Vector3::new(0., 0., 3.);
     let view_updated = true;
+    let move_stick = [0., 0.];
+    let look_stick = [0., 0.];
 
     LocalExample {
       skybox,
@@ -217,12 +222,12 @@ impl Example for LocalExample {
       env_map_program,
       fullscreen_quad,
       cube,
-      last_cursor_pos,
-      rotate_viewport,
       x_theta,
       y_theta,
       eye,
       view_updated,
+      move_stick,
+      look_stick,
     }
   }
 
@@ -308,31 +313,15 @@ impl Example for LocalExample {
           self.projection = perspective(Rad(self.fovy), self.aspect_ratio, Z_NEAR, Z_FAR);
         }
 
-        // When the cursor move, we need to update the last cursor position we know and, if needed,
-        // update the Euler angles we use to orient the camera in space.
-        InputAction::CursorMoved { x, y } => {
-          let [px, py] = self.last_cursor_pos.unwrap_or([x, y]);
-          let [rx, ry] = [x - px, y - py];
+        // When the cursor is dragged, update the Euler angles we use to orient the camera in space.
+        InputAction::Drag { dx, dy } => {
+          self.x_theta += CAMERA_SENSITIVITY_PITCH * dy;
+          self.y_theta += CAMERA_SENSITIVITY_YAW * dx;
 
-          self.last_cursor_pos = Some([x, y]);
+          // Stick the camera at verticals.
+          self.x_theta = clamp_pitch(self.x_theta);
 
-          if self.rotate_viewport {
-            self.x_theta += CAMERA_SENSITIVITY_PITCH * ry as f32;
-            self.y_theta += CAMERA_SENSITIVITY_YAW * rx as f32;
-
-            // Stick the camera at verticals.
-            self.x_theta = clamp_pitch(self.x_theta);
-
-            self.view_updated = true;
-          }
-        }
-
-        InputAction::PrimaryPressed => {
-          self.rotate_viewport = true;
-        }
-
-        InputAction::PrimaryReleased => {
-          self.rotate_viewport = false;
+          self.view_updated = true;
         }
 
         InputAction::VScroll { amount } => {
@@ -346,10 +335,38 @@ impl Example for LocalExample {
           log::info!("new fovy is {}°", deg);
         }
 
+        // Sticks only report their new position when it changes; we keep track of it to move smoothly as long as they
+        // are held.
+        InputAction::Axis { axis, value } => match axis {
+          Axis::LeftX => self.move_stick[0] = value,
+          Axis::LeftY => self.move_stick[1] = value,
+          Axis::RightX => self.look_stick[0] = value,
+          Axis::RightY => self.look_stick[1] = value,
+          _ => (),
+        },
+
         _ => (),
       }
     }
 
+    if self.move_stick != [0., 0.] {
+      let [x, y] = self.move_stick;
+      let v = self.cam_orient.invert().rotate_vector(Vector3::new(
+        -x * CAMERA_SENSITIVITY_STICK_STRAFE,
+        0.,
+        -y * CAMERA_SENSITIVITY_STICK_STRAFE,
+      ));
+      self.eye -= v;
+      self.view_updated = true;
+    }
+
+    if self.look_stick != [0., 0.] {
+      let [x, y] = self.look_stick;
+      self.x_theta = clamp_pitch(self.x_theta + CAMERA_SENSITIVITY_STICK_LOOK * y);
+      self.y_theta += CAMERA_SENSITIVITY_STICK_LOOK * x;
+      self.view_updated = true;
+    }
+
     // When the view is updated (i.e. the camera has moved or got re-oriented), we want to
     // recompute a bunch of quaternions (used to encode orientations) and matrices.
     if self.view_updated {
@@ -0,0 +1,257 @@
+//! Sprite batching.
+//!
+//! 2D renderers usually draw many textured quads — sprites — in a single draw call. Each sprite is made of four
+//! vertices connected by the same index pattern, [`QUAD_INDICES`], offset by four for every sprite. [`SpriteBatch`]
+//! builds both buffers out of a list of [`Sprite`] and keeps them on the GPU. Updating the batch only re-uploads the
+//! vertices, since the index pattern doesn’t depend on the sprites; the storage is only reallocated when the batch
+//! grows beyond its capacity.
+
+use crate::shared::{Semantics, VertexPosition, VertexUV};
+use luminance::Vertex;
+use luminance_front::{
+  context::GraphicsContext,
+  tess::{Interleaved, Mode, Tess, TessError, TessMapError, TessView, View as _},
+  Backend,
+};
+use std::{error, fmt};
+
+/// Indices of the two triangles of a single sprite, wound counter-clockwise.
+pub const QUAD_INDICES: [u32; 6] = [0, 1, 2, 2, 3, 0];
+
+/// A textured, axis-aligned rectangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprite {
+  /// Position of the lower-left corner.
+  pub position: [f32; 2],
+  /// Width and height.
+  pub size: [f32; 2],
+  /// Texture coordinates of the lower-left and upper-right corners.
+  pub uv: [[f32; 2]; 2],
+}
+
+/// Vertex of a [`Sprite`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+pub struct SpriteVertex {
+  pub pos: VertexPosition,
+  pub uv: VertexUV,
+}
+
+/// Vertices of a list of sprites, four per sprite: lower-left, lower-right, upper-right and upper-left corners.
+pub fn sprite_vertices(sprites: &[Sprite]) -> Vec<SpriteVertex> {
+  sprites
+    .iter()
+    .flat_map(|sprite| {
+      let [x, y] = sprite.position;
+      let [w, h] = sprite.size;
+      let [[u0, v0], [u1, v1]] = sprite.uv;
+
+      [
+        ([x, y], [u0, v0]),
+        ([x + w, y], [u1, v0]),
+        ([x + w, y + h], [u1, v1]),
+        ([x, y + h], [u0, v1]),
+      ]
+      .map(|(pos, uv)| SpriteVertex {
+        pos: VertexPosition::new(pos),
+        uv: VertexUV::new(uv),
+      })
+    })
+    .collect()
+}
+
+/// Indices of `sprite_nb` sprites, repeating [`QUAD_INDICES`] for each group of four vertices.
+pub fn sprite_indices(sprite_nb: usize) -> Vec<u32> {
+  (0..sprite_nb as u32)
+    .flat_map(|sprite| QUAD_INDICES.map(|i| 4 * sprite + i))
+    .collect()
+}
+
+/// Error that can occur while creating or updating a [`SpriteBatch`].
+#[derive(Debug)]
+pub enum SpriteBatchError {
+  /// The GPU storage couldn’t be created.
+  Tess(TessError),
+  /// The GPU storage couldn’t be updated.
+  Map(TessMapError),
+}
+
+impl fmt::Display for SpriteBatchError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      SpriteBatchError::Tess(e) => write!(f, "cannot create sprite batch: {}", e),
+      SpriteBatchError::Map(e) => write!(f, "cannot update sprite batch: {}", e),
+    }
+  }
+}
+
+impl error::Error for SpriteBatchError {}
+
+impl From<TessError> for SpriteBatchError {
+  fn from(e: TessError) -> Self {
+    SpriteBatchError::Tess(e)
+  }
+}
+
+impl From<TessMapError> for SpriteBatchError {
+  fn from(e: TessMapError) -> Self {
+    SpriteBatchError::Map(e)
+  }
+}
+
+/// A list of sprites rendered in a single draw call.
+///
+/// Render the batch with [`SpriteBatch::view`], which only covers the sprites of the last update.
+pub struct SpriteBatch {
+  tess: Tess<SpriteVertex, u32>,
+  capacity: usize,
+  len: usize,
+}
+
+impl SpriteBatch {
+  /// Create a batch holding `sprites`.
+  pub fn new(
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    sprites: &[Sprite],
+  ) -> Result<Self, SpriteBatchError> {
+    let capacity = sprites.len().max(1);
+    let tess = Self::build_tess(context, sprites, capacity)?;
+
+    Ok(SpriteBatch {
+      tess,
+      capacity,
+      len: sprites.len(),
+    })
+  }
+
+  /// Replace the sprites of the batch.
+  ///
+  /// If the sprites fit in the current capacity, only the vertices are uploaded, reusing the GPU storage. Otherwise,
+  /// the storage is reallocated with at least twice the capacity, so that batches growing a bit every frame don’t
+  /// reallocate every frame.
+  pub fn update(
+    &mut self,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    sprites: &[Sprite],
+  ) -> Result<(), SpriteBatchError> {
+    if sprites.len() > self.capacity {
+      let capacity = sprites.len().max(2 * self.capacity);
+      self.tess = Self::build_tess(context, sprites, capacity)?;
+      self.capacity = capacity;
+    } else {
+      let vertices = sprite_vertices(sprites);
+      self.tess.vertices_mut()?[..vertices.len()].copy_from_slice(&vertices);
+    }
+
+    self.len = sprites.len();
+    Ok(())
+  }
+
+  /// Number of sprites in the batch.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the batch has no sprite.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Number of sprites the batch can hold before its storage gets reallocated.
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Underlying [`Tess`], holding [`SpriteBatch::capacity`] sprites.
+  pub fn tess(&self) -> &Tess<SpriteVertex, u32> {
+    &self.tess
+  }
+
+  /// View on the sprites of the batch, to render.
+  pub fn view(&self) -> TessView<'_, SpriteVertex, u32, (), Interleaved> {
+    self
+      .tess
+      .view(..QUAD_INDICES.len() * self.len)
+      .expect("sprite batch view")
+  }
+
+  // unused slots are filled with empty sprites, which don’t cover any pixel
+  fn build_tess(
+    context: &mut impl GraphicsContext<Backend = Backend>,
+    sprites: &[Sprite],
+    capacity: usize,
+  ) -> Result<Tess<SpriteVertex, u32>, TessError> {
+    let mut vertices = sprite_vertices(sprites);
+    let empty = SpriteVertex {
+      pos: VertexPosition::new([0., 0.]),
+      uv: VertexUV::new([0., 0.]),
+    };
+    vertices.resize(4 * capacity, empty);
+
+    context
+      .new_tess()
+      .set_vertices(vertices)
+      .set_indices(sprite_indices(capacity))
+      .set_mode(Mode::Triangle)
+      .build()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sprite(x: f32) -> Sprite {
+    Sprite {
+      position: [x, 0.],
+      size: [1., 2.],
+      uv: [[0., 0.], [0.5, 1.]],
+    }
+  }
+
+  #[test]
+  fn three_sprites_counts() {
+    let sprites = [sprite(0.), sprite(1.), sprite(2.)];
+
+    assert_eq!(sprite_vertices(&sprites).len(), 12);
+    assert_eq!(sprite_indices(sprites.len()).len(), 18);
+  }
+
+  #[test]
+  fn indices_repeat_quad_pattern() {
+    assert_eq!(
+      sprite_indices(3),
+      [0, 1, 2, 2, 3, 0, 4, 5, 6, 6, 7, 4, 8, 9, 10, 10, 11, 8]
+    );
+    assert!(sprite_indices(0).is_empty());
+  }
+
+  #[test]
+  fn vertices_cover_sprite_corners() {
+    let vertices = sprite_vertices(&[sprite(3.)]);
+    let corners = vertices.iter().map(|v| (*v.pos, *v.uv)).collect::<Vec<_>>();
+
+    assert_eq!(
+      corners,
+      [
+        ([3., 0.], [0., 0.]),
+        ([4., 0.], [0.5, 0.]),
+        ([4., 2.], [0.5, 1.]),
+        ([3., 2.], [0., 1.]),
+      ]
+    );
+  }
+
+  #[test]
+  fn quads_are_counter_clockwise() {
+    let vertices = sprite_vertices(&[sprite(0.), sprite(5.)]);
+
+    for tri in sprite_indices(2).chunks(3) {
+      let [a, b, c] = [0, 1, 2].map(|i| *vertices[tri[i] as usize].pos);
+      let area = (b[0] - a[0]) * (c[1] - a[1]) - (c[0] - a[0]) * (b[1] - a[1]);
+
+      assert!(area > 0., "triangle {:?} is not counter-clockwise", tri);
+    }
+  }
+}
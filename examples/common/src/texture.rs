@@ -1,12 +1,14 @@
 //! This program is a showcase to demonstrate how you can use a texture from an image loaded from the disk.
 //! For the purpose of simplicity, the image is stretched to match your window resolution.
 //!
+//! Press the main toggle to cycle through the sampler presets (pixel art, UI and tiling).
+//!
 //! > Note: for this example, it is recommended to compile with --release to speed up image loading.
 //!
 //! <https://docs.rs/luminance>
 
 use crate::{
-  shared::{load_texture, RGBTexture},
+  shared::{load_texture_with_samplers, RGBTexture},
   Example, InputAction, LoopFeedback, PlatformServices,
 };
 use luminance::UniformInterface;
@@ -19,13 +21,26 @@ use luminance_front::{
   render_state::RenderState,
   shader::{Program, Uniform},
   tess::{Mode, Tess},
-  texture::Dim2,
+  texture::{Dim2, Sampler},
   Backend,
 };
 
 const VS: &'static str = include_str!("texture-vs.glsl");
 const FS: &'static str = include_str!("texture-fs.glsl");
 
+// a sampler preset, along with its name
+type SamplerPreset = (&'static str, fn() -> Sampler);
+
+// sampler presets to cycle through
+const SAMPLERS: [SamplerPreset; 3] = [
+  ("pixel art", Sampler::pixel_art),
+  ("UI", Sampler::ui),
+  ("tiling", Sampler::tiling),
+];
+
+// number of mipmaps to generate; only the tiling sampler uses them
+const MIPMAPS: usize = 4;
+
 // we also need a special uniform interface here to pass the texture to the shader
 #[derive(UniformInterface)]
 struct ShaderInterface {
@@ -33,7 +48,9 @@ struct ShaderInterface {
 }
 
 pub struct LocalExample {
-  image: RGBTexture,
+  // one texture per sampler preset
+  images: Vec<RGBTexture>,
+  sampler_index: usize,
   program: Program<(), (), ShaderInterface>,
   tess: Tess<()>,
 }
@@ -43,7 +60,12 @@ impl Example for LocalExample {
     platform: &mut impl PlatformServices,
     context: &mut impl GraphicsContext<Backend = Backend>,
   ) -> Self {
-    let image = load_texture(context, platform).expect("texture to display");
+    let samplers = SAMPLERS
+      .iter()
+      .map(|(_, sampler)| sampler())
+      .collect::<Vec<_>>();
+    let images = load_texture_with_samplers(context, platform, &samplers, MIPMAPS)
+      .expect("texture to display");
 
     // set the uniform interface to our type so that we can read textures from the shader
     let program = context
@@ -63,7 +85,8 @@ impl Example for LocalExample {
       .unwrap();
 
     LocalExample {
-      image,
+      images,
+      sampler_index: 0,
       program,
       tess,
     }
@@ -79,11 +102,20 @@ impl Example for LocalExample {
     for action in actions {
       match action {
         InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::MainToggle => {
+          self.sampler_index = (self.sampler_index + 1) % SAMPLERS.len();
+          log::info!(
+            "now sampling with the {} sampler",
+            SAMPLERS[self.sampler_index].0
+          );
+        }
+
         _ => (),
       }
     }
 
-    let tex = &mut self.image;
+    let tex = &mut self.images[self.sampler_index];
     let program = &mut self.program;
     let tess = &self.tess;
     let render_st = &RenderState::default().set_blending(Blending {
@@ -104,7 +136,7 @@ impl Example for LocalExample {
           shd_gate.shade(program, |mut iface, uni, mut rdr_gate| {
             // update the texture; strictly speaking, this update doesn’t do much: it just tells the GPU
             // to use the texture passed as argument (no allocation or copy is performed)
-            iface.set(&uni.tex, bound_tex.binding());
+            iface.set_texture(&uni.tex, &bound_tex);
 
             rdr_gate.render(render_st, |mut tess_gate| {
               // render the tessellation to the surface the regular way and let the vertex shader’s
@@ -0,0 +1,20 @@
+in vec3 v_nor;
+
+out vec3 frag;
+
+uniform vec3 front_color;
+uniform vec3 back_color;
+
+// the light comes from the camera side, so that both faces get lit in turn
+const vec3 LIGHT_DIR = normalize(vec3(0., 0.5, 1.));
+const float AMBIENT = 0.2;
+
+void main() {
+  // back faces are seen from behind the surface: their normal must be flipped to point towards the viewer, otherwise
+  // they would only get ambient light
+  vec3 normal = normalize(gl_FrontFacing ? v_nor : -v_nor);
+  vec3 color = gl_FrontFacing ? front_color : back_color;
+
+  float diffuse = max(0., dot(normal, LIGHT_DIR));
+  frag = color * (AMBIENT + (1. - AMBIENT) * diffuse);
+}
@@ -0,0 +1,15 @@
+in vec3 co3;
+in vec3 nor;
+
+out vec3 v_nor;
+
+uniform mat4 projection;
+uniform mat4 view;
+uniform mat4 model;
+
+void main() {
+  gl_Position = projection * view * model * vec4(co3, 1.);
+
+  // models are only rotated and translated, so their normals can be transformed with the model matrix
+  v_nor = mat3(model) * nor;
+}
@@ -0,0 +1,131 @@
+//! This program renders a thin two-sided surface — think of a sheet of paper — spinning around the Y axis. Face
+//! culling is disabled with [`RenderState::two_sided`] so that both faces are rendered, and the fragment shader uses
+//! `gl_FrontFacing` to flip the normal of back faces, so that they are lit like front faces. Each face also gets its
+//! own color.
+//!
+//! <https://docs.rs/luminance>
+
+use cgmath::{perspective, Matrix4, Point3, Rad, Vector3};
+use luminance::UniformInterface;
+use luminance_front::{
+  context::GraphicsContext,
+  framebuffer::Framebuffer,
+  pipeline::PipelineState,
+  render_state::RenderState,
+  shader::{
+    types::{Mat44, Vec3},
+    Program, Uniform,
+  },
+  tess::Tess,
+  texture::Dim2,
+  Backend,
+};
+
+use crate::{
+  shared::{unit_quad, PrimitiveVertex, Semantics},
+  Example, InputAction, LoopFeedback, PlatformServices,
+};
+
+const VS: &str = include_str!("two-sided-vs.glsl");
+const FS: &str = include_str!("two-sided-fs.glsl");
+
+const FOVY: f32 = std::f32::consts::FRAC_PI_3;
+const Z_NEAR: f32 = 0.1;
+const Z_FAR: f32 = 100.;
+
+#[derive(UniformInterface)]
+struct ShaderInterface {
+  projection: Uniform<Mat44<f32>>,
+  view: Uniform<Mat44<f32>>,
+  model: Uniform<Mat44<f32>>,
+  front_color: Uniform<Vec3<f32>>,
+  back_color: Uniform<Vec3<f32>>,
+}
+
+pub struct LocalExample {
+  program: Program<Semantics, (), ShaderInterface>,
+  quad: Tess<PrimitiveVertex, u32>,
+  projection: Matrix4<f32>,
+}
+
+impl Example for LocalExample {
+  fn bootstrap(
+    _: &mut impl PlatformServices,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> Self {
+    let program = context
+      .new_shader_program::<Semantics, (), ShaderInterface>()
+      .from_strings(VS, None, None, FS)
+      .expect("program creation")
+      .ignore_warnings();
+
+    let quad = unit_quad(context).expect("quad");
+    let projection = perspective(Rad(FOVY), 800. / 600., Z_NEAR, Z_FAR);
+
+    LocalExample {
+      program,
+      quad,
+      projection,
+    }
+  }
+
+  fn render_frame(
+    mut self,
+    t: f32,
+    back_buffer: Framebuffer<Dim2, (), ()>,
+    actions: impl Iterator<Item = InputAction>,
+    context: &mut impl GraphicsContext<Backend = Backend>,
+  ) -> LoopFeedback<Self> {
+    for action in actions {
+      match action {
+        InputAction::Quit => return LoopFeedback::Exit,
+
+        InputAction::Resized { width, height } => {
+          self.projection = perspective(Rad(FOVY), width as f32 / height as f32, Z_NEAR, Z_FAR);
+        }
+
+        _ => (),
+      }
+    }
+
+    let view = Matrix4::look_at_rh(
+      Point3::new(0., 0., 2.),
+      Point3::new(0., 0., 0.),
+      Vector3::unit_y(),
+    );
+    let model = Matrix4::from_angle_y(Rad(t));
+
+    let projection = Mat44::new(self.projection);
+    let view = Mat44::new(view);
+    let model = Mat44::new(model);
+    let program = &mut self.program;
+    let quad = &self.quad;
+
+    let render = context
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default(),
+        |_, mut shd_gate| {
+          shd_gate.shade(program, |mut iface, unis, mut rdr_gate| {
+            iface.set(&unis.projection, projection);
+            iface.set(&unis.view, view);
+            iface.set(&unis.model, model);
+            iface.set(&unis.front_color, Vec3::new(1., 0.8, 0.3));
+            iface.set(&unis.back_color, Vec3::new(0.3, 0.6, 1.));
+
+            rdr_gate.render(&RenderState::two_sided(), |mut tess_gate| {
+              tess_gate.render(quad)
+            })
+          })
+        },
+      )
+      .assume();
+
+    if render.is_ok() {
+      LoopFeedback::Continue(self)
+    } else {
+      LoopFeedback::Exit
+    }
+  }
+}
@@ -1,13 +1,18 @@
 mod platform;
 
 use glfw::{
-  Action, Context as _, Key, Modifiers, MouseButton, SwapInterval, WindowEvent, WindowMode,
+  Action, Context as _, GamepadAxis, Glfw, JoystickId, Key, Modifiers, MouseButton, WindowEvent,
+  WindowHint, WindowMode,
+};
+use luminance::context::{GraphicsContext as _, VSyncMode};
+use luminance_examples::{
+  gamepad::{Axis, GamepadButton, GamepadState},
+  DragTracker, Example, InputAction, LoopFeedback,
 };
-use luminance_examples::{Example, InputAction, LoopFeedback};
 use luminance_gl::GL33;
-use luminance_glfw::{GlfwSurface, GlfwSurfaceError};
+use luminance_glfw::{GL33Context, GlfwSurface, GlfwSurfaceError};
 use platform::DesktopPlatformServices;
-use std::{iter, time::Instant};
+use std::{iter, path::PathBuf, time::Instant};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -16,10 +21,18 @@ pub struct CLIOpts {
   #[structopt(short, long)]
   textures: Vec<String>,
 
+  /// Directory assets are fetched from; defaults to the directory of the examples sources.
+  #[structopt(short, long)]
+  assets: Option<PathBuf>,
+
   /// List available examples.
   #[structopt(short, long)]
   list_examples: bool,
 
+  /// Ask for an sRGB-capable default framebuffer.
+  #[structopt(long)]
+  srgb: bool,
+
   /// Example to run.
   example: Option<String>,
 }
@@ -93,18 +106,21 @@ fn run_example<E>(cli_opts: CLIOpts, name: &str)
 where
   E: Example<GL33>,
 {
+  let srgb = cli_opts.srgb;
+
   // Check the features so that we know what we need to load.
   let mut services = DesktopPlatformServices::new(cli_opts);
 
   // First thing first: we create a new surface to render to and get events from.
   let surface = GlfwSurface::new(|glfw| {
+    glfw.window_hint(WindowHint::SRgbCapable(srgb));
+
     let (mut window, events) = glfw
       .create_window(960, 540, name, WindowMode::Windowed)
       .ok_or_else(|| GlfwSurfaceError::UserError(PlatformError::CannotCreateWindow))?;
 
     window.make_current();
     window.set_all_polling(true);
-    glfw.set_swap_interval(SwapInterval::Sync(1));
 
     Ok((window, events))
   })
@@ -113,6 +129,10 @@ where
   let mut context = surface.context;
   let events = surface.events_rx;
 
+  // vsync can be cycled at runtime with the V key
+  let mut vsync = VSyncMode::On;
+  set_vsync(&mut context, vsync);
+
   let example = E::bootstrap(&mut services, &mut context);
   let start_t = Instant::now();
 
@@ -133,10 +153,43 @@ where
     LoopFeedback::Continue(example) => example,
   };
 
+  let mut gamepad = GamepadState::default();
+  let mut drag = DragTracker::new();
+
   'app: loop {
     // handle events
     context.window.glfw.poll_events();
-    let actions = glfw::flush_messages(&events).flat_map(|(_, event)| adapt_events(event));
+
+    // gamepads don’t generate events with GLFW, so we poll them and compare with the previous state
+    let new_gamepad = poll_gamepad(&context.window.glfw);
+    let gamepad_actions = new_gamepad.actions_since(&gamepad).collect::<Vec<_>>();
+    gamepad = new_gamepad;
+
+    let mut window_events = Vec::new();
+    for (_, event) in glfw::flush_messages(&events) {
+      match event {
+        WindowEvent::Key(Key::V, _, Action::Release, _) => {
+          vsync = next_vsync_mode(vsync);
+          set_vsync(&mut context, vsync);
+        }
+
+        // forget the cursor when it leaves the window so that a drag resuming elsewhere doesn’t jump
+        WindowEvent::CursorEnter(false) => drag.cursor_left(),
+
+        _ => window_events.push(event),
+      }
+    }
+
+    let mut actions = Vec::new();
+    for action in window_events.into_iter().flat_map(adapt_events) {
+      let dragged = drag.track(&action);
+      actions.push(action);
+      actions.extend(dragged);
+    }
+    let actions = actions
+      .into_iter()
+      .chain(gamepad_actions)
+      .chain(services.changed_assets());
 
     let elapsed = start_t.elapsed();
     let t = elapsed.as_secs() as f64 + (elapsed.subsec_millis() as f64 * 1e-3);
@@ -156,6 +209,22 @@ where
   }
 }
 
+fn next_vsync_mode(mode: VSyncMode) -> VSyncMode {
+  match mode {
+    VSyncMode::Off => VSyncMode::On,
+    VSyncMode::On => VSyncMode::Adaptive,
+    VSyncMode::Adaptive => VSyncMode::Off,
+  }
+}
+
+fn set_vsync(context: &mut GL33Context, mode: VSyncMode) {
+  match context.set_vsync(mode) {
+    Ok(applied) if applied != mode => log::info!("vsync: {} (fallback from {})", applied, mode),
+    Ok(applied) => log::info!("vsync: {}", applied),
+    Err(e) => log::warn!("{}", e),
+  }
+}
+
 fn adapt_events(event: WindowEvent) -> Option<InputAction> {
   match event {
     WindowEvent::Close | WindowEvent::Key(Key::Escape, _, Action::Release, _) => {
@@ -172,6 +241,8 @@ fn adapt_events(event: WindowEvent) -> Option<InputAction> {
       }
     }
 
+    WindowEvent::Key(Key::F12, _, Action::Release, _) => Some(InputAction::Screenshot),
+
     WindowEvent::Key(key, _, Action::Press, _) | WindowEvent::Key(key, _, Action::Repeat, _) => {
       log::debug!("key press: {:?}", key);
       match key {
@@ -209,6 +280,79 @@ fn adapt_events(event: WindowEvent) -> Option<InputAction> {
   }
 }
 
+// Read the state of the first gamepad; a missing gamepad is seen as a gamepad at rest.
+fn poll_gamepad(glfw: &Glfw) -> GamepadState {
+  let mut state = GamepadState::default();
+
+  let glfw_state = match glfw.get_joystick(JoystickId::Joystick1).get_gamepad_state() {
+    Some(glfw_state) => glfw_state,
+    None => return state,
+  };
+
+  let axes = [
+    (Axis::LeftX, GamepadAxis::AxisLeftX),
+    (Axis::LeftY, GamepadAxis::AxisLeftY),
+    (Axis::RightX, GamepadAxis::AxisRightX),
+    (Axis::RightY, GamepadAxis::AxisRightY),
+    (Axis::LeftTrigger, GamepadAxis::AxisLeftTrigger),
+    (Axis::RightTrigger, GamepadAxis::AxisRightTrigger),
+  ];
+
+  for (axis, glfw_axis) in axes {
+    let value = glfw_state.get_axis(glfw_axis);
+
+    // GLFW reports triggers in [-1; 1]
+    let value = match axis {
+      Axis::LeftTrigger | Axis::RightTrigger => (value + 1.) * 0.5,
+      _ => value,
+    };
+
+    state.set_axis(axis, value);
+  }
+
+  let buttons = [
+    (GamepadButton::South, glfw::GamepadButton::ButtonA),
+    (GamepadButton::East, glfw::GamepadButton::ButtonB),
+    (GamepadButton::West, glfw::GamepadButton::ButtonX),
+    (GamepadButton::North, glfw::GamepadButton::ButtonY),
+    (
+      GamepadButton::LeftShoulder,
+      glfw::GamepadButton::ButtonLeftBumper,
+    ),
+    (
+      GamepadButton::RightShoulder,
+      glfw::GamepadButton::ButtonRightBumper,
+    ),
+    (GamepadButton::Back, glfw::GamepadButton::ButtonBack),
+    (GamepadButton::Start, glfw::GamepadButton::ButtonStart),
+    (GamepadButton::Guide, glfw::GamepadButton::ButtonGuide),
+    (
+      GamepadButton::LeftThumb,
+      glfw::GamepadButton::ButtonLeftThumb,
+    ),
+    (
+      GamepadButton::RightThumb,
+      glfw::GamepadButton::ButtonRightThumb,
+    ),
+    (GamepadButton::DPadUp, glfw::GamepadButton::ButtonDpadUp),
+    (GamepadButton::DPadDown, glfw::GamepadButton::ButtonDpadDown),
+    (GamepadButton::DPadLeft, glfw::GamepadButton::ButtonDpadLeft),
+    (
+      GamepadButton::DPadRight,
+      glfw::GamepadButton::ButtonDpadRight,
+    ),
+  ];
+
+  for (button, glfw_button) in buttons {
+    state.set_button(
+      button,
+      glfw_state.get_button_state(glfw_button) == Action::Press,
+    );
+  }
+
+  state
+}
+
 examples! {
   examples:
   "hello-world", hello_world,
@@ -229,6 +373,12 @@ examples! {
   "skybox", skybox,
   "shader-data", shader_data,
   "stencil", stencil,
+  "many-instances", many_instances,
+  "mesh-orbit", mesh_orbit,
+  "runtime-shaders", runtime_shaders,
+  "point-sprites", point_sprites,
+  "primitives", primitives,
+  "two-sided", two_sided,
 
   // examples that do not use luminance-front but luminance polymorphic interface directly
   polymorphic examples:
@@ -243,6 +393,85 @@ examples! {
   "funtest-flatten-slice", funtest_flatten_slice,
   "funtest-pixel-array-encoding", funtest_pixel_array_encoding,
   "funtest-483-indices-mut-corruption", funtest_483_indices_mut_corruption,
+  "funtest-flip-winding", funtest_flip_winding,
+  "funtest-framebuffer-color-slot-count", funtest_framebuffer_color_slot_count,
+  "funtest-framebuffer-clear-region", funtest_framebuffer_clear_region,
+  "funtest-tess-index-slice", funtest_tess_index_slice,
+  "funtest-read-depth-linear", funtest_read_depth_linear,
+  "funtest-early-fragment-tests", funtest_early_fragment_tests,
+  "funtest-gl33-error-checking", funtest_gl33_error_checking,
+  "funtest-multisample-texture-resolve", funtest_multisample_texture_resolve,
+  "funtest-dynamic-tess", funtest_dynamic_tess,
+  "funtest-program-binary", funtest_program_binary,
+  "funtest-pipeline-origin", funtest_pipeline_origin,
+  "funtest-clip-distances", funtest_clip_distances,
+  "funtest-compressed-texture", funtest_compressed_texture,
+  "funtest-framebuffer-invalidate", funtest_framebuffer_invalidate,
+  "funtest-uniform-set-opt", funtest_uniform_set_opt,
+  "funtest-tess-interleaved-from", funtest_tess_interleaved_from,
+  "funtest-framebuffer-3d-slice", funtest_framebuffer_3d_slice,
+  "funtest-tess-raw-interleaved", funtest_tess_raw_interleaved,
+  "funtest-read-depth-raw", funtest_read_depth_raw,
+  "funtest-tess-usage", funtest_tess_usage,
+  "funtest-shader-data-multiple", funtest_shader_data_multiple,
+  "funtest-framebuffer-stencil-sampling", funtest_framebuffer_stencil_sampling,
+  "funtest-tess-normalized-override", funtest_tess_normalized_override,
+  "funtest-uniform-set-texture", funtest_uniform_set_texture,
+  "funtest-dynamic-framebuffer", funtest_dynamic_framebuffer,
+  "funtest-memory-barrier", funtest_memory_barrier,
+  "funtest-tess-render-repeated", funtest_tess_render_repeated,
+  "funtest-blend-enabled-buffers", funtest_blend_enabled_buffers,
+  "funtest-resource-loader", funtest_resource_loader,
+  "funtest-tess-grid-indices", funtest_tess_grid_indices,
+  "funtest-shader-include", funtest_shader_include,
+  "funtest-framebuffer-back-buffer", funtest_framebuffer_back_buffer,
+  "funtest-uniform-array-struct", funtest_uniform_array_struct,
+  "funtest-framebuffer-integer-picking", funtest_framebuffer_integer_picking,
+  "funtest-render-with-depth", funtest_render_with_depth,
+  "funtest-texture-mip-view", funtest_texture_mip_view,
+  "funtest-uniform-block-layout", funtest_uniform_block_layout,
+  "funtest-tess-debug-normals", funtest_tess_debug_normals,
+  "funtest-state-guard", funtest_state_guard,
+  "funtest-tess-deinterleaved-missing-attribute", funtest_tess_deinterleaved_missing_attribute,
+  "funtest-face-culling-mode", funtest_face_culling_mode,
+  "funtest-texture-swizzle", funtest_texture_swizzle,
+  "funtest-framebuffer-integer-clear", funtest_framebuffer_integer_clear,
+  "funtest-transform-feedback", funtest_transform_feedback,
+  "funtest-draw-buffers", funtest_draw_buffers,
+  "funtest-framebuffer-depth-copy", funtest_framebuffer_depth_copy,
+  "funtest-pipeline-stats-query", funtest_pipeline_stats_query,
+  "funtest-uniform-bool-from-int", funtest_uniform_bool_from_int,
+  "funtest-texture-unit-overflow", funtest_texture_unit_overflow,
+  "funtest-sample-shading", funtest_sample_shading,
+  "funtest-tess-to-deinterleaved", funtest_tess_to_deinterleaved,
+  "funtest-framebuffer-shared-depth", funtest_framebuffer_shared_depth,
+  "funtest-uniform-batch", funtest_uniform_batch,
+  "funtest-texture-pixel-alignment", funtest_texture_pixel_alignment,
+  "funtest-tess-base-instance", funtest_tess_base_instance,
+  "funtest-point-sprites", funtest_point_sprites,
+  "funtest-framebuffer-incomplete", funtest_framebuffer_incomplete,
+  "funtest-tess-vertex-checksum", funtest_tess_vertex_checksum,
+  "funtest-render-depth-prepass", funtest_render_depth_prepass,
+  "funtest-tess-update-attribute", funtest_tess_update_attribute,
+  "funtest-framebuffer-raw-handle", funtest_framebuffer_raw_handle,
+  "funtest-uniform-matrix-transposed", funtest_uniform_matrix_transposed,
+  "funtest-sprite-batch", funtest_sprite_batch,
+  "funtest-two-sided-lighting", funtest_two_sided_lighting,
+  "funtest-tess-index-type-overflow", funtest_tess_index_type_overflow,
+  "funtest-texture-buffer", funtest_texture_buffer,
+  "funtest-framebuffer-clear-colors", funtest_framebuffer_clear_colors,
+  "funtest-pipeline-target-size", funtest_pipeline_target_size,
+  "funtest-tess-restart-mode", funtest_tess_restart_mode,
+  "funtest-texture-mirror-clamp", funtest_texture_mirror_clamp,
+  "funtest-uniform-readback", funtest_uniform_readback,
+  "funtest-tess-vertex-count", funtest_tess_vertex_count,
+  "funtest-framebuffer-default-srgb", funtest_framebuffer_default_srgb,
+  "funtest-blending-hdr", funtest_blending_hdr,
+  "funtest-framebuffer-capture", funtest_framebuffer_capture,
+  "funtest-geometry-max-vertices", funtest_geometry_max_vertices,
+  "funtest-resource-loader-budget", funtest_resource_loader_budget,
+  "funtest-texture-stencil-index", funtest_texture_stencil_index,
+  "funtest-invalidate-state", funtest_invalidate_state,
 }
 
 fn main() {
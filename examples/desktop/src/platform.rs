@@ -2,22 +2,45 @@
 
 use crate::CLIOpts;
 use image::ImageError;
-use luminance_examples::PlatformServices;
-use std::{error::Error, fmt};
+use luminance_examples::{InputAction, PlatformServices};
+use std::{
+  collections::HashMap,
+  error::Error,
+  fmt, fs, io,
+  path::{Path, PathBuf},
+  time::SystemTime,
+};
 
 /// Desktop implementation of the [`PlatformServices`] API.
 #[derive(Debug)]
 pub struct DesktopPlatformServices {
   textures: Vec<image::RgbImage>,
+  assets_dir: PathBuf,
+  // fetched assets, along with their modification time to notify changes
+  assets: HashMap<String, Asset>,
+}
+
+#[derive(Debug)]
+struct Asset {
+  modified: Option<SystemTime>,
+  bytes: Vec<u8>,
+  // whether the asset couldn’t be reloaded, so that it’s only warned about once
+  unreadable: bool,
 }
 
 impl DesktopPlatformServices {
   pub fn new(cli_opts: CLIOpts) -> Self {
     let textures = cli_opts.textures;
+    let assets_dir = cli_opts
+      .assets
+      .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../common/src"));
+    let assets = HashMap::new();
 
     if textures.is_empty() {
       Self {
         textures: Vec::new(),
+        assets_dir,
+        assets,
       }
     } else {
       let textures = textures
@@ -29,15 +52,61 @@ impl DesktopPlatformServices {
         })
         .collect();
 
-      Self { textures }
+      Self {
+        textures,
+        assets_dir,
+        assets,
+      }
     }
   }
+
+  /// Reload the fetched assets which changed on disk since they were last read.
+  pub fn changed_assets(&mut self) -> Vec<InputAction> {
+    let mut actions = Vec::new();
+
+    for (name, asset) in &mut self.assets {
+      let path = self.assets_dir.join(name);
+      let modified = modified_time(&path);
+
+      if modified == asset.modified {
+        continue;
+      }
+
+      // the asset might be read while being written; try again on the next change
+      match fs::read(&path) {
+        Ok(bytes) => {
+          log::info!("asset {} changed", name);
+          asset.modified = modified;
+          asset.bytes = bytes.clone();
+          asset.unreadable = false;
+          actions.push(InputAction::AssetChanged {
+            name: name.clone(),
+            bytes,
+          });
+        }
+
+        Err(e) => {
+          if !asset.unreadable {
+            log::warn!("cannot reload asset {}: {}", name, e);
+            asset.unreadable = true;
+          }
+        }
+      }
+    }
+
+    actions
+  }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+  fs::metadata(path).and_then(|md| md.modified()).ok()
 }
 
 #[derive(Debug)]
 pub enum DesktopFetchError {
   NoMoreTexture,
   ImageError(ImageError),
+  IoError(io::Error),
 }
 
 impl fmt::Display for DesktopFetchError {
@@ -45,6 +114,7 @@ impl fmt::Display for DesktopFetchError {
     match self {
       DesktopFetchError::NoMoreTexture => f.write_str("no more texture, sorry"),
       DesktopFetchError::ImageError(ref e) => write!(f, "cannot fetch texture: {}", e),
+      DesktopFetchError::IoError(ref e) => write!(f, "cannot fetch asset: {}", e),
     }
   }
 }
@@ -57,6 +127,12 @@ impl From<ImageError> for DesktopFetchError {
   }
 }
 
+impl From<io::Error> for DesktopFetchError {
+  fn from(source: io::Error) -> Self {
+    Self::IoError(source)
+  }
+}
+
 impl PlatformServices for DesktopPlatformServices {
   type FetchError = DesktopFetchError;
 
@@ -67,4 +143,20 @@ impl PlatformServices for DesktopPlatformServices {
       Ok(self.textures.remove(0)) // bit of a cost but for small textures who cares?
     }
   }
+
+  fn fetch_bytes(&mut self, name: &str) -> Result<&[u8], Self::FetchError> {
+    let path = self.assets_dir.join(name);
+    let modified = modified_time(&path);
+    let bytes = fs::read(&path)?;
+    self.assets.insert(
+      name.to_owned(),
+      Asset {
+        modified,
+        bytes,
+        unreadable: false,
+      },
+    );
+
+    Ok(&self.assets[name].bytes)
+  }
 }
@@ -31,11 +31,20 @@ widgets.appendChild(example_select);
 let texture_input = document.createElement('input');
 widgets.appendChild(texture_input);
 
+// assets, separated by commas (leave empty for no asset)
+let assets_input = document.createElement('input');
+widgets.appendChild(assets_input);
+
 // run button
 let run_button = document.createElement('button')
 run_button.innerText = 'Run';
 widgets.appendChild(run_button);
 
+// reload button, to fetch the assets again
+let reload_button = document.createElement('button')
+reload_button.innerText = 'Reload';
+widgets.appendChild(reload_button);
+
 let canvas = document.createElement('canvas');
 canvas.tabIndex = 0;
 canvas.width = window.innerWidth;
@@ -48,6 +57,7 @@ document.body.appendChild(canvas);
 const reset_view = function() {
   canvas.hidden = true;
   texture_input.value = '';
+  assets_input.value = '';
   example_select.value = '';
   set_ready(null);
 }
@@ -65,6 +75,27 @@ rust
       example_select.add(option);
     });
 
+    // fetch the assets typed by the user and make them available to the example
+    const fetch_assets = function() {
+      const asset_names = assets_input.value.split(',').map(name => name.trim()).filter(name => name !== '');
+
+      return Promise.all(asset_names.map(name =>
+        fetch(name)
+          .then(res => res.blob())
+          .then(res => res.arrayBuffer())
+          .then(res => {
+            console.log('adding asset ' + name);
+            showcase.add_bytes(name, new Uint8Array(res));
+          })
+      ));
+    }
+
+    reload_button.onclick = () => {
+      fetch_assets().catch(error => {
+        console.error(error);
+      });
+    }
+
     // handle run
     run_button.onclick = () => {
       // check that something is selected
@@ -78,7 +109,8 @@ rust
       const texture_name = texture_input.value;
       if (texture_name !== '') {
         console.log('there’s a texture OMG, and it’s ' + texture_name);
-        fetch(texture_name)
+        fetch_assets()
+          .then(() => fetch(texture_name))
           .then(res => res.blob())
           .then(res => res.arrayBuffer())
           .then(res => {
@@ -91,9 +123,14 @@ rust
             console.error(error);
           });
       } else {
-        // set it ready right away
-        set_ready(example_name);
-        canvas.hidden = false;
+        // set it ready once the assets are fetched
+        fetch_assets()
+          .then(() => {
+            set_ready(example_name);
+            canvas.hidden = false;
+          }).catch(error => {
+            console.error(error);
+          });
       }
 
     }
@@ -137,6 +174,10 @@ rust
           showcase.enqueue_down_action();
           break;
 
+        case 'F12':
+          showcase.enqueue_screenshot_action();
+          break;
+
         default:
       }
     });
@@ -153,6 +194,10 @@ rust
       showcase.enqueue_cursor_moved_action(value.x, value.y);
     };
 
+    document.onmouseleave = () => {
+      showcase.enqueue_cursor_left_action();
+    };
+
     window.onmouseup = (value) => {
       if (value.button == 0) {
         showcase.enqueue_primary_released_action();
@@ -3,9 +3,10 @@
 mod platform;
 
 use crate::platform::WebPlatformServices;
-use luminance_examples::{Example as _, InputAction, LoopFeedback};
+use luminance_examples::{DragTracker, Example as _, InputAction, LoopFeedback};
 use luminance_web_sys::WebSysWebGL2Surface;
 use wasm_bindgen::prelude::*;
+use web_sys::HtmlAnchorElement;
 
 /// Macro to declaratively add examples.
 macro_rules! examples {
@@ -37,6 +38,7 @@ macro_rules! examples {
       platform: WebPlatformServices,
       surface: WebSysWebGL2Surface,
       actions: Vec<InputAction>,
+      drag: DragTracker,
       $( $test_ident: Option<luminance_examples::$test_ident::LocalExample> ),*,
       $( #[cfg(all(feature = "funtest", $(feature = $fun_feature_gate)?))] $fun_ident: Option<luminance_examples::$fun_ident::LocalExample> ),*,
     }
@@ -46,6 +48,7 @@ macro_rules! examples {
       fn new(surface: WebSysWebGL2Surface) -> Self {
         let platform = WebPlatformServices::new();
         let actions = Vec::new();
+        let drag = DragTracker::new();
         $(
           let $test_ident = None;
         )*
@@ -58,6 +61,7 @@ macro_rules! examples {
           platform,
           surface,
           actions,
+          drag,
           $( $test_ident ),*,
           $( #[cfg(all(feature = "funtest", $(feature = $fun_feature_gate)?))] $fun_ident ),*
         }
@@ -68,11 +72,11 @@ macro_rules! examples {
       }
 
       pub fn enqueue_primary_pressed_action(&mut self) {
-        self.actions.push(InputAction::PrimaryPressed);
+        self.enqueue_tracked_action(InputAction::PrimaryPressed);
       }
 
       pub fn enqueue_primary_released_action(&mut self) {
-        self.actions.push(InputAction::PrimaryReleased);
+        self.enqueue_tracked_action(InputAction::PrimaryReleased);
       }
 
       pub fn enqueue_main_toggle_action(&mut self) {
@@ -112,13 +116,28 @@ macro_rules! examples {
       }
 
       pub fn enqueue_cursor_moved_action(&mut self, x: f32, y: f32) {
-        self.actions.push(InputAction::CursorMoved { x, y });
+        self.enqueue_tracked_action(InputAction::CursorMoved { x, y });
+      }
+
+      pub fn enqueue_cursor_left_action(&mut self) {
+        self.drag.cursor_left();
+      }
+
+      /// Enqueue an action and the drag it might synthesize.
+      fn enqueue_tracked_action(&mut self, action: InputAction) {
+        let dragged = self.drag.track(&action);
+        self.actions.push(action);
+        self.actions.extend(dragged);
       }
 
       pub fn enqueue_vscroll_action(&mut self, amount: f32) {
         self.actions.push(InputAction::VScroll { amount });
       }
 
+      pub fn enqueue_screenshot_action(&mut self) {
+        self.actions.push(InputAction::Screenshot);
+      }
+
       /// Cleanup all examples.
       pub fn reset(&mut self) {
         $(
@@ -139,7 +158,15 @@ macro_rules! examples {
         self.platform.add_texture(blob);
       }
 
+      pub fn add_bytes(&mut self, name: String, blob: Vec<u8>) {
+        if let Some(action) = self.platform.add_bytes(name, blob) {
+          self.actions.push(action);
+        }
+      }
+
       pub fn render_example(&mut self, name: &str, time: f32) -> bool {
+        let screenshot = self.actions.iter().any(|action| matches!(action, InputAction::Screenshot));
+
         // first, check whether the example exists
         match name {
           $(
@@ -222,6 +249,13 @@ macro_rules! examples {
           _ => ()
         }
 
+        // the canvas must be read before the frame is presented, as its drawing buffer is not preserved
+        if screenshot {
+          if let Err(e) = download_canvas(&self.surface, &format!("{}.png", name)) {
+            log::error!("cannot capture {}: {:?}", name, e);
+          }
+        }
+
         true
       }
     }
@@ -247,6 +281,12 @@ examples! {
   "skybox", skybox,
   "shader-data", shader_data,
   "stencil", stencil,
+  "many-instances", many_instances,
+  "mesh-orbit", mesh_orbit,
+  "runtime-shaders", runtime_shaders,
+  "point-sprites", point_sprites,
+  "primitives", primitives,
+  "two-sided", two_sided,
 
   funtests:
   "funtest-tess-no-data", funtest_tess_no_data,
@@ -255,6 +295,72 @@ examples! {
   "funtest-flatten-slice", funtest_flatten_slice,
   "funtest-pixel-array-encoding", funtest_pixel_array_encoding,
   "funtest-483-indices-mut-corruption", funtest_483_indices_mut_corruption,
+  "funtest-flip-winding", funtest_flip_winding,
+  "funtest-framebuffer-color-slot-count", funtest_framebuffer_color_slot_count,
+  "funtest-framebuffer-clear-region", funtest_framebuffer_clear_region,
+  "funtest-tess-index-slice", funtest_tess_index_slice,
+  "funtest-dynamic-tess", funtest_dynamic_tess,
+  "funtest-pipeline-origin", funtest_pipeline_origin,
+  "funtest-framebuffer-invalidate", funtest_framebuffer_invalidate,
+  "funtest-uniform-set-opt", funtest_uniform_set_opt,
+  "funtest-tess-interleaved-from", funtest_tess_interleaved_from,
+  "funtest-framebuffer-3d-slice", funtest_framebuffer_3d_slice,
+  "funtest-tess-raw-interleaved", funtest_tess_raw_interleaved,
+  "funtest-tess-usage", funtest_tess_usage,
+  "funtest-shader-data-multiple", funtest_shader_data_multiple,
+  "funtest-uniform-set-texture", funtest_uniform_set_texture,
+  "funtest-tess-render-repeated", funtest_tess_render_repeated,
+  "funtest-tess-grid-indices", funtest_tess_grid_indices,
+  "funtest-shader-include", funtest_shader_include,
+  "funtest-framebuffer-back-buffer", funtest_framebuffer_back_buffer,
+  "funtest-uniform-array-struct", funtest_uniform_array_struct,
+  "funtest-framebuffer-integer-picking", funtest_framebuffer_integer_picking,
+  "funtest-render-with-depth", funtest_render_with_depth,
+  "funtest-uniform-block-layout", funtest_uniform_block_layout,
+  "funtest-tess-debug-normals", funtest_tess_debug_normals,
+  "funtest-state-guard", funtest_state_guard,
+  "funtest-tess-deinterleaved-missing-attribute", funtest_tess_deinterleaved_missing_attribute,
+  "funtest-face-culling-mode", funtest_face_culling_mode,
+  "funtest-framebuffer-integer-clear", funtest_framebuffer_integer_clear,
+  "funtest-transform-feedback", funtest_transform_feedback,
+  "funtest-draw-buffers", funtest_draw_buffers,
+  "funtest-uniform-bool-from-int", funtest_uniform_bool_from_int,
+  "funtest-texture-unit-overflow", funtest_texture_unit_overflow,
+  "funtest-tess-to-deinterleaved", funtest_tess_to_deinterleaved,
+  "funtest-framebuffer-shared-depth", funtest_framebuffer_shared_depth,
+  "funtest-uniform-batch", funtest_uniform_batch,
+  "funtest-texture-pixel-alignment", funtest_texture_pixel_alignment,
+  "funtest-tess-base-instance", funtest_tess_base_instance,
+  "funtest-point-sprites", funtest_point_sprites,
+  "funtest-framebuffer-incomplete", funtest_framebuffer_incomplete,
+  "funtest-tess-vertex-checksum", funtest_tess_vertex_checksum,
+  "funtest-render-depth-prepass", funtest_render_depth_prepass,
+  "funtest-tess-update-attribute", funtest_tess_update_attribute,
+  "funtest-uniform-matrix-transposed", funtest_uniform_matrix_transposed,
+  "funtest-texture-from-image", funtest_texture_from_image,
+  "funtest-sprite-batch", funtest_sprite_batch,
+  "funtest-two-sided-lighting", funtest_two_sided_lighting,
+  "funtest-tess-index-type-overflow", funtest_tess_index_type_overflow,
+  "funtest-framebuffer-clear-colors", funtest_framebuffer_clear_colors,
+  "funtest-pipeline-target-size", funtest_pipeline_target_size,
+  "funtest-tess-restart-mode", funtest_tess_restart_mode,
+  "funtest-tess-vertex-count", funtest_tess_vertex_count,
+  "funtest-resource-loader-budget", funtest_resource_loader_budget,
+}
+
+/// Download the content of the canvas as a PNG file named `file_name`.
+fn download_canvas(surface: &WebSysWebGL2Surface, file_name: &str) -> Result<(), JsValue> {
+  let url = surface.canvas.to_data_url_with_type("image/png")?;
+  let document = surface.window.document().ok_or("no document")?;
+  let anchor = document
+    .create_element("a")?
+    .dyn_into::<HtmlAnchorElement>()?;
+
+  anchor.set_href(&url);
+  anchor.set_download(file_name);
+  anchor.click();
+
+  Ok(())
 }
 
 #[wasm_bindgen]
@@ -1,19 +1,29 @@
 //! Platform services implementation.
 
 use image::ImageError;
-use luminance_examples::PlatformServices;
+use luminance_examples::{AssetStore, InputAction, PlatformServices};
 use std::{error::Error, fmt};
 
 /// Web implementation of the [`PlatformService`] API.
 #[derive(Debug)]
 pub struct WebPlatformServices {
   textures: Vec<image::RgbImage>,
+  assets: AssetStore,
 }
 
 impl WebPlatformServices {
   pub fn new() -> Self {
     let textures = Vec::new();
-    Self { textures }
+    let assets = AssetStore::new();
+    Self { textures, assets }
+  }
+
+  /// Add the asset `name`, replacing it if it was already added.
+  ///
+  /// If the asset is replaced, the returned action notifies the running example about it.
+  pub fn add_bytes(&mut self, name: String, blob: Vec<u8>) -> Option<InputAction> {
+    log::info!("added asset {}", name);
+    self.assets.add(name, blob)
   }
 
   pub fn add_texture(&mut self, blob: Vec<u8>) {
@@ -31,6 +41,7 @@ impl WebPlatformServices {
 pub enum WebFetchError {
   NoMoreTexture,
   ImageError(ImageError),
+  NoSuchAsset(String),
 }
 
 impl fmt::Display for WebFetchError {
@@ -38,6 +49,7 @@ impl fmt::Display for WebFetchError {
     match self {
       WebFetchError::NoMoreTexture => f.write_str("no more texture, sorry"),
       WebFetchError::ImageError(ref e) => write!(f, "cannot fetch texture: {}", e),
+      WebFetchError::NoSuchAsset(ref name) => write!(f, "asset {} was not added", name),
     }
   }
 }
@@ -60,4 +72,11 @@ impl PlatformServices for WebPlatformServices {
       Ok(self.textures.remove(0)) // bit of a cost but for small textures who cares?
     }
   }
+
+  fn fetch_bytes(&mut self, name: &str) -> Result<&[u8], Self::FetchError> {
+    self
+      .assets
+      .get(name)
+      .ok_or_else(|| WebFetchError::NoSuchAsset(name.to_owned()))
+  }
 }
@@ -1 +1,3 @@
-pub use luminance::context::GraphicsContext;
+pub use luminance::context::{
+  resource_loader, GraphicsContext, StateGuard, StateSnapshot, VSyncError, VSyncMode,
+};
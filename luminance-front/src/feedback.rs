@@ -0,0 +1,5 @@
+use crate::Backend;
+
+pub use luminance::feedback::{FeedbackError, FeedbackMode};
+
+pub type FeedbackBuffer<T> = luminance::feedback::FeedbackBuffer<Backend, T>;
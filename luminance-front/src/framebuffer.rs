@@ -1,4 +1,7 @@
 use crate::Backend;
 
 pub type Framebuffer<D, CS, DS> = luminance::framebuffer::Framebuffer<Backend, D, CS, DS>;
-pub use luminance::framebuffer::{FramebufferError, IncompleteReason};
+pub type DynamicFramebuffer<D> = luminance::framebuffer::DynamicFramebuffer<Backend, D>;
+pub type DynamicTexture<D> = luminance::framebuffer::DynamicTexture<Backend, D>;
+pub type FramebufferSlice<'a, DS> = luminance::framebuffer::FramebufferSlice<'a, Backend, DS>;
+pub use luminance::framebuffer::{Attachment, FramebufferError, IncompleteReason};
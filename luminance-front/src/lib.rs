@@ -60,6 +60,7 @@
 //! [luminance]: https://crates.io/crates/luminance
 
 pub mod context;
+pub mod feedback;
 pub mod framebuffer;
 pub mod pipeline;
 pub mod query;
@@ -73,7 +74,9 @@ pub mod texture;
 // re-export
 pub use luminance::blending;
 pub use luminance::depth_stencil;
+pub use luminance::error_check;
 pub use luminance::face_culling;
+pub use luminance::memory_barrier;
 pub use luminance::pixel;
 pub use luminance::render_state;
 pub use luminance::scissor;
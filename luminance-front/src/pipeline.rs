@@ -1,11 +1,15 @@
 use crate::Backend;
 
 pub use luminance::pipeline::{
-  PipelineError, PipelineState, ShaderDataBinding, TextureBinding, Viewport,
+  Origin, PipelineError, PipelineState, ShaderDataBinding, TextureBinding, TextureBufferBinding,
+  Viewport,
 };
 
 pub type Pipeline<'a> = luminance::pipeline::Pipeline<'a, Backend>;
 pub type PipelineGate<'a> = luminance::pipeline::PipelineGate<'a, Backend>;
 pub type BoundTexture<'a, D, P> = luminance::pipeline::BoundTexture<'a, Backend, D, P>;
+pub type BoundStencilTexture<'a, D, P> =
+  luminance::pipeline::BoundStencilTexture<'a, Backend, D, P>;
+pub type BoundTextureBuffer<'a, P> = luminance::pipeline::BoundTextureBuffer<'a, Backend, P>;
 pub type BoundShaderData<'a, T> = luminance::pipeline::BoundShaderData<'a, Backend, T>;
 pub type Render<E> = luminance::pipeline::Render<E>;
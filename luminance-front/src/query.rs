@@ -1,3 +1,6 @@
 use crate::Backend;
 
+pub use luminance::{backend::query::QueryError, query::PipelineStats};
+
 pub type Query<'a> = luminance::query::Query<'a, Backend>;
+pub type PipelineStatsQuery = luminance::query::PipelineStatsQuery<Backend>;
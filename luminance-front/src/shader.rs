@@ -3,8 +3,9 @@ use crate::Backend;
 pub mod types;
 
 pub use luminance::shader::{
-  ProgramError, ProgramWarning, ShaderDataError, StageError, StageType, TessellationStages,
-  Uniform, UniformInterface, UniformType, UniformWarning, VertexAttribWarning,
+  preprocessor, BlockLayout, DepthOnlyInterface, ProgramError, ProgramWarning, ShaderDataError,
+  StageError, StageType, TessellationStages, Uniform, UniformArrayField, UniformInterface,
+  UniformType, UniformWarning, VertexAttribWarning,
 };
 
 pub type Stage = luminance::shader::Stage<Backend>;
@@ -13,5 +14,6 @@ pub type BuiltProgram<Sem, Out, Uni> = luminance::shader::BuiltProgram<Backend,
 pub type AdaptationFailure<Sem, Out, Uni> =
   luminance::shader::AdaptationFailure<Backend, Sem, Out, Uni>;
 pub type ProgramInterface<'a> = luminance::shader::ProgramInterface<'a, Backend>;
+pub type UniformBatch<'u> = luminance::shader::UniformBatch<'u, Backend>;
 pub type Program<Sem, Out, Uni> = luminance::shader::Program<Backend, Sem, Out, Uni>;
 pub type ShaderData<T> = luminance::shader::ShaderData<Backend, T>;
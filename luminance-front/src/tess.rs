@@ -1,13 +1,14 @@
 use crate::Backend;
 
 pub use luminance::tess::{
-  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndexType, TessMapError,
-  TessViewError, View,
+  Deinterleaved, DeinterleavedData, Interleaved, Mode, RawAttribDesc, RawInterleaved,
+  RawVertexData, TessError, TessIndexType, TessMapError, TessUsage, TessViewError, View,
 };
 
 pub type TessBuilder<'a, V, I = (), W = (), S = Interleaved> =
   luminance::tess::TessBuilder<'a, Backend, V, I, W, S>;
 pub type Tess<V, I = (), W = (), S = Interleaved> = luminance::tess::Tess<Backend, V, I, W, S>;
+pub type DynamicTess<V, I = ()> = luminance::tess::DynamicTess<Backend, V, I>;
 pub type Vertices<'a, V, I, W, S, T> = luminance::tess::Vertices<'a, Backend, V, I, W, S, T>;
 pub type VerticesMut<'a, V, I, W, S, T> = luminance::tess::VerticesMut<'a, Backend, V, I, W, S, T>;
 pub type Indices<'a, V, I, W, S> = luminance::tess::Indices<'a, Backend, V, I, W, S>;
@@ -1,3 +1,5 @@
 use crate::Backend;
 
+pub use luminance::tess_gate::DebugMode;
+
 pub type TessGate<'a> = luminance::tess_gate::TessGate<'a, Backend>;
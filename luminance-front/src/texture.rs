@@ -1,8 +1,10 @@
 use crate::Backend;
 
 pub use luminance::texture::{
-  CubeFace, Cubemap, Dim, Dim1, Dim1Array, Dim2, Dim2Array, Dim3, Dimensionable, MagFilter,
-  MinFilter, Sampler, TexelUpload, TextureError, Wrap,
+  CubeFace, Cubemap, Dim, Dim1, Dim1Array, Dim2, Dim2Array, Dim2Multisample, Dim3, Dimensionable,
+  MagFilter, MinFilter, Sampler, Swizzle, TexelUpload, TextureError, Wrap,
 };
 
 pub type Texture<D, P> = luminance::texture::Texture<Backend, D, P>;
+pub type TextureView<'a, D, P> = luminance::texture::TextureView<'a, Backend, D, P>;
+pub type TextureBuffer<P> = luminance::texture::TextureBuffer<Backend, P>;
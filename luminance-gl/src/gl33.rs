@@ -3,8 +3,12 @@
 //! This module implements an OpenGL 3.3 backend for luminance. The backend type is [`GL33`].
 
 mod buffer;
+mod debug;
 mod depth_stencil;
+mod error_check;
+mod feedback;
 mod framebuffer;
+mod memory_barrier;
 mod pipeline;
 mod pixel;
 mod query;
@@ -14,8 +18,13 @@ mod tess;
 mod texture;
 mod vertex_restart;
 
+use self::debug::DebugPrograms;
 pub use self::state::GLState;
 pub use self::state::StateQueryError;
+use luminance::{
+  backend::state::{InvalidateState, SaveState},
+  context::StateSnapshot,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -25,6 +34,7 @@ use std::rc::Rc;
 #[derive(Debug)]
 pub struct GL33 {
   pub(crate) state: Rc<RefCell<GLState>>,
+  debug_programs: DebugPrograms,
 }
 
 impl GL33 {
@@ -32,6 +42,7 @@ impl GL33 {
   pub fn new() -> Result<Self, StateQueryError> {
     GLState::new().map(|state| GL33 {
       state: Rc::new(RefCell::new(state)),
+      debug_programs: DebugPrograms::default(),
     })
   }
 
@@ -45,3 +56,32 @@ impl GL33 {
     &self.state
   }
 }
+
+// the backend lives as long as the OpenGL context it was created with; resources still alive at this point will then
+// skip releasing their OpenGL objects
+impl Drop for GL33 {
+  fn drop(&mut self) {
+    let mut state = self.state.borrow_mut();
+
+    // the debug message callback writes into the state, which might not outlive the context; unregister it while the
+    // context is still alive
+    unsafe { state.set_error_checking(false) };
+    state.mark_context_dead();
+  }
+}
+
+unsafe impl InvalidateState for GL33 {
+  unsafe fn invalidate_state(&mut self) {
+    self.state.borrow_mut().invalidate_all();
+  }
+}
+
+unsafe impl SaveState for GL33 {
+  unsafe fn save_state(&mut self) -> StateSnapshot {
+    self.state.borrow().snapshot()
+  }
+
+  unsafe fn restore_state(&mut self, snapshot: &StateSnapshot) {
+    self.state.borrow_mut().restore(snapshot);
+  }
+}
@@ -11,6 +11,7 @@ use std::{
   cell::RefCell,
   error, fmt, mem,
   ops::{Deref, DerefMut},
+  ptr,
   rc::Rc,
   slice,
 };
@@ -48,8 +49,18 @@ struct BufferWrapper {
 
 impl Drop for BufferWrapper {
   fn drop(&mut self) {
+    let mut state = self.state.borrow_mut();
+
+    if !state.is_context_alive() {
+      log::debug!(
+        "buffer {} dropped after its context; skipping deletion",
+        self.handle
+      );
+      return;
+    }
+
     unsafe {
-      self.state.borrow_mut().unbind_buffer(self.handle);
+      state.unbind_buffer(self.handle);
       gl::DeleteBuffers(1, &self.handle);
     }
   }
@@ -60,11 +71,19 @@ impl Drop for BufferWrapper {
 pub struct Buffer<T> {
   /// A cached version of the GPU buffer; emulate persistent mapping.
   pub(crate) buf: Vec<T>,
+  /// Number of elements the GPU storage can hold.
+  capacity: usize,
+  /// Usage hint the GPU storage is (re)allocated with.
+  usage: GLenum,
   gl_buf: BufferWrapper,
 }
 
 impl<T> Buffer<T> {
   pub(crate) unsafe fn from_vec(gl33: &mut GL33, vec: Vec<T>) -> Self {
+    Self::from_vec_with_usage(gl33, vec, gl::STREAM_DRAW)
+  }
+
+  pub(crate) unsafe fn from_vec_with_usage(gl33: &mut GL33, vec: Vec<T>, usage: GLenum) -> Self {
     let mut handle: GLuint = 0;
 
     gl::GenBuffers(1, &mut handle);
@@ -75,22 +94,95 @@ impl<T> Buffer<T> {
 
     let len = vec.len();
     let bytes = mem::size_of::<T>() * len;
-    gl::BufferData(
-      gl::ARRAY_BUFFER,
-      bytes as isize,
-      vec.as_ptr() as _,
-      gl::STREAM_DRAW,
-    );
+    gl::BufferData(gl::ARRAY_BUFFER, bytes as isize, vec.as_ptr() as _, usage);
     let state = gl33.state.clone();
     let gl_buf = BufferWrapper { handle, state };
 
-    Buffer { gl_buf, buf: vec }
+    Buffer {
+      gl_buf,
+      buf: vec,
+      capacity: len,
+      usage,
+    }
   }
 
   pub(crate) fn handle(&self) -> GLuint {
     self.gl_buf.handle
   }
 
+  /// Orphan the GPU storage and replace its content with `data`, growing the storage if needed.
+  ///
+  /// The buffer must be bound to `target`.
+  pub(crate) unsafe fn orphan_and_upload(&mut self, target: GLenum, data: &[T])
+  where
+    T: Copy,
+  {
+    if data.len() > self.capacity {
+      self.capacity = data.len().next_power_of_two();
+    }
+
+    let elem_bytes = mem::size_of::<T>();
+    gl::BufferData(
+      target,
+      (self.capacity * elem_bytes) as isize,
+      ptr::null(),
+      self.usage,
+    );
+    gl::BufferSubData(
+      target,
+      0,
+      mem::size_of_val(data) as isize,
+      data.as_ptr() as _,
+    );
+
+    self.buf.clear();
+    self.buf.extend_from_slice(data);
+  }
+
+  /// Replace the content of the GPU storage with `data`, which must have the same length as the buffer.
+  pub(crate) unsafe fn update(&mut self, data: &[T])
+  where
+    T: Copy,
+  {
+    self
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(self.handle(), Bind::Cached);
+
+    gl::BufferSubData(
+      gl::ARRAY_BUFFER,
+      0,
+      mem::size_of_val(data) as isize,
+      data.as_ptr() as _,
+    );
+
+    self.buf.copy_from_slice(data);
+  }
+
+  /// Reallocate the GPU storage so that it holds exactly `data`, which length might differ from the buffer’s.
+  pub(crate) unsafe fn reallocate(&mut self, data: &[T])
+  where
+    T: Copy,
+  {
+    self
+      .gl_buf
+      .state
+      .borrow_mut()
+      .bind_array_buffer(self.handle(), Bind::Cached);
+
+    gl::BufferData(
+      gl::ARRAY_BUFFER,
+      mem::size_of_val(data) as isize,
+      data.as_ptr() as _,
+      self.usage,
+    );
+
+    self.capacity = data.len();
+    self.buf.clear();
+    self.buf.extend_from_slice(data);
+  }
+
   /// Length of the buffer (number of elements).
   #[inline]
   pub fn len(&self) -> usize {
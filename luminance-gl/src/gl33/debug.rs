@@ -0,0 +1,194 @@
+//! Debug rendering of tessellations.
+//!
+//! The debug programs are provided by the backend and built lazily, the first time a given debug mode is rendered.
+//! Because they read vertex attributes from the user’s tessellations, they are linked with the attribute locations of
+//! the semantics they read, and are then cached per set of locations.
+
+use crate::gl33::{shader::Program, GL33};
+use gl::{self, types::*};
+use luminance::{
+  backend::shader::Shader as _,
+  shader::{ProgramError, StageType},
+  tess_gate::DebugMode,
+};
+use std::{collections::HashMap, ffi::CString};
+
+const WIREFRAME_VS: &str = "
+in vec4 position;
+
+uniform mat4 transform;
+
+void main() {
+  gl_Position = transform * vec4(position.xyz, 1.);
+}";
+
+const VECTORS_VS: &str = "
+in vec4 position;
+in vec3 vector;
+
+out vec3 v_vector;
+
+void main() {
+  gl_Position = vec4(position.xyz, 1.);
+  v_vector = vector;
+}";
+
+const VECTORS_GS: &str = "
+layout (points) in;
+layout (line_strip, max_vertices = 2) out;
+
+in vec3 v_vector[];
+
+uniform mat4 transform;
+uniform float vector_length;
+
+void main() {
+  vec4 origin = gl_in[0].gl_Position;
+
+  gl_Position = transform * origin;
+  EmitVertex();
+
+  gl_Position = transform * (origin + vec4(v_vector[0] * vector_length, 0.));
+  EmitVertex();
+
+  EndPrimitive();
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec4 color;
+
+void main() {
+  frag = color;
+}";
+
+const WIREFRAME_COLOR: [f32; 4] = [1., 1., 1., 1.];
+const NORMALS_COLOR: [f32; 4] = [0.2, 0.5, 1., 1.];
+const TANGENTS_COLOR: [f32; 4] = [1., 0.3, 0.2, 1.];
+
+/// Debug programs, indexed by the location of the position attribute and, for vectors, the location of the vector
+/// attribute.
+///
+/// Programs that failed to build are cached as [`None`] so that they are not rebuilt every frame.
+#[derive(Debug, Default)]
+pub(crate) struct DebugPrograms {
+  programs: HashMap<(usize, Option<usize>), Option<DebugProgram>>,
+}
+
+#[derive(Debug)]
+struct DebugProgram {
+  program: Program,
+  transform: GLint,
+  length: GLint,
+  color: GLint,
+}
+
+impl DebugProgram {
+  unsafe fn new(
+    gl33: &mut GL33,
+    position: usize,
+    vector: Option<usize>,
+  ) -> Result<Self, ProgramError> {
+    let fragment = gl33.new_stage(StageType::FragmentShader, FS)?;
+
+    let program = if vector.is_some() {
+      let vertex = gl33.new_stage(StageType::VertexShader, VECTORS_VS)?;
+      let geometry = gl33.new_stage(StageType::GeometryShader, VECTORS_GS)?;
+      gl33.new_program(&vertex, None, Some(&geometry), &fragment)?
+    } else {
+      let vertex = gl33.new_stage(StageType::VertexShader, WIREFRAME_VS)?;
+      gl33.new_program(&vertex, None, None, &fragment)?
+    };
+
+    // bind the attributes to the locations of the semantics and link again to apply them
+    gl::BindAttribLocation(
+      program.handle,
+      position as GLuint,
+      b"position\0".as_ptr() as *const GLchar,
+    );
+
+    if let Some(vector) = vector {
+      gl::BindAttribLocation(
+        program.handle,
+        vector as GLuint,
+        b"vector\0".as_ptr() as *const GLchar,
+      );
+    }
+
+    program.link()?;
+
+    let location = |name: &str| {
+      let c_name = CString::new(name).unwrap();
+      gl::GetUniformLocation(program.handle, c_name.as_ptr() as *const GLchar)
+    };
+    let transform = location("transform");
+    let length = location("vector_length");
+    let color = location("color");
+
+    Ok(DebugProgram {
+      program,
+      transform,
+      length,
+      color,
+    })
+  }
+}
+
+/// Use the debug program for `mode`, run `render` and use the previous program again.
+///
+/// `render` is passed whether the wireframe is to be rendered. Nothing is rendered if the debug program cannot be built.
+pub(crate) unsafe fn with_debug_program(
+  gl33: &mut GL33,
+  mode: DebugMode<usize>,
+  transform: [[f32; 4]; 4],
+  render: impl FnOnce(bool),
+) {
+  let (position, vector, length, color) = match mode {
+    DebugMode::Wireframe { position } => (position, None, 0., WIREFRAME_COLOR),
+    DebugMode::Normals {
+      position,
+      normal,
+      length,
+    } => (position, Some(normal), length, NORMALS_COLOR),
+    DebugMode::Tangents {
+      position,
+      tangent,
+      length,
+    } => (position, Some(tangent), length, TANGENTS_COLOR),
+  };
+
+  let key = (position, vector);
+
+  if !gl33.debug_programs.programs.contains_key(&key) {
+    let program = DebugProgram::new(gl33, position, vector)
+      .map_err(|e| log::warn!("cannot build debug program: {}", e))
+      .ok();
+    gl33.debug_programs.programs.insert(key, program);
+  }
+
+  let program = match gl33.debug_programs.programs.get(&key) {
+    Some(Some(program)) => program,
+    _ => return,
+  };
+
+  let previous = {
+    let mut state = gl33.state.borrow_mut();
+    let previous = state.current_program();
+    state.use_program(program.program.handle);
+    previous
+  };
+
+  gl::UniformMatrix4fv(
+    program.transform,
+    1,
+    gl::FALSE,
+    transform.as_ptr() as *const GLfloat,
+  );
+  gl::Uniform1f(program.length, length);
+  gl::Uniform4fv(program.color, 1, color.as_ptr());
+
+  render(vector.is_none());
+
+  gl33.state.borrow_mut().use_program(previous);
+}
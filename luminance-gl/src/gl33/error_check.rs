@@ -0,0 +1,110 @@
+//! Error checking implementation for OpenGL 3.3.
+//!
+//! When error checking is enabled, errors are detected in two possible ways:
+//!
+//! - If `KHR_debug` is available, a debug message callback is installed and errors are reported as soon as the driver
+//!   notices them.
+//! - Otherwise, `glGetError` is polled after each significant operation.
+
+use crate::gl33::GL33;
+use gl::types::*;
+use luminance::{
+  backend::error_check::ErrorCheck,
+  error_check::{BackendError, BackendErrorKind, ErrorLog},
+};
+use std::{
+  cell::RefCell,
+  ffi::CStr,
+  os::raw::{c_char, c_void},
+  slice,
+};
+
+unsafe impl ErrorCheck for GL33 {
+  fn set_error_checking(&mut self, enabled: bool) {
+    unsafe { self.state.borrow_mut().set_error_checking(enabled) }
+  }
+
+  fn is_error_checking(&self) -> bool {
+    self.state.borrow().is_error_checking()
+  }
+
+  fn take_errors(&mut self) -> Vec<BackendError> {
+    self.state.borrow_mut().take_errors()
+  }
+}
+
+/// Map an OpenGL error code to a [`BackendErrorKind`].
+pub(crate) fn glenum_to_error_kind(code: GLenum) -> BackendErrorKind {
+  match code {
+    gl::INVALID_ENUM => BackendErrorKind::InvalidEnum,
+    gl::INVALID_VALUE => BackendErrorKind::InvalidValue,
+    gl::INVALID_OPERATION => BackendErrorKind::InvalidOperation,
+    gl::INVALID_FRAMEBUFFER_OPERATION => BackendErrorKind::InvalidFramebufferOperation,
+    gl::OUT_OF_MEMORY => BackendErrorKind::OutOfMemory,
+    gl::STACK_OVERFLOW => BackendErrorKind::StackOverflow,
+    gl::STACK_UNDERFLOW => BackendErrorKind::StackUnderflow,
+    _ => BackendErrorKind::Unknown(code),
+  }
+}
+
+/// Check whether the `KHR_debug` extension is available in the current context.
+pub(crate) unsafe fn has_khr_debug() -> bool {
+  if !gl::DebugMessageCallback::is_loaded() {
+    return false;
+  }
+
+  has_extension(b"GL_KHR_debug")
+}
+
+/// Check whether an extension, given by its full name, is available in the current context.
+pub(crate) unsafe fn has_extension(name: &[u8]) -> bool {
+  let mut nb: GLint = 0;
+  gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut nb);
+
+  (0..nb.max(0) as GLuint).any(|i| {
+    let ptr = gl::GetStringi(gl::EXTENSIONS, i);
+    !ptr.is_null() && CStr::from_ptr(ptr as *const c_char).to_bytes() == name
+  })
+}
+
+/// Debug message callback installed when `KHR_debug` is available.
+///
+/// The user parameter is a pointer to the `RefCell<ErrorLog>` owned by the graphics state.
+pub(crate) extern "system" fn debug_message_callback(
+  _source: GLenum,
+  ty: GLenum,
+  _id: GLuint,
+  severity: GLenum,
+  length: GLsizei,
+  message: *const GLchar,
+  user_param: *mut c_void,
+) {
+  // we are only interested in errors; the rest is just noise for our use case
+  if ty != gl::DEBUG_TYPE_ERROR && severity != gl::DEBUG_SEVERITY_HIGH {
+    return;
+  }
+
+  if message.is_null() || user_param.is_null() {
+    return;
+  }
+
+  let msg = unsafe {
+    if length < 0 {
+      CStr::from_ptr(message as *const c_char)
+        .to_string_lossy()
+        .into_owned()
+    } else {
+      let bytes = slice::from_raw_parts(message as *const u8, length as usize);
+      String::from_utf8_lossy(bytes).into_owned()
+    }
+  };
+
+  let errors = unsafe { &*(user_param as *const RefCell<ErrorLog>) };
+
+  if let Ok(mut errors) = errors.try_borrow_mut() {
+    errors.push(BackendError::new(
+      "debug output",
+      BackendErrorKind::DebugMessage(msg),
+    ));
+  }
+}
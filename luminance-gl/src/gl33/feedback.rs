@@ -0,0 +1,160 @@
+//! Transform feedback support for OpenGL 3.3.
+
+use crate::gl33::{buffer::Buffer, GL33};
+use gl::types::*;
+use luminance::{
+  backend::{
+    feedback::{FeedbackBuffer, TessGateFeedback},
+    tess_gate::TessGate,
+  },
+  feedback::FeedbackError,
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, RawInterleaved, RawVertexData, TessIndex,
+    TessVertexData,
+  },
+};
+
+unsafe impl<T> FeedbackBuffer<T> for GL33
+where
+  T: Copy + Default,
+{
+  type FeedbackBufferRepr = Buffer<T>;
+
+  unsafe fn new_feedback_buffer(
+    &mut self,
+    len: usize,
+  ) -> Result<Self::FeedbackBufferRepr, FeedbackError> {
+    Ok(Buffer::from_vec_with_usage(
+      self,
+      vec![T::default(); len],
+      gl::STREAM_READ,
+    ))
+  }
+
+  unsafe fn read_feedback_buffer(
+    buffer: &Self::FeedbackBufferRepr,
+  ) -> Result<Vec<T>, FeedbackError> {
+    buffer
+      .slice_buffer()
+      .map(|slice| slice.to_vec())
+      .map_err(|e| FeedbackError::cannot_read(e.to_string()))
+  }
+}
+
+/// Bind `buffers` to the transform feedback binding points and run `render` while capturing `primitive`s.
+unsafe fn capture<T>(primitive: GLenum, buffers: &[&mut Buffer<T>], render: impl FnOnce()) {
+  for (index, buffer) in buffers.iter().enumerate() {
+    gl::BindBufferBase(
+      gl::TRANSFORM_FEEDBACK_BUFFER,
+      index as GLuint,
+      buffer.handle(),
+    );
+  }
+
+  gl::BeginTransformFeedback(primitive);
+  render();
+  gl::EndTransformFeedback();
+
+  for index in 0..buffers.len() {
+    gl::BindBufferBase(gl::TRANSFORM_FEEDBACK_BUFFER, index as GLuint, 0);
+  }
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, Interleaved, T> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let primitive = tess.raw.feedback_primitive()?;
+
+    capture(primitive, buffers, || {
+      let _ = <Self as TessGate<V, I, W, Interleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, Deinterleaved, T> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let primitive = tess.raw.feedback_primitive()?;
+
+    capture(primitive, buffers, || {
+      let _ = <Self as TessGate<V, I, W, Deinterleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, RawInterleaved, T> for GL33
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let primitive = tess.raw.feedback_primitive()?;
+
+    capture(primitive, buffers, || {
+      let _ = <Self as TessGate<V, I, W, RawInterleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
@@ -1,5 +1,6 @@
 use crate::gl33::{
   state::{Bind, GLState},
+  texture::{new_texture_storage, Texture},
   GL33,
 };
 use gl::{self, types::*};
@@ -7,10 +8,16 @@ use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      DynamicFramebuffer, Framebuffer as FramebufferBackend, FramebufferBackBuffer,
+      FramebufferBackBufferSrgb, FramebufferDepthCopy, FramebufferRawHandle,
+      FramebufferSharedDepth,
+    },
   },
-  framebuffer::{FramebufferError, IncompleteReason},
-  texture::{Dim2, Dimensionable, Sampler},
+  framebuffer::{Attachment, FramebufferError, IncompleteReason},
+  pipeline::PipelineState,
+  pixel::{PixelFormat, Type},
+  texture::{Dim, Dim2, Dimensionable, Sampler},
 };
 use std::{cell::RefCell, rc::Rc};
 
@@ -21,14 +28,85 @@ where
   pub(crate) handle: GLuint,
   renderbuffer: Option<GLuint>,
   pub(crate) size: D::Size,
+  // encoding of each color attachment; integer attachments must be cleared differently
+  color_encodings: Vec<Type>,
+  // whether the framebuffer is owned by a third party, in which case it must not be deleted
+  external: bool,
   state: Rc<RefCell<GLState>>,
 }
 
+impl<D> Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Number of color attachments.
+  pub(crate) fn color_attachment_count(&self) -> usize {
+    self.color_encodings.len()
+  }
+
+  /// Whether any color attachment has an integer pixel format.
+  pub(crate) fn has_integer_color_attachments(&self) -> bool {
+    self
+      .color_encodings
+      .iter()
+      .any(|encoding| matches!(encoding, Type::Integral | Type::Unsigned))
+  }
+
+  /// Clear every color attachment, one by one.
+  ///
+  /// Each attachment has its own color, picked with [`PipelineState::attachment_clear_color`]. Integer attachments are
+  /// cleared with the integer clear color if set, or with their color converted to integers otherwise. The other
+  /// attachments are cleared with their color, if set. Unlike `glClear`, this works with integer attachments and distinct
+  /// colors.
+  pub(crate) unsafe fn clear_color_attachments(&self, pipeline_state: &PipelineState) {
+    let integer = pipeline_state.clear_color_integer;
+
+    for (i, encoding) in self.color_encodings.iter().enumerate() {
+      let color = pipeline_state.attachment_clear_color(i);
+      let draw_buffer = i as GLint;
+
+      match encoding {
+        Type::Integral => {
+          let value = integer
+            .map(|c| c.map(|c| c as GLint))
+            .or_else(|| color.map(|c| c.map(|c| c as GLint)));
+
+          if let Some(value) = value {
+            gl::ClearBufferiv(gl::COLOR, draw_buffer, value.as_ptr());
+          }
+        }
+
+        Type::Unsigned => {
+          let value = integer.or_else(|| color.map(|c| c.map(|c| c as GLuint)));
+
+          if let Some(value) = value {
+            gl::ClearBufferuiv(gl::COLOR, draw_buffer, value.as_ptr());
+          }
+        }
+
+        _ => {
+          if let Some(color) = color {
+            gl::ClearBufferfv(gl::COLOR, draw_buffer, color.as_ptr());
+          }
+        }
+      }
+    }
+  }
+}
+
 impl<D> Drop for Framebuffer<D>
 where
   D: Dimensionable,
 {
   fn drop(&mut self) {
+    if !self.state.borrow().is_context_alive() {
+      log::debug!(
+        "framebuffer {} dropped after its context; skipping deletion",
+        self.handle
+      );
+      return;
+    }
+
     unsafe {
       if let Some(renderbuffer) = self.renderbuffer {
         gl::DeleteRenderbuffers(1, &renderbuffer);
@@ -36,8 +114,14 @@ where
       }
 
       if self.handle != 0 {
-        gl::DeleteFramebuffers(1, &self.handle);
-        self.state.borrow_mut().bind_vertex_array(0, Bind::Cached);
+        if !self.external {
+          gl::DeleteFramebuffers(1, &self.handle);
+        }
+
+        let mut state = self.state.borrow_mut();
+        state.forget_draw_buffers(self.handle);
+        state.forget_sample_buffers(self.handle);
+        state.bind_vertex_array(0, Bind::Cached);
       }
     }
   }
@@ -59,82 +143,85 @@ where
     CS: ColorSlot<Self, D>,
     DS: DepthStencilSlot<Self, D>,
   {
-    let mut handle: GLuint = 0;
-    let color_formats = CS::color_formats();
-    let depth_format = DS::depth_format();
-    let mut depth_renderbuffer: Option<GLuint> = None;
-
-    gl::GenFramebuffers(1, &mut handle);
+    new_framebuffer::<D>(self, size, &CS::color_formats(), DS::depth_format())
+  }
 
-    {
-      let mut state = self.state.borrow_mut();
+  unsafe fn attach_color_texture(
+    _: &mut Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+    attachment_index: usize,
+  ) -> Result<(), FramebufferError> {
+    gl::FramebufferTexture(
+      gl::FRAMEBUFFER,
+      gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
+      texture.handle,
+      0,
+    );
 
-      state.bind_draw_framebuffer(handle);
+    Ok(())
+  }
 
-      // reserve textures to speed slots creation
-      let textures_needed = color_formats.len() + depth_format.map_or(0, |_| 1);
-      state.reserve_textures(textures_needed);
+  unsafe fn attach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+    pf: PixelFormat,
+    attachment_index: usize,
+    layer: u32,
+  ) -> Result<(), FramebufferError> {
+    // the back buffer cannot have texture attachments
+    if framebuffer.handle == 0 {
+      return Err(FramebufferError::unsupported_attachment());
     }
 
-    // color textures
-    if color_formats.is_empty() {
-      gl::DrawBuffer(gl::NONE);
-    } else {
-      // specify the list of color buffers to draw to
-      let color_buf_nb = color_formats.len() as GLsizei;
-      let color_buffers: Vec<_> =
-        (gl::COLOR_ATTACHMENT0..gl::COLOR_ATTACHMENT0 + color_buf_nb as GLenum).collect();
+    let mut state = framebuffer.state.borrow_mut();
+    // draw buffers overridden by a render state refer to the previous attachments
+    state.reset_draw_buffers();
+    state.bind_draw_framebuffer(framebuffer.handle);
+
+    gl::FramebufferTextureLayer(
+      gl::FRAMEBUFFER,
+      gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
+      texture.handle,
+      0,
+      layer as GLint,
+    );
 
-      gl::DrawBuffers(color_buf_nb, color_buffers.as_ptr());
+    // the framebuffer might have been created without any color buffer to draw to
+    let color_encodings = &mut framebuffer.color_encodings;
+    if color_encodings.len() <= attachment_index {
+      color_encodings.resize(attachment_index + 1, pf.encoding);
     }
+    color_encodings[attachment_index] = pf.encoding;
+    set_color_draw_buffers(color_encodings.len());
 
-    // depth texture
-    if depth_format.is_none() {
-      let mut renderbuffer: GLuint = 0;
+    state.check_errors("framebuffer texture layer attachment");
 
-      gl::GenRenderbuffers(1, &mut renderbuffer);
-      gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
-      gl::RenderbufferStorage(
-        gl::RENDERBUFFER,
-        gl::DEPTH_COMPONENT32F,
-        D::width(size) as GLsizei,
-        D::height(size) as GLsizei,
-      );
-      gl::BindRenderbuffer(gl::RENDERBUFFER, 0); // FIXME: see whether really needed
+    get_framebuffer_status().map_err(FramebufferError::from)
+  }
 
-      gl::FramebufferRenderbuffer(
-        gl::FRAMEBUFFER,
-        gl::DEPTH_ATTACHMENT,
-        gl::RENDERBUFFER,
-        renderbuffer,
-      );
+  unsafe fn detach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachment_index: usize,
+  ) {
+    let mut state = framebuffer.state.borrow_mut();
 
-      depth_renderbuffer = Some(renderbuffer);
+    if framebuffer.handle == 0 || !state.is_context_alive() {
+      return;
     }
 
-    let framebuffer = Framebuffer {
-      handle,
-      renderbuffer: depth_renderbuffer,
-      size,
-      state: self.state.clone(),
-    };
-
-    Ok(framebuffer)
-  }
+    state.reset_draw_buffers();
+    state.bind_draw_framebuffer(framebuffer.handle);
 
-  unsafe fn attach_color_texture(
-    _: &mut Self::FramebufferRepr,
-    texture: &Self::TextureRepr,
-    attachment_index: usize,
-  ) -> Result<(), FramebufferError> {
-    gl::FramebufferTexture(
+    gl::FramebufferTextureLayer(
       gl::FRAMEBUFFER,
       gl::COLOR_ATTACHMENT0 + attachment_index as GLenum,
-      texture.handle,
+      0,
+      0,
       0,
     );
 
-    Ok(())
+    framebuffer.color_encodings.truncate(attachment_index);
+    set_color_draw_buffers(framebuffer.color_encodings.len());
   }
 
   unsafe fn attach_depth_texture(
@@ -157,6 +244,178 @@ where
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
     framebuffer.size
   }
+
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  ) -> Result<(), FramebufferError> {
+    // glInvalidateFramebuffer is OpenGL 4.3; invalidation is only a hint, so ignore it if unavailable
+    if !gl::InvalidateFramebuffer::is_loaded() {
+      return Ok(());
+    }
+
+    let mut gl_attachments = Vec::with_capacity(attachments.len());
+    for &attachment in attachments {
+      push_opengl_attachment(&mut gl_attachments, attachment, framebuffer.handle == 0)?;
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle);
+
+    gl::InvalidateFramebuffer(
+      gl::DRAW_FRAMEBUFFER,
+      gl_attachments.len() as GLsizei,
+      gl_attachments.as_ptr(),
+    );
+
+    state.check_errors("framebuffer invalidation");
+
+    Ok(())
+  }
+}
+
+unsafe impl<D> DynamicFramebuffer<D> for GL33
+where
+  D: Dimensionable,
+{
+  unsafe fn new_dynamic_framebuffer(
+    &mut self,
+    size: D::Size,
+    color_formats: &[PixelFormat],
+    depth_stencil_format: Option<PixelFormat>,
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    new_framebuffer::<D>(self, size, color_formats, depth_stencil_format)
+  }
+
+  unsafe fn new_attachment_texture(
+    &mut self,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    format: PixelFormat,
+  ) -> Result<Texture, FramebufferError> {
+    let texture = new_texture_storage::<D>(self, size, mipmaps, format, *sampler)?;
+    self
+      .state
+      .borrow_mut()
+      .check_errors("attachment texture creation");
+
+    Ok(texture)
+  }
+}
+
+unsafe fn new_framebuffer<D>(
+  gl33: &mut GL33,
+  size: D::Size,
+  color_formats: &[PixelFormat],
+  depth_format: Option<PixelFormat>,
+) -> Result<Framebuffer<D>, FramebufferError>
+where
+  D: Dimensionable,
+{
+  let mut handle: GLuint = 0;
+  let mut depth_renderbuffer: Option<GLuint> = None;
+
+  gl::GenFramebuffers(1, &mut handle);
+
+  {
+    let mut state = gl33.state.borrow_mut();
+
+    state.bind_draw_framebuffer(handle);
+
+    // reserve textures to speed slots creation
+    let textures_needed = color_formats.len() + depth_format.map_or(0, |_| 1);
+    state.reserve_textures(textures_needed);
+  }
+
+  // color textures
+  set_color_draw_buffers(color_formats.len());
+
+  // depth texture
+  if depth_format.is_none() {
+    let mut renderbuffer: GLuint = 0;
+
+    gl::GenRenderbuffers(1, &mut renderbuffer);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+
+    // the depth renderbuffer must have as many samples as the color attachments
+    if let Dim::Dim2Multisample(samples) = D::dim() {
+      gl::RenderbufferStorageMultisample(
+        gl::RENDERBUFFER,
+        samples as GLsizei,
+        gl::DEPTH_COMPONENT32F,
+        D::width(size) as GLsizei,
+        D::height(size) as GLsizei,
+      );
+    } else {
+      gl::RenderbufferStorage(
+        gl::RENDERBUFFER,
+        gl::DEPTH_COMPONENT32F,
+        D::width(size) as GLsizei,
+        D::height(size) as GLsizei,
+      );
+    }
+
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0); // FIXME: see whether really needed
+
+    gl::FramebufferRenderbuffer(
+      gl::FRAMEBUFFER,
+      gl::DEPTH_ATTACHMENT,
+      gl::RENDERBUFFER,
+      renderbuffer,
+    );
+
+    depth_renderbuffer = Some(renderbuffer);
+  }
+
+  gl33.state.borrow_mut().check_errors("framebuffer creation");
+
+  let framebuffer = Framebuffer {
+    handle,
+    renderbuffer: depth_renderbuffer,
+    size,
+    color_encodings: color_formats.iter().map(|format| format.encoding).collect(),
+    external: false,
+    state: gl33.state.clone(),
+  };
+
+  Ok(framebuffer)
+}
+
+// Draw to the first `color_attachments` color attachments of the bound draw framebuffer.
+unsafe fn set_color_draw_buffers(color_attachments: usize) {
+  if color_attachments == 0 {
+    gl::DrawBuffer(gl::NONE);
+  } else {
+    // specify the list of color buffers to draw to
+    let color_buf_nb = color_attachments as GLsizei;
+    let color_buffers: Vec<_> =
+      (gl::COLOR_ATTACHMENT0..gl::COLOR_ATTACHMENT0 + color_buf_nb as GLenum).collect();
+
+    gl::DrawBuffers(color_buf_nb, color_buffers.as_ptr());
+  }
+}
+
+// Push the enums to use to refer to an attachment; the back buffer uses different enums.
+fn push_opengl_attachment(
+  attachments: &mut Vec<GLenum>,
+  attachment: Attachment,
+  back_buffer: bool,
+) -> Result<(), FramebufferError> {
+  match (attachment, back_buffer) {
+    (Attachment::Color(0), true) => attachments.push(gl::COLOR),
+    (Attachment::Color(_), true) => return Err(FramebufferError::unsupported_attachment()),
+    (Attachment::Color(i), false) => attachments.push(gl::COLOR_ATTACHMENT0 + i as GLenum),
+    (Attachment::Depth, true) => attachments.push(gl::DEPTH),
+    (Attachment::Depth, false) => attachments.push(gl::DEPTH_ATTACHMENT),
+    (Attachment::Stencil, true) => attachments.push(gl::STENCIL),
+    (Attachment::Stencil, false) => attachments.push(gl::STENCIL_ATTACHMENT),
+    // the back buffer has no combined depth-stencil enum
+    (Attachment::DepthStencil, true) => attachments.extend_from_slice(&[gl::DEPTH, gl::STENCIL]),
+    (Attachment::DepthStencil, false) => attachments.push(gl::DEPTH_STENCIL_ATTACHMENT),
+  }
+
+  Ok(())
 }
 
 fn get_framebuffer_status() -> Result<(), IncompleteReason> {
@@ -172,10 +431,7 @@ fn get_framebuffer_status() -> Result<(), IncompleteReason> {
     gl::FRAMEBUFFER_UNSUPPORTED => Err(IncompleteReason::Unsupported),
     gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Err(IncompleteReason::IncompleteMultisample),
     gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Err(IncompleteReason::IncompleteLayerTargets),
-    _ => panic!(
-      "unknown OpenGL framebuffer incomplete status! status={}",
-      status
-    ),
+    _ => Err(IncompleteReason::Unknown(status)),
   }
 }
 
@@ -188,7 +444,113 @@ unsafe impl FramebufferBackBuffer for GL33 {
       handle: 0,
       renderbuffer: None,
       size,
+      color_encodings: Vec::new(),
+      external: false,
+      state: self.state.clone(),
+    })
+  }
+}
+
+unsafe impl FramebufferBackBufferSrgb for GL33 {
+  unsafe fn back_buffer_is_srgb(&mut self) -> bool {
+    let mut state = self.state.borrow_mut();
+    state.bind_draw_framebuffer(0);
+
+    let mut encoding: GLint = 0;
+    gl::GetFramebufferAttachmentParameteriv(
+      gl::DRAW_FRAMEBUFFER,
+      gl::BACK_LEFT,
+      gl::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+      &mut encoding,
+    );
+
+    encoding as GLenum == gl::SRGB
+  }
+}
+
+unsafe impl FramebufferRawHandle for GL33 {
+  type RawFramebufferHandle = GLuint;
+
+  unsafe fn framebuffer_from_raw_handle(
+    &mut self,
+    handle: Self::RawFramebufferHandle,
+    size: <Dim2 as Dimensionable>::Size,
+    color_formats: &[PixelFormat],
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    let mut state = self.state.borrow_mut();
+
+    // the third party might have bound framebuffers behind our back, so the binding must not be skipped
+    state.invalidate_framebuffer();
+    state.bind_draw_framebuffer(handle);
+    state.forget_draw_buffers(handle);
+    state.forget_sample_buffers(handle);
+    set_color_draw_buffers(color_formats.len());
+
+    get_framebuffer_status()?;
+    state.check_errors("raw framebuffer wrapping");
+
+    Ok(Framebuffer {
+      handle,
+      renderbuffer: None,
+      size,
+      color_encodings: color_formats.iter().map(|format| format.encoding).collect(),
+      external: true,
       state: self.state.clone(),
     })
   }
 }
+
+unsafe impl FramebufferDepthCopy for GL33 {
+  unsafe fn copy_depth_to_texture(
+    framebuffer: &Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+  ) -> Result<(), FramebufferError> {
+    let mut state = framebuffer.state.borrow_mut();
+    let [width, height] = framebuffer.size;
+
+    // blit from the framebuffer into a temporary framebuffer which depth attachment is the texture
+    let mut copy_handle: GLuint = 0;
+    gl::GenFramebuffers(1, &mut copy_handle);
+    state.bind_draw_framebuffer(copy_handle);
+    gl::FramebufferTexture2D(
+      gl::DRAW_FRAMEBUFFER,
+      gl::DEPTH_ATTACHMENT,
+      gl::TEXTURE_2D,
+      texture.handle,
+      0,
+    );
+    gl::DrawBuffer(gl::NONE);
+
+    let status = get_framebuffer_status();
+
+    if status.is_ok() {
+      gl::BindFramebuffer(gl::READ_FRAMEBUFFER, framebuffer.handle);
+      gl::BlitFramebuffer(
+        0,
+        0,
+        width as GLint,
+        height as GLint,
+        0,
+        0,
+        width as GLint,
+        height as GLint,
+        gl::DEPTH_BUFFER_BIT,
+        gl::NEAREST,
+      );
+      gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+    }
+
+    state.bind_draw_framebuffer(0);
+    gl::DeleteFramebuffers(1, &copy_handle);
+
+    state.check_errors("framebuffer depth copy");
+
+    status.map_err(FramebufferError::from)
+  }
+}
+
+unsafe impl FramebufferSharedDepth for GL33 {
+  unsafe fn share_depth_texture(texture: &Self::TextureRepr) -> Self::TextureRepr {
+    texture.share()
+  }
+}
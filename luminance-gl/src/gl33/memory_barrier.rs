@@ -0,0 +1,75 @@
+//! Memory barrier implementation for OpenGL 3.3.
+//!
+//! `glMemoryBarrier` is OpenGL 4.2, and shader storage buffers are OpenGL 4.3: barriers are only issued when the
+//! context is recent enough.
+
+use crate::gl33::GL33;
+use gl::types::*;
+use luminance::{
+  backend::memory_barrier::MemoryBarrier,
+  memory_barrier::{MemoryBarrierBits, MemoryBarrierError},
+};
+
+unsafe impl MemoryBarrier for GL33 {
+  unsafe fn memory_barrier(&mut self, bits: MemoryBarrierBits) -> Result<(), MemoryBarrierError> {
+    let mut state = self.state.borrow_mut();
+    let version = state.get_gl_version_number();
+
+    let supported = gl::MemoryBarrier::is_loaded()
+      && version >= (4, 2)
+      && (version >= (4, 3) || !bits.contains(MemoryBarrierBits::SHADER_STORAGE));
+
+    if !supported {
+      return Err(MemoryBarrierError::unsupported(bits));
+    }
+
+    if bits.is_empty() {
+      return Ok(());
+    }
+
+    gl::MemoryBarrier(opengl_memory_barrier_bits(bits));
+    state.check_errors("memory barrier");
+
+    Ok(())
+  }
+}
+
+fn opengl_memory_barrier_bits(bits: MemoryBarrierBits) -> GLbitfield {
+  const GL_BITS: [(MemoryBarrierBits, GLbitfield); 9] = [
+    (
+      MemoryBarrierBits::VERTEX_ATTRIB_ARRAY,
+      gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT,
+    ),
+    (
+      MemoryBarrierBits::ELEMENT_ARRAY,
+      gl::ELEMENT_ARRAY_BARRIER_BIT,
+    ),
+    (MemoryBarrierBits::UNIFORM, gl::UNIFORM_BARRIER_BIT),
+    (
+      MemoryBarrierBits::TEXTURE_FETCH,
+      gl::TEXTURE_FETCH_BARRIER_BIT,
+    ),
+    (
+      MemoryBarrierBits::SHADER_IMAGE_ACCESS,
+      gl::SHADER_IMAGE_ACCESS_BARRIER_BIT,
+    ),
+    (
+      MemoryBarrierBits::TEXTURE_UPDATE,
+      gl::TEXTURE_UPDATE_BARRIER_BIT,
+    ),
+    (
+      MemoryBarrierBits::BUFFER_UPDATE,
+      gl::BUFFER_UPDATE_BARRIER_BIT,
+    ),
+    (MemoryBarrierBits::FRAMEBUFFER, gl::FRAMEBUFFER_BARRIER_BIT),
+    (
+      MemoryBarrierBits::SHADER_STORAGE,
+      gl::SHADER_STORAGE_BARRIER_BIT,
+    ),
+  ];
+
+  GL_BITS
+    .iter()
+    .filter(|(b, _)| bits.contains(*b))
+    .fold(0, |gl_bits, (_, gl_bit)| gl_bits | gl_bit)
+}
@@ -1,23 +1,32 @@
 use super::buffer::Buffer;
 use crate::gl33::{
+  debug::with_debug_program,
   state::{BlendingState, DepthTest, FaceCullingState, GLState, ScissorState},
   GL33,
 };
 use gl::types::*;
 use luminance::{
   backend::{
-    pipeline::{Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture},
+    pipeline::{
+      Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture,
+      PipelineTextureBuffer, PipelineTextureLevel, PipelineTextureStencil,
+    },
     render_gate::RenderGate,
     shader::ShaderData,
     shading_gate::ShadingGate,
     tess::Tess,
-    tess_gate::TessGate,
+    tess_gate::{TessGate, TessGateDebug},
   },
   blending::BlendingMode,
+  depth_stencil::Write,
   pipeline::{PipelineError, PipelineState, Viewport},
-  pixel::Pixel,
+  pixel::{Pixel, StencilPixel},
   render_state::RenderState,
-  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, RawInterleaved, RawVertexData, TessError,
+    TessIndex, TessVertexData,
+  },
+  tess_gate::DebugMode,
   texture::Dimensionable,
 };
 use luminance_std140::{ArrElem, Std140};
@@ -33,6 +42,10 @@ where
   P: Pixel,
 {
   pub(crate) unit: u32,
+  // target and handle of a depth / stencil texture currently sampled as stencil
+  stencil_view: Option<(GLenum, GLuint)>,
+  // target, handle and mipmaps of a texture currently restricted to a single level
+  level_view: Option<(GLenum, GLuint, usize)>,
   state: Rc<RefCell<GLState>>,
   _phantom: PhantomData<*const (D, P)>,
 }
@@ -45,10 +58,52 @@ where
   fn drop(&mut self) {
     // place the binding into the free list
     let mut state = self.state.borrow_mut();
+
+    // restore the depth view of depth / stencil textures
+    if let Some((target, handle)) = self.stencil_view {
+      unsafe {
+        state.bind_texture_at(target, handle, self.unit);
+        state.set_texture_unit(self.unit);
+        gl::TexParameteri(
+          target,
+          gl::DEPTH_STENCIL_TEXTURE_MODE,
+          gl::DEPTH_COMPONENT as GLint,
+        );
+      }
+    }
+
+    // restore all the levels of textures restricted to a single level
+    if let Some((target, handle, mipmaps)) = self.level_view {
+      unsafe {
+        state.bind_texture_at(target, handle, self.unit);
+        state.set_texture_unit(self.unit);
+        gl::TexParameteri(target, gl::TEXTURE_BASE_LEVEL, 0);
+        gl::TexParameteri(target, gl::TEXTURE_MAX_LEVEL, mipmaps as GLint);
+      }
+    }
+
     state.binding_stack_mut().free_texture_units.push(self.unit);
   }
 }
 
+pub struct BoundTextureBuffer<T> {
+  pub(crate) unit: u32,
+  state: Rc<RefCell<GLState>>,
+  _phantom: PhantomData<*const T>,
+}
+
+impl<T> Drop for BoundTextureBuffer<T> {
+  fn drop(&mut self) {
+    // place the binding into the free list
+    self
+      .state
+      .borrow_mut()
+      .binding_stack_mut()
+      .free_texture_units
+      .push(self.unit);
+  }
+}
+
 pub struct BoundShaderData<T> {
   pub(crate) binding: u32,
   state: Rc<RefCell<GLState>>,
@@ -90,7 +145,7 @@ where
   ) {
     let mut state = self.state.borrow_mut();
 
-    state.bind_draw_framebuffer(framebuffer.handle);
+    state.bind_pipeline_framebuffer(framebuffer.handle, framebuffer.color_attachment_count());
 
     let size = framebuffer.size;
 
@@ -110,7 +165,17 @@ where
     }
 
     let mut clear_buffer_bits = 0;
-    if let Some(clear_color) = pipeline_state.clear_color {
+    let mut clear_color_attachments = false;
+    let per_attachment_colors =
+      !pipeline_state.clear_colors.is_empty() && framebuffer.color_attachment_count() > 1;
+
+    if framebuffer.has_integer_color_attachments() || per_attachment_colors {
+      // glClear leaves integer attachments undefined and uses a single color; attachments are then cleared one by one
+      // once the scissor is set
+      clear_color_attachments = pipeline_state.clear_color.is_some()
+        || pipeline_state.clear_color_integer.is_some()
+        || !pipeline_state.clear_colors.is_empty();
+    } else if let Some(clear_color) = pipeline_state.attachment_clear_color(0) {
       state.set_clear_color([
         clear_color[0] as _,
         clear_color[1] as _,
@@ -121,7 +186,13 @@ where
       clear_buffer_bits |= gl::COLOR_BUFFER_BIT;
     }
 
+    // clears are masked like renders, and the last render state might have disabled writes
+    if clear_color_attachments || clear_buffer_bits & gl::COLOR_BUFFER_BIT != 0 {
+      state.set_color_mask([true; 4]);
+    }
+
     if let Some(clear_depth) = pipeline_state.clear_depth {
+      state.set_depth_write(Write::On);
       state.set_clear_depth(clear_depth);
       clear_buffer_bits |= gl::DEPTH_BUFFER_BIT;
     }
@@ -144,7 +215,13 @@ where
       gl::Clear(clear_buffer_bits);
     }
 
+    if clear_color_attachments {
+      framebuffer.clear_color_attachments(pipeline_state);
+    }
+
     state.enable_srgb_framebuffer(pipeline_state.srgb_enabled);
+
+    state.check_errors("pipeline start");
   }
 }
 
@@ -164,19 +241,14 @@ where
     P: Pixel,
   {
     let mut state = pipeline.state.borrow_mut();
-    let bstack = state.binding_stack_mut();
-
-    let unit = bstack.free_texture_units.pop().unwrap_or_else(|| {
-      // no more free units; reserve one
-      let unit = bstack.next_texture_unit;
-      bstack.next_texture_unit += 1;
-      unit
-    });
+    let unit = reserve_texture_unit(&mut state)?;
 
     state.bind_texture_at(texture.target, texture.handle, unit);
 
     Ok(BoundTexture {
       unit,
+      stencil_view: None,
+      level_view: None,
       state: pipeline.state.clone(),
       _phantom: PhantomData,
     })
@@ -187,6 +259,105 @@ where
   }
 }
 
+unsafe impl<D, P> PipelineTextureStencil<D, P> for GL33
+where
+  D: Dimensionable,
+  P: StencilPixel,
+{
+  unsafe fn bind_texture_stencil(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+  ) -> Result<Self::BoundTextureRepr, PipelineError> {
+    if !pipeline.state.borrow_mut().supports_stencil_texturing() {
+      return Err(PipelineError::stencil_texturing_unsupported());
+    }
+
+    let mut bound = <Self as PipelineTexture<D, P>>::bind_texture(pipeline, texture)?;
+
+    // switch to the stencil view
+    let mut state = pipeline.state.borrow_mut();
+    state.set_texture_unit(bound.unit);
+    gl::TexParameteri(
+      texture.target,
+      gl::DEPTH_STENCIL_TEXTURE_MODE,
+      gl::STENCIL_INDEX as GLint,
+    );
+
+    bound.stencil_view = Some((texture.target, texture.handle));
+    Ok(bound)
+  }
+}
+
+unsafe impl<D, P> PipelineTextureLevel<D, P> for GL33
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  unsafe fn bind_texture_level(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+    level: usize,
+  ) -> Result<Self::BoundTextureRepr, PipelineError> {
+    let mut bound = <Self as PipelineTexture<D, P>>::bind_texture(pipeline, texture)?;
+
+    // restrict the levels that can be sampled to the single level
+    let mut state = pipeline.state.borrow_mut();
+    state.set_texture_unit(bound.unit);
+    gl::TexParameteri(texture.target, gl::TEXTURE_BASE_LEVEL, level as GLint);
+    gl::TexParameteri(texture.target, gl::TEXTURE_MAX_LEVEL, level as GLint);
+
+    bound.level_view = Some((texture.target, texture.handle, texture.mipmaps));
+    Ok(bound)
+  }
+}
+
+unsafe impl<P> PipelineTextureBuffer<P> for GL33
+where
+  P: Pixel,
+{
+  type BoundTextureBufferRepr = BoundTextureBuffer<P>;
+
+  unsafe fn bind_texture_buffer(
+    pipeline: &Self::PipelineRepr,
+    texture_buffer: &Self::TextureBufferRepr,
+  ) -> Result<Self::BoundTextureBufferRepr, PipelineError> {
+    let mut state = pipeline.state.borrow_mut();
+    let unit = reserve_texture_unit(&mut state)?;
+
+    let texture = &texture_buffer.texture;
+    state.bind_texture_at(texture.target, texture.handle, unit);
+
+    Ok(BoundTextureBuffer {
+      unit,
+      state: pipeline.state.clone(),
+      _phantom: PhantomData,
+    })
+  }
+
+  unsafe fn texture_buffer_binding(bound: &Self::BoundTextureBufferRepr) -> u32 {
+    bound.unit
+  }
+}
+
+// reserve a texture unit for a texture or a texture buffer
+fn reserve_texture_unit(state: &mut GLState) -> Result<u32, PipelineError> {
+  let max = state.get_max_texture_units();
+  let bstack = state.binding_stack_mut();
+
+  match bstack.free_texture_units.pop() {
+    Some(unit) => Ok(unit),
+
+    // no more free units; reserve one
+    None if bstack.next_texture_unit < max => {
+      let unit = bstack.next_texture_unit;
+      bstack.next_texture_unit += 1;
+      Ok(unit)
+    }
+
+    None => Err(PipelineError::out_of_texture_units(max)),
+  }
+}
+
 unsafe impl<T> PipelineShaderData<T> for GL33
 where
   Self: ShaderData<T, ShaderDataRepr = Buffer<<ArrElem<T> as Std140>::Encoded>>,
@@ -199,14 +370,21 @@ where
     shader_data: &Self::ShaderDataRepr,
   ) -> Result<Self::BoundShaderDataRepr, PipelineError> {
     let mut state = pipeline.state.borrow_mut();
+    let max = state.get_max_shader_data_bindings();
     let bstack = state.binding_stack_mut();
 
-    let binding = bstack.free_shader_data.pop().unwrap_or_else(|| {
+    let binding = match bstack.free_shader_data.pop() {
+      Some(binding) => binding,
+
       // no more free bindings; reserve one
-      let binding = bstack.next_shader_data;
-      bstack.next_shader_data += 1;
-      binding
-    });
+      None if bstack.next_shader_data < max => {
+        let binding = bstack.next_shader_data;
+        bstack.next_shader_data += 1;
+        binding
+      }
+
+      None => return Err(PipelineError::too_many_shader_data_bindings(max)),
+    };
 
     state.bind_uniform_buffer(shader_data.handle(), binding);
 
@@ -234,8 +412,17 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Interleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
   }
 }
 
@@ -251,8 +438,103 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Deinterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> TessGate<V, I, W, RawInterleaved> for GL33
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn render(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, RawInterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
   ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    with_debug_program(self, mode, transform, |wireframe| {
+      tess.raw.render_debug(start_index, vert_nb, wireframe)
+    });
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
+  ) {
+    with_debug_program(self, mode, transform, |wireframe| {
+      tess.raw.render_debug(start_index, vert_nb, wireframe)
+    });
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, RawInterleaved> for GL33
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
+  ) {
+    with_debug_program(self, mode, transform, |wireframe| {
+      tess.raw.render_debug(start_index, vert_nb, wireframe)
+    });
   }
 }
 
@@ -263,7 +545,11 @@ unsafe impl RenderGate for GL33 {
     // blending state
     match rdr_st.blending() {
       Some(blending) => {
-        gfx_state.set_blending_state(BlendingState::On);
+        match rdr_st.blend_enabled_buffers() {
+          Some(mask) => gfx_state.set_blending_buffers(mask),
+          None => gfx_state.set_blending_state(BlendingState::On),
+        }
+
         match blending {
           BlendingMode::Combined(b) => {
             gfx_state.set_blending_equation(b.equation);
@@ -289,6 +575,7 @@ unsafe impl RenderGate for GL33 {
     }
 
     gfx_state.set_depth_write(rdr_st.depth_write());
+    gfx_state.set_color_mask(rdr_st.color_mask());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
@@ -313,6 +600,21 @@ unsafe impl RenderGate for GL33 {
       }
     }
 
+    // clip distances
+    gfx_state.set_clip_distances(rdr_st.clip_planes());
+
+    // sample shading
+    gfx_state.set_min_sample_shading(rdr_st.min_sample_shading());
+
+    // program point size
+    gfx_state.set_program_point_size(rdr_st.program_point_size());
+
+    // draw buffers
+    match rdr_st.draw_buffers() {
+      Some(draw_buffers) => gfx_state.set_draw_buffers(draw_buffers),
+      None => gfx_state.reset_draw_buffers(),
+    }
+
     // scissor related state
     match rdr_st.scissor().as_ref() {
       Some(region) => {
@@ -324,11 +626,15 @@ unsafe impl RenderGate for GL33 {
         gfx_state.set_scissor_state(ScissorState::Off);
       }
     }
+
+    gfx_state.check_errors("render state");
   }
 }
 
 unsafe impl ShadingGate for GL33 {
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr) {
-    self.state.borrow_mut().use_program(shader_program.handle);
+    let mut state = self.state.borrow_mut();
+    state.use_program(shader_program.handle);
+    state.check_errors("shader program application");
   }
 }
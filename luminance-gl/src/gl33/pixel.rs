@@ -1,6 +1,13 @@
 use gl::types::*;
 
-use luminance::pixel::{Format, PixelFormat, Size, Type};
+use luminance::pixel::{CompressedFormat, Format, PixelFormat, Size, Type};
+
+// compressed internal formats; S3TC ones come from GL_EXT_texture_compression_s3tc
+const COMPRESSED_RGBA_S3TC_DXT1_EXT: GLenum = 0x83F1;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: GLenum = 0x83F3;
+const COMPRESSED_RGBA_BPTC_UNORM: GLenum = 0x8E8C;
+const COMPRESSED_RGB8_ETC2: GLenum = 0x9274;
+const COMPRESSED_RGBA8_ETC2_EAC: GLenum = 0x9278;
 
 // OpenGL format, internal sized-format and type.
 pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GLenum)> {
@@ -192,12 +199,59 @@ pub(crate) fn opengl_pixel_format(pf: PixelFormat) -> Option<(GLenum, GLenum, GL
       Some((gl::DEPTH_COMPONENT, gl::DEPTH_COMPONENT32F, gl::FLOAT))
     }
 
+    (Format::Depth(Size::TwentyFour), Type::NormUnsigned) => {
+      Some((gl::DEPTH_COMPONENT, gl::DEPTH_COMPONENT24, gl::UNSIGNED_INT))
+    }
+
+    (Format::DepthStencil(Size::TwentyFour, Size::Eight), Type::NormUnsigned) => Some((
+      gl::DEPTH_STENCIL,
+      gl::DEPTH24_STENCIL8,
+      gl::UNSIGNED_INT_24_8,
+    )),
+
     (Format::DepthStencil(Size::ThirtyTwo, Size::Eight), Type::Floating) => Some((
       gl::DEPTH_STENCIL,
       gl::DEPTH32F_STENCIL8,
       gl::FLOAT_32_UNSIGNED_INT_24_8_REV,
     )),
 
+    // compressed formats; format and type describe decompressed texels
+    (Format::Compressed(cf), Type::NormUnsigned) => {
+      let format = if cf.channels_len() == 3 {
+        gl::RGB
+      } else {
+        gl::RGBA
+      };
+
+      Some((format, opengl_compressed_format(cf), gl::UNSIGNED_BYTE))
+    }
+
     _ => None,
   }
 }
+
+// OpenGL internal format of a compressed format.
+pub(crate) fn opengl_compressed_format(cf: CompressedFormat) -> GLenum {
+  match cf {
+    CompressedFormat::BC1 => COMPRESSED_RGBA_S3TC_DXT1_EXT,
+    CompressedFormat::BC3 => COMPRESSED_RGBA_S3TC_DXT5_EXT,
+    CompressedFormat::BC7 => COMPRESSED_RGBA_BPTC_UNORM,
+    CompressedFormat::ETC2RGB8 => COMPRESSED_RGB8_ETC2,
+    CompressedFormat::ETC2RGBA8 => COMPRESSED_RGBA8_ETC2_EAC,
+  }
+}
+
+// Check whether the running context supports a compressed internal format.
+pub(crate) fn supports_compressed_format(iformat: GLenum) -> bool {
+  unsafe {
+    let mut formats_nb: GLint = 0;
+    gl::GetIntegerv(gl::NUM_COMPRESSED_TEXTURE_FORMATS, &mut formats_nb);
+
+    let mut formats = vec![0; formats_nb.max(0) as usize];
+    if !formats.is_empty() {
+      gl::GetIntegerv(gl::COMPRESSED_TEXTURE_FORMATS, formats.as_mut_ptr());
+    }
+
+    formats.contains(&(iformat as GLint))
+  }
+}
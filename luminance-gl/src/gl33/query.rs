@@ -1,7 +1,14 @@
 //! Query API implementation for OpenGL 3.3.
 
-use crate::GL33;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use crate::gl33::{state::GLState, GL33};
+use gl::types::*;
+use luminance::{
+  backend::query::{
+    PipelineStatsQuery as PipelineStatsQueryBackend, Query as QueryBackend, QueryError,
+  },
+  query::PipelineStats,
+};
+use std::{cell::RefCell, rc::Rc};
 
 unsafe impl QueryBackend for GL33 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -29,3 +36,134 @@ unsafe impl QueryBackend for GL33 {
     Ok(max)
   }
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PipelineStatsQueryStatus {
+  /// The query has never been run.
+  Idle,
+  /// The query is counting.
+  Active,
+  /// The query has been run at least once and is not counting.
+  Ended,
+}
+
+/// Pipeline statistics query.
+///
+/// OpenGL 3.3 has no pipeline statistics query as such, so this runs a `GL_PRIMITIVES_GENERATED` and a
+/// `GL_SAMPLES_PASSED` query together. Vertices submitted cannot be counted (`GL_VERTICES_SUBMITTED` requires
+/// OpenGL 4.6).
+pub struct PipelineStatsQuery {
+  primitives_generated: GLuint,
+  samples_passed: GLuint,
+  status: PipelineStatsQueryStatus,
+  state: Rc<RefCell<GLState>>,
+}
+
+impl Drop for PipelineStatsQuery {
+  fn drop(&mut self) {
+    let mut state = self.state.borrow_mut();
+
+    if !state.is_context_alive() {
+      return;
+    }
+
+    unsafe {
+      if self.status == PipelineStatsQueryStatus::Active {
+        gl::EndQuery(gl::PRIMITIVES_GENERATED);
+        gl::EndQuery(gl::SAMPLES_PASSED);
+        state.set_pipeline_stats_query_active(false);
+      }
+
+      let queries = [self.primitives_generated, self.samples_passed];
+      gl::DeleteQueries(queries.len() as GLsizei, queries.as_ptr());
+    }
+  }
+}
+
+unsafe impl PipelineStatsQueryBackend for GL33 {
+  type PipelineStatsQueryRepr = PipelineStatsQuery;
+
+  unsafe fn new_pipeline_stats_query(
+    &mut self,
+  ) -> Result<Self::PipelineStatsQueryRepr, QueryError> {
+    let mut queries: [GLuint; 2] = [0; 2];
+    gl::GenQueries(queries.len() as GLsizei, queries.as_mut_ptr());
+
+    if queries.contains(&0) {
+      gl::DeleteQueries(queries.len() as GLsizei, queries.as_ptr());
+      return Err(QueryError::cannot_create_pipeline_stats_query(
+        "cannot generate query objects",
+      ));
+    }
+
+    Ok(PipelineStatsQuery {
+      primitives_generated: queries[0],
+      samples_passed: queries[1],
+      status: PipelineStatsQueryStatus::Idle,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn begin_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError> {
+    let mut state = query.state.borrow_mut();
+
+    if state.is_pipeline_stats_query_active() {
+      return Err(QueryError::PipelineStatsQueryActive);
+    }
+
+    gl::BeginQuery(gl::PRIMITIVES_GENERATED, query.primitives_generated);
+    gl::BeginQuery(gl::SAMPLES_PASSED, query.samples_passed);
+    state.set_pipeline_stats_query_active(true);
+    query.status = PipelineStatsQueryStatus::Active;
+    state.check_errors("pipeline statistics query beginning");
+
+    Ok(())
+  }
+
+  unsafe fn end_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError> {
+    if query.status != PipelineStatsQueryStatus::Active {
+      return Err(QueryError::PipelineStatsQueryInactive);
+    }
+
+    let mut state = query.state.borrow_mut();
+
+    gl::EndQuery(gl::PRIMITIVES_GENERATED);
+    gl::EndQuery(gl::SAMPLES_PASSED);
+    state.set_pipeline_stats_query_active(false);
+    query.status = PipelineStatsQueryStatus::Ended;
+    state.check_errors("pipeline statistics query end");
+
+    Ok(())
+  }
+
+  unsafe fn pipeline_stats(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<PipelineStats, QueryError> {
+    match query.status {
+      PipelineStatsQueryStatus::Idle => Err(QueryError::NoPipelineStats),
+      PipelineStatsQueryStatus::Active => Err(QueryError::PipelineStatsQueryActive),
+      PipelineStatsQueryStatus::Ended => {
+        let mut primitives_generated: GLuint64 = 0;
+        let mut samples_passed: GLuint64 = 0;
+
+        // QUERY_RESULT waits for the result to be available
+        gl::GetQueryObjectui64v(
+          query.primitives_generated,
+          gl::QUERY_RESULT,
+          &mut primitives_generated,
+        );
+        gl::GetQueryObjectui64v(query.samples_passed, gl::QUERY_RESULT, &mut samples_passed);
+
+        Ok(PipelineStats {
+          primitives_generated: Some(primitives_generated),
+          vertices_submitted: None,
+          samples_passed: Some(samples_passed),
+        })
+      }
+    }
+  }
+}
@@ -1,33 +1,49 @@
 use super::buffer::Buffer;
-use crate::gl33::GL33;
+use crate::gl33::{state::GLState, GL33};
 use gl::{self, types::*};
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
-  pipeline::{ShaderDataBinding, TextureBinding},
+  backend::{
+    feedback::ShaderFeedback,
+    shader::{Shader, ShaderData, Uniformable, UniformableMatrix, UniformableReadback},
+  },
+  feedback::FeedbackMode,
+  pipeline::{ShaderDataBinding, TextureBinding, TextureBufferBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
     types::{Arr, Mat22, Mat33, Mat44, Vec2, Vec3, Vec4},
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    BlockLayout, ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform,
+    UniformType, UniformWarning, VertexAttribWarning,
   },
   texture::{Dim, Dimensionable},
   vertex::Semantics,
 };
 use luminance_std140::{ArrElem, Std140};
 use std::{
+  cell::RefCell,
   ffi::CString,
   mem,
+  os::raw::c_void,
   ptr::{null, null_mut},
+  rc::Rc,
 };
 
 #[derive(Debug)]
 pub struct Stage {
   handle: GLuint,
   ty: StageType,
+  state: Rc<RefCell<GLState>>,
 }
 
 impl Drop for Stage {
   fn drop(&mut self) {
+    if !self.state.borrow().is_context_alive() {
+      log::debug!(
+        "shader stage {} dropped after its context; skipping deletion",
+        self.handle
+      );
+      return;
+    }
+
     unsafe {
       gl::DeleteShader(self.handle);
     }
@@ -37,10 +53,19 @@ impl Drop for Stage {
 #[derive(Debug)]
 pub struct Program {
   pub(crate) handle: GLuint,
+  state: Rc<RefCell<GLState>>,
 }
 
 impl Drop for Program {
   fn drop(&mut self) {
+    if !self.state.borrow().is_context_alive() {
+      log::debug!(
+        "program {} dropped after its context; skipping deletion",
+        self.handle
+      );
+      return;
+    }
+
     unsafe {
       gl::DeleteProgram(self.handle);
     }
@@ -48,7 +73,7 @@ impl Drop for Program {
 }
 
 impl Program {
-  fn link(&self) -> Result<(), ProgramError> {
+  pub(crate) fn link(&self) -> Result<(), ProgramError> {
     let handle = self.handle;
 
     unsafe {
@@ -60,17 +85,44 @@ impl Program {
       if linked == gl::TRUE.into() {
         Ok(())
       } else {
-        let mut log_len: GLint = 0;
-        gl::GetProgramiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
+        Err(ProgramError::link_failed(self.info_log()))
+      }
+    }
+  }
 
-        let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
-        gl::GetProgramInfoLog(handle, log_len, null_mut(), log.as_mut_ptr() as *mut GLchar);
+  fn info_log(&self) -> String {
+    let handle = self.handle;
 
-        log.set_len(log_len as usize);
+    unsafe {
+      let mut log_len: GLint = 0;
+      gl::GetProgramiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
 
-        Err(ProgramError::link_failed(String::from_utf8(log).unwrap()))
-      }
+      let mut log: Vec<u8> = Vec::with_capacity(log_len as usize);
+      gl::GetProgramInfoLog(handle, log_len, null_mut(), log.as_mut_ptr() as *mut GLchar);
+
+      log.set_len(log_len as usize);
+
+      String::from_utf8(log).unwrap()
+    }
+  }
+}
+
+/// Check whether the driver can load program binaries of the given format.
+fn supports_program_binary_format(format: GLenum) -> bool {
+  if !gl::ProgramBinary::is_loaded() {
+    return false;
+  }
+
+  unsafe {
+    let mut formats_nb: GLint = 0;
+    gl::GetIntegerv(gl::NUM_PROGRAM_BINARY_FORMATS, &mut formats_nb);
+
+    let mut formats = vec![0; formats_nb.max(0) as usize];
+    if !formats.is_empty() {
+      gl::GetIntegerv(gl::PROGRAM_BINARY_FORMATS, formats.as_mut_ptr());
     }
+
+    formats.contains(&(format as GLint))
   }
 }
 
@@ -153,7 +205,11 @@ unsafe impl Shader for GL33 {
     gl::GetShaderiv(handle, gl::COMPILE_STATUS, &mut compiled);
 
     if compiled == gl::TRUE.into() {
-      Ok(Stage { handle, ty })
+      Ok(Stage {
+        handle,
+        ty,
+        state: self.state.clone(),
+      })
     } else {
       let mut log_len: GLint = 0;
       gl::GetShaderiv(handle, gl::INFO_LOG_LENGTH, &mut log_len);
@@ -181,6 +237,15 @@ unsafe impl Shader for GL33 {
   ) -> Result<Self::ProgramRepr, ProgramError> {
     let handle = gl::CreateProgram();
 
+    // allow retrieving the program binary later on
+    if gl::ProgramParameteri::is_loaded() {
+      gl::ProgramParameteri(
+        handle,
+        gl::PROGRAM_BINARY_RETRIEVABLE_HINT,
+        gl::TRUE as GLint,
+      );
+    }
+
     if let Some(TessellationStages {
       control,
       evaluation,
@@ -198,8 +263,168 @@ unsafe impl Shader for GL33 {
 
     gl::AttachShader(handle, fragment.handle);
 
-    let program = Program { handle };
-    program.link().map(move |_| program)
+    let program = Program {
+      handle,
+      state: self.state.clone(),
+    };
+    let linked = program.link().map(move |_| program);
+
+    self.state.borrow_mut().check_errors("program creation");
+
+    linked
+  }
+
+  unsafe fn max_geometry_output_vertices(&mut self) -> Option<usize> {
+    Some(self.state.borrow_mut().get_max_geometry_output_vertices())
+  }
+
+  unsafe fn program_binary(program: &Self::ProgramRepr) -> Option<(u32, Vec<u8>)> {
+    if !gl::GetProgramBinary::is_loaded() {
+      return None;
+    }
+
+    let mut len: GLint = 0;
+    gl::GetProgramiv(program.handle, gl::PROGRAM_BINARY_LENGTH, &mut len);
+
+    if len <= 0 {
+      return None;
+    }
+
+    let mut binary = vec![0u8; len as usize];
+    let mut written: GLsizei = 0;
+    let mut format: GLenum = 0;
+    gl::GetProgramBinary(
+      program.handle,
+      len,
+      &mut written,
+      &mut format,
+      binary.as_mut_ptr() as *mut c_void,
+    );
+    binary.truncate(written.max(0) as usize);
+
+    if binary.is_empty() {
+      None
+    } else {
+      Some((format, binary))
+    }
+  }
+
+  unsafe fn uniform_block_layout(program: &Self::ProgramRepr, name: &str) -> Option<BlockLayout> {
+    let c_name = CString::new(name.as_bytes()).ok()?;
+    let index = gl::GetUniformBlockIndex(program.handle, c_name.as_ptr() as *const GLchar);
+
+    if index == gl::INVALID_INDEX {
+      return None;
+    }
+
+    let mut size: GLint = 0;
+    gl::GetActiveUniformBlockiv(
+      program.handle,
+      index,
+      gl::UNIFORM_BLOCK_DATA_SIZE,
+      &mut size,
+    );
+
+    let mut members_nb: GLint = 0;
+    gl::GetActiveUniformBlockiv(
+      program.handle,
+      index,
+      gl::UNIFORM_BLOCK_ACTIVE_UNIFORMS,
+      &mut members_nb,
+    );
+
+    let mut indices: Vec<GLint> = vec![0; members_nb.max(0) as usize];
+    gl::GetActiveUniformBlockiv(
+      program.handle,
+      index,
+      gl::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+      indices.as_mut_ptr(),
+    );
+    let indices: Vec<GLuint> = indices.into_iter().map(|i| i as GLuint).collect();
+
+    let query = |pname| {
+      let mut values: Vec<GLint> = vec![0; indices.len()];
+      gl::GetActiveUniformsiv(
+        program.handle,
+        indices.len() as GLsizei,
+        indices.as_ptr(),
+        pname,
+        values.as_mut_ptr(),
+      );
+      values
+    };
+    let offsets = query(gl::UNIFORM_OFFSET);
+    let strides = query(gl::UNIFORM_ARRAY_STRIDE);
+    let name_lens = query(gl::UNIFORM_NAME_LENGTH);
+
+    let mut members = indices
+      .iter()
+      .zip(name_lens)
+      .zip(offsets.into_iter().zip(strides))
+      .map(|((&uniform_index, name_len), (offset, stride))| {
+        let mut name = vec![0u8; name_len.max(1) as usize];
+        let mut written: GLsizei = 0;
+        gl::GetActiveUniformName(
+          program.handle,
+          uniform_index,
+          name.len() as GLsizei,
+          &mut written,
+          name.as_mut_ptr() as *mut GLchar,
+        );
+        name.truncate(written.max(0) as usize);
+
+        (
+          String::from_utf8_lossy(&name).into_owned(),
+          offset.max(0) as usize,
+          stride.max(0) as usize,
+        )
+      })
+      .collect::<Vec<_>>();
+    members.sort_by_key(|&(_, offset, _)| offset);
+
+    Some(BlockLayout {
+      size: size.max(0) as usize,
+      members,
+    })
+  }
+
+  unsafe fn new_program_from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    if !supports_program_binary_format(format) {
+      return Err(ProgramError::binary_rejected(format!(
+        "unsupported program binary format {:#x}",
+        format
+      )));
+    }
+
+    let program = Program {
+      handle: gl::CreateProgram(),
+      state: self.state.clone(),
+    };
+
+    gl::ProgramBinary(
+      program.handle,
+      format,
+      binary.as_ptr() as *const c_void,
+      binary.len() as GLsizei,
+    );
+
+    let mut linked: GLint = gl::FALSE.into();
+    gl::GetProgramiv(program.handle, gl::LINK_STATUS, &mut linked);
+
+    self
+      .state
+      .borrow_mut()
+      .check_errors("program creation from binary");
+
+    if linked == gl::TRUE.into() {
+      Ok(program)
+    } else {
+      Err(ProgramError::binary_rejected(program.info_log()))
+    }
   }
 
   unsafe fn apply_semantics<Sem>(
@@ -244,6 +469,34 @@ unsafe impl Shader for GL33 {
   }
 }
 
+unsafe impl ShaderFeedback for GL33 {
+  unsafe fn apply_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[&str],
+    mode: FeedbackMode,
+  ) -> Result<Vec<String>, ProgramError> {
+    let buffer_mode = match mode {
+      FeedbackMode::Interleaved => gl::INTERLEAVED_ATTRIBS,
+      FeedbackMode::Separate => gl::SEPARATE_ATTRIBS,
+    };
+
+    if link_feedback_varyings(program, varyings, buffer_mode).is_ok() {
+      return Ok(Vec::new());
+    }
+
+    // linking fails as soon as a single varying is missing; find them out by trying each varying on its own, then
+    // link with the found ones only
+    let (found, missing): (Vec<&str>, Vec<&str>) = varyings
+      .iter()
+      .copied()
+      .partition(|&varying| link_feedback_varyings(program, &[varying], buffer_mode).is_ok());
+
+    link_feedback_varyings(program, &found, buffer_mode)?;
+
+    Ok(missing.into_iter().map(String::from).collect())
+  }
+}
+
 fn opengl_shader_type(t: StageType) -> GLenum {
   match t {
     StageType::TessellationControlShader => gl::TESS_CONTROL_SHADER,
@@ -341,6 +594,20 @@ fn check_uniform_type_match(
     }
   }
 
+  // GLSL booleans can also be set with integers, so accept integer uniforms for them
+  let int_as_bool = matches!(
+    (ty, glty),
+    (UniformType::Int, gl::BOOL)
+      | (UniformType::IVec2, gl::BOOL_VEC2)
+      | (UniformType::IVec3, gl::BOOL_VEC3)
+      | (UniformType::IVec4, gl::BOOL_VEC4)
+  );
+
+  if int_as_bool {
+    log::debug!("uniform {} is a GLSL boolean set with integers", name);
+    return Ok(());
+  }
+
   milkcheck!(
     ty,
     // scalars
@@ -378,19 +645,25 @@ fn check_uniform_type_match(
     (ISampler3D, INT_SAMPLER_3D),
     (ISampler1DArray, INT_SAMPLER_1D_ARRAY),
     (ISampler2DArray, INT_SAMPLER_2D_ARRAY),
+    (ISampler2DMS, INT_SAMPLER_2D_MULTISAMPLE),
     (UISampler1D, UNSIGNED_INT_SAMPLER_1D),
     (UISampler2D, UNSIGNED_INT_SAMPLER_2D),
     (UISampler3D, UNSIGNED_INT_SAMPLER_3D),
     (UISampler1DArray, UNSIGNED_INT_SAMPLER_1D_ARRAY),
     (UISampler2DArray, UNSIGNED_INT_SAMPLER_2D_ARRAY),
+    (UISampler2DMS, UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE),
     (Sampler1D, SAMPLER_1D),
     (Sampler2D, SAMPLER_2D),
     (Sampler3D, SAMPLER_3D),
     (Sampler1DArray, SAMPLER_1D_ARRAY),
     (Sampler2DArray, SAMPLER_2D_ARRAY),
+    (Sampler2DMS, SAMPLER_2D_MULTISAMPLE),
     (ICubemap, INT_SAMPLER_CUBE),
     (UICubemap, UNSIGNED_INT_SAMPLER_CUBE),
     (Cubemap, SAMPLER_CUBE),
+    (ISamplerBuffer, INT_SAMPLER_BUFFER),
+    (UISamplerBuffer, UNSIGNED_INT_SAMPLER_BUFFER),
+    (SamplerBuffer, SAMPLER_BUFFER),
   )
 }
 
@@ -418,6 +691,33 @@ where
   warnings
 }
 
+/// Declare the feedback varyings of a program and relink it.
+fn link_feedback_varyings(
+  program: &Program,
+  varyings: &[&str],
+  buffer_mode: GLenum,
+) -> Result<(), ProgramError> {
+  let c_names = varyings
+    .iter()
+    .map(|varying| CString::new(varying.as_bytes()).unwrap())
+    .collect::<Vec<_>>();
+  let c_ptrs = c_names
+    .iter()
+    .map(|c_name| c_name.as_ptr() as *const GLchar)
+    .collect::<Vec<_>>();
+
+  unsafe {
+    gl::TransformFeedbackVaryings(
+      program.handle,
+      c_ptrs.len() as GLsizei,
+      c_ptrs.as_ptr(),
+      buffer_mode,
+    );
+  }
+
+  program.link()
+}
+
 fn get_vertex_attrib_location(
   program: &Program,
   name: &str,
@@ -464,10 +764,20 @@ macro_rules! impl_Uniformable {
       unsafe fn update(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
         gl::$f(uniform.index(), 1, value.as_ptr());
       }
+
+      unsafe fn update_elements(
+        _: &mut Program,
+        elements: &'a [Uniform<$t>],
+        values: Vec<Self::Target>,
+      ) {
+        if let Some(first) = elements.first() {
+          gl::$f(first.index(), values.len() as GLsizei, values.as_ptr() as _);
+        }
+      }
     }
   };
 
-  ($t:ty, $uty:tt, $f:tt) => {
+  ($t:ty, $uty:tt, $f:tt, $fv:tt) => {
     unsafe impl<'a> Uniformable<'a, $t> for GL33 {
       type Target = $t;
 
@@ -480,6 +790,16 @@ macro_rules! impl_Uniformable {
       unsafe fn update(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
         gl::$f(uniform.index(), value);
       }
+
+      unsafe fn update_elements(
+        _: &mut Program,
+        elements: &'a [Uniform<$t>],
+        values: Vec<Self::Target>,
+      ) {
+        if let Some(first) = elements.first() {
+          gl::$fv(first.index(), values.len() as GLsizei, values.as_ptr());
+        }
+      }
     }
   };
 
@@ -503,6 +823,16 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a, const N: usize> UniformableMatrix<'a, Arr<$t, N>> for GL33 {
+      unsafe fn update_transposed(
+        _: &mut Program,
+        uniform: &'a Uniform<Arr<$t, N>>,
+        value: Self::Target,
+      ) {
+        gl::$f(uniform.index(), N as GLsizei, gl::TRUE, value.as_ptr() as _);
+      }
+    }
   };
 
   (mat $t:ty, $uty:tt, $f:tt) => {
@@ -519,10 +849,36 @@ macro_rules! impl_Uniformable {
         gl::$f(uniform.index(), 1, gl::FALSE, value.as_ptr() as _);
       }
     }
+
+    unsafe impl<'a> UniformableMatrix<'a, $t> for GL33 {
+      unsafe fn update_transposed(_: &mut Program, uniform: &'a Uniform<$t>, value: Self::Target) {
+        gl::$f(uniform.index(), 1, gl::TRUE, value.as_ptr() as _);
+      }
+    }
   };
 }
 
-impl_Uniformable!(i32, Int, Uniform1i);
+macro_rules! impl_UniformableReadback {
+  ($t:ty, $f:tt) => {
+    unsafe impl UniformableReadback<$t> for GL33 {
+      unsafe fn get(program: &Program, uniform: &Uniform<$t>) -> Option<$t> {
+        if uniform.index() < 0 {
+          return None;
+        }
+
+        let mut value: $t = mem::zeroed();
+        gl::$f(
+          program.handle,
+          uniform.index(),
+          &mut value as *mut $t as *mut _,
+        );
+        Some(value)
+      }
+    }
+  };
+}
+
+impl_Uniformable!(i32, Int, Uniform1i, Uniform1iv);
 impl_Uniformable!(vec Vec2<i32>, IVec2, Uniform2iv);
 impl_Uniformable!(vec Vec3<i32>, IVec3, Uniform3iv);
 impl_Uniformable!(vec Vec4<i32>, IVec4, Uniform4iv);
@@ -532,7 +888,7 @@ impl_Uniformable!(Arr<Vec2<i32>>, IVec2, Uniform2iv);
 impl_Uniformable!(Arr<Vec3<i32>>, IVec3, Uniform3iv);
 impl_Uniformable!(Arr<Vec4<i32>>, IVec4, Uniform4iv);
 
-impl_Uniformable!(u32, UInt, Uniform1ui);
+impl_Uniformable!(u32, UInt, Uniform1ui, Uniform1uiv);
 impl_Uniformable!(vec Vec2<u32>, UIVec2, Uniform2uiv);
 impl_Uniformable!(vec Vec3<u32>, UIVec3, Uniform3uiv);
 impl_Uniformable!(vec Vec4<u32>, UIVec4, Uniform4uiv);
@@ -541,7 +897,7 @@ impl_Uniformable!(Arr<Vec2<u32>>, UIVec2, Uniform2uiv);
 impl_Uniformable!(Arr<Vec3<u32>>, UIVec3, Uniform3uiv);
 impl_Uniformable!(Arr<Vec4<u32>>, UIVec4, Uniform4uiv);
 
-impl_Uniformable!(f32, Float, Uniform1f);
+impl_Uniformable!(f32, Float, Uniform1f, Uniform1fv);
 impl_Uniformable!(vec Vec2<f32>, Vec2, Uniform2fv);
 impl_Uniformable!(vec Vec3<f32>, Vec3, Uniform3fv);
 impl_Uniformable!(vec Vec4<f32>, Vec4, Uniform4fv);
@@ -551,7 +907,7 @@ impl_Uniformable!(Arr<Vec3<f32>>, Vec3, Uniform3fv);
 impl_Uniformable!(Arr<Vec4<f32>>, Vec4, Uniform4fv);
 
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
-impl_Uniformable!(f64, Double, Uniform1d);
+impl_Uniformable!(f64, Double, Uniform1d, Uniform1dv);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
 impl_Uniformable!(vec Vec2<f64>, DVec2, Uniform2dv);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
@@ -591,6 +947,39 @@ impl_Uniformable!(mat Mat44<f64>, DM44, UniformMatrix4dv);
 #[cfg(feature = "GL_ARB_gpu_shader_fp64")]
 impl_Uniformable!(mat Arr<Mat44<f64>>, DM44, UniformMatrix4dv);
 
+impl_UniformableReadback!(i32, GetUniformiv);
+impl_UniformableReadback!(Vec2<i32>, GetUniformiv);
+impl_UniformableReadback!(Vec3<i32>, GetUniformiv);
+impl_UniformableReadback!(Vec4<i32>, GetUniformiv);
+
+impl_UniformableReadback!(u32, GetUniformuiv);
+impl_UniformableReadback!(Vec2<u32>, GetUniformuiv);
+impl_UniformableReadback!(Vec3<u32>, GetUniformuiv);
+impl_UniformableReadback!(Vec4<u32>, GetUniformuiv);
+
+impl_UniformableReadback!(f32, GetUniformfv);
+impl_UniformableReadback!(Vec2<f32>, GetUniformfv);
+impl_UniformableReadback!(Vec3<f32>, GetUniformfv);
+impl_UniformableReadback!(Vec4<f32>, GetUniformfv);
+impl_UniformableReadback!(Mat22<f32>, GetUniformfv);
+impl_UniformableReadback!(Mat33<f32>, GetUniformfv);
+impl_UniformableReadback!(Mat44<f32>, GetUniformfv);
+
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(f64, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Vec2<f64>, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Vec3<f64>, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Vec4<f64>, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Mat22<f64>, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Mat33<f64>, GetUniformdv);
+#[cfg(feature = "GL_ARB_gpu_shader_fp64")]
+impl_UniformableReadback!(Mat44<f64>, GetUniformdv);
+
 unsafe impl<'a> Uniformable<'a, bool> for GL33 {
   type Target = bool;
 
@@ -805,6 +1194,12 @@ where
       (PixelType::Integral, Dim::Dim2Array) => UniformType::ISampler2DArray,
       (PixelType::Unsigned, Dim::Dim2Array) => UniformType::UISampler2DArray,
       (PixelType::Floating, Dim::Dim2Array) => UniformType::Sampler2DArray,
+
+      (PixelType::NormIntegral, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
+      (PixelType::NormUnsigned, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
+      (PixelType::Integral, Dim::Dim2Multisample(_)) => UniformType::ISampler2DMS,
+      (PixelType::Unsigned, Dim::Dim2Multisample(_)) => UniformType::UISampler2DMS,
+      (PixelType::Floating, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
     }
   }
 
@@ -817,6 +1212,33 @@ where
   }
 }
 
+unsafe impl<'a, S> Uniformable<'a, TextureBufferBinding<S>> for GL33
+where
+  S: 'a + SamplerType,
+{
+  type Target = TextureBufferBinding<S>;
+
+  const SIZE: usize = 0;
+
+  unsafe fn ty() -> UniformType {
+    match S::sample_type() {
+      PixelType::NormIntegral | PixelType::NormUnsigned | PixelType::Floating => {
+        UniformType::SamplerBuffer
+      }
+      PixelType::Integral => UniformType::ISamplerBuffer,
+      PixelType::Unsigned => UniformType::UISamplerBuffer,
+    }
+  }
+
+  unsafe fn update(
+    _: &mut Program,
+    uniform: &'a Uniform<TextureBufferBinding<S>>,
+    value: Self::Target,
+  ) {
+    gl::Uniform1i(uniform.index(), value.binding() as GLint)
+  }
+}
+
 unsafe impl<T> ShaderData<T> for GL33
 where
   T: Std140,
@@ -4,16 +4,28 @@ use crate::gl33::{
   depth_stencil::{
     comparison_to_glenum, glenum_to_comparison, glenum_to_stencil_op, stencil_op_to_glenum,
   },
+  error_check::{debug_message_callback, glenum_to_error_kind, has_extension, has_khr_debug},
   vertex_restart::VertexRestart,
 };
 use gl::types::*;
 use luminance::{
-  blending::{Equation, Factor},
+  blending::{Blending, BlendingMode, Equation, Factor},
+  context::StateSnapshot,
   depth_stencil::{Comparison, StencilOperations, StencilTest, Write},
+  error_check::{BackendError, BackendErrorKind, ErrorLog},
   face_culling::{FaceCullingMode, FaceCullingOrder},
   scissor::ScissorRegion,
 };
-use std::{cell::RefCell, error, ffi::CStr, fmt, marker::PhantomData, os::raw::c_char};
+use std::{
+  cell::RefCell,
+  error,
+  ffi::CStr,
+  fmt,
+  marker::PhantomData,
+  os::raw::{c_char, c_void},
+  ptr,
+  rc::Rc,
+};
 
 // TLS synchronization barrier for `GLState`.
 //
@@ -91,6 +103,14 @@ where
   }
 }
 
+/// Draw buffers of a framebuffer overridden by a render state.
+#[derive(Debug)]
+struct DrawBuffersOverride {
+  framebuffer: GLuint,
+  color_attachments: usize,
+  draw_buffers: Cached<Vec<GLenum>>,
+}
+
 /// The graphics state.
 ///
 /// This type represents the current state of a given graphics context. It acts
@@ -114,6 +134,9 @@ pub struct GLState {
 
   // blending
   blending_state: Cached<BlendingState>,
+  // bit i is set if blending is enabled for the draw buffer i; not cached when blending was toggled globally
+  blending_buffers: Cached<u32>,
+  max_draw_buffers: u32,
   blending_equations: Cached<BlendingEquations>,
   blending_funcs: Cached<BlendingFactors>,
 
@@ -138,6 +161,20 @@ pub struct GLState {
   scissor_state: Cached<ScissorState>,
   scissor_region: Cached<ScissorRegion>,
 
+  // clip distances; bit i is set if GL_CLIP_DISTANCEi is enabled
+  clip_distances: Cached<u32>,
+  max_clip_distances: u32,
+
+  // sample shading; the minimum fraction of samples to shade, if enabled
+  min_sample_shading: Cached<Option<f32>>,
+  // whether a draw framebuffer, identified by its handle, is multisampled; queried the first time sample shading is
+  // asked while it’s bound
+  sample_buffers: Option<(GLuint, bool)>,
+  // whether the point size is set by shaders
+  program_point_size: Cached<bool>,
+  // color channels written to the color attachments
+  color_mask: Cached<[bool; 4]>,
+
   // vertex restart
   vertex_restart: Cached<VertexRestart>,
 
@@ -146,6 +183,9 @@ pub struct GLState {
 
   // texture
   current_texture_unit: Cached<GLenum>,
+  // pixel row alignments forced for texel transfers; picked from the byte size of a row if None
+  pack_alignment: Option<usize>,
+  unpack_alignment: Option<usize>,
   bound_textures: Vec<(GLenum, GLuint)>,
 
   // texture buffer used to optimize texture creation; regular textures typically will never ask
@@ -168,6 +208,15 @@ pub struct GLState {
   // framebuffer
   bound_draw_framebuffer: Cached<GLuint>,
 
+  // draw buffers; the framebuffer the current pipeline renders to and its number of color attachments, and the
+  // framebuffer which draw buffers are overridden by a render state, if any
+  pipeline_framebuffer: GLuint,
+  pipeline_color_attachments: usize,
+  draw_buffers_override: Option<DrawBuffersOverride>,
+
+  // whether a pipeline statistics query is active; OpenGL allows a single active query per query target
+  pipeline_stats_query_active: bool,
+
   // vertex array
   bound_vertex_array: GLuint,
 
@@ -186,11 +235,42 @@ pub struct GLState {
   // OpenGL version; cached when asked the first time and then re-used
   gl_version: Option<String>,
 
+  // OpenGL major and minor version numbers; cached when asked the first time and then re-used
+  gl_version_number: Option<(u32, u32)>,
+
   // GLSL version; cached when asked the first time and then re-used
   glsl_version: Option<String>,
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  /// Maximum number of uniform buffers that can be bound at the same time.
+  max_shader_data_bindings: Option<u32>,
+
+  /// Maximum number of texture units that can be used at the same time.
+  max_texture_units: Option<u32>,
+
+  /// Recommended maximum numbers of vertices and indices of a single draw.
+  max_elements: Option<(usize, usize)>,
+
+  /// Maximum number of vertices a geometry shader can output.
+  max_geometry_output_vertices: Option<usize>,
+
+  /// Whether the mirror-clamp-to-edge wrap mode is available.
+  mirror_clamp_to_edge: Option<bool>,
+
+  /// Whether the stencil component of depth / stencil textures can be sampled.
+  stencil_texturing: Option<bool>,
+
+  // whether the OpenGL context is still alive; resources must not call OpenGL once it’s dead
+  context_alive: bool,
+
+  // error checking
+  error_checking: bool,
+  // whether errors are reported via the KHR_debug callback instead of polling
+  debug_output: bool,
+  // errors recorded while error checking is enabled; shared with the debug callback
+  errors: Rc<RefCell<ErrorLog>>,
 }
 
 impl GLState {
@@ -223,6 +303,8 @@ impl GLState {
       let clear_depth = Cached::new(get_ctx_clear_depth()?);
       let clear_stencil = Cached::new(get_ctx_clear_stencil()?);
       let blending_state = Cached::new(get_ctx_blending_state()?);
+      let blending_buffers = Cached(None);
+      let max_draw_buffers = get_ctx_max_draw_buffers()?;
       let blending_equations = Cached::new(get_ctx_blending_equations()?);
       let blending_funcs = Cached::new(get_ctx_blending_factors()?);
       let depth_test = Cached::new(get_ctx_depth_test()?);
@@ -248,13 +330,23 @@ impl GLState {
       let srgb_framebuffer_enabled = Cached::new(get_ctx_srgb_framebuffer_enabled()?);
       let scissor_state = Cached::new(get_ctx_scissor_state()?);
       let scissor_region = Cached::new(get_ctx_scissor_region()?);
+      let max_clip_distances = get_ctx_max_clip_distances()?;
+      let clip_distances = Cached::new(get_ctx_clip_distances(max_clip_distances)?);
       let vendor_name = None;
       let renderer_name = None;
       let gl_version = None;
+      let gl_version_number = None;
       let glsl_version = None;
       let max_texture_array_elements = None;
-
-      Ok(GLState {
+      let max_shader_data_bindings = None;
+      let max_texture_units = None;
+      let max_elements = None;
+      let max_geometry_output_vertices = None;
+      let mirror_clamp_to_edge = None;
+      let stencil_texturing = None;
+      let errors = Rc::new(RefCell::new(ErrorLog::default()));
+
+      let mut state = GLState {
         _a: PhantomData,
         binding_stack,
         viewport,
@@ -262,6 +354,8 @@ impl GLState {
         clear_depth,
         clear_stencil,
         blending_state,
+        blending_buffers,
+        max_draw_buffers,
         blending_equations,
         blending_funcs,
         depth_test,
@@ -282,17 +376,45 @@ impl GLState {
         bound_array_buffer,
         bound_element_array_buffer,
         bound_draw_framebuffer,
+        pipeline_framebuffer: 0,
+        pipeline_color_attachments: 0,
+        draw_buffers_override: None,
+        pipeline_stats_query_active: false,
         bound_vertex_array,
         current_program,
         srgb_framebuffer_enabled,
         scissor_state,
         scissor_region,
+        clip_distances,
+        max_clip_distances,
+        min_sample_shading: Cached(None),
+        sample_buffers: None,
+        program_point_size: Cached(None),
+        color_mask: Cached(None),
+        pack_alignment: None,
+        unpack_alignment: None,
         vendor_name,
         renderer_name,
         gl_version,
+        gl_version_number,
         glsl_version,
         max_texture_array_elements,
-      })
+        max_shader_data_bindings,
+        max_texture_units,
+        max_elements,
+        max_geometry_output_vertices,
+        mirror_clamp_to_edge,
+        stencil_texturing,
+        context_alive: true,
+        error_checking: false,
+        debug_output: false,
+        errors,
+      };
+
+      // error checking is enabled by default in debug builds only
+      state.set_error_checking(cfg!(debug_assertions));
+
+      Ok(state)
     }
   }
 
@@ -355,6 +477,11 @@ impl GLState {
     self.blending_state.invalidate()
   }
 
+  /// Invalidate the draw buffers blending is currently enabled for.
+  pub fn invalidate_blending_buffers(&mut self) {
+    self.blending_buffers.invalidate()
+  }
+
   /// Invalidate the currently in-use blending equation.
   pub fn invalidate_blending_equation(&mut self) {
     self.blending_equations.invalidate()
@@ -410,6 +537,72 @@ impl GLState {
     self.srgb_framebuffer_enabled.invalidate()
   }
 
+  /// Invalidate the currently enabled clip distances.
+  pub fn invalidate_clip_distances(&mut self) {
+    self.clip_distances.invalidate()
+  }
+
+  /// Invalidate the sample shading state.
+  pub fn invalidate_min_sample_shading(&mut self) {
+    self.min_sample_shading.invalidate();
+    self.sample_buffers = None;
+  }
+
+  /// Invalidate the program point size state.
+  pub fn invalidate_program_point_size(&mut self) {
+    self.program_point_size.invalidate()
+  }
+
+  /// Invalidate the color mask state.
+  pub fn invalidate_color_mask(&mut self) {
+    self.color_mask.invalidate()
+  }
+
+  /// Invalidate the whole cached state.
+  ///
+  /// This is needed when OpenGL was used behind luminance’s back, so that the next operations re-issue every setting
+  /// and binding they need.
+  pub fn invalidate_all(&mut self) {
+    self.invalidate_vertex_array();
+    self.invalidate_array_buffer();
+    self.invalidate_shader_program();
+    self.invalidate_framebuffer();
+    self.invalidate_element_array_buffer();
+    self.invalidate_texture_unit();
+    self.invalidate_bound_textures();
+    self.invalidate_bound_uniform_buffers();
+    self.invalidate_viewport();
+    self.invalidate_clear_color();
+    self.clear_depth.invalidate();
+    self.clear_stencil.invalidate();
+    self.invalidate_blending_state();
+    self.invalidate_blending_buffers();
+    self.invalidate_blending_equation();
+    self.invalidate_blending_func();
+    self.invalidate_depth_test();
+    self.invalidate_depth_test_comparison();
+    self.invalidate_depth_write();
+    self.stencil_test_enabled.invalidate();
+    self.stencil_test.invalidate();
+    self.stencil_operations.invalidate();
+    self.invalidate_face_culling_state();
+    self.invalidate_face_culling_order();
+    self.invalidate_face_culling_mode();
+    self.scissor_state.invalidate();
+    self.scissor_region.invalidate();
+    self.invalidate_vertex_restart();
+    self.invalidate_patch_vertex_nb();
+    self.invalidate_srgb_framebuffer_enabled();
+    self.invalidate_clip_distances();
+    self.invalidate_min_sample_shading();
+    self.invalidate_program_point_size();
+    self.invalidate_color_mask();
+
+    if let Some(ref mut draw_buffers_override) = self.draw_buffers_override {
+      draw_buffers_override.draw_buffers.invalidate();
+    }
+  }
+
   /// Marshal a string represented as `*const c_uchar`, represented by the input argument, into a `&str`.
   ///
   /// The string is returned in a lossy way, which means that non-unicode characters go wheeeeeeeeeeee.
@@ -454,6 +647,22 @@ impl GLState {
     })
   }
 
+  /// Get the OpenGL major and minor version numbers.
+  ///
+  /// Cache the numbers on the first call and then re-use them for later calls.
+  pub fn get_gl_version_number(&mut self) -> (u32, u32) {
+    self.gl_version_number.unwrap_or_else(|| {
+      let (mut major, mut minor) = (0, 0);
+      unsafe {
+        gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+        gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+      }
+      let version = (major as u32, minor as u32);
+      self.gl_version_number = Some(version);
+      version
+    })
+  }
+
   /// Get the GLSL version.
   ///
   /// Cache the version on the first call and then re-use it for later calls.
@@ -478,6 +687,194 @@ impl GLState {
     })
   }
 
+  /// Get the maximum number of uniform buffers that can be bound at the same time.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub fn get_max_shader_data_bindings(&mut self) -> u32 {
+    self.max_shader_data_bindings.unwrap_or_else(|| {
+      let mut max = 0;
+      unsafe { gl::GetIntegerv(gl::MAX_UNIFORM_BUFFER_BINDINGS, &mut max) };
+      let max = max as u32;
+      self.max_shader_data_bindings = Some(max);
+      max
+    })
+  }
+
+  /// Get the maximum number of texture units that can be used at the same time.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub fn get_max_texture_units(&mut self) -> u32 {
+    self.max_texture_units.unwrap_or_else(|| {
+      let mut max = 0;
+      unsafe { gl::GetIntegerv(gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS, &mut max) };
+      let max = max as u32;
+      self.max_texture_units = Some(max);
+      max
+    })
+  }
+
+  /// Get the recommended maximum numbers of vertices and indices of a single draw.
+  ///
+  /// Cache the numbers on the first call and then re-use them for later calls.
+  pub fn get_max_elements(&mut self) -> (usize, usize) {
+    self.max_elements.unwrap_or_else(|| {
+      let (mut max_vertices, mut max_indices) = (0, 0);
+      unsafe {
+        gl::GetIntegerv(gl::MAX_ELEMENTS_VERTICES, &mut max_vertices);
+        gl::GetIntegerv(gl::MAX_ELEMENTS_INDICES, &mut max_indices);
+      }
+      let max = (max_vertices as usize, max_indices as usize);
+      self.max_elements = Some(max);
+      max
+    })
+  }
+
+  /// Get the maximum number of vertices a geometry shader can output.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub fn get_max_geometry_output_vertices(&mut self) -> usize {
+    self.max_geometry_output_vertices.unwrap_or_else(|| {
+      let mut max = 0;
+      unsafe { gl::GetIntegerv(gl::MAX_GEOMETRY_OUTPUT_VERTICES, &mut max) };
+      let max = max as usize;
+      self.max_geometry_output_vertices = Some(max);
+      max
+    })
+  }
+
+  /// Check whether the mirror-clamp-to-edge wrap mode is available.
+  ///
+  /// It is core since OpenGL 4.4 and exposed by extensions before that. Cache the answer on the first call and then
+  /// re-use it for later calls.
+  pub fn supports_mirror_clamp_to_edge(&mut self) -> bool {
+    if let Some(supported) = self.mirror_clamp_to_edge {
+      return supported;
+    }
+
+    let supported = self.get_gl_version_number() >= (4, 4)
+      || unsafe {
+        has_extension(b"GL_ARB_texture_mirror_clamp_to_edge")
+          || has_extension(b"GL_EXT_texture_mirror_clamp")
+      };
+    self.mirror_clamp_to_edge = Some(supported);
+    supported
+  }
+
+  /// Check whether the stencil component of depth / stencil textures can be sampled (`GL_DEPTH_STENCIL_TEXTURE_MODE`).
+  ///
+  /// It is core since OpenGL 4.3 and exposed by an extension before that. Cache the answer on the first call and then
+  /// re-use it for later calls.
+  pub fn supports_stencil_texturing(&mut self) -> bool {
+    if let Some(supported) = self.stencil_texturing {
+      return supported;
+    }
+
+    let supported = self.get_gl_version_number() >= (4, 3)
+      || unsafe { has_extension(b"GL_ARB_stencil_texturing") };
+    self.stencil_texturing = Some(supported);
+    supported
+  }
+
+  /// Enable or disable error checking.
+  ///
+  /// If `KHR_debug` is available, a debug message callback is installed; otherwise, errors are polled with
+  /// `glGetError` after significant operations.
+  pub(crate) unsafe fn set_error_checking(&mut self, enabled: bool) {
+    if self.error_checking == enabled {
+      return;
+    }
+
+    if enabled {
+      // flush any error that occurred before we started checking
+      while gl::GetError() != gl::NO_ERROR {}
+
+      if has_khr_debug() {
+        gl::Enable(gl::DEBUG_OUTPUT);
+        gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+        gl::DebugMessageCallback(
+          Some(debug_message_callback),
+          Rc::as_ptr(&self.errors) as *const c_void,
+        );
+        self.debug_output = true;
+      }
+    } else if self.debug_output {
+      gl::DebugMessageCallback(None, ptr::null());
+      gl::Disable(gl::DEBUG_OUTPUT);
+      self.debug_output = false;
+    }
+
+    self.error_checking = enabled;
+  }
+
+  /// Whether the OpenGL context is still alive.
+  ///
+  /// Resources check this before releasing their OpenGL objects, as they can outlive the context.
+  pub(crate) fn is_context_alive(&self) -> bool {
+    self.context_alive
+  }
+
+  /// Mark the OpenGL context as dead.
+  pub(crate) fn mark_context_dead(&mut self) {
+    self.context_alive = false;
+  }
+
+  /// Pixel row alignment forced for texels read back from textures, if any.
+  pub(crate) fn pack_alignment(&self) -> Option<usize> {
+    self.pack_alignment
+  }
+
+  /// Pixel row alignment forced for texels uploaded to textures, if any.
+  pub(crate) fn unpack_alignment(&self) -> Option<usize> {
+    self.unpack_alignment
+  }
+
+  /// Force the pixel row alignments of texel transfers.
+  pub(crate) fn set_pixel_alignment(&mut self, pack: Option<usize>, unpack: Option<usize>) {
+    self.pack_alignment = pack;
+    self.unpack_alignment = unpack;
+  }
+
+  /// Whether error checking is enabled.
+  pub(crate) fn is_error_checking(&self) -> bool {
+    self.error_checking
+  }
+
+  /// Take all the errors recorded so far.
+  pub(crate) fn take_errors(&mut self) -> Vec<BackendError> {
+    self.errors.borrow_mut().take()
+  }
+
+  /// Record an error that OpenGL doesn’t report by itself, if error checking is enabled.
+  pub(crate) fn record_error(&mut self, context: &str, kind: BackendErrorKind) {
+    if self.error_checking {
+      self
+        .errors
+        .borrow_mut()
+        .push(BackendError::new(context, kind));
+    }
+  }
+
+  /// Check for errors after an operation described by `context`.
+  ///
+  /// This is a no-op if error checking is disabled or if errors are reported via the debug callback.
+  pub(crate) unsafe fn check_errors(&mut self, context: &str) {
+    if !self.error_checking || self.debug_output {
+      return;
+    }
+
+    let mut errors = self.errors.borrow_mut();
+
+    loop {
+      let code = gl::GetError();
+
+      if code == gl::NO_ERROR {
+        break;
+      }
+
+      errors.push(BackendError::new(context, glenum_to_error_kind(code)));
+    }
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -547,6 +944,31 @@ impl GLState {
       }
 
       self.blending_state.set(state);
+      self.blending_buffers.invalidate();
+    }
+  }
+
+  /// Enable blending for the draw buffers in `mask` and disable it for the other ones.
+  pub(crate) unsafe fn set_blending_buffers(&mut self, mask: u32) {
+    if self.blending_buffers.is_invalid(&mask) {
+      // if nothing is cached, every draw buffer must be written
+      let changed = self.blending_buffers.0.map_or(!0, |enabled| enabled ^ mask);
+
+      for i in 0..self.max_draw_buffers.min(32) {
+        let bit = 1 << i;
+
+        if changed & bit != 0 {
+          if mask & bit != 0 {
+            gl::Enablei(gl::BLEND, i);
+          } else {
+            gl::Disablei(gl::BLEND, i);
+          }
+        }
+      }
+
+      self.blending_buffers.set(mask);
+      // the global blending state doesn’t describe the per draw buffer states
+      self.blending_state.invalidate();
     }
   }
 
@@ -576,6 +998,86 @@ impl GLState {
     }
   }
 
+  /// Enable the clip distances whose bits are set in `mask` and disable the others.
+  ///
+  /// Bits past the maximum number of clip distances supported by the driver are ignored.
+  pub(crate) unsafe fn set_clip_distances(&mut self, mask: u32) {
+    if self.clip_distances.is_invalid(&mask) {
+      // if nothing is cached, every slot must be written
+      let changed = self.clip_distances.0.map_or(!0, |enabled| enabled ^ mask);
+
+      for i in 0..self.max_clip_distances.min(32) {
+        let bit = 1 << i;
+
+        if changed & bit != 0 {
+          if mask & bit != 0 {
+            gl::Enable(gl::CLIP_DISTANCE0 + i);
+          } else {
+            gl::Disable(gl::CLIP_DISTANCE0 + i);
+          }
+        }
+      }
+
+      self.clip_distances.set(mask);
+    }
+  }
+
+  /// Enable sample shading with a minimum fraction of samples to shade, or disable it.
+  ///
+  /// Sample shading requires OpenGL 4.0 or `ARB_sample_shading`; it’s ignored with a warning otherwise, as well as
+  /// if the bound draw framebuffer is not multisampled.
+  pub(crate) unsafe fn set_min_sample_shading(&mut self, min_sample_shading: Option<f32>) {
+    if !gl::MinSampleShading::is_loaded() {
+      if min_sample_shading.is_some() {
+        log::warn!("sample shading not supported; ignoring it");
+        self.record_error(
+          "render state",
+          BackendErrorKind::Unsupported("sample shading".to_owned()),
+        );
+      }
+
+      return;
+    }
+
+    let min_sample_shading = min_sample_shading.filter(|_| self.is_draw_framebuffer_multisampled());
+
+    if self.min_sample_shading.is_invalid(&min_sample_shading) {
+      match min_sample_shading {
+        Some(min_fraction) => {
+          gl::Enable(gl::SAMPLE_SHADING);
+          gl::MinSampleShading(min_fraction);
+        }
+
+        None => gl::Disable(gl::SAMPLE_SHADING),
+      }
+
+      self.min_sample_shading.set(min_sample_shading);
+    }
+  }
+
+  /// Let shaders set the point size, or use a fixed size of one pixel.
+  pub(crate) unsafe fn set_program_point_size(&mut self, program_point_size: bool) {
+    if self.program_point_size.is_invalid(&program_point_size) {
+      if program_point_size {
+        gl::Enable(gl::PROGRAM_POINT_SIZE);
+      } else {
+        gl::Disable(gl::PROGRAM_POINT_SIZE);
+      }
+
+      self.program_point_size.set(program_point_size);
+    }
+  }
+
+  /// Select the color channels written to the color attachments.
+  pub(crate) unsafe fn set_color_mask(&mut self, color_mask: [bool; 4]) {
+    if self.color_mask.is_invalid(&color_mask) {
+      let [r, g, b, a] = color_mask.map(|write| if write { gl::TRUE } else { gl::FALSE });
+      gl::ColorMask(r, g, b, a);
+
+      self.color_mask.set(color_mask);
+    }
+  }
+
   pub(crate) unsafe fn set_blending_equation(&mut self, equation: Equation) {
     let equations = BlendingEquations {
       rgb: equation,
@@ -682,6 +1184,99 @@ impl GLState {
     }
   }
 
+  /// Read the render state saved by a [`StateGuard`](luminance::context::StateGuard) from the context.
+  ///
+  /// Parts of the state that cannot be read back fall back to the OpenGL defaults.
+  pub(crate) unsafe fn snapshot(&self) -> StateSnapshot {
+    let blending_mode = match (get_ctx_blending_equations(), get_ctx_blending_factors()) {
+      (Ok(equations), Ok(factors)) => BlendingMode::Separate {
+        rgb: Blending {
+          equation: equations.rgb,
+          src: factors.src_rgb,
+          dst: factors.dst_rgb,
+        },
+        alpha: Blending {
+          equation: equations.alpha,
+          src: factors.src_alpha,
+          dst: factors.dst_alpha,
+        },
+      },
+
+      _ => BlendingMode::Combined(Blending {
+        equation: Equation::Additive,
+        src: Factor::One,
+        dst: Factor::Zero,
+      }),
+    };
+
+    StateSnapshot {
+      viewport: get_ctx_viewport().unwrap_or([0; 4]),
+      scissor_test: matches!(get_ctx_scissor_state(), Ok(ScissorState::On)),
+      scissor_region: get_ctx_scissor_region().unwrap_or(ScissorRegion {
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+      }),
+      blending: matches!(get_ctx_blending_state(), Ok(BlendingState::On)),
+      blending_mode,
+      depth_test: matches!(get_ctx_depth_test(), Ok(DepthTest::On)),
+      depth_test_comparison: get_ctx_depth_test_comparison().unwrap_or(Comparison::Less),
+      depth_write: get_ctx_depth_write().unwrap_or(Write::On),
+    }
+  }
+
+  /// Restore a render state previously read with [`GLState::snapshot`].
+  ///
+  /// The cached values are invalidated first so that every part of the state is issued again.
+  pub(crate) unsafe fn restore(&mut self, snapshot: &StateSnapshot) {
+    self.viewport.invalidate();
+    self.scissor_state.invalidate();
+    self.scissor_region.invalidate();
+    self.blending_state.invalidate();
+    self.blending_buffers.invalidate();
+    self.blending_equations.invalidate();
+    self.blending_funcs.invalidate();
+    self.depth_test.invalidate();
+    self.depth_test_comparison.invalidate();
+    self.depth_write.invalidate();
+
+    self.set_viewport(snapshot.viewport);
+
+    self.set_scissor_region(&snapshot.scissor_region);
+    self.set_scissor_state(if snapshot.scissor_test {
+      ScissorState::On
+    } else {
+      ScissorState::Off
+    });
+
+    match snapshot.blending_mode {
+      BlendingMode::Combined(b) => {
+        self.set_blending_equation(b.equation);
+        self.set_blending_func(b.src, b.dst);
+      }
+
+      BlendingMode::Separate { rgb, alpha } => {
+        self.set_blending_equation_separate(rgb.equation, alpha.equation);
+        self.set_blending_func_separate(rgb.src, rgb.dst, alpha.src, alpha.dst);
+      }
+    }
+
+    self.set_blending_state(if snapshot.blending {
+      BlendingState::On
+    } else {
+      BlendingState::Off
+    });
+
+    self.set_depth_test_comparison(snapshot.depth_test_comparison);
+    self.set_depth_test(if snapshot.depth_test {
+      DepthTest::On
+    } else {
+      DepthTest::Off
+    });
+    self.set_depth_write(snapshot.depth_write);
+  }
+
   pub(crate) unsafe fn enable_stencil_test(&mut self, enable: bool) {
     if self.stencil_test_enabled.is_invalid(&enable) {
       if enable {
@@ -746,7 +1341,7 @@ impl GLState {
       match mode {
         FaceCullingMode::Front => gl::CullFace(gl::FRONT),
         FaceCullingMode::Back => gl::CullFace(gl::BACK),
-        FaceCullingMode::Both => gl::CullFace(gl::FRONT_AND_BACK),
+        FaceCullingMode::FrontAndBack => gl::CullFace(gl::FRONT_AND_BACK),
       }
 
       self.face_culling_mode.set(mode);
@@ -782,9 +1377,15 @@ impl GLState {
 
   /// Bind a texture at the current texture unit.
   pub(crate) unsafe fn bind_texture(&mut self, target: GLenum, handle: GLuint) {
-    // Unwrap should be safe here, because we should always bind the texture unit before we bind the texture.
-    // Maybe this should be handled differently?
-    let unit = self.current_texture_unit.0.unwrap();
+    // the texture unit is unknown if it was invalidated; select one so that we know where the texture gets bound
+    let unit = match self.current_texture_unit.0 {
+      Some(unit) => unit,
+      None => {
+        self.set_texture_unit(0);
+        0
+      }
+    };
+
     self.bind_texture_at(target, handle, unit);
   }
 
@@ -869,6 +1470,122 @@ impl GLState {
     }
   }
 
+  /// Bind the framebuffer a pipeline renders to, which has `color_attachments` color attachments.
+  ///
+  /// The draw buffers of the previous framebuffer are restored first, if they were overridden.
+  pub(crate) unsafe fn bind_pipeline_framebuffer(
+    &mut self,
+    handle: GLuint,
+    color_attachments: usize,
+  ) {
+    self.reset_draw_buffers();
+    self.bind_draw_framebuffer(handle);
+    self.pipeline_framebuffer = handle;
+    self.pipeline_color_attachments = color_attachments;
+  }
+
+  /// Write the fragment output `i` to the color attachment `draw_buffers[i]` of the pipeline framebuffer, or discard it
+  /// if `None`.
+  pub(crate) unsafe fn set_draw_buffers(&mut self, draw_buffers: &[Option<usize>]) {
+    let color_attachments = self.pipeline_color_attachments;
+
+    if draw_buffers
+      .iter()
+      .flatten()
+      .any(|&index| index >= color_attachments)
+    {
+      self.record_error("render state draw buffers", BackendErrorKind::InvalidValue);
+      return;
+    }
+
+    let draw_buffers = draw_buffers
+      .iter()
+      .map(|draw_buffer| {
+        draw_buffer.map_or(gl::NONE, |index| gl::COLOR_ATTACHMENT0 + index as GLenum)
+      })
+      .collect::<Vec<_>>();
+    let framebuffer = self.pipeline_framebuffer;
+    let draw_buffers_override = self
+      .draw_buffers_override
+      .get_or_insert(DrawBuffersOverride {
+        framebuffer,
+        color_attachments,
+        draw_buffers: Cached(None),
+      });
+
+    if draw_buffers_override.draw_buffers.is_invalid(&draw_buffers) {
+      gl::DrawBuffers(draw_buffers.len() as GLsizei, draw_buffers.as_ptr());
+      draw_buffers_override.draw_buffers.set(draw_buffers);
+    }
+  }
+
+  /// Restore the draw buffers of the framebuffer they were overridden for, if any: the fragment output `i` is written
+  /// to the color attachment `i` again.
+  pub(crate) unsafe fn reset_draw_buffers(&mut self) {
+    if let Some(draw_buffers_override) = self.draw_buffers_override.take() {
+      self.bind_draw_framebuffer(draw_buffers_override.framebuffer);
+
+      if draw_buffers_override.framebuffer == 0 {
+        gl::DrawBuffer(gl::BACK);
+      } else if draw_buffers_override.color_attachments == 0 {
+        gl::DrawBuffer(gl::NONE);
+      } else {
+        let draw_buffers = (0..draw_buffers_override.color_attachments as GLenum)
+          .map(|index| gl::COLOR_ATTACHMENT0 + index)
+          .collect::<Vec<_>>();
+        gl::DrawBuffers(draw_buffers.len() as GLsizei, draw_buffers.as_ptr());
+      }
+    }
+  }
+
+  /// Forget the draw buffers override of a framebuffer that is about to be deleted.
+  pub(crate) fn forget_draw_buffers(&mut self, framebuffer: GLuint) {
+    if matches!(self.draw_buffers_override, Some(ref o) if o.framebuffer == framebuffer) {
+      self.draw_buffers_override = None;
+    }
+  }
+
+  /// Whether the bound draw framebuffer is multisampled.
+  ///
+  /// The answer is cached for the bound framebuffer, so that `GL_SAMPLE_BUFFERS` is only queried once per framebuffer.
+  unsafe fn is_draw_framebuffer_multisampled(&mut self) -> bool {
+    let framebuffer = self.bound_draw_framebuffer.0;
+
+    match (framebuffer, self.sample_buffers) {
+      (Some(framebuffer), Some((cached, multisampled))) if cached == framebuffer => multisampled,
+
+      _ => {
+        let mut sample_buffers = 0;
+        gl::GetIntegerv(gl::SAMPLE_BUFFERS, &mut sample_buffers);
+
+        let multisampled = sample_buffers != 0;
+        if !multisampled {
+          log::warn!("sample shading ignored: the framebuffer is not multisampled");
+        }
+
+        self.sample_buffers = framebuffer.map(|framebuffer| (framebuffer, multisampled));
+        multisampled
+      }
+    }
+  }
+
+  /// Forget whether a framebuffer that is about to be deleted or replaced is multisampled.
+  pub(crate) fn forget_sample_buffers(&mut self, framebuffer: GLuint) {
+    if matches!(self.sample_buffers, Some((cached, _)) if cached == framebuffer) {
+      self.sample_buffers = None;
+    }
+  }
+
+  /// Whether a pipeline statistics query is active.
+  pub(crate) fn is_pipeline_stats_query_active(&self) -> bool {
+    self.pipeline_stats_query_active
+  }
+
+  /// Mark whether a pipeline statistics query is active.
+  pub(crate) fn set_pipeline_stats_query_active(&mut self, active: bool) {
+    self.pipeline_stats_query_active = active;
+  }
+
   pub(crate) unsafe fn bind_vertex_array(&mut self, handle: GLuint, bind: Bind) {
     if bind == Bind::Forced || self.bound_vertex_array != handle {
       gl::BindVertexArray(handle);
@@ -880,6 +1597,10 @@ impl GLState {
     self.bind_vertex_array(0, Bind::Cached)
   }
 
+  pub(crate) fn current_program(&self) -> GLuint {
+    self.current_program
+  }
+
   pub(crate) unsafe fn use_program(&mut self, handle: GLuint) {
     if self.current_program != handle {
       gl::UseProgram(handle);
@@ -957,6 +1678,8 @@ pub enum StateQueryError {
   UnknownBlendingDstFactor(GLenum),
   /// Corrupted depth test state.
   UnknownDepthTestState(GLboolean),
+  /// Corrupted depth test comparison.
+  UnknownDepthTestComparison(GLint),
   /// Corrupted stencil test state.
   UnknownStencilTestState(GLboolean),
   /// Corrupted stencil test comparison.
@@ -994,6 +1717,9 @@ impl fmt::Display for StateQueryError {
         write!(f, "unknown blending destination factor: {}", k)
       }
       StateQueryError::UnknownDepthTestState(ref s) => write!(f, "unknown depth test state: {}", s),
+      StateQueryError::UnknownDepthTestComparison(ref k) => {
+        write!(f, "unknown depth test comparison: {}", k)
+      }
       StateQueryError::UnknownWriteState(ref s) => {
         write!(f, "unknown depth write state: {}", s)
       }
@@ -1084,6 +1810,30 @@ unsafe fn get_ctx_scissor_region() -> Result<ScissorRegion, StateQueryError> {
   })
 }
 
+unsafe fn get_ctx_max_clip_distances() -> Result<u32, StateQueryError> {
+  let mut max = 0;
+  gl::GetIntegerv(gl::MAX_CLIP_DISTANCES, &mut max);
+  Ok(max as u32)
+}
+
+unsafe fn get_ctx_max_draw_buffers() -> Result<u32, StateQueryError> {
+  let mut max = 0;
+  gl::GetIntegerv(gl::MAX_DRAW_BUFFERS, &mut max);
+  Ok(max as u32)
+}
+
+unsafe fn get_ctx_clip_distances(max_clip_distances: u32) -> Result<u32, StateQueryError> {
+  let mut mask = 0;
+
+  for i in 0..max_clip_distances.min(32) {
+    if gl::IsEnabled(gl::CLIP_DISTANCE0 + i) == gl::TRUE {
+      mask |= 1 << i;
+    }
+  }
+
+  Ok(mask)
+}
+
 unsafe fn get_ctx_blending_equations() -> Result<BlendingEquations, StateQueryError> {
   let mut rgb = gl::FUNC_ADD as GLint;
   let mut alpha = gl::FUNC_ADD as GLint;
@@ -1165,6 +1915,13 @@ unsafe fn get_ctx_depth_test() -> Result<DepthTest, StateQueryError> {
   }
 }
 
+unsafe fn get_ctx_depth_test_comparison() -> Result<Comparison, StateQueryError> {
+  let mut data = gl::LESS as GLint;
+
+  gl::GetIntegerv(gl::DEPTH_FUNC, &mut data);
+  glenum_to_comparison(data as GLenum).ok_or(StateQueryError::UnknownDepthTestComparison(data))
+}
+
 unsafe fn get_ctx_depth_write() -> Result<Write, StateQueryError> {
   let mut state = gl::FALSE;
 
@@ -1258,7 +2015,7 @@ unsafe fn get_ctx_face_culling_mode() -> Result<FaceCullingMode, StateQueryError
   match mode {
     gl::FRONT => Ok(FaceCullingMode::Front),
     gl::BACK => Ok(FaceCullingMode::Back),
-    gl::FRONT_AND_BACK => Ok(FaceCullingMode::Both),
+    gl::FRONT_AND_BACK => Ok(FaceCullingMode::FrontAndBack),
     _ => Err(StateQueryError::UnknownFaceCullingMode(mode)),
   }
 }
@@ -6,12 +6,15 @@ use crate::gl33::{
 };
 use gl::{self, types::*};
 use luminance::backend::tess::{
-  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  override_normalization, DynamicTess as DynamicTessBackend, IndexSlice as IndexSliceBackend,
+  InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
+  UpdateVertexAttribute as UpdateVertexAttributeBackend, VertexAttribPointer,
+  VertexBytes as VertexBytesBackend, VertexSlice as VertexSliceBackend,
 };
+use luminance::feedback::FeedbackError;
 use luminance::tess::{
-  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
-  TessMapError, TessVertexData,
+  Deinterleaved, DeinterleavedData, Interleaved, Mode, RawInterleaved, RawVertexData, TessError,
+  TessIndex, TessIndexType, TessMapError, TessUsage, TessVertexData,
 };
 use luminance::vertex::{
   Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
@@ -30,7 +33,7 @@ where
 }
 
 #[derive(Debug)]
-struct TessRaw<I>
+pub(crate) struct TessRaw<I>
 where
   I: TessIndex,
 {
@@ -38,6 +41,8 @@ where
   mode: GLenum,
   patch_vert_nb: usize,
   index_state: Option<IndexedDrawState<I>>,
+  usage: TessUsage,
+  update_count: u64,
   state: Rc<RefCell<GLState>>,
 }
 
@@ -50,14 +55,88 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
   ) -> Result<(), TessError> {
-    let vert_nb = vert_nb as GLsizei;
-    let inst_nb = inst_nb as GLsizei;
+    let mut gfx_st = self.state.borrow_mut();
+
+    // base instances require OpenGL 4.2 or ARB_base_instance
+    if base_instance != 0
+      && !(gl::DrawArraysInstancedBaseInstance::is_loaded()
+        && gl::DrawElementsInstancedBaseInstance::is_loaded())
+    {
+      return Err(TessError::unsupported_base_instance(base_instance));
+    }
+
+    gfx_st.bind_vertex_array(self.vao, Bind::Cached);
+
+    override_normalization::<_, AttribPointer, _>(&mut gfx_st, normalized_attribs, |gfx_st| {
+      self.draw(
+        gfx_st,
+        self.mode,
+        start_index,
+        vert_nb,
+        inst_nb,
+        base_instance,
+      )
+    });
+
+    gfx_st.check_errors("tess render");
 
+    Ok(())
+  }
+
+  /// Primitive mode captured when rendering this tessellation with transform feedback.
+  ///
+  /// Strips and fans are captured as their independent primitives; patches cannot be captured.
+  pub(crate) fn feedback_primitive(&self) -> Result<GLenum, FeedbackError> {
+    match self.mode {
+      gl::POINTS => Ok(gl::POINTS),
+      gl::LINES | gl::LINE_STRIP => Ok(gl::LINES),
+      gl::TRIANGLES | gl::TRIANGLE_FAN | gl::TRIANGLE_STRIP => Ok(gl::TRIANGLES),
+      _ => Err(FeedbackError::unsupported_mode(Mode::Patch(
+        self.patch_vert_nb,
+      ))),
+    }
+  }
+
+  /// Render with a debug program, which must be in use already.
+  ///
+  /// The wireframe is rendered with the primitive mode of the tessellation and a line polygon mode. Vectors are
+  /// rendered from points, expanded to lines by the geometry shader of the debug program. Patches are always rendered
+  /// as points, since debug programs have no tessellation stages.
+  pub(crate) unsafe fn render_debug(&self, start_index: usize, vert_nb: usize, wireframe: bool) {
     let mut gfx_st = self.state.borrow_mut();
     gfx_st.bind_vertex_array(self.vao, Bind::Cached);
 
-    if self.mode == gl::PATCHES {
+    if wireframe && self.mode != gl::PATCHES {
+      gl::PolygonMode(gl::FRONT_AND_BACK, gl::LINE);
+      self.draw(&mut gfx_st, self.mode, start_index, vert_nb, 1, 0);
+      gl::PolygonMode(gl::FRONT_AND_BACK, gl::FILL);
+    } else {
+      self.draw(&mut gfx_st, gl::POINTS, start_index, vert_nb, 1, 0);
+    }
+
+    gfx_st.check_errors("tess debug render");
+  }
+
+  /// Issue the draw call for the currently bound vertex array, with the given primitive mode.
+  ///
+  /// A non-zero `base_instance` requires the base instance draw calls to be loaded.
+  unsafe fn draw(
+    &self,
+    gfx_st: &mut GLState,
+    mode: GLenum,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_instance: usize,
+  ) {
+    let vert_nb = vert_nb as GLsizei;
+    let inst_nb = inst_nb as GLsizei;
+    let base_instance = base_instance as GLuint;
+
+    if mode == gl::PATCHES {
       gfx_st.set_patch_vertex_nb(self.patch_vert_nb);
     }
 
@@ -73,11 +152,20 @@ where
           gfx_st.set_vertex_restart(VertexRestart::Off);
         }
 
-        if inst_nb <= 1 {
-          gl::DrawElements(self.mode, vert_nb, index_type_to_glenum(index_ty), first);
+        if base_instance != 0 {
+          gl::DrawElementsInstancedBaseInstance(
+            mode,
+            vert_nb,
+            index_type_to_glenum(index_ty),
+            first,
+            inst_nb.max(1),
+            base_instance,
+          );
+        } else if inst_nb <= 1 {
+          gl::DrawElements(mode, vert_nb, index_type_to_glenum(index_ty), first);
         } else {
           gl::DrawElementsInstanced(
-            self.mode,
+            mode,
             vert_nb,
             index_type_to_glenum(index_ty),
             first,
@@ -90,15 +178,83 @@ where
         // direct render
         let first = start_index as GLint;
 
-        if inst_nb <= 1 {
-          gl::DrawArrays(self.mode, first, vert_nb);
+        if base_instance != 0 {
+          gl::DrawArraysInstancedBaseInstance(mode, first, vert_nb, inst_nb.max(1), base_instance);
+        } else if inst_nb <= 1 {
+          gl::DrawArrays(mode, first, vert_nb);
         } else {
-          gl::DrawArraysInstanced(self.mode, first, vert_nb, inst_nb);
+          gl::DrawArraysInstanced(mode, first, vert_nb, inst_nb);
         }
       }
     }
+  }
+}
 
-    Ok(())
+/// Vertex attribute pointer of the currently bound vertex array.
+struct AttribPointer {
+  index: GLuint,
+  buffer: GLuint,
+  size: GLint,
+  ty: GLenum,
+  normalized: bool,
+  integer: bool,
+  stride: GLsizei,
+  offset: *mut c_void,
+}
+
+impl VertexAttribPointer<GLState> for AttribPointer {
+  unsafe fn query(_: &mut GLState, index: usize) -> Option<Self> {
+    let index = index as GLuint;
+    let get = |pname| {
+      let mut value = 0;
+      gl::GetVertexAttribiv(index, pname, &mut value);
+      value
+    };
+
+    if get(gl::VERTEX_ATTRIB_ARRAY_ENABLED) == gl::FALSE as GLint {
+      return None;
+    }
+
+    let mut offset: *mut c_void = ptr::null_mut();
+    let offset_ptr: *mut *mut c_void = &mut offset;
+    gl::GetVertexAttribPointerv(index, gl::VERTEX_ATTRIB_ARRAY_POINTER, offset_ptr);
+
+    Some(AttribPointer {
+      index,
+      buffer: get(gl::VERTEX_ATTRIB_ARRAY_BUFFER_BINDING) as GLuint,
+      size: get(gl::VERTEX_ATTRIB_ARRAY_SIZE),
+      ty: get(gl::VERTEX_ATTRIB_ARRAY_TYPE) as GLenum,
+      normalized: get(gl::VERTEX_ATTRIB_ARRAY_NORMALIZED) != gl::FALSE as GLint,
+      integer: get(gl::VERTEX_ATTRIB_ARRAY_INTEGER) != gl::FALSE as GLint,
+      stride: get(gl::VERTEX_ATTRIB_ARRAY_STRIDE),
+      offset,
+    })
+  }
+
+  fn is_integer(&self) -> bool {
+    self.integer
+  }
+
+  fn is_normalized(&self) -> bool {
+    self.normalized
+  }
+
+  unsafe fn set(&self, state: &mut GLState, integer: bool, normalized: bool) {
+    state.bind_array_buffer(self.buffer, Bind::Cached);
+
+    if integer {
+      gl::VertexAttribIPointer(self.index, self.size, self.ty, self.stride, self.offset);
+    } else {
+      let normalized = if normalized { gl::TRUE } else { gl::FALSE };
+      gl::VertexAttribPointer(
+        self.index,
+        self.size,
+        self.ty,
+        normalized,
+        self.stride,
+        self.offset,
+      );
+    }
   }
 }
 
@@ -107,8 +263,18 @@ where
   I: TessIndex,
 {
   fn drop(&mut self) {
+    let mut state = self.state.borrow_mut();
+
+    if !state.is_context_alive() {
+      log::debug!(
+        "vertex array {} dropped after its context; skipping deletion",
+        self.vao
+      );
+      return;
+    }
+
     unsafe {
-      self.state.borrow_mut().unbind_vertex_array();
+      state.unbind_vertex_array();
       gl::DeleteVertexArrays(1, &self.vao);
     }
   }
@@ -121,7 +287,7 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
+  pub(crate) raw: TessRaw<I>,
   vertex_buffer: Option<Buffer<V>>,
   instance_buffer: Option<Buffer<W>>,
 }
@@ -141,6 +307,7 @@ where
     instance_data: Option<W::Data>,
     mode: Mode,
     restart_index: Option<I>,
+    usage: TessUsage,
   ) -> Result<Self::TessRepr, TessError> {
     let mut vao: GLuint = 0;
 
@@ -155,12 +322,20 @@ where
     // handle) don’t prevent us from binding here
     self.state.borrow_mut().bind_vertex_array(vao, Bind::Forced);
 
-    let vertex_buffer = build_interleaved_vertex_buffer(self, vertex_data)?;
+    let vertex_buffer = build_interleaved_vertex_buffer(self, vertex_data, usage)?;
 
     // in case of indexed render, create an index buffer
-    let index_state = build_index_buffer(self, index_data, restart_index)?;
+    let index_state = build_index_buffer(self, index_data, restart_index, usage)?;
+
+    let instance_buffer = build_interleaved_vertex_buffer(self, instance_data, usage)?;
 
-    let instance_buffer = build_interleaved_vertex_buffer(self, instance_data)?;
+    warn_element_limits(
+      self,
+      vertex_buffer.as_ref().map_or(0, |vb| vb.buf.len()),
+      index_state.as_ref().map_or(0, |ids| ids.buffer.len()),
+    );
+
+    self.state.borrow_mut().check_errors("tess creation");
 
     let mode = opengl_mode(mode);
     let state = self.state.clone();
@@ -169,6 +344,8 @@ where
       mode,
       patch_vert_nb,
       index_state,
+      usage,
+      update_count: 0,
       state,
     };
 
@@ -205,13 +382,129 @@ where
       .unwrap_or(0)
   }
 
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I> DynamicTessBackend<V, I> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+{
+  unsafe fn build_dynamic(&mut self, mode: Mode) -> Result<Self::TessRepr, TessError> {
+    let mut vao: GLuint = 0;
+
+    let patch_vert_nb = match mode {
+      Mode::Patch(nb) => nb,
+      _ => 0,
+    };
+
+    gl::GenVertexArrays(1, &mut vao);
+    self.state.borrow_mut().bind_vertex_array(vao, Bind::Forced);
+
+    // the storage starts empty but the vertex pointers must be set right away, as they are part of the vao
+    let vertex_buffer = Buffer::from_vec_with_usage(self, Vec::new(), gl::STREAM_DRAW);
+    self
+      .state
+      .borrow_mut()
+      .bind_array_buffer(vertex_buffer.handle(), Bind::Forced);
+    set_vertex_pointers(&V::vertex_desc());
+
+    let index_state = if I::INDEX_TYPE.is_some() {
+      let buffer = Buffer::from_vec_with_usage(self, Vec::new(), gl::STREAM_DRAW);
+      self
+        .state
+        .borrow_mut()
+        .bind_element_array_buffer(buffer.handle(), Bind::Forced);
+
+      Some(IndexedDrawState {
+        buffer,
+        restart_index: None,
+      })
+    } else {
+      None
+    };
+
+    self
+      .state
+      .borrow_mut()
+      .check_errors("dynamic tess creation");
+
+    let raw = TessRaw {
+      vao,
+      mode: opengl_mode(mode),
+      patch_vert_nb,
+      index_state,
+      usage: TessUsage::Stream,
+      update_count: 0,
+      state: self.state.clone(),
+    };
+
+    Ok(InterleavedTess {
+      raw,
+      vertex_buffer: Some(vertex_buffer),
+      instance_buffer: None,
+    })
+  }
+
+  unsafe fn set_dynamic_data(
+    tess: &mut Self::TessRepr,
+    vertices: &[V],
+    indices: &[I],
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    let mut state = tess.raw.state.borrow_mut();
+
+    // the element array buffer binding is part of the vao state, so bind it first
+    state.bind_vertex_array(tess.raw.vao, Bind::Cached);
+
+    if let Some(ref mut vb) = tess.vertex_buffer {
+      state.bind_array_buffer(vb.handle(), Bind::Cached);
+      vb.orphan_and_upload(gl::ARRAY_BUFFER, vertices);
+    }
+
+    match tess.raw.index_state {
+      Some(ref mut index_state) => {
+        state.bind_element_array_buffer(index_state.buffer.handle(), Bind::Forced);
+        index_state
+          .buffer
+          .orphan_and_upload(gl::ELEMENT_ARRAY_BUFFER, indices);
+      }
+
+      None if !indices.is_empty() => {
+        return Err(TessError::cannot_create(
+          "cannot stream indices into a non-indexed tessellation",
+        ));
+      }
+
+      None => (),
+    }
+
+    state.check_errors("dynamic tess upload");
+    tess.raw.update_count += 1;
+
+    Ok(())
   }
 }
 
@@ -235,7 +528,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::VertexSliceMutRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()?),
+      Some(ref mut vb) => {
+        tess.raw.update_count += 1;
+        Ok(vb.slice_buffer_mut()?)
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -261,7 +557,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
     match tess.raw.index_state {
-      Some(ref mut state) => Ok(state.buffer.slice_buffer_mut()?),
+      Some(ref mut state) => {
+        tess.raw.update_count += 1;
+        Ok(state.buffer.slice_buffer_mut()?)
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -289,12 +588,33 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()?),
+      Some(ref mut vb) => {
+        tess.raw.update_count += 1;
+        Ok(vb.slice_buffer_mut()?)
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
 }
 
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, Interleaved> for GL33
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    if let Some(ref vb) = tess.vertex_buffer {
+      f(as_bytes(&vb.slice_buffer()?));
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Debug)]
 pub struct DeinterleavedTess<V, I, W>
 where
@@ -302,7 +622,7 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
+  pub(crate) raw: TessRaw<I>,
   vertex_buffers: Vec<Buffer<u8>>,
   instance_buffers: Vec<Buffer<u8>>,
   _phantom: PhantomData<*const (V, W)>,
@@ -323,6 +643,7 @@ where
     instance_data: Option<W::Data>,
     mode: Mode,
     restart_index: Option<I>,
+    usage: TessUsage,
   ) -> Result<Self::TessRepr, TessError> {
     let mut vao: GLuint = 0;
 
@@ -337,12 +658,20 @@ where
     // handle) don’t prevent us from binding here
     self.state.borrow_mut().bind_vertex_array(vao, Bind::Forced);
 
-    let vertex_buffers = build_deinterleaved_vertex_buffers::<V>(self, vertex_data)?;
+    let vertex_buffers = build_deinterleaved_vertex_buffers::<V>(self, vertex_data, usage)?;
 
     // in case of indexed render, create an index buffer
-    let index_state = build_index_buffer(self, index_data, restart_index)?;
+    let index_state = build_index_buffer(self, index_data, restart_index, usage)?;
+
+    let instance_buffers = build_deinterleaved_vertex_buffers::<W>(self, instance_data, usage)?;
 
-    let instance_buffers = build_deinterleaved_vertex_buffers::<W>(self, instance_data)?;
+    warn_element_limits(
+      self,
+      vertex_buffers.first().map_or(0, |vb| vb.buf.len()),
+      index_state.as_ref().map_or(0, |ids| ids.buffer.len()),
+    );
+
+    self.state.borrow_mut().check_errors("tess creation");
 
     let mode = opengl_mode(mode);
     let state = self.state.clone();
@@ -351,6 +680,8 @@ where
       mode,
       patch_vert_nb,
       index_state,
+      usage,
+      update_count: 0,
       state,
     };
 
@@ -388,13 +719,29 @@ where
       .unwrap_or(0)
   }
 
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
   }
 }
 
@@ -424,6 +771,7 @@ where
     if tess.vertex_buffers.is_empty() {
       Err(TessMapError::forbidden_attributeless_mapping())
     } else {
+      tess.raw.update_count += 1;
       let buffer = &mut tess.vertex_buffers[V::RANK];
       let slice = buffer.slice_buffer_mut()?.transmute();
       Ok(slice)
@@ -451,7 +799,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
     match tess.raw.index_state {
-      Some(ref mut state) => Ok(state.buffer.slice_buffer_mut()?),
+      Some(ref mut state) => {
+        tess.raw.update_count += 1;
+        Ok(state.buffer.slice_buffer_mut()?)
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -485,6 +836,7 @@ where
     if tess.instance_buffers.is_empty() {
       Err(TessMapError::forbidden_attributeless_mapping())
     } else {
+      tess.raw.update_count += 1;
       let buffer = &mut tess.instance_buffers[W::RANK];
       let slice = buffer.slice_buffer_mut()?.transmute();
       Ok(slice)
@@ -492,9 +844,202 @@ where
   }
 }
 
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, Deinterleaved> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    // buffers are stored in rank order
+    for buffer in &tess.vertex_buffers {
+      f(&buffer.slice_buffer()?);
+    }
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> UpdateVertexAttributeBackend<V, I, W, T> for GL33
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>> + Deinterleave<T>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn update_vertex_attribute(
+    tess: &mut Self::TessRepr,
+    values: &[T],
+  ) -> Result<(), TessMapError> {
+    let buffer = tess
+      .vertex_buffers
+      .get_mut(V::RANK)
+      .ok_or_else(TessMapError::forbidden_attributeless_mapping)?;
+
+    let expected = buffer.len() / std::mem::size_of::<T>();
+    if values.len() != expected {
+      return Err(TessMapError::attribute_length_mismatch(
+        expected,
+        values.len(),
+      ));
+    }
+
+    buffer.update(as_bytes(values));
+    tess.raw.update_count += 1;
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct RawInterleavedTess<I>
+where
+  I: TessIndex,
+{
+  pub(crate) raw: TessRaw<I>,
+  vertex_buffer: Option<Buffer<u8>>,
+  vert_nb: usize,
+}
+
+unsafe impl<V, I, W> TessBackend<V, I, W, RawInterleaved> for GL33
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  type TessRepr = RawInterleavedTess<I>;
+
+  unsafe fn build(
+    &mut self,
+    vertex_data: Option<V::Data>,
+    index_data: Vec<I>,
+    instance_data: Option<W::Data>,
+    mode: Mode,
+    restart_index: Option<I>,
+    usage: TessUsage,
+  ) -> Result<Self::TessRepr, TessError> {
+    if instance_data.is_some() {
+      return Err(TessError::cannot_create(
+        "raw interleaved tessellations don’t support instance data",
+      ));
+    }
+
+    let mut vao: GLuint = 0;
+
+    let patch_vert_nb = match mode {
+      Mode::Patch(nb) => nb,
+      _ => 0,
+    };
+
+    gl::GenVertexArrays(1, &mut vao);
+
+    // force binding the vertex array so that previously bound vertex arrays (possibly the same
+    // handle) don’t prevent us from binding here
+    self.state.borrow_mut().bind_vertex_array(vao, Bind::Forced);
+
+    let vert_nb = vertex_data.as_ref().map_or(0, RawVertexData::len);
+    let vertex_buffer = build_raw_vertex_buffer(self, vertex_data, usage)?;
+
+    // in case of indexed render, create an index buffer
+    let index_state = build_index_buffer(self, index_data, restart_index, usage)?;
+
+    warn_element_limits(
+      self,
+      vert_nb,
+      index_state.as_ref().map_or(0, |ids| ids.buffer.len()),
+    );
+
+    self.state.borrow_mut().check_errors("raw tess creation");
+
+    let raw = TessRaw {
+      vao,
+      mode: opengl_mode(mode),
+      patch_vert_nb,
+      index_state,
+      usage,
+      update_count: 0,
+      state: self.state.clone(),
+    };
+
+    Ok(RawInterleavedTess {
+      raw,
+      vertex_buffer,
+      vert_nb,
+    })
+  }
+
+  unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
+    tess.vert_nb
+  }
+
+  unsafe fn tess_indices_nb(tess: &Self::TessRepr) -> usize {
+    tess
+      .raw
+      .index_state
+      .as_ref()
+      .map(|ids| ids.buffer.len())
+      .unwrap_or(0)
+  }
+
+  unsafe fn tess_instances_nb(_: &Self::TessRepr) -> usize {
+    0
+  }
+
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
+  unsafe fn render(
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, RawInterleaved> for GL33
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    if let Some(ref vb) = tess.vertex_buffer {
+      f(&vb.slice_buffer()?);
+    }
+
+    Ok(())
+  }
+}
+
+// View a slice of vertices as raw bytes.
+fn as_bytes<T>(slice: &[T]) -> &[u8] {
+  unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice)) }
+}
+
 fn build_interleaved_vertex_buffer<V>(
   gl33: &mut GL33,
   vertices: Option<Vec<V>>,
+  usage: TessUsage,
 ) -> Result<Option<Buffer<V>>, TessError>
 where
   V: Vertex,
@@ -506,7 +1051,7 @@ where
       let vb = if vertices.is_empty() {
         None
       } else {
-        let vb = unsafe { Buffer::from_vec(gl33, vertices) };
+        let vb = unsafe { Buffer::from_vec_with_usage(gl33, vertices, opengl_usage(usage)) };
 
         // force binding as it’s meaningful when a vao is bound
         unsafe {
@@ -530,6 +1075,7 @@ where
 fn build_deinterleaved_vertex_buffers<V>(
   gl33: &mut GL33,
   vertices: Option<Vec<DeinterleavedData>>,
+  usage: TessUsage,
 ) -> Result<Vec<Buffer<u8>>, TessError>
 where
   V: Vertex,
@@ -540,7 +1086,8 @@ where
         .into_iter()
         .zip(V::vertex_desc())
         .map(|(attribute, fmt)| {
-          let vb = unsafe { Buffer::from_vec(gl33, attribute.into_vec()) };
+          let vb =
+            unsafe { Buffer::from_vec_with_usage(gl33, attribute.into_vec(), opengl_usage(usage)) };
 
           // force binding as it’s meaningful when a vao is bound
           unsafe {
@@ -560,18 +1107,79 @@ where
   }
 }
 
+fn build_raw_vertex_buffer(
+  gl33: &mut GL33,
+  vertices: Option<RawVertexData>,
+  usage: TessUsage,
+) -> Result<Option<Buffer<u8>>, TessError> {
+  match vertices {
+    Some(vertices) if !vertices.is_empty() => {
+      let (raw, attributes) = vertices.into_parts();
+      let vb = unsafe { Buffer::from_vec_with_usage(gl33, raw, opengl_usage(usage)) };
+
+      // force binding as it’s meaningful when a vao is bound
+      unsafe {
+        gl33
+          .state
+          .borrow_mut()
+          .bind_array_buffer(vb.handle(), Bind::Forced)
+      };
+
+      // strides and offsets are explicit, so we don’t compute them from the attributes
+      for attribute in attributes {
+        let desc = VertexBufferDesc {
+          index: attribute.index,
+          name: "",
+          instancing: VertexInstancing::Off,
+          attrib_desc: attribute.attrib_desc,
+        };
+
+        set_component_format(attribute.stride as GLsizei, attribute.offset, &desc);
+      }
+
+      Ok(Some(vb))
+    }
+
+    _ => Ok(None),
+  }
+}
+
+/// Warn about tessellations exceeding the recommended maximum numbers of vertices and indices of a single draw.
+///
+/// Those are not hard limits: such tessellations still render correctly, but possibly more slowly.
+fn warn_element_limits(gl33: &mut GL33, vert_nb: usize, idx_nb: usize) {
+  let (max_vertices, max_indices) = gl33.state.borrow_mut().get_max_elements();
+
+  if vert_nb > max_vertices {
+    log::warn!(
+      "tessellation has {} vertices, more than the recommended maximum of {}",
+      vert_nb,
+      max_vertices
+    );
+  }
+
+  if idx_nb > max_indices {
+    log::warn!(
+      "tessellation has {} indices, more than the recommended maximum of {}",
+      idx_nb,
+      max_indices
+    );
+  }
+}
+
 /// Turn a [`Vec`] of indices to an [`IndexedDrawState`].
 fn build_index_buffer<I>(
   gl33: &mut GL33,
   data: Vec<I>,
   restart_index: Option<I>,
+  usage: TessUsage,
 ) -> Result<Option<IndexedDrawState<I>>, TessError>
 where
   I: TessIndex,
 {
   let ids = if !data.is_empty() {
     let ib = IndexedDrawState {
-      buffer: unsafe { Buffer::from_vec(gl33, data) },
+      buffer: unsafe { Buffer::from_vec_with_usage(gl33, data, opengl_usage(usage)) },
       restart_index,
     };
 
@@ -739,6 +1347,14 @@ fn opengl_mode(mode: Mode) -> GLenum {
   }
 }
 
+fn opengl_usage(usage: TessUsage) -> GLenum {
+  match usage {
+    TessUsage::Static => gl::STATIC_DRAW,
+    TessUsage::Dynamic => gl::DYNAMIC_DRAW,
+    TessUsage::Stream => gl::STREAM_DRAW,
+  }
+}
+
 fn index_type_to_glenum(ty: TessIndexType) -> GLenum {
   match ty {
     TessIndexType::U8 => gl::UNSIGNED_BYTE,
@@ -1,23 +1,58 @@
 use crate::gl33::{
-  depth_stencil::comparison_to_glenum, pixel::opengl_pixel_format, state::GLState, GL33,
+  buffer::Buffer,
+  depth_stencil::comparison_to_glenum,
+  pixel::{opengl_compressed_format, opengl_pixel_format, supports_compressed_format},
+  state::GLState,
+  GL33,
 };
 use gl::{self, types::*};
 use luminance::{
-  backend::texture::{Texture as TextureBackend, TextureBase},
-  pixel::{Pixel, PixelFormat},
-  texture::{Dim, Dimensionable, MagFilter, MinFilter, Sampler, TexelUpload, TextureError, Wrap},
+  backend::texture::{
+    PixelAlignment, Texture as TextureBackend, TextureBase, TextureBuffer as TextureBufferBackend,
+  },
+  pixel::{CompressedFormat, Format, Pixel, PixelFormat},
+  texture::{
+    Dim, Dimensionable, MagFilter, MinFilter, Sampler, Swizzle, TexelUpload, TextureError, Wrap,
+  },
 };
 use std::{cell::RefCell, mem, os::raw::c_void, ptr, rc::Rc};
 
 pub struct Texture {
   pub(crate) handle: GLuint, // handle to the GPU texture object
   pub(crate) target: GLenum, // “type” of the texture; used for bindings
-  mipmaps: usize,
+  pub(crate) mipmaps: usize,
+  // shared by every representation of the same texture; the last one to be dropped deletes it
+  refs: Rc<()>,
   state: Rc<RefCell<GLState>>,
 }
 
+impl Texture {
+  /// Get another representation of the same texture.
+  pub(crate) fn share(&self) -> Self {
+    Texture {
+      handle: self.handle,
+      target: self.target,
+      mipmaps: self.mipmaps,
+      refs: self.refs.clone(),
+      state: self.state.clone(),
+    }
+  }
+}
+
 impl Drop for Texture {
   fn drop(&mut self) {
+    if Rc::strong_count(&self.refs) > 1 {
+      return;
+    }
+
+    if !self.state.borrow().is_context_alive() {
+      log::debug!(
+        "texture {} dropped after its context; skipping deletion",
+        self.handle
+      );
+      return;
+    }
+
     unsafe {
       gl::DeleteTextures(1, &self.handle);
     }
@@ -65,9 +100,11 @@ where
 
     gfx_state.bind_texture(texture.target, texture.handle);
 
-    upload_texels::<D, P, P::Encoding>(texture.target, offset, size, texels)?;
+    let alignment = gfx_state.unpack_alignment();
+    upload_texels::<D, P, P::Encoding>(texture.target, offset, size, alignment, texels)?;
 
     gfx_state.bind_texture(texture.target, 0);
+    gfx_state.check_errors("texture upload");
 
     Ok(())
   }
@@ -90,9 +127,11 @@ where
 
     gfx_state.bind_texture(texture.target, texture.handle);
 
-    upload_texels::<D, P, P::RawEncoding>(texture.target, offset, size, texels)?;
+    let alignment = gfx_state.unpack_alignment();
+    upload_texels::<D, P, P::RawEncoding>(texture.target, offset, size, alignment, texels)?;
 
     gfx_state.bind_texture(texture.target, 0);
+    gfx_state.check_errors("texture upload");
 
     Ok(())
   }
@@ -112,9 +151,22 @@ where
   where
     P::RawEncoding: Copy + Default,
   {
+    if texture.target == gl::TEXTURE_2D_MULTISAMPLE {
+      return Err(TextureError::cannot_retrieve_texels(
+        "multisample textures must be resolved before their texels can be retrieved",
+      ));
+    }
+
     let pf = P::pixel_format();
     let (format, _, ty) = opengl_pixel_format(pf).unwrap();
 
+    // compressed formats have no per-pixel size to compute the rows with
+    if let Format::Compressed(_) = pf.format {
+      return Err(TextureError::cannot_retrieve_texels(
+        "compressed textures cannot be read back",
+      ));
+    }
+
     let mut w = 0;
     let mut h = 0;
 
@@ -125,12 +177,16 @@ where
     gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_WIDTH, &mut w);
     gl::GetTexLevelParameteriv(texture.target, 0, gl::TEXTURE_HEIGHT, &mut h);
 
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.bytes_len() * w as usize) % 8;
-    set_pack_alignment(skip_bytes);
+    // set the packing alignment based on the byte size of a row, unless forced
+    let row_bytes = pf.format.bytes_len() * w as usize;
+    let alignment = pixel_alignment(gfx_state.pack_alignment(), row_bytes);
+    set_pack_alignment(alignment);
 
-    // resize the vec to allocate enough space to host the returned texels
-    let mut texels = vec![Default::default(); (w * h) as usize * pf.channels_len()];
+    // resize the vec to allocate enough space to host the returned texels, including the padding of rows
+    let padding_bytes = (aligned_row_bytes(row_bytes, alignment) - row_bytes) * h as usize;
+    let texels_nb = (w * h) as usize * pf.channels_len()
+      + (padding_bytes + mem::size_of::<P::RawEncoding>() - 1) / mem::size_of::<P::RawEncoding>();
+    let mut texels = vec![Default::default(); texels_nb];
 
     gl::GetTexImage(
       texture.target,
@@ -145,6 +201,39 @@ where
     Ok(texels)
   }
 
+  unsafe fn upload_compressed(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    size: D::Size,
+    bytes: &[u8],
+  ) -> Result<(), TextureError> {
+    let pf = P::pixel_format();
+    let cf = match (pf.format, D::dim()) {
+      (Format::Compressed(cf), Dim::Dim2) => cf,
+      _ => return Err(TextureError::unsupported_pixel_format(pf)),
+    };
+
+    let mut gfx_state = texture.state.borrow_mut();
+    gfx_state.bind_texture(texture.target, texture.handle);
+
+    gl::CompressedTexSubImage2D(
+      texture.target,
+      level as GLint,
+      0,
+      0,
+      D::width(size) as GLsizei,
+      D::height(size) as GLsizei,
+      opengl_compressed_format(cf),
+      bytes.len() as GLsizei,
+      bytes.as_ptr() as *const c_void,
+    );
+
+    gfx_state.bind_texture(texture.target, 0);
+    gfx_state.check_errors("compressed texture upload");
+
+    Ok(())
+  }
+
   unsafe fn resize(
     texture: &mut Self::TextureRepr,
     size: D::Size,
@@ -155,7 +244,8 @@ where
 
     state.bind_texture(texture.target, texture.handle);
     create_texture_storage::<D>(size, 1 + mipmaps, P::pixel_format())?;
-    upload_texels::<D, P, P::Encoding>(texture.target, D::ZERO_OFFSET, size, texels)
+    let alignment = state.unpack_alignment();
+    upload_texels::<D, P, P::Encoding>(texture.target, D::ZERO_OFFSET, size, alignment, texels)
   }
 
   unsafe fn resize_raw(
@@ -168,7 +258,119 @@ where
 
     state.bind_texture(texture.target, texture.handle);
     create_texture_storage::<D>(size, 1 + mipmaps, P::pixel_format())?;
-    upload_texels::<D, P, P::RawEncoding>(texture.target, D::ZERO_OFFSET, size, texels)
+    let alignment = state.unpack_alignment();
+    upload_texels::<D, P, P::RawEncoding>(texture.target, D::ZERO_OFFSET, size, alignment, texels)
+  }
+}
+
+unsafe impl PixelAlignment for GL33 {
+  unsafe fn set_pixel_alignment(
+    &mut self,
+    pack: Option<usize>,
+    unpack: Option<usize>,
+  ) -> Result<(), TextureError> {
+    for alignment in pack.into_iter().chain(unpack) {
+      if !matches!(alignment, 1 | 2 | 4 | 8) {
+        return Err(TextureError::invalid_pixel_alignment(alignment));
+      }
+    }
+
+    self.state.borrow_mut().set_pixel_alignment(pack, unpack);
+    Ok(())
+  }
+}
+
+/// Texture buffer: a buffer texture reading its texels from a buffer.
+pub struct TextureBuffer<T> {
+  pub(crate) texture: Texture,
+  buffer: Buffer<T>,
+}
+
+unsafe impl<P> TextureBufferBackend<P> for GL33
+where
+  P: Pixel,
+{
+  type TextureBufferRepr = TextureBuffer<P::Encoding>;
+
+  unsafe fn new_texture_buffer(
+    &mut self,
+    texels: &[P::Encoding],
+  ) -> Result<Self::TextureBufferRepr, TextureError> {
+    let pf = P::pixel_format();
+    let iformat =
+      opengl_texture_buffer_format(pf).ok_or_else(|| TextureError::unsupported_pixel_format(pf))?;
+
+    let buffer = Buffer::from_vec_with_usage(self, texels.to_vec(), gl::STATIC_DRAW);
+
+    let mut state = self.state.borrow_mut();
+    let handle = state.create_texture();
+    state.bind_texture(gl::TEXTURE_BUFFER, handle);
+    gl::TexBuffer(gl::TEXTURE_BUFFER, iformat, buffer.handle());
+
+    state.check_errors("texture buffer creation");
+
+    let texture = Texture {
+      handle,
+      target: gl::TEXTURE_BUFFER,
+      mipmaps: 0,
+      refs: Rc::new(()),
+      state: self.state.clone(),
+    };
+
+    Ok(TextureBuffer { texture, buffer })
+  }
+
+  unsafe fn set_texture_buffer_texels(
+    texture_buffer: &mut Self::TextureBufferRepr,
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError> {
+    // the buffer texture follows the storage of the buffer, so it doesn’t have to be attached again
+    if texels.len() == texture_buffer.buffer.len() {
+      texture_buffer.buffer.update(texels);
+    } else {
+      texture_buffer.buffer.reallocate(texels);
+    }
+
+    Ok(())
+  }
+}
+
+// internal formats buffer textures can be created with; there are no RGB nor signed normalized ones
+fn opengl_texture_buffer_format(pf: PixelFormat) -> Option<GLenum> {
+  let (_, iformat, _) = opengl_pixel_format(pf)?;
+
+  match iformat {
+    gl::R8
+    | gl::R16
+    | gl::R16F
+    | gl::R32F
+    | gl::R8I
+    | gl::R16I
+    | gl::R32I
+    | gl::R8UI
+    | gl::R16UI
+    | gl::R32UI
+    | gl::RG8
+    | gl::RG16
+    | gl::RG16F
+    | gl::RG32F
+    | gl::RG8I
+    | gl::RG16I
+    | gl::RG32I
+    | gl::RG8UI
+    | gl::RG16UI
+    | gl::RG32UI
+    | gl::RGBA8
+    | gl::RGBA16
+    | gl::RGBA16F
+    | gl::RGBA32F
+    | gl::RGBA8I
+    | gl::RGBA16I
+    | gl::RGBA32I
+    | gl::RGBA8UI
+    | gl::RGBA16UI
+    | gl::RGBA32UI => Some(iformat),
+    _ => None,
   }
 }
 
@@ -180,10 +382,12 @@ pub(crate) fn opengl_target(d: Dim) -> GLenum {
     Dim::Cubemap => gl::TEXTURE_CUBE_MAP,
     Dim::Dim1Array => gl::TEXTURE_1D_ARRAY,
     Dim::Dim2Array => gl::TEXTURE_2D_ARRAY,
+    Dim::Dim2Multisample(_) => gl::TEXTURE_2D_MULTISAMPLE,
   }
 }
 
 pub(crate) unsafe fn create_texture<D>(
+  state: &mut GLState,
   target: GLenum,
   size: D::Size,
   mipmaps: usize,
@@ -193,8 +397,12 @@ pub(crate) unsafe fn create_texture<D>(
 where
   D: Dimensionable,
 {
-  set_texture_levels(target, mipmaps);
-  apply_sampler_to_texture(target, sampler);
+  // multisample textures have neither mipmaps nor sampler state
+  if target != gl::TEXTURE_2D_MULTISAMPLE {
+    set_texture_levels(target, mipmaps);
+    apply_sampler_to_texture(state, target, sampler);
+  }
+
   create_texture_storage::<D>(size, 1 + mipmaps, pf)
 }
 
@@ -205,23 +413,15 @@ fn set_texture_levels(target: GLenum, mipmaps: usize) {
   }
 }
 
-fn apply_sampler_to_texture(target: GLenum, sampler: Sampler) {
+fn apply_sampler_to_texture(state: &mut GLState, target: GLenum, sampler: Sampler) {
+  let wrap_r = opengl_wrap(state, sampler.wrap_r) as GLint;
+  let wrap_s = opengl_wrap(state, sampler.wrap_s) as GLint;
+  let wrap_t = opengl_wrap(state, sampler.wrap_t) as GLint;
+
   unsafe {
-    gl::TexParameteri(
-      target,
-      gl::TEXTURE_WRAP_R,
-      opengl_wrap(sampler.wrap_r) as GLint,
-    );
-    gl::TexParameteri(
-      target,
-      gl::TEXTURE_WRAP_S,
-      opengl_wrap(sampler.wrap_s) as GLint,
-    );
-    gl::TexParameteri(
-      target,
-      gl::TEXTURE_WRAP_T,
-      opengl_wrap(sampler.wrap_t) as GLint,
-    );
+    gl::TexParameteri(target, gl::TEXTURE_WRAP_R, wrap_r);
+    gl::TexParameteri(target, gl::TEXTURE_WRAP_S, wrap_s);
+    gl::TexParameteri(target, gl::TEXTURE_WRAP_T, wrap_t);
     gl::TexParameteri(
       target,
       gl::TEXTURE_MIN_FILTER,
@@ -250,14 +450,33 @@ fn apply_sampler_to_texture(target: GLenum, sampler: Sampler) {
         gl::TexParameteri(target, gl::TEXTURE_COMPARE_MODE, gl::NONE as GLint);
       }
     }
+
+    let swizzle = sampler.swizzle.map(|s| opengl_swizzle(s) as GLint);
+    gl::TexParameteriv(target, gl::TEXTURE_SWIZZLE_RGBA, swizzle.as_ptr());
+  }
+}
+
+fn opengl_swizzle(swizzle: Swizzle) -> GLenum {
+  match swizzle {
+    Swizzle::Red => gl::RED,
+    Swizzle::Green => gl::GREEN,
+    Swizzle::Blue => gl::BLUE,
+    Swizzle::Alpha => gl::ALPHA,
+    Swizzle::Zero => gl::ZERO,
+    Swizzle::One => gl::ONE,
   }
 }
 
-fn opengl_wrap(wrap: Wrap) -> GLenum {
+fn opengl_wrap(state: &mut GLState, wrap: Wrap) -> GLenum {
   match wrap {
     Wrap::ClampToEdge => gl::CLAMP_TO_EDGE,
     Wrap::Repeat => gl::REPEAT,
     Wrap::MirroredRepeat => gl::MIRRORED_REPEAT,
+    Wrap::MirrorClampToEdge if state.supports_mirror_clamp_to_edge() => gl::MIRROR_CLAMP_TO_EDGE,
+    Wrap::MirrorClampToEdge => {
+      log::warn!("mirror-clamp-to-edge wrap mode not supported; falling back to mirrored repeat");
+      gl::MIRRORED_REPEAT
+    }
   }
 }
 
@@ -288,24 +507,43 @@ unsafe fn generic_new_texture<D, P, Px>(
 where
   D: Dimensionable,
   P: Pixel,
+{
+  let texture = new_texture_storage::<D>(gl33, size, texels.mipmaps(), P::pixel_format(), sampler)?;
+  let alignment = gl33.state.borrow().unpack_alignment();
+  upload_texels::<D, P, Px>(texture.target, D::ZERO_OFFSET, size, alignment, texels)?;
+
+  gl33.state.borrow_mut().check_errors("texture creation");
+
+  Ok(texture)
+}
+
+/// Create a texture and its storage, without uploading any texels; the texture is left bound.
+pub(crate) unsafe fn new_texture_storage<D>(
+  gl33: &mut GL33,
+  size: D::Size,
+  mipmaps: usize,
+  pf: PixelFormat,
+  sampler: Sampler,
+) -> Result<Texture, TextureError>
+where
+  D: Dimensionable,
 {
   let mut state = gl33.state.borrow_mut();
-  let mipmaps = texels.mipmaps();
   let target = opengl_target(D::dim());
 
   let handle = state.create_texture();
   state.bind_texture(target, handle);
 
-  create_texture::<D>(target, size, mipmaps, P::pixel_format(), sampler)?;
-  upload_texels::<D, P, Px>(target, D::ZERO_OFFSET, size, texels)?;
-
   let texture = Texture {
     handle,
     target,
     mipmaps,
+    refs: Rc::new(()),
     state: gl33.state.clone(),
   };
 
+  create_texture::<D>(&mut state, target, size, mipmaps, pf, sampler)?;
+
   Ok(texture)
 }
 
@@ -317,6 +555,10 @@ fn create_texture_storage<D>(
 where
   D: Dimensionable,
 {
+  if let Format::Compressed(cf) = pf.format {
+    return create_compressed_texture_storage::<D>(size, levels, pf, cf);
+  }
+
   match opengl_pixel_format(pf) {
     Some(glf) => {
       let (format, iformat, encoding) = glf;
@@ -391,6 +633,12 @@ where
           );
           Ok(())
         }
+
+        // 2D multisample texture
+        Dim::Dim2Multisample(samples) => {
+          create_texture_2d_multisample_storage(iformat, D::width(size), D::height(size), samples);
+          Ok(())
+        }
       }
     }
 
@@ -401,6 +649,50 @@ where
   }
 }
 
+// compressed storage is allocated with zeroed blocks, as not all compressed formats can be used with glTexImage*
+fn create_compressed_texture_storage<D>(
+  size: D::Size,
+  levels: usize,
+  pf: PixelFormat,
+  cf: CompressedFormat,
+) -> Result<(), TextureError>
+where
+  D: Dimensionable,
+{
+  if D::dim() != Dim::Dim2 {
+    return Err(TextureError::texture_storage_creation_failed(format!(
+      "compressed textures must be 2D, not {}",
+      D::dim()
+    )));
+  }
+
+  let iformat = opengl_compressed_format(cf);
+  if !supports_compressed_format(iformat) {
+    return Err(TextureError::unsupported_pixel_format(pf));
+  }
+
+  for level in 0..levels {
+    let w = (D::width(size) >> level).max(1);
+    let h = (D::height(size) >> level).max(1);
+    let blocks = vec![0u8; cf.bytes_len(w, h)];
+
+    unsafe {
+      gl::CompressedTexImage2D(
+        gl::TEXTURE_2D,
+        level as GLint,
+        iformat,
+        w as GLsizei,
+        h as GLsizei,
+        0,
+        blocks.len() as GLsizei,
+        blocks.as_ptr() as *const c_void,
+      )
+    };
+  }
+
+  Ok(())
+}
+
 fn create_texture_1d_storage(
   format: GLenum,
   iformat: GLenum,
@@ -456,6 +748,19 @@ fn create_texture_2d_storage(
   }
 }
 
+fn create_texture_2d_multisample_storage(iformat: GLenum, w: u32, h: u32, samples: usize) {
+  unsafe {
+    gl::TexImage2DMultisample(
+      gl::TEXTURE_2D_MULTISAMPLE,
+      samples as GLsizei,
+      iformat,
+      w as GLsizei,
+      h as GLsizei,
+      gl::TRUE,
+    )
+  };
+}
+
 fn create_texture_3d_storage(
   target: GLenum,
   format: GLenum,
@@ -517,28 +822,29 @@ fn create_cubemap_storage(
   }
 }
 
-// set the unpack alignment for uploading aligned texels
-fn set_unpack_alignment(skip_bytes: usize) {
-  let unpack_alignment = match skip_bytes {
+// pixel row alignment of a transfer: the forced one, if any, or the largest one dividing the byte size of a row
+fn pixel_alignment(forced: Option<usize>, row_bytes: usize) -> usize {
+  forced.unwrap_or(match row_bytes % 8 {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
-  };
+  })
+}
 
-  unsafe { gl::PixelStorei(gl::UNPACK_ALIGNMENT, unpack_alignment) };
+// byte size of a row once padded to the given alignment
+fn aligned_row_bytes(row_bytes: usize, alignment: usize) -> usize {
+  (row_bytes + alignment - 1) / alignment * alignment
 }
 
-// set the pack alignment for downloading aligned texels
-fn set_pack_alignment(skip_bytes: usize) {
-  let pack_alignment = match skip_bytes {
-    0 => 8,
-    2 => 2,
-    4 => 4,
-    _ => 1,
-  };
+// set the unpack alignment for uploading aligned texels
+fn set_unpack_alignment(alignment: usize) {
+  unsafe { gl::PixelStorei(gl::UNPACK_ALIGNMENT, alignment as GLint) };
+}
 
-  unsafe { gl::PixelStorei(gl::PACK_ALIGNMENT, pack_alignment) };
+// set the pack alignment for downloading aligned texels
+fn set_pack_alignment(alignment: usize) {
+  unsafe { gl::PixelStorei(gl::PACK_ALIGNMENT, alignment as GLint) };
 }
 
 // Upload texels into the texture’s memory.
@@ -546,6 +852,7 @@ fn upload_texels<D, P, T>(
   target: GLenum,
   off: D::Offset,
   size: D::Size,
+  alignment: Option<usize>,
   texels: TexelUpload<[T]>,
 ) -> Result<(), TextureError>
 where
@@ -553,8 +860,20 @@ where
   P: Pixel,
 {
   let pf = P::pixel_format();
+  // compressed formats yield 0 here; they only accept empty base levels, checked below
   let pf_size = pf.format.bytes_len();
-  let expected_bytes = D::count(size) * pf_size;
+
+  // rows are padded to the pixel row alignment, which is picked from the width of the texture and the size of a
+  // single pixel unless forced; the last row doesn’t need any padding
+  let width = D::width(size) as usize;
+  let row_bytes = width * pf_size;
+  let alignment = pixel_alignment(alignment, row_bytes);
+  let rows = D::count(size) / width.max(1);
+  let expected_bytes = if rows == 0 {
+    0
+  } else {
+    aligned_row_bytes(row_bytes, alignment) * (rows - 1) + row_bytes
+  };
 
   // get base level texels
   let base_level_texels = texels
@@ -564,6 +883,17 @@ where
       provided_bytes: 0,
     })?;
 
+  // an empty base level only reserves the storage (e.g. framebuffer attachments); nothing to upload
+  if base_level_texels.is_empty() {
+    return Ok(());
+  }
+
+  if let Format::Compressed(_) = pf.format {
+    return Err(TextureError::cannot_upload_texels(
+      "compressed textures must be uploaded to with Texture::upload_compressed",
+    ));
+  }
+
   // number of bytes in the input texels argument
   let input_bytes = base_level_texels.len() * mem::size_of::<T>();
 
@@ -572,11 +902,7 @@ where
     return Err(TextureError::not_enough_pixels(expected_bytes, input_bytes));
   }
 
-  // set the pixel row alignment to the required value for uploading data according to the width
-  // of the texture and the size of a single pixel; here, skip_bytes represents the number of bytes
-  // that will be skipped
-  let skip_bytes = (D::width(size) as usize * pf_size) % 8;
-  set_unpack_alignment(skip_bytes);
+  set_unpack_alignment(alignment);
 
   // handle mipmaps
   match texels {
@@ -697,6 +1023,12 @@ where
           texels.as_ptr() as *const c_void,
         );
       },
+
+      Dim::Dim2Multisample(_) => {
+        return Err(TextureError::cannot_upload_texels(
+          "multisample textures cannot be uploaded to; render into them instead",
+        ));
+      }
     },
 
     None => return Err(TextureError::unsupported_pixel_format(pf)),
@@ -3,9 +3,9 @@
 #![deny(missing_docs)]
 
 use gl;
-use glfw::{self, Glfw, InitError, Window, WindowEvent};
+use glfw::{self, Glfw, InitError, SwapInterval, Window, WindowEvent};
 use luminance::{
-  context::GraphicsContext,
+  context::{GraphicsContext, VSyncError, VSyncMode},
   framebuffer::{Framebuffer, FramebufferError},
   texture::Dim2,
 };
@@ -120,11 +120,11 @@ impl GlfwSurface {
 /// This type also re-exports the GLFW window, if you need access to it.
 #[derive(Debug)]
 pub struct GL33Context {
+  /// OpenGL 3.3 state; declared first so that it is dropped while the window and its context are still alive.
+  gl: GL33,
+
   /// Wrapped GLFW window.
   pub window: Window,
-
-  /// OpenGL 3.3 state.
-  gl: GL33,
 }
 
 impl GL33Context {
@@ -141,4 +141,29 @@ unsafe impl GraphicsContext for GL33Context {
   fn backend(&mut self) -> &mut Self::Backend {
     &mut self.gl
   }
+
+  fn set_vsync(&mut self, mode: VSyncMode) -> Result<VSyncMode, VSyncError> {
+    let glfw = &mut self.window.glfw;
+
+    // GLFW doesn’t report whether negative swap intervals are accepted, so we have to check for the extensions
+    let late_swap_tearing = glfw.extension_supported("WGL_EXT_swap_control_tear")
+      || glfw.extension_supported("GLX_EXT_swap_control_tear");
+
+    mode.apply(|interval| {
+      let swap_interval = match interval {
+        0 => SwapInterval::None,
+        i if i < 0 && late_swap_tearing => SwapInterval::Adaptive,
+        i if i < 0 => {
+          return Err(VSyncError::swap_interval_failed(
+            i,
+            "late swap tearing is not supported",
+          ))
+        }
+        i => SwapInterval::Sync(i as u32),
+      };
+
+      glfw.set_swap_interval(swap_interval);
+      Ok(())
+    })
+  }
 }
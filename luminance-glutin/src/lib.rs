@@ -4,12 +4,13 @@
 
 use gl;
 use glutin::{
-  event_loop::EventLoop, window::WindowBuilder, Api, ContextBuilder, ContextError, CreationError,
-  GlProfile, GlRequest, NotCurrent, PossiblyCurrent, WindowedContext,
+  dpi::PhysicalSize, event_loop::EventLoop, window::WindowBuilder, Api, Context, ContextBuilder,
+  ContextError, CreationError, GlProfile, GlRequest, NotCurrent, PossiblyCurrent, WindowedContext,
 };
 use luminance::context::GraphicsContext;
 use luminance::framebuffer::{Framebuffer, FramebufferError};
-use luminance::texture::Dim2;
+use luminance::pixel::{Depth32F, NormRGBA8UI};
+use luminance::texture::{Dim2, Sampler};
 pub use luminance_gl::gl33::StateQueryError;
 use luminance_gl::GL33;
 use std::error;
@@ -72,12 +73,16 @@ impl From<StateQueryError> for GlutinError {
 ///
 /// You want to create such an object in order to use any [luminance] construct.
 ///
+/// Glutin doesn’t allow changing the swap interval once the context is created, so
+/// [`GraphicsContext::set_vsync`] is not supported: use [`ContextBuilder::with_vsync`] with
+/// [`GlutinSurface::new_gl33_from_builders`] instead.
+///
 /// [luminance]: https://crates.io/crates/luminance
 pub struct GlutinSurface {
+  /// OpenGL 3.3 state; declared first so that it is dropped while the context is still alive.
+  gl: GL33,
   /// The windowed context.
   pub ctx: WindowedContext<PossiblyCurrent>,
-  /// OpenGL 3.3 state.
-  gl: GL33,
 }
 
 unsafe impl GraphicsContext for GlutinSurface {
@@ -132,6 +137,51 @@ impl GlutinSurface {
     Ok((surface, event_loop))
   }
 
+  /// Create a new [`GlutinHeadlessSurface`], which renders offscreen without any visible window.
+  ///
+  /// The OpenGL context is built with [`ContextBuilder::build_headless`]. Some platforms cannot create
+  /// an OpenGL context without a window, though; in that case, the context is created with a hidden
+  /// dummy window instead, which is never shown and doesn’t change how the surface is used.
+  ///
+  /// `size` is the size of the offscreen buffer returned by [`GlutinHeadlessSurface::back_buffer`].
+  pub fn new_headless(size: [u32; 2]) -> Result<GlutinHeadlessSurface, GlutinError> {
+    let event_loop = headless_event_loop();
+    let ctx_builder = || {
+      ContextBuilder::new()
+        .with_gl(GlRequest::Specific(Api::OpenGl, (3, 3)))
+        .with_gl_profile(GlProfile::Core)
+    };
+
+    let ctx = match ctx_builder().build_headless(&event_loop, PhysicalSize::new(size[0], size[1])) {
+      Ok(ctx) => {
+        let ctx = unsafe { ctx.make_current().map_err(|(_, e)| e)? };
+        HeadlessContext::Headless(ctx)
+      }
+
+      Err(_) => {
+        let window_builder = WindowBuilder::new()
+          .with_inner_size(PhysicalSize::new(size[0], size[1]))
+          .with_visible(false);
+        let windowed_ctx = ctx_builder().build_windowed(window_builder, &event_loop)?;
+        let ctx = unsafe { windowed_ctx.make_current().map_err(|(_, e)| e)? };
+        HeadlessContext::Windowed(ctx)
+      }
+    };
+
+    // init OpenGL
+    gl::load_with(|s| ctx.get_proc_address(s) as *const c_void);
+
+    let gl = GL33::new().map_err(GlutinError::GraphicsStateError)?;
+    let surface = GlutinHeadlessSurface {
+      _ctx: ctx,
+      gl,
+      size,
+      _event_loop: event_loop,
+    };
+
+    Ok(surface)
+  }
+
   /// Create a new [`GlutinSurface`] from scratch.
   pub fn new_gl33(
     window_builder: WindowBuilder,
@@ -178,3 +228,98 @@ impl GlutinSurface {
     let _ = self.ctx.swap_buffers();
   }
 }
+
+/// A Glutin surface without any visible window, used to render offscreen.
+///
+/// Such a surface is created with [`GlutinSurface::new_headless`]. Because a headless OpenGL context might not have
+/// any default framebuffer at all, [`GlutinHeadlessSurface::back_buffer`] returns a regular offscreen framebuffer
+/// which color slot can be read back.
+pub struct GlutinHeadlessSurface {
+  /// OpenGL 3.3 state; declared first so that it is dropped while the context is still alive.
+  gl: GL33,
+  /// The headless context, or the context of the hidden dummy window on platforms requiring one.
+  _ctx: HeadlessContext,
+  /// Size of the offscreen buffer.
+  size: [u32; 2],
+  /// The event loop the context was created with; it must outlive the context.
+  _event_loop: EventLoop<()>,
+}
+
+unsafe impl GraphicsContext for GlutinHeadlessSurface {
+  type Backend = GL33;
+
+  fn backend(&mut self) -> &mut Self::Backend {
+    &mut self.gl
+  }
+}
+
+impl GlutinHeadlessSurface {
+  /// Get the size (in pixels) of the offscreen buffer.
+  pub fn size(&self) -> [u32; 2] {
+    self.size
+  }
+
+  /// Create the offscreen buffer to render to.
+  ///
+  /// Contrary to [`GlutinSurface::back_buffer`], this allocates a new framebuffer on each call, so you want to keep
+  /// it around instead of asking for it on every frame.
+  pub fn back_buffer(
+    &mut self,
+  ) -> Result<Framebuffer<GL33, Dim2, NormRGBA8UI, Depth32F>, FramebufferError> {
+    let size = self.size;
+    Framebuffer::new(self, size, 0, Sampler::default())
+  }
+}
+
+/// OpenGL context of a [`GlutinHeadlessSurface`].
+enum HeadlessContext {
+  /// Context created without any window.
+  Headless(Context<PossiblyCurrent>),
+  /// Context created with a hidden dummy window, for platforms that cannot do without one.
+  Windowed(WindowedContext<PossiblyCurrent>),
+}
+
+impl HeadlessContext {
+  fn get_proc_address(&self, addr: &str) -> *const c_void {
+    match self {
+      HeadlessContext::Headless(ctx) => ctx.get_proc_address(addr),
+      HeadlessContext::Windowed(ctx) => ctx.get_proc_address(addr),
+    }
+  }
+}
+
+/// Create the event loop used by headless surfaces.
+///
+/// Nothing is ever read from that event loop, so on platforms supporting it, it is allowed to be created outside of
+/// the main thread (typically, in tests).
+fn headless_event_loop() -> EventLoop<()> {
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use glutin::platform::unix::EventLoopExtUnix as _;
+    EventLoop::new_any_thread()
+  }
+
+  #[cfg(target_os = "windows")]
+  {
+    use glutin::platform::windows::EventLoopExtWindows as _;
+    EventLoop::new_any_thread()
+  }
+
+  #[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows"
+  )))]
+  {
+    EventLoop::new()
+  }
+}
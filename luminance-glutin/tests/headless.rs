@@ -0,0 +1,228 @@
+use luminance::context::GraphicsContext as _;
+use luminance::pipeline::PipelineState;
+use luminance::pixel::{Depth32F, NormRGBA8UI};
+use luminance::render_state::RenderState;
+use luminance::tess::Mode;
+use luminance::texture::{Dim2, Sampler, TexelUpload};
+use luminance::{Semantics, Vertex};
+use luminance_glutin::GlutinSurface;
+
+const VS: &str = "
+in vec2 co;
+in vec3 color;
+
+out vec3 v_color;
+
+void main() {
+  gl_Position = vec4(co, 0., 1.);
+  v_color = color;
+}";
+
+const FS: &str = "
+in vec3 v_color;
+
+out vec4 frag;
+
+void main() {
+  frag = vec4(v_color, 1.);
+}";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Semantics)]
+pub enum Semantics {
+  #[sem(name = "co", repr = "[f32; 2]", wrapper = "VertexPosition")]
+  Position,
+  #[sem(name = "color", repr = "[u8; 3]", wrapper = "VertexColor")]
+  Color,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Vertex)]
+#[vertex(sem = "Semantics")]
+struct Vertex {
+  pos: VertexPosition,
+  #[vertex(normalized = "true")]
+  rgb: VertexColor,
+}
+
+// the hello world triangles
+const TRI_VERTICES: [Vertex; 6] = [
+  Vertex::new(
+    VertexPosition::new([0.5, -0.5]),
+    VertexColor::new([0, 255, 0]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.0, 0.5]),
+    VertexColor::new([0, 0, 255]),
+  ),
+  Vertex::new(
+    VertexPosition::new([-0.5, -0.5]),
+    VertexColor::new([255, 0, 0]),
+  ),
+  Vertex::new(
+    VertexPosition::new([-0.5, 0.5]),
+    VertexColor::new([255, 51, 255]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.0, -0.5]),
+    VertexColor::new([51, 255, 255]),
+  ),
+  Vertex::new(
+    VertexPosition::new([0.5, 0.5]),
+    VertexColor::new([51, 51, 255]),
+  ),
+];
+
+#[test]
+#[ignore = "requires an OpenGL 3.3 driver; run with --ignored"]
+fn headless_hello_world() {
+  let mut surface = GlutinSurface::new_headless([64, 64]).expect("headless glutin surface");
+  assert_eq!(surface.size(), [64, 64]);
+
+  let mut program = surface
+    .new_shader_program::<Semantics, (), ()>()
+    .from_strings(VS, None, None, FS)
+    .expect("program creation")
+    .ignore_warnings();
+
+  let triangles = surface
+    .new_tess()
+    .set_vertices(&TRI_VERTICES[..])
+    .set_mode(Mode::Triangle)
+    .build()
+    .expect("triangles");
+
+  let mut back_buffer = surface.back_buffer().expect("offscreen back buffer");
+
+  surface
+    .new_pipeline_gate()
+    .pipeline(
+      &back_buffer,
+      &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+      |_, mut shd_gate| {
+        shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+          rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+            tess_gate.render(&triangles)
+          })
+        })
+      },
+    )
+    .assume()
+    .into_result()
+    .expect("render");
+
+  let texels = back_buffer
+    .color_slot()
+    .get_raw_texels()
+    .expect("offscreen texels");
+  assert_eq!(texels.len(), 64 * 64 * 4);
+
+  let lit = texels.chunks(4).filter(|texel| texel[3] != 0).count();
+  assert!(lit > 0, "no pixel rendered in the offscreen buffer");
+
+  // the center of the framebuffer is covered by both triangles
+  let center = (32 * 64 + 32) * 4;
+  assert_ne!(&texels[center..center + 3], &[0, 0, 0]);
+}
+
+#[test]
+#[ignore = "requires an OpenGL 3.3 driver; run with --ignored"]
+fn headless_resources_outliving_context() {
+  let mut surface = GlutinSurface::new_headless([16, 16]).expect("headless glutin surface");
+
+  let program = surface
+    .new_shader_program::<Semantics, (), ()>()
+    .from_strings(VS, None, None, FS)
+    .expect("program creation")
+    .ignore_warnings();
+
+  let triangles = surface
+    .new_tess()
+    .set_vertices(&TRI_VERTICES[..])
+    .set_mode(Mode::Triangle)
+    .build()
+    .expect("triangles");
+
+  let texture = surface
+    .new_texture::<Dim2, NormRGBA8UI>(
+      [16, 16],
+      Sampler::default(),
+      TexelUpload::base_level_without_mipmaps(&[[0, 0, 0, 255]; 16 * 16]),
+    )
+    .expect("texture");
+
+  let framebuffer = surface
+    .new_framebuffer::<Dim2, NormRGBA8UI, Depth32F>([16, 16], 0, Sampler::default())
+    .expect("framebuffer");
+
+  // the context is gone; dropping the resources must not call OpenGL anymore
+  drop(surface);
+
+  drop(framebuffer);
+  drop(texture);
+  drop(triangles);
+  drop(program);
+}
+
+#[test]
+#[ignore = "requires an OpenGL 3.3 driver; run with --ignored"]
+fn headless_invalidate_state() {
+  let mut surface = GlutinSurface::new_headless([64, 64]).expect("headless glutin surface");
+
+  let mut program = surface
+    .new_shader_program::<Semantics, (), ()>()
+    .from_strings(VS, None, None, FS)
+    .expect("program creation")
+    .ignore_warnings();
+
+  let triangles = surface
+    .new_tess()
+    .set_vertices(&TRI_VERTICES[..])
+    .set_mode(Mode::Triangle)
+    .build()
+    .expect("triangles");
+
+  let mut back_buffer = surface.back_buffer().expect("offscreen back buffer");
+
+  for pass in 0..2 {
+    surface
+      .new_pipeline_gate()
+      .pipeline(
+        &back_buffer,
+        &PipelineState::default().set_clear_color([0., 0., 0., 0.]),
+        |_, mut shd_gate| {
+          shd_gate.shade(&mut program, |_, _, mut rdr_gate| {
+            rdr_gate.render(&RenderState::default(), |mut tess_gate| {
+              tess_gate.render(&triangles)
+            })
+          })
+        },
+      )
+      .assume()
+      .into_result()
+      .expect("render");
+
+    let texels = back_buffer
+      .color_slot()
+      .get_raw_texels()
+      .expect("offscreen texels");
+
+    let center = (32 * 64 + 32) * 4;
+    assert_ne!(&texels[center..center + 3], &[0, 0, 0], "pass {}", pass);
+    assert_eq!(&texels[..4], &[0, 0, 0, 0], "pass {}", pass);
+
+    // mess with the state behind luminance’s back, as foreign code sharing the context would
+    unsafe {
+      gl::ClearColor(1., 0., 0., 1.);
+      gl::Enable(gl::SCISSOR_TEST);
+      gl::Scissor(0, 0, 1, 1);
+      gl::Enable(gl::CULL_FACE);
+      gl::CullFace(gl::FRONT_AND_BACK);
+      gl::Viewport(0, 0, 1, 1);
+      gl::UseProgram(0);
+      gl::BindVertexArray(0);
+      gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+    }
+
+    surface.invalidate_state();
+  }
+}
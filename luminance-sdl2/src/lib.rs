@@ -2,7 +2,7 @@
 
 #![deny(missing_docs)]
 
-use luminance::context::GraphicsContext;
+use luminance::context::{GraphicsContext, VSyncError, VSyncMode};
 use luminance::framebuffer::Framebuffer;
 use luminance::framebuffer::FramebufferError;
 use luminance::texture::Dim2;
@@ -155,4 +155,20 @@ unsafe impl GraphicsContext for GL33Surface {
   fn backend(&mut self) -> &mut Self::Backend {
     &mut self.gl
   }
+
+  fn set_vsync(&mut self, mode: VSyncMode) -> Result<VSyncMode, VSyncError> {
+    let video = self.window.subsystem();
+
+    mode.apply(|interval| {
+      let swap_interval = match interval {
+        0 => sdl2::video::SwapInterval::Immediate,
+        i if i < 0 => sdl2::video::SwapInterval::LateSwapTearing,
+        _ => sdl2::video::SwapInterval::VSync,
+      };
+
+      video
+        .gl_set_swap_interval(swap_interval)
+        .map_err(|reason| VSyncError::swap_interval_failed(interval, reason))
+    })
+  }
 }
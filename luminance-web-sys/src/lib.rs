@@ -1,10 +1,11 @@
 use luminance::context::GraphicsContext;
 use luminance::framebuffer::{Framebuffer, FramebufferError};
-use luminance::texture::Dim2;
+use luminance::pixel::NormRGBA8UI;
+use luminance::texture::{Dim2, Sampler, Texture, TextureError};
 use luminance_webgl::webgl2::{StateQueryError, WebGL2};
 use std::fmt;
 use wasm_bindgen::{JsCast as _, JsValue};
-use web_sys::{Document, HtmlCanvasElement, Window};
+use web_sys::{Document, HtmlCanvasElement, HtmlImageElement, HtmlVideoElement, Window};
 
 /// web-sys errors that might occur while initializing and using the platform.
 #[non_exhaustive]
@@ -174,6 +175,30 @@ impl WebSysWebGL2Surface {
     let dim = [self.canvas.width(), self.canvas.height()];
     Framebuffer::back_buffer(self, dim)
   }
+
+  /// Create a new texture filled with the content of an image element.
+  ///
+  /// If the image is still loading, the texture is created empty; call [`Texture::update_from_source`] once the image
+  /// is loaded to fill it.
+  pub fn new_texture_from_image(
+    &mut self,
+    image: &HtmlImageElement,
+    sampler: Sampler,
+  ) -> Result<Texture<WebGL2, Dim2, NormRGBA8UI>, TextureError> {
+    Texture::from_source(self, image, sampler)
+  }
+
+  /// Create a new texture filled with the current frame of a video element.
+  ///
+  /// Call [`Texture::update_from_source`] with the video every frame to keep the texture up to date. Updates are
+  /// skipped as long as the video has no frame to provide.
+  pub fn new_texture_from_video(
+    &mut self,
+    video: &HtmlVideoElement,
+    sampler: Sampler,
+  ) -> Result<Texture<WebGL2, Dim2, NormRGBA8UI>, TextureError> {
+    Texture::from_source(self, video, sampler)
+  }
 }
 
 unsafe impl GraphicsContext for WebSysWebGL2Surface {
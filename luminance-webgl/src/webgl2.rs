@@ -2,7 +2,11 @@
 
 mod array_buffer;
 pub mod buffer;
+mod debug;
+pub mod error_check;
+pub mod feedback;
 pub mod framebuffer;
+pub mod memory_barrier;
 pub mod pipeline;
 pub mod pixel;
 pub mod query;
@@ -12,8 +16,13 @@ pub mod tess;
 pub mod texture;
 
 pub use crate::webgl2::array_buffer::IntoArrayBuffer;
+use crate::webgl2::debug::DebugPrograms;
 pub use crate::webgl2::state::StateQueryError;
 use crate::webgl2::state::WebGL2State;
+use luminance::{
+  backend::state::{InvalidateState, SaveState},
+  context::StateSnapshot,
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 use web_sys::WebGl2RenderingContext;
@@ -22,12 +31,30 @@ use web_sys::WebGl2RenderingContext;
 #[derive(Debug)]
 pub struct WebGL2 {
   pub(crate) state: Rc<RefCell<WebGL2State>>,
+  debug_programs: DebugPrograms,
 }
 
 impl WebGL2 {
   pub fn new(ctx: WebGl2RenderingContext) -> Result<Self, StateQueryError> {
     WebGL2State::new(ctx).map(|state| WebGL2 {
       state: Rc::new(RefCell::new(state)),
+      debug_programs: DebugPrograms::default(),
     })
   }
 }
+
+unsafe impl InvalidateState for WebGL2 {
+  unsafe fn invalidate_state(&mut self) {
+    self.state.borrow_mut().invalidate();
+  }
+}
+
+unsafe impl SaveState for WebGL2 {
+  unsafe fn save_state(&mut self) -> StateSnapshot {
+    self.state.borrow_mut().snapshot()
+  }
+
+  unsafe fn restore_state(&mut self, snapshot: &StateSnapshot) {
+    self.state.borrow_mut().restore(snapshot);
+  }
+}
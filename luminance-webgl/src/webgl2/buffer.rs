@@ -63,6 +63,10 @@ impl<const TARGET: u32> Drop for BufferWrapper<TARGET> {
 pub struct Buffer<T, const TARGET: u32> {
   /// A cached version of the GPU buffer; emulate persistent mapping.
   pub(crate) buf: Vec<T>,
+  /// Number of elements the GPU storage can hold.
+  capacity: usize,
+  /// Usage hint the GPU storage is (re)allocated with.
+  usage: u32,
   gl_buf: BufferWrapper<TARGET>,
 }
 
@@ -71,6 +75,14 @@ where
   WebGL2State: BindBuffer<TARGET>,
 {
   pub(crate) fn from_vec(webgl2: &mut WebGL2, vec: Vec<T>) -> Result<Self, BufferError> {
+    Self::from_vec_with_usage(webgl2, vec, WebGl2RenderingContext::STREAM_DRAW)
+  }
+
+  pub(crate) fn from_vec_with_usage(
+    webgl2: &mut WebGL2,
+    vec: Vec<T>,
+    usage: u32,
+  ) -> Result<Self, BufferError> {
     let mut state = webgl2.state.borrow_mut();
     let len = vec.len();
 
@@ -82,22 +94,91 @@ where
 
     let bytes = mem::size_of::<T>() * len;
     let data = unsafe { slice::from_raw_parts(vec.as_ptr() as *const _, bytes) };
-    state
-      .ctx
-      .buffer_data_with_u8_array(TARGET, data, WebGl2RenderingContext::STREAM_DRAW);
+    state.ctx.buffer_data_with_u8_array(TARGET, data, usage);
 
     let gl_buf = BufferWrapper {
       handle,
       state: webgl2.state.clone(),
     };
 
-    Ok(Buffer { gl_buf, buf: vec })
+    Ok(Buffer {
+      gl_buf,
+      buf: vec,
+      capacity: len,
+      usage,
+    })
   }
 
   pub(crate) fn handle(&self) -> &WebGlBuffer {
     &self.gl_buf.handle
   }
 
+  /// Orphan the GPU storage and replace its content with `data`, growing the storage if needed.
+  pub(crate) fn orphan_and_upload(&mut self, state: &mut WebGL2State, data: &[T])
+  where
+    T: Copy,
+  {
+    if data.len() > self.capacity {
+      self.capacity = data.len().next_power_of_two();
+    }
+
+    // force binding as element array buffers are part of the vao state
+    state.bind_buffer(&self.gl_buf.handle, Bind::Forced);
+
+    let elem_bytes = mem::size_of::<T>();
+    state
+      .ctx
+      .buffer_data_with_i32(TARGET, (self.capacity * elem_bytes) as i32, self.usage);
+
+    let bytes =
+      unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data)) };
+    state
+      .ctx
+      .buffer_sub_data_with_i32_and_u8_array(TARGET, 0, bytes);
+
+    self.buf.clear();
+    self.buf.extend_from_slice(data);
+  }
+
+  /// Replace the content of the GPU storage with `data`, which must have the same length as the buffer.
+  pub(crate) fn update(&mut self, data: &[T])
+  where
+    T: Copy,
+  {
+    let mut state = self.gl_buf.state.borrow_mut();
+    let bytes = mem::size_of_val(data);
+    let _ = update_webgl_buffer::<TARGET>(
+      &mut state,
+      &self.gl_buf.handle,
+      data.as_ptr() as _,
+      bytes,
+      0,
+    );
+
+    self.buf.copy_from_slice(data);
+  }
+
+  /// Read back the content of the GPU storage.
+  ///
+  /// Unlike [`Buffer::slice_buffer`], this doesn’t read the cached version of the buffer, so it sees data written by
+  /// the GPU, such as captured feedback varyings.
+  pub(crate) fn read_back(&self) -> Vec<T>
+  where
+    T: Copy,
+  {
+    let mut state = self.gl_buf.state.borrow_mut();
+    state.bind_buffer(&self.gl_buf.handle, Bind::Cached);
+
+    let mut data = self.buf.clone();
+    let bytes = mem::size_of::<T>() * data.len();
+    let raw = unsafe { slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, bytes) };
+    state
+      .ctx
+      .get_buffer_sub_data_with_i32_and_u8_array(TARGET, 0, raw);
+
+    data
+  }
+
   pub(crate) fn slice_buffer(&self) -> BufferSlice<T> {
     BufferSlice {
       handle: &self.gl_buf.handle,
@@ -0,0 +1,183 @@
+//! Debug rendering of tessellations.
+//!
+//! The debug programs are provided by the backend and built lazily, the first time a given debug mode is rendered.
+//! Because they read vertex attributes from the user’s tessellations, they are linked with the attribute locations of
+//! the semantics they read, and are then cached per set of locations.
+//!
+//! WebGL2 has neither polygon modes nor geometry shaders, so:
+//!
+//! - The wireframe is approximated by rendering the vertices of the view as a line strip.
+//! - Vectors are rendered as instanced lines, one per vertex of the tessellation, whatever the view.
+
+use crate::webgl2::{shader::Program, WebGL2};
+use luminance::{
+  backend::shader::Shader as _,
+  shader::{ProgramError, StageType},
+  tess_gate::DebugMode,
+};
+use std::collections::HashMap;
+use web_sys::WebGlUniformLocation;
+
+const WIREFRAME_VS: &str = "
+in vec4 position;
+
+uniform mat4 transform;
+
+void main() {
+  gl_Position = transform * vec4(position.xyz, 1.);
+}";
+
+// rendered as two-vertex line instances, one per vertex of the tessellation
+const VECTORS_VS: &str = "
+in vec4 position;
+in vec3 vector;
+
+uniform mat4 transform;
+uniform float vector_length;
+
+void main() {
+  vec3 p = position.xyz;
+
+  if (gl_VertexID == 1) {
+    p += vector * vector_length;
+  }
+
+  gl_Position = transform * vec4(p, 1.);
+}";
+
+const FS: &str = "
+out vec4 frag;
+
+uniform vec4 color;
+
+void main() {
+  frag = color;
+}";
+
+const WIREFRAME_COLOR: [f32; 4] = [1., 1., 1., 1.];
+const NORMALS_COLOR: [f32; 4] = [0.2, 0.5, 1., 1.];
+const TANGENTS_COLOR: [f32; 4] = [1., 0.3, 0.2, 1.];
+
+/// Debug programs, indexed by the location of the position attribute and, for vectors, the location of the vector
+/// attribute.
+///
+/// Programs that failed to build are cached as [`None`] so that they are not rebuilt every frame; debug renders using
+/// them are then skipped.
+#[derive(Debug, Default)]
+pub(crate) struct DebugPrograms {
+  programs: HashMap<(usize, Option<usize>), Option<DebugProgram>>,
+}
+
+#[derive(Debug)]
+struct DebugProgram {
+  program: Program,
+  transform: Option<WebGlUniformLocation>,
+  length: Option<WebGlUniformLocation>,
+  color: Option<WebGlUniformLocation>,
+}
+
+impl DebugProgram {
+  unsafe fn new(
+    webgl2: &mut WebGL2,
+    position: usize,
+    vector: Option<usize>,
+  ) -> Result<Self, ProgramError> {
+    let vs = if vector.is_some() {
+      VECTORS_VS
+    } else {
+      WIREFRAME_VS
+    };
+    let vertex = webgl2.new_stage(StageType::VertexShader, vs)?;
+    let fragment = webgl2.new_stage(StageType::FragmentShader, FS)?;
+    let program = webgl2.new_program(&vertex, None, None, &fragment)?;
+
+    // bind the attributes to the locations of the semantics and link again to apply them
+    {
+      let state = webgl2.state.borrow();
+      state
+        .ctx
+        .bind_attrib_location(&program.handle, position as u32, "position");
+
+      if let Some(vector) = vector {
+        state
+          .ctx
+          .bind_attrib_location(&program.handle, vector as u32, "vector");
+      }
+    }
+
+    program.link()?;
+
+    let state = webgl2.state.borrow();
+    let transform = state.ctx.get_uniform_location(&program.handle, "transform");
+    let length = state
+      .ctx
+      .get_uniform_location(&program.handle, "vector_length");
+    let color = state.ctx.get_uniform_location(&program.handle, "color");
+    drop(state);
+
+    Ok(DebugProgram {
+      program,
+      transform,
+      length,
+      color,
+    })
+  }
+}
+
+/// Use the debug program for `mode`, run `render` and use the previous program again.
+///
+/// `render` is passed the locations of the position and vector attributes when vectors are to be rendered, or [`None`]
+/// for the wireframe. Nothing is rendered if the debug program cannot be built.
+pub(crate) unsafe fn with_debug_program(
+  webgl2: &mut WebGL2,
+  mode: DebugMode<usize>,
+  transform: [[f32; 4]; 4],
+  render: impl FnOnce(Option<[usize; 2]>),
+) {
+  let (position, vector, length, color) = match mode {
+    DebugMode::Wireframe { position } => (position, None, 0., WIREFRAME_COLOR),
+    DebugMode::Normals {
+      position,
+      normal,
+      length,
+    } => (position, Some(normal), length, NORMALS_COLOR),
+    DebugMode::Tangents {
+      position,
+      tangent,
+      length,
+    } => (position, Some(tangent), length, TANGENTS_COLOR),
+  };
+
+  let key = (position, vector);
+
+  if !webgl2.debug_programs.programs.contains_key(&key) {
+    let program = DebugProgram::new(webgl2, position, vector).ok();
+    webgl2.debug_programs.programs.insert(key, program);
+  }
+
+  let program = match webgl2.debug_programs.programs.get(&key) {
+    Some(Some(program)) => program,
+    _ => return,
+  };
+
+  let previous = {
+    let mut state = webgl2.state.borrow_mut();
+    let previous = state.current_program();
+    state.use_program(Some(&program.program.handle));
+
+    let transform: Vec<f32> = transform.iter().flatten().copied().collect();
+    state
+      .ctx
+      .uniform_matrix4fv_with_f32_array(program.transform.as_ref(), false, &transform);
+    state.ctx.uniform1f(program.length.as_ref(), length);
+    state
+      .ctx
+      .uniform4fv_with_f32_array(program.color.as_ref(), &color);
+
+    previous
+  };
+
+  render(vector.map(|vector| [position, vector]));
+
+  webgl2.state.borrow_mut().use_program(previous.as_ref());
+}
@@ -0,0 +1,40 @@
+//! Error checking implementation.
+//!
+//! WebGL2 doesn’t have any asynchronous debug output mechanism, so errors are polled with `gl.getError` after each
+//! significant operation when error checking is enabled.
+
+use crate::WebGL2;
+use luminance::{
+  backend::error_check::ErrorCheck,
+  error_check::{BackendError, BackendErrorKind},
+};
+use web_sys::WebGl2RenderingContext;
+
+unsafe impl ErrorCheck for WebGL2 {
+  fn set_error_checking(&mut self, enabled: bool) {
+    self.state.borrow_mut().set_error_checking(enabled)
+  }
+
+  fn is_error_checking(&self) -> bool {
+    self.state.borrow().is_error_checking()
+  }
+
+  fn take_errors(&mut self) -> Vec<BackendError> {
+    self.state.borrow_mut().take_errors()
+  }
+}
+
+/// Map a WebGL error code to a [`BackendErrorKind`].
+pub(crate) fn webgl_error_to_error_kind(code: u32) -> BackendErrorKind {
+  match code {
+    WebGl2RenderingContext::INVALID_ENUM => BackendErrorKind::InvalidEnum,
+    WebGl2RenderingContext::INVALID_VALUE => BackendErrorKind::InvalidValue,
+    WebGl2RenderingContext::INVALID_OPERATION => BackendErrorKind::InvalidOperation,
+    WebGl2RenderingContext::INVALID_FRAMEBUFFER_OPERATION => {
+      BackendErrorKind::InvalidFramebufferOperation
+    }
+    WebGl2RenderingContext::OUT_OF_MEMORY => BackendErrorKind::OutOfMemory,
+    WebGl2RenderingContext::CONTEXT_LOST_WEBGL => BackendErrorKind::ContextLost,
+    _ => BackendErrorKind::Unknown(code),
+  }
+}
@@ -0,0 +1,182 @@
+//! WebGL2 transform feedback implementation.
+
+use crate::webgl2::{buffer::Buffer, state::WebGL2State, WebGL2};
+use luminance::{
+  backend::{
+    feedback::{FeedbackBuffer, TessGateFeedback},
+    tess_gate::TessGate,
+  },
+  feedback::FeedbackError,
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, RawInterleaved, RawVertexData, TessIndex,
+    TessVertexData,
+  },
+};
+use std::{cell::RefCell, rc::Rc};
+use web_sys::WebGl2RenderingContext;
+
+/// Buffer receiving captured feedback varyings.
+pub type FeedbackBufferRepr<T> = Buffer<T, { WebGl2RenderingContext::ARRAY_BUFFER }>;
+
+unsafe impl<T> FeedbackBuffer<T> for WebGL2
+where
+  T: Copy + Default,
+{
+  type FeedbackBufferRepr = FeedbackBufferRepr<T>;
+
+  unsafe fn new_feedback_buffer(
+    &mut self,
+    len: usize,
+  ) -> Result<Self::FeedbackBufferRepr, FeedbackError> {
+    Buffer::from_vec_with_usage(
+      self,
+      vec![T::default(); len],
+      WebGl2RenderingContext::STREAM_READ,
+    )
+    .map_err(|e| FeedbackError::cannot_create(e.to_string()))
+  }
+
+  unsafe fn read_feedback_buffer(
+    buffer: &Self::FeedbackBufferRepr,
+  ) -> Result<Vec<T>, FeedbackError> {
+    Ok(buffer.read_back())
+  }
+}
+
+/// Bind `buffers` to the transform feedback binding points and run `render` while capturing `primitive`s.
+///
+/// WebGL2 doesn’t support capturing indexed renders; those are reported as errors by the context.
+fn capture<T>(
+  state: &Rc<RefCell<WebGL2State>>,
+  primitive: u32,
+  buffers: &[&mut FeedbackBufferRepr<T>],
+  render: impl FnOnce(),
+) {
+  {
+    let state = state.borrow();
+
+    for (index, buffer) in buffers.iter().enumerate() {
+      state.ctx.bind_buffer_base(
+        WebGl2RenderingContext::TRANSFORM_FEEDBACK_BUFFER,
+        index as u32,
+        Some(buffer.handle()),
+      );
+    }
+
+    state.ctx.begin_transform_feedback(primitive);
+  }
+
+  render();
+
+  let mut state = state.borrow_mut();
+  state.ctx.end_transform_feedback();
+
+  for index in 0..buffers.len() {
+    state.ctx.bind_buffer_base(
+      WebGl2RenderingContext::TRANSFORM_FEEDBACK_BUFFER,
+      index as u32,
+      None,
+    );
+  }
+
+  state.check_errors("transform feedback");
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, Interleaved, T> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let state = self.state.clone();
+
+    capture(&state, tess.raw.feedback_primitive(), buffers, || {
+      let _ = <Self as TessGate<V, I, W, Interleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, Deinterleaved, T> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let state = self.state.clone();
+
+    capture(&state, tess.raw.feedback_primitive(), buffers, || {
+      let _ = <Self as TessGate<V, I, W, Deinterleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> TessGateFeedback<V, I, W, RawInterleaved, T> for WebGL2
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  T: Copy + Default,
+{
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError> {
+    let state = self.state.clone();
+
+    capture(&state, tess.raw.feedback_primitive(), buffers, || {
+      let _ = <Self as TessGate<V, I, W, RawInterleaved>>::render(
+        self,
+        tess,
+        start_index,
+        vert_nb,
+        inst_nb,
+        0,
+        &[],
+      );
+    });
+
+    Ok(())
+  }
+}
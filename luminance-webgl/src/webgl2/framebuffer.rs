@@ -1,14 +1,24 @@
 //! Framebuffer support for WebGL2.
 
-use crate::webgl2::{state::WebGL2State, WebGL2};
+use crate::webgl2::{
+  state::WebGL2State,
+  texture::{new_texture_storage, Texture},
+  WebGL2,
+};
 use js_sys::Uint32Array;
 use luminance::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      DynamicFramebuffer, Framebuffer as FramebufferBackend, FramebufferBackBuffer,
+      FramebufferBackBufferSrgb, FramebufferDepthCopy, FramebufferRawHandle,
+      FramebufferSharedDepth,
+    },
   },
-  framebuffer::{FramebufferError, IncompleteReason},
+  framebuffer::{Attachment, FramebufferError, IncompleteReason},
+  pipeline::PipelineState,
+  pixel::{PixelFormat, Type},
   texture::{Dim2, Dimensionable, Sampler},
 };
 use std::{cell::RefCell, rc::Rc};
@@ -22,18 +32,92 @@ where
   pub(crate) handle: Option<WebGlFramebuffer>,
   renderbuffer: Option<WebGlRenderbuffer>,
   pub(crate) size: D::Size,
+  // encoding of each color attachment; integer attachments must be cleared differently
+  color_encodings: Vec<Type>,
+  // whether the framebuffer is owned by a third party, in which case it must not be deleted
+  external: bool,
   state: Rc<RefCell<WebGL2State>>,
 }
 
+impl<D> Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Number of color attachments.
+  pub(crate) fn color_attachment_count(&self) -> usize {
+    self.color_encodings.len()
+  }
+
+  /// Whether any color attachment has an integer pixel format.
+  pub(crate) fn has_integer_color_attachments(&self) -> bool {
+    self
+      .color_encodings
+      .iter()
+      .any(|encoding| matches!(encoding, Type::Integral | Type::Unsigned))
+  }
+
+  /// Clear every color attachment, one by one.
+  ///
+  /// Each attachment has its own color, picked with [`PipelineState::attachment_clear_color`]. Integer attachments are
+  /// cleared with the integer clear color if set, or with their color converted to integers otherwise. The other
+  /// attachments are cleared with their color, if set. Unlike `clear`, this works with integer attachments and distinct
+  /// colors.
+  pub(crate) fn clear_color_attachments(
+    &self,
+    ctx: &WebGl2RenderingContext,
+    pipeline_state: &PipelineState,
+  ) {
+    let integer = pipeline_state.clear_color_integer;
+
+    for (i, encoding) in self.color_encodings.iter().enumerate() {
+      let color = pipeline_state.attachment_clear_color(i);
+      let draw_buffer = i as i32;
+
+      match encoding {
+        Type::Integral => {
+          let value = integer
+            .map(|c| c.map(|c| c as i32))
+            .or_else(|| color.map(|c| c.map(|c| c as i32)));
+
+          if let Some(value) = value {
+            ctx.clear_bufferiv_with_i32_array(WebGl2RenderingContext::COLOR, draw_buffer, &value);
+          }
+        }
+
+        Type::Unsigned => {
+          let value = integer.or_else(|| color.map(|c| c.map(|c| c as u32)));
+
+          if let Some(value) = value {
+            ctx.clear_bufferuiv_with_u32_array(WebGl2RenderingContext::COLOR, draw_buffer, &value);
+          }
+        }
+
+        _ => {
+          if let Some(color) = color {
+            ctx.clear_bufferfv_with_f32_array(WebGl2RenderingContext::COLOR, draw_buffer, &color);
+          }
+        }
+      }
+    }
+  }
+}
+
 impl<D> Drop for Framebuffer<D>
 where
   D: Dimensionable,
 {
   fn drop(&mut self) {
-    let state = self.state.borrow();
+    let mut state = self.state.borrow_mut();
 
     state.ctx.delete_renderbuffer(self.renderbuffer.as_ref());
-    state.ctx.delete_framebuffer(self.handle.as_ref());
+
+    if self.handle.is_some() {
+      state.forget_draw_buffers(self.handle.as_ref());
+
+      if !self.external {
+        state.ctx.delete_framebuffer(self.handle.as_ref());
+      }
+    }
   }
 }
 
@@ -53,73 +137,7 @@ where
     CS: ColorSlot<Self, D>,
     DS: DepthStencilSlot<Self, D>,
   {
-    let color_formats = CS::color_formats();
-    let depth_format = DS::depth_format();
-    let mut depth_renderbuffer = None;
-
-    let mut state = self.state.borrow_mut();
-
-    let handle = state
-      .create_framebuffer()
-      .ok_or_else(|| FramebufferError::cannot_create())?;
-    state.bind_draw_framebuffer(Some(&handle));
-
-    // reserve textures to speed up slots creation
-    let textures_needed = color_formats.len() + depth_format.map_or(0, |_| 1);
-    state.reserve_textures(textures_needed);
-
-    // color textures
-    if color_formats.is_empty() {
-      state.ctx.draw_buffers(&WebGl2RenderingContext::NONE.into());
-    } else {
-      // Specify the list of color buffers to draw to; to do so, we need to generate a temporary
-      // list (Vec) of 32-bit integers and turn it into a Uint32Array to pass it across WASM
-      // boundary.
-      let color_buf_nb = color_formats.len() as u32;
-      let color_buffers: Vec<_> = (WebGl2RenderingContext::COLOR_ATTACHMENT0
-        ..WebGl2RenderingContext::COLOR_ATTACHMENT0 + color_buf_nb)
-        .collect();
-
-      let buffers = Uint32Array::view(&color_buffers);
-
-      state.ctx.draw_buffers(buffers.as_ref());
-    }
-
-    // depth texture
-    if depth_format.is_none() {
-      let renderbuffer = state
-        .ctx
-        .create_renderbuffer()
-        .ok_or_else(|| FramebufferError::cannot_create())?;
-
-      state
-        .ctx
-        .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(&renderbuffer));
-
-      state.ctx.renderbuffer_storage(
-        WebGl2RenderingContext::RENDERBUFFER,
-        WebGl2RenderingContext::DEPTH_COMPONENT32F,
-        D::width(size) as i32,
-        D::height(size) as i32,
-      );
-      state.ctx.framebuffer_renderbuffer(
-        WebGl2RenderingContext::FRAMEBUFFER,
-        WebGl2RenderingContext::DEPTH_ATTACHMENT,
-        WebGl2RenderingContext::RENDERBUFFER,
-        Some(&renderbuffer),
-      );
-
-      depth_renderbuffer = Some(renderbuffer);
-    }
-
-    let framebuffer = Framebuffer {
-      handle: Some(handle),
-      renderbuffer: depth_renderbuffer,
-      size,
-      state: self.state.clone(),
-    };
-
-    Ok(framebuffer)
+    new_framebuffer::<D>(self, size, &CS::color_formats(), DS::depth_format())
   }
 
   unsafe fn attach_color_texture(
@@ -145,6 +163,77 @@ where
     }
   }
 
+  unsafe fn attach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+    pf: PixelFormat,
+    attachment_index: usize,
+    layer: u32,
+  ) -> Result<(), FramebufferError> {
+    // the back buffer cannot have texture attachments
+    let handle = framebuffer
+      .handle
+      .as_ref()
+      .ok_or_else(FramebufferError::unsupported_attachment)?;
+
+    match texture.target {
+      WebGl2RenderingContext::TEXTURE_3D | WebGl2RenderingContext::TEXTURE_2D_ARRAY => {
+        let mut state = framebuffer.state.borrow_mut();
+        // draw buffers overridden by a render state refer to the previous attachments
+        state.reset_draw_buffers();
+        state.bind_draw_framebuffer(Some(handle));
+
+        state.ctx.framebuffer_texture_layer(
+          WebGl2RenderingContext::FRAMEBUFFER,
+          WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32,
+          Some(&texture.handle),
+          0,
+          layer as i32,
+        );
+
+        // the framebuffer might have been created without any color buffer to draw to
+        let color_encodings = &mut framebuffer.color_encodings;
+        if color_encodings.len() <= attachment_index {
+          color_encodings.resize(attachment_index + 1, pf.encoding);
+        }
+        color_encodings[attachment_index] = pf.encoding;
+        set_color_draw_buffers(&state.ctx, color_encodings.len());
+
+        state.check_errors("framebuffer texture layer attachment");
+
+        get_framebuffer_status(&mut state)?;
+        Ok(())
+      }
+
+      _ => Err(FramebufferError::unsupported_attachment()),
+    }
+  }
+
+  unsafe fn detach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachment_index: usize,
+  ) {
+    let handle = match framebuffer.handle {
+      Some(ref handle) => handle,
+      None => return,
+    };
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.reset_draw_buffers();
+    state.bind_draw_framebuffer(Some(handle));
+
+    state.ctx.framebuffer_texture_layer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      WebGl2RenderingContext::COLOR_ATTACHMENT0 + attachment_index as u32,
+      None,
+      0,
+      0,
+    );
+
+    framebuffer.color_encodings.truncate(attachment_index);
+    set_color_draw_buffers(&state.ctx, framebuffer.color_encodings.len());
+  }
+
   unsafe fn attach_depth_texture(
     framebuffer: &mut Self::FramebufferRepr,
     texture: &Self::TextureRepr,
@@ -177,6 +266,174 @@ where
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size {
     framebuffer.size
   }
+
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  ) -> Result<(), FramebufferError> {
+    let back_buffer = framebuffer.handle.is_none();
+    let mut webgl_attachments = Vec::with_capacity(attachments.len());
+    for &attachment in attachments {
+      push_webgl_attachment(&mut webgl_attachments, attachment, back_buffer)?;
+    }
+
+    let mut state = framebuffer.state.borrow_mut();
+    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+
+    let attachments = Uint32Array::view(&webgl_attachments);
+    state
+      .ctx
+      .invalidate_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, attachments.as_ref())
+      .map_err(|_| FramebufferError::unsupported_attachment())?;
+
+    state.check_errors("framebuffer invalidation");
+
+    Ok(())
+  }
+}
+
+unsafe impl<D> DynamicFramebuffer<D> for WebGL2
+where
+  D: Dimensionable,
+{
+  unsafe fn new_dynamic_framebuffer(
+    &mut self,
+    size: D::Size,
+    color_formats: &[PixelFormat],
+    depth_stencil_format: Option<PixelFormat>,
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    new_framebuffer::<D>(self, size, color_formats, depth_stencil_format)
+  }
+
+  unsafe fn new_attachment_texture(
+    &mut self,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    format: PixelFormat,
+  ) -> Result<Texture, FramebufferError> {
+    let texture = new_texture_storage::<D>(self, size, mipmaps, format, *sampler)?;
+    self
+      .state
+      .borrow_mut()
+      .check_errors("attachment texture creation");
+
+    Ok(texture)
+  }
+}
+
+unsafe fn new_framebuffer<D>(
+  webgl2: &mut WebGL2,
+  size: D::Size,
+  color_formats: &[PixelFormat],
+  depth_format: Option<PixelFormat>,
+) -> Result<Framebuffer<D>, FramebufferError>
+where
+  D: Dimensionable,
+{
+  let mut depth_renderbuffer = None;
+
+  let mut state = webgl2.state.borrow_mut();
+
+  let handle = state
+    .create_framebuffer()
+    .ok_or_else(|| FramebufferError::cannot_create())?;
+  state.bind_draw_framebuffer(Some(&handle));
+
+  // reserve textures to speed up slots creation
+  let textures_needed = color_formats.len() + depth_format.map_or(0, |_| 1);
+  state.reserve_textures(textures_needed);
+
+  // color textures
+  set_color_draw_buffers(&state.ctx, color_formats.len());
+
+  // depth texture
+  if depth_format.is_none() {
+    let renderbuffer = state
+      .ctx
+      .create_renderbuffer()
+      .ok_or_else(|| FramebufferError::cannot_create())?;
+
+    state
+      .ctx
+      .bind_renderbuffer(WebGl2RenderingContext::RENDERBUFFER, Some(&renderbuffer));
+
+    state.ctx.renderbuffer_storage(
+      WebGl2RenderingContext::RENDERBUFFER,
+      WebGl2RenderingContext::DEPTH_COMPONENT32F,
+      D::width(size) as i32,
+      D::height(size) as i32,
+    );
+    state.ctx.framebuffer_renderbuffer(
+      WebGl2RenderingContext::FRAMEBUFFER,
+      WebGl2RenderingContext::DEPTH_ATTACHMENT,
+      WebGl2RenderingContext::RENDERBUFFER,
+      Some(&renderbuffer),
+    );
+
+    depth_renderbuffer = Some(renderbuffer);
+  }
+
+  state.check_errors("framebuffer creation");
+
+  let framebuffer = Framebuffer {
+    handle: Some(handle),
+    renderbuffer: depth_renderbuffer,
+    size,
+    color_encodings: color_formats.iter().map(|format| format.encoding).collect(),
+    external: false,
+    state: webgl2.state.clone(),
+  };
+
+  Ok(framebuffer)
+}
+
+// Draw to the first `color_attachments` color attachments of the bound draw framebuffer.
+fn set_color_draw_buffers(ctx: &WebGl2RenderingContext, color_attachments: usize) {
+  if color_attachments == 0 {
+    ctx.draw_buffers(&WebGl2RenderingContext::NONE.into());
+  } else {
+    // Specify the list of color buffers to draw to; to do so, we need to generate a temporary
+    // list (Vec) of 32-bit integers and turn it into a Uint32Array to pass it across WASM
+    // boundary.
+    let color_buf_nb = color_attachments as u32;
+    let color_buffers: Vec<_> = (WebGl2RenderingContext::COLOR_ATTACHMENT0
+      ..WebGl2RenderingContext::COLOR_ATTACHMENT0 + color_buf_nb)
+      .collect();
+
+    let buffers = unsafe { Uint32Array::view(&color_buffers) };
+
+    ctx.draw_buffers(buffers.as_ref());
+  }
+}
+
+// Push the enums to use to refer to an attachment; the back buffer uses different enums.
+fn push_webgl_attachment(
+  attachments: &mut Vec<u32>,
+  attachment: Attachment,
+  back_buffer: bool,
+) -> Result<(), FramebufferError> {
+  match (attachment, back_buffer) {
+    (Attachment::Color(0), true) => attachments.push(WebGl2RenderingContext::COLOR),
+    (Attachment::Color(_), true) => return Err(FramebufferError::unsupported_attachment()),
+    (Attachment::Color(i), false) => {
+      attachments.push(WebGl2RenderingContext::COLOR_ATTACHMENT0 + i as u32)
+    }
+    (Attachment::Depth, true) => attachments.push(WebGl2RenderingContext::DEPTH),
+    (Attachment::Depth, false) => attachments.push(WebGl2RenderingContext::DEPTH_ATTACHMENT),
+    (Attachment::Stencil, true) => attachments.push(WebGl2RenderingContext::STENCIL),
+    (Attachment::Stencil, false) => attachments.push(WebGl2RenderingContext::STENCIL_ATTACHMENT),
+    // the back buffer has no combined depth-stencil enum
+    (Attachment::DepthStencil, true) => attachments.extend_from_slice(&[
+      WebGl2RenderingContext::DEPTH,
+      WebGl2RenderingContext::STENCIL,
+    ]),
+    (Attachment::DepthStencil, false) => {
+      attachments.push(WebGl2RenderingContext::DEPTH_STENCIL_ATTACHMENT)
+    }
+  }
+
+  Ok(())
 }
 
 fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReason> {
@@ -196,10 +453,10 @@ fn get_framebuffer_status(state: &mut WebGL2State) -> Result<(), IncompleteReaso
     WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => {
       Err(IncompleteReason::IncompleteMultisample)
     }
-    _ => panic!(
-      "unknown WebGL2 framebuffer incomplete status! status={}",
-      status
-    ),
+    WebGl2RenderingContext::FRAMEBUFFER_INCOMPLETE_DIMENSIONS => {
+      Err(IncompleteReason::IncompleteDimensions)
+    }
+    _ => Err(IncompleteReason::Unknown(status)),
   }
 }
 
@@ -212,7 +469,121 @@ unsafe impl FramebufferBackBuffer for WebGL2 {
       handle: None, // None is the default framebuffer in WebGL
       renderbuffer: None,
       size,
+      color_encodings: Vec::new(),
+      external: false,
       state: self.state.clone(),
     })
   }
 }
+
+unsafe impl FramebufferBackBufferSrgb for WebGL2 {
+  unsafe fn back_buffer_is_srgb(&mut self) -> bool {
+    let mut state = self.state.borrow_mut();
+    state.bind_draw_framebuffer(None);
+
+    let encoding = state
+      .ctx
+      .get_framebuffer_attachment_parameter(
+        WebGl2RenderingContext::FRAMEBUFFER,
+        WebGl2RenderingContext::BACK,
+        WebGl2RenderingContext::FRAMEBUFFER_ATTACHMENT_COLOR_ENCODING,
+      )
+      .ok()
+      .and_then(|encoding| encoding.as_f64());
+
+    encoding == Some(WebGl2RenderingContext::SRGB as f64)
+  }
+}
+
+unsafe impl FramebufferRawHandle for WebGL2 {
+  type RawFramebufferHandle = WebGlFramebuffer;
+
+  unsafe fn framebuffer_from_raw_handle(
+    &mut self,
+    handle: Self::RawFramebufferHandle,
+    size: <Dim2 as Dimensionable>::Size,
+    color_formats: &[PixelFormat],
+  ) -> Result<Self::FramebufferRepr, FramebufferError> {
+    let mut state = self.state.borrow_mut();
+
+    // the third party might have bound framebuffers behind our back, so the binding must not be skipped
+    state
+      .ctx
+      .bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&handle));
+    state.bind_draw_framebuffer(Some(&handle));
+    state.forget_draw_buffers(Some(&handle));
+    set_color_draw_buffers(&state.ctx, color_formats.len());
+
+    get_framebuffer_status(&mut state)?;
+    state.check_errors("raw framebuffer wrapping");
+
+    Ok(Framebuffer {
+      handle: Some(handle),
+      renderbuffer: None,
+      size,
+      color_encodings: color_formats.iter().map(|format| format.encoding).collect(),
+      external: true,
+      state: self.state.clone(),
+    })
+  }
+}
+
+unsafe impl FramebufferDepthCopy for WebGL2 {
+  unsafe fn copy_depth_to_texture(
+    framebuffer: &Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+  ) -> Result<(), FramebufferError> {
+    let mut state = framebuffer.state.borrow_mut();
+    let [width, height] = framebuffer.size;
+
+    // blit from the framebuffer into a temporary framebuffer which depth attachment is the texture
+    let copy_handle = state
+      .create_framebuffer()
+      .ok_or_else(FramebufferError::cannot_create)?;
+    state.bind_draw_framebuffer(Some(&copy_handle));
+    state.ctx.framebuffer_texture_2d(
+      WebGl2RenderingContext::DRAW_FRAMEBUFFER,
+      WebGl2RenderingContext::DEPTH_ATTACHMENT,
+      WebGl2RenderingContext::TEXTURE_2D,
+      Some(&texture.handle),
+      0,
+    );
+
+    let buffers = [WebGl2RenderingContext::NONE];
+    let buffers = Uint32Array::view(&buffers);
+    state.ctx.draw_buffers(buffers.as_ref());
+
+    let status = get_framebuffer_status(&mut state);
+
+    if status.is_ok() {
+      state.bind_read_framebuffer(framebuffer.handle.as_ref());
+      state.ctx.blit_framebuffer(
+        0,
+        0,
+        width as i32,
+        height as i32,
+        0,
+        0,
+        width as i32,
+        height as i32,
+        WebGl2RenderingContext::DEPTH_BUFFER_BIT,
+        WebGl2RenderingContext::NEAREST,
+      );
+    }
+
+    // binding the draw framebuffer binds the read one as well
+    state.bind_draw_framebuffer(None);
+    state.bind_read_framebuffer(None);
+    state.ctx.delete_framebuffer(Some(&copy_handle));
+
+    state.check_errors("framebuffer depth copy");
+
+    status.map_err(FramebufferError::from)
+  }
+}
+
+unsafe impl FramebufferSharedDepth for WebGL2 {
+  unsafe fn share_depth_texture(texture: &Self::TextureRepr) -> Self::TextureRepr {
+    texture.share()
+  }
+}
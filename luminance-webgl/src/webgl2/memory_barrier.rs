@@ -0,0 +1,21 @@
+//! Memory barrier implementation.
+//!
+//! WebGL2 has no incoherent memory access, and hence no memory barrier: requesting one is reported as an error.
+
+use crate::WebGL2;
+use luminance::{
+  backend::memory_barrier::MemoryBarrier,
+  error_check::BackendErrorKind,
+  memory_barrier::{MemoryBarrierBits, MemoryBarrierError},
+};
+
+unsafe impl MemoryBarrier for WebGL2 {
+  unsafe fn memory_barrier(&mut self, bits: MemoryBarrierBits) -> Result<(), MemoryBarrierError> {
+    self.state.borrow_mut().record_error(
+      "memory barrier",
+      BackendErrorKind::Unsupported(format!("memory barrier ({})", bits)),
+    );
+
+    Err(MemoryBarrierError::unsupported(bits))
+  }
+}
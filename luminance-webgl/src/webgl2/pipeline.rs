@@ -7,13 +7,19 @@ use luminance::{
     shader::ShaderData,
     shading_gate::ShadingGate,
     tess::Tess,
-    tess_gate::TessGate,
+    tess_gate::{TessGate, TessGateDebug},
   },
   blending::BlendingMode,
+  depth_stencil::Write,
+  error_check::BackendErrorKind,
   pipeline::{PipelineError, PipelineState, Viewport},
   pixel::Pixel,
   render_state::RenderState,
-  tess::{Deinterleaved, DeinterleavedData, Interleaved, TessIndex, TessVertexData},
+  tess::{
+    Deinterleaved, DeinterleavedData, Interleaved, RawInterleaved, RawVertexData, TessError,
+    TessIndex, TessVertexData,
+  },
+  tess_gate::DebugMode,
   texture::Dimensionable,
 };
 use luminance_std140::{ArrElem, Std140};
@@ -22,6 +28,7 @@ use web_sys::WebGl2RenderingContext;
 
 use crate::webgl2::{
   array_buffer::IntoArrayBuffer,
+  debug::with_debug_program,
   state::{BlendingState, FaceCullingState, ScissorState, WebGL2State},
   WebGL2,
 };
@@ -99,7 +106,10 @@ where
   ) {
     let mut state = self.state.borrow_mut();
 
-    state.bind_draw_framebuffer(framebuffer.handle.as_ref());
+    state.bind_pipeline_framebuffer(
+      framebuffer.handle.as_ref(),
+      framebuffer.color_attachment_count(),
+    );
 
     let size = framebuffer.size;
 
@@ -116,13 +126,30 @@ where
     state.set_viewport([x as _, y as _, w as _, h as _]);
 
     let mut clear_buffer_bits = 0;
-
-    if let Some(clear_color) = pipeline_state.clear_color {
+    let mut clear_color_attachments = false;
+
+    let per_attachment_colors =
+      !pipeline_state.clear_colors.is_empty() && framebuffer.color_attachment_count() > 1;
+
+    if framebuffer.has_integer_color_attachments() || per_attachment_colors {
+      // clear leaves integer attachments undefined and uses a single color; attachments are then cleared one by one
+      // once the scissor is set
+      clear_color_attachments = pipeline_state.clear_color.is_some()
+        || pipeline_state.clear_color_integer.is_some()
+        || !pipeline_state.clear_colors.is_empty();
+    } else if let Some(clear_color) = pipeline_state.attachment_clear_color(0) {
       state.set_clear_color(clear_color);
       clear_buffer_bits |= WebGl2RenderingContext::COLOR_BUFFER_BIT;
     }
 
+    // clears are masked like renders, and the last render state might have disabled writes
+    if clear_color_attachments || clear_buffer_bits & WebGl2RenderingContext::COLOR_BUFFER_BIT != 0
+    {
+      state.set_color_mask([true; 4]);
+    }
+
     if let Some(clear_depth) = pipeline_state.clear_depth {
+      state.set_depth_write(Write::On);
       state.set_clear_depth(clear_depth);
       clear_buffer_bits |= WebGl2RenderingContext::DEPTH_BUFFER_BIT;
     }
@@ -147,6 +174,12 @@ where
     if clear_buffer_bits != 0 {
       state.ctx.clear(clear_buffer_bits);
     }
+
+    if clear_color_attachments {
+      framebuffer.clear_color_attachments(&state.ctx, pipeline_state);
+    }
+
+    state.check_errors("pipeline start");
   }
 }
 
@@ -168,14 +201,21 @@ where
     P: Pixel,
   {
     let mut state = pipeline.state.borrow_mut();
+    let max = state.get_max_texture_units();
     let bstack = state.binding_stack_mut();
 
-    let unit = bstack.free_texture_units.pop().unwrap_or_else(|| {
+    let unit = match bstack.free_texture_units.pop() {
+      Some(unit) => unit,
+
       // no more free units; reserve one
-      let unit = bstack.next_texture_unit;
-      bstack.next_texture_unit += 1;
-      unit
-    });
+      None if bstack.next_texture_unit < max => {
+        let unit = bstack.next_texture_unit;
+        bstack.next_texture_unit += 1;
+        unit
+      }
+
+      None => return Err(PipelineError::out_of_texture_units(max)),
+    };
 
     state.set_texture_unit(unit);
     state.bind_texture(texture.target, Some(texture.handle()));
@@ -210,14 +250,21 @@ where
     shader_data: &Self::ShaderDataRepr,
   ) -> Result<Self::BoundShaderDataRepr, PipelineError> {
     let mut state = pipeline.state.borrow_mut();
+    let max = state.get_max_shader_data_bindings();
     let bstack = state.binding_stack_mut();
 
-    let binding = bstack.free_shader_data_bindings.pop().unwrap_or_else(|| {
-      // no more free bindings; resorve one
-      let binding = bstack.next_shader_data_binding;
-      bstack.next_shader_data_binding += 1;
-      binding
-    });
+    let binding = match bstack.free_shader_data_bindings.pop() {
+      Some(binding) => binding,
+
+      // no more free bindings; reserve one
+      None if bstack.next_shader_data_binding < max => {
+        let binding = bstack.next_shader_data_binding;
+        bstack.next_shader_data_binding += 1;
+        binding
+      }
+
+      None => return Err(PipelineError::too_many_shader_data_bindings(max)),
+    };
 
     state.bind_uniform_buffer_at(shader_data.handle(), binding);
 
@@ -245,8 +292,17 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
-  ) {
-    let _ = <Self as Tess<V, I, W, Interleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Interleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
   }
 }
 
@@ -262,8 +318,112 @@ where
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, Deinterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> TessGate<V, I, W, RawInterleaved> for WebGL2
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn render(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    <Self as Tess<V, I, W, RawInterleaved>>::render(
+      tess,
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, Interleaved> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
   ) {
-    let _ = <Self as Tess<V, I, W, Deinterleaved>>::render(tess, start_index, vert_nb, inst_nb);
+    let vertices_nb = <Self as Tess<V, I, W, Interleaved>>::tess_vertices_nb(tess);
+
+    with_debug_program(self, mode, transform, |vectors| match vectors {
+      Some(attribs) => tess.raw.render_debug_vectors(attribs, vertices_nb),
+      None => tess.raw.render_debug_wireframe(start_index, vert_nb),
+    });
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, Deinterleaved> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
+  ) {
+    let vertices_nb = <Self as Tess<V, I, W, Deinterleaved>>::tess_vertices_nb(tess);
+
+    with_debug_program(self, mode, transform, |vectors| match vectors {
+      Some(attribs) => tess.raw.render_debug_vectors(attribs, vertices_nb),
+      None => tess.raw.render_debug_wireframe(start_index, vert_nb),
+    });
+  }
+}
+
+unsafe impl<V, I, W> TessGateDebug<V, I, W, RawInterleaved> for WebGL2
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
+  ) {
+    let vertices_nb = <Self as Tess<V, I, W, RawInterleaved>>::tess_vertices_nb(tess);
+
+    with_debug_program(self, mode, transform, |vectors| match vectors {
+      Some(attribs) => tess.raw.render_debug_vectors(attribs, vertices_nb),
+      None => tess.raw.render_debug_wireframe(start_index, vert_nb),
+    });
   }
 }
 
@@ -275,6 +435,16 @@ unsafe impl RenderGate for WebGL2 {
     match rdr_st.blending() {
       Some(blending) => {
         state.set_blending_state(BlendingState::On);
+
+        // WebGL2 cannot toggle blending per draw buffer
+        if rdr_st.blend_enabled_buffers().is_some() {
+          log::warn!("per draw buffer blending not supported; blending all draw buffers");
+          state.record_error(
+            "render state",
+            BackendErrorKind::Unsupported("per draw buffer blending".to_owned()),
+          );
+        }
+
         match blending {
           BlendingMode::Combined(b) => {
             state.set_blending_equation(b.equation);
@@ -301,6 +471,7 @@ unsafe impl RenderGate for WebGL2 {
     }
 
     state.set_depth_write(rdr_st.depth_write());
+    state.set_color_mask(rdr_st.color_mask());
 
     // stencil-related state
     if let Some(stencil_test) = rdr_st.stencil_test() {
@@ -336,14 +507,41 @@ unsafe impl RenderGate for WebGL2 {
         state.set_scissor_state(ScissorState::Off);
       }
     }
+
+    // WebGL2 has no user clip planes
+    if rdr_st.clip_planes() != 0 {
+      log::warn!("clip distances not supported; ignoring them");
+      state.record_error(
+        "render state",
+        BackendErrorKind::Unsupported("clip distances".to_owned()),
+      );
+    }
+
+    // WebGL2 has no sample shading
+    if rdr_st.min_sample_shading().is_some() {
+      log::warn!("sample shading not supported; ignoring it");
+      state.record_error(
+        "render state",
+        BackendErrorKind::Unsupported("sample shading".to_owned()),
+      );
+    }
+
+    // WebGL2 always takes the point size from gl_PointSize, so rdr_st.program_point_size() has nothing to set
+
+    // draw buffers
+    match rdr_st.draw_buffers() {
+      Some(draw_buffers) => state.set_draw_buffers(draw_buffers),
+      None => state.reset_draw_buffers(),
+    }
+
+    state.check_errors("render state");
   }
 }
 
 unsafe impl ShadingGate for WebGL2 {
   unsafe fn apply_shader_program(&mut self, shader_program: &Self::ProgramRepr) {
-    self
-      .state
-      .borrow_mut()
-      .use_program(Some(&shader_program.handle));
+    let mut state = self.state.borrow_mut();
+    state.use_program(Some(&shader_program.handle));
+    state.check_errors("shader program application");
   }
 }
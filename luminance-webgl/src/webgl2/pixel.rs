@@ -1,6 +1,13 @@
-use luminance::pixel::{Format, PixelFormat, Size, Type};
+use luminance::pixel::{CompressedFormat, Format, PixelFormat, Size, Type};
 use web_sys::WebGl2RenderingContext;
 
+// compressed internal formats, exposed by WebGL extensions
+const COMPRESSED_RGBA_S3TC_DXT1_EXT: u32 = 0x83F1;
+const COMPRESSED_RGBA_S3TC_DXT5_EXT: u32 = 0x83F3;
+const COMPRESSED_RGBA_BPTC_UNORM_EXT: u32 = 0x8E8C;
+const COMPRESSED_RGB8_ETC2: u32 = 0x9274;
+const COMPRESSED_RGBA8_ETC2_EAC: u32 = 0x9278;
+
 // WebGL format, internal sized-format and type.
 pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
   match (pf.format, pf.encoding) {
@@ -301,12 +308,51 @@ pub(crate) fn webgl_pixel_format(pf: PixelFormat) -> Option<(u32, u32, u32)> {
       WebGl2RenderingContext::FLOAT,
     )),
 
+    (Format::Depth(Size::TwentyFour), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::DEPTH_COMPONENT,
+      WebGl2RenderingContext::DEPTH_COMPONENT24,
+      WebGl2RenderingContext::UNSIGNED_INT,
+    )),
+
+    (Format::DepthStencil(Size::TwentyFour, Size::Eight), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::DEPTH_STENCIL,
+      WebGl2RenderingContext::DEPTH24_STENCIL8,
+      WebGl2RenderingContext::UNSIGNED_INT_24_8,
+    )),
+
     (Format::DepthStencil(Size::ThirtyTwo, Size::Eight), Type::Floating) => Some((
       WebGl2RenderingContext::DEPTH_STENCIL,
       WebGl2RenderingContext::DEPTH32F_STENCIL8,
       WebGl2RenderingContext::FLOAT_32_UNSIGNED_INT_24_8_REV,
     )),
 
+    // compressed formats; format and type are unused, as compressed texels are uploaded as-is
+    (Format::Compressed(cf), Type::NormUnsigned) => Some((
+      WebGl2RenderingContext::RGBA,
+      webgl_compressed_format(cf).0,
+      WebGl2RenderingContext::UNSIGNED_BYTE,
+    )),
+
     _ => None,
   }
 }
+
+// WebGL internal format of a compressed format, along with the extension that must be enabled to use it.
+pub(crate) fn webgl_compressed_format(cf: CompressedFormat) -> (u32, &'static str) {
+  match cf {
+    CompressedFormat::BC1 => (
+      COMPRESSED_RGBA_S3TC_DXT1_EXT,
+      "WEBGL_compressed_texture_s3tc",
+    ),
+    CompressedFormat::BC3 => (
+      COMPRESSED_RGBA_S3TC_DXT5_EXT,
+      "WEBGL_compressed_texture_s3tc",
+    ),
+    CompressedFormat::BC7 => (
+      COMPRESSED_RGBA_BPTC_UNORM_EXT,
+      "EXT_texture_compression_bptc",
+    ),
+    CompressedFormat::ETC2RGB8 => (COMPRESSED_RGB8_ETC2, "WEBGL_compressed_texture_etc"),
+    CompressedFormat::ETC2RGBA8 => (COMPRESSED_RGBA8_ETC2_EAC, "WEBGL_compressed_texture_etc"),
+  }
+}
@@ -1,7 +1,13 @@
 //! Query API implementation.
 
-use crate::WebGL2;
-use luminance::backend::query::{Query as QueryBackend, QueryError};
+use crate::webgl2::{state::WebGL2State, WebGL2};
+use luminance::{
+  backend::query::{
+    PipelineStatsQuery as PipelineStatsQueryBackend, Query as QueryBackend, QueryError,
+  },
+  query::PipelineStats,
+};
+use std::{cell::RefCell, rc::Rc};
 
 unsafe impl QueryBackend for WebGL2 {
   fn backend_author(&self) -> Result<String, QueryError> {
@@ -44,3 +50,82 @@ unsafe impl QueryBackend for WebGL2 {
       .ok_or_else(|| QueryError::NoMaxTextureArrayElements)
   }
 }
+
+/// Pipeline statistics query.
+///
+/// WebGL2 cannot count any of the pipeline statistics — it only has boolean occlusion queries — so this query only
+/// tracks whether it’s active, and all of its statistics are [`None`].
+pub struct PipelineStatsQuery {
+  active: bool,
+  ended: bool,
+  state: Rc<RefCell<WebGL2State>>,
+}
+
+impl Drop for PipelineStatsQuery {
+  fn drop(&mut self) {
+    if self.active {
+      self
+        .state
+        .borrow_mut()
+        .set_pipeline_stats_query_active(false);
+    }
+  }
+}
+
+unsafe impl PipelineStatsQueryBackend for WebGL2 {
+  type PipelineStatsQueryRepr = PipelineStatsQuery;
+
+  unsafe fn new_pipeline_stats_query(
+    &mut self,
+  ) -> Result<Self::PipelineStatsQueryRepr, QueryError> {
+    Ok(PipelineStatsQuery {
+      active: false,
+      ended: false,
+      state: self.state.clone(),
+    })
+  }
+
+  unsafe fn begin_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError> {
+    let mut state = query.state.borrow_mut();
+
+    if state.is_pipeline_stats_query_active() {
+      return Err(QueryError::PipelineStatsQueryActive);
+    }
+
+    state.set_pipeline_stats_query_active(true);
+    query.active = true;
+
+    Ok(())
+  }
+
+  unsafe fn end_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError> {
+    if !query.active {
+      return Err(QueryError::PipelineStatsQueryInactive);
+    }
+
+    query
+      .state
+      .borrow_mut()
+      .set_pipeline_stats_query_active(false);
+    query.active = false;
+    query.ended = true;
+
+    Ok(())
+  }
+
+  unsafe fn pipeline_stats(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<PipelineStats, QueryError> {
+    if query.active {
+      Err(QueryError::PipelineStatsQueryActive)
+    } else if !query.ended {
+      Err(QueryError::NoPipelineStats)
+    } else {
+      Ok(PipelineStats::default())
+    }
+  }
+}
@@ -2,14 +2,19 @@
 
 use super::buffer::{Buffer, BufferError};
 use crate::webgl2::{state::WebGL2State, WebGL2};
+use js_sys::{Array, Uint32Array};
 use luminance::{
-  backend::shader::{Shader, ShaderData, Uniformable},
+  backend::{
+    feedback::ShaderFeedback,
+    shader::{Shader, ShaderData, Uniformable, UniformableMatrix},
+  },
+  feedback::FeedbackMode,
   pipeline::{ShaderDataBinding, TextureBinding},
   pixel::{SamplerType, Type as PixelType},
   shader::{
     types::{Arr, Mat22, Mat33, Mat44, Vec2, Vec3, Vec4},
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    BlockLayout, ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform,
+    UniformType, UniformWarning, VertexAttribWarning,
   },
   texture::{Dim, Dimensionable},
   vertex::Semantics,
@@ -134,10 +139,14 @@ impl Program {
       state,
     };
 
-    program.link().map(move |_| program)
+    let linked = program.link().map(move |_| program);
+
+    webgl2.state.borrow_mut().check_errors("program creation");
+
+    linked
   }
 
-  fn link(&self) -> Result<(), ProgramError> {
+  pub(crate) fn link(&self) -> Result<(), ProgramError> {
     let handle = &self.handle;
     let state = self.state.borrow();
 
@@ -259,6 +268,73 @@ unsafe impl Shader for WebGL2 {
     Program::new(self, vertex, tess, geometry, fragment)
   }
 
+  unsafe fn program_binary(_: &Self::ProgramRepr) -> Option<(u32, Vec<u8>)> {
+    None
+  }
+
+  unsafe fn uniform_block_layout(program: &Self::ProgramRepr, name: &str) -> Option<BlockLayout> {
+    let state = program.state.borrow();
+    let ctx = &state.ctx;
+    let index = ctx.get_uniform_block_index(&program.handle, name);
+
+    if index == WebGl2RenderingContext::INVALID_INDEX {
+      return None;
+    }
+
+    let size = ctx
+      .get_active_uniform_block_parameter(
+        &program.handle,
+        index,
+        WebGl2RenderingContext::UNIFORM_BLOCK_DATA_SIZE,
+      )
+      .ok()?
+      .as_f64()? as usize;
+
+    let indices = ctx
+      .get_active_uniform_block_parameter(
+        &program.handle,
+        index,
+        WebGl2RenderingContext::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+      )
+      .ok()?;
+    let indices = Uint32Array::new(&indices);
+
+    let query = |pname| {
+      Array::from(&ctx.get_active_uniforms(&program.handle, &indices, pname))
+        .iter()
+        .map(|value| value.as_f64().unwrap_or(0.).max(0.) as usize)
+        .collect::<Vec<_>>()
+    };
+    let offsets = query(WebGl2RenderingContext::UNIFORM_OFFSET);
+    let strides = query(WebGl2RenderingContext::UNIFORM_ARRAY_STRIDE);
+
+    let mut members = indices
+      .to_vec()
+      .into_iter()
+      .zip(offsets.into_iter().zip(strides))
+      .map(|(uniform_index, (offset, stride))| {
+        let name = ctx
+          .get_active_uniform(&program.handle, uniform_index)
+          .map(|info| info.name())
+          .unwrap_or_default();
+        (name, offset, stride)
+      })
+      .collect::<Vec<_>>();
+    members.sort_by_key(|&(_, offset, _)| offset);
+
+    Some(BlockLayout { size, members })
+  }
+
+  unsafe fn new_program_from_binary(
+    &mut self,
+    _: u32,
+    _: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError> {
+    Err(ProgramError::binary_rejected(
+      "WebGL2 doesn’t support program binaries",
+    ))
+  }
+
   unsafe fn apply_semantics<Sem>(
     program: &mut Self::ProgramRepr,
   ) -> Result<Vec<VertexAttribWarning>, ProgramError>
@@ -305,6 +381,34 @@ unsafe impl Shader for WebGL2 {
   }
 }
 
+unsafe impl ShaderFeedback for WebGL2 {
+  unsafe fn apply_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[&str],
+    mode: FeedbackMode,
+  ) -> Result<Vec<String>, ProgramError> {
+    let buffer_mode = match mode {
+      FeedbackMode::Interleaved => WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
+      FeedbackMode::Separate => WebGl2RenderingContext::SEPARATE_ATTRIBS,
+    };
+
+    if link_feedback_varyings(program, varyings, buffer_mode).is_ok() {
+      return Ok(Vec::new());
+    }
+
+    // linking fails as soon as a single varying is missing; find them out by trying each varying on its own, then
+    // link with the found ones only
+    let (found, missing): (Vec<&str>, Vec<&str>) = varyings
+      .iter()
+      .copied()
+      .partition(|&varying| link_feedback_varyings(program, &[varying], buffer_mode).is_ok());
+
+    link_feedback_varyings(program, &found, buffer_mode)?;
+
+    Ok(missing.into_iter().map(String::from).collect())
+  }
+}
+
 fn webgl_shader_type(ty: StageType) -> Option<u32> {
   match ty {
     StageType::VertexShader => Some(WebGl2RenderingContext::VERTEX_SHADER),
@@ -390,6 +494,20 @@ fn check_types_match(name: &str, ty: UniformType, glty: u32) -> Result<(), Unifo
     }
   }
 
+  // GLSL booleans can also be set with integers, so accept integer uniforms for them
+  let int_as_bool = matches!(
+    (ty, glty),
+    (UniformType::Int, WebGl2RenderingContext::BOOL)
+      | (UniformType::IVec2, WebGl2RenderingContext::BOOL_VEC2)
+      | (UniformType::IVec3, WebGl2RenderingContext::BOOL_VEC3)
+      | (UniformType::IVec4, WebGl2RenderingContext::BOOL_VEC4)
+  );
+
+  if int_as_bool {
+    log::debug!("uniform {} is a GLSL boolean set with integers", name);
+    return Ok(());
+  }
+
   milkcheck!(
     ty,
     // scalars
@@ -455,6 +573,26 @@ where
   warnings
 }
 
+/// Declare the feedback varyings of a program and relink it.
+fn link_feedback_varyings(
+  program: &Program,
+  varyings: &[&str],
+  buffer_mode: u32,
+) -> Result<(), ProgramError> {
+  let names = Array::new();
+  for &varying in varyings {
+    names.push(&varying.into());
+  }
+
+  program.state.borrow().ctx.transform_feedback_varyings(
+    program.handle(),
+    names.as_ref(),
+    buffer_mode,
+  );
+
+  program.link()
+}
+
 fn get_vertex_attrib_location(
   state: &WebGL2State,
   program: &Program,
@@ -592,6 +730,24 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a, const N: usize> UniformableMatrix<'a, Arr<$q<$t>, N>> for WebGL2 {
+      unsafe fn update_transposed(
+        program: &mut Program,
+        uniform: &'a Uniform<Arr<$q<$t>, N>>,
+        value: Self::Target,
+      ) {
+        let data = flatten_slice!(value: $t, len = $size * N);
+
+        program.state.borrow().ctx.$f(
+          program.location_map.borrow().get(&uniform.index()),
+          true,
+          data,
+          0,
+          value.len() as u32,
+        );
+      }
+    }
   };
 
   (mat $q:ident $t:ty, $size:expr, $uty:tt, $f:tt) => {
@@ -614,6 +770,22 @@ macro_rules! impl_Uniformable {
         );
       }
     }
+
+    unsafe impl<'a> UniformableMatrix<'a, $q<$t>> for WebGL2 {
+      unsafe fn update_transposed(
+        program: &mut Program,
+        uniform: &'a Uniform<$q<$t>>,
+        value: Self::Target,
+      ) {
+        let data = flatten_slice!(value: $t, len = $size);
+
+        program.state.borrow().ctx.$f(
+          program.location_map.borrow().get(&uniform.index()),
+          true,
+          data,
+        );
+      }
+    }
   };
 }
 
@@ -962,6 +1134,12 @@ where
       (PixelType::Integral, Dim::Dim2Array) => UniformType::ISampler2DArray,
       (PixelType::Unsigned, Dim::Dim2Array) => UniformType::UISampler2DArray,
       (PixelType::Floating, Dim::Dim2Array) => UniformType::Sampler2DArray,
+
+      (PixelType::NormIntegral, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
+      (PixelType::NormUnsigned, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
+      (PixelType::Integral, Dim::Dim2Multisample(_)) => UniformType::ISampler2DMS,
+      (PixelType::Unsigned, Dim::Dim2Multisample(_)) => UniformType::UISampler2DMS,
+      (PixelType::Floating, Dim::Dim2Multisample(_)) => UniformType::Sampler2DMS,
     }
   }
 
@@ -1,13 +1,17 @@
 //! Graphics state.
 
-use js_sys::{Float32Array, Int32Array, Uint32Array};
+use crate::webgl2::error_check::webgl_error_to_error_kind;
+use js_sys::{Array, Float32Array, Int32Array, Uint32Array};
 use luminance::{
-  blending::{Equation, Factor},
+  blending::{Blending, BlendingMode, Equation, Factor},
+  context::StateSnapshot,
   depth_stencil::{Comparison, StencilOp, StencilOperations, StencilTest, Write},
+  error_check::{BackendError, BackendErrorKind, ErrorLog},
   face_culling::{FaceCullingMode, FaceCullingOrder},
   scissor::ScissorRegion,
 };
 use std::{fmt, marker::PhantomData};
+use wasm_bindgen::JsCast;
 use web_sys::{
   WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlTexture,
   WebGlVertexArrayObject,
@@ -33,6 +37,15 @@ impl BindingStack {
   }
 }
 
+/// Draw buffers of a framebuffer overridden by a render state.
+#[derive(Debug)]
+struct DrawBuffersOverride {
+  framebuffer: Option<WebGlFramebuffer>,
+  color_attachments: usize,
+  // `None` if the draw buffers must be set again
+  draw_buffers: Option<Vec<u32>>,
+}
+
 /// The graphics state.
 ///
 /// This type represents the current state of a given graphics context. It acts
@@ -74,6 +87,9 @@ pub struct WebGL2State {
   // depth write
   depth_write: Write,
 
+  // color channels written to the color attachments
+  color_mask: [bool; 4],
+
   // face culling
   face_culling_state: FaceCullingState,
   face_culling_order: FaceCullingOrder,
@@ -85,6 +101,9 @@ pub struct WebGL2State {
 
   // texture
   current_texture_unit: u32,
+  // pixel row alignments forced for texel transfers; picked from the byte size of a row if None
+  pack_alignment: Option<usize>,
+  unpack_alignment: Option<usize>,
   bound_textures: Vec<(u32, Option<WebGlTexture>)>,
 
   // texture buffer used to optimize texture creation; regular textures typically will never ask
@@ -109,6 +128,15 @@ pub struct WebGL2State {
   bound_draw_framebuffer: Option<WebGlFramebuffer>,
   bound_read_framebuffer: Option<WebGlFramebuffer>,
 
+  // draw buffers; the framebuffer the current pipeline renders to and its number of color attachments, and the
+  // framebuffer which draw buffers are overridden by a render state, if any
+  pipeline_framebuffer: Option<WebGlFramebuffer>,
+  pipeline_color_attachments: usize,
+  draw_buffers_override: Option<DrawBuffersOverride>,
+
+  // whether a pipeline statistics query is active; only one can be active at a time
+  pipeline_stats_query_active: bool,
+
   // A special framebuffer used to read textures (workaround the fact WebGL2 doesn’t have
   // support of glGetTexImage). That object will never be created until trying to read a
   // texture’s image.
@@ -134,6 +162,17 @@ pub struct WebGL2State {
 
   /// Maximum number of elements a texture array can hold.
   max_texture_array_elements: Option<usize>,
+
+  /// Maximum number of uniform buffers that can be bound at the same time.
+  max_shader_data_bindings: Option<u32>,
+
+  /// Maximum number of texture units that can be used at the same time.
+  max_texture_units: Option<u32>,
+
+  // error checking
+  error_checking: bool,
+  // errors recorded while error checking is enabled
+  errors: ErrorLog,
 }
 
 impl WebGL2State {
@@ -161,6 +200,7 @@ impl WebGL2State {
     let depth_test_enabled = get_ctx_depth_test_enabled(&mut ctx);
     let depth_test_comparison = Comparison::Less;
     let depth_write = get_ctx_depth_write(&mut ctx)?;
+    let color_mask = get_ctx_color_mask(&mut ctx);
     let stencil_test_enabled = get_ctx_stencil_test_enabled(&mut ctx);
     let stencil_test = get_ctx_stencil_test(&mut ctx)?;
     let stencil_operations = get_ctx_stencil_operations(&mut ctx)?;
@@ -188,6 +228,8 @@ impl WebGL2State {
     let gl_version = None;
     let glsl_version = None;
     let max_texture_array_elements = None;
+    let max_shader_data_bindings = None;
+    let max_texture_units = None;
 
     Ok(WebGL2State {
       _phantom: PhantomData,
@@ -203,6 +245,7 @@ impl WebGL2State {
       depth_test_enabled,
       depth_test_comparison,
       depth_write,
+      color_mask,
       stencil_test_enabled,
       stencil_test,
       stencil_operations,
@@ -212,6 +255,8 @@ impl WebGL2State {
       scissor_state,
       scissor_region,
       current_texture_unit,
+      pack_alignment: None,
+      unpack_alignment: None,
       bound_textures,
       texture_swimming_pool,
       bound_uniform_buffers,
@@ -220,6 +265,10 @@ impl WebGL2State {
       bound_uniform_buffer,
       bound_draw_framebuffer,
       bound_read_framebuffer,
+      pipeline_framebuffer: None,
+      pipeline_color_attachments: 0,
+      draw_buffers_override: None,
+      pipeline_stats_query_active: false,
       readback_framebuffer,
       bound_vertex_array,
       current_program,
@@ -228,9 +277,153 @@ impl WebGL2State {
       webgl_version: gl_version,
       glsl_version,
       max_texture_array_elements,
+      max_shader_data_bindings,
+      max_texture_units,
+      // error checking is enabled by default in debug builds only
+      error_checking: cfg!(debug_assertions),
+      errors: ErrorLog::default(),
     })
   }
 
+  /// Enable or disable error checking.
+  pub(crate) fn set_error_checking(&mut self, enabled: bool) {
+    if enabled && !self.error_checking {
+      // flush any error that occurred before we started checking
+      while self.ctx.get_error() != WebGl2RenderingContext::NO_ERROR {}
+    }
+
+    self.error_checking = enabled;
+  }
+
+  /// Pixel row alignment forced for texels read back from textures, if any.
+  pub(crate) fn pack_alignment(&self) -> Option<usize> {
+    self.pack_alignment
+  }
+
+  /// Pixel row alignment forced for texels uploaded to textures, if any.
+  pub(crate) fn unpack_alignment(&self) -> Option<usize> {
+    self.unpack_alignment
+  }
+
+  /// Force the pixel row alignments of texel transfers.
+  pub(crate) fn set_pixel_alignment(&mut self, pack: Option<usize>, unpack: Option<usize>) {
+    self.pack_alignment = pack;
+    self.unpack_alignment = unpack;
+  }
+
+  /// Whether error checking is enabled.
+  pub(crate) fn is_error_checking(&self) -> bool {
+    self.error_checking
+  }
+
+  /// Take all the errors recorded so far.
+  pub(crate) fn take_errors(&mut self) -> Vec<BackendError> {
+    self.errors.take()
+  }
+
+  /// Check for errors after an operation described by `context`.
+  ///
+  /// This is a no-op if error checking is disabled.
+  pub(crate) fn check_errors(&mut self, context: &str) {
+    if !self.error_checking {
+      return;
+    }
+
+    loop {
+      let code = self.ctx.get_error();
+
+      if code == WebGl2RenderingContext::NO_ERROR {
+        break;
+      }
+
+      self
+        .errors
+        .push(BackendError::new(context, webgl_error_to_error_kind(code)));
+
+      // a lost context keeps reporting the same error; don’t loop forever
+      if code == WebGl2RenderingContext::CONTEXT_LOST_WEBGL {
+        break;
+      }
+    }
+  }
+
+  /// Record an error that wasn’t reported by WebGL itself.
+  ///
+  /// This is a no-op if error checking is disabled.
+  pub(crate) fn record_error(&mut self, context: &str, kind: BackendErrorKind) {
+    if self.error_checking {
+      self.errors.push(BackendError::new(context, kind));
+    }
+  }
+
+  /// Re-synchronize the cached state with the WebGL context.
+  ///
+  /// This is needed when WebGL was used behind luminance’s back. WebGL can be queried cheaply enough, so instead of
+  /// forgetting the cached values, they are fetched again from the context. Texture and uniform buffer bindings are
+  /// forgotten, so that they are bound again the next time they’re used.
+  pub(crate) fn invalidate(&mut self) {
+    if let Err(e) = self.requery() {
+      self.record_error(
+        "invalidate state",
+        BackendErrorKind::Unsupported(e.to_string()),
+      );
+    }
+  }
+
+  fn requery(&mut self) -> Result<(), StateQueryError> {
+    let ctx = &mut self.ctx;
+
+    self.viewport = get_ctx_viewport(ctx)?;
+    self.clear_color = get_ctx_clear_color(ctx)?;
+    self.clear_depth = get_ctx_clear_depth(ctx)?;
+    self.clear_stencil = get_ctx_clear_stencil(ctx)?;
+    self.blending_state = get_ctx_blending_state(ctx);
+    self.blending_equations = get_ctx_blending_equations(ctx)?;
+    self.blending_funcs = get_ctx_blending_factors(ctx)?;
+    self.depth_test_enabled = get_ctx_depth_test_enabled(ctx);
+    self.depth_test_comparison = get_ctx_depth_test_comparison(ctx)?;
+    self.depth_write = get_ctx_depth_write(ctx)?;
+    self.color_mask = get_ctx_color_mask(ctx);
+    self.stencil_test_enabled = get_ctx_stencil_test_enabled(ctx);
+    self.stencil_test = get_ctx_stencil_test(ctx)?;
+    self.stencil_operations = get_ctx_stencil_operations(ctx)?;
+    self.face_culling_state = get_ctx_face_culling_state(ctx);
+    self.face_culling_order = get_ctx_face_culling_order(ctx)?;
+    self.face_culling_mode = get_ctx_face_culling_mode(ctx)?;
+    self.scissor_state = get_ctx_scissor_state(ctx)?;
+    self.scissor_region = get_ctx_scissor_region(ctx)?;
+
+    self.current_texture_unit = ctx
+      .get_webgl_param(WebGl2RenderingContext::ACTIVE_TEXTURE)
+      .map(|unit: u32| unit - WebGl2RenderingContext::TEXTURE0)
+      .ok_or(StateQueryError::UnknownTextureUnitState)?;
+
+    for binding in &mut self.bound_textures {
+      *binding = (WebGl2RenderingContext::TEXTURE0, None);
+    }
+
+    for binding in &mut self.bound_uniform_buffers {
+      *binding = None;
+    }
+
+    self.bound_array_buffer = get_ctx_object(ctx, WebGl2RenderingContext::ARRAY_BUFFER_BINDING);
+    self.bound_element_array_buffer =
+      get_ctx_object(ctx, WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER_BINDING);
+    self.bound_uniform_buffer = get_ctx_object(ctx, WebGl2RenderingContext::UNIFORM_BUFFER_BINDING);
+    self.bound_draw_framebuffer =
+      get_ctx_object(ctx, WebGl2RenderingContext::DRAW_FRAMEBUFFER_BINDING);
+    self.bound_read_framebuffer =
+      get_ctx_object(ctx, WebGl2RenderingContext::READ_FRAMEBUFFER_BINDING);
+
+    if let Some(ref mut draw_buffers_override) = self.draw_buffers_override {
+      draw_buffers_override.draw_buffers = None;
+    }
+    self.bound_vertex_array = get_ctx_object(ctx, WebGl2RenderingContext::VERTEX_ARRAY_BINDING);
+    self.current_program = get_ctx_object(ctx, WebGl2RenderingContext::CURRENT_PROGRAM);
+
+    Ok(())
+  }
+
   pub(crate) fn binding_stack_mut(&mut self) -> &mut BindingStack {
     &mut self.binding_stack
   }
@@ -402,6 +595,99 @@ impl WebGL2State {
     }
   }
 
+  /// Bind the framebuffer a pipeline renders to, which has `color_attachments` color attachments.
+  ///
+  /// The draw buffers of the previous framebuffer are restored first, if they were overridden.
+  pub(crate) fn bind_pipeline_framebuffer(
+    &mut self,
+    handle: Option<&WebGlFramebuffer>,
+    color_attachments: usize,
+  ) {
+    self.reset_draw_buffers();
+    self.bind_draw_framebuffer(handle);
+    self.pipeline_framebuffer = handle.cloned();
+    self.pipeline_color_attachments = color_attachments;
+  }
+
+  /// Write the fragment output `i` to the color attachment `draw_buffers[i]` of the pipeline framebuffer, or discard it
+  /// if `None`.
+  pub(crate) fn set_draw_buffers(&mut self, draw_buffers: &[Option<usize>]) {
+    let color_attachments = self.pipeline_color_attachments;
+
+    if draw_buffers
+      .iter()
+      .flatten()
+      .any(|&index| index >= color_attachments)
+    {
+      self.record_error("render state draw buffers", BackendErrorKind::InvalidValue);
+      return;
+    }
+
+    let draw_buffers = draw_buffers
+      .iter()
+      .map(|draw_buffer| {
+        draw_buffer.map_or(WebGl2RenderingContext::NONE, |index| {
+          WebGl2RenderingContext::COLOR_ATTACHMENT0 + index as u32
+        })
+      })
+      .collect::<Vec<_>>();
+    let framebuffer = self.pipeline_framebuffer.clone();
+    let draw_buffers_override =
+      self
+        .draw_buffers_override
+        .get_or_insert_with(|| DrawBuffersOverride {
+          framebuffer,
+          color_attachments,
+          draw_buffers: None,
+        });
+
+    if draw_buffers_override.draw_buffers.as_ref() != Some(&draw_buffers) {
+      self
+        .ctx
+        .draw_buffers(Uint32Array::from(draw_buffers.as_slice()).as_ref());
+      draw_buffers_override.draw_buffers = Some(draw_buffers);
+    }
+  }
+
+  /// Restore the draw buffers of the framebuffer they were overridden for, if any: the fragment output `i` is written
+  /// to the color attachment `i` again.
+  pub(crate) fn reset_draw_buffers(&mut self) {
+    if let Some(draw_buffers_override) = self.draw_buffers_override.take() {
+      self.bind_draw_framebuffer(draw_buffers_override.framebuffer.as_ref());
+
+      let draw_buffers = if draw_buffers_override.framebuffer.is_none() {
+        vec![WebGl2RenderingContext::BACK]
+      } else if draw_buffers_override.color_attachments == 0 {
+        vec![WebGl2RenderingContext::NONE]
+      } else {
+        (0..draw_buffers_override.color_attachments as u32)
+          .map(|index| WebGl2RenderingContext::COLOR_ATTACHMENT0 + index)
+          .collect()
+      };
+
+      self
+        .ctx
+        .draw_buffers(Uint32Array::from(draw_buffers.as_slice()).as_ref());
+    }
+  }
+
+  /// Forget the draw buffers override of a framebuffer that is about to be deleted.
+  pub(crate) fn forget_draw_buffers(&mut self, framebuffer: Option<&WebGlFramebuffer>) {
+    if matches!(self.draw_buffers_override, Some(ref o) if o.framebuffer.as_ref() == framebuffer) {
+      self.draw_buffers_override = None;
+    }
+  }
+
+  /// Whether a pipeline statistics query is active.
+  pub(crate) fn is_pipeline_stats_query_active(&self) -> bool {
+    self.pipeline_stats_query_active
+  }
+
+  /// Mark whether a pipeline statistics query is active.
+  pub(crate) fn set_pipeline_stats_query_active(&mut self, active: bool) {
+    self.pipeline_stats_query_active = active;
+  }
+
   pub(crate) fn bind_read_framebuffer(&mut self, handle: Option<&WebGlFramebuffer>) {
     if self.bound_read_framebuffer.as_ref() != handle {
       self
@@ -411,6 +697,10 @@ impl WebGL2State {
     }
   }
 
+  pub(crate) fn current_program(&self) -> Option<WebGlProgram> {
+    self.current_program.clone()
+  }
+
   pub(crate) fn use_program(&mut self, handle: Option<&WebGlProgram>) {
     if self.current_program.as_ref() != handle {
       self.ctx.use_program(handle);
@@ -575,6 +865,139 @@ impl WebGL2State {
     }
   }
 
+  /// Select the color channels written to the color attachments.
+  pub(crate) fn set_color_mask(&mut self, color_mask: [bool; 4]) {
+    if self.color_mask != color_mask {
+      let [r, g, b, a] = color_mask;
+      self.ctx.color_mask(r, g, b, a);
+
+      self.color_mask = color_mask;
+    }
+  }
+
+  /// Read the render state saved by a [`StateGuard`](luminance::context::StateGuard) from the context.
+  ///
+  /// Parts of the state that cannot be read back fall back to the WebGL defaults.
+  pub(crate) fn snapshot(&mut self) -> StateSnapshot {
+    let ctx = &mut self.ctx;
+
+    let blending_mode = match (
+      get_ctx_blending_equations(ctx),
+      get_ctx_blending_factors(ctx),
+    ) {
+      (Ok(equations), Ok(factors)) => BlendingMode::Separate {
+        rgb: Blending {
+          equation: equations.rgb,
+          src: factors.src_rgb,
+          dst: factors.dst_rgb,
+        },
+        alpha: Blending {
+          equation: equations.alpha,
+          src: factors.src_alpha,
+          dst: factors.dst_alpha,
+        },
+      },
+
+      _ => BlendingMode::Combined(Blending {
+        equation: Equation::Additive,
+        src: Factor::One,
+        dst: Factor::Zero,
+      }),
+    };
+
+    StateSnapshot {
+      viewport: get_ctx_viewport(ctx).unwrap_or([0; 4]),
+      scissor_test: matches!(get_ctx_scissor_state(ctx), Ok(ScissorState::On)),
+      scissor_region: get_ctx_scissor_region(ctx).unwrap_or(ScissorRegion {
+        x: 0,
+        y: 0,
+        width: 0,
+        height: 0,
+      }),
+      blending: get_ctx_blending_state(ctx) == BlendingState::On,
+      blending_mode,
+      depth_test: get_ctx_depth_test_enabled(ctx),
+      depth_test_comparison: get_ctx_depth_test_comparison(ctx).unwrap_or(Comparison::Less),
+      depth_write: get_ctx_depth_write(ctx).unwrap_or(Write::On),
+    }
+  }
+
+  /// Restore a render state previously read with [`WebGL2State::snapshot`].
+  ///
+  /// Every part of the state is issued again, whatever the cached values are, and the cache is updated accordingly.
+  pub(crate) fn restore(&mut self, snapshot: &StateSnapshot) {
+    let [x, y, width, height] = snapshot.viewport;
+    self.ctx.viewport(x, y, width, height);
+    self.viewport = snapshot.viewport;
+
+    let region = snapshot.scissor_region;
+    self.ctx.scissor(
+      region.x as i32,
+      region.y as i32,
+      region.width as i32,
+      region.height as i32,
+    );
+    self.scissor_region = region;
+
+    if snapshot.scissor_test {
+      self.ctx.enable(WebGl2RenderingContext::SCISSOR_TEST);
+      self.scissor_state = ScissorState::On;
+    } else {
+      self.ctx.disable(WebGl2RenderingContext::SCISSOR_TEST);
+      self.scissor_state = ScissorState::Off;
+    }
+
+    let (rgb, alpha) = match snapshot.blending_mode {
+      BlendingMode::Combined(b) => (b, b),
+      BlendingMode::Separate { rgb, alpha } => (rgb, alpha),
+    };
+
+    self.ctx.blend_equation_separate(
+      blending_equation_to_webgl(rgb.equation),
+      blending_equation_to_webgl(alpha.equation),
+    );
+    self.blending_equations = BlendingEquations {
+      rgb: rgb.equation,
+      alpha: alpha.equation,
+    };
+
+    self.ctx.blend_func_separate(
+      blending_factor_to_webgl(rgb.src),
+      blending_factor_to_webgl(rgb.dst),
+      blending_factor_to_webgl(alpha.src),
+      blending_factor_to_webgl(alpha.dst),
+    );
+    self.blending_funcs = BlendingFactors {
+      src_rgb: rgb.src,
+      dst_rgb: rgb.dst,
+      src_alpha: alpha.src,
+      dst_alpha: alpha.dst,
+    };
+
+    if snapshot.blending {
+      self.ctx.enable(WebGl2RenderingContext::BLEND);
+      self.blending_state = BlendingState::On;
+    } else {
+      self.ctx.disable(WebGl2RenderingContext::BLEND);
+      self.blending_state = BlendingState::Off;
+    }
+
+    if snapshot.depth_test {
+      self.ctx.enable(WebGl2RenderingContext::DEPTH_TEST);
+    } else {
+      self.ctx.disable(WebGl2RenderingContext::DEPTH_TEST);
+    }
+    self.depth_test_enabled = snapshot.depth_test;
+
+    self
+      .ctx
+      .depth_func(comparison_to_glenum(snapshot.depth_test_comparison));
+    self.depth_test_comparison = snapshot.depth_test_comparison;
+
+    self.ctx.depth_mask(snapshot.depth_write == Write::On);
+    self.depth_write = snapshot.depth_write;
+  }
+
   pub(crate) fn enable_stencil_test(&mut self, enabled: bool) {
     if self.stencil_test_enabled != enabled {
       if enabled {
@@ -635,7 +1058,7 @@ impl WebGL2State {
       match mode {
         FaceCullingMode::Front => self.ctx.cull_face(WebGl2RenderingContext::FRONT),
         FaceCullingMode::Back => self.ctx.cull_face(WebGl2RenderingContext::BACK),
-        FaceCullingMode::Both => self.ctx.cull_face(WebGl2RenderingContext::FRONT_AND_BACK),
+        FaceCullingMode::FrontAndBack => self.ctx.cull_face(WebGl2RenderingContext::FRONT_AND_BACK),
       }
 
       self.face_culling_mode = mode;
@@ -715,6 +1138,35 @@ impl WebGL2State {
       max
     })
   }
+
+  /// Get the maximum number of uniform buffers that can be bound at the same time.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls. If the context cannot be queried, the
+  /// minimum value guaranteed by WebGL2 is returned.
+  pub fn get_max_shader_data_bindings(&mut self) -> u32 {
+    self.max_shader_data_bindings.unwrap_or_else(|| {
+      let max = self
+        .ctx
+        .get_webgl_param(WebGl2RenderingContext::MAX_UNIFORM_BUFFER_BINDINGS)
+        .unwrap_or(24);
+      self.max_shader_data_bindings = Some(max);
+      max
+    })
+  }
+
+  /// Get the maximum number of texture units that can be used at the same time.
+  ///
+  /// Cache the number on the first call and then re-use it for later calls.
+  pub fn get_max_texture_units(&mut self) -> u32 {
+    self.max_texture_units.unwrap_or_else(|| {
+      let max = self
+        .ctx
+        .get_webgl_param(WebGl2RenderingContext::MAX_COMBINED_TEXTURE_IMAGE_UNITS)
+        .unwrap_or(32);
+      self.max_texture_units = Some(max);
+      max
+    })
+  }
 }
 
 impl Drop for WebGL2State {
@@ -757,6 +1209,10 @@ pub enum StateQueryError {
   UnknownStencilOpState,
   /// Unknown depth write mask initial state.
   UnknownDepthWriteMaskState,
+  /// Unknown depth comparison function state.
+  UnknownDepthComparisonState,
+  /// Unknown active texture unit state.
+  UnknownTextureUnitState,
   /// Corrupted blending equation.
   UnknownBlendingEquation(u32),
   /// RGB blending equation couldn’t be retrieved when initializing the WebGL2 state.
@@ -828,6 +1284,10 @@ impl fmt::Display for StateQueryError {
 
       StateQueryError::UnknownDepthWriteMaskState => f.write_str("unknown depth write mask state"),
 
+      StateQueryError::UnknownDepthComparisonState => f.write_str("unknown depth comparison state"),
+
+      StateQueryError::UnknownTextureUnitState => f.write_str("unknown texture unit state"),
+
       StateQueryError::UnknownBlendingEquation(ref e) => {
         write!(f, "unknown blending equation: {}", e)
       }
@@ -1029,6 +1489,15 @@ fn get_ctx_depth_test_enabled(ctx: &mut WebGl2RenderingContext) -> bool {
   ctx.is_enabled(WebGl2RenderingContext::DEPTH_TEST)
 }
 
+fn get_ctx_depth_test_comparison(
+  ctx: &mut WebGl2RenderingContext,
+) -> Result<Comparison, StateQueryError> {
+  ctx
+    .get_webgl_param(WebGl2RenderingContext::DEPTH_FUNC)
+    .and_then(glenum_to_comparison)
+    .ok_or(StateQueryError::UnknownDepthComparisonState)
+}
+
 fn get_ctx_stencil_test_enabled(ctx: &mut WebGl2RenderingContext) -> bool {
   ctx.is_enabled(WebGl2RenderingContext::STENCIL_TEST)
 }
@@ -1137,6 +1606,13 @@ fn get_ctx_depth_write(ctx: &mut WebGl2RenderingContext) -> Result<Write, StateQ
   }
 }
 
+fn get_ctx_color_mask(ctx: &mut WebGl2RenderingContext) -> [bool; 4] {
+  // all the channels are written by default
+  ctx
+    .get_webgl_param(WebGl2RenderingContext::COLOR_WRITEMASK)
+    .unwrap_or([true; 4])
+}
+
 fn get_ctx_face_culling_state(ctx: &mut WebGl2RenderingContext) -> FaceCullingState {
   let enabled = ctx.is_enabled(WebGl2RenderingContext::CULL_FACE);
 
@@ -1171,7 +1647,7 @@ fn get_ctx_face_culling_mode(
   match mode {
     WebGl2RenderingContext::FRONT => Ok(FaceCullingMode::Front),
     WebGl2RenderingContext::BACK => Ok(FaceCullingMode::Back),
-    WebGl2RenderingContext::FRONT_AND_BACK => Ok(FaceCullingMode::Both),
+    WebGl2RenderingContext::FRONT_AND_BACK => Ok(FaceCullingMode::FrontAndBack),
     _ => Err(StateQueryError::UnknownFaceCullingMode),
   }
 }
@@ -1301,6 +1777,17 @@ fn blending_factor_to_webgl(factor: Factor) -> u32 {
   }
 }
 
+// Get the object bound to a binding point; unbound binding points yield null, hence None.
+fn get_ctx_object<T>(ctx: &mut WebGl2RenderingContext, param: u32) -> Option<T>
+where
+  T: JsCast,
+{
+  ctx
+    .get_parameter(param)
+    .ok()
+    .and_then(|object| object.dyn_into().ok())
+}
+
 // Workaround around the lack of implementor for [`TryFrom`] on [`JsValue`].
 trait GetWebGLParam<T> {
   fn get_webgl_param(&mut self, param: u32) -> Option<T>;
@@ -1347,6 +1834,23 @@ impl GetWebGLParam<bool> for WebGl2RenderingContext {
   }
 }
 
+impl GetWebGLParam<[bool; 4]> for WebGl2RenderingContext {
+  fn get_webgl_param(&mut self, param: u32) -> Option<[bool; 4]> {
+    let array: Array = self.get_parameter(param).ok()?.dyn_into().ok()?;
+
+    if array.length() != 4 {
+      return None;
+    }
+
+    let mut mask = [true; 4];
+    for (i, write) in mask.iter_mut().enumerate() {
+      *write = array.get(i as u32).as_bool()?;
+    }
+
+    Some(mask)
+  }
+}
+
 impl GetWebGLParam<String> for WebGl2RenderingContext {
   fn get_webgl_param(&mut self, param: u32) -> Option<String> {
     self.get_parameter(param).ok().and_then(|x| x.as_string())
@@ -1,12 +1,14 @@
 //! WebGL2 tessellation implementation.
 
 use luminance::backend::tess::{
-  IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
-  VertexSlice as VertexSliceBackend,
+  override_normalization, DynamicTess as DynamicTessBackend, IndexSlice as IndexSliceBackend,
+  InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
+  UpdateVertexAttribute as UpdateVertexAttributeBackend, VertexAttribPointer,
+  VertexBytes as VertexBytesBackend, VertexSlice as VertexSliceBackend,
 };
 use luminance::tess::{
-  Deinterleaved, DeinterleavedData, Interleaved, Mode, TessError, TessIndex, TessIndexType,
-  TessMapError, TessVertexData,
+  Deinterleaved, DeinterleavedData, Interleaved, Mode, RawInterleaved, RawVertexData, TessError,
+  TessIndex, TessIndexType, TessMapError, TessUsage, TessVertexData,
 };
 use luminance::vertex::{
   Deinterleave, Normalized, Vertex, VertexAttribDesc, VertexAttribDim, VertexAttribType,
@@ -15,14 +17,15 @@ use luminance::vertex::{
 use std::cell::RefCell;
 use std::marker::PhantomData;
 use std::rc::Rc;
-use web_sys::WebGlVertexArrayObject;
+use wasm_bindgen::JsCast as _;
+use web_sys::{WebGlBuffer, WebGlVertexArrayObject};
 
 use crate::webgl2::buffer::{Buffer, BufferSlice, BufferSliceMut};
 use crate::webgl2::state::{Bind, WebGL2State};
 use crate::webgl2::{WebGL2, WebGl2RenderingContext};
 
 #[derive(Debug)]
-struct TessRaw<I>
+pub(crate) struct TessRaw<I>
 where
   I: TessIndex,
 {
@@ -31,6 +34,8 @@ where
   // A small note: WebGL2 doesn’t support custom primitive restart index; it assumes the maximum
   // value of I as being that restart index.
   index_buffer: Option<Buffer<I, { WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER }>>,
+  usage: TessUsage,
+  update_count: u64,
   state: Rc<RefCell<WebGL2State>>,
 }
 
@@ -38,33 +43,120 @@ impl<I> TessRaw<I>
 where
   I: TessIndex,
 {
+  /// Primitive mode captured when rendering this tessellation with transform feedback.
+  ///
+  /// Strips and fans are captured as their independent primitives.
+  pub(crate) fn feedback_primitive(&self) -> u32 {
+    match self.mode {
+      WebGl2RenderingContext::POINTS => WebGl2RenderingContext::POINTS,
+      WebGl2RenderingContext::LINES | WebGl2RenderingContext::LINE_STRIP => {
+        WebGl2RenderingContext::LINES
+      }
+      _ => WebGl2RenderingContext::TRIANGLES,
+    }
+  }
+
   unsafe fn render(
     &self,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
   ) -> Result<(), TessError> {
-    let vert_nb = vert_nb as _;
-    let inst_nb = inst_nb as _;
+    let mut gfx_st = self.state.borrow_mut();
+
+    // WebGL2 has no base instance draw calls
+    if base_instance != 0 {
+      return Err(TessError::unsupported_base_instance(base_instance));
+    }
+
+    gfx_st.bind_vertex_array(Some(&self.vao), Bind::Cached);
+
+    override_normalization::<_, AttribPointer, _>(&mut gfx_st, normalized_attribs, |gfx_st| {
+      self.draw(gfx_st, self.mode, start_index, vert_nb, inst_nb)
+    });
+
+    gfx_st.check_errors("tess render");
+
+    Ok(())
+  }
 
+  /// Render the wireframe with a debug program, which must be in use already.
+  ///
+  /// WebGL2 has no polygon mode, so the wireframe is approximated by rendering the vertices of the view as a line
+  /// strip.
+  pub(crate) unsafe fn render_debug_wireframe(&self, start_index: usize, vert_nb: usize) {
     let mut gfx_st = self.state.borrow_mut();
     gfx_st.bind_vertex_array(Some(&self.vao), Bind::Cached);
 
+    self.draw(
+      &gfx_st,
+      WebGl2RenderingContext::LINE_STRIP,
+      start_index,
+      vert_nb,
+      1,
+    );
+
+    gfx_st.check_errors("tess debug render");
+  }
+
+  /// Render vectors with a debug program, which must be in use already.
+  ///
+  /// WebGL2 has no geometry shader, so every vertex is expanded to a line by rendering a two-vertex line instance per
+  /// vertex, reading the `attribs` vertex attributes per instance. All the `vertices_nb` vertices are rendered, whatever
+  /// the view.
+  pub(crate) unsafe fn render_debug_vectors(&self, attribs: [usize; 2], vertices_nb: usize) {
+    let mut gfx_st = self.state.borrow_mut();
+    gfx_st.bind_vertex_array(Some(&self.vao), Bind::Cached);
+
+    let divisors = attribs.map(|index| {
+      let index = index as u32;
+      let divisor = gfx_st
+        .ctx
+        .get_vertex_attrib(index, WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_DIVISOR)
+        .ok()
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.) as u32;
+      gfx_st.ctx.vertex_attrib_divisor(index, 1);
+      (index, divisor)
+    });
+
+    gfx_st
+      .ctx
+      .draw_arrays_instanced(WebGl2RenderingContext::LINES, 0, 2, vertices_nb as _);
+
+    for (index, divisor) in divisors {
+      gfx_st.ctx.vertex_attrib_divisor(index, divisor);
+    }
+
+    gfx_st.check_errors("tess debug render");
+  }
+
+  /// Issue the draw call for the currently bound vertex array, with the given primitive mode.
+  unsafe fn draw(
+    &self,
+    gfx_st: &WebGL2State,
+    mode: u32,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+  ) {
+    let vert_nb = vert_nb as _;
+    let inst_nb = inst_nb as _;
+
     match (I::INDEX_TYPE, self.index_buffer.as_ref()) {
       (Some(index_ty), Some(_)) => {
         // indexed render
         let first = (index_ty.bytes() * start_index) as _;
 
         if inst_nb <= 1 {
-          gfx_st.ctx.draw_elements_with_i32(
-            self.mode,
-            vert_nb,
-            index_type_to_glenum(index_ty),
-            first,
-          );
+          gfx_st
+            .ctx
+            .draw_elements_with_i32(mode, vert_nb, index_type_to_glenum(index_ty), first);
         } else {
           gfx_st.ctx.draw_elements_instanced_with_i32(
-            self.mode,
+            mode,
             vert_nb,
             index_type_to_glenum(index_ty),
             first,
@@ -78,16 +170,85 @@ where
         let first = start_index as _;
 
         if inst_nb <= 1 {
-          gfx_st.ctx.draw_arrays(self.mode, first, vert_nb);
+          gfx_st.ctx.draw_arrays(mode, first, vert_nb);
         } else {
           gfx_st
             .ctx
-            .draw_arrays_instanced(self.mode, first, vert_nb, inst_nb);
+            .draw_arrays_instanced(mode, first, vert_nb, inst_nb);
         }
       }
     }
+  }
+}
+
+/// Vertex attribute pointer of the currently bound vertex array.
+struct AttribPointer {
+  index: u32,
+  buffer: Option<WebGlBuffer>,
+  size: i32,
+  ty: u32,
+  normalized: bool,
+  integer: bool,
+  stride: i32,
+  offset: i32,
+}
+
+impl VertexAttribPointer<WebGL2State> for AttribPointer {
+  unsafe fn query(state: &mut WebGL2State, index: usize) -> Option<Self> {
+    let ctx = &state.ctx;
+    let index = index as u32;
+    let get = |pname| ctx.get_vertex_attrib(index, pname).ok();
+    let get_bool = |pname| get(pname).and_then(|v| v.as_bool()).unwrap_or(false);
+    let get_int = |pname| get(pname).and_then(|v| v.as_f64()).unwrap_or(0.) as i32;
+
+    if !get_bool(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_ENABLED) {
+      return None;
+    }
 
-    Ok(())
+    Some(AttribPointer {
+      index,
+      buffer: get(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_BUFFER_BINDING)
+        .and_then(|v| v.dyn_into().ok()),
+      size: get_int(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_SIZE),
+      ty: get_int(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_TYPE) as u32,
+      normalized: get_bool(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_NORMALIZED),
+      integer: get_bool(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_INTEGER),
+      stride: get_int(WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_STRIDE),
+      offset: ctx
+        .get_vertex_attrib_offset(index, WebGl2RenderingContext::VERTEX_ATTRIB_ARRAY_POINTER)
+        as i32,
+    })
+  }
+
+  fn is_integer(&self) -> bool {
+    self.integer
+  }
+
+  fn is_normalized(&self) -> bool {
+    self.normalized
+  }
+
+  unsafe fn set(&self, state: &mut WebGL2State, integer: bool, normalized: bool) {
+    state.bind_array_buffer(self.buffer.as_ref(), Bind::Cached);
+
+    if integer {
+      state.ctx.vertex_attrib_i_pointer_with_i32(
+        self.index,
+        self.size,
+        self.ty,
+        self.stride,
+        self.offset,
+      );
+    } else {
+      state.ctx.vertex_attrib_pointer_with_i32(
+        self.index,
+        self.size,
+        self.ty,
+        normalized,
+        self.stride,
+        self.offset,
+      );
+    }
   }
 }
 
@@ -109,7 +270,7 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
+  pub(crate) raw: TessRaw<I>,
   vertex_buffer: Option<Buffer<V, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
   instance_buffer: Option<Buffer<W, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
 }
@@ -129,6 +290,7 @@ where
     instance_data: Option<W::Data>,
     mode: Mode,
     _: Option<I>,
+    usage: TessUsage,
   ) -> Result<Self::TessRepr, TessError> {
     let vao = self
       .state
@@ -143,9 +305,9 @@ where
       .borrow_mut()
       .bind_vertex_array(Some(&vao), Bind::Forced);
 
-    let vertex_buffer = build_interleaved_vertex_buffer(self, vertex_data)?;
-    let index_buffer = build_index_buffer(self, index_data)?;
-    let instance_buffer = build_interleaved_vertex_buffer(self, instance_data)?;
+    let vertex_buffer = build_interleaved_vertex_buffer(self, vertex_data, usage)?;
+    let index_buffer = build_index_buffer(self, index_data, usage)?;
+    let instance_buffer = build_interleaved_vertex_buffer(self, instance_data, usage)?;
 
     let mode = webgl_mode(mode).ok_or_else(|| TessError::ForbiddenPrimitiveMode(mode))?;
     let state = self.state.clone();
@@ -153,6 +315,8 @@ where
       vao,
       mode,
       index_buffer,
+      usage,
+      update_count: 0,
       state,
     };
 
@@ -188,13 +352,119 @@ where
       .unwrap_or(0)
   }
 
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I> DynamicTessBackend<V, I> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+{
+  unsafe fn build_dynamic(&mut self, mode: Mode) -> Result<Self::TessRepr, TessError> {
+    let vao = self
+      .state
+      .borrow_mut()
+      .create_vertex_array()
+      .ok_or_else(|| TessError::cannot_create("the backend failed to create the VAO"))?;
+
+    self
+      .state
+      .borrow_mut()
+      .bind_vertex_array(Some(&vao), Bind::Forced);
+
+    // the storage starts empty but the vertex pointers must be set right away, as they are part of the vao
+    let vertex_buffer =
+      Buffer::from_vec_with_usage(self, Vec::new(), WebGl2RenderingContext::STREAM_DRAW)?;
+    {
+      let mut state = self.state.borrow_mut();
+      state.bind_array_buffer(Some(vertex_buffer.handle()), Bind::Forced);
+      set_vertex_pointers(&mut state.ctx, &V::vertex_desc());
+    }
+
+    let index_buffer = if I::INDEX_TYPE.is_some() {
+      let buffer =
+        Buffer::from_vec_with_usage(self, Vec::new(), WebGl2RenderingContext::STREAM_DRAW)?;
+      self
+        .state
+        .borrow_mut()
+        .bind_element_array_buffer(Some(buffer.handle()), Bind::Forced);
+
+      Some(buffer)
+    } else {
+      None
+    };
+
+    let mode = webgl_mode(mode).ok_or_else(|| TessError::ForbiddenPrimitiveMode(mode))?;
+    let state = self.state.clone();
+    let raw = TessRaw {
+      vao,
+      mode,
+      index_buffer,
+      usage: TessUsage::Stream,
+      update_count: 0,
+      state,
+    };
+
+    Ok(InterleavedTess {
+      raw,
+      vertex_buffer: Some(vertex_buffer),
+      instance_buffer: None,
+    })
+  }
+
+  unsafe fn set_dynamic_data(
+    tess: &mut Self::TessRepr,
+    vertices: &[V],
+    indices: &[I],
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    let mut state = tess.raw.state.borrow_mut();
+
+    // the element array buffer binding is part of the vao state, so bind it first
+    state.bind_vertex_array(Some(&tess.raw.vao), Bind::Cached);
+
+    if let Some(ref mut vb) = tess.vertex_buffer {
+      vb.orphan_and_upload(&mut state, vertices);
+    }
+
+    match tess.raw.index_buffer {
+      Some(ref mut ib) => ib.orphan_and_upload(&mut state, indices),
+
+      None if !indices.is_empty() => {
+        return Err(TessError::cannot_create(
+          "cannot stream indices into a non-indexed tessellation",
+        ));
+      }
+
+      None => (),
+    }
+
+    state.check_errors("dynamic tess upload");
+    tess.raw.update_count += 1;
+
+    Ok(())
   }
 }
 
@@ -218,7 +488,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::VertexSliceMutRepr, TessMapError> {
     match tess.vertex_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()),
+      Some(ref mut vb) => {
+        tess.raw.update_count += 1;
+        Ok(vb.slice_buffer_mut())
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -244,7 +517,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
     match tess.raw.index_buffer {
-      Some(ref mut buffer) => Ok(buffer.slice_buffer_mut()),
+      Some(ref mut buffer) => {
+        tess.raw.update_count += 1;
+        Ok(buffer.slice_buffer_mut())
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -272,12 +548,33 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError> {
     match tess.instance_buffer {
-      Some(ref mut vb) => Ok(vb.slice_buffer_mut()),
+      Some(ref mut vb) => {
+        tess.raw.update_count += 1;
+        Ok(vb.slice_buffer_mut())
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
 }
 
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, Interleaved> for WebGL2
+where
+  V: TessVertexData<Interleaved, Data = Vec<V>>,
+  I: TessIndex,
+  W: TessVertexData<Interleaved, Data = Vec<W>>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    if let Some(ref vb) = tess.vertex_buffer {
+      f(as_bytes(&vb.slice_buffer()));
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Debug)]
 pub struct DeinterleavedTess<V, I, W>
 where
@@ -285,7 +582,7 @@ where
   I: TessIndex,
   W: Vertex,
 {
-  raw: TessRaw<I>,
+  pub(crate) raw: TessRaw<I>,
   vertex_buffers: Vec<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
   instance_buffers: Vec<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
   _phantom: PhantomData<*const (V, W)>,
@@ -306,6 +603,7 @@ where
     instance_data: Option<W::Data>,
     mode: Mode,
     _: Option<I>,
+    usage: TessUsage,
   ) -> Result<Self::TessRepr, TessError> {
     let vao = self
       .state
@@ -320,9 +618,9 @@ where
       .borrow_mut()
       .bind_vertex_array(Some(&vao), Bind::Forced);
 
-    let vertex_buffers = build_deinterleaved_vertex_buffers::<V>(self, vertex_data)?;
-    let index_buffer = build_index_buffer(self, index_data)?;
-    let instance_buffers = build_deinterleaved_vertex_buffers::<W>(self, instance_data)?;
+    let vertex_buffers = build_deinterleaved_vertex_buffers::<V>(self, vertex_data, usage)?;
+    let index_buffer = build_index_buffer(self, index_data, usage)?;
+    let instance_buffers = build_deinterleaved_vertex_buffers::<W>(self, instance_data, usage)?;
 
     let mode = webgl_mode(mode).ok_or_else(|| TessError::ForbiddenPrimitiveMode(mode))?;
     let state = self.state.clone();
@@ -330,6 +628,8 @@ where
       vao,
       mode,
       index_buffer,
+      usage,
+      update_count: 0,
       state,
     };
 
@@ -366,13 +666,29 @@ where
       .unwrap_or(0)
   }
 
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
   ) -> Result<(), TessError> {
-    tess.raw.render(start_index, vert_nb, inst_nb)
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
   }
 }
 
@@ -402,6 +718,7 @@ where
     if tess.vertex_buffers.is_empty() {
       Err(TessMapError::forbidden_attributeless_mapping())
     } else {
+      tess.raw.update_count += 1;
       let buffer = &mut tess.vertex_buffers[V::RANK];
       let slice = buffer.slice_buffer_mut().transmute();
       Ok(slice)
@@ -429,7 +746,10 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::IndexSliceMutRepr, TessMapError> {
     match tess.raw.index_buffer {
-      Some(ref mut buffer) => Ok(buffer.slice_buffer_mut()),
+      Some(ref mut buffer) => {
+        tess.raw.update_count += 1;
+        Ok(buffer.slice_buffer_mut())
+      }
       None => Err(TessMapError::forbidden_attributeless_mapping()),
     }
   }
@@ -463,6 +783,7 @@ where
     if tess.instance_buffers.is_empty() {
       Err(TessMapError::forbidden_attributeless_mapping())
     } else {
+      tess.raw.update_count += 1;
       let buffer = &mut tess.instance_buffers[W::RANK];
       let slice = buffer.slice_buffer_mut().transmute();
       Ok(slice)
@@ -470,9 +791,193 @@ where
   }
 }
 
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, Deinterleaved> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    // buffers are stored in rank order
+    for buffer in &tess.vertex_buffers {
+      f(&buffer.slice_buffer());
+    }
+
+    Ok(())
+  }
+}
+
+unsafe impl<V, I, W, T> UpdateVertexAttributeBackend<V, I, W, T> for WebGL2
+where
+  V: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>> + Deinterleave<T>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  unsafe fn update_vertex_attribute(
+    tess: &mut Self::TessRepr,
+    values: &[T],
+  ) -> Result<(), TessMapError> {
+    let buffer = tess
+      .vertex_buffers
+      .get_mut(V::RANK)
+      .ok_or_else(TessMapError::forbidden_attributeless_mapping)?;
+
+    let expected = buffer.buf.len() / std::mem::size_of::<T>();
+    if values.len() != expected {
+      return Err(TessMapError::attribute_length_mismatch(
+        expected,
+        values.len(),
+      ));
+    }
+
+    buffer.update(as_bytes(values));
+    tess.raw.update_count += 1;
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct RawInterleavedTess<I>
+where
+  I: TessIndex,
+{
+  pub(crate) raw: TessRaw<I>,
+  vertex_buffer: Option<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>,
+  vert_nb: usize,
+}
+
+unsafe impl<V, I, W> TessBackend<V, I, W, RawInterleaved> for WebGL2
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  type TessRepr = RawInterleavedTess<I>;
+
+  unsafe fn build(
+    &mut self,
+    vertex_data: Option<V::Data>,
+    index_data: Vec<I>,
+    instance_data: Option<W::Data>,
+    mode: Mode,
+    _: Option<I>,
+    usage: TessUsage,
+  ) -> Result<Self::TessRepr, TessError> {
+    if instance_data.is_some() {
+      return Err(TessError::cannot_create(
+        "raw interleaved tessellations don’t support instance data",
+      ));
+    }
+
+    let vao = self
+      .state
+      .borrow_mut()
+      .create_vertex_array()
+      .ok_or_else(|| TessError::cannot_create("the backend failed to create the VAO"))?;
+
+    // force binding the vertex array so that previously bound vertex arrays (possibly the same
+    // handle) don’t prevent us from binding here
+    self
+      .state
+      .borrow_mut()
+      .bind_vertex_array(Some(&vao), Bind::Forced);
+
+    let vert_nb = vertex_data.as_ref().map_or(0, RawVertexData::len);
+    let vertex_buffer = build_raw_vertex_buffer(self, vertex_data, usage)?;
+    let index_buffer = build_index_buffer(self, index_data, usage)?;
+
+    let mode = webgl_mode(mode).ok_or_else(|| TessError::ForbiddenPrimitiveMode(mode))?;
+    let state = self.state.clone();
+    let raw = TessRaw {
+      vao,
+      mode,
+      index_buffer,
+      usage,
+      update_count: 0,
+      state,
+    };
+
+    Ok(RawInterleavedTess {
+      raw,
+      vertex_buffer,
+      vert_nb,
+    })
+  }
+
+  unsafe fn tess_vertices_nb(tess: &Self::TessRepr) -> usize {
+    tess.vert_nb
+  }
+
+  unsafe fn tess_indices_nb(tess: &Self::TessRepr) -> usize {
+    tess
+      .raw
+      .index_buffer
+      .as_ref()
+      .map(|ib| ib.buf.len())
+      .unwrap_or(0)
+  }
+
+  unsafe fn tess_instances_nb(_: &Self::TessRepr) -> usize {
+    0
+  }
+
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage {
+    tess.raw.usage
+  }
+
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64 {
+    tess.raw.update_count
+  }
+
+  unsafe fn render(
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError> {
+    tess.raw.render(
+      start_index,
+      vert_nb,
+      inst_nb,
+      base_instance,
+      normalized_attribs,
+    )
+  }
+}
+
+unsafe impl<V, I, W> VertexBytesBackend<V, I, W, RawInterleaved> for WebGL2
+where
+  V: TessVertexData<RawInterleaved, Data = RawVertexData>,
+  I: TessIndex,
+  W: TessVertexData<RawInterleaved, Data = RawVertexData>,
+{
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError> {
+    if let Some(ref vb) = tess.vertex_buffer {
+      f(&vb.slice_buffer());
+    }
+
+    Ok(())
+  }
+}
+
+// View a slice of vertices as raw bytes.
+fn as_bytes<T>(slice: &[T]) -> &[u8] {
+  unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice)) }
+}
+
 fn build_interleaved_vertex_buffer<V>(
   webgl2: &mut WebGL2,
   vertices: Option<Vec<V>>,
+  usage: TessUsage,
 ) -> Result<Option<Buffer<V, { WebGl2RenderingContext::ARRAY_BUFFER }>>, TessError>
 where
   V: Vertex,
@@ -484,7 +989,7 @@ where
       let vb = if vertices.is_empty() {
         None
       } else {
-        let vb = Buffer::from_vec(webgl2, vertices)?;
+        let vb = Buffer::from_vec_with_usage(webgl2, vertices, webgl_usage(usage))?;
 
         // force binding as it’s meaningful when a vao is bound
         webgl2
@@ -506,6 +1011,7 @@ where
 fn build_deinterleaved_vertex_buffers<V>(
   webgl2: &mut WebGL2,
   vertices: Option<Vec<DeinterleavedData>>,
+  usage: TessUsage,
 ) -> Result<Vec<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>, TessError>
 where
   V: Vertex,
@@ -516,7 +1022,7 @@ where
         .into_iter()
         .zip(V::vertex_desc())
         .map(|(attribute, fmt)| {
-          let vb = Buffer::from_vec(webgl2, attribute.into_vec())?;
+          let vb = Buffer::from_vec_with_usage(webgl2, attribute.into_vec(), webgl_usage(usage))?;
 
           // force binding as it’s meaningful when a vao is bound
           webgl2
@@ -534,16 +1040,50 @@ where
   }
 }
 
+fn build_raw_vertex_buffer(
+  webgl2: &mut WebGL2,
+  vertices: Option<RawVertexData>,
+  usage: TessUsage,
+) -> Result<Option<Buffer<u8, { WebGl2RenderingContext::ARRAY_BUFFER }>>, TessError> {
+  match vertices {
+    Some(vertices) if !vertices.is_empty() => {
+      let (raw, attributes) = vertices.into_parts();
+      let vb = Buffer::from_vec_with_usage(webgl2, raw, webgl_usage(usage))?;
+
+      // force binding as it’s meaningful when a vao is bound
+      let mut state = webgl2.state.borrow_mut();
+      state.bind_array_buffer(Some(vb.handle()), Bind::Forced);
+
+      // strides and offsets are explicit, so we don’t compute them from the attributes
+      for attribute in attributes {
+        let desc = VertexBufferDesc {
+          index: attribute.index,
+          name: "",
+          instancing: VertexInstancing::Off,
+          attrib_desc: attribute.attrib_desc,
+        };
+
+        set_component_format(&mut state.ctx, attribute.stride, attribute.offset, &desc);
+      }
+
+      Ok(Some(vb))
+    }
+
+    _ => Ok(None),
+  }
+}
+
 /// Turn a [`Vec`] of indices to a [`Buffer`], if indices are present.
 fn build_index_buffer<I>(
   webgl2: &mut WebGL2,
   data: Vec<I>,
+  usage: TessUsage,
 ) -> Result<Option<Buffer<I, { WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER }>>, TessError>
 where
   I: TessIndex,
 {
   let ib = if !data.is_empty() {
-    let ib = Buffer::from_vec(webgl2, data)?;
+    let ib = Buffer::from_vec_with_usage(webgl2, data, webgl_usage(usage))?;
 
     // force binding as it’s meaningful when a vao is bound
     webgl2
@@ -712,6 +1252,14 @@ fn webgl_mode(mode: Mode) -> Option<u32> {
   }
 }
 
+fn webgl_usage(usage: TessUsage) -> u32 {
+  match usage {
+    TessUsage::Static => WebGl2RenderingContext::STATIC_DRAW,
+    TessUsage::Dynamic => WebGl2RenderingContext::DYNAMIC_DRAW,
+    TessUsage::Stream => WebGl2RenderingContext::STREAM_DRAW,
+  }
+}
+
 fn index_type_to_glenum(ty: TessIndexType) -> u32 {
   match ty {
     TessIndexType::U8 => WebGl2RenderingContext::UNSIGNED_BYTE,
@@ -1,21 +1,30 @@
 use crate::webgl2::{
   array_buffer::IntoArrayBuffer,
-  pixel::webgl_pixel_format,
+  pixel::{webgl_compressed_format, webgl_pixel_format},
   state::{comparison_to_glenum, WebGL2State},
   WebGL2,
 };
 use luminance::{
-  backend::texture::{Texture as TextureBackend, TextureBase},
-  pixel::{Pixel, PixelFormat},
-  texture::{Dim, Dimensionable, MagFilter, MinFilter, Sampler, TexelUpload, TextureError, Wrap},
+  backend::texture::{
+    PixelAlignment, Texture as TextureBackend, TextureBase, TextureBuffer as TextureBufferBackend,
+    TextureSource,
+  },
+  error_check::BackendErrorKind,
+  pixel::{Format, NormRGBA8UI, Pixel, PixelFormat},
+  texture::{
+    Dim, Dimensionable, MagFilter, MinFilter, Sampler, Swizzle, TexelUpload, TextureError, Wrap,
+  },
 };
 use std::{cell::RefCell, mem, rc::Rc, slice};
-use web_sys::{WebGl2RenderingContext, WebGlTexture};
+use wasm_bindgen::JsValue;
+use web_sys::{HtmlImageElement, HtmlVideoElement, WebGl2RenderingContext, WebGlTexture};
 
 pub struct Texture {
   pub(crate) handle: WebGlTexture,
   pub(crate) target: u32, // “type” of the texture; used for bindings
   mipmaps: usize,
+  // shared by every representation of the same texture; the last one to be dropped deletes it
+  refs: Rc<()>,
   state: Rc<RefCell<WebGL2State>>,
 }
 
@@ -23,10 +32,25 @@ impl Texture {
   pub(crate) fn handle(&self) -> &WebGlTexture {
     &self.handle
   }
+
+  /// Get another representation of the same texture.
+  pub(crate) fn share(&self) -> Self {
+    Texture {
+      handle: self.handle.clone(),
+      target: self.target,
+      mipmaps: self.mipmaps,
+      refs: self.refs.clone(),
+      state: self.state.clone(),
+    }
+  }
 }
 
 impl Drop for Texture {
   fn drop(&mut self) {
+    if Rc::strong_count(&self.refs) > 1 {
+      return;
+    }
+
     self
       .state
       .borrow_mut()
@@ -80,6 +104,8 @@ where
 
     upload_texels::<D, P, P::Encoding>(&mut gfx_state, texture.target, offset, size, texels)?;
 
+    gfx_state.check_errors("texture upload");
+
     Ok(())
   }
 
@@ -103,6 +129,8 @@ where
 
     upload_texels::<D, P, P::RawEncoding>(&mut gfx_state, texture.target, offset, size, texels)?;
 
+    gfx_state.check_errors("texture upload");
+
     Ok(())
   }
 
@@ -124,6 +152,13 @@ where
     let pf = P::pixel_format();
     let (format, _, ty) = webgl_pixel_format(pf).ok_or(TextureError::UnsupportedPixelFormat(pf))?;
 
+    // compressed formats have no per-pixel size to compute the rows with
+    if let Format::Compressed(_) = pf.format {
+      return Err(TextureError::cannot_retrieve_texels(
+        "compressed textures cannot be read back",
+      ));
+    }
+
     let mut gfx_state = texture.state.borrow_mut();
     gfx_state.bind_texture(texture.target, Some(&texture.handle));
 
@@ -133,17 +168,21 @@ where
     let w = D::width(size);
     let h = D::height(size);
 
-    // set the packing alignment based on the number of bytes to skip
-    let skip_bytes = (pf.format.bytes_len() * w as usize) % 8;
-    set_pack_alignment(&mut gfx_state, skip_bytes);
+    // set the packing alignment based on the byte size of a row, unless forced
+    let row_bytes = pf.format.bytes_len() * w as usize;
+    let alignment = pixel_alignment(gfx_state.pack_alignment(), row_bytes);
+    set_pack_alignment(&mut gfx_state, alignment);
 
     // We need a workaround to get the texel data, because WebGL2 doesn’t support the glGetTexImage
     // function. The idea is that we are using a special read framebuffer that is always around and
     // on which we can attach the texture we want to read the texels from.
     match gfx_state.create_or_get_readback_framebuffer() {
       Some(ref readback_fb) => {
-        // Resize the vec to allocate enough space to host the returned texels.
-        let texels_nb = (w * h) as usize * pf.channels_len();
+        // Resize the vec to allocate enough space to host the returned texels, including the padding of rows.
+        let padding_bytes = (aligned_row_bytes(row_bytes, alignment) - row_bytes) * h as usize;
+        let texels_nb = (w * h) as usize * pf.channels_len()
+          + (padding_bytes + mem::size_of::<P::RawEncoding>() - 1)
+            / mem::size_of::<P::RawEncoding>();
         let mut texels = vec![Default::default(); texels_nb];
 
         // Attach the texture so that we can read from the framebuffer; careful here, since we are
@@ -193,6 +232,40 @@ where
     }
   }
 
+  unsafe fn upload_compressed(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    size: D::Size,
+    bytes: &[u8],
+  ) -> Result<(), TextureError> {
+    let pf = P::pixel_format();
+    let cf = match (pf.format, D::dim()) {
+      (Format::Compressed(cf), Dim::Dim2) => cf,
+      _ => return Err(TextureError::unsupported_pixel_format(pf)),
+    };
+
+    let mut gfx_state = texture.state.borrow_mut();
+    gfx_state.bind_texture(texture.target, Some(&texture.handle));
+
+    let array_buffer = u8::into_array_buffer(bytes);
+    gfx_state
+      .ctx
+      .compressed_tex_sub_image_2d_with_array_buffer_view(
+        texture.target,
+        level as i32,
+        0,
+        0,
+        D::width(size) as i32,
+        D::height(size) as i32,
+        webgl_compressed_format(cf).0,
+        &array_buffer,
+      );
+
+    gfx_state.check_errors("compressed texture upload");
+
+    Ok(())
+  }
+
   unsafe fn resize(
     texture: &mut Self::TextureRepr,
     size: D::Size,
@@ -220,6 +293,165 @@ where
   }
 }
 
+unsafe impl PixelAlignment for WebGL2 {
+  unsafe fn set_pixel_alignment(
+    &mut self,
+    pack: Option<usize>,
+    unpack: Option<usize>,
+  ) -> Result<(), TextureError> {
+    for alignment in pack.into_iter().chain(unpack) {
+      if !matches!(alignment, 1 | 2 | 4 | 8) {
+        return Err(TextureError::invalid_pixel_alignment(alignment));
+      }
+    }
+
+    self.state.borrow_mut().set_pixel_alignment(pack, unpack);
+    Ok(())
+  }
+}
+
+/// Texture buffer.
+///
+/// WebGL2 has no buffer textures, so texture buffers can never be created.
+pub enum TextureBuffer {}
+
+unsafe impl<P> TextureBufferBackend<P> for WebGL2
+where
+  P: Pixel,
+{
+  type TextureBufferRepr = TextureBuffer;
+
+  unsafe fn new_texture_buffer(
+    &mut self,
+    _: &[P::Encoding],
+  ) -> Result<Self::TextureBufferRepr, TextureError> {
+    self.state.borrow_mut().record_error(
+      "texture buffer creation",
+      BackendErrorKind::Unsupported("texture buffer".to_owned()),
+    );
+
+    Err(TextureError::unsupported_texture_buffer())
+  }
+
+  unsafe fn set_texture_buffer_texels(
+    texture_buffer: &mut Self::TextureBufferRepr,
+    _: &[P::Encoding],
+  ) -> Result<(), TextureError> {
+    match *texture_buffer {}
+  }
+}
+
+unsafe impl TextureSource<HtmlImageElement> for WebGL2 {
+  unsafe fn source_size(source: &HtmlImageElement) -> Option<[u32; 2]> {
+    // an image still loading (or that failed to load) has no content to provide
+    if source.complete() {
+      Some([source.natural_width(), source.natural_height()])
+    } else {
+      None
+    }
+  }
+
+  unsafe fn new_texture_for_source(
+    &mut self,
+    sampler: Sampler,
+  ) -> Result<Self::TextureRepr, TextureError> {
+    new_source_texture(self, sampler)
+  }
+
+  unsafe fn upload_source(
+    texture: &mut Self::TextureRepr,
+    source: &HtmlImageElement,
+  ) -> Result<(), TextureError> {
+    upload_source_texels(texture, |ctx, iformat, format, ty| {
+      ctx.tex_image_2d_with_u32_and_u32_and_html_image_element(
+        WebGl2RenderingContext::TEXTURE_2D,
+        0,
+        iformat as i32,
+        format,
+        ty,
+        source,
+      )
+    })
+  }
+}
+
+unsafe impl TextureSource<HtmlVideoElement> for WebGL2 {
+  unsafe fn source_size(source: &HtmlVideoElement) -> Option<[u32; 2]> {
+    // the dimensions of a video are zero until its first frame is known
+    Some([source.video_width(), source.video_height()])
+  }
+
+  unsafe fn new_texture_for_source(
+    &mut self,
+    sampler: Sampler,
+  ) -> Result<Self::TextureRepr, TextureError> {
+    new_source_texture(self, sampler)
+  }
+
+  unsafe fn upload_source(
+    texture: &mut Self::TextureRepr,
+    source: &HtmlVideoElement,
+  ) -> Result<(), TextureError> {
+    upload_source_texels(texture, |ctx, iformat, format, ty| {
+      ctx.tex_image_2d_with_u32_and_u32_and_html_video_element(
+        WebGl2RenderingContext::TEXTURE_2D,
+        0,
+        iformat as i32,
+        format,
+        ty,
+        source,
+      )
+    })
+  }
+}
+
+/// Create a 2D texture without any storage, to be filled from a DOM source.
+///
+/// The storage of such textures is respecified at every upload, since the size of the source might change over time.
+unsafe fn new_source_texture(
+  webgl2: &mut WebGL2,
+  sampler: Sampler,
+) -> Result<Texture, TextureError> {
+  let target = WebGl2RenderingContext::TEXTURE_2D;
+  let mut state = webgl2.state.borrow_mut();
+
+  let handle = state.create_texture().ok_or_else(|| {
+    TextureError::TextureStorageCreationFailed("cannot create texture".to_owned())
+  })?;
+  state.bind_texture(target, Some(&handle));
+
+  set_texture_levels(&mut state, target, 0);
+  apply_sampler_to_texture(&mut state, target, sampler);
+
+  Ok(Texture {
+    handle,
+    target,
+    mipmaps: 0,
+    refs: Rc::new(()),
+    state: webgl2.state.clone(),
+  })
+}
+
+/// Upload the content of a DOM source to a texture with the provided `texImage2D` call.
+fn upload_source_texels(
+  texture: &mut Texture,
+  tex_image_2d: impl FnOnce(&WebGl2RenderingContext, u32, u32, u32) -> Result<(), JsValue>,
+) -> Result<(), TextureError> {
+  let pf = NormRGBA8UI::pixel_format();
+  let (format, iformat, ty) =
+    webgl_pixel_format(pf).ok_or_else(|| TextureError::unsupported_pixel_format(pf))?;
+
+  let mut state = texture.state.borrow_mut();
+  state.bind_texture(texture.target, Some(&texture.handle));
+
+  tex_image_2d(&state.ctx, iformat, format, ty)
+    .map_err(|e| TextureError::cannot_upload_texels(format!("{:?}", e)))?;
+
+  state.check_errors("texture source upload");
+
+  Ok(())
+}
+
 pub(crate) fn opengl_target(d: Dim) -> Option<u32> {
   match d {
     Dim::Dim2 => Some(WebGl2RenderingContext::TEXTURE_2D),
@@ -240,6 +472,28 @@ where
   D: Dimensionable,
   P: Pixel,
   Px: IntoArrayBuffer,
+{
+  let texture =
+    new_texture_storage::<D>(webgl2, size, texels.mipmaps(), P::pixel_format(), sampler)?;
+
+  let mut state = webgl2.state.borrow_mut();
+  upload_texels::<D, P, Px>(&mut state, texture.target, D::ZERO_OFFSET, size, texels)?;
+
+  state.check_errors("texture creation");
+
+  Ok(texture)
+}
+
+/// Create a texture and its storage, without uploading any texels; the texture is left bound.
+pub(crate) unsafe fn new_texture_storage<D>(
+  webgl2: &mut WebGL2,
+  size: D::Size,
+  mipmaps: usize,
+  pf: PixelFormat,
+  sampler: Sampler,
+) -> Result<Texture, TextureError>
+where
+  D: Dimensionable,
 {
   let dim = D::dim();
   let target = opengl_target(dim).ok_or_else(|| {
@@ -253,23 +507,13 @@ where
   })?;
   state.bind_texture(target, Some(&handle));
 
-  let mipmaps = texels.mipmaps();
-
-  setup_texture::<D>(
-    &mut state,
-    target,
-    size,
-    mipmaps,
-    P::pixel_format(),
-    sampler,
-  )?;
-
-  upload_texels::<D, P, Px>(&mut state, target, D::ZERO_OFFSET, size, texels)?;
+  setup_texture::<D>(&mut state, target, size, mipmaps, pf, sampler)?;
 
   let texture = Texture {
     handle,
     target,
     mipmaps,
+    refs: Rc::new(()),
     state: webgl2.state.clone(),
   };
 
@@ -306,20 +550,24 @@ fn set_texture_levels(state: &mut WebGL2State, target: u32, mipmaps: usize) {
 }
 
 fn apply_sampler_to_texture(state: &mut WebGL2State, target: u32, sampler: Sampler) {
+  let wrap_r = webgl_wrap(state, sampler.wrap_r);
+  let wrap_s = webgl_wrap(state, sampler.wrap_s);
+  let wrap_t = webgl_wrap(state, sampler.wrap_t);
+
   state.ctx.tex_parameteri(
     target,
     WebGl2RenderingContext::TEXTURE_WRAP_R,
-    webgl_wrap(sampler.wrap_r) as i32,
+    wrap_r as i32,
   );
   state.ctx.tex_parameteri(
     target,
     WebGl2RenderingContext::TEXTURE_WRAP_S,
-    webgl_wrap(sampler.wrap_s) as i32,
+    wrap_s as i32,
   );
   state.ctx.tex_parameteri(
     target,
     WebGl2RenderingContext::TEXTURE_WRAP_T,
-    webgl_wrap(sampler.wrap_t) as i32,
+    wrap_t as i32,
   );
   state.ctx.tex_parameteri(
     target,
@@ -354,13 +602,35 @@ fn apply_sampler_to_texture(state: &mut WebGL2State, target: u32, sampler: Sampl
       );
     }
   }
+
+  // WebGL2 has no texture swizzle
+  if sampler.swizzle != [Swizzle::Red, Swizzle::Green, Swizzle::Blue, Swizzle::Alpha] {
+    log::warn!(
+      "texture swizzle not supported; ignoring {:?}",
+      sampler.swizzle
+    );
+    state.record_error(
+      "texture sampler",
+      BackendErrorKind::Unsupported(format!("texture swizzle ({:?})", sampler.swizzle)),
+    );
+  }
 }
 
-fn webgl_wrap(wrap: Wrap) -> u32 {
+fn webgl_wrap(state: &mut WebGL2State, wrap: Wrap) -> u32 {
   match wrap {
     Wrap::ClampToEdge => WebGl2RenderingContext::CLAMP_TO_EDGE,
     Wrap::Repeat => WebGl2RenderingContext::REPEAT,
     Wrap::MirroredRepeat => WebGl2RenderingContext::MIRRORED_REPEAT,
+
+    // WebGL2 has no mirror-clamp-to-edge
+    Wrap::MirrorClampToEdge => {
+      log::warn!("mirror-clamp-to-edge wrap mode not supported; falling back to mirrored repeat");
+      state.record_error(
+        "texture sampler",
+        BackendErrorKind::Unsupported("mirror-clamp-to-edge wrap mode".to_owned()),
+      );
+      WebGl2RenderingContext::MIRRORED_REPEAT
+    }
   }
 }
 
@@ -391,6 +661,21 @@ fn create_texture_storage<D>(
 where
   D: Dimensionable,
 {
+  // compressed formats require their extension to be enabled and can only be used with 2D textures
+  if let Format::Compressed(cf) = pf.format {
+    if D::dim() != Dim::Dim2 {
+      return Err(TextureError::texture_storage_creation_failed(format!(
+        "compressed textures must be 2D, not {}",
+        D::dim()
+      )));
+    }
+
+    let (_, extension) = webgl_compressed_format(cf);
+    if !matches!(state.ctx.get_extension(extension), Ok(Some(_))) {
+      return Err(TextureError::unsupported_pixel_format(pf));
+    }
+  }
+
   match webgl_pixel_format(pf) {
     Some(glf) => {
       let (_, iformat, _) = glf;
@@ -499,32 +784,33 @@ fn create_cubemap_storage(
   Ok(())
 }
 
-// set the unpack alignment for uploading aligned texels
-fn set_unpack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
-  let unpack_alignment = match skip_bytes {
+// pixel row alignment of a transfer: the forced one, if any, or the largest one dividing the byte size of a row
+fn pixel_alignment(forced: Option<usize>, row_bytes: usize) -> usize {
+  forced.unwrap_or(match row_bytes % 8 {
     0 => 8,
-    2 => 2,
     4 => 4,
+    2 | 6 => 2,
     _ => 1,
-  } as i32;
+  })
+}
 
+// byte size of a row once padded to the given alignment
+fn aligned_row_bytes(row_bytes: usize, alignment: usize) -> usize {
+  (row_bytes + alignment - 1) / alignment * alignment
+}
+
+// set the unpack alignment for uploading aligned texels
+fn set_unpack_alignment(state: &mut WebGL2State, alignment: usize) {
   state
     .ctx
-    .pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, unpack_alignment);
+    .pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, alignment as i32);
 }
 
 // set the pack alignment for downloading aligned texels
-fn set_pack_alignment(state: &mut WebGL2State, skip_bytes: usize) {
-  let pack_alignment = match skip_bytes {
-    0 => 8,
-    2 => 2,
-    4 => 4,
-    _ => 1,
-  } as i32;
-
+fn set_pack_alignment(state: &mut WebGL2State, alignment: usize) {
   state
     .ctx
-    .pixel_storei(WebGl2RenderingContext::PACK_ALIGNMENT, pack_alignment);
+    .pixel_storei(WebGl2RenderingContext::PACK_ALIGNMENT, alignment as i32);
 }
 // Upload texels into the texture’s memory. Becareful of the type of texels you send down.
 fn upload_texels<D, P, T>(
@@ -541,8 +827,20 @@ where
 {
   // number of bytes in the input texels argument
   let pf = P::pixel_format();
+  // compressed formats yield 0 here; they only accept empty base levels, checked below
   let pf_size = pf.format.bytes_len();
-  let expected_bytes = D::count(size) * pf_size;
+
+  // rows are padded to the pixel row alignment, which is picked from the width of the texture and the size of a
+  // single pixel unless forced; the last row doesn’t need any padding
+  let width = D::width(size) as usize;
+  let row_bytes = width * pf_size;
+  let alignment = pixel_alignment(state.unpack_alignment(), row_bytes);
+  let rows = D::count(size) / width.max(1);
+  let expected_bytes = if rows == 0 {
+    0
+  } else {
+    aligned_row_bytes(row_bytes, alignment) * (rows - 1) + row_bytes
+  };
 
   // get base level texels
   let base_level_texels = texels
@@ -552,6 +850,17 @@ where
       provided_bytes: 0,
     })?;
 
+  // an empty base level only reserves the storage (e.g. framebuffer attachments); nothing to upload
+  if base_level_texels.is_empty() {
+    return Ok(());
+  }
+
+  if let Format::Compressed(_) = pf.format {
+    return Err(TextureError::cannot_upload_texels(
+      "compressed textures must be uploaded to with Texture::upload_compressed",
+    ));
+  }
+
   // number of bytes in the input texels argument
   let input_bytes = base_level_texels.len() * mem::size_of::<T>();
 
@@ -560,11 +869,7 @@ where
     return Err(TextureError::not_enough_pixels(expected_bytes, input_bytes));
   }
 
-  // set the pixel row alignment to the required value for uploading data according to the width
-  // of the texture and the size of a single pixel; here, skip_bytes represents the number of bytes
-  // that will be skipped
-  let skip_bytes = (D::width(size) as usize * pf_size) % 8;
-  set_unpack_alignment(state, skip_bytes);
+  set_unpack_alignment(state, alignment);
 
   match texels {
     TexelUpload::BaseLevel { texels, mipmaps } => {
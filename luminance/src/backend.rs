@@ -87,12 +87,16 @@
 
 pub mod color_slot;
 pub mod depth_stencil_slot;
+pub mod error_check;
+pub mod feedback;
 pub mod framebuffer;
+pub mod memory_barrier;
 pub mod pipeline;
 pub mod query;
 pub mod render_gate;
 pub mod shader;
 pub mod shading_gate;
+pub mod state;
 pub mod tess;
 pub mod tess_gate;
 pub mod texture;
@@ -14,7 +14,7 @@ use crate::{
   texture::{TexelUpload, Texture},
 };
 use crate::{
-  pixel::{ColorPixel, PixelFormat, RenderablePixel},
+  pixel::{ColorPixel, Pixel, PixelFormat, RenderablePixel},
   texture::{Dimensionable, Sampler},
 };
 
@@ -217,3 +217,62 @@ macro_rules! impl_color_slot_tuples {
 }
 
 impl_color_slot_tuples!(P0, P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11);
+
+/// Typed access to the `N`-th color texture of a color slot.
+///
+/// This trait is implemented on the [`ColorSlot::ColorTextures`] of single textures (only for `N = 0`) and tuples of
+/// textures (for every `N` smaller than the size of the tuple), so that accessing an out-of-bounds attachment fails to
+/// compile.
+pub trait ColorSlotIndex<const N: usize> {
+  /// Type of the `N`-th color texture.
+  type Texture;
+
+  /// Access the `N`-th color texture.
+  fn color_texture(&mut self) -> &mut Self::Texture;
+}
+
+impl<B, D, P> ColorSlotIndex<0> for Texture<B, D, P>
+where
+  B: ?Sized + TextureBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  type Texture = Self;
+
+  fn color_texture(&mut self) -> &mut Self::Texture {
+    self
+  }
+}
+
+// Implement ColorSlotIndex for every index of a tuple of textures.
+macro_rules! impl_color_slot_index {
+  ($($idx:tt $t:ident),*) => {
+    impl_color_slot_index!(@impl [$($t),*] $($idx $t),*);
+  };
+
+  (@impl [$($t:ident),*] $idx:tt $ti:ident $(, $ridx:tt $rti:ident)*) => {
+    impl<$($t),*> ColorSlotIndex<$idx> for ($($t),*) {
+      type Texture = $ti;
+
+      fn color_texture(&mut self) -> &mut Self::Texture {
+        &mut self.$idx
+      }
+    }
+
+    impl_color_slot_index!(@impl [$($t),*] $($ridx $rti),*);
+  };
+
+  (@impl [$($t:ident),*]) => {};
+}
+
+impl_color_slot_index!(0 T0, 1 T1);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10);
+impl_color_slot_index!(0 T0, 1 T1, 2 T2, 3 T3, 4 T4, 5 T5, 6 T6, 7 T7, 8 T8, 9 T9, 10 T10, 11 T11);
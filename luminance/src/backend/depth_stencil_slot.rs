@@ -13,7 +13,7 @@ use crate::{
   backend::{framebuffer::Framebuffer, texture::Texture as TextureBackend},
   context::GraphicsContext,
   framebuffer::FramebufferError,
-  pixel::{Depth32F, Depth32FStencil8, Pixel as _, PixelFormat},
+  pixel::{Depth24, Depth24Stencil8, Depth32F, Depth32FStencil8, Pixel as _, PixelFormat},
   texture::{Dimensionable, Sampler, TexelUpload, Texture},
 };
 
@@ -150,3 +150,71 @@ where
     Ok(texture)
   }
 }
+
+impl<B, D> DepthStencilSlot<B, D> for Depth24
+where
+  B: ?Sized + Framebuffer<D> + TextureBackend<D, Depth24>,
+  D: Dimensionable,
+  D::Size: Copy,
+{
+  type DepthStencilTexture = Texture<B, D, Depth24>;
+
+  fn depth_format() -> Option<PixelFormat> {
+    Some(Depth24::pixel_format())
+  }
+
+  fn reify_depth_texture<C>(
+    ctx: &mut C,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<Self::DepthStencilTexture, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let texture = Texture::new(
+      ctx,
+      size,
+      sampler.clone(),
+      TexelUpload::base_level_with_mipmaps(&[], mipmaps),
+    )?;
+    unsafe { B::attach_depth_texture(framebuffer, &texture.repr)? };
+
+    Ok(texture)
+  }
+}
+
+impl<B, D> DepthStencilSlot<B, D> for Depth24Stencil8
+where
+  B: ?Sized + Framebuffer<D> + TextureBackend<D, Depth24Stencil8>,
+  D: Dimensionable,
+  D::Size: Copy,
+{
+  type DepthStencilTexture = Texture<B, D, Depth24Stencil8>;
+
+  fn depth_format() -> Option<PixelFormat> {
+    Some(Depth24Stencil8::pixel_format())
+  }
+
+  fn reify_depth_texture<C>(
+    ctx: &mut C,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    framebuffer: &mut B::FramebufferRepr,
+  ) -> Result<Self::DepthStencilTexture, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let texture = Texture::new(
+      ctx,
+      size,
+      sampler.clone(),
+      TexelUpload::base_level_with_mipmaps(&[], mipmaps),
+    )?;
+    unsafe { B::attach_depth_texture(framebuffer, &texture.repr)? };
+
+    Ok(texture)
+  }
+}
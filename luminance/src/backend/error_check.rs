@@ -0,0 +1,18 @@
+//! Error checking backend interface.
+//!
+//! This interface allows to toggle backend error checking and retrieve the errors that were detected while it was
+//! enabled.
+
+use crate::error_check::BackendError;
+
+/// Backends that support explicit error checking.
+pub unsafe trait ErrorCheck {
+  /// Enable or disable error checking.
+  fn set_error_checking(&mut self, enabled: bool);
+
+  /// Whether error checking is currently enabled.
+  fn is_error_checking(&self) -> bool;
+
+  /// Take all the errors that were recorded since the last call.
+  fn take_errors(&mut self) -> Vec<BackendError>;
+}
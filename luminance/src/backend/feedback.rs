@@ -0,0 +1,65 @@
+//! Transform feedback backend interface.
+//!
+//! This interface defines the low-level API backends must implement to capture the outputs of vertex processing into
+//! GPU buffers.
+
+use crate::backend::shader::Shader;
+use crate::backend::tess_gate::TessGate;
+use crate::feedback::{FeedbackError, FeedbackMode};
+use crate::shader::ProgramError;
+use crate::tess::{TessIndex, TessVertexData};
+
+/// Shader programs declaring feedback varyings.
+pub unsafe trait ShaderFeedback: Shader {
+  /// Declare the feedback varyings to capture with transform feedback and link the program again.
+  ///
+  /// This happens after [`Shader::apply_semantics`]. Varyings that don’t exist in the program must not make the link
+  /// fail: backends must link the program with the other ones and return the names of the missing ones.
+  unsafe fn apply_feedback_varyings(
+    program: &mut Self::ProgramRepr,
+    varyings: &[&str],
+    mode: FeedbackMode,
+  ) -> Result<Vec<String>, ProgramError>;
+}
+
+/// Buffers receiving captured feedback varyings.
+pub unsafe trait FeedbackBuffer<T>
+where
+  T: Copy + Default,
+{
+  /// Backend representation of the feedback buffer.
+  type FeedbackBufferRepr;
+
+  /// Create a feedback buffer holding `len` items, initialized with [`Default::default`].
+  unsafe fn new_feedback_buffer(
+    &mut self,
+    len: usize,
+  ) -> Result<Self::FeedbackBufferRepr, FeedbackError>;
+
+  /// Read back the content of a feedback buffer.
+  unsafe fn read_feedback_buffer(
+    buffer: &Self::FeedbackBufferRepr,
+  ) -> Result<Vec<T>, FeedbackError>;
+}
+
+/// Trait to implement to render [`Tess`](crate::backend::tess::Tess) objects while capturing feedback varyings.
+pub unsafe trait TessGateFeedback<V, I, W, S, T>:
+  TessGate<V, I, W, S> + FeedbackBuffer<T> + ShaderFeedback
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+  T: Copy + Default,
+{
+  /// Render the [`Tess`](crate::backend::tess::Tess) as with [`TessGate::render`], capturing the feedback varyings of
+  /// the shader program in use into `buffers`, bound in order to the feedback buffer binding points.
+  unsafe fn render_feedback(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    inst_nb: usize,
+    buffers: &mut [&mut Self::FeedbackBufferRepr],
+  ) -> Result<(), FeedbackError>;
+}
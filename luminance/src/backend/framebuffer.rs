@@ -4,7 +4,8 @@
 
 use crate::{
   backend::{color_slot::ColorSlot, depth_stencil_slot::DepthStencilSlot, texture::TextureBase},
-  framebuffer::FramebufferError,
+  framebuffer::{Attachment, FramebufferError},
+  pixel::PixelFormat,
   texture::{Dim2, Dimensionable, Sampler},
 };
 
@@ -59,6 +60,28 @@ where
     attachment_index: usize,
   ) -> Result<(), FramebufferError>;
 
+  /// Attach a single layer of a layered texture (e.g. a Z-slice of a 3D texture) as color data to the framebuffer.
+  ///
+  /// Contrary to [`Framebuffer::attach_color_texture`], this method is called on an already validated framebuffer,
+  /// so implementations must bind the framebuffer and check its status again. `pf` is the pixel format of the texture;
+  /// the attachment must be accounted for as any other color attachment, so that draw buffers and per-attachment
+  /// clear colors apply to it.
+  unsafe fn attach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+    pf: PixelFormat,
+    attachment_index: usize,
+    layer: u32,
+  ) -> Result<(), FramebufferError>;
+
+  /// Detach a layer attached with [`Framebuffer::attach_color_texture_layer`].
+  ///
+  /// The texture might be dropped right after this call, so the framebuffer must not refer to it anymore.
+  unsafe fn detach_color_texture_layer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachment_index: usize,
+  );
+
   /// Attach a single depth data to the framebuffer.
   ///
   /// This method will never be called if the depth slot is `()`.
@@ -80,6 +103,46 @@ where
   ///
   /// The size is currently stored on the backend side, so this function extracts it from the backend.
   unsafe fn framebuffer_size(framebuffer: &Self::FramebufferRepr) -> D::Size;
+
+  /// Invalidate the content of some attachments of the framebuffer.
+  ///
+  /// This is only a hint: backends which cannot invalidate attachments can simply do nothing.
+  unsafe fn invalidate_framebuffer(
+    framebuffer: &mut Self::FramebufferRepr,
+    attachments: &[Attachment],
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Framebuffer which attachment formats are picked at runtime.
+///
+/// Whereas [`Framebuffer::new_framebuffer`] gets the formats of the attachments from the [`ColorSlot`] and
+/// [`DepthStencilSlot`] types, this trait receives them as [`PixelFormat`] values. The process is otherwise the same:
+/// the framebuffer is created with [`DynamicFramebuffer::new_dynamic_framebuffer`], each attachment is created with
+/// [`DynamicFramebuffer::new_attachment_texture`] and attached with [`Framebuffer::attach_color_texture`] or
+/// [`Framebuffer::attach_depth_texture`], then [`Framebuffer::validate_framebuffer`] is called.
+///
+/// Formats are checked to be color and depth formats before this trait is used, but backends are still expected to
+/// fail if a format cannot be used as an attachment.
+pub unsafe trait DynamicFramebuffer<D>: Framebuffer<D>
+where
+  D: Dimensionable,
+{
+  /// Create a new framebuffer on the backend with the given color and depth / stencil formats.
+  unsafe fn new_dynamic_framebuffer(
+    &mut self,
+    size: D::Size,
+    color_formats: &[PixelFormat],
+    depth_stencil_format: Option<PixelFormat>,
+  ) -> Result<Self::FramebufferRepr, FramebufferError>;
+
+  /// Create a texture with the given format and no texels, to be used as a framebuffer attachment.
+  unsafe fn new_attachment_texture(
+    &mut self,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    format: PixelFormat,
+  ) -> Result<Self::TextureRepr, FramebufferError>;
 }
 
 /// Back buffer.
@@ -97,3 +160,49 @@ pub unsafe trait FramebufferBackBuffer: Framebuffer<Dim2> {
     size: <Dim2 as Dimensionable>::Size,
   ) -> Result<Self::FramebufferRepr, FramebufferError>;
 }
+
+/// Back buffer which color encoding can be queried.
+///
+/// Whether the back buffer stores sRGB-encoded colors depends on how the platform created it, not on luminance.
+pub unsafe trait FramebufferBackBufferSrgb: FramebufferBackBuffer {
+  /// Whether the back buffer stores sRGB-encoded colors.
+  unsafe fn back_buffer_is_srgb(&mut self) -> bool;
+}
+
+/// Framebuffer owned by a third party.
+///
+/// A raw framebuffer is created and deleted outside of luminance — typically by an engine luminance renders into — and
+/// is only wrapped to be rendered to. Its color attachments are described by their formats, so that the backend knows
+/// how to draw to and clear them.
+pub unsafe trait FramebufferRawHandle: Framebuffer<Dim2> {
+  /// Backend handle of a framebuffer.
+  type RawFramebufferHandle;
+
+  /// Wrap the framebuffer referred to by `handle`.
+  ///
+  /// The framebuffer must be validated, and it must never be deleted by the backend, even when the returned
+  /// representation is dropped.
+  unsafe fn framebuffer_from_raw_handle(
+    &mut self,
+    handle: Self::RawFramebufferHandle,
+    size: <Dim2 as Dimensionable>::Size,
+    color_formats: &[PixelFormat],
+  ) -> Result<Self::FramebufferRepr, FramebufferError>;
+}
+
+/// Framebuffer which depth attachment can be copied into a texture.
+pub unsafe trait FramebufferDepthCopy: Framebuffer<Dim2> {
+  /// Copy the depth attachment of `framebuffer` into `texture`, which have the same size and depth format.
+  unsafe fn copy_depth_to_texture(
+    framebuffer: &Self::FramebufferRepr,
+    texture: &Self::TextureRepr,
+  ) -> Result<(), FramebufferError>;
+}
+
+/// Framebuffer which depth / stencil attachment can be shared with other framebuffers.
+pub unsafe trait FramebufferSharedDepth: Framebuffer<Dim2> {
+  /// Get another representation of `texture`, referring to the same backend texture.
+  ///
+  /// The backend texture must only be deleted once every representation sharing it is dropped.
+  unsafe fn share_depth_texture(texture: &Self::TextureRepr) -> Self::TextureRepr;
+}
@@ -0,0 +1,14 @@
+//! Memory barrier backend interface.
+//!
+//! This interface allows to order incoherent GPU writes with the reads that follow them.
+
+use crate::memory_barrier::{MemoryBarrierBits, MemoryBarrierError};
+
+/// Backends that can issue memory barriers.
+pub unsafe trait MemoryBarrier {
+  /// Issue a memory barrier for the given flags.
+  ///
+  /// Backends unable to issue the barrier for some of the flags must return [`MemoryBarrierError::Unsupported`]
+  /// instead of silently ignoring them.
+  unsafe fn memory_barrier(&mut self, bits: MemoryBarrierBits) -> Result<(), MemoryBarrierError>;
+}
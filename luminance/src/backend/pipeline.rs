@@ -23,10 +23,10 @@ use crate::{
     framebuffer::Framebuffer as FramebufferBackend,
     shader::ShaderData,
     shading_gate::ShadingGate as ShadingGateBackend,
-    texture::{Texture, TextureBase},
+    texture::{Texture, TextureBase, TextureBuffer},
   },
   pipeline::{PipelineError, PipelineState},
-  pixel::Pixel,
+  pixel::{Pixel, StencilPixel},
   texture::Dimensionable,
 };
 
@@ -97,6 +97,68 @@ where
   unsafe fn texture_binding(bound: &Self::BoundTextureRepr) -> u32;
 }
 
+/// Operations that can be run on pipelines and combined depth / stencil textures.
+///
+/// This trait requires [`PipelineTexture`].
+pub unsafe trait PipelineTextureStencil<D, P>: PipelineTexture<D, P>
+where
+  D: Dimensionable,
+  P: StencilPixel,
+{
+  /// Bind a combined depth / stencil [`Texture`] to the current [`Pipeline`] so that its stencil component is sampled.
+  ///
+  /// This method works like [`PipelineTexture::bind_texture`], but must switch the texture to sample its stencil
+  /// component instead of its depth component. The depth component must be sampled again once the bound representation
+  /// is dropped. Backends unable to do so at runtime must fail with [`PipelineError::StencilTexturingUnsupported`].
+  unsafe fn bind_texture_stencil(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+  ) -> Result<Self::BoundTextureRepr, PipelineError>;
+}
+
+/// Operations that can be run on pipelines and single mipmap levels of textures.
+///
+/// This trait requires [`PipelineTexture`].
+pub unsafe trait PipelineTextureLevel<D, P>: PipelineTexture<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Bind a [`Texture`] to the current [`Pipeline`] so that only its mipmap level `level` is sampled.
+  ///
+  /// This method works like [`PipelineTexture::bind_texture`], but must restrict sampling to `level`, whatever the
+  /// level of detail computed when sampling. All the levels must be sampled again once the bound representation is
+  /// dropped. `level` is guaranteed to be a valid level of the texture.
+  unsafe fn bind_texture_level(
+    pipeline: &Self::PipelineRepr,
+    texture: &Self::TextureRepr,
+    level: usize,
+  ) -> Result<Self::BoundTextureRepr, PipelineError>;
+}
+
+/// Operations that can be run on pipelines and texture buffers.
+///
+/// This trait requires [`PipelineBase`] and [`TextureBuffer`].
+pub unsafe trait PipelineTextureBuffer<P>: PipelineBase + TextureBuffer<P>
+where
+  P: Pixel,
+{
+  /// Representation of a _bound_ [`TextureBuffer`] on the backend.
+  type BoundTextureBufferRepr;
+
+  /// Bind a [`TextureBuffer`] to the current [`Pipeline`].
+  ///
+  /// This method works like [`PipelineTexture::bind_texture`]: texture buffers are bound to the same bindings as
+  /// textures, and the bound representation must free its binding when dropped.
+  unsafe fn bind_texture_buffer(
+    pipeline: &Self::PipelineRepr,
+    texture_buffer: &Self::TextureBufferRepr,
+  ) -> Result<Self::BoundTextureBufferRepr, PipelineError>;
+
+  /// Get the `u32` representation of the bound texture buffer, also known as binding.
+  unsafe fn texture_buffer_binding(bound: &Self::BoundTextureBufferRepr) -> u32;
+}
+
 /// Operations that can be run on pipelines and shader data.
 ///
 /// This trait requires [`PipelineBase`] and [`ShaderData`].
@@ -3,6 +3,7 @@
 //! This interface provides various means to query some metrics and data from the backend, such as the maximum number of
 //! active texture units, memory sizes, etc.
 
+use crate::query::PipelineStats;
 use std::fmt;
 
 /// Query error.
@@ -22,6 +23,25 @@ pub enum QueryError {
 
   /// No maximum number of elements for texture arrays information available.
   NoMaxTextureArrayElements,
+
+  /// Cannot create a pipeline statistics query.
+  CannotCreatePipelineStatsQuery(String),
+
+  /// A pipeline statistics query is already active; only one can be active at a time.
+  PipelineStatsQueryActive,
+
+  /// The pipeline statistics query is not active.
+  PipelineStatsQueryInactive,
+
+  /// The pipeline statistics query has not been run yet, so it has no statistics available.
+  NoPipelineStats,
+}
+
+impl QueryError {
+  /// Cannot create a pipeline statistics query.
+  pub fn cannot_create_pipeline_stats_query(reason: impl Into<String>) -> Self {
+    QueryError::CannotCreatePipelineStatsQuery(reason.into())
+  }
 }
 
 impl fmt::Display for QueryError {
@@ -36,6 +56,16 @@ impl fmt::Display for QueryError {
       QueryError::NoMaxTextureArrayElements => {
         f.write_str("no maximum number of elements for texture arrays available")
       }
+      QueryError::CannotCreatePipelineStatsQuery(reason) => {
+        write!(f, "cannot create pipeline statistics query: {}", reason)
+      }
+      QueryError::PipelineStatsQueryActive => {
+        f.write_str("a pipeline statistics query is already active")
+      }
+      QueryError::PipelineStatsQueryInactive => {
+        f.write_str("the pipeline statistics query is not active")
+      }
+      QueryError::NoPipelineStats => f.write_str("no pipeline statistics available"),
     }
   }
 }
@@ -61,3 +91,38 @@ pub unsafe trait Query {
   /// The maximum number of elements a texture array can hold.
   fn max_texture_array_elements(&self) -> Result<usize, QueryError>;
 }
+
+/// Backends that support pipeline statistics queries.
+///
+/// A pipeline statistics query counts what the GPU processes between its beginning and its end — primitives,
+/// vertices, samples, etc. Only one pipeline statistics query can be active at a time.
+pub unsafe trait PipelineStatsQuery {
+  /// Backend representation of a pipeline statistics query.
+  type PipelineStatsQueryRepr;
+
+  /// Create a new pipeline statistics query.
+  unsafe fn new_pipeline_stats_query(&mut self)
+    -> Result<Self::PipelineStatsQueryRepr, QueryError>;
+
+  /// Start counting.
+  ///
+  /// Fails with [`QueryError::PipelineStatsQueryActive`] if a pipeline statistics query is already active.
+  unsafe fn begin_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError>;
+
+  /// Stop counting.
+  ///
+  /// Fails with [`QueryError::PipelineStatsQueryInactive`] if the query is not active.
+  unsafe fn end_pipeline_stats_query(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<(), QueryError>;
+
+  /// Get the statistics counted between the last beginning and end of the query.
+  ///
+  /// This waits for the GPU to be done with the commands issued while the query was active. Statistics the backend
+  /// cannot count are [`None`].
+  unsafe fn pipeline_stats(
+    query: &mut Self::PipelineStatsQueryRepr,
+  ) -> Result<PipelineStats, QueryError>;
+}
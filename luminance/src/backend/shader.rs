@@ -27,8 +27,8 @@
 
 use crate::{
   shader::{
-    ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform, UniformType,
-    UniformWarning, VertexAttribWarning,
+    BlockLayout, ProgramError, ShaderDataError, StageError, StageType, TessellationStages, Uniform,
+    UniformType, UniformWarning, VertexAttribWarning,
   },
   vertex::Semantics,
 };
@@ -62,6 +62,46 @@ pub unsafe trait Uniformable<'a, T>: Shader {
 
   /// Update the associated value of the [`Uniform`] in the given shader program.
   unsafe fn update(program: &mut Self::ProgramRepr, uniform: &'a Uniform<T>, value: Self::Target);
+
+  /// Update the values of consecutive elements of an array, starting at the element of `elements[0]`.
+  ///
+  /// `elements` are the [`Uniform`]s of consecutive elements of a single array, and `values` has the same length. The
+  /// default implementation updates each element in turn; backends able to upload several elements at once should
+  /// override it.
+  unsafe fn update_elements(
+    program: &mut Self::ProgramRepr,
+    elements: &'a [Uniform<T>],
+    values: Vec<Self::Target>,
+  ) {
+    for (uniform, value) in elements.iter().zip(values) {
+      Self::update(program, uniform, value);
+    }
+  }
+}
+
+/// Backend support for matrix uniforms.
+///
+/// Matrices are uploaded in column-major order by [`Uniformable::update`]. Backends implementing this trait can also
+/// upload them in row-major order, transposing them on the backend side.
+pub unsafe trait UniformableMatrix<'a, T>: Uniformable<'a, T> {
+  /// Update the associated value of the [`Uniform`] in the given shader program, transposing the matrix (or each
+  /// matrix of an array).
+  unsafe fn update_transposed(
+    program: &mut Self::ProgramRepr,
+    uniform: &'a Uniform<T>,
+    value: Self::Target,
+  );
+}
+
+/// Backend support for reading uniforms back.
+///
+/// Backends implementing this trait can read the current value of a [`Uniform`] back from a shader program, which is
+/// mostly useful to debug shaders.
+pub unsafe trait UniformableReadback<T>: Shader {
+  /// Read the current value of the [`Uniform`] back from the given shader program.
+  ///
+  /// Return `None` if the uniform is unbound.
+  unsafe fn get(program: &Self::ProgramRepr, uniform: &Uniform<T>) -> Option<T>;
 }
 
 /// Shader support.
@@ -100,6 +140,37 @@ pub unsafe trait Shader {
     fragment: &Self::StageRepr,
   ) -> Result<Self::ProgramRepr, ProgramError>;
 
+  /// Maximum number of vertices a geometry shader can output.
+  ///
+  /// Backends not supporting geometry shaders don’t have to implement this method, which returns [`None`] by default.
+  unsafe fn max_geometry_output_vertices(&mut self) -> Option<usize> {
+    None
+  }
+
+  /// Retrieve the binary representation of a linked shader program, along with its backend-specific format.
+  ///
+  /// Backends not supporting program binaries must return [`None`].
+  unsafe fn program_binary(program: &Self::ProgramRepr) -> Option<(u32, Vec<u8>)>;
+
+  /// Create a new shader program from a binary previously retrieved with [`Shader::program_binary`].
+  ///
+  /// The program must be fully linked when returned, including the vertex attribute locations, so that
+  /// [`Shader::apply_semantics`] is not called on it. Backends rejecting the binary (unsupported format, driver change,
+  /// etc.) must fail with [`ProgramError::BinaryRejected`].
+  unsafe fn new_program_from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<Self::ProgramRepr, ProgramError>;
+
+  /// Get the layout of a uniform block of a linked shader program.
+  ///
+  /// Backends must return [`None`] if the block doesn’t exist or is inactive. Backends not able to query uniform block
+  /// layouts don’t have to implement this method, which returns [`None`] by default.
+  unsafe fn uniform_block_layout(_program: &Self::ProgramRepr, _name: &str) -> Option<BlockLayout> {
+    None
+  }
+
   /// Apply semantics.
   ///
   /// This is a very specific operations that happen right after the shader program got successfully created by the
@@ -0,0 +1,31 @@
+//! Cached state backend interface.
+//!
+//! Backends typically cache the state of the underlying graphics API to avoid issuing redundant calls. This interface
+//! allows to drop that cache when the state was changed behind the backend’s back, or to save and restore the state
+//! around luminance code running inside a larger application.
+
+use crate::context::StateSnapshot;
+
+/// Backends which cached state can be invalidated.
+pub unsafe trait InvalidateState {
+  /// Invalidate the whole cached state.
+  ///
+  /// After this call, the backend must not assume anything about the current state of the graphics API: every
+  /// setting and binding needed by the next operations must be issued again.
+  unsafe fn invalidate_state(&mut self);
+}
+
+/// Backends which render state can be saved and restored.
+pub unsafe trait SaveState {
+  /// Save the current render state.
+  ///
+  /// The state must be read from the graphics API, not from the cache, so that state changed behind the backend’s
+  /// back is saved too.
+  unsafe fn save_state(&mut self) -> StateSnapshot;
+
+  /// Restore a render state previously saved with [`SaveState::save_state`].
+  ///
+  /// Every part of the state must be issued again, whatever the cache says, and the cache must be updated to reflect
+  /// the restored state.
+  unsafe fn restore_state(&mut self, snapshot: &StateSnapshot);
+}
@@ -18,7 +18,12 @@
 
 use std::ops::{Deref, DerefMut};
 
-use crate::tess::{Mode, TessError, TessIndex, TessMapError, TessVertexData};
+use crate::{
+  tess::{
+    Deinterleaved, Interleaved, Mode, TessError, TessIndex, TessMapError, TessUsage, TessVertexData,
+  },
+  vertex::Deinterleave,
+};
 
 /// Tessellation support on the backend.
 ///
@@ -65,6 +70,9 @@ where
   ///
   /// [`Interleaved`]: crate::tess::Interleaved
   /// [`Deinterleaved`]: crate::tess::Deinterleaved
+  /// `usage` is a hint about how often the data will be updated; implementations must remember it, so that it can be
+  /// returned by [`Tess::tess_usage`].
+  ///
   /// [`DeinterleavedData`]: crate::tess::DeinterleavedData
   unsafe fn build(
     &mut self,
@@ -73,6 +81,7 @@ where
     instance_data: Option<W::Data>,
     mode: Mode,
     restart_index: Option<I>,
+    usage: TessUsage,
   ) -> Result<Self::TessRepr, TessError>;
 
   /// Number of vertices available in the [`Tess`].
@@ -84,14 +93,56 @@ where
   /// Number of instance data available in the [`Tess`].
   unsafe fn tess_instances_nb(tess: &Self::TessRepr) -> usize;
 
+  /// Usage the [`Tess`] was built with.
+  unsafe fn tess_usage(tess: &Self::TessRepr) -> TessUsage;
+
+  /// Number of updates of the [`Tess`].
+  ///
+  /// Implementations must count every mutable slicing of vertices, indices and instances, as well as every dynamic
+  /// upload (see [`DynamicTess::set_dynamic_data`]).
+  unsafe fn tess_update_count(tess: &Self::TessRepr) -> u64;
+
   /// Render the tessellation, starting at `start_index`, rendering `vert_nb` vertices, instantiating `inst_nb` times.
   ///
-  /// If `inst_nb` is `0`, you should perform a render as if you were asking for `1`.
+  /// If `inst_nb` is `0`, you should perform a render as if you were asking for `1`. Instance vertex attributes must
+  /// be read from instance `base_instance` onwards; backends not supporting a non-zero `base_instance` must skip the
+  /// render and return [`TessError::UnsupportedBaseInstance`].
+  ///
+  /// `normalized_attribs` contains `(location, normalized)` pairs. Integral vertex attributes at those locations must
+  /// be read as floating-point, normalized or not, for this render only: once the render is done, the attributes must
+  /// be restored as they were built. Locations of disabled attributes must be ignored.
   unsafe fn render(
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError>;
+}
+
+/// Tessellations which vertex and index data is streamed every frame.
+///
+/// This trait must be implemented by backends supporting [`DynamicTess`], which are interleaved tessellations without
+/// instance data. Contrary to [`Tess::build`], [`DynamicTess::build_dynamic`] doesn’t take any data: the storage starts
+/// empty and is replaced as a whole with [`DynamicTess::set_dynamic_data`]. Implementations are expected to _orphan_
+/// the previous storage (so that the GPU can keep reading it while new data is uploaded) and to grow the storage
+/// capacity as needed, instead of reallocating every time.
+///
+/// [`DynamicTess`]: crate::tess::DynamicTess
+pub unsafe trait DynamicTess<V, I>: Tess<V, I, (), Interleaved>
+where
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+{
+  /// Build an empty tessellation which data will be streamed, with the [`TessUsage::Stream`] usage.
+  unsafe fn build_dynamic(&mut self, mode: Mode) -> Result<Self::TessRepr, TessError>;
+
+  /// Orphan the current storage and upload new vertex and index data, growing the storage if needed.
+  unsafe fn set_dynamic_data(
+    tess: &mut Self::TessRepr,
+    vertices: &[V],
+    indices: &[I],
   ) -> Result<(), TessError>;
 }
 
@@ -198,3 +249,96 @@ where
     tess: &'a mut Self::TessRepr,
   ) -> Result<Self::InstanceSliceMutRepr, TessMapError>;
 }
+
+/// Read the raw bytes of the vertex storage.
+///
+/// This trait must be implemented by the backend so that it’s possible to inspect the vertex storage of a
+/// tessellation without knowing the types of its vertices or attributes, such as when computing a
+/// [`Tess::vertex_checksum`]. [`VertexBytes::vertex_bytes`] must call its closure once per vertex buffer: once for
+/// interleaved tessellations, and once per attribute — in rank order — for deinterleaved ones. Attributeless
+/// tessellations have no vertex buffer, so the closure must not be called.
+///
+/// [`Tess::vertex_checksum`]: crate::tess::Tess::vertex_checksum
+pub unsafe trait VertexBytes<V, I, W, S>: Tess<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Call `f` with the raw bytes of every vertex buffer.
+  unsafe fn vertex_bytes(
+    tess: &mut Self::TessRepr,
+    f: &mut dyn FnMut(&[u8]),
+  ) -> Result<(), TessMapError>;
+}
+
+/// Overwrite a single vertex attribute of deinterleaved tessellations.
+///
+/// This trait must be implemented by the backend so that it’s possible to replace the storage of a single vertex
+/// attribute — selected by its type `T` — without mapping it, leaving the storage of the other attributes untouched.
+/// Implementations will typically upload the values directly into the attribute buffer.
+pub unsafe trait UpdateVertexAttribute<V, I, W, T>: Tess<V, I, W, Deinterleaved>
+where
+  V: TessVertexData<Deinterleaved> + Deinterleave<T>,
+  I: TessIndex,
+  W: TessVertexData<Deinterleaved>,
+{
+  /// Replace the content of the attribute storage with `values`.
+  ///
+  /// If `values` doesn’t contain exactly one value per vertex, [`TessMapError::AttributeLengthMismatch`] must be
+  /// returned and the storage left untouched.
+  unsafe fn update_vertex_attribute(
+    tess: &mut Self::TessRepr,
+    values: &[T],
+  ) -> Result<(), TessMapError>;
+}
+
+/// Pointer of a vertex attribute of the currently bound vertex array, as seen by the backend.
+///
+/// This is a helper for implementing the normalization overrides of [`Tess::render`]; see
+/// [`override_normalization`]. `S` is the state of the backend, used to query and re-specify pointers.
+pub trait VertexAttribPointer<S>: Sized {
+  /// Query the pointer of the vertex attribute at `index`, if it is enabled.
+  unsafe fn query(state: &mut S, index: usize) -> Option<Self>;
+
+  /// Whether the attribute is read as integral.
+  fn is_integer(&self) -> bool;
+
+  /// Whether the attribute is normalized.
+  fn is_normalized(&self) -> bool;
+
+  /// Re-specify the pointer, either as integral or as floating-point, normalized or not.
+  unsafe fn set(&self, state: &mut S, integer: bool, normalized: bool);
+}
+
+/// Override the normalization of vertex attributes for the duration of `render`.
+///
+/// `normalized_attribs` contains `(location, normalized)` pairs, as passed to [`Tess::render`]. Enabled attributes at
+/// those locations are re-specified as floating-point with the requested normalization, and get their original
+/// pointers back once `render` returns. Disabled attributes are ignored.
+pub unsafe fn override_normalization<S, P, R>(
+  state: &mut S,
+  normalized_attribs: &[(usize, bool)],
+  render: impl FnOnce(&mut S) -> R,
+) -> R
+where
+  P: VertexAttribPointer<S>,
+{
+  let overridden = normalized_attribs
+    .iter()
+    .filter_map(|&(index, normalized)| {
+      let pointer = P::query(state, index)?;
+      pointer.set(state, false, normalized);
+      Some(pointer)
+    })
+    .collect::<Vec<_>>();
+
+  let r = render(state);
+
+  for pointer in overridden {
+    pointer.set(state, pointer.is_integer(), pointer.is_normalized());
+  }
+
+  r
+}
@@ -5,7 +5,8 @@
 //! A tessellation gate allows to render [`Tess`] objects.
 
 use crate::backend::tess::Tess;
-use crate::tess::{TessIndex, TessVertexData};
+use crate::tess::{TessError, TessIndex, TessVertexData};
+use crate::tess_gate::DebugMode;
 
 /// Trait to implement to be able to render [`Tess`] objects.
 ///
@@ -18,12 +19,43 @@ where
   W: TessVertexData<S>,
   S: ?Sized,
 {
-  /// Render the [`Tess`] starting at `start_index`, for `vert_nb` vertices with `inst_nb` instances.
+  /// Render the [`Tess`] starting at `start_index`, for `vert_nb` vertices with `inst_nb` instances starting at
+  /// `base_instance`.
+  ///
+  /// `normalized_attribs` contains `(location, normalized)` normalization overrides of vertex attributes that must
+  /// apply to this render only (see [`Tess::render`]).
   unsafe fn render(
     &mut self,
     tess: &Self::TessRepr,
     start_index: usize,
     vert_nb: usize,
     inst_nb: usize,
+    base_instance: usize,
+    normalized_attribs: &[(usize, bool)],
+  ) -> Result<(), TessError>;
+}
+
+/// Trait to implement to render debug geometry of [`Tess`] objects.
+///
+/// See [`TessGate::render_debug`](crate::tess_gate::TessGate::render_debug).
+pub unsafe trait TessGateDebug<V, I, W, S>: TessGate<V, I, W, S>
+where
+  V: TessVertexData<S>,
+  I: TessIndex,
+  W: TessVertexData<S>,
+  S: ?Sized,
+{
+  /// Render the debug geometry described by `mode` for the [`Tess`] starting at `start_index`, for `vert_nb`
+  /// vertices.
+  ///
+  /// The semantics in `mode` are given as vertex attribute indices. The shader program in use before the call must be
+  /// in use again when this function returns.
+  unsafe fn render_debug(
+    &mut self,
+    tess: &Self::TessRepr,
+    start_index: usize,
+    vert_nb: usize,
+    mode: DebugMode<usize>,
+    transform: [[f32; 4]; 4],
   );
 }
@@ -11,8 +11,8 @@
 //! You will have to implement both traits to be able to use textures.
 
 use crate::{
-  pixel::Pixel,
-  texture::{Dimensionable, Sampler, TexelUpload, TextureError},
+  pixel::{NormRGBA8UI, Pixel},
+  texture::{Dim2, Dimensionable, Sampler, TexelUpload, TextureError},
 };
 
 /// Type family giving the backend representation type.
@@ -117,6 +117,16 @@ where
   where
     P::RawEncoding: Copy + Default;
 
+  /// Upload already compressed texels to a mipmap level of a texture.
+  ///
+  /// `size` is the size of the level, and `bytes` has already been checked to cover it exactly.
+  unsafe fn upload_compressed(
+    texture: &mut Self::TextureRepr,
+    level: usize,
+    size: D::Size,
+    bytes: &[u8],
+  ) -> Result<(), TextureError>;
+
   /// Resize the texture.
   ///
   /// Once the texture is resized, pixels are left in an unknown state. Depending on the implementation of the backend,
@@ -137,3 +147,74 @@ where
     texel: TexelUpload<[P::RawEncoding]>,
   ) -> Result<(), TextureError>;
 }
+
+/// Backends which row alignment of texels in client memory can be set.
+///
+/// The _pack_ alignment applies to texels read back from textures and the _unpack_ alignment to texels uploaded to
+/// textures. By default, backends pick for each transfer the largest alignment dividing the byte size of a row, so
+/// that tightly packed texels are always transferred correctly.
+pub unsafe trait PixelAlignment {
+  /// Force the pack and unpack alignments, or go back to the default behavior with [`None`].
+  ///
+  /// Alignments must be `1`, `2`, `4` or `8`, otherwise [`TextureError::InvalidPixelAlignment`] must be returned and no
+  /// alignment changed. While an alignment is forced, uploaded texels must have their rows padded to it, and texels
+  /// read back must be returned with their rows padded to it.
+  unsafe fn set_pixel_alignment(
+    &mut self,
+    pack: Option<usize>,
+    unpack: Option<usize>,
+  ) -> Result<(), TextureError>;
+}
+
+/// Backends which 2D textures can be filled from external image sources, such as images or video frames owned by the
+/// platform.
+///
+/// `S` is the type of the source. Sources are read as-is by the backend, without going through client memory.
+pub unsafe trait TextureSource<S>: Texture<Dim2, NormRGBA8UI>
+where
+  S: ?Sized,
+{
+  /// Get the current size of the source, or [`None`] if it has nothing to provide yet (i.e. an image still loading or
+  /// a video without any decoded frame).
+  unsafe fn source_size(source: &S) -> Option<[u32; 2]>;
+
+  /// Create a new texture with no texels, which will be filled with [`TextureSource::upload_source`].
+  unsafe fn new_texture_for_source(
+    &mut self,
+    sampler: Sampler,
+  ) -> Result<Self::TextureRepr, TextureError>;
+
+  /// Replace the texels of a texture with the current content of the source.
+  ///
+  /// The texture takes the size of the source, which is known to be ready.
+  unsafe fn upload_source(texture: &mut Self::TextureRepr, source: &S) -> Result<(), TextureError>;
+}
+
+/// Backends supporting texture buffers.
+///
+/// A texture buffer is a one-dimensional array of texels stored in a GPU buffer. Shaders access its texels by index
+/// only — e.g. with `texelFetch` on a `samplerBuffer` — with no filtering nor mipmaps. `P` is the format of the carried
+/// pixels.
+pub unsafe trait TextureBuffer<P>
+where
+  P: Pixel,
+{
+  /// Backend representation of a texture buffer.
+  type TextureBufferRepr;
+
+  /// Create a new texture buffer holding `texels`.
+  ///
+  /// Backends without texture buffers must return [`TextureError::UnsupportedTextureBuffer`].
+  unsafe fn new_texture_buffer(
+    &mut self,
+    texels: &[P::Encoding],
+  ) -> Result<Self::TextureBufferRepr, TextureError>;
+
+  /// Replace the texels of a texture buffer.
+  ///
+  /// The number of texels might differ from the current one, in which case the storage must be resized.
+  unsafe fn set_texture_buffer_texels(
+    texture_buffer: &mut Self::TextureBufferRepr,
+    texels: &[P::Encoding],
+  ) -> Result<(), TextureError>;
+}
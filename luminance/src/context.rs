@@ -30,41 +30,98 @@
 //!
 //! ```ignore
 //! use luminance::context::GraphicsContext as _;
-//! use luminance::buffer::Buffer;
+//! use luminance::tess::{Mode, TessBuilder};
 //!
-//! let buffer: Buffer<SomeBackendType, u8> = Buffer::from_slice(&mut context, slice).unwrap();
+//! let tess = TessBuilder::<SomeBackendType, _>::new(&mut context)
+//!   .set_vertices(vertices)
+//!   .set_mode(Mode::Triangle)
+//!   .build()
+//!   .unwrap();
 //! ```
 //!
 //! You can simply do:
 //!
 //! ```ignore
 //! use luminance::context::GraphicsContext as _;
+//! use luminance::tess::Mode;
 //!
-//! let buffer = context.new_buffer_from_slice(slice).unwrap();
+//! let tess = context
+//!   .new_tess()
+//!   .set_vertices(vertices)
+//!   .set_mode(Mode::Triangle)
+//!   .build()
+//!   .unwrap();
 //! ```
+//!
+//! Vertex, index and instance data are always given to [`TessBuilder`] as host memory and uploaded to the GPU by the
+//! backend: luminance doesn’t expose standalone GPU buffers.
+//!
+//! # Vertical synchronization
+//!
+//! Graphics contexts backed by a window can control how buffer swaps are synchronized with the display via
+//! [`GraphicsContext::set_vsync`]. See [`VSyncMode`] for the available modes.
+//!
+//! # Loading resources from other threads
+//!
+//! Graphics contexts cannot be used from other threads than the one they were created on. The
+//! [`resource_loader`] module allows to prepare resource data on worker threads and upload it on the graphics context
+//! thread.
+//!
+//! # Sharing the context with foreign code
+//!
+//! Backends cache the state of the graphics API. If other code uses the same context, call
+//! [`GraphicsContext::invalidate_state`] once it’s done, before using luminance again.
+//!
+//! The other way around, when luminance renders inside a larger application, [`GraphicsContext::state_guard`] saves
+//! the render state and restores it once the returned [`StateGuard`] is dropped, so that luminance doesn’t leave the
+//! viewport, scissor, blending or depth state modified.
+//!
+//! [`TessBuilder`]: crate::tess::TessBuilder
 
 use crate::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::Framebuffer as FramebufferBackend,
-    query::Query as QueryBackend,
+    error_check::ErrorCheck,
+    feedback::FeedbackBuffer as FeedbackBufferBackend,
+    framebuffer::{
+      DynamicFramebuffer as DynamicFramebufferBackend, Framebuffer as FramebufferBackend,
+      FramebufferBackBufferSrgb,
+    },
+    memory_barrier::MemoryBarrier as MemoryBarrierBackend,
+    query::{PipelineStatsQuery as PipelineStatsQueryBackend, Query as QueryBackend, QueryError},
     shader::{Shader, ShaderData as ShaderDataBackend},
-    tess::Tess as TessBackend,
-    texture::Texture as TextureBackend,
+    state::{InvalidateState, SaveState},
+    tess::{DynamicTess as DynamicTessBackend, Tess as TessBackend},
+    texture::{PixelAlignment, Texture as TextureBackend, TextureBuffer as TextureBufferBackend},
   },
   texture::TexelUpload,
 };
 use crate::{
-  framebuffer::{Framebuffer, FramebufferError},
+  blending::BlendingMode,
+  depth_stencil::{Comparison, Write},
+  error_check::BackendError,
+  feedback::{FeedbackBuffer, FeedbackError},
+  framebuffer::{DynamicFramebuffer, Framebuffer, FramebufferError},
+  memory_barrier::{MemoryBarrierBits, MemoryBarrierError},
   pipeline::PipelineGate,
-  pixel::Pixel,
-  query::Query,
+  pixel::{Pixel, PixelFormat},
+  query::{PipelineStatsQuery, Query},
+  scissor::ScissorRegion,
   shader::{ProgramBuilder, ShaderData, ShaderDataError, Stage, StageError, StageType},
-  tess::{Deinterleaved, Interleaved, TessBuilder, TessVertexData},
-  texture::{Dimensionable, Sampler, Texture, TextureError},
+  tess::{
+    Deinterleaved, DynamicTess, Interleaved, Mode, TessBuilder, TessError, TessIndex,
+    TessVertexData,
+  },
+  texture::{Dimensionable, Sampler, Texture, TextureBuffer, TextureError},
   vertex::Semantics,
 };
+use std::{
+  error, fmt,
+  ops::{Deref, DerefMut},
+};
+
+pub mod resource_loader;
 
 /// Class of graphics context.
 ///
@@ -86,6 +143,114 @@ pub unsafe trait GraphicsContext: Sized {
     Query::new(self)
   }
 
+  /// Enable or disable backend error checking.
+  ///
+  /// See the documentation of the [`error_check`](crate::error_check) module for further details.
+  fn set_error_checking(&mut self, enabled: bool)
+  where
+    Self::Backend: ErrorCheck,
+  {
+    self.backend().set_error_checking(enabled)
+  }
+
+  /// Whether backend error checking is currently enabled.
+  fn is_error_checking(&mut self) -> bool
+  where
+    Self::Backend: ErrorCheck,
+  {
+    self.backend().is_error_checking()
+  }
+
+  /// Take all the errors the backend recorded since the last call.
+  ///
+  /// If error checking is disabled, this will typically return an empty list.
+  fn take_backend_errors(&mut self) -> Vec<BackendError>
+  where
+    Self::Backend: ErrorCheck,
+  {
+    self.backend().take_errors()
+  }
+
+  /// Invalidate the state cached by the backend.
+  ///
+  /// Backends cache the state of the underlying graphics API to avoid redundant calls. When other code uses the same
+  /// graphics API context — another renderer, a GUI library, etc. — that cache gets stale, and luminance could skip
+  /// calls that are actually needed. Call this function after such foreign code ran and before using luminance again,
+  /// so that the next operations issue every setting and binding they need.
+  fn invalidate_state(&mut self)
+  where
+    Self::Backend: InvalidateState,
+  {
+    unsafe { self.backend().invalidate_state() }
+  }
+
+  /// Save the render state and get a [`StateGuard`] restoring it when dropped.
+  ///
+  /// The guard gives access to the graphics context, so that rendering can happen while the state is saved. See
+  /// [`StateSnapshot`] for the saved state.
+  fn state_guard(&mut self) -> StateGuard<'_, Self>
+  where
+    Self::Backend: SaveState,
+  {
+    StateGuard::new(self)
+  }
+
+  /// Set the vertical synchronization mode used when swapping buffers.
+  ///
+  /// The actually applied [`VSyncMode`] is returned, as [`VSyncMode::Adaptive`] falls back to [`VSyncMode::On`] when
+  /// late swap tearing is not supported.
+  ///
+  /// The default implementation returns [`VSyncError::Unsupported`]; graphics contexts able to control their swap
+  /// interval override it.
+  fn set_vsync(&mut self, mode: VSyncMode) -> Result<VSyncMode, VSyncError> {
+    Err(VSyncError::unsupported(mode))
+  }
+
+  /// Force the row alignment of texels read back from textures (`pack`) and uploaded to textures (`unpack`).
+  ///
+  /// By default — or when set to [`None`] — the alignment is picked for each transfer from the byte size of a row, so
+  /// that tightly packed texels, such as RGB8 texels of any width, are transferred correctly. Forcing an alignment is
+  /// useful when working with texels which rows are padded: texels uploaded with [`Texture::upload_raw`] must then have
+  /// their rows padded to the unpack alignment, and [`Texture::get_raw_texels`] returns rows padded to the pack
+  /// alignment.
+  ///
+  /// Alignments must be `1`, `2`, `4` or `8`, otherwise [`TextureError::InvalidPixelAlignment`] is returned.
+  fn set_pixel_alignment(
+    &mut self,
+    pack: Option<usize>,
+    unpack: Option<usize>,
+  ) -> Result<(), TextureError>
+  where
+    Self::Backend: PixelAlignment,
+  {
+    unsafe { self.backend().set_pixel_alignment(pack, unpack) }
+  }
+
+  /// Issue a memory barrier.
+  ///
+  /// Incoherent writes made by the previous renders become visible to the later reads selected by `bits`. See the
+  /// documentation of the [`memory_barrier`](crate::memory_barrier) module for further details.
+  fn memory_barrier(&mut self, bits: MemoryBarrierBits) -> Result<(), MemoryBarrierError>
+  where
+    Self::Backend: MemoryBarrierBackend,
+  {
+    unsafe { self.backend().memory_barrier(bits) }
+  }
+
+  /// Whether the default framebuffer — i.e. the back buffer — stores sRGB-encoded colors.
+  ///
+  /// The platform decides whether the default framebuffer is sRGB-capable when creating it. When it is, enabling
+  /// [`PipelineState::srgb_enabled`] lets the pipeline encode shader outputs; otherwise, shaders must apply gamma
+  /// correction themselves. Checking this avoids applying gamma correction twice, or not at all.
+  ///
+  /// [`PipelineState::srgb_enabled`]: crate::pipeline::PipelineState::srgb_enabled
+  fn default_framebuffer_is_srgb(&mut self) -> bool
+  where
+    Self::Backend: FramebufferBackBufferSrgb,
+  {
+    unsafe { self.backend().back_buffer_is_srgb() }
+  }
+
   /// Create a new pipeline gate
   fn new_pipeline_gate(&mut self) -> PipelineGate<Self::Backend> {
     PipelineGate::new(self)
@@ -109,6 +274,32 @@ pub unsafe trait GraphicsContext: Sized {
     Framebuffer::new(self, size, mipmaps, sampler)
   }
 
+  /// Create a new framebuffer which attachment formats are picked at runtime.
+  ///
+  /// See the documentation of [`DynamicFramebuffer::new`] for further details.
+  fn new_dynamic_framebuffer<D>(
+    &mut self,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: Sampler,
+    color_formats: Vec<PixelFormat>,
+    depth_stencil_format: Option<PixelFormat>,
+  ) -> Result<DynamicFramebuffer<Self::Backend, D>, FramebufferError>
+  where
+    Self::Backend: DynamicFramebufferBackend<D>,
+    D: Dimensionable,
+    D::Size: Copy,
+  {
+    DynamicFramebuffer::new(
+      self,
+      size,
+      mipmaps,
+      sampler,
+      color_formats,
+      depth_stencil_format,
+    )
+  }
+
   /// Create a new shader stage.
   ///
   /// See the documentation of [`Stage::new`] for further details.
@@ -135,6 +326,30 @@ pub unsafe trait GraphicsContext: Sized {
     ProgramBuilder::new(self)
   }
 
+  /// Create a new feedback buffer holding `len` items.
+  ///
+  /// See the documentation of [`FeedbackBuffer::new`] for further details.
+  fn new_feedback_buffer<T>(
+    &mut self,
+    len: usize,
+  ) -> Result<FeedbackBuffer<Self::Backend, T>, FeedbackError>
+  where
+    Self::Backend: FeedbackBufferBackend<T>,
+    T: Copy + Default,
+  {
+    FeedbackBuffer::new(self, len)
+  }
+
+  /// Create a new pipeline statistics query.
+  ///
+  /// See the documentation of [`PipelineStatsQuery`] for further details.
+  fn new_pipeline_stats_query(&mut self) -> Result<PipelineStatsQuery<Self::Backend>, QueryError>
+  where
+    Self::Backend: PipelineStatsQueryBackend,
+  {
+    PipelineStatsQuery::new(self)
+  }
+
   /// Create a new shader data.
   ///
   /// See the documentation of [`ShaderData::new`] for further details.
@@ -170,6 +385,21 @@ pub unsafe trait GraphicsContext: Sized {
     TessBuilder::new(self)
   }
 
+  /// Create an empty [`DynamicTess`] which data is streamed every frame.
+  ///
+  /// See the documentation of [`DynamicTess::new`] for further details.
+  fn new_dynamic_tess<V, I>(
+    &mut self,
+    mode: Mode,
+  ) -> Result<DynamicTess<Self::Backend, V, I>, TessError>
+  where
+    Self::Backend: DynamicTessBackend<V, I>,
+    V: TessVertexData<Interleaved>,
+    I: TessIndex,
+  {
+    DynamicTess::new(self, mode)
+  }
+
   /// Create a new texture from texels.
   ///
   /// Feel free to have a look at the documentation of [`Texture::new`] for further details.
@@ -203,4 +433,256 @@ pub unsafe trait GraphicsContext: Sized {
   {
     Texture::new_raw(self, size, sampler, texels)
   }
+
+  /// Create a new texture buffer from texels.
+  ///
+  /// Feel free to have a look at the documentation of [`TextureBuffer::new`] for further details.
+  fn new_texture_buffer<P>(
+    &mut self,
+    texels: &[P::Encoding],
+  ) -> Result<TextureBuffer<Self::Backend, P>, TextureError>
+  where
+    Self::Backend: TextureBufferBackend<P>,
+    P: Pixel,
+  {
+    TextureBuffer::new(self, texels)
+  }
+}
+
+/// Render state saved by a [`StateGuard`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StateSnapshot {
+  /// Viewport, as `[x, y, width, height]`.
+  pub viewport: [i32; 4],
+  /// Whether the scissor test is enabled.
+  pub scissor_test: bool,
+  /// Scissor region, used when the scissor test is enabled.
+  pub scissor_region: ScissorRegion,
+  /// Whether blending is enabled.
+  pub blending: bool,
+  /// Blending equations and factors, used when blending is enabled.
+  pub blending_mode: BlendingMode,
+  /// Whether the depth test is enabled.
+  pub depth_test: bool,
+  /// Depth test comparison, used when the depth test is enabled.
+  pub depth_test_comparison: Comparison,
+  /// Depth write.
+  pub depth_write: Write,
+}
+
+/// Guard restoring the render state when dropped.
+///
+/// Obtained with [`GraphicsContext::state_guard`]. The guard dereferences to the graphics context, so it can be used
+/// to render as usual.
+pub struct StateGuard<'a, C>
+where
+  C: GraphicsContext,
+  C::Backend: SaveState,
+{
+  ctx: &'a mut C,
+  snapshot: StateSnapshot,
+}
+
+impl<'a, C> StateGuard<'a, C>
+where
+  C: GraphicsContext,
+  C::Backend: SaveState,
+{
+  fn new(ctx: &'a mut C) -> Self {
+    let snapshot = unsafe { ctx.backend().save_state() };
+    StateGuard { ctx, snapshot }
+  }
+
+  /// Render state that will be restored when the guard is dropped.
+  pub fn snapshot(&self) -> &StateSnapshot {
+    &self.snapshot
+  }
+}
+
+impl<'a, C> Deref for StateGuard<'a, C>
+where
+  C: GraphicsContext,
+  C::Backend: SaveState,
+{
+  type Target = C;
+
+  fn deref(&self) -> &Self::Target {
+    self.ctx
+  }
+}
+
+impl<'a, C> DerefMut for StateGuard<'a, C>
+where
+  C: GraphicsContext,
+  C::Backend: SaveState,
+{
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    self.ctx
+  }
+}
+
+impl<'a, C> Drop for StateGuard<'a, C>
+where
+  C: GraphicsContext,
+  C::Backend: SaveState,
+{
+  fn drop(&mut self) {
+    unsafe { self.ctx.backend().restore_state(&self.snapshot) };
+  }
+}
+
+/// Vertical synchronization mode.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VSyncMode {
+  /// Buffers are swapped as soon as possible, without waiting for the vertical blank. Tearing might occur.
+  Off,
+  /// Buffer swaps wait for the vertical blank.
+  On,
+  /// Buffer swaps wait for the vertical blank, unless the frame is late, in which case the swap happens immediately.
+  ///
+  /// This is also known as late swap tearing.
+  Adaptive,
+}
+
+impl VSyncMode {
+  /// Swap interval corresponding to this mode, as expected by the various windowing APIs.
+  ///
+  /// [`VSyncMode::Off`] maps to `0`, [`VSyncMode::On`] to `1` and [`VSyncMode::Adaptive`] to `-1`.
+  pub fn swap_interval(self) -> i32 {
+    match self {
+      VSyncMode::Off => 0,
+      VSyncMode::On => 1,
+      VSyncMode::Adaptive => -1,
+    }
+  }
+
+  /// Apply this mode with a function setting the swap interval.
+  ///
+  /// `set_swap_interval` is called with [`VSyncMode::swap_interval`]. If it fails for [`VSyncMode::Adaptive`], it is
+  /// called again for [`VSyncMode::On`]. The mode that was eventually applied is returned.
+  ///
+  /// This is a helper for graphics contexts implementing [`GraphicsContext::set_vsync`].
+  pub fn apply<E>(
+    self,
+    mut set_swap_interval: impl FnMut(i32) -> Result<(), E>,
+  ) -> Result<VSyncMode, E> {
+    match set_swap_interval(self.swap_interval()) {
+      Ok(()) => Ok(self),
+
+      Err(_) if self == VSyncMode::Adaptive => {
+        set_swap_interval(VSyncMode::On.swap_interval())?;
+        Ok(VSyncMode::On)
+      }
+
+      Err(e) => Err(e),
+    }
+  }
+}
+
+impl fmt::Display for VSyncMode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      VSyncMode::Off => f.write_str("off"),
+      VSyncMode::On => f.write_str("on"),
+      VSyncMode::Adaptive => f.write_str("adaptive"),
+    }
+  }
+}
+
+/// Errors that might occur when setting the [`VSyncMode`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VSyncError {
+  /// The graphics context cannot control vertical synchronization.
+  Unsupported(VSyncMode),
+
+  /// The swap interval could not be set.
+  SwapIntervalFailed {
+    /// Swap interval that was requested.
+    interval: i32,
+    /// Reason of the failure.
+    reason: String,
+  },
+}
+
+impl VSyncError {
+  /// The graphics context cannot control vertical synchronization.
+  pub fn unsupported(mode: VSyncMode) -> Self {
+    VSyncError::Unsupported(mode)
+  }
+
+  /// The swap interval could not be set.
+  pub fn swap_interval_failed(interval: i32, reason: impl Into<String>) -> Self {
+    VSyncError::SwapIntervalFailed {
+      interval,
+      reason: reason.into(),
+    }
+  }
+}
+
+impl fmt::Display for VSyncError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      VSyncError::Unsupported(mode) => {
+        write!(
+          f,
+          "cannot set vsync to {}: unsupported by the graphics context",
+          mode
+        )
+      }
+
+      VSyncError::SwapIntervalFailed {
+        interval,
+        ref reason,
+      } => write!(f, "cannot set swap interval to {}: {}", interval, reason),
+    }
+  }
+}
+
+impl error::Error for VSyncError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn vsync_swap_intervals() {
+    for &(mode, interval) in &[
+      (VSyncMode::Off, 0),
+      (VSyncMode::On, 1),
+      (VSyncMode::Adaptive, -1),
+    ] {
+      let mut calls = Vec::new();
+      let applied = mode.apply(|i| {
+        calls.push(i);
+        Ok::<_, ()>(())
+      });
+
+      assert_eq!(applied, Ok(mode));
+      assert_eq!(calls, [interval]);
+    }
+  }
+
+  #[test]
+  fn vsync_adaptive_fallback() {
+    let mut calls = Vec::new();
+    let applied = VSyncMode::Adaptive.apply(|i| {
+      calls.push(i);
+
+      if i < 0 {
+        Err("late swap tearing not supported")
+      } else {
+        Ok(())
+      }
+    });
+
+    assert_eq!(applied, Ok(VSyncMode::On));
+    assert_eq!(calls, [-1, 1]);
+  }
+
+  #[test]
+  fn vsync_failure() {
+    let applied = VSyncMode::Off.apply(|_| Err("no context"));
+    assert_eq!(applied, Err("no context"));
+  }
 }
@@ -0,0 +1,432 @@
+//! Off-thread resource loading.
+//!
+//! Graphics contexts are bound to a single thread, which prevents creating GPU resources from worker threads. A
+//! [`ResourceLoader`] allows to prepare resource _data_ — decoded images, generated meshes, etc. — on any thread and to
+//! send it to the thread owning the graphics context. That thread then calls [`ResourceLoader::process_pending`],
+//! typically once per frame, to upload the pending data and get the created resources back.
+//!
+//! Uploads can be spread over several frames with a per-call budget (see [`ResourceLoader::set_budget`]), so that
+//! loading a lot of resources at once doesn’t cause hitches. Data can’t be split across calls, so data costing more
+//! than the whole budget is still uploaded, on its own, and flagged with [`LoadedResource::over_budget`].
+//!
+//! Data is sent with a [`ResourceSender`], which can be cloned and moved to other threads. Each sent item gets a
+//! [`ResourceId`], which allows to match the resources returned by [`ResourceLoader::process_pending`] with the
+//! requests.
+//!
+//! Any type implementing [`Upload`] can be loaded; [`TextureData`] and [`TessData`] are provided for textures and
+//! meshes.
+
+use crate::{
+  backend::{tess::Tess as TessBackend, texture::Texture as TextureBackend},
+  context::GraphicsContext,
+  pixel::Pixel,
+  tess::{Interleaved, Mode, Tess, TessBuilder, TessError, TessIndex, TessVertexData},
+  texture::{Dimensionable, Sampler, TexelUpload, Texture, TextureError},
+};
+use std::{
+  collections::VecDeque,
+  error, fmt, mem,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc::{self, Receiver, Sender},
+    Arc,
+  },
+};
+
+/// Resource data that can be uploaded to the GPU by a graphics context.
+pub trait Upload<C>: Send
+where
+  C: GraphicsContext,
+{
+  /// Resource created by the upload.
+  type Resource;
+
+  /// Error that might occur while uploading.
+  type Error;
+
+  /// Cost of the upload, counted against the budget of [`ResourceLoader::process_pending`].
+  ///
+  /// This is typically the number of bytes to upload.
+  fn cost(&self) -> usize;
+
+  /// Upload the data and create the resource.
+  fn upload(self, ctx: &mut C) -> Result<Self::Resource, Self::Error>;
+}
+
+/// Texture data to be uploaded as a [`Texture`].
+#[derive(Debug)]
+pub struct TextureData<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  size: D::Size,
+  sampler: Sampler,
+  texels: Vec<P::Encoding>,
+  mipmaps: Option<usize>,
+}
+
+impl<D, P> TextureData<D, P>
+where
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Texture data of the given size, with the base level texels and no mipmaps.
+  pub fn new(size: D::Size, sampler: Sampler, texels: Vec<P::Encoding>) -> Self {
+    TextureData {
+      size,
+      sampler,
+      texels,
+      mipmaps: None,
+    }
+  }
+
+  /// Automatically create `mipmaps` mipmaps when uploading.
+  pub fn with_mipmaps(self, mipmaps: usize) -> Self {
+    TextureData {
+      mipmaps: Some(mipmaps),
+      ..self
+    }
+  }
+}
+
+impl<C, D, P> Upload<C> for TextureData<D, P>
+where
+  C: GraphicsContext,
+  C::Backend: TextureBackend<D, P>,
+  D: Dimensionable,
+  D::Size: Send,
+  P: Pixel,
+  P::Encoding: Send,
+{
+  type Resource = Texture<C::Backend, D, P>;
+
+  type Error = TextureError;
+
+  fn cost(&self) -> usize {
+    self.texels.len() * mem::size_of::<P::Encoding>()
+  }
+
+  fn upload(self, ctx: &mut C) -> Result<Self::Resource, Self::Error> {
+    let texels = TexelUpload::BaseLevel {
+      texels: self.texels.as_slice(),
+      mipmaps: self.mipmaps,
+    };
+
+    Texture::new(ctx, self.size, self.sampler, texels)
+  }
+}
+
+/// Interleaved vertices and indices to be uploaded as a [`Tess`].
+#[derive(Debug)]
+pub struct TessData<V, I = ()> {
+  mode: Mode,
+  vertices: Vec<V>,
+  indices: Vec<I>,
+}
+
+impl<V, I> TessData<V, I> {
+  /// Mesh data connecting `vertices` with `mode`, picked by `indices` if not empty.
+  pub fn new(mode: Mode, vertices: Vec<V>, indices: Vec<I>) -> Self {
+    TessData {
+      mode,
+      vertices,
+      indices,
+    }
+  }
+}
+
+impl<C, V, I> Upload<C> for TessData<V, I>
+where
+  C: GraphicsContext,
+  C::Backend: TessBackend<V, I, (), Interleaved>,
+  V: TessVertexData<Interleaved, Data = Vec<V>> + Send,
+  I: TessIndex + Send,
+{
+  type Resource = Tess<C::Backend, V, I>;
+
+  type Error = TessError;
+
+  fn cost(&self) -> usize {
+    self.vertices.len() * mem::size_of::<V>() + self.indices.len() * mem::size_of::<I>()
+  }
+
+  fn upload(self, ctx: &mut C) -> Result<Self::Resource, Self::Error> {
+    TessBuilder::<_, (), (), (), Interleaved>::new(ctx)
+      .set_vertices(self.vertices)
+      .set_indices(self.indices)
+      .set_mode(self.mode)
+      .build()
+  }
+}
+
+/// Identifier of data sent to a [`ResourceLoader`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ResourceId(u64);
+
+/// Resource created by [`ResourceLoader::process_pending`].
+#[derive(Debug)]
+pub struct LoadedResource<R, E> {
+  /// Identifier returned when the data was sent.
+  pub id: ResourceId,
+  /// Created resource, or the error that occurred while uploading its data.
+  pub result: Result<R, E>,
+  /// Whether the cost of the data alone exceeded the budget of the [`ResourceLoader`].
+  ///
+  /// Such data is uploaded on its own and is likely to cause a hitch; it should be split into smaller pieces, or the
+  /// budget raised.
+  pub over_budget: bool,
+}
+
+/// Errors that might occur when sending data to a [`ResourceLoader`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResourceLoaderError {
+  /// The [`ResourceLoader`] was dropped.
+  Disconnected,
+}
+
+impl ResourceLoaderError {
+  /// The [`ResourceLoader`] was dropped.
+  pub fn disconnected() -> Self {
+    ResourceLoaderError::Disconnected
+  }
+}
+
+impl fmt::Display for ResourceLoaderError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      ResourceLoaderError::Disconnected => f.write_str("resource loader disconnected"),
+    }
+  }
+}
+
+impl error::Error for ResourceLoaderError {}
+
+/// Sending end of a [`ResourceLoader`].
+///
+/// Senders can be cloned and moved to other threads.
+#[derive(Debug)]
+pub struct ResourceSender<T> {
+  sender: Sender<(ResourceId, T)>,
+  next_id: Arc<AtomicU64>,
+}
+
+impl<T> Clone for ResourceSender<T> {
+  fn clone(&self) -> Self {
+    ResourceSender {
+      sender: self.sender.clone(),
+      next_id: self.next_id.clone(),
+    }
+  }
+}
+
+impl<T> ResourceSender<T> {
+  /// Send data to be uploaded by the [`ResourceLoader`].
+  pub fn send(&self, data: T) -> Result<ResourceId, ResourceLoaderError> {
+    let id = ResourceId(self.next_id.fetch_add(1, Ordering::Relaxed));
+
+    self
+      .sender
+      .send((id, data))
+      .map(|_| id)
+      .map_err(|_| ResourceLoaderError::disconnected())
+  }
+}
+
+/// Resource loader.
+///
+/// See the documentation of the [`resource_loader`](crate::context::resource_loader) module for further details.
+#[derive(Debug)]
+pub struct ResourceLoader<T> {
+  sender: ResourceSender<T>,
+  receiver: Receiver<(ResourceId, T)>,
+  pending: VecDeque<(ResourceId, T)>,
+  budget: Option<usize>,
+}
+
+impl<T> Default for ResourceLoader<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T> ResourceLoader<T> {
+  /// Create a new [`ResourceLoader`], without budget.
+  pub fn new() -> Self {
+    let (sender, receiver) = mpsc::channel();
+
+    ResourceLoader {
+      sender: ResourceSender {
+        sender,
+        next_id: Arc::new(AtomicU64::new(0)),
+      },
+      receiver,
+      pending: VecDeque::new(),
+      budget: None,
+    }
+  }
+
+  /// Set the maximum cost of the uploads done by a single call to [`ResourceLoader::process_pending`].
+  ///
+  /// `None` removes the budget.
+  pub fn set_budget(&mut self, budget: impl Into<Option<usize>>) {
+    self.budget = budget.into();
+  }
+
+  /// Maximum cost of the uploads done by a single call to [`ResourceLoader::process_pending`].
+  pub fn budget(&self) -> Option<usize> {
+    self.budget
+  }
+
+  /// Get a new sender to send data to this loader.
+  pub fn sender(&self) -> ResourceSender<T> {
+    self.sender.clone()
+  }
+
+  /// Number of items received but not uploaded yet.
+  ///
+  /// Items still in flight from other threads are not counted.
+  pub fn pending_nb(&mut self) -> usize {
+    self.receive();
+    self.pending.len()
+  }
+
+  /// Upload pending data and return the created resources, in the order the data was sent.
+  ///
+  /// Uploads stop as soon as the next one would exceed the budget; the remaining data is uploaded by the next calls.
+  /// The first pending upload is always done, even if its cost alone exceeds the budget; the returned resource is then
+  /// flagged with [`LoadedResource::over_budget`].
+  pub fn process_pending<C>(&mut self, ctx: &mut C) -> Vec<LoadedResource<T::Resource, T::Error>>
+  where
+    C: GraphicsContext,
+    T: Upload<C>,
+  {
+    self.receive();
+
+    let mut spent = 0;
+    let mut loaded = Vec::new();
+
+    while let Some((_, data)) = self.pending.front() {
+      let cost = data.cost();
+
+      if let Some(budget) = self.budget {
+        if !loaded.is_empty() && spent + cost > budget {
+          break;
+        }
+      }
+
+      spent += cost;
+      let over_budget = self.budget.map_or(false, |budget| cost > budget);
+
+      if let Some((id, data)) = self.pending.pop_front() {
+        loaded.push(LoadedResource {
+          id,
+          result: data.upload(ctx),
+          over_budget,
+        });
+      }
+    }
+
+    loaded
+  }
+
+  // move the received data to the pending queue
+  fn receive(&mut self) {
+    self.pending.extend(self.receiver.try_iter());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::thread;
+
+  struct Context(());
+
+  unsafe impl GraphicsContext for Context {
+    type Backend = ();
+
+    fn backend(&mut self) -> &mut Self::Backend {
+      &mut self.0
+    }
+  }
+
+  // fake upload that just returns its content
+  struct Data(usize);
+
+  impl Upload<Context> for Data {
+    type Resource = usize;
+
+    type Error = ();
+
+    fn cost(&self) -> usize {
+      self.0
+    }
+
+    fn upload(self, _: &mut Context) -> Result<Self::Resource, Self::Error> {
+      Ok(self.0)
+    }
+  }
+
+  fn uploaded(loaded: Vec<LoadedResource<usize, ()>>) -> Vec<usize> {
+    loaded.into_iter().map(|r| r.result.unwrap()).collect()
+  }
+
+  #[test]
+  fn resource_loader_from_thread() {
+    let mut loader = ResourceLoader::new();
+    let sender = loader.sender();
+
+    let ids = thread::spawn(move || {
+      (1..=3)
+        .map(|i| sender.send(Data(i)).unwrap())
+        .collect::<Vec<_>>()
+    })
+    .join()
+    .unwrap();
+
+    let loaded = loader.process_pending(&mut Context(()));
+    assert_eq!(loaded.iter().map(|r| r.id).collect::<Vec<_>>(), ids);
+    assert_eq!(uploaded(loaded), [1, 2, 3]);
+    assert_eq!(loader.pending_nb(), 0);
+  }
+
+  #[test]
+  fn resource_loader_budget() {
+    let mut loader = ResourceLoader::new();
+    loader.set_budget(10);
+
+    let sender = loader.sender();
+    for cost in [4, 5, 2, 20, 1] {
+      sender.send(Data(cost)).unwrap();
+    }
+
+    assert_eq!(uploaded(loader.process_pending(&mut Context(()))), [4, 5]);
+    assert_eq!(loader.pending_nb(), 3);
+
+    // an upload exceeding the budget is done on its own, and flagged
+    let loaded = loader.process_pending(&mut Context(()));
+    assert!(loaded.iter().all(|r| !r.over_budget));
+    assert_eq!(uploaded(loaded), [2]);
+
+    let loaded = loader.process_pending(&mut Context(()));
+    assert!(loaded.iter().all(|r| r.over_budget));
+    assert_eq!(uploaded(loaded), [20]);
+
+    assert_eq!(uploaded(loader.process_pending(&mut Context(()))), [1]);
+    assert!(loader.process_pending(&mut Context(())).is_empty());
+  }
+
+  #[test]
+  fn resource_loader_disconnected() {
+    let loader = ResourceLoader::<Data>::new();
+    let sender = loader.sender();
+    drop(loader);
+
+    assert_eq!(
+      sender.send(Data(1)).err(),
+      Some(ResourceLoaderError::disconnected())
+    );
+  }
+}
@@ -0,0 +1,165 @@
+//! Backend error checking.
+//!
+//! Most of the time, a backend silently swallows the errors the underlying technology produces — for instance, an
+//! OpenGL backend won’t call `glGetError` after each call because it would be way too costly. When debugging, however,
+//! you might want to know that something went wrong and _where_.
+//!
+//! Error checking can be toggled on a per-context basis via [`GraphicsContext::set_error_checking`]. When enabled,
+//! the backend checks for errors after each significant operation (pipeline start, render, resource creation, etc.) and
+//! records them along with some context describing the operation. Errors are then retrieved with
+//! [`GraphicsContext::take_backend_errors`]. Backends are free to use asynchronous reporting mechanisms (debug
+//! callbacks, for instance), so errors might be reported a bit after the operation that caused them.
+//!
+//! The default is backend-specific, but backends are encouraged to enable error checking in debug builds and disable
+//! it in release builds. Backends record errors into an [`ErrorLog`], which only keeps a bounded number of them between
+//! two calls to [`GraphicsContext::take_backend_errors`], so that applications never taking them don’t leak memory.
+//!
+//! [`GraphicsContext::set_error_checking`]: crate::context::GraphicsContext::set_error_checking
+//! [`GraphicsContext::take_backend_errors`]: crate::context::GraphicsContext::take_backend_errors
+
+use std::{error, fmt};
+
+/// Error reported by a backend while error checking is enabled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BackendError {
+  /// Operation during which the error was detected.
+  pub context: String,
+  /// Kind of error.
+  pub kind: BackendErrorKind,
+}
+
+impl BackendError {
+  /// Create a new [`BackendError`].
+  pub fn new(context: impl Into<String>, kind: BackendErrorKind) -> Self {
+    BackendError {
+      context: context.into(),
+      kind,
+    }
+  }
+}
+
+impl fmt::Display for BackendError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} (during {})", self.kind, self.context)
+  }
+}
+
+impl error::Error for BackendError {}
+
+/// Kind of error reported by a backend.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BackendErrorKind {
+  /// An enumeration argument was out of range.
+  InvalidEnum,
+  /// A numeric argument was out of range.
+  InvalidValue,
+  /// The operation is not allowed in the current state.
+  InvalidOperation,
+  /// The currently bound framebuffer is not complete.
+  InvalidFramebufferOperation,
+  /// Not enough memory left to execute the operation.
+  OutOfMemory,
+  /// An operation would have caused an internal stack to overflow.
+  StackOverflow,
+  /// An operation would have caused an internal stack to underflow.
+  StackUnderflow,
+  /// The context was lost.
+  ContextLost,
+  /// A feature was requested but is not supported by the backend.
+  Unsupported(String),
+  /// Message reported by a debug output mechanism of the backend.
+  DebugMessage(String),
+  /// That many errors were dropped because the [`ErrorLog`] was full.
+  ErrorsDropped(usize),
+  /// Unknown error code.
+  Unknown(u32),
+}
+
+impl fmt::Display for BackendErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      BackendErrorKind::InvalidEnum => f.write_str("invalid enum"),
+      BackendErrorKind::InvalidValue => f.write_str("invalid value"),
+      BackendErrorKind::InvalidOperation => f.write_str("invalid operation"),
+      BackendErrorKind::InvalidFramebufferOperation => f.write_str("invalid framebuffer operation"),
+      BackendErrorKind::OutOfMemory => f.write_str("out of memory"),
+      BackendErrorKind::StackOverflow => f.write_str("stack overflow"),
+      BackendErrorKind::StackUnderflow => f.write_str("stack underflow"),
+      BackendErrorKind::ContextLost => f.write_str("context lost"),
+      BackendErrorKind::Unsupported(ref feature) => write!(f, "unsupported feature: {}", feature),
+      BackendErrorKind::DebugMessage(ref msg) => write!(f, "debug message: {}", msg),
+      BackendErrorKind::ErrorsDropped(nb) => write!(f, "{} more errors dropped", nb),
+      BackendErrorKind::Unknown(code) => write!(f, "unknown error code: {:#x}", code),
+    }
+  }
+}
+
+/// Bounded log of backend errors.
+///
+/// Only the first [`ErrorLog::CAPACITY`] errors are kept between two calls to [`ErrorLog::take`]; the next ones are
+/// counted and reported as a single [`BackendErrorKind::ErrorsDropped`] error.
+#[derive(Debug, Default)]
+pub struct ErrorLog {
+  errors: Vec<BackendError>,
+  dropped: usize,
+}
+
+impl ErrorLog {
+  /// Maximum number of errors kept between two calls to [`ErrorLog::take`].
+  pub const CAPACITY: usize = 256;
+
+  /// Record an error, or count it as dropped if the log is full.
+  pub fn push(&mut self, error: BackendError) {
+    if self.errors.len() < Self::CAPACITY {
+      self.errors.push(error);
+    } else {
+      self.dropped += 1;
+    }
+  }
+
+  /// Take all the errors recorded so far, followed by a [`BackendErrorKind::ErrorsDropped`] error if some were
+  /// dropped.
+  pub fn take(&mut self) -> Vec<BackendError> {
+    let mut errors = std::mem::take(&mut self.errors);
+
+    if self.dropped > 0 {
+      errors.push(BackendError::new(
+        "error log",
+        BackendErrorKind::ErrorsDropped(self.dropped),
+      ));
+      self.dropped = 0;
+    }
+
+    errors
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn error_log_capacity() {
+    let mut log = ErrorLog::default();
+
+    for _ in 0..ErrorLog::CAPACITY + 3 {
+      log.push(BackendError::new("test", BackendErrorKind::InvalidValue));
+    }
+
+    let errors = log.take();
+    assert_eq!(errors.len(), ErrorLog::CAPACITY + 1);
+    assert_eq!(
+      errors.last().map(|e| &e.kind),
+      Some(&BackendErrorKind::ErrorsDropped(3))
+    );
+
+    // the log is usable again once taken
+    log.push(BackendError::new("test", BackendErrorKind::InvalidEnum));
+    assert_eq!(
+      log.take(),
+      [BackendError::new("test", BackendErrorKind::InvalidEnum)]
+    );
+    assert!(log.take().is_empty());
+  }
+}
@@ -1,5 +1,13 @@
 //! Face culling is the operation of removing triangles if they’re facing the screen in a specific
 //! direction with a specific mode.
+//!
+//! Face culling is configured with three independent parts:
+//!
+//! - Whether it’s enabled at all: a [`RenderState`] with no [`FaceCulling`] doesn’t cull anything.
+//! - The [`FaceCullingOrder`], deciding which side of a triangle is its front side.
+//! - The [`FaceCullingMode`], deciding which sides are culled.
+//!
+//! [`RenderState`]: crate::render_state::RenderState
 
 /// Face culling setup.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -27,6 +35,20 @@ impl Default for FaceCulling {
   }
 }
 
+/// Enable face culling with the given mode and the [`FaceCullingOrder::CCW`] order.
+impl From<FaceCullingMode> for FaceCulling {
+  fn from(mode: FaceCullingMode) -> Self {
+    FaceCulling::new(FaceCullingOrder::CCW, mode)
+  }
+}
+
+/// Enable face culling with the given mode and the [`FaceCullingOrder::CCW`] order.
+impl From<FaceCullingMode> for Option<FaceCulling> {
+  fn from(mode: FaceCullingMode) -> Self {
+    Some(mode.into())
+  }
+}
+
 /// Face culling order.
 ///
 /// The order determines how a triangle is determined to be discarded. If the triangle’s vertices
@@ -47,6 +69,15 @@ pub enum FaceCullingMode {
   Front,
   /// Cull the back side only.
   Back,
-  /// Always cull any triangle.
-  Both,
+  /// Cull both sides, which discards every triangle.
+  ///
+  /// This is a cheap way to skip rasterizing a mesh while still running its vertex processing.
+  FrontAndBack,
+}
+
+impl FaceCullingMode {
+  /// Cull both sides.
+  #[allow(non_upper_case_globals)]
+  #[deprecated(note = "use FaceCullingMode::FrontAndBack")]
+  pub const Both: Self = FaceCullingMode::FrontAndBack;
 }
@@ -0,0 +1,124 @@
+//! Transform feedback.
+//!
+//! Transform feedback captures the outputs of the last vertex processing stage — vertex, tessellation evaluation or
+//! geometry shader — into GPU buffers, instead of (or along with) rasterizing them. The outputs to capture, called
+//! _feedback varyings_, must be declared before the shader program is linked, with
+//! [`ProgramBuilder::with_feedback_varyings`]. They are then captured into [`FeedbackBuffer`]s with
+//! [`TessGate::render_feedback`].
+//!
+//! [`ProgramBuilder::with_feedback_varyings`]: crate::shader::ProgramBuilder::with_feedback_varyings
+//! [`TessGate::render_feedback`]: crate::tess_gate::TessGate::render_feedback
+
+use crate::{
+  backend::feedback::FeedbackBuffer as FeedbackBufferBackend, context::GraphicsContext, tess::Mode,
+};
+use std::{error, fmt, marker::PhantomData};
+
+/// How feedback varyings are laid out in feedback buffers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FeedbackMode {
+  /// All the varyings are captured into a single buffer, one vertex after the other.
+  Interleaved,
+  /// Each varying is captured into its own buffer, in the order the varyings were declared.
+  Separate,
+}
+
+/// Possible errors that might occur with transform feedback.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeedbackError {
+  /// Cannot create a feedback buffer.
+  CannotCreate(String),
+  /// Cannot read back a feedback buffer.
+  CannotRead(String),
+  /// The primitive mode of the rendered tessellation cannot be captured.
+  UnsupportedMode(Mode),
+}
+
+impl FeedbackError {
+  /// Cannot create a feedback buffer.
+  pub fn cannot_create(reason: impl Into<String>) -> Self {
+    FeedbackError::CannotCreate(reason.into())
+  }
+
+  /// Cannot read back a feedback buffer.
+  pub fn cannot_read(reason: impl Into<String>) -> Self {
+    FeedbackError::CannotRead(reason.into())
+  }
+
+  /// The primitive mode of the rendered tessellation cannot be captured.
+  pub fn unsupported_mode(mode: Mode) -> Self {
+    FeedbackError::UnsupportedMode(mode)
+  }
+}
+
+impl fmt::Display for FeedbackError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FeedbackError::CannotCreate(ref reason) => {
+        write!(f, "cannot create feedback buffer: {}", reason)
+      }
+      FeedbackError::CannotRead(ref reason) => write!(f, "cannot read feedback buffer: {}", reason),
+      FeedbackError::UnsupportedMode(mode) => {
+        write!(f, "cannot capture primitives of mode {}", mode)
+      }
+    }
+  }
+}
+
+impl error::Error for FeedbackError {}
+
+/// GPU buffer receiving captured feedback varyings.
+///
+/// A [`FeedbackBuffer`] holds a fixed number of items of type `T`, which must match the layout of the captured
+/// varyings — for instance, `[f32; 4]` for a single `vec4` varying captured in [`FeedbackMode::Interleaved`] mode.
+/// Captures stop once the buffer is full.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `T` is the type of the captured items.
+pub struct FeedbackBuffer<B, T>
+where
+  B: ?Sized + FeedbackBufferBackend<T>,
+  T: Copy + Default,
+{
+  pub(crate) repr: B::FeedbackBufferRepr,
+  len: usize,
+  _phantom: PhantomData<T>,
+}
+
+impl<B, T> FeedbackBuffer<B, T>
+where
+  B: ?Sized + FeedbackBufferBackend<T>,
+  T: Copy + Default,
+{
+  /// Create a [`FeedbackBuffer`] able to hold `len` items, initialized with [`Default::default`].
+  pub fn new(
+    ctx: &mut impl GraphicsContext<Backend = B>,
+    len: usize,
+  ) -> Result<Self, FeedbackError> {
+    let repr = unsafe { ctx.backend().new_feedback_buffer(len)? };
+
+    Ok(FeedbackBuffer {
+      repr,
+      len,
+      _phantom: PhantomData,
+    })
+  }
+
+  /// Number of items the buffer holds.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the buffer holds no item.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Read back the content of the buffer.
+  pub fn read(&self) -> Result<Vec<T>, FeedbackError> {
+    unsafe { B::read_feedback_buffer(&self.repr) }
+  }
+}
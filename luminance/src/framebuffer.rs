@@ -63,16 +63,27 @@
 //! [backend::depth_slot]: crate::backend::depth_slot
 //! [`PipelineGate`]: crate::pipeline::PipelineGate
 
-use std::{error, fmt};
+use std::{error, fmt, ops::Deref};
 
 use crate::{
   backend::{
-    color_slot::ColorSlot,
+    color_slot::{ColorSlot, ColorSlotIndex},
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::{Framebuffer as FramebufferBackend, FramebufferBackBuffer},
+    framebuffer::{
+      DynamicFramebuffer as DynamicFramebufferBackend, Framebuffer as FramebufferBackend,
+      FramebufferBackBuffer, FramebufferDepthCopy, FramebufferRawHandle, FramebufferSharedDepth,
+    },
+    pipeline::Pipeline as PipelineBackend,
+    texture::Texture as TextureBackend,
   },
   context::GraphicsContext,
-  texture::{Dim2, Dimensionable, Sampler, TextureError},
+  pipeline::{PipelineError, PipelineGate, PipelineState},
+  pixel::{
+    linearize_depth, ColorPixel, Depth32F, DepthProjection, Format, NormDepthPixel, Pixel,
+    PixelFormat, RenderablePixel,
+  },
+  scissor::ScissorRegion,
+  texture::{Dim2, Dim3, Dimensionable, Sampler, Texture, TextureError},
 };
 
 /// Typed framebuffers.
@@ -95,6 +106,7 @@ where
   pub(crate) repr: B::FramebufferRepr,
   color_slot: CS::ColorTextures,
   depth_stencil_slot: DS::DepthStencilTexture,
+  back_buffer: bool,
 }
 
 impl<B, D, CS, DS> Framebuffer<B, D, CS, DS>
@@ -143,6 +155,7 @@ where
         repr,
         color_slot,
         depth_stencil_slot: depth_slot,
+        back_buffer: false,
       })
     }
   }
@@ -152,11 +165,57 @@ where
     unsafe { B::framebuffer_size(&self.repr) }
   }
 
+  /// Whether this [`Framebuffer`] is the _back buffer_ returned by [`Framebuffer::back_buffer`].
+  ///
+  /// The back buffer is owned by the system: it has no color nor depth / stencil textures — its slots are `()` — so
+  /// it cannot be bound as a texture, and texture attachments cannot be added to it.
+  pub fn is_back_buffer(&self) -> bool {
+    self.back_buffer
+  }
+
+  /// Invalidate the content of some attachments.
+  ///
+  /// Invalidating an attachment tells the backend its content is not needed anymore, so that it doesn’t have to be
+  /// preserved. This saves memory bandwidth on tile-based GPUs, which would otherwise write it back to memory; a
+  /// typical use is invalidating the depth attachment once a frame is rendered, or after a depth prepass if depth is
+  /// not read back. The content of invalidated attachments is undefined until they’re rendered to or cleared again.
+  ///
+  /// On the back buffer, [`Attachment::Color`] must be `0` and refers to the color buffer of the back buffer.
+  ///
+  /// This is only a hint; backends which cannot invalidate attachments don’t do anything.
+  pub fn invalidate(&mut self, attachments: &[Attachment]) -> Result<(), FramebufferError> {
+    unsafe { B::invalidate_framebuffer(&mut self.repr, attachments) }
+  }
+
+  /// Number of color attachments of the framebuffer.
+  ///
+  /// A `()` color slot has no attachment, a single texture has one and a tuple of textures has as many as its size.
+  pub fn color_slot_count(&self) -> usize {
+    CS::color_formats().len()
+  }
+
   /// Access the carried color slot.
   pub fn color_slot(&mut self) -> &mut CS::ColorTextures {
     &mut self.color_slot
   }
 
+  /// Access the `N`-th color attachment of the carried color slot.
+  ///
+  /// The index is checked at compile-time: trying to access an attachment that doesn’t exist fails to compile.
+  ///
+  /// ```ignore
+  /// let mut framebuffer = ctx.new_framebuffer::<Dim2, (NormRGBA8UI, R32F), ()>(size, 0, sampler)?;
+  /// let r32f_texture = framebuffer.color_slot_n::<1>();
+  /// ```
+  pub fn color_slot_n<const N: usize>(
+    &mut self,
+  ) -> &mut <CS::ColorTextures as ColorSlotIndex<N>>::Texture
+  where
+    CS::ColorTextures: ColorSlotIndex<N>,
+  {
+    self.color_slot.color_texture()
+  }
+
   /// Access the carried depth/stencil slot.
   pub fn depth_stencil_slot(&mut self) -> &mut DS::DepthStencilTexture {
     &mut self.depth_stencil_slot
@@ -178,11 +237,336 @@ where
   }
 }
 
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: FramebufferBackend<Dim2> + PipelineBackend<Dim2>,
+  CS: ColorSlot<B, Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Clear a rectangular region of the framebuffer, leaving the rest of it untouched.
+  ///
+  /// `color` and `depth` are the values to clear the color and depth buffers with; pass `None` to leave the
+  /// corresponding buffer untouched. The region is clamped to the size of the framebuffer.
+  ///
+  /// This is the same as running an empty [`PipelineGate`] with a scissored [`PipelineState`]; the scissor state is
+  /// set again by the next pipeline, so it doesn’t leak into subsequent renders.
+  pub fn clear_region<C>(
+    &self,
+    ctx: &mut C,
+    region: ScissorRegion,
+    color: impl Into<Option<[f32; 4]>>,
+    depth: impl Into<Option<f32>>,
+  ) -> Result<(), PipelineError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let [width, height] = self.size();
+    let x = region.x.min(width);
+    let y = region.y.min(height);
+    let region = ScissorRegion {
+      x,
+      y,
+      width: region.width.min(width - x),
+      height: region.height.min(height - y),
+    };
+
+    let pipeline_state = PipelineState::default()
+      .set_clear_color(color)
+      .set_clear_depth(depth)
+      .set_clear_stencil(None)
+      .set_scissor(region);
+
+    PipelineGate::new(ctx)
+      .pipeline(self, &pipeline_state, |_, _| Ok(()))
+      .into_result()
+  }
+}
+
+impl<B, DS> Framebuffer<B, Dim2, (), DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Attach the `z`-th slice of a 3D texture as the color attachment of the framebuffer.
+  ///
+  /// This allows to render into a single slice of a volume texture with a regular [`PipelineGate`], by rendering into
+  /// the returned [`FramebufferSlice`]. The framebuffer must have been created without color slot (`()`) and its size
+  /// should match the width and height of the texture. The texture is borrowed as long as the [`FramebufferSlice`]
+  /// lives and detached once it’s dropped, so you can sample it once you’re done rendering into it; call this function
+  /// again with another `z` to render into another slice.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::LayerOutOfBounds`] is returned if `z` is not smaller than the depth of the texture, and
+  /// [`FramebufferError::UnsupportedOnBackBuffer`] if the framebuffer is the back buffer.
+  pub fn attach_3d_slice<'a, P>(
+    &'a mut self,
+    texture: &'a Texture<B, Dim3, P>,
+    z: u32,
+  ) -> Result<FramebufferSlice<'a, B, DS>, FramebufferError>
+  where
+    B: TextureBackend<Dim3, P>,
+    P: ColorPixel + RenderablePixel,
+  {
+    if self.back_buffer {
+      return Err(FramebufferError::unsupported_on_back_buffer());
+    }
+
+    let [_, _, depth] = texture.size();
+
+    if z >= depth {
+      return Err(FramebufferError::layer_out_of_bounds(z, depth));
+    }
+
+    unsafe {
+      B::attach_color_texture_layer(&mut self.repr, &texture.repr, P::pixel_format(), 0, z)?;
+    }
+
+    Ok(FramebufferSlice { framebuffer: self })
+  }
+}
+
+/// A [`Framebuffer`] rendering into a slice of a 3D texture.
+///
+/// It’s created with [`Framebuffer::attach_3d_slice`] and dereferences to the framebuffer, so that it can be used with
+/// a [`PipelineGate`]. The slice is detached from the framebuffer when the [`FramebufferSlice`] is dropped.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type.
+/// - `DS` is the depth slot type of the framebuffer.
+pub struct FramebufferSlice<'a, B, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  framebuffer: &'a mut Framebuffer<B, Dim2, (), DS>,
+}
+
+impl<'a, B, DS> Deref for FramebufferSlice<'a, B, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  type Target = Framebuffer<B, Dim2, (), DS>;
+
+  fn deref(&self) -> &Self::Target {
+    self.framebuffer
+  }
+}
+
+impl<'a, B, DS> Drop for FramebufferSlice<'a, B, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2>,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  fn drop(&mut self) {
+    unsafe { B::detach_color_texture_layer(&mut self.framebuffer.repr, 0) };
+  }
+}
+
+impl<B, CS> Framebuffer<B, Dim2, CS, Depth32F>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + TextureBackend<Dim2, Depth32F>,
+  CS: ColorSlot<B, Dim2>,
+{
+  /// Read back a rectangular region of the depth attachment and linearize it.
+  ///
+  /// The region starts at `offset` (lower-left corner) and spans `size` texels; it is clamped to the size of the
+  /// framebuffer. `near` and `far` are the clipping planes of the projection used to render into the framebuffer and
+  /// `projection` tells how depth values were produced — see [`linearize_depth`] for further details.
+  ///
+  /// Values are returned row by row, starting from the lower-left corner of the region. This is mostly useful to
+  /// debug depth-based techniques, such as shadow maps.
+  pub fn read_depth_linear(
+    &self,
+    offset: [u32; 2],
+    size: [u32; 2],
+    near: f32,
+    far: f32,
+    projection: DepthProjection,
+  ) -> Result<Vec<f32>, TextureError> {
+    let region = read_region(&self.depth_stencil_slot, self.size(), offset, size, 1)?;
+    let linear = region
+      .into_iter()
+      .map(|depth| linearize_depth(depth, near, far, projection))
+      .collect();
+
+    Ok(linear)
+  }
+}
+
+impl<B, CS> Framebuffer<B, Dim2, CS, Depth32F>
+where
+  B: ?Sized + FramebufferDepthCopy + TextureBackend<Dim2, Depth32F>,
+  CS: ColorSlot<B, Dim2>,
+{
+  /// Copy the depth attachment into `dst`, which must have the size of the framebuffer.
+  ///
+  /// This snapshots the depth of a frame into a separate texture, which can then be sampled while rendering the next
+  /// frame into the same framebuffer — e.g. for temporal reprojection — without reading and writing the same depth
+  /// attachment.
+  pub fn copy_depth_to_texture(
+    &self,
+    dst: &mut Texture<B, Dim2, Depth32F>,
+  ) -> Result<(), FramebufferError> {
+    let size = self.size();
+    let dst_size = dst.size();
+
+    if size != dst_size {
+      return Err(FramebufferError::size_mismatch(size, dst_size));
+    }
+
+    unsafe { B::copy_depth_to_texture(&self.repr, &dst.repr) }
+  }
+}
+
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferSharedDepth + TextureBackend<Dim2, DS>,
+  CS: ColorSlot<B, Dim2>,
+  DS: Pixel + DepthStencilSlot<B, Dim2, DepthStencilTexture = Texture<B, Dim2, DS>>,
+{
+  /// Create a new [`Framebuffer`] which depth / stencil attachment is `depth`, an existing depth / stencil texture.
+  ///
+  /// Only the color slot is created, just like with [`Framebuffer::new`]. The depth / stencil texture is shared, not
+  /// copied: rendering into any framebuffer sharing it — typically two framebuffers ping-ponging an effect — tests and
+  /// writes the same depth. `depth` can be the depth / stencil slot of another framebuffer or a standalone texture;
+  /// the texture is kept alive as long as any framebuffer or [`Texture`] sharing it is.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::SizeMismatch`] is returned if `size` is not the size of `depth`.
+  pub fn new_sharing_depth<C>(
+    ctx: &mut C,
+    size: [u32; 2],
+    mipmaps: usize,
+    sampler: Sampler,
+    depth: &Texture<B, Dim2, DS>,
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let depth_size = depth.size();
+
+    if size != depth_size {
+      return Err(FramebufferError::size_mismatch(size, depth_size));
+    }
+
+    unsafe {
+      let mut repr = ctx
+        .backend()
+        .new_framebuffer::<CS, DS>(size, mipmaps, &sampler)?;
+      let color_slot = CS::reify_color_textures(ctx, size, mipmaps, &sampler, &mut repr, 0)?;
+
+      let depth_slot = Texture::from_repr(B::share_depth_texture(&depth.repr), size);
+      B::attach_depth_texture(&mut repr, &depth_slot.repr)?;
+
+      let repr = B::validate_framebuffer(repr)?;
+
+      Ok(Framebuffer {
+        repr,
+        color_slot,
+        depth_stencil_slot: depth_slot,
+        back_buffer: false,
+      })
+    }
+  }
+}
+
+impl<B, CS, DS> Framebuffer<B, Dim2, CS, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + TextureBackend<Dim2, DS>,
+  CS: ColorSlot<B, Dim2>,
+  DS: NormDepthPixel + DepthStencilSlot<B, Dim2, DepthStencilTexture = Texture<B, Dim2, DS>>,
+  DS::RawEncoding: Default,
+{
+  /// Read back a rectangular region of the depth attachment as normalized unsigned integers.
+  ///
+  /// The region starts at `offset` (lower-left corner) and spans `size` texels; it is clamped to the size of the
+  /// framebuffer. Each value holds [`NormDepthPixel::DEPTH_BITS`] bits of depth — `0` being the near plane and
+  /// `2^DEPTH_BITS - 1` the far plane; stencil bits of combined depth/stencil formats are dropped. Values are exact
+  /// and can be compared against [`quantize_depth`].
+  ///
+  /// [`quantize_depth`]: crate::pixel::quantize_depth
+  ///
+  /// Values are returned row by row, starting from the lower-left corner of the region.
+  pub fn read_depth_raw(&self, offset: [u32; 2], size: [u32; 2]) -> Result<Vec<u32>, TextureError> {
+    let region = read_region(&self.depth_stencil_slot, self.size(), offset, size, 1)?;
+    Ok(region.into_iter().map(DS::norm_depth).collect())
+  }
+}
+
+impl<B, P, DS> Framebuffer<B, Dim2, P, DS>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + TextureBackend<Dim2, P>,
+  P: ColorPixel + RenderablePixel + ColorSlot<B, Dim2, ColorTextures = Texture<B, Dim2, P>>,
+  P::RawEncoding: Default,
+  DS: DepthStencilSlot<B, Dim2>,
+{
+  /// Read back a rectangular region of the color attachment.
+  ///
+  /// The region starts at `offset` (lower-left corner) and spans `size` texels; it is clamped to the size of the
+  /// framebuffer. Values are returned row by row, starting from the lower-left corner of the region, with
+  /// [`PixelFormat::channels_len`] raw values per texel.
+  ///
+  /// This is typically used with integer attachments, such as [`R32UI`], to read back object IDs for picking.
+  ///
+  /// [`R32UI`]: crate::pixel::R32UI
+  pub fn read_color(
+    &self,
+    offset: [u32; 2],
+    size: [u32; 2],
+  ) -> Result<Vec<P::RawEncoding>, TextureError> {
+    let channels = P::pixel_format().channels_len();
+    read_region(&self.color_slot, self.size(), offset, size, channels)
+  }
+}
+
+// Read back the raw texels of a rectangular region of a texture, clamped to the size of the framebuffer.
+fn read_region<B, P>(
+  texture: &Texture<B, Dim2, P>,
+  [width, height]: [u32; 2],
+  offset: [u32; 2],
+  size: [u32; 2],
+  channels: usize,
+) -> Result<Vec<P::RawEncoding>, TextureError>
+where
+  B: ?Sized + TextureBackend<Dim2, P>,
+  P: Pixel,
+  P::RawEncoding: Default,
+{
+  let x = offset[0].min(width) as usize;
+  let y = offset[1].min(height) as usize;
+  let w = size[0].min(width - x as u32) as usize;
+  let h = size[1].min(height - y as u32) as usize;
+
+  if w == 0 || h == 0 {
+    return Ok(Vec::new());
+  }
+
+  // combined depth/stencil textures might return more than one value per texel; only the first width × height
+  // values are meaningful
+  let texels = texture.get_raw_texels()?;
+  let region = texels
+    .chunks(width as usize * channels)
+    .skip(y)
+    .take(h)
+    .flat_map(|row| &row[x * channels..(x + w) * channels])
+    .copied()
+    .collect();
+
+  Ok(region)
+}
+
 impl<B> Framebuffer<B, Dim2, (), ()>
 where
   B: ?Sized + FramebufferBackend<Dim2> + FramebufferBackBuffer,
 {
   /// Get the _back buffer_ from the input context and the required resolution.
+  ///
+  /// This is the single way to get the back buffer; windowing crates call it with the size of their system framebuffer.
+  /// The returned [`Framebuffer`] reports `true` in [`Framebuffer::is_back_buffer`].
   pub fn back_buffer<C>(
     ctx: &mut C,
     size: <Dim2 as Dimensionable>::Size,
@@ -194,10 +578,294 @@ where
       repr,
       color_slot: (),
       depth_stencil_slot: (),
+      back_buffer: true,
     })
   }
 }
 
+impl<B> Framebuffer<B, Dim2, (), ()>
+where
+  B: ?Sized + FramebufferBackend<Dim2> + FramebufferRawHandle,
+{
+  /// Wrap a framebuffer created and owned by a third party, such as the engine luminance is embedded into.
+  ///
+  /// `handle` is the backend handle of the framebuffer and `size` the size of its attachments. `color_formats` gives
+  /// the formats of its color attachments, in order: fragment outputs are written to the first `color_formats.len()`
+  /// color attachments, which are cleared according to their formats. Like the back buffer, the returned
+  /// [`Framebuffer`] has no color nor depth / stencil slots, so its content must be read by the third party.
+  ///
+  /// The framebuffer is never deleted by luminance: dropping the returned [`Framebuffer`] only forgets about it.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::IncompatibleAttachmentFormat`] is returned if a color format is a depth or compressed format,
+  /// and [`FramebufferError::Incomplete`] if the framebuffer cannot be rendered to.
+  ///
+  /// # Safety
+  ///
+  /// `handle` must refer to a framebuffer of the same context as `ctx`, which attachments match `size` and
+  /// `color_formats`, and which outlives the returned [`Framebuffer`].
+  pub unsafe fn from_raw_handle<C>(
+    ctx: &mut C,
+    handle: B::RawFramebufferHandle,
+    size: <Dim2 as Dimensionable>::Size,
+    color_formats: &[PixelFormat],
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    for (i, &format) in color_formats.iter().enumerate() {
+      if !is_color_attachment_format(format) {
+        return Err(FramebufferError::incompatible_attachment_format(
+          Attachment::Color(i),
+          format,
+        ));
+      }
+    }
+
+    let repr = ctx
+      .backend()
+      .framebuffer_from_raw_handle(handle, size, color_formats)?;
+
+    Ok(Framebuffer {
+      repr,
+      color_slot: (),
+      depth_stencil_slot: (),
+      back_buffer: false,
+    })
+  }
+}
+
+/// Framebuffer which attachment formats are picked at runtime.
+///
+/// [`Framebuffer`] encodes the formats of its color and depth / stencil slots in its type, which is not possible when
+/// they are only known at runtime, such as in editors letting users choose them. A [`DynamicFramebuffer`] trades that
+/// compile-time safety for flexibility: its slots are [`DynamicTexture`]s, which pixel format is checked whenever they
+/// are accessed as typed textures.
+///
+/// Rendering into a [`DynamicFramebuffer`] is done with [`PipelineGate::dynamic_pipeline`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [backend::framebuffer::DynamicFramebuffer].
+/// - `D` is the dimension type. It must implement [`Dimensionable`].
+///
+/// [backend::framebuffer::DynamicFramebuffer]: crate::backend::framebuffer::DynamicFramebuffer
+pub struct DynamicFramebuffer<B, D>
+where
+  B: ?Sized + DynamicFramebufferBackend<D>,
+  D: Dimensionable,
+{
+  pub(crate) repr: B::FramebufferRepr,
+  color_slots: Vec<DynamicTexture<B, D>>,
+  depth_stencil_slot: Option<DynamicTexture<B, D>>,
+}
+
+impl<B, D> DynamicFramebuffer<B, D>
+where
+  B: ?Sized + DynamicFramebufferBackend<D>,
+  D: Dimensionable,
+  D::Size: Copy,
+{
+  /// Create a new [`DynamicFramebuffer`].
+  ///
+  /// A color slot is created for each of `color_formats`, in order, and a depth / stencil slot is created if
+  /// `depth_stencil_format` is provided. Just like with [`Framebuffer::new`], if no depth / stencil slot is asked, the
+  /// framebuffer still gets a depth buffer that cannot be accessed.
+  ///
+  /// # Errors
+  ///
+  /// [`FramebufferError::IncompatibleAttachmentFormat`] is returned if a color format is a depth or compressed format,
+  /// or if the depth / stencil format is not a depth format. Other combinations the backend cannot render to make the
+  /// framebuffer incomplete.
+  pub fn new<C>(
+    ctx: &mut C,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: Sampler,
+    color_formats: Vec<PixelFormat>,
+    depth_stencil_format: Option<PixelFormat>,
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    for (i, &format) in color_formats.iter().enumerate() {
+      if !is_color_attachment_format(format) {
+        return Err(FramebufferError::incompatible_attachment_format(
+          Attachment::Color(i),
+          format,
+        ));
+      }
+    }
+
+    if let Some(format) = depth_stencil_format {
+      if !is_depth_stencil_attachment_format(format) {
+        return Err(FramebufferError::incompatible_attachment_format(
+          Attachment::DepthStencil,
+          format,
+        ));
+      }
+    }
+
+    unsafe {
+      let mut repr =
+        ctx
+          .backend()
+          .new_dynamic_framebuffer(size, &color_formats, depth_stencil_format)?;
+
+      let mut color_slots = Vec::with_capacity(color_formats.len());
+      for (i, &format) in color_formats.iter().enumerate() {
+        let texture = DynamicTexture::new(ctx, size, mipmaps, &sampler, format)?;
+        B::attach_color_texture(&mut repr, &texture.repr, i)?;
+        color_slots.push(texture);
+      }
+
+      let depth_stencil_slot = match depth_stencil_format {
+        Some(format) => {
+          let texture = DynamicTexture::new(ctx, size, mipmaps, &sampler, format)?;
+          B::attach_depth_texture(&mut repr, &texture.repr)?;
+          Some(texture)
+        }
+
+        None => None,
+      };
+
+      let repr = B::validate_framebuffer(repr)?;
+
+      Ok(DynamicFramebuffer {
+        repr,
+        color_slots,
+        depth_stencil_slot,
+      })
+    }
+  }
+
+  /// Get the size of the framebuffer.
+  pub fn size(&self) -> D::Size {
+    unsafe { B::framebuffer_size(&self.repr) }
+  }
+
+  /// Number of color slots.
+  pub fn color_slot_count(&self) -> usize {
+    self.color_slots.len()
+  }
+
+  /// Access the color slots, in the order of the formats the framebuffer was created with.
+  pub fn color_slots(&mut self) -> &mut [DynamicTexture<B, D>] {
+    &mut self.color_slots
+  }
+
+  /// Access the color slot at index `index`, if any.
+  pub fn color_slot(&mut self, index: usize) -> Option<&mut DynamicTexture<B, D>> {
+    self.color_slots.get_mut(index)
+  }
+
+  /// Access the depth / stencil slot, if the framebuffer was created with one.
+  pub fn depth_stencil_slot(&mut self) -> Option<&mut DynamicTexture<B, D>> {
+    self.depth_stencil_slot.as_mut()
+  }
+}
+
+// Color attachments cannot be depth nor compressed.
+fn is_color_attachment_format(format: PixelFormat) -> bool {
+  !matches!(
+    format.format,
+    Format::Depth(_) | Format::DepthStencil(_, _) | Format::Compressed(_)
+  )
+}
+
+fn is_depth_stencil_attachment_format(format: PixelFormat) -> bool {
+  matches!(format.format, Format::Depth(_) | Format::DepthStencil(_, _))
+}
+
+/// A slot of a [`DynamicFramebuffer`].
+///
+/// A [`DynamicTexture`] is a texture which pixel format is only known at runtime. It can be bound with
+/// [`Pipeline::bind_dynamic_texture`] and read back with [`DynamicTexture::get_raw_texels`], which both require the
+/// pixel type to match [`DynamicTexture::format`].
+///
+/// [`Pipeline::bind_dynamic_texture`]: crate::pipeline::Pipeline::bind_dynamic_texture
+pub struct DynamicTexture<B, D>
+where
+  B: ?Sized + DynamicFramebufferBackend<D>,
+  D: Dimensionable,
+{
+  pub(crate) repr: B::TextureRepr,
+  size: D::Size,
+  format: PixelFormat,
+}
+
+impl<B, D> DynamicTexture<B, D>
+where
+  B: ?Sized + DynamicFramebufferBackend<D>,
+  D: Dimensionable,
+  D::Size: Copy,
+{
+  fn new<C>(
+    ctx: &mut C,
+    size: D::Size,
+    mipmaps: usize,
+    sampler: &Sampler,
+    format: PixelFormat,
+  ) -> Result<Self, FramebufferError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe {
+      ctx
+        .backend()
+        .new_attachment_texture(size, mipmaps, sampler, format)?
+    };
+
+    Ok(DynamicTexture { repr, size, format })
+  }
+
+  /// Pixel format of the texture.
+  pub fn format(&self) -> PixelFormat {
+    self.format
+  }
+
+  /// Size of the texture.
+  pub fn size(&self) -> D::Size {
+    self.size
+  }
+
+  /// Get a copy of all the pixels from the texture, read as pixel type `P`.
+  ///
+  /// [`TextureError::PixelFormatMismatch`] is returned if `P` doesn’t match [`DynamicTexture::format`].
+  pub fn get_raw_texels<P>(&self) -> Result<Vec<P::RawEncoding>, TextureError>
+  where
+    B: TextureBackend<D, P>,
+    P: Pixel,
+    P::RawEncoding: Copy + Default,
+  {
+    if self.format != P::pixel_format() {
+      return Err(TextureError::pixel_format_mismatch(
+        self.format,
+        P::pixel_format(),
+      ));
+    }
+
+    unsafe { B::get_raw_texels(&self.repr, self.size) }
+  }
+}
+
+/// Framebuffer attachment.
+///
+/// Used to refer to a specific attachment of a [`Framebuffer`], for instance when invalidating its content with
+/// [`Framebuffer::invalidate`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Attachment {
+  /// Color attachment, by index in the color slot.
+  Color(usize),
+  /// Depth attachment.
+  Depth,
+  /// Stencil attachment.
+  Stencil,
+  /// Both the depth and stencil attachments.
+  DepthStencil,
+}
+
 /// Framebuffer error.
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -214,6 +882,32 @@ pub enum FramebufferError {
   Incomplete(IncompleteReason),
   /// Cannot attach something to a framebuffer.
   UnsupportedAttachment,
+  /// A pixel format cannot be used for an attachment.
+  ///
+  /// This happens with a [`DynamicFramebuffer`] when a depth or compressed format is asked for a color attachment, or
+  /// when a color format is asked for the depth / stencil attachment.
+  IncompatibleAttachmentFormat {
+    /// Attachment the format was asked for.
+    attachment: Attachment,
+    /// Incompatible format.
+    format: PixelFormat,
+  },
+  /// Layer out of bounds of the attached texture.
+  LayerOutOfBounds {
+    /// Requested layer.
+    layer: u32,
+    /// Number of layers of the texture.
+    depth: u32,
+  },
+  /// The operation requires texture attachments, which the back buffer doesn’t have.
+  UnsupportedOnBackBuffer,
+  /// A texture doesn’t have the size of the framebuffer it’s used with.
+  SizeMismatch {
+    /// Size of the framebuffer.
+    framebuffer: [u32; 2],
+    /// Size of the texture.
+    texture: [u32; 2],
+  },
 }
 
 impl FramebufferError {
@@ -236,6 +930,29 @@ impl FramebufferError {
   pub fn unsupported_attachment() -> Self {
     FramebufferError::UnsupportedAttachment
   }
+
+  /// A pixel format cannot be used for an attachment.
+  pub fn incompatible_attachment_format(attachment: Attachment, format: PixelFormat) -> Self {
+    FramebufferError::IncompatibleAttachmentFormat { attachment, format }
+  }
+
+  /// Layer out of bounds of the attached texture.
+  pub fn layer_out_of_bounds(layer: u32, depth: u32) -> Self {
+    FramebufferError::LayerOutOfBounds { layer, depth }
+  }
+
+  /// The operation requires texture attachments, which the back buffer doesn’t have.
+  pub fn unsupported_on_back_buffer() -> Self {
+    FramebufferError::UnsupportedOnBackBuffer
+  }
+
+  /// A texture doesn’t have the size of the framebuffer it’s used with.
+  pub fn size_mismatch(framebuffer: [u32; 2], texture: [u32; 2]) -> Self {
+    FramebufferError::SizeMismatch {
+      framebuffer,
+      texture,
+    }
+  }
 }
 
 impl fmt::Display for FramebufferError {
@@ -250,6 +967,34 @@ impl fmt::Display for FramebufferError {
       FramebufferError::Incomplete(ref e) => write!(f, "incomplete framebuffer: {}", e),
 
       FramebufferError::UnsupportedAttachment => f.write_str("unsupported framebuffer attachment"),
+
+      FramebufferError::IncompatibleAttachmentFormat {
+        ref attachment,
+        ref format,
+      } => write!(
+        f,
+        "pixel format {:?} cannot be used for the {:?} attachment",
+        format, attachment
+      ),
+
+      FramebufferError::LayerOutOfBounds { layer, depth } => write!(
+        f,
+        "layer {} out of bounds of a texture with {} layers",
+        layer, depth
+      ),
+
+      FramebufferError::UnsupportedOnBackBuffer => {
+        f.write_str("the back buffer has no texture attachments")
+      }
+
+      FramebufferError::SizeMismatch {
+        framebuffer,
+        texture,
+      } => write!(
+        f,
+        "texture size {:?} doesn’t match framebuffer size {:?}",
+        texture, framebuffer
+      ),
     }
   }
 }
@@ -261,6 +1006,10 @@ impl std::error::Error for FramebufferError {
       FramebufferError::TextureError(e) => Some(e),
       FramebufferError::Incomplete(e) => Some(e),
       FramebufferError::UnsupportedAttachment => None,
+      FramebufferError::IncompatibleAttachmentFormat { .. } => None,
+      FramebufferError::LayerOutOfBounds { .. } => None,
+      FramebufferError::UnsupportedOnBackBuffer => None,
+      FramebufferError::SizeMismatch { .. } => None,
     }
   }
 }
@@ -278,6 +1027,12 @@ impl From<IncompleteReason> for FramebufferError {
 }
 
 /// Reason a framebuffer is incomplete.
+///
+/// Those map the statuses a backend reports when checking the completeness of a framebuffer, and their [`Display`]
+/// implementation explains what is typically wrong with the attachments.
+///
+/// [`Display`]: fmt::Display
+#[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum IncompleteReason {
   /// Incomplete framebuffer.
@@ -296,19 +1051,43 @@ pub enum IncompleteReason {
   IncompleteMultisample,
   /// Incomplete layer targets.
   IncompleteLayerTargets,
+  /// Attachments don’t have the same dimensions.
+  IncompleteDimensions,
+  /// Status unknown to luminance, as reported by the backend.
+  Unknown(u32),
 }
 
 impl fmt::Display for IncompleteReason {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
     match *self {
-      IncompleteReason::Undefined => write!(f, "incomplete reason"),
-      IncompleteReason::IncompleteAttachment => write!(f, "incomplete attachment"),
-      IncompleteReason::MissingAttachment => write!(f, "missing attachment"),
-      IncompleteReason::IncompleteDrawBuffer => write!(f, "incomplete draw buffer"),
-      IncompleteReason::IncompleteReadBuffer => write!(f, "incomplete read buffer"),
-      IncompleteReason::Unsupported => write!(f, "unsupported"),
-      IncompleteReason::IncompleteMultisample => write!(f, "incomplete multisample"),
-      IncompleteReason::IncompleteLayerTargets => write!(f, "incomplete layer targets"),
+      IncompleteReason::Undefined => {
+        f.write_str("undefined framebuffer (the default framebuffer doesn’t exist)")
+      }
+      IncompleteReason::IncompleteAttachment => f.write_str(
+        "incomplete attachment (an attached image has a zero size or a non-renderable format)",
+      ),
+      IncompleteReason::MissingAttachment => {
+        f.write_str("missing attachment (the framebuffer has no image attached)")
+      }
+      IncompleteReason::IncompleteDrawBuffer => {
+        f.write_str("incomplete draw buffer (a draw buffer has no image attached)")
+      }
+      IncompleteReason::IncompleteReadBuffer => {
+        f.write_str("incomplete read buffer (the read buffer has no image attached)")
+      }
+      IncompleteReason::Unsupported => f.write_str(
+        "unsupported (the combination of attachment formats is not supported by the implementation)",
+      ),
+      IncompleteReason::IncompleteMultisample => f.write_str(
+        "incomplete multisample (attachments don’t have the same number of samples)",
+      ),
+      IncompleteReason::IncompleteLayerTargets => f.write_str(
+        "incomplete layer targets (layered and non-layered images are attached together)",
+      ),
+      IncompleteReason::IncompleteDimensions => {
+        f.write_str("incomplete dimensions (attachments don’t have the same size)")
+      }
+      IncompleteReason::Unknown(status) => write!(f, "unknown status {:#x}", status),
     }
   }
 }
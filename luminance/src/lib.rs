@@ -81,6 +81,8 @@
 //! - A “core” crate, [luminance], which is about all the abstract, common and interface code.
 //! - A proc-macro crate, [luminance-derive], which is exported by [luminance] if you use the `"derive"`
 //!   feature flag. That crate allows to implement various important traits of the core crate.
+//!   The feature is enabled by default; the backend and windowing crates don’t require it, so
+//!   disabling it removes the proc-macro dependency from your build graph entirely.
 //! - A set of _backend implementation_ crates, implementing the [luminance] crate backend interfaces.
 //! - A set of _windowing_ crates, executing your code written with the core and backend crate on native
 //!   systems (most of the time, _windowing platforms_, but not limited to).
@@ -329,8 +331,11 @@ pub mod backend;
 pub mod blending;
 pub mod context;
 pub mod depth_stencil;
+pub mod error_check;
 pub mod face_culling;
+pub mod feedback;
 pub mod framebuffer;
+pub mod memory_barrier;
 pub mod pipeline;
 pub mod pixel;
 pub mod query;
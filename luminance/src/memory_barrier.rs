@@ -0,0 +1,157 @@
+//! Memory barriers.
+//!
+//! Some GPU writes — image stores, shader storage writes, atomic counters, etc. — are _incoherent_: the GPU doesn’t
+//! guarantee that later commands will see them. A memory barrier, issued with [`GraphicsContext::memory_barrier`],
+//! orders such writes with later reads. The [`MemoryBarrierBits`] passed to the barrier select the ways in which the
+//! written memory will be read afterwards; only those reads are guaranteed to see the writes.
+//!
+//! Memory barriers are not available on every backend (for instance, they require OpenGL 4.2 — 4.3 for shader storage
+//! — and are not part of WebGL2); backends that cannot honor a barrier return [`MemoryBarrierError::Unsupported`].
+//!
+//! [`GraphicsContext::memory_barrier`]: crate::context::GraphicsContext::memory_barrier
+
+use std::{error, fmt, ops};
+
+/// Set of memory barrier flags.
+///
+/// Each flag describes a way in which memory written by incoherent operations will be read after the barrier. Flags are
+/// combined with `|`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct MemoryBarrierBits {
+  bits: u32,
+}
+
+impl MemoryBarrierBits {
+  /// Vertex data sourced from buffers.
+  pub const VERTEX_ATTRIB_ARRAY: Self = MemoryBarrierBits::from_bits(1 << 0);
+
+  /// Vertex indices sourced from buffers.
+  pub const ELEMENT_ARRAY: Self = MemoryBarrierBits::from_bits(1 << 1);
+
+  /// Shader data (uniform buffers) read by shaders.
+  pub const UNIFORM: Self = MemoryBarrierBits::from_bits(1 << 2);
+
+  /// Textures fetched by shaders.
+  pub const TEXTURE_FETCH: Self = MemoryBarrierBits::from_bits(1 << 3);
+
+  /// Images read or written by shaders.
+  pub const SHADER_IMAGE_ACCESS: Self = MemoryBarrierBits::from_bits(1 << 4);
+
+  /// Textures uploaded or read back by the host.
+  pub const TEXTURE_UPDATE: Self = MemoryBarrierBits::from_bits(1 << 5);
+
+  /// Buffers updated or read back by the host.
+  pub const BUFFER_UPDATE: Self = MemoryBarrierBits::from_bits(1 << 6);
+
+  /// Framebuffer attachments read or written by renders.
+  pub const FRAMEBUFFER: Self = MemoryBarrierBits::from_bits(1 << 7);
+
+  /// Shader storage buffers read or written by shaders.
+  pub const SHADER_STORAGE: Self = MemoryBarrierBits::from_bits(1 << 8);
+
+  const fn from_bits(bits: u32) -> Self {
+    MemoryBarrierBits { bits }
+  }
+
+  /// No flag.
+  pub const fn empty() -> Self {
+    MemoryBarrierBits::from_bits(0)
+  }
+
+  /// All the flags.
+  pub const fn all() -> Self {
+    MemoryBarrierBits::from_bits((1 << 9) - 1)
+  }
+
+  /// Whether no flag is set.
+  pub const fn is_empty(self) -> bool {
+    self.bits == 0
+  }
+
+  /// Whether all the flags of `other` are set in `self`.
+  pub const fn contains(self, other: Self) -> bool {
+    self.bits & other.bits == other.bits
+  }
+}
+
+impl ops::BitOr for MemoryBarrierBits {
+  type Output = Self;
+
+  fn bitor(self, rhs: Self) -> Self {
+    MemoryBarrierBits::from_bits(self.bits | rhs.bits)
+  }
+}
+
+impl ops::BitOrAssign for MemoryBarrierBits {
+  fn bitor_assign(&mut self, rhs: Self) {
+    self.bits |= rhs.bits;
+  }
+}
+
+impl fmt::Display for MemoryBarrierBits {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    const NAMES: [(MemoryBarrierBits, &str); 9] = [
+      (
+        MemoryBarrierBits::VERTEX_ATTRIB_ARRAY,
+        "vertex attrib array",
+      ),
+      (MemoryBarrierBits::ELEMENT_ARRAY, "element array"),
+      (MemoryBarrierBits::UNIFORM, "uniform"),
+      (MemoryBarrierBits::TEXTURE_FETCH, "texture fetch"),
+      (
+        MemoryBarrierBits::SHADER_IMAGE_ACCESS,
+        "shader image access",
+      ),
+      (MemoryBarrierBits::TEXTURE_UPDATE, "texture update"),
+      (MemoryBarrierBits::BUFFER_UPDATE, "buffer update"),
+      (MemoryBarrierBits::FRAMEBUFFER, "framebuffer"),
+      (MemoryBarrierBits::SHADER_STORAGE, "shader storage"),
+    ];
+
+    if self.is_empty() {
+      return f.write_str("none");
+    }
+
+    let mut names = NAMES
+      .iter()
+      .filter(|(bits, _)| self.contains(*bits))
+      .map(|(_, name)| name);
+
+    if let Some(name) = names.next() {
+      f.write_str(name)?;
+    }
+
+    for name in names {
+      write!(f, " | {}", name)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// Errors that might occur when issuing a memory barrier.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MemoryBarrierError {
+  /// The backend cannot issue a memory barrier for these flags.
+  Unsupported(MemoryBarrierBits),
+}
+
+impl MemoryBarrierError {
+  /// The backend cannot issue a memory barrier for these flags.
+  pub fn unsupported(bits: MemoryBarrierBits) -> Self {
+    MemoryBarrierError::Unsupported(bits)
+  }
+}
+
+impl fmt::Display for MemoryBarrierError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      MemoryBarrierError::Unsupported(bits) => {
+        write!(f, "unsupported memory barrier: {}", bits)
+      }
+    }
+  }
+}
+
+impl error::Error for MemoryBarrierError {}
@@ -127,30 +127,147 @@ use crate::{
   backend::{
     color_slot::ColorSlot,
     depth_stencil_slot::DepthStencilSlot,
-    framebuffer::Framebuffer as FramebufferBackend,
-    pipeline::{Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture},
+    framebuffer::{
+      DynamicFramebuffer as DynamicFramebufferBackend, Framebuffer as FramebufferBackend,
+    },
+    pipeline::{
+      Pipeline as PipelineBackend, PipelineBase, PipelineShaderData, PipelineTexture,
+      PipelineTextureBuffer, PipelineTextureLevel, PipelineTextureStencil,
+    },
   },
   context::GraphicsContext,
-  framebuffer::Framebuffer,
-  pixel::Pixel,
+  feedback::FeedbackError,
+  framebuffer::{DynamicFramebuffer, DynamicTexture, Framebuffer},
+  pixel::{Pixel, PixelFormat, StencilPixel, Unsigned},
   scissor::ScissorRegion,
   shader::ShaderData,
   shading_gate::ShadingGate,
-  texture::{Dimensionable, Texture},
+  tess::TessError,
+  texture::{Dimensionable, Texture, TextureBuffer, TextureView},
 };
 
 /// Possible errors that might occur in a graphics [`Pipeline`].
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
-pub enum PipelineError {}
+pub enum PipelineError {
+  /// All the shader data bindings are already in use.
+  TooManyShaderDataBindings {
+    /// Maximum number of shader data that can be bound at the same time.
+    max: u32,
+  },
+  /// All the texture units are already in use.
+  OutOfTextureUnits {
+    /// Maximum number of textures that can be bound at the same time.
+    max: u32,
+  },
+  /// A [`DynamicTexture`] was bound with a pixel format different from the one it was created with.
+  TextureFormatMismatch {
+    /// Pixel format of the texture.
+    texture: PixelFormat,
+    /// Pixel format the texture was bound with.
+    requested: PixelFormat,
+  },
+  /// Transform feedback error.
+  Feedback(FeedbackError),
+  /// Tessellation render error.
+  Tess(TessError),
+  /// The stencil component of depth / stencil textures cannot be sampled by the backend.
+  StencilTexturingUnsupported,
+  /// A viewport or scissor region expressed with [`Origin::TopLeft`] cannot be flipped, as its Y coordinate plus its
+  /// height overflows.
+  InvalidRegion {
+    /// Y coordinate of the region.
+    y: u32,
+    /// Height of the region.
+    height: u32,
+  },
+}
+
+impl PipelineError {
+  /// All the shader data bindings are already in use.
+  pub fn too_many_shader_data_bindings(max: u32) -> Self {
+    PipelineError::TooManyShaderDataBindings { max }
+  }
+
+  /// All the texture units are already in use.
+  pub fn out_of_texture_units(max: u32) -> Self {
+    PipelineError::OutOfTextureUnits { max }
+  }
+
+  /// A [`DynamicTexture`] was bound with a pixel format different from the one it was created with.
+  pub fn texture_format_mismatch(texture: PixelFormat, requested: PixelFormat) -> Self {
+    PipelineError::TextureFormatMismatch { texture, requested }
+  }
+
+  /// Transform feedback error.
+  pub fn feedback(e: FeedbackError) -> Self {
+    PipelineError::Feedback(e)
+  }
+
+  /// Tessellation render error.
+  pub fn tess(e: TessError) -> Self {
+    PipelineError::Tess(e)
+  }
+
+  /// The stencil component of depth / stencil textures cannot be sampled by the backend.
+  pub fn stencil_texturing_unsupported() -> Self {
+    PipelineError::StencilTexturingUnsupported
+  }
+
+  /// A viewport or scissor region expressed with [`Origin::TopLeft`] cannot be flipped.
+  pub fn invalid_region(y: u32, height: u32) -> Self {
+    PipelineError::InvalidRegion { y, height }
+  }
+}
 
 impl fmt::Display for PipelineError {
-  fn fmt(&self, _: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    Ok(())
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      PipelineError::TooManyShaderDataBindings { max } => write!(
+        f,
+        "too many shader data bound at the same time (max: {})",
+        max
+      ),
+
+      PipelineError::OutOfTextureUnits { max } => {
+        write!(f, "too many textures bound at the same time (max: {})", max)
+      }
+
+      PipelineError::TextureFormatMismatch {
+        ref texture,
+        ref requested,
+      } => write!(
+        f,
+        "texture format mismatch: texture is {:?}, bound as {:?}",
+        texture, requested
+      ),
+
+      PipelineError::Feedback(ref e) => write!(f, "transform feedback error: {}", e),
+
+      PipelineError::Tess(ref e) => write!(f, "tessellation error: {}", e),
+
+      PipelineError::StencilTexturingUnsupported => {
+        f.write_str("sampling the stencil component of textures is not supported")
+      }
+
+      PipelineError::InvalidRegion { y, height } => write!(
+        f,
+        "region at y = {} with height {} cannot be flipped to a bottom-left origin",
+        y, height
+      ),
+    }
   }
 }
 
-impl error::Error for PipelineError {}
+impl error::Error for PipelineError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      PipelineError::Feedback(e) => Some(e),
+      PipelineError::Tess(e) => Some(e),
+      _ => None,
+    }
+  }
+}
 
 /// The viewport being part of the [`PipelineState`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -171,6 +288,59 @@ pub enum Viewport {
   },
 }
 
+impl Viewport {
+  /// Convert a viewport expressed with a top-left origin to the bottom-left one, for a framebuffer of the given
+  /// height.
+  ///
+  /// Viewports which Y coordinate plus height overflows are rejected.
+  fn flip_y(self, fb_height: u32) -> Result<Self, PipelineError> {
+    match self {
+      Viewport::Whole => Ok(Viewport::Whole),
+      Viewport::Specific {
+        x,
+        y,
+        width,
+        height,
+      } => {
+        let top = y
+          .checked_add(height)
+          .ok_or_else(|| PipelineError::invalid_region(y, height))?;
+
+        Ok(Viewport::Specific {
+          x,
+          y: fb_height.saturating_sub(top),
+          width,
+          height,
+        })
+      }
+    }
+  }
+}
+
+/// Origin of the coordinate system used by viewports, scissor regions and texel readbacks.
+///
+/// Backends have their origin at the bottom-left corner of framebuffers, with the Y axis going up, while most image
+/// and UI code assumes a top-left origin, with the Y axis going down. Setting [`Origin::TopLeft`] on a
+/// [`PipelineState`] flips the Y coordinates of its viewport and scissor region — and of the scissor regions of the
+/// [`RenderState`]s used in the pipeline — so that they can be expressed with a top-left origin. Use
+/// [`Texture::get_raw_texels_with_origin`] to read texels back with the same convention.
+///
+/// [`RenderState`]: crate::render_state::RenderState
+/// [`Texture::get_raw_texels_with_origin`]: crate::texture::Texture::get_raw_texels_with_origin
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Origin {
+  /// Bottom-left corner; the Y axis goes up. This is the default.
+  BottomLeft,
+  /// Top-left corner; the Y axis goes down.
+  TopLeft,
+}
+
+impl Default for Origin {
+  fn default() -> Self {
+    Origin::BottomLeft
+  }
+}
+
 /// Various customization options for pipelines.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
@@ -184,6 +354,21 @@ pub struct PipelineState {
   /// for a paint-like application).
   pub clear_color: Option<[f32; 4]>,
 
+  /// Value to use when clearing integer color buffers.
+  ///
+  /// Integer color attachments, such as [`R32UI`](crate::pixel::R32UI) ones used for picking, are cleared with this
+  /// value when set; otherwise, they are cleared with [`PipelineState::clear_color`] converted to integers, which
+  /// cannot represent every 32-bit integer. Signed integer attachments reinterpret the bits as `i32`. Other color
+  /// attachments ignore this value.
+  pub clear_color_integer: Option<[u32; 4]>,
+
+  /// Colors to use when clearing each color buffer.
+  ///
+  /// The color at index `i` clears the color slot `i` of the [`Framebuffer`] — e.g. a G-buffer can clear its albedo
+  /// to black and its normals to `[0.5, 0.5, 1., 1.]`. Color buffers without a color in this list are cleared with
+  /// [`PipelineState::clear_color`].
+  pub clear_colors: Vec<[f32; 4]>,
+
   /// Depth value to use when clearing the depth buffer.
   ///
   /// Set this to `Some(depth)` to use that depth to clear the [`Framebuffer`] depth buffer.
@@ -210,25 +395,34 @@ pub struct PipelineState {
 
   /// Whether to use scissor test when clearing buffers.
   pub clear_scissor: Option<ScissorRegion>,
+
+  /// Origin of the coordinate system used by the viewport and scissor regions.
+  pub origin: Origin,
 }
 
 impl Default for PipelineState {
   /// Default [`PipelineState`]:
   ///
   /// - Clear color is `Some([0., 0., 0., 1.])`.
+  /// - Integer clear color is `None`, so integer color buffers are cleared with the clear color.
+  /// - No per color buffer clear color, so all color buffers are cleared with the clear color.
   /// - Depth value is `Some(1.)`.
   /// - Stencil value is `Some(0)`.
   /// - The viewport uses the whole framebuffer’s.
   /// - sRGB encoding is disabled.
   /// - No scissor test is performed.
+  /// - The origin is [`Origin::BottomLeft`].
   fn default() -> Self {
     PipelineState {
       clear_color: Some([0., 0., 0., 1.]),
+      clear_color_integer: None,
+      clear_colors: Vec::new(),
       clear_depth: Some(1.),
       clear_stencil: Some(0),
       viewport: Viewport::Whole,
       srgb_enabled: false,
       clear_scissor: None,
+      origin: Origin::BottomLeft,
     }
   }
 }
@@ -254,6 +448,42 @@ impl PipelineState {
     }
   }
 
+  /// Get the integer clear color, if any.
+  pub fn clear_color_integer(&self) -> Option<&[u32; 4]> {
+    self.clear_color_integer.as_ref()
+  }
+
+  /// Set the integer clear color, used for integer color buffers.
+  pub fn set_clear_color_integer(self, clear_color_integer: impl Into<Option<[u32; 4]>>) -> Self {
+    Self {
+      clear_color_integer: clear_color_integer.into(),
+      ..self
+    }
+  }
+
+  /// Get the per color buffer clear colors.
+  pub fn clear_colors(&self) -> &[[f32; 4]] {
+    &self.clear_colors
+  }
+
+  /// Set the clear colors of each color buffer.
+  ///
+  /// The color at index `i` is used for the color slot `i`; color slots past the end of the list are cleared with the
+  /// clear color.
+  pub fn set_clear_colors(self, clear_colors: impl Into<Vec<[f32; 4]>>) -> Self {
+    Self {
+      clear_colors: clear_colors.into(),
+      ..self
+    }
+  }
+
+  /// Get the color the color slot `index` is cleared with, if any.
+  ///
+  /// That is the clear color set for that slot with [`PipelineState::set_clear_colors`], or the clear color otherwise.
+  pub fn attachment_clear_color(&self, index: usize) -> Option<[f32; 4]> {
+    self.clear_colors.get(index).copied().or(self.clear_color)
+  }
+
   /// Get the clear depth, if any.
   pub fn clear_depth(&self) -> Option<f32> {
     self.clear_depth
@@ -315,6 +545,31 @@ impl PipelineState {
       ..self
     }
   }
+
+  /// Get the origin of the coordinate system.
+  pub fn origin(&self) -> Origin {
+    self.origin
+  }
+
+  /// Set the origin of the coordinate system used by the viewport and scissor regions.
+  ///
+  /// See the documentation of [`Origin`] for further details.
+  pub fn set_origin(self, origin: Origin) -> Self {
+    Self { origin, ..self }
+  }
+
+  /// Express the viewport and scissor region with a bottom-left origin, for a framebuffer of the given height.
+  fn to_bottom_left(&self, fb_height: u32) -> Result<Self, PipelineError> {
+    Ok(Self {
+      viewport: self.viewport.flip_y(fb_height)?,
+      clear_scissor: self
+        .clear_scissor
+        .map(|region| region.flip_y(fb_height))
+        .transpose()?,
+      origin: Origin::BottomLeft,
+      ..self.clone()
+    })
+  }
 }
 
 /// A GPU pipeline handle.
@@ -332,6 +587,7 @@ where
   B: ?Sized + PipelineBase,
 {
   repr: B::PipelineRepr,
+  target_size: [u32; 2],
   _phantom: PhantomData<&'a mut ()>,
 }
 
@@ -339,6 +595,14 @@ impl<'a, B> Pipeline<'a, B>
 where
   B: PipelineBase,
 {
+  /// Width and height of the framebuffer the pipeline renders to.
+  ///
+  /// This is typically used to pass the resolution of the render target to full-screen passes, without having to
+  /// carry it around along with the framebuffer.
+  pub fn target_size(&self) -> [u32; 2] {
+    self.target_size
+  }
+
   /// Bind a texture.
   ///
   /// Once the texture is bound, the [`BoundTexture`] object has to be dropped / die in order to bind the texture again.
@@ -359,10 +623,107 @@ where
     }
   }
 
+  /// Bind a [`DynamicTexture`], such as a slot of a [`DynamicFramebuffer`], as a texture of pixel type `P`.
+  ///
+  /// Because the pixel format of a [`DynamicTexture`] is only known at runtime, `P` is checked against it and
+  /// [`PipelineError::TextureFormatMismatch`] is returned if they differ.
+  pub fn bind_dynamic_texture<D, P>(
+    &'a self,
+    texture: &'a mut DynamicTexture<B, D>,
+  ) -> Result<BoundTexture<'a, B, D, P>, PipelineError>
+  where
+    B: DynamicFramebufferBackend<D> + PipelineTexture<D, P>,
+    D: Dimensionable,
+    P: Pixel,
+  {
+    if texture.format() != P::pixel_format() {
+      return Err(PipelineError::texture_format_mismatch(
+        texture.format(),
+        P::pixel_format(),
+      ));
+    }
+
+    unsafe {
+      B::bind_texture(&self.repr, &texture.repr).map(|repr| BoundTexture {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Bind a combined depth / stencil texture so that its stencil component is sampled.
+  ///
+  /// Combined depth / stencil textures, such as the depth / stencil slot of a [`Framebuffer`], are sampled as depth by
+  /// [`Pipeline::bind_texture`]. This method binds them as a stencil view instead, which must be sampled with an
+  /// unsigned sampler (e.g. `usampler2D`). The depth view is restored once the [`BoundStencilTexture`] is dropped.
+  ///
+  /// Backends unable to sample stencil components fail with [`PipelineError::StencilTexturingUnsupported`].
+  pub fn bind_texture_stencil<D, P>(
+    &'a self,
+    texture: &'a mut Texture<B, D, P>,
+  ) -> Result<BoundStencilTexture<'a, B, D, P>, PipelineError>
+  where
+    B: PipelineTextureStencil<D, P>,
+    D: Dimensionable,
+    P: StencilPixel,
+  {
+    unsafe {
+      B::bind_texture_stencil(&self.repr, &texture.repr).map(|repr| BoundStencilTexture {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Bind a [`TextureView`] on a single mipmap level of a texture.
+  ///
+  /// The bound texture is sampled as if the level of the view were its only level. All the levels are sampled again
+  /// once the [`BoundTexture`] is dropped.
+  pub fn bind_texture_view<D, P>(
+    &'a self,
+    view: &'a mut TextureView<B, D, P>,
+  ) -> Result<BoundTexture<'a, B, D, P>, PipelineError>
+  where
+    B: PipelineTextureLevel<D, P>,
+    D: Dimensionable,
+    P: Pixel,
+  {
+    unsafe {
+      B::bind_texture_level(&self.repr, &view.texture.repr, view.level()).map(|repr| BoundTexture {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
+  /// Bind a [`TextureBuffer`].
+  ///
+  /// Texture buffers share the texture units with textures, so binding one might fail with
+  /// [`PipelineError::OutOfTextureUnits`] too. The binding is freed once the [`BoundTextureBuffer`] is dropped.
+  pub fn bind_texture_buffer<P>(
+    &'a self,
+    texture_buffer: &'a mut TextureBuffer<B, P>,
+  ) -> Result<BoundTextureBuffer<'a, B, P>, PipelineError>
+  where
+    B: PipelineTextureBuffer<P>,
+    P: Pixel,
+  {
+    unsafe {
+      B::bind_texture_buffer(&self.repr, &texture_buffer.repr).map(|repr| BoundTextureBuffer {
+        repr,
+        _phantom: PhantomData,
+      })
+    }
+  }
+
   /// Bind a shader data.
   ///
   /// Once the shader data is bound, the [`BoundShaderData`] object has to be dropped / die in order to bind the shader
   /// data again.
+  ///
+  /// Several shader data can be bound at the same time, each getting its own binding — e.g. per-frame, per-object and
+  /// lighting data used by the same shader. The number of shader data bound at the same time is limited by the
+  /// backend; [`PipelineError::TooManyShaderDataBindings`] is returned when that limit is exceeded.
   pub fn bind_shader_data<T>(
     &'a self,
     shader_data: &'a mut ShaderData<B, T>,
@@ -430,22 +791,74 @@ impl<'a, B> PipelineGate<'a, B> {
     F: for<'b> FnOnce(Pipeline<'b, B>, ShadingGate<'b, B>) -> Result<(), E>,
     E: From<PipelineError>,
   {
+    self.enter_pipeline(&framebuffer.repr, framebuffer.size(), pipeline_state, f)
+  }
+
+  /// Enter a pipeline node rendering into a [`DynamicFramebuffer`].
+  ///
+  /// This is the same as [`PipelineGate::pipeline`], but for framebuffers which attachment formats are only known at
+  /// runtime.
+  pub fn dynamic_pipeline<E, D, F>(
+    &mut self,
+    framebuffer: &DynamicFramebuffer<B, D>,
+    pipeline_state: &PipelineState,
+    f: F,
+  ) -> Render<E>
+  where
+    B: DynamicFramebufferBackend<D> + PipelineBackend<D>,
+    D: Dimensionable,
+    F: for<'b> FnOnce(Pipeline<'b, B>, ShadingGate<'b, B>) -> Result<(), E>,
+    E: From<PipelineError>,
+  {
+    self.enter_pipeline(&framebuffer.repr, framebuffer.size(), pipeline_state, f)
+  }
+
+  fn enter_pipeline<E, D, F>(
+    &mut self,
+    framebuffer: &B::FramebufferRepr,
+    size: D::Size,
+    pipeline_state: &PipelineState,
+    f: F,
+  ) -> Render<E>
+  where
+    B: FramebufferBackend<D> + PipelineBackend<D>,
+    D: Dimensionable,
+    F: for<'b> FnOnce(Pipeline<'b, B>, ShadingGate<'b, B>) -> Result<(), E>,
+    E: From<PipelineError>,
+  {
+    // backends work with a bottom-left origin
+    let flip_height = match pipeline_state.origin {
+      Origin::BottomLeft => None,
+      Origin::TopLeft => Some(D::height(size)),
+    };
+    let flipped_state;
+    let pipeline_state = match flip_height {
+      Some(fb_height) => match pipeline_state.to_bottom_left(fb_height) {
+        Ok(state) => {
+          flipped_state = state;
+          &flipped_state
+        }
+        Err(e) => return Render(Err(e.into())),
+      },
+      None => pipeline_state,
+    };
+
     let render = || {
       unsafe {
-        self
-          .backend
-          .start_pipeline(&framebuffer.repr, pipeline_state);
+        self.backend.start_pipeline(framebuffer, pipeline_state);
       }
 
       let pipeline = unsafe {
         self.backend.new_pipeline().map(|repr| Pipeline {
           repr,
+          target_size: [D::width(size), D::height(size)],
           _phantom: PhantomData,
         })?
       };
 
       let shading_gate = ShadingGate {
         backend: self.backend,
+        flip_height,
       };
 
       f(pipeline, shading_gate)
@@ -661,3 +1074,125 @@ where
     }
   }
 }
+
+/// A _bound_ [`Texture`] sampled through its stencil component.
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineTextureStencil`].
+/// - `D` is the dimension. It must implement [`Dimensionable`].
+/// - `P` is the pixel type. It must implement [`StencilPixel`].
+///
+/// # Notes
+///
+/// This works like [`BoundTexture`], but the [`TextureBinding`] it provides is always sampled as [`Unsigned`]
+/// stencil values, i.e. as a texture of [`StencilIndex8`] pixels.
+///
+/// [`StencilIndex8`]: crate::pixel::StencilIndex8
+pub struct BoundStencilTexture<'a, B, D, P>
+where
+  B: PipelineTextureStencil<D, P>,
+  D: Dimensionable,
+  P: StencilPixel,
+{
+  pub(crate) repr: B::BoundTextureRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B, D, P> BoundStencilTexture<'a, B, D, P>
+where
+  B: PipelineTextureStencil<D, P>,
+  D: Dimensionable,
+  P: StencilPixel,
+{
+  /// Obtain a [`TextureBinding`] object that can be used to refer to this bound texture in shader
+  /// stages.
+  ///
+  /// # Notes
+  ///
+  /// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+  /// an opaque object.
+  pub fn binding(&self) -> TextureBinding<D, Unsigned> {
+    let binding = unsafe { B::texture_binding(&self.repr) };
+    TextureBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
+
+/// Opaque texture buffer binding.
+///
+/// This type represents a bound [`TextureBuffer`] via [`BoundTextureBuffer`]. It can be used along with a [`Uniform`]
+/// to customize a shader’s behavior, and maps to a `samplerBuffer` — or its integral variants — in shaders.
+///
+/// # Parametricity
+///
+/// - `S` is the sampler type. It must implement [`SamplerType`] in most useful methods.
+///
+/// # Notes
+///
+/// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+/// an opaque object.
+///
+/// [`Uniform`]: crate::shader::Uniform
+/// [`SamplerType`]: crate::pixel::SamplerType
+#[derive(Debug)]
+pub struct TextureBufferBinding<S> {
+  binding: u32,
+  _phantom: PhantomData<*const S>,
+}
+
+impl<S> TextureBufferBinding<S> {
+  /// Access the underlying binding value.
+  ///
+  /// # Notes
+  ///
+  /// That value shouldn’t be read nor store, as it’s only meaningful for backend implementations.
+  pub fn binding(self) -> u32 {
+    self.binding
+  }
+}
+
+/// A _bound_ [`TextureBuffer`].
+///
+/// # Parametricity
+///
+/// - `B` is the backend type. It must implement [`PipelineTextureBuffer`].
+/// - `P` is the pixel type. It must implement [`Pixel`].
+///
+/// # Notes
+///
+/// This works like [`BoundTexture`]: pass the [`TextureBufferBinding`] obtained with [`BoundTextureBuffer::binding`]
+/// to your [`ProgramInterface`].
+///
+/// [`ProgramInterface`]: crate::shader::ProgramInterface
+pub struct BoundTextureBuffer<'a, B, P>
+where
+  B: PipelineTextureBuffer<P>,
+  P: Pixel,
+{
+  pub(crate) repr: B::BoundTextureBufferRepr,
+  _phantom: PhantomData<&'a ()>,
+}
+
+impl<'a, B, P> BoundTextureBuffer<'a, B, P>
+where
+  B: PipelineTextureBuffer<P>,
+  P: Pixel,
+{
+  /// Obtain a [`TextureBufferBinding`] object that can be used to refer to this bound texture buffer in shader
+  /// stages.
+  ///
+  /// # Notes
+  ///
+  /// You shouldn’t try to do store / cache or do anything special with that value. Consider it
+  /// an opaque object.
+  pub fn binding(&self) -> TextureBufferBinding<P::SamplerType> {
+    let binding = unsafe { B::texture_buffer_binding(&self.repr) };
+    TextureBufferBinding {
+      binding,
+      _phantom: PhantomData,
+    }
+  }
+}
@@ -37,6 +37,24 @@ pub unsafe trait DepthPixel: Pixel {}
 /// Constraint on [`Pixel`] for renderable ones.
 pub unsafe trait RenderablePixel: Pixel {}
 
+/// Constraint on [`Pixel`] for combined depth and stencil ones.
+///
+/// Such pixels can be sampled either as depth (the default) or as stencil, in which case their stencil component is
+/// sampled as [`StencilIndex8`].
+pub unsafe trait StencilPixel: DepthPixel {}
+
+/// Depth pixels which depth can be read back as normalized unsigned integers.
+///
+/// Normalized depth values are exact, which makes them suitable for comparisons, unlike their floating-point
+/// counterparts.
+pub trait NormDepthPixel: DepthPixel {
+  /// Number of bits of a normalized depth value.
+  const DEPTH_BITS: u32;
+
+  /// Extract the normalized depth out of a raw texel, dropping any stencil information.
+  fn norm_depth(texel: Self::RawEncoding) -> u32;
+}
+
 /// Reify a static sample type at runtime.
 ///
 /// That trait is used to allow sampling with different types than the actual encoding of the
@@ -80,6 +98,7 @@ impl PixelFormat {
       Format::SRGBA(_, _, _, _) => 4,
       Format::Depth(_) => 1,
       Format::DepthStencil(_, _) => 2,
+      Format::Compressed(cf) => cf.channels_len(),
     }
   }
 }
@@ -129,10 +148,15 @@ pub enum Format {
   Depth(Size),
   /// Holds a depth+stencil channel.
   DepthStencil(Size, Size),
+  /// Holds block-compressed texels.
+  Compressed(CompressedFormat),
 }
 
 impl Format {
   /// Size (in bytes) of a pixel that a format represents.
+  ///
+  /// Compressed formats don’t have a per-pixel size and yield `0`; see [`CompressedFormat::bytes_len`] instead. Callers
+  /// must then check for [`Format::Compressed`] before using the result to size texel storage.
   pub fn bytes_len(self) -> usize {
     let bits = match self {
       Format::R(r) => r.bits_len(),
@@ -143,14 +167,72 @@ impl Format {
       Format::SRGBA(r, g, b, a) => r.bits_len() + g.bits_len() + b.bits_len() + a.bits_len(),
       Format::Depth(d) => d.bits_len(),
       Format::DepthStencil(d, s) => d.bits_len() + s.bits_len(),
+      Format::Compressed(_) => 0,
     };
 
     bits / 8
   }
 }
 
+/// Block-compression format.
+///
+/// Compressed formats encode texels by blocks of 4×4 texels. They cannot be rendered to nor read back, and their texels
+/// must be uploaded already compressed, with [`Texture::upload_compressed`]. Support depends on the backend and on the
+/// running context.
+///
+/// [`Texture::upload_compressed`]: crate::texture::Texture::upload_compressed
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompressedFormat {
+  /// BC1 (also known as DXT1): RGB with 1-bit alpha, 8 bytes per block.
+  BC1,
+  /// BC3 (also known as DXT5): RGBA, 16 bytes per block.
+  BC3,
+  /// BC7: high-quality RGBA, 16 bytes per block.
+  BC7,
+  /// ETC2 RGB, 8 bytes per block.
+  ETC2RGB8,
+  /// ETC2 RGBA (with EAC alpha), 16 bytes per block.
+  ETC2RGBA8,
+}
+
+impl CompressedFormat {
+  /// Width and height (in texels) of a block.
+  pub fn block_size(self) -> [u32; 2] {
+    [4, 4]
+  }
+
+  /// Size (in bytes) of a block.
+  pub fn block_bytes_len(self) -> usize {
+    match self {
+      CompressedFormat::BC1 | CompressedFormat::ETC2RGB8 => 8,
+      CompressedFormat::BC3 | CompressedFormat::BC7 | CompressedFormat::ETC2RGBA8 => 16,
+    }
+  }
+
+  /// Size (in bytes) of a `width`×`height` image.
+  ///
+  /// Partial blocks on the edges still take the size of a whole block.
+  pub fn bytes_len(self, width: u32, height: u32) -> usize {
+    let [bw, bh] = self.block_size();
+    let blocks = ((width + bw - 1) / bw) as usize * ((height + bh - 1) / bh) as usize;
+
+    blocks * self.block_bytes_len()
+  }
+
+  /// Number of channels once decompressed.
+  pub fn channels_len(self) -> usize {
+    match self {
+      CompressedFormat::ETC2RGB8 => 3,
+      _ => 4,
+    }
+  }
+}
+
 /// Size in bits a pixel channel can be.
+///
+/// More sizes might be added as new pixel formats get supported, so this enum is not exhaustive.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Size {
   /// 8-bit.
   Eight,
@@ -160,6 +242,8 @@ pub enum Size {
   Eleven,
   /// 16-bit.
   Sixteen,
+  /// 24-bit.
+  TwentyFour,
   /// 32-bit.
   ThirtyTwo,
 }
@@ -172,6 +256,7 @@ impl Size {
       Size::Ten => 10,
       Size::Eleven => 11,
       Size::Sixteen => 16,
+      Size::TwentyFour => 24,
       Size::ThirtyTwo => 32,
     }
   }
@@ -1001,6 +1086,71 @@ impl_Pixel!(
 impl_ColorPixel!(SRGBA8UI);
 impl_RenderablePixel!(SRGBA8UI);
 
+/// BC1 (DXT1) compressed pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC1;
+
+impl_Pixel!(
+  BC1,
+  u8,
+  u8,
+  NormUnsigned,
+  Format::Compressed(CompressedFormat::BC1)
+);
+impl_ColorPixel!(BC1);
+
+/// BC3 (DXT5) compressed pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC3;
+
+impl_Pixel!(
+  BC3,
+  u8,
+  u8,
+  NormUnsigned,
+  Format::Compressed(CompressedFormat::BC3)
+);
+impl_ColorPixel!(BC3);
+
+/// BC7 compressed pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct BC7;
+
+impl_Pixel!(
+  BC7,
+  u8,
+  u8,
+  NormUnsigned,
+  Format::Compressed(CompressedFormat::BC7)
+);
+impl_ColorPixel!(BC7);
+
+/// ETC2 RGB compressed pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2RGB8;
+
+impl_Pixel!(
+  ETC2RGB8,
+  u8,
+  u8,
+  NormUnsigned,
+  Format::Compressed(CompressedFormat::ETC2RGB8)
+);
+impl_ColorPixel!(ETC2RGB8);
+
+/// ETC2 RGBA compressed pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct ETC2RGBA8;
+
+impl_Pixel!(
+  ETC2RGBA8,
+  u8,
+  u8,
+  NormUnsigned,
+  Format::Compressed(CompressedFormat::ETC2RGBA8)
+);
+impl_ColorPixel!(ETC2RGBA8);
+
 /// A depth 32-bit floating pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct Depth32F;
@@ -1008,6 +1158,36 @@ pub struct Depth32F;
 impl_Pixel!(Depth32F, f32, f32, Floating, Format::Depth(Size::ThirtyTwo));
 impl_DepthPixel!(Depth32F);
 
+impl NormDepthPixel for Depth32F {
+  const DEPTH_BITS: u32 = 32;
+
+  fn norm_depth(texel: Self::RawEncoding) -> u32 {
+    quantize_depth(texel, Self::DEPTH_BITS)
+  }
+}
+
+/// A depth 24-bit normalized unsigned pixel format.
+#[derive(Clone, Copy, Debug)]
+pub struct Depth24;
+
+impl_Pixel!(
+  Depth24,
+  u32,
+  u32,
+  NormUnsigned,
+  Format::Depth(Size::TwentyFour)
+);
+impl_DepthPixel!(Depth24);
+
+impl NormDepthPixel for Depth24 {
+  const DEPTH_BITS: u32 = 24;
+
+  fn norm_depth(texel: Self::RawEncoding) -> u32 {
+    // texels are read back as 32-bit normalized integers
+    texel >> 8
+  }
+}
+
 /// A depth 24-bit + stencil 8-bit pixel format.
 #[derive(Clone, Copy, Debug)]
 pub struct Depth32FStencil8;
@@ -1020,3 +1200,119 @@ impl_Pixel!(
   Format::DepthStencil(Size::ThirtyTwo, Size::Eight)
 );
 impl_DepthPixel!(Depth32FStencil8);
+
+unsafe impl StencilPixel for Depth32FStencil8 {}
+
+/// A depth 24-bit normalized unsigned + stencil 8-bit pixel format.
+///
+/// Both are packed in a single 32-bit integer: the depth lies in the 24 most significant bits and the stencil in the 8
+/// least significant bits.
+#[derive(Clone, Copy, Debug)]
+pub struct Depth24Stencil8;
+
+impl_Pixel!(
+  Depth24Stencil8,
+  u32,
+  u32,
+  NormUnsigned,
+  Format::DepthStencil(Size::TwentyFour, Size::Eight)
+);
+impl_DepthPixel!(Depth24Stencil8);
+
+unsafe impl StencilPixel for Depth24Stencil8 {}
+
+impl NormDepthPixel for Depth24Stencil8 {
+  const DEPTH_BITS: u32 = 24;
+
+  fn norm_depth(texel: Self::RawEncoding) -> u32 {
+    texel >> 8
+  }
+}
+
+/// Stencil 8-bit unsigned integral values.
+///
+/// This names the values of the stencil component of combined depth / stencil pixels, such as [`Depth24Stencil8`],
+/// when sampled through a stencil view (see [`Pipeline::bind_texture_stencil`]). Stencil values are unsigned integers
+/// and must be sampled with an unsigned sampler (e.g. `usampler2D`), with nearest filtering.
+///
+/// This is not a [`Pixel`] type: textures cannot hold stencil values only, so they are always sampled from a
+/// [`StencilPixel`] texture.
+///
+/// [`Pipeline::bind_texture_stencil`]: crate::pipeline::Pipeline::bind_texture_stencil
+#[derive(Clone, Copy, Debug)]
+pub struct StencilIndex8;
+
+/// Kind of projection used to produce depth values.
+///
+/// This is needed to interpret depth values stored in a depth texture, as perspective projections store them
+/// non-linearly.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DepthProjection {
+  /// Perspective projection; depth values are non-linear.
+  Perspective,
+  /// Orthographic projection; depth values are already linear.
+  Orthographic,
+}
+
+/// Quantize a depth value to a normalized unsigned integer of `bits` bits, with `bits` in `[1; 32]`.
+///
+/// `depth` is clamped to `[0; 1]` and mapped to `[0; 2^bits - 1]`, rounding to the nearest integer, which is how
+/// depth values get stored in normalized depth buffers. This is useful to compute the expected result of
+/// [`Framebuffer::read_depth_raw`].
+///
+/// [`Framebuffer::read_depth_raw`]: crate::framebuffer::Framebuffer::read_depth_raw
+pub fn quantize_depth(depth: f32, bits: u32) -> u32 {
+  let max = (u64::MAX >> (64 - bits)) as f64;
+  (depth.clamp(0., 1.) as f64 * max).round() as u32
+}
+
+/// Linearize a depth value read from a depth texture.
+///
+/// `depth` is the window-space depth value, in `[0; 1]`, as stored in the depth texture. `near` and `far` are the
+/// distances to the clipping planes used by the projection that produced the value. The returned value is the distance
+/// to the viewer along the view axis, in `[near; far]`.
+pub fn linearize_depth(depth: f32, near: f32, far: f32, projection: DepthProjection) -> f32 {
+  match projection {
+    DepthProjection::Perspective => {
+      let ndc = depth * 2. - 1.;
+      2. * near * far / (far + near - ndc * (far - near))
+    }
+
+    DepthProjection::Orthographic => near + depth * (far - near),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_depth(depth: f32, near: f32, far: f32, projection: DepthProjection, expected: f32) {
+    let linear = linearize_depth(depth, near, far, projection);
+    assert!(
+      (linear - expected).abs() < 1e-4,
+      "{:?} depth {} in [{}; {}] linearized to {}, expected {}",
+      projection,
+      depth,
+      near,
+      far,
+      linear,
+      expected
+    );
+  }
+
+  #[test]
+  fn linearize_perspective_depth() {
+    assert_depth(0., 1., 100., DepthProjection::Perspective, 1.);
+    assert_depth(1., 1., 100., DepthProjection::Perspective, 100.);
+
+    // a perspective projection with near = 1 and far = 100 stores the plane at distance 10 at depth 10 / 11
+    assert_depth(10. / 11., 1., 100., DepthProjection::Perspective, 10.);
+  }
+
+  #[test]
+  fn linearize_orthographic_depth() {
+    assert_depth(0., 1., 3., DepthProjection::Orthographic, 1.);
+    assert_depth(1., 1., 3., DepthProjection::Orthographic, 3.);
+    assert_depth(0.5, 1., 3., DepthProjection::Orthographic, 2.);
+  }
+}
@@ -1,9 +1,14 @@
 //! GPU queries.
 //!
 //! GPU queries allow to get information about the backend and the GPU in a straight-forward way.
+//!
+//! [`PipelineStatsQuery`] counts what the GPU processes while rendering, which is useful to check the effectiveness
+//! of culling, for instance.
 
 use crate::{
-  backend::query::{Query as QueryBackend, QueryError},
+  backend::query::{
+    PipelineStatsQuery as PipelineStatsQueryBackend, Query as QueryBackend, QueryError,
+  },
   context::GraphicsContext,
 };
 
@@ -54,3 +59,84 @@ where
     self.backend.max_texture_array_elements()
   }
 }
+
+/// Statistics counted by a [`PipelineStatsQuery`].
+///
+/// Statistics the backend cannot count are [`None`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PipelineStats {
+  /// Number of primitives generated by the last vertex processing stage.
+  pub primitives_generated: Option<u64>,
+  /// Number of vertices submitted to the vertex shader.
+  pub vertices_submitted: Option<u64>,
+  /// Number of samples passing the depth and stencil tests.
+  pub samples_passed: Option<u64>,
+}
+
+/// Pipeline statistics query.
+///
+/// Such a query counts what the GPU processes between [`PipelineStatsQuery::begin`] and [`PipelineStatsQuery::end`],
+/// which are typically called around a pipeline:
+///
+/// ```ignore
+/// let mut query = ctxt.new_pipeline_stats_query()?;
+///
+/// query.begin()?;
+/// ctxt.new_pipeline_gate().pipeline(/* … */);
+/// query.end()?;
+///
+/// let primitives = query.primitives_generated()?;
+/// ```
+///
+/// Only one [`PipelineStatsQuery`] can be active at a time. A query can be run several times; its statistics are the
+/// ones of its last run.
+pub struct PipelineStatsQuery<B>
+where
+  B: ?Sized + PipelineStatsQueryBackend,
+{
+  repr: B::PipelineStatsQueryRepr,
+}
+
+impl<B> PipelineStatsQuery<B>
+where
+  B: ?Sized + PipelineStatsQueryBackend,
+{
+  /// Create a new [`PipelineStatsQuery`].
+  pub fn new(ctxt: &mut impl GraphicsContext<Backend = B>) -> Result<Self, QueryError> {
+    let repr = unsafe { ctxt.backend().new_pipeline_stats_query()? };
+    Ok(PipelineStatsQuery { repr })
+  }
+
+  /// Start counting.
+  pub fn begin(&mut self) -> Result<(), QueryError> {
+    unsafe { B::begin_pipeline_stats_query(&mut self.repr) }
+  }
+
+  /// Stop counting.
+  pub fn end(&mut self) -> Result<(), QueryError> {
+    unsafe { B::end_pipeline_stats_query(&mut self.repr) }
+  }
+
+  /// All the statistics counted during the last run of the query.
+  ///
+  /// This waits for the GPU to be done with the commands issued while the query was active.
+  pub fn stats(&mut self) -> Result<PipelineStats, QueryError> {
+    unsafe { B::pipeline_stats(&mut self.repr) }
+  }
+
+  /// Number of primitives generated during the last run of the query, if the backend can count them.
+  pub fn primitives_generated(&mut self) -> Result<Option<u64>, QueryError> {
+    self.stats().map(|stats| stats.primitives_generated)
+  }
+
+  /// Number of vertices submitted during the last run of the query, if the backend can count them.
+  pub fn vertices_submitted(&mut self) -> Result<Option<u64>, QueryError> {
+    self.stats().map(|stats| stats.vertices_submitted)
+  }
+
+  /// Number of samples that passed the depth and stencil tests during the last run of the query, if the backend can
+  /// count them.
+  pub fn samples_passed(&mut self) -> Result<Option<u64>, QueryError> {
+    self.stats().map(|stats| stats.samples_passed)
+  }
+}
@@ -6,6 +6,8 @@
 //! [`Tess`]: crate::tess::Tess
 
 use crate::backend::render_gate::RenderGate as RenderGateBackend;
+use crate::depth_stencil::Comparison;
+use crate::pipeline::PipelineError;
 use crate::render_state::RenderState;
 use crate::tess_gate::TessGate;
 
@@ -19,6 +21,8 @@ where
   B: ?Sized,
 {
   pub(crate) backend: &'a mut B,
+  // framebuffer height to flip scissor regions against, when using a top-left origin
+  pub(crate) flip_height: Option<u32>,
 }
 
 impl<'a, B> RenderGate<'a, B>
@@ -26,13 +30,15 @@ where
   B: ?Sized + RenderGateBackend,
 {
   /// Enter a [`RenderGate`] and go deeper in the pipeline.
+  ///
+  /// With a top-left origin, [`PipelineError::InvalidRegion`] is returned if the scissor region of `rdr_st` cannot be
+  /// flipped.
   pub fn render<'b, E, F>(&'b mut self, rdr_st: &RenderState, f: F) -> Result<(), E>
   where
     F: FnOnce(TessGate<'b, B>) -> Result<(), E>,
+    E: From<PipelineError>,
   {
-    unsafe {
-      self.backend.enter_render_state(rdr_st);
-    }
+    self.enter_render_state(rdr_st)?;
 
     let tess_gate = TessGate {
       backend: self.backend,
@@ -40,4 +46,47 @@ where
 
     f(tess_gate)
   }
+
+  /// Enter a [`RenderGate`] with `rdr_st` but the depth test overridden by `depth_test`, and go deeper in the pipeline.
+  ///
+  /// This avoids building a whole new [`RenderState`] when only the depth comparison changes between passes — e.g. a
+  /// color pass using [`Comparison::Equal`] over the depth of a prepass. `rdr_st` is applied again once `f` returns,
+  /// restoring its depth comparison.
+  pub fn render_with_depth<E, F>(
+    &mut self,
+    depth_test: impl Into<Option<Comparison>>,
+    rdr_st: &RenderState,
+    f: F,
+  ) -> Result<(), E>
+  where
+    F: FnOnce(TessGate<B>) -> Result<(), E>,
+    E: From<PipelineError>,
+  {
+    self.enter_render_state(&rdr_st.clone().set_depth_test(depth_test))?;
+
+    let r = f(TessGate {
+      backend: self.backend,
+    });
+
+    self.enter_render_state(rdr_st)?;
+
+    r
+  }
+
+  fn enter_render_state(&mut self, rdr_st: &RenderState) -> Result<(), PipelineError> {
+    let flipped_st;
+    let rdr_st = match (self.flip_height, rdr_st.scissor()) {
+      (Some(fb_height), Some(region)) => {
+        flipped_st = rdr_st.clone().set_scissor(region.flip_y(fb_height)?);
+        &flipped_st
+      }
+      _ => rdr_st,
+    };
+
+    unsafe {
+      self.backend.enter_render_state(rdr_st);
+    }
+
+    Ok(())
+  }
 }
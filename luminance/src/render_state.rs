@@ -18,10 +18,14 @@ use crate::{
 pub struct RenderState {
   /// Blending configuration.
   blending: Option<BlendingMode>,
+  /// Draw buffers blending is enabled for; `None` enables it for all of them.
+  blend_enabled_buffers: Option<u32>,
   /// Depth test configuration.
   depth_test: Option<Comparison>,
   /// Depth write configuration.
   depth_write: Write,
+  /// Color channels (red, green, blue, alpha) written to the color attachments.
+  color_mask: [bool; 4],
   /// Stencil test configuration.
   stencil_test: Option<StencilTest>,
   /// Stencil operations.
@@ -30,10 +34,23 @@ pub struct RenderState {
   face_culling: Option<FaceCulling>,
   /// Scissor region configuration.
   scissor: Option<ScissorRegion>,
+  /// Enabled clip distances.
+  clip_planes: u32,
+  /// Color attachments written by each fragment output; `None` writes to all of them.
+  draw_buffers: Option<Vec<Option<usize>>>,
+  /// Minimum fraction of samples to shade, stored as its bit representation to keep [`Eq`]; `None` disables sample
+  /// shading.
+  min_sample_shading: Option<u32>,
+  /// Whether the point size is set by shaders.
+  program_point_size: bool,
 }
 
 impl RenderState {
   /// Override the blending configuration.
+  ///
+  /// Blending into floating-point color attachments, such as [`RGBA32F`](crate::pixel::RGBA32F) ones, doesn’t clamp
+  /// the source nor the destination colors to `[0; 1]`: colors above `1` add up, as HDR compositing expects. Only
+  /// normalized attachments clamp, as they cannot store anything outside of that range anyway.
   pub fn set_blending<B>(self, blending: B) -> Self
   where
     B: Into<Option<Blending>>,
@@ -60,6 +77,26 @@ impl RenderState {
     self.blending
   }
 
+  /// Enable blending only for some draw buffers.
+  ///
+  /// Bit `i` of `mask` enables blending for the draw buffer `i` (i.e. the color slot at index `i` of the framebuffer);
+  /// all the enabled draw buffers share the blending configuration set with [`RenderState::set_blending`] or
+  /// [`RenderState::set_blending_separate`]. This has no effect if blending is disabled. Backends unable to toggle
+  /// blending per draw buffer (e.g. WebGL2) blend all of them and report an error if error checking is enabled.
+  pub fn set_blend_enabled_buffers(self, mask: u32) -> Self {
+    RenderState {
+      blend_enabled_buffers: Some(mask),
+      ..self
+    }
+  }
+
+  /// Get the draw buffers blending is enabled for, as a bit mask.
+  ///
+  /// `None` means that blending is enabled for all draw buffers.
+  pub fn blend_enabled_buffers(&self) -> Option<u32> {
+    self.blend_enabled_buffers
+  }
+
   /// Override the depth test configuration.
   pub fn set_depth_test<D>(self, depth_test: D) -> Self
   where
@@ -87,6 +124,22 @@ impl RenderState {
     self.depth_write
   }
 
+  /// Select the color channels written to the color attachments, as `[red, green, blue, alpha]`.
+  ///
+  /// Fragments are still shaded and depth / stencil tested, but the disabled channels of the color attachments keep
+  /// their contents. Disabling all the channels is typical of depth prepasses and shadow map passes, which only need
+  /// the depth buffer. Pipelines clearing the color attachments clear all the channels, whatever the color mask.
+  ///
+  /// By default, all the channels are written.
+  pub fn set_color_mask(self, color_mask: [bool; 4]) -> Self {
+    RenderState { color_mask, ..self }
+  }
+
+  /// Get the color channels written to the color attachments.
+  pub fn color_mask(&self) -> [bool; 4] {
+    self.color_mask
+  }
+
   /// Override the stencil test configuration.
   pub fn set_stencil_test(self, stencil_test: impl Into<Option<StencilTest>>) -> Self {
     let stencil_test = stencil_test.into();
@@ -116,6 +169,9 @@ impl RenderState {
   }
 
   /// Override the face culling configuration.
+  ///
+  /// Pass `None` to disable face culling, a [`FaceCulling`] to set both the order and the mode, or only a
+  /// [`FaceCullingMode`](crate::face_culling::FaceCullingMode) to cull with the default counter-clockwise order.
   pub fn set_face_culling<FC>(self, face_culling: FC) -> Self
   where
     FC: Into<Option<FaceCulling>>,
@@ -146,27 +202,144 @@ impl RenderState {
   pub fn scissor(&self) -> &Option<ScissorRegion> {
     &self.scissor
   }
+
+  /// Override the enabled clip distances.
+  ///
+  /// Bit `i` of `mask` enables the clip distance `i`: vertex shaders are then expected to write `gl_ClipDistance[i]`,
+  /// and primitives are clipped where the interpolated distance becomes negative. Bits past the number of clip
+  /// distances supported by the backend are ignored. Backends without user clip planes (e.g. WebGL2) don’t clip
+  /// anything and report an error if error checking is enabled.
+  pub fn set_clip_planes(self, mask: u32) -> Self {
+    RenderState {
+      clip_planes: mask,
+      ..self
+    }
+  }
+
+  /// Get the enabled clip distances, as a bit mask.
+  pub fn clip_planes(&self) -> u32 {
+    self.clip_planes
+  }
+
+  /// Select the color attachments written by the fragment outputs.
+  ///
+  /// Fragment output `i` is written to the color attachment `draw_buffers[i]` (i.e. the color slot at that index of
+  /// the framebuffer), or discarded if `None`. Attachments not selected keep their contents, which allows updating a
+  /// subset of the attachments of a G-buffer. Selecting an attachment the framebuffer doesn’t have leaves the draw
+  /// buffers untouched and reports an error if error checking is enabled. WebGL2 only accepts attachment `i` or `None`
+  /// for the fragment output `i`.
+  ///
+  /// By default, fragment output `i` is written to attachment `i`.
+  pub fn set_draw_buffers(self, draw_buffers: &[Option<usize>]) -> Self {
+    RenderState {
+      draw_buffers: Some(draw_buffers.to_vec()),
+      ..self
+    }
+  }
+
+  /// Get the color attachments written by the fragment outputs, if selected.
+  pub fn draw_buffers(&self) -> Option<&[Option<usize>]> {
+    self.draw_buffers.as_deref()
+  }
+
+  /// Enable sample shading, or disable it with `None`.
+  ///
+  /// With sample shading, the fragment shader runs for at least `min_fraction` (clamped to `[0; 1]`) of the samples
+  /// of each pixel instead of once per pixel, which reduces the aliasing produced by shaders (e.g. alpha-tested
+  /// foliage) on multisampled framebuffers — `1.` runs it for every sample. It has no effect on framebuffers without
+  /// multisampling. Backends without sample shading (e.g. WebGL2) shade once per pixel and report an error if error
+  /// checking is enabled.
+  pub fn set_min_sample_shading<F>(self, min_fraction: F) -> Self
+  where
+    F: Into<Option<f32>>,
+  {
+    RenderState {
+      min_sample_shading: min_fraction
+        .into()
+        .map(|fraction| fraction.clamp(0., 1.).to_bits()),
+      ..self
+    }
+  }
+
+  /// Get the minimum fraction of samples to shade, if sample shading is enabled.
+  pub fn min_sample_shading(&self) -> Option<f32> {
+    self.min_sample_shading.map(f32::from_bits)
+  }
+
+  /// Render state of a depth prepass.
+  ///
+  /// Fragments closer than the ones already rendered (i.e. [`Comparison::Less`]) write their depth, but no color. A
+  /// later color pass using the same geometry and transformations with [`Comparison::Equal`] — or
+  /// [`Comparison::LessOrEqual`] — then shades each pixel only once, for the closest fragment. The other settings are
+  /// the ones of [`RenderState::default`].
+  pub fn depth_prepass() -> Self {
+    RenderState::default()
+      .set_depth_test(Comparison::Less)
+      .set_depth_write(Write::On)
+      .set_color_mask([false; 4])
+  }
+
+  /// Render state of two-sided surfaces, such as foliage or paper.
+  ///
+  /// Face culling is disabled, so that both faces of the geometry are rendered. Lit two-sided surfaces must flip their
+  /// normal for back faces — which fragment shaders tell apart with `gl_FrontFacing` — for them to be lit as front
+  /// faces. The other settings are the ones of [`RenderState::default`].
+  pub fn two_sided() -> Self {
+    RenderState::default().set_face_culling(None)
+  }
+
+  /// Let shaders set the size of rendered points.
+  ///
+  /// When enabled, points rendered with [`Mode::Point`] take the size written to `gl_PointSize` by the vertex (or
+  /// geometry) shader, in pixels; otherwise, they are one pixel wide. Points are always rendered as sprites:
+  /// `gl_PointCoord` gives the coordinates of fragments within the point in the fragment shader, which is typically
+  /// used to texture particles. Some backends (e.g. WebGL2) always let shaders set the point size.
+  ///
+  /// [`Mode::Point`]: crate::tess::Mode::Point
+  pub fn set_program_point_size(self, program_point_size: bool) -> Self {
+    RenderState {
+      program_point_size,
+      ..self
+    }
+  }
+
+  /// Whether the point size is set by shaders.
+  pub fn program_point_size(&self) -> bool {
+    self.program_point_size
+  }
 }
 
 impl Default for RenderState {
   /// The default `RenderState`.
   ///
   ///   - `blending`: `None`
+  ///   - `blend_enabled_buffers`: `None`
   ///   - `depth_test`: `Some(Comparison::Less)`
   ///   - `depth_write`: `Write::On`
+  ///   - `color_mask`: `[true; 4]`
   ///   - `stencil_test`: `None`
   ///   - `stencil_operations`: `StencilOperations::default()`
   ///   - `face_culling`: `None`
   ///   - 'scissor_region`: `None`
+  ///   - `clip_planes`: `0`
+  ///   - `draw_buffers`: `None`
+  ///   - `min_sample_shading`: `None`
+  ///   - `program_point_size`: `false`
   fn default() -> Self {
     RenderState {
       blending: None,
+      blend_enabled_buffers: None,
       depth_test: Some(Comparison::Less),
       depth_write: Write::On,
+      color_mask: [true; 4],
       stencil_test: None,
       stencil_operations: StencilOperations::default(),
       face_culling: None,
       scissor: None,
+      clip_planes: 0,
+      draw_buffers: None,
+      min_sample_shading: None,
+      program_point_size: false,
     }
   }
 }
@@ -3,6 +3,8 @@
 //! The scissor test is a special test performed at rendering time. It allows to define a region of the screen for which
 //! fragments will be discarded.
 
+use crate::pipeline::PipelineError;
+
 /// The region outside of which fragments will be discarded.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct ScissorRegion {
@@ -18,3 +20,52 @@ pub struct ScissorRegion {
   /// The screen height of the scissor region.
   pub height: u32,
 }
+
+impl ScissorRegion {
+  /// Convert a region expressed with a top-left origin to the bottom-left one, for a framebuffer of the given height.
+  ///
+  /// Regions which Y coordinate plus height overflows are rejected.
+  pub(crate) fn flip_y(&self, fb_height: u32) -> Result<Self, PipelineError> {
+    let top = self
+      .y
+      .checked_add(self.height)
+      .ok_or_else(|| PipelineError::invalid_region(self.y, self.height))?;
+
+    Ok(ScissorRegion {
+      y: fb_height.saturating_sub(top),
+      ..*self
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn flip_y() {
+    let region = ScissorRegion {
+      x: 1,
+      y: 2,
+      width: 3,
+      height: 4,
+    };
+
+    assert_eq!(region.flip_y(10), Ok(ScissorRegion { y: 4, ..region }));
+  }
+
+  #[test]
+  fn flip_y_overflow() {
+    let region = ScissorRegion {
+      x: 0,
+      y: 2,
+      width: 1,
+      height: u32::MAX,
+    };
+
+    assert_eq!(
+      region.flip_y(10),
+      Err(PipelineError::invalid_region(2, u32::MAX))
+    );
+  }
+}
@@ -126,14 +126,29 @@
 //! [`Pipeline`]: crate::pipeline::Pipeline
 //! [`ShaderData`]: crate::shader::ShaderData
 
+pub mod preprocessor;
 pub mod types;
 
 use crate::{
-  backend::shader::{Shader, ShaderData as ShaderDataBackend, Uniformable},
+  backend::{
+    feedback::ShaderFeedback,
+    pipeline::PipelineTexture,
+    shader::{
+      Shader, ShaderData as ShaderDataBackend, Uniformable, UniformableMatrix, UniformableReadback,
+    },
+  },
   context::GraphicsContext,
+  feedback::FeedbackMode,
+  pipeline::{BoundTexture, TextureBinding},
+  pixel::Pixel,
+  shader::{
+    preprocessor::{IncludeError, PreprocessedSource},
+    types::Mat44,
+  },
+  texture::Dimensionable,
   vertex::Semantics,
 };
-use std::{error, fmt, marker::PhantomData};
+use std::{any::TypeId, collections::HashMap, error, fmt, marker::PhantomData};
 
 /// A shader stage type.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -170,6 +185,8 @@ pub enum StageError {
   CompilationFailed(StageType, String),
   /// Occurs when you try to create a shader which type is not supported on the current hardware.
   UnsupportedType(StageType),
+  /// Occurs when the `#include` directives of a shader cannot be resolved.
+  IncludeFailed(StageType, IncludeError),
 }
 
 impl StageError {
@@ -182,6 +199,11 @@ impl StageError {
   pub fn unsupported_type(ty: StageType) -> Self {
     StageError::UnsupportedType(ty)
   }
+
+  /// Occurs when the `#include` directives of a shader cannot be resolved.
+  pub fn include_failed(ty: StageType, e: IncludeError) -> Self {
+    StageError::IncludeFailed(ty, e)
+  }
 }
 
 impl fmt::Display for StageError {
@@ -190,11 +212,20 @@ impl fmt::Display for StageError {
       StageError::CompilationFailed(ref ty, ref r) => write!(f, "{} compilation error: {}", ty, r),
 
       StageError::UnsupportedType(ty) => write!(f, "unsupported {}", ty),
+
+      StageError::IncludeFailed(ref ty, ref e) => write!(f, "{} include error: {}", ty, e),
     }
   }
 }
 
-impl error::Error for StageError {}
+impl error::Error for StageError {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      StageError::IncludeFailed(_, e) => Some(e),
+      _ => None,
+    }
+  }
+}
 
 impl From<StageError> for ProgramError {
   fn from(e: StageError) -> Self {
@@ -234,6 +265,20 @@ pub enum ProgramError {
   StageError(StageError),
   /// Program link failed. You can inspect the reason by looking at the contained [`String`].
   LinkFailed(String),
+  /// A program binary was rejected by the backend. You can inspect the reason by looking at the contained [`String`].
+  ///
+  /// This typically happens when the driver changed since the binary was retrieved; the program must then be built
+  /// from its sources again.
+  BinaryRejected(String),
+  /// The geometry shader declares more output vertices (`layout(max_vertices = N) out;`) than the backend supports.
+  ///
+  /// This is reported before linking, as drivers usually fail to link such programs with obscure messages.
+  GeometryOutputTooLarge {
+    /// Number of output vertices declared by the geometry shader.
+    requested: usize,
+    /// Maximum number of output vertices supported by the backend.
+    max: usize,
+  },
   /// A program warning.
   Warning(ProgramWarning),
 }
@@ -254,6 +299,16 @@ impl ProgramError {
     ProgramError::LinkFailed(reason.into())
   }
 
+  /// A program binary was rejected by the backend.
+  pub fn binary_rejected(reason: impl Into<String>) -> Self {
+    ProgramError::BinaryRejected(reason.into())
+  }
+
+  /// The geometry shader declares more output vertices than the backend supports.
+  pub fn geometry_output_too_large(requested: usize, max: usize) -> Self {
+    ProgramError::GeometryOutputTooLarge { requested, max }
+  }
+
   /// A program warning.
   pub fn warning(w: ProgramWarning) -> Self {
     ProgramError::Warning(w)
@@ -269,6 +324,16 @@ impl fmt::Display for ProgramError {
 
       ProgramError::LinkFailed(ref s) => write!(f, "shader program failed to link: {}", s),
 
+      ProgramError::BinaryRejected(ref s) => {
+        write!(f, "shader program binary was rejected: {}", s)
+      }
+
+      ProgramError::GeometryOutputTooLarge { requested, max } => write!(
+        f,
+        "geometry shader outputs {} vertices but at most {} are supported",
+        requested, max
+      ),
+
       ProgramError::Warning(ref e) => write!(f, "shader program warning: {}", e),
     }
   }
@@ -291,6 +356,13 @@ pub enum ProgramWarning {
   Uniform(UniformWarning),
   /// Some vertex attribute is ill-formed.
   VertexAttrib(VertexAttribWarning),
+  /// The fragment shader forces early fragment tests (`layout(early_fragment_tests) in;`) but also uses a construct
+  /// that conflicts with them (`discard` or a `gl_FragDepth` write). The carried [`String`] is the name of that
+  /// construct.
+  EarlyFragmentTestsConflict(String),
+  /// A feedback varying declared with [`ProgramBuilder::with_feedback_varyings`] doesn’t exist in the program, and
+  /// won’t be captured. The carried [`String`] is its name.
+  FeedbackVarying(String),
 }
 
 impl fmt::Display for ProgramWarning {
@@ -298,6 +370,14 @@ impl fmt::Display for ProgramWarning {
     match *self {
       ProgramWarning::Uniform(ref e) => write!(f, "uniform warning: {}", e),
       ProgramWarning::VertexAttrib(ref e) => write!(f, "vertex attribute warning: {}", e),
+      ProgramWarning::EarlyFragmentTestsConflict(ref construct) => write!(
+        f,
+        "early fragment tests are forced but the fragment shader uses {}, which conflicts with them",
+        construct
+      ),
+      ProgramWarning::FeedbackVarying(ref name) => {
+        write!(f, "feedback varying {} doesn’t exist", name)
+      }
     }
   }
 }
@@ -307,6 +387,8 @@ impl error::Error for ProgramWarning {
     match self {
       ProgramWarning::Uniform(e) => Some(e),
       ProgramWarning::VertexAttrib(e) => Some(e),
+      ProgramWarning::EarlyFragmentTestsConflict(_) => None,
+      ProgramWarning::FeedbackVarying(_) => None,
     }
   }
 }
@@ -511,6 +593,39 @@ where
   }
 }
 
+/// A field of an array of structs in a shader program, for every element of the array.
+///
+/// GLSL doesn’t store arrays of structs contiguously: `lights[0].position` and `lights[1].position` are two unrelated
+/// uniforms. A [`UniformArrayField`] gathers the uniforms of the same field across the `N` elements of such an array, so
+/// that they can all be set at once with [`ProgramInterface::set_array_field`].
+///
+/// It is created with [`UniformBuilder::ask_array_struct`]. [`UniformBuilder::ask_array_elements`] gathers the elements
+/// of an array of `T` instead, which backends can update at once when several consecutive elements are set.
+///
+/// # Parametricity
+///
+/// - `T` is the type of the field.
+/// - `N` is the length of the array.
+#[derive(Debug)]
+pub struct UniformArrayField<T, const N: usize>
+where
+  T: ?Sized,
+{
+  uniforms: Vec<Uniform<T>>,
+  // whether uniforms are consecutive elements of a single array
+  elements: bool,
+}
+
+impl<T, const N: usize> UniformArrayField<T, N>
+where
+  T: ?Sized,
+{
+  /// Uniforms of the field, one per array element.
+  pub fn uniforms(&self) -> &[Uniform<T>] {
+    &self.uniforms
+  }
+}
+
 /// Type of a uniform.
 ///
 /// This is an exhaustive list of possible types of value you can send to a shader program.
@@ -587,6 +702,8 @@ pub enum UniformType {
   ISampler1DArray,
   /// Signed integral 2D array texture sampler.
   ISampler2DArray,
+  /// Signed integral 2D multisample texture sampler.
+  ISampler2DMS,
   /// Unsigned integral 1D texture sampler.
   UISampler1D,
   /// Unsigned integral 2D texture sampler.
@@ -597,6 +714,8 @@ pub enum UniformType {
   UISampler1DArray,
   /// Unsigned integral 2D array texture sampler.
   UISampler2DArray,
+  /// Unsigned integral 2D multisample texture sampler.
+  UISampler2DMS,
   /// Floating-point 1D texture sampler.
   Sampler1D,
   /// Floating-point 2D texture sampler.
@@ -607,12 +726,20 @@ pub enum UniformType {
   Sampler1DArray,
   /// Floating-point 2D array texture sampler.
   Sampler2DArray,
+  /// Floating-point 2D multisample texture sampler.
+  Sampler2DMS,
   /// Signed cubemap sampler.
   ICubemap,
   /// Unsigned cubemap sampler.
   UICubemap,
   /// Floating-point cubemap sampler.
   Cubemap,
+  /// Signed integral buffer texture sampler.
+  ISamplerBuffer,
+  /// Unsigned integral buffer texture sampler.
+  UISamplerBuffer,
+  /// Floating-point buffer texture sampler.
+  SamplerBuffer,
 
   /// Shader data binding.
   ShaderDataBinding,
@@ -652,19 +779,25 @@ impl fmt::Display for UniformType {
       UniformType::ISampler3D => f.write_str("isampler3D"),
       UniformType::ISampler1DArray => f.write_str("isampler1DArray"),
       UniformType::ISampler2DArray => f.write_str("isampler2DArray"),
+      UniformType::ISampler2DMS => f.write_str("isampler2DMS"),
       UniformType::UISampler1D => f.write_str("usampler1D"),
       UniformType::UISampler2D => f.write_str("usampler2D"),
       UniformType::UISampler3D => f.write_str("usampler3D"),
       UniformType::UISampler1DArray => f.write_str("usampler1DArray"),
       UniformType::UISampler2DArray => f.write_str("usampler2DArray"),
+      UniformType::UISampler2DMS => f.write_str("usampler2DMS"),
       UniformType::Sampler1D => f.write_str("sampler1D"),
       UniformType::Sampler2D => f.write_str("sampler2D"),
       UniformType::Sampler3D => f.write_str("sampler3D"),
       UniformType::Sampler1DArray => f.write_str("sampler1DArray"),
       UniformType::Sampler2DArray => f.write_str("sampler2DArray"),
+      UniformType::Sampler2DMS => f.write_str("sampler2DMS"),
       UniformType::ICubemap => f.write_str("isamplerCube"),
       UniformType::UICubemap => f.write_str("usamplerCube"),
       UniformType::Cubemap => f.write_str("samplerCube"),
+      UniformType::ISamplerBuffer => f.write_str("isamplerBuffer"),
+      UniformType::UISamplerBuffer => f.write_str("usamplerBuffer"),
+      UniformType::SamplerBuffer => f.write_str("samplerBuffer"),
       UniformType::ShaderDataBinding => f.write_str("shader data binding"),
     }
   }
@@ -682,6 +815,8 @@ where
   B: ?Sized + Shader,
 {
   repr: B::StageRepr,
+  early_fragment_tests: Option<EarlyFragmentTests>,
+  max_vertices: Option<usize>,
 }
 
 impl<B> Stage<B>
@@ -706,13 +841,130 @@ where
     C: GraphicsContext<Backend = B>,
     R: AsRef<str>,
   {
+    let src = src.as_ref();
+    let early_fragment_tests = if ty == StageType::FragmentShader {
+      Some(EarlyFragmentTests::scan(src))
+    } else {
+      None
+    };
+    let max_vertices = if ty == StageType::GeometryShader {
+      scan_max_vertices(src)
+    } else {
+      None
+    };
+
     unsafe {
-      ctx
-        .backend()
-        .new_stage(ty, src.as_ref())
-        .map(|repr| Stage { repr })
+      ctx.backend().new_stage(ty, src).map(|repr| Stage {
+        repr,
+        early_fragment_tests,
+        max_vertices,
+      })
     }
   }
+
+  /// Create a new stage of type `ty` by compiling `src`, after having resolved its `#include` directives against
+  /// `files`.
+  ///
+  /// `src` is named after `ty` in include errors. See the [`preprocessor`] module for further details.
+  pub fn new_with_includes<C, R>(
+    ctx: &mut C,
+    ty: StageType,
+    src: R,
+    files: &HashMap<String, String>,
+  ) -> Result<Self, StageError>
+  where
+    C: GraphicsContext<Backend = B>,
+    R: AsRef<str>,
+  {
+    let preprocessed = PreprocessedSource::new(ty.to_string(), src.as_ref(), files)
+      .map_err(|e| StageError::include_failed(ty, e))?;
+
+    Self::new(ctx, ty, preprocessed.source())
+  }
+}
+
+/// Early fragment tests information found in a fragment shader source.
+#[derive(Clone, Debug, Default)]
+struct EarlyFragmentTests {
+  /// Whether `layout(early_fragment_tests) in;` is present.
+  forced: bool,
+  /// Constructs conflicting with early fragment tests found in the source.
+  conflicts: Vec<&'static str>,
+}
+
+impl EarlyFragmentTests {
+  fn scan(src: &str) -> Self {
+    let src = strip_glsl_comments(src);
+    let forced = layout_qualifiers(&src).any(|q| q == "early_fragment_tests");
+    let conflicts = ["discard", "gl_FragDepth"]
+      .iter()
+      .copied()
+      .filter(|&ident| contains_glsl_identifier(&src, ident))
+      .collect();
+
+    EarlyFragmentTests { forced, conflicts }
+  }
+}
+
+// Find the number of output vertices declared by a geometry shader with `layout(max_vertices = N) out;`.
+fn scan_max_vertices(src: &str) -> Option<usize> {
+  layout_qualifiers(&strip_glsl_comments(src)).find_map(|q| {
+    let (name, value) = q.split_once('=')?;
+
+    if name.trim() == "max_vertices" {
+      value.trim().parse().ok()
+    } else {
+      None
+    }
+  })
+}
+
+// Iterate over the qualifiers of all the layout declarations of a GLSL source.
+fn layout_qualifiers(src: &str) -> impl Iterator<Item = &str> {
+  src
+    .match_indices("layout")
+    .filter_map(move |(i, _)| {
+      let rest = src[i + "layout".len()..].trim_start();
+      rest.strip_prefix('(')?.split(')').next()
+    })
+    .flat_map(|qualifiers| qualifiers.split(','))
+    .map(str::trim)
+}
+
+// Remove line and block comments from a GLSL source.
+fn strip_glsl_comments(src: &str) -> String {
+  let mut out = String::with_capacity(src.len());
+  let mut rest = src;
+
+  while let Some(i) = rest.find('/') {
+    out.push_str(&rest[..i]);
+    let tail = &rest[i..];
+
+    if tail.starts_with("//") {
+      rest = tail.find('\n').map_or("", |j| &tail[j..]);
+    } else if let Some(comment) = tail.strip_prefix("/*") {
+      out.push(' ');
+      rest = comment.find("*/").map_or("", |j| &comment[j + 2..]);
+    } else {
+      out.push('/');
+      rest = &tail[1..];
+    }
+  }
+
+  out.push_str(rest);
+  out
+}
+
+// Check whether a GLSL source contains a given identifier as a whole word.
+fn contains_glsl_identifier(src: &str, ident: &str) -> bool {
+  let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+  src.match_indices(ident).any(|(i, _)| {
+    let before = src[..i].chars().next_back();
+    let after = src[i + ident.len()..].chars().next();
+
+    !before.map_or(false, is_ident_char) && !after.map_or(false, is_ident_char)
+  })
 }
 
 /// A builder of [`Uniform`].
@@ -760,6 +1012,69 @@ where
       }
     }
   }
+
+  /// Ask the creation of a [`Uniform`], identified by its `name`.
+  ///
+  /// If the name is not found, or if the uniform is inactive, `None` is returned. This is handy when looking up
+  /// uniforms that might not be present in a shader program, when used with [`ProgramInterface::set_opt`].
+  pub fn ask_opt<T>(&mut self, name: &str) -> Option<Uniform<T>>
+  where
+    B: for<'u> Uniformable<'u, T>,
+  {
+    match self.ask(name) {
+      Ok(uniform) => Some(uniform),
+      Err(err) => {
+        self.warnings.push(err);
+        None
+      }
+    }
+  }
+
+  /// Ask the creation of a [`UniformArrayField`], identified by the name of an array of `N` structs and the name of
+  /// one of their fields.
+  ///
+  /// The uniforms are looked up with the GLSL naming of array of structs members — i.e. `array[i].field`. As with
+  /// [`UniformBuilder::ask`], the first element which field is not found or inactive makes the whole lookup fail.
+  pub fn ask_array_struct<T, const N: usize>(
+    &mut self,
+    array: &str,
+    field: &str,
+  ) -> Result<UniformArrayField<T, N>, UniformWarning>
+  where
+    B: for<'u> Uniformable<'u, T>,
+  {
+    let uniforms = (0..N)
+      .map(|i| self.ask(&format!("{}[{}].{}", array, i, field)))
+      .collect::<Result<_, _>>()?;
+
+    Ok(UniformArrayField {
+      uniforms,
+      elements: false,
+    })
+  }
+
+  /// Ask the creation of a [`UniformArrayField`] gathering the `N` elements of the array `name`.
+  ///
+  /// The uniforms are looked up as `name[i]`. Unlike a single `Uniform<Arr<T, N>>`, this allows to set only some of
+  /// the elements, and consecutive elements set in a [`UniformBatch`] are updated at once.
+  pub fn ask_array_elements<T, const N: usize>(
+    &mut self,
+    name: &str,
+  ) -> Result<UniformArrayField<T, N>, UniformWarning>
+  where
+    B: for<'u> Uniformable<'u, T>,
+  {
+    let uniforms: Vec<Uniform<T>> = (0..N)
+      .map(|i| self.ask(&format!("{}[{}]", name, i)))
+      .collect::<Result<_, _>>()?;
+
+    // backends identifying uniforms by location can only update elements at once if their locations follow each other
+    let elements = uniforms
+      .windows(2)
+      .all(|pair| pair[1].index == pair[0].index + 1);
+
+    Ok(UniformArrayField { uniforms, elements })
+  }
 }
 
 /// [`Uniform`] interface.
@@ -874,6 +1189,22 @@ where
   }
 }
 
+/// Layout of a uniform block, as laid out by the backend.
+///
+/// This is useful to fill a uniform buffer manually or to validate the `std140` encoding of a type against what the
+/// driver expects.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BlockLayout {
+  /// Size of the whole block, in bytes.
+  pub size: usize,
+
+  /// Active members of the block, as `(name, offset, stride)`, sorted by offset.
+  ///
+  /// The offset is relative to the start of the block, in bytes. The stride is the array stride in bytes, or `0` for
+  /// members that are not arrays.
+  pub members: Vec<(String, usize, usize)>,
+}
+
 /// Interact with the [`UniformInterface`] carried by a [`Program`] and/or perform dynamic
 /// uniform lookup.
 ///
@@ -908,6 +1239,114 @@ where
     unsafe { B::update(self.program, uniform, value) };
   }
 
+  /// Set a matrix on a [`Uniform`], transposing it.
+  ///
+  /// [`ProgramInterface::set`] expects matrices in column-major order, as GLSL does. Some math libraries produce
+  /// row-major matrices instead: this method lets the backend transpose them while uploading, instead of transposing
+  /// them on the CPU. Arrays of matrices have each matrix transposed.
+  pub fn set_transposed<'u, T>(&'u mut self, uniform: &'u Uniform<T>, value: B::Target)
+  where
+    B: UniformableMatrix<'u, T>,
+  {
+    unsafe { B::update_transposed(self.program, uniform, value) };
+  }
+
+  /// Read the current value of a [`Uniform`] back from the shader program.
+  ///
+  /// This is mostly useful to check that a value set with [`ProgramInterface::set`] actually took effect, for instance
+  /// in a shader debugger. It is slow, as it synchronizes with the GPU, so don’t use it in hot code. `None` is returned
+  /// for unbound [`Uniform`]s.
+  pub fn get_uniform_value<T>(&self, uniform: &Uniform<T>) -> Option<T>
+  where
+    B: UniformableReadback<T>,
+  {
+    unsafe { B::get(self.program, uniform) }
+  }
+
+  /// Set a value on a [`Uniform`], if any.
+  ///
+  /// Nothing is updated if `value` is `None`; the uniform keeps its previous value. Just like with
+  /// [`ProgramInterface::set`], setting a value on an unbound [`Uniform`] doesn’t do anything either.
+  pub fn set_opt<'u, T>(&'u mut self, uniform: &'u Uniform<T>, value: Option<B::Target>)
+  where
+    B: Uniformable<'u, T>,
+  {
+    if let Some(value) = value {
+      self.set(uniform, value);
+    }
+  }
+
+  /// Set the values of a field for every element of an array of structs, or of every element of an array.
+  ///
+  /// `values[i]` is set on the `i`-th element. The elements of an array are updated at once; backends cannot upload the
+  /// field of several structs in a single call though, so those are updated in turn.
+  pub fn set_array_field<'u, T, const N: usize>(
+    &'u mut self,
+    field: &'u UniformArrayField<T, N>,
+    values: [B::Target; N],
+  ) where
+    B: Uniformable<'u, T>,
+  {
+    if field.elements {
+      unsafe { B::update_elements(self.program, &field.uniforms, values.into()) };
+    } else {
+      for (uniform, value) in field.uniforms.iter().zip(values) {
+        unsafe { B::update(self.program, uniform, value) };
+      }
+    }
+  }
+
+  /// Set a [`BoundTexture`] on a sampler [`Uniform`].
+  ///
+  /// This is a shortcut for `iface.set(uniform, texture.binding())`: the sampler uniform is set to the texture unit
+  /// the texture is bound to. Setting the same [`BoundTexture`] on several sampler uniforms makes them all share that
+  /// single texture unit.
+  pub fn set_texture<'u, D, P>(
+    &'u mut self,
+    uniform: &'u Uniform<TextureBinding<D, P::SamplerType>>,
+    texture: &BoundTexture<'_, B, D, P>,
+  ) where
+    B: PipelineTexture<D, P>
+      + Uniformable<'u, TextureBinding<D, P::SamplerType>, Target = TextureBinding<D, P::SamplerType>>,
+    D: Dimensionable,
+    P: Pixel,
+  {
+    self.set(uniform, texture.binding());
+  }
+
+  /// Set several uniforms at once.
+  ///
+  /// The values are set on the [`UniformBatch`] passed to `f` and issued once `f` returns. If the same uniform is set
+  /// several times in the batch, only its last value is issued, saving a backend call per duplicate; this is useful
+  /// when a large uniform interface is updated from several places. Consecutive elements of an array (see
+  /// [`UniformBuilder::ask_array_elements`]) are updated at once.
+  ///
+  /// ```ignore
+  /// iface.set_all(|batch| {
+  ///   batch.set(&uni.time, t);
+  ///   batch.set(&uni.color, color);
+  /// });
+  /// ```
+  pub fn set_all<'u, F>(&'u mut self, f: F)
+  where
+    F: FnOnce(&mut UniformBatch<'u, B>),
+  {
+    let mut batch = UniformBatch {
+      updates: UniformUpdates::new(),
+    };
+
+    f(&mut batch);
+    batch.updates.flush(self.program);
+  }
+
+  /// Get the layout of the uniform block `name`, as laid out by the backend.
+  ///
+  /// Returns [`None`] if the block doesn’t exist or is inactive in the program, or if the backend cannot query block
+  /// layouts.
+  pub fn uniform_block_layout(&self, name: &str) -> Option<BlockLayout> {
+    unsafe { B::uniform_block_layout(self.program, name) }
+  }
+
   /// Get back a [`UniformBuilder`] to dynamically access [`Uniform`] objects.
   pub fn query(&mut self) -> Result<UniformBuilder<'a, B>, ProgramError> {
     unsafe {
@@ -920,15 +1359,234 @@ where
   }
 }
 
+/// Uniform updates collected by [`ProgramInterface::set_all`].
+///
+/// Values set on a [`UniformBatch`] are not issued right away but once the batch is complete: only the last value set
+/// on each [`Uniform`] is issued, in the order of the uniform indices, and consecutive elements of an array are issued
+/// at once. Just like with [`ProgramInterface::set`], setting a value on an unbound [`Uniform`] doesn’t do anything.
+///
+/// # Parametricity
+///
+/// `B` is the backend type.
+pub struct UniformBatch<'u, B>
+where
+  B: Shader,
+{
+  updates: UniformUpdates<'u, B::ProgramRepr>,
+}
+
+impl<'u, B> UniformBatch<'u, B>
+where
+  B: Shader,
+{
+  /// Set a value on a [`Uniform`].
+  ///
+  /// The value replaces any value previously set on the same [`Uniform`] in the batch.
+  pub fn set<T>(&mut self, uniform: &'u Uniform<T>, value: B::Target)
+  where
+    B: 'u + Uniformable<'u, T>,
+    T: 'static,
+  {
+    self
+      .updates
+      .queue(B::update, B::update_elements)
+      .push(uniform, None, value);
+  }
+
+  /// Set a value on a [`Uniform`], if any.
+  ///
+  /// Nothing is set if `value` is `None`; a value previously set on the same [`Uniform`] in the batch is still issued.
+  pub fn set_opt<T>(&mut self, uniform: &'u Uniform<T>, value: Option<B::Target>)
+  where
+    B: 'u + Uniformable<'u, T>,
+    T: 'static,
+  {
+    if let Some(value) = value {
+      self.set(uniform, value);
+    }
+  }
+
+  /// Set the values of a field for every element of an array of structs, or of every element of an array.
+  ///
+  /// This is the batched version of [`ProgramInterface::set_array_field`]. The elements of an array are issued at once,
+  /// even if some of them are set again later in the batch.
+  pub fn set_array_field<T, const N: usize>(
+    &mut self,
+    field: &'u UniformArrayField<T, N>,
+    values: [B::Target; N],
+  ) where
+    B: 'u + Uniformable<'u, T>,
+    T: 'static,
+  {
+    let array = if field.elements {
+      Some(field.uniforms.as_slice())
+    } else {
+      None
+    };
+    let queue = self.updates.queue(B::update, B::update_elements);
+
+    for (uniform, value) in field.uniforms.iter().zip(values) {
+      queue.push(uniform, array, value);
+    }
+  }
+}
+
+// Deferred updates of the uniforms of a program of type P, with a queue per type of uniform.
+struct UniformUpdates<'u, P> {
+  queues: Vec<(TypeId, Box<dyn PendingUpdates<P> + 'u>)>,
+}
+
+impl<'u, P> UniformUpdates<'u, P> {
+  fn new() -> Self {
+    UniformUpdates { queues: Vec::new() }
+  }
+
+  // Get the queue of the uniforms of type T.
+  //
+  // The queue of a given T is always created with the same update functions, as they are those of the backend for T.
+  fn queue<T, V>(
+    &mut self,
+    update: unsafe fn(&mut P, &'u Uniform<T>, V),
+    update_elements: unsafe fn(&mut P, &'u [Uniform<T>], Vec<V>),
+  ) -> &mut TypedUpdates<'u, P, T, V>
+  where
+    P: 'u,
+    T: 'static,
+    V: 'u,
+  {
+    let type_id = TypeId::of::<T>();
+    let i = match self.queues.iter().position(|(id, _)| *id == type_id) {
+      Some(i) => i,
+      None => {
+        let queue = TypedUpdates {
+          updates: Vec::new(),
+          update,
+          update_elements,
+        };
+        self.queues.push((type_id, Box::new(queue)));
+        self.queues.len() - 1
+      }
+    };
+
+    // the queue was created for T by this very function, so it has that type
+    let queue: *mut dyn PendingUpdates<P> = &mut *self.queues[i].1;
+    unsafe { &mut *(queue as *mut TypedUpdates<'u, P, T, V>) }
+  }
+
+  // Issue the updates of every queue.
+  fn flush(self, program: &mut P) {
+    for (_, queue) in self.queues {
+      unsafe { queue.flush(program) };
+    }
+  }
+}
+
+// Type-erased queue of deferred updates.
+trait PendingUpdates<P> {
+  unsafe fn flush(self: Box<Self>, program: &mut P);
+}
+
+// Deferred updates of uniforms of type T, set with values of type V.
+struct TypedUpdates<'u, P, T, V> {
+  updates: Vec<UniformUpdate<'u, T, V>>,
+  update: unsafe fn(&mut P, &'u Uniform<T>, V),
+  update_elements: unsafe fn(&mut P, &'u [Uniform<T>], Vec<V>),
+}
+
+// Deferred update of a uniform, with the uniforms of the array it’s an element of, if any.
+struct UniformUpdate<'u, T, V> {
+  uniform: &'u Uniform<T>,
+  array: Option<&'u [Uniform<T>]>,
+  value: V,
+}
+
+impl<'u, P, T, V> TypedUpdates<'u, P, T, V> {
+  // Add an update; unbound uniforms are not updated at all.
+  fn push(&mut self, uniform: &'u Uniform<T>, array: Option<&'u [Uniform<T>]>, value: V) {
+    if uniform.index >= 0 {
+      self.updates.push(UniformUpdate {
+        uniform,
+        array,
+        value,
+      });
+    }
+  }
+}
+
+impl<'u, P, T, V> PendingUpdates<P> for TypedUpdates<'u, P, T, V> {
+  // Issue the last update of each uniform, in the order of the uniform indices, and runs of consecutive elements of an
+  // array at once.
+  unsafe fn flush(self: Box<Self>, program: &mut P) {
+    let TypedUpdates {
+      mut updates,
+      update,
+      update_elements,
+    } = *self;
+
+    // the sort is stable, so reversing first keeps the last update of each uniform first
+    updates.reverse();
+    updates.sort_by_key(|u| u.uniform.index);
+    updates.dedup_by_key(|u| u.uniform.index);
+
+    let mut updates = updates.into_iter().peekable();
+    while let Some(first) = updates.next() {
+      let array = match first.array {
+        Some(array) => array,
+        None => {
+          update(program, first.uniform, first.value);
+          continue;
+        }
+      };
+
+      let start = (first.uniform.index - array[0].index) as usize;
+      let mut values = vec![first.value];
+
+      while let Some(next) = updates.next_if(|next| {
+        next.array.map_or(false, |a| a.as_ptr() == array.as_ptr())
+          && next.uniform.index == first.uniform.index + values.len() as i32
+      }) {
+        values.push(next.value);
+      }
+
+      if values.len() == 1 {
+        update(program, first.uniform, values.pop().unwrap());
+      } else {
+        update_elements(program, &array[start..start + values.len()], values);
+      }
+    }
+  }
+}
+
 /// A [`Program`] builder.
 ///
 /// This type allows to create shader programs without having to worry too much about the highly
 /// generic API.
-pub struct ProgramBuilder<'a, C, Sem, Out, Uni> {
+pub struct ProgramBuilder<'a, C, Sem, Out, Uni>
+where
+  C: GraphicsContext,
+  C::Backend: Shader,
+{
   ctx: &'a mut C,
+  feedback: Option<FeedbackVaryings<C::Backend>>,
   _phantom: PhantomData<(Sem, Out, Uni)>,
 }
 
+// Feedback varyings to declare once the program is linked, along with the backend function declaring them; the
+// function is picked when the varyings are set, so that only backends supporting transform feedback have to implement
+// it.
+struct FeedbackVaryings<B>
+where
+  B: Shader,
+{
+  varyings: Vec<String>,
+  mode: FeedbackMode,
+  apply: ApplyFeedbackVaryings<B::ProgramRepr>,
+}
+
+// Backend function declaring the feedback varyings of a program of type P.
+type ApplyFeedbackVaryings<P> =
+  unsafe fn(&mut P, &[&str], FeedbackMode) -> Result<Vec<String>, ProgramError>;
+
 impl<'a, C, Sem, Out, Uni> ProgramBuilder<'a, C, Sem, Out, Uni>
 where
   C: GraphicsContext,
@@ -939,6 +1597,7 @@ where
   pub fn new(ctx: &'a mut C) -> Self {
     ProgramBuilder {
       ctx,
+      feedback: None,
       _phantom: PhantomData,
     }
   }
@@ -972,6 +1631,14 @@ where
     let geometry = geometry.into();
 
     unsafe {
+      if let Some(requested) = geometry.and_then(|stage| stage.max_vertices) {
+        if let Some(max) = self.ctx.backend().max_geometry_output_vertices() {
+          if requested > max {
+            return Err(ProgramError::geometry_output_too_large(requested, max));
+          }
+        }
+      }
+
       let mut repr = self.ctx.backend().new_program(
         &vertex.repr,
         tess.map(|stages| TessellationStages {
@@ -982,32 +1649,102 @@ where
         &fragment.repr,
       )?;
 
-      let warnings = C::Backend::apply_semantics::<Sem>(&mut repr)?
+      let mut warnings: Vec<_> = C::Backend::apply_semantics::<Sem>(&mut repr)?
         .into_iter()
         .map(|w| ProgramError::Warning(w.into()))
         .collect();
 
-      let mut uniform_builder =
-        C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
-          repr,
-          warnings: Vec::new(),
-          _a: PhantomData,
-        })?;
+      if let Some(ref feedback) = self.feedback {
+        let varyings: Vec<_> = feedback.varyings.iter().map(String::as_str).collect();
+        let missing = (feedback.apply)(&mut repr, &varyings, feedback.mode)?;
 
-      let uni =
-        Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+        warnings.extend(
+          missing
+            .into_iter()
+            .map(|name| ProgramError::Warning(ProgramWarning::FeedbackVarying(name))),
+        );
+      }
 
-      let program = Program {
-        repr,
-        uni,
-        _sem: PhantomData,
-        _out: PhantomData,
-      };
+      let early_fragment_tests = fragment.early_fragment_tests.clone().unwrap_or_default();
+      if early_fragment_tests.forced {
+        warnings.extend(early_fragment_tests.conflicts.iter().map(|construct| {
+          ProgramError::Warning(ProgramWarning::EarlyFragmentTestsConflict(
+            construct.to_string(),
+          ))
+        }));
+      }
 
-      Ok(BuiltProgram { program, warnings })
+      Self::build_program(repr, warnings, early_fragment_tests.forced, env)
+    }
+  }
+
+  /// Create a [`Program`] from a binary previously retrieved with [`Program::get_binary`] and accessing a mutable
+  /// environment variable.
+  ///
+  /// The binary must have been retrieved from a [`Program`] built with the same [`Semantics`]. Because no source is
+  /// available, [`Program::uses_early_fragment_tests`] always returns `false` for programs built that way.
+  ///
+  /// If the backend rejects the binary — for instance because the driver was updated since it was retrieved —
+  /// [`ProgramError::BinaryRejected`] is returned, and the program should be built from its sources again.
+  pub fn from_binary_env<E>(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    unsafe {
+      let repr = self.ctx.backend().new_program_from_binary(format, binary)?;
+      Self::build_program(repr, Vec::new(), false, env)
     }
   }
 
+  /// Create a [`Program`] from a binary previously retrieved with [`Program::get_binary`].
+  ///
+  /// See the documentation of [`ProgramBuilder::from_binary_env`] for further details.
+  pub fn from_binary(
+    &mut self,
+    format: u32,
+    binary: &[u8],
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend>,
+  {
+    Self::from_binary_env(self, format, binary, &mut ())
+  }
+
+  /// Build the uniform interface of a linked program.
+  unsafe fn build_program<E>(
+    mut repr: <C::Backend as Shader>::ProgramRepr,
+    warnings: Vec<ProgramError>,
+    early_fragment_tests: bool,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    let mut uniform_builder =
+      C::Backend::new_uniform_builder(&mut repr).map(|repr| UniformBuilder {
+        repr,
+        warnings: Vec::new(),
+        _a: PhantomData,
+      })?;
+
+    let uni = Uni::uniform_interface(&mut uniform_builder, env).map_err(ProgramWarning::Uniform)?;
+
+    let program = Program {
+      repr,
+      uni,
+      early_fragment_tests,
+      _sem: PhantomData,
+      _out: PhantomData,
+    };
+
+    Ok(BuiltProgram { program, warnings })
+  }
+
   /// Create a [`Program`] by linking [`Stage`]s.
   ///
   /// # Parametricity
@@ -1062,19 +1799,100 @@ where
     T: Into<Option<TessellationStages<'b, str>>>,
     G: Into<Option<&'b str>>,
   {
-    let vs_stage = Stage::new(self.ctx, StageType::VertexShader, vertex)?;
+    self.link_strings(vertex, tess.into(), geometry.into(), fragment, None, env)
+  }
 
-    let tess_stages = match tess.into() {
+  /// Create a [`Program`] by linking [`&str`]s, after having resolved their `#include` directives against `files`.
+  ///
+  /// See [`Stage::new_with_includes`] for further details.
+  ///
+  /// # Parametricity
+  ///
+  /// - `C` is the graphics context.
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`Stage`] inside (geometry shader).
+  /// - `E` is the type of the environment.
+  ///
+  /// [`&str`]: str
+  pub fn from_strings_with_includes_env<'b, T, G, E>(
+    &mut self,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+    files: &HashMap<String, String>,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    self.link_strings(
+      vertex,
+      tess.into(),
+      geometry.into(),
+      fragment,
+      Some(files),
+      env,
+    )
+  }
+
+  /// Create a [`Program`] by linking [`&str`]s, after having resolved their `#include` directives against `files`.
+  ///
+  /// See [`Stage::new_with_includes`] for further details.
+  ///
+  /// # Parametricity
+  ///
+  /// - `C` is the graphics context.
+  /// - `T` is an [`Option`] containing a [`TessellationStages`] with [`&str`] inside.
+  /// - `G` is an [`Option`] containing a [`Stage`] inside (geometry shader).
+  ///
+  /// [`&str`]: str
+  pub fn from_strings_with_includes<'b, T, G>(
+    &mut self,
+    vertex: &'b str,
+    tess: T,
+    geometry: G,
+    fragment: &'b str,
+    files: &HashMap<String, String>,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend>,
+    T: Into<Option<TessellationStages<'b, str>>>,
+    G: Into<Option<&'b str>>,
+  {
+    Self::from_strings_with_includes_env(self, vertex, tess, geometry, fragment, files, &mut ())
+  }
+
+  // compile the stages, resolving their includes if files are provided, and link them
+  fn link_strings<E>(
+    &mut self,
+    vertex: &str,
+    tess: Option<TessellationStages<str>>,
+    geometry: Option<&str>,
+    fragment: &str,
+    files: Option<&HashMap<String, String>>,
+    env: &mut E,
+  ) -> Result<BuiltProgram<C::Backend, Sem, Out, Uni>, ProgramError>
+  where
+    Uni: UniformInterface<C::Backend, E>,
+  {
+    let ctx = &mut *self.ctx;
+    let mut new_stage = |ty, src| match files {
+      Some(files) => Stage::new_with_includes(ctx, ty, src, files),
+      None => Stage::new(ctx, ty, src),
+    };
+
+    let vs_stage = new_stage(StageType::VertexShader, vertex)?;
+
+    let tess_stages = match tess {
       Some(TessellationStages {
         control,
         evaluation,
       }) => {
-        let control_stage = Stage::new(self.ctx, StageType::TessellationControlShader, control)?;
-        let evaluation_stage = Stage::new(
-          self.ctx,
-          StageType::TessellationEvaluationShader,
-          evaluation,
-        )?;
+        let control_stage = new_stage(StageType::TessellationControlShader, control)?;
+        let evaluation_stage = new_stage(StageType::TessellationEvaluationShader, evaluation)?;
         Some((control_stage, evaluation_stage))
       }
       None => None,
@@ -1087,12 +1905,12 @@ where
           evaluation,
         });
 
-    let gs_stage = match geometry.into() {
-      Some(geometry) => Some(Stage::new(self.ctx, StageType::GeometryShader, geometry)?),
+    let gs_stage = match geometry {
+      Some(geometry) => Some(new_stage(StageType::GeometryShader, geometry)?),
       None => None,
     };
 
-    let fs_stage = Stage::new(self.ctx, StageType::FragmentShader, fragment)?;
+    let fs_stage = new_stage(StageType::FragmentShader, fragment)?;
 
     Self::from_stages_env(
       self,
@@ -1134,6 +1952,29 @@ where
   }
 }
 
+impl<'a, C, Sem, Out, Uni> ProgramBuilder<'a, C, Sem, Out, Uni>
+where
+  C: GraphicsContext,
+  C::Backend: ShaderFeedback,
+  Sem: Semantics,
+{
+  /// Declare the outputs of the last vertex processing stage to capture with transform feedback.
+  ///
+  /// The varyings are declared before the program is linked; they are captured in that order, either in a single
+  /// buffer or in a buffer each depending on `mode`. A varying that doesn’t exist in the program is not captured and
+  /// is reported as a [`ProgramWarning::FeedbackVarying`] warning.
+  ///
+  /// See the [`feedback`](crate::feedback) module for further details.
+  pub fn with_feedback_varyings(mut self, varyings: &[&str], mode: FeedbackMode) -> Self {
+    self.feedback = Some(FeedbackVaryings {
+      varyings: varyings.iter().map(|&v| v.to_owned()).collect(),
+      mode,
+      apply: C::Backend::apply_feedback_varyings,
+    });
+    self
+  }
+}
+
 /// A shader program.
 ///
 /// Shader programs are GPU binaries that execute when a draw command is issued.
@@ -1150,6 +1991,7 @@ where
 {
   pub(crate) repr: B::ProgramRepr,
   pub(crate) uni: Uni,
+  early_fragment_tests: bool,
   _sem: PhantomData<*const Sem>,
   _out: PhantomData<*const Out>,
 }
@@ -1159,6 +2001,24 @@ where
   B: Shader,
   Sem: Semantics,
 {
+  /// Whether the fragment shader forces early fragment tests via `layout(early_fragment_tests) in;`.
+  ///
+  /// Early fragment tests perform the depth and stencil tests before running the fragment shader, which is a common
+  /// optimization. Constructs that conflict with them (`discard`, `gl_FragDepth` writes) are reported as
+  /// [`ProgramWarning::EarlyFragmentTestsConflict`] when the [`Program`] is built.
+  pub fn uses_early_fragment_tests(&self) -> bool {
+    self.early_fragment_tests
+  }
+
+  /// Retrieve the binary representation of the [`Program`], along with its backend-specific format.
+  ///
+  /// The returned `(format, bytes)` pair can be cached (e.g. on disk) and given back to [`ProgramBuilder::from_binary`]
+  /// to skip compiling and linking the program sources again. [`None`] is returned if the backend doesn’t support
+  /// program binaries.
+  pub fn get_binary(&self) -> Option<(u32, Vec<u8>)> {
+    unsafe { B::program_binary(&self.repr) }
+  }
+
   /// Create a new [`UniformInterface`] but keep the [`Program`] around without rebuilding it.
   ///
   /// # Parametricity
@@ -1216,6 +2076,7 @@ where
     let program = Program {
       repr: self.repr,
       uni,
+      early_fragment_tests: self.early_fragment_tests,
       _sem: PhantomData,
       _out: PhantomData,
     };
@@ -1239,6 +2100,70 @@ where
   }
 }
 
+// Vertex shader of depth-only programs; `{position}` is replaced with the name of the position semantics.
+const DEPTH_ONLY_VS: &str = "
+in vec4 {position};
+
+uniform mat4 transform;
+
+void main() {
+  gl_Position = transform * {position};
+}";
+
+// Fragment shader of depth-only programs; depth is written by the fixed pipeline.
+const DEPTH_ONLY_FS: &str = "
+void main() {
+}";
+
+/// [`UniformInterface`] of the programs created with [`Program::depth_only`].
+#[derive(Debug)]
+pub struct DepthOnlyInterface {
+  /// Matrix transforming vertex positions into clip space — typically the product of the projection, view and model
+  /// matrices.
+  pub transform: Uniform<Mat44<f32>>,
+}
+
+impl<B> UniformInterface<B> for DepthOnlyInterface
+where
+  B: Shader + for<'u> Uniformable<'u, Mat44<f32>>,
+{
+  fn uniform_interface<'a>(
+    builder: &mut UniformBuilder<'a, B>,
+    _: &mut (),
+  ) -> Result<Self, UniformWarning> {
+    let transform = builder.ask("transform")?;
+    Ok(DepthOnlyInterface { transform })
+  }
+}
+
+impl<B, Sem> Program<B, Sem, (), DepthOnlyInterface>
+where
+  B: Shader + for<'u> Uniformable<'u, Mat44<f32>>,
+  Sem: Semantics,
+{
+  /// Create a [`Program`] which only writes depth, for depth prepasses and shadow map passes.
+  ///
+  /// The program is made of minimal shaders bundled with luminance: the vertex shader reads the vertex positions
+  /// from the `position` semantics and transforms them with [`DepthOnlyInterface::transform`], while the fragment
+  /// shader has no output. Positions with fewer than four components are completed with `z = 0` and `w = 1`. The other
+  /// semantics of `Sem` are not used by the program, so they are reported as inactive attribute warnings.
+  ///
+  /// Such a program is typically used with [`RenderState::depth_prepass`].
+  ///
+  /// [`RenderState::depth_prepass`]: crate::render_state::RenderState::depth_prepass
+  pub fn depth_only<C>(
+    ctx: &mut C,
+    position: Sem,
+  ) -> Result<BuiltProgram<B, Sem, (), DepthOnlyInterface>, ProgramError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let vs = DEPTH_ONLY_VS.replace("{position}", position.name());
+
+    ProgramBuilder::new(ctx).from_strings(&vs, None, None, DEPTH_ONLY_FS)
+  }
+}
+
 /// Shader data.
 ///
 /// # Parametricity
@@ -1325,3 +2250,153 @@ impl fmt::Display for ShaderDataError {
 }
 
 impl std::error::Error for ShaderDataError {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // backend calls issued by the updates, with the index of the first uniform and the values
+  type Calls = Vec<(i32, Vec<u8>)>;
+
+  unsafe fn update(calls: &mut Calls, uniform: &Uniform<u8>, value: u8) {
+    calls.push((uniform.index, vec![value]));
+  }
+
+  unsafe fn update_elements(calls: &mut Calls, elements: &[Uniform<u8>], values: Vec<u8>) {
+    calls.push((elements[0].index, values));
+  }
+
+  fn uniforms(indices: std::ops::Range<i32>) -> Vec<Uniform<u8>> {
+    indices.map(|i| unsafe { Uniform::new(i) }).collect()
+  }
+
+  fn flush(updates: UniformUpdates<Calls>) -> Calls {
+    let mut calls = Vec::new();
+    updates.flush(&mut calls);
+    calls
+  }
+
+  #[test]
+  fn uniform_updates_dedup() {
+    let uniforms = uniforms(0..4);
+    let mut updates = UniformUpdates::new();
+    let queue = updates.queue(update, update_elements);
+    queue.push(&uniforms[3], None, 1);
+    queue.push(&uniforms[1], None, 2);
+    queue.push(&uniforms[3], None, 3);
+    queue.push(&uniforms[3], None, 4);
+
+    // one call per uniform, with the last value
+    assert_eq!(flush(updates), [(1, vec![2]), (3, vec![4])]);
+  }
+
+  #[test]
+  fn uniform_updates_skip_unbound() {
+    let unbound = unsafe { Uniform::new(-1) };
+    let uniforms = uniforms(0..1);
+    let mut updates = UniformUpdates::new();
+    let queue = updates.queue(update, update_elements);
+    queue.push(&unbound, None, 1);
+    queue.push(&uniforms[0], None, 2);
+
+    assert_eq!(flush(updates), [(0, vec![2])]);
+  }
+
+  #[test]
+  fn uniform_updates_coalesce_elements() {
+    let elements = uniforms(4..8);
+    let mut updates = UniformUpdates::new();
+    let queue = updates.queue(update, update_elements);
+    for (uniform, value) in elements.iter().zip(0..) {
+      queue.push(uniform, Some(&elements), value);
+    }
+    queue.push(&elements[1], Some(&elements), 10);
+
+    // a single call for the whole array
+    assert_eq!(flush(updates), [(4, vec![0, 10, 2, 3])]);
+  }
+
+  #[test]
+  fn uniform_updates_coalesce_runs() {
+    let elements = uniforms(4..8);
+    let others = uniforms(8..9);
+    let mut updates = UniformUpdates::new();
+    let queue = updates.queue(update, update_elements);
+    queue.push(&elements[3], Some(&elements), 3);
+    queue.push(&elements[0], Some(&elements), 0);
+    queue.push(&others[0], None, 8);
+    queue.push(&elements[1], Some(&elements), 1);
+
+    // the gap at element 2 splits the runs, and the uniform following the array is not part of it
+    assert_eq!(
+      flush(updates),
+      [(4, vec![0, 1]), (7, vec![3]), (8, vec![8])]
+    );
+  }
+
+  #[test]
+  fn uniform_updates_struct_fields() {
+    let fields = uniforms(0..3);
+    let mut updates = UniformUpdates::new();
+    let queue = updates.queue(update, update_elements);
+    for (uniform, value) in fields.iter().zip(0..) {
+      queue.push(uniform, None, value);
+    }
+
+    // fields of arrays of structs are not elements of an array
+    assert_eq!(flush(updates), [(0, vec![0]), (1, vec![1]), (2, vec![2])]);
+  }
+
+  #[test]
+  fn strip_comments() {
+    assert_eq!(strip_glsl_comments("a // b\nc"), "a \nc");
+    assert_eq!(strip_glsl_comments("a /* b\n */c"), "a  c");
+    assert_eq!(strip_glsl_comments("a /* b // c */ d"), "a   d");
+    assert_eq!(strip_glsl_comments("a / b"), "a / b");
+
+    // an unterminated block comment runs until the end of the source
+    assert_eq!(strip_glsl_comments("a /* b\nc"), "a  ");
+  }
+
+  #[test]
+  fn glsl_identifier() {
+    assert!(contains_glsl_identifier("if (x) discard;", "discard"));
+    assert!(contains_glsl_identifier("discard", "discard"));
+    assert!(!contains_glsl_identifier("bool discarded;", "discard"));
+    assert!(!contains_glsl_identifier("float my_discard;", "discard"));
+    assert!(contains_glsl_identifier("gl_FragDepth=0.;", "gl_FragDepth"));
+  }
+
+  #[test]
+  fn early_fragment_tests_scan() {
+    let scan = EarlyFragmentTests::scan("layout ( early_fragment_tests ) in;\nvoid main() {}");
+    assert!(scan.forced);
+    assert!(scan.conflicts.is_empty());
+
+    let scan =
+      EarlyFragmentTests::scan("layout(early_fragment_tests) in; void main() { discard; }");
+    assert!(scan.forced);
+    assert_eq!(scan.conflicts, ["discard"]);
+
+    // commented out constructs don’t count, and neither do identifiers merely containing them
+    let scan = EarlyFragmentTests::scan(
+      "// layout(early_fragment_tests) in;
+      bool discarded;
+      void main() { /* discard; */ gl_FragDepth = 0.; }",
+    );
+    assert!(!scan.forced);
+    assert_eq!(scan.conflicts, ["gl_FragDepth"]);
+  }
+
+  #[test]
+  fn geometry_max_vertices() {
+    let src = "
+      layout (triangles) in;
+      // layout (max_vertices = 1) out;
+      layout (triangle_strip, max_vertices = 12) out;
+    ";
+
+    assert_eq!(scan_max_vertices(src), Some(12));
+    assert_eq!(scan_max_vertices("layout (points) in;"), None);
+  }
+}
@@ -0,0 +1,301 @@
+//! Shader source preprocessing.
+//!
+//! GLSL doesn’t have a way to share code between shader stages. This module implements a small preprocessor resolving
+//! `#include "name"` directives against a set of _virtual files_ — a map of file names to GLSL sources — so that common
+//! snippets (noise functions, lighting, etc.) can be written once and included in several stages.
+//!
+//! Included files can include other files. Including the same file several times is allowed, but a file including
+//! itself, directly or via other files, is an error.
+//!
+//! GLSL `#line` directives only accept a _source string number_ — not a file name — so each file is given a number in
+//! the order files are first encountered, the including source being `0`. [`PreprocessedSource::file_name`] maps those
+//! numbers back to file names, to make sense of compilation errors.
+
+use std::{collections::HashMap, error, fmt};
+
+/// Errors that might occur while preprocessing a shader source.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IncludeError {
+  /// An included file is not in the virtual files.
+  NotFound {
+    /// Name of the missing file.
+    name: String,
+    /// File containing the `#include` directive.
+    file: String,
+    /// Line of the `#include` directive, starting at 1.
+    line: usize,
+  },
+
+  /// A file includes itself, directly or via other files.
+  Cycle {
+    /// Name of the file included again.
+    name: String,
+    /// File containing the `#include` directive closing the cycle.
+    file: String,
+    /// Line of the `#include` directive, starting at 1.
+    line: usize,
+  },
+
+  /// An `#include` directive is not followed by a quoted file name.
+  Malformed {
+    /// File containing the `#include` directive.
+    file: String,
+    /// Line of the `#include` directive, starting at 1.
+    line: usize,
+  },
+}
+
+impl IncludeError {
+  /// An included file is not in the virtual files.
+  pub fn not_found(name: impl Into<String>, file: impl Into<String>, line: usize) -> Self {
+    IncludeError::NotFound {
+      name: name.into(),
+      file: file.into(),
+      line,
+    }
+  }
+
+  /// A file includes itself, directly or via other files.
+  pub fn cycle(name: impl Into<String>, file: impl Into<String>, line: usize) -> Self {
+    IncludeError::Cycle {
+      name: name.into(),
+      file: file.into(),
+      line,
+    }
+  }
+
+  /// An `#include` directive is not followed by a quoted file name.
+  pub fn malformed(file: impl Into<String>, line: usize) -> Self {
+    IncludeError::Malformed {
+      file: file.into(),
+      line,
+    }
+  }
+}
+
+impl fmt::Display for IncludeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      IncludeError::NotFound {
+        ref name,
+        ref file,
+        line,
+      } => write!(
+        f,
+        "{}:{}: cannot find included file \"{}\"",
+        file, line, name
+      ),
+
+      IncludeError::Cycle {
+        ref name,
+        ref file,
+        line,
+      } => write!(f, "{}:{}: \"{}\" includes itself", file, line, name),
+
+      IncludeError::Malformed { ref file, line } => {
+        write!(f, "{}:{}: malformed #include directive", file, line)
+      }
+    }
+  }
+}
+
+impl error::Error for IncludeError {}
+
+/// A shader source with its `#include` directives resolved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreprocessedSource {
+  source: String,
+  files: Vec<String>,
+}
+
+impl PreprocessedSource {
+  /// Resolve the `#include` directives of `src` against `files`.
+  ///
+  /// `name` is the name of `src`, used in errors and as the name of the source string `0`.
+  pub fn new(
+    name: impl Into<String>,
+    src: &str,
+    files: &HashMap<String, String>,
+  ) -> Result<Self, IncludeError> {
+    let mut preprocessed = PreprocessedSource {
+      source: String::with_capacity(src.len()),
+      files: vec![name.into()],
+    };
+    let mut stack = Vec::new();
+
+    preprocessed.include(0, src, files, &mut stack)?;
+
+    Ok(preprocessed)
+  }
+
+  /// Resolved source.
+  pub fn source(&self) -> &str {
+    &self.source
+  }
+
+  /// Name of the file with the given source string number, as used in `#line` directives and compilation errors.
+  pub fn file_name(&self, source_nb: usize) -> Option<&str> {
+    self.files.get(source_nb).map(String::as_str)
+  }
+
+  // append the file with the given source string number, resolving its includes; stack holds the files being included
+  fn include(
+    &mut self,
+    source_nb: usize,
+    src: &str,
+    files: &HashMap<String, String>,
+    stack: &mut Vec<usize>,
+  ) -> Result<(), IncludeError> {
+    stack.push(source_nb);
+    self.line_directive(1, source_nb);
+
+    for (i, line) in src.lines().enumerate() {
+      let directive = match line.trim_start().strip_prefix('#') {
+        Some(directive) => directive.trim_start(),
+        None => {
+          self.source.push_str(line);
+          self.source.push('\n');
+          continue;
+        }
+      };
+
+      let name = match directive.strip_prefix("include") {
+        Some(name) => name.trim(),
+        None => {
+          self.source.push_str(line);
+          self.source.push('\n');
+          continue;
+        }
+      };
+
+      let line_nb = i + 1;
+      let name = name
+        .strip_prefix('"')
+        .and_then(|name| name.strip_suffix('"'))
+        .filter(|name| !name.is_empty() && !name.contains('"'))
+        .ok_or_else(|| IncludeError::malformed(&self.files[source_nb], line_nb))?;
+
+      let included_src = files
+        .get(name)
+        .ok_or_else(|| IncludeError::not_found(name, &self.files[source_nb], line_nb))?;
+
+      let included_nb = match self.files.iter().position(|file| file == name) {
+        Some(nb) if stack.contains(&nb) => {
+          return Err(IncludeError::cycle(name, &self.files[source_nb], line_nb));
+        }
+
+        Some(nb) => nb,
+
+        None => {
+          self.files.push(name.to_owned());
+          self.files.len() - 1
+        }
+      };
+
+      self.include(included_nb, included_src, files, stack)?;
+
+      // resume the including file right after the directive
+      self.line_directive(line_nb + 1, source_nb);
+    }
+
+    stack.pop();
+    Ok(())
+  }
+
+  fn line_directive(&mut self, line: usize, source_nb: usize) {
+    self
+      .source
+      .push_str(&format!("#line {} {}\n", line, source_nb));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn virtual_files(files: &[(&str, &str)]) -> HashMap<String, String> {
+    files
+      .iter()
+      .map(|&(name, src)| (name.to_owned(), src.to_owned()))
+      .collect()
+  }
+
+  #[test]
+  fn preprocess_without_include() {
+    let preprocessed = PreprocessedSource::new("main", "void main() {}", &HashMap::new()).unwrap();
+
+    assert_eq!(preprocessed.source(), "#line 1 0\nvoid main() {}\n");
+    assert_eq!(preprocessed.file_name(0), Some("main"));
+    assert_eq!(preprocessed.file_name(1), None);
+  }
+
+  #[test]
+  fn preprocess_nested_includes() {
+    let files = virtual_files(&[
+      (
+        "lighting",
+        "#include \"math\"\nfloat light() { return halve(1.); }",
+      ),
+      ("math", "float halve(float x) { return x * .5; }"),
+    ]);
+    let src = "out vec4 frag;\n  #  include \"lighting\"\nvoid main() {}";
+    let preprocessed = PreprocessedSource::new("main", src, &files).unwrap();
+
+    assert_eq!(
+      preprocessed.source(),
+      "#line 1 0\n\
+       out vec4 frag;\n\
+       #line 1 1\n\
+       #line 1 2\n\
+       float halve(float x) { return x * .5; }\n\
+       #line 2 1\n\
+       float light() { return halve(1.); }\n\
+       #line 3 0\n\
+       void main() {}\n"
+    );
+    assert_eq!(preprocessed.file_name(1), Some("lighting"));
+    assert_eq!(preprocessed.file_name(2), Some("math"));
+  }
+
+  #[test]
+  fn preprocess_same_include_twice() {
+    let files = virtual_files(&[("a", "// a")]);
+    let preprocessed =
+      PreprocessedSource::new("main", "#include \"a\"\n#include \"a\"", &files).unwrap();
+
+    assert_eq!(
+      preprocessed.source(),
+      "#line 1 0\n#line 1 1\n// a\n#line 2 0\n#line 1 1\n// a\n#line 3 0\n"
+    );
+  }
+
+  #[test]
+  fn preprocess_missing_include() {
+    let files = virtual_files(&[("a", "\n#include \"b\"")]);
+    let err = PreprocessedSource::new("main", "#include \"a\"", &files).unwrap_err();
+
+    assert_eq!(err, IncludeError::not_found("b", "a", 2));
+    assert_eq!(err.to_string(), "a:2: cannot find included file \"b\"");
+  }
+
+  #[test]
+  fn preprocess_include_cycle() {
+    let files = virtual_files(&[("a", "#include \"b\""), ("b", "#include \"a\"")]);
+    let err = PreprocessedSource::new("main", "#include \"a\"", &files).unwrap_err();
+
+    assert_eq!(err, IncludeError::cycle("a", "b", 1));
+
+    let files = virtual_files(&[("a", "#include \"a\"")]);
+    let err = PreprocessedSource::new("a", "#include \"a\"", &files).unwrap_err();
+
+    assert_eq!(err, IncludeError::cycle("a", "a", 1));
+  }
+
+  #[test]
+  fn preprocess_malformed_include() {
+    let err = PreprocessedSource::new("main", "#include <a>", &HashMap::new()).unwrap_err();
+
+    assert_eq!(err, IncludeError::malformed("main", 1));
+  }
+}
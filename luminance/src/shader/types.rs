@@ -2,9 +2,17 @@
 //!
 //! These types are used, mostly, to be passed to shaders as [`Uniform`] data.
 //!
+//! Vectors and matrices also come with a few arithmetic operations (component-wise [`Add`], [`Sub`] and [`Mul`], dot
+//! and cross products, matrix multiplication and some [`Mat44`] constructors) so that computing a simple
+//! _model-view-projection_ matrix doesn’t require an external math crate. Those are not meant to replace a proper
+//! linear algebra crate, though.
+//!
+//! Matrices are stored in _column-major_ order: `m[i]` is the `i`-th column of `m`, which is the layout expected by
+//! shaders.
+//!
 //! [`Uniform`]: crate::shader::Uniform
 
-use std::ops::{Deref, DerefMut};
+use std::ops::{Add, Deref, DerefMut, Mul, Sub};
 
 /// An array of values.
 ///
@@ -186,6 +194,20 @@ impl<T> Vec4<T> {
   }
 }
 
+// Multiply a column-major square matrix by a column vector.
+fn mat_vec_mul<T, const N: usize>(m: &[[T; N]; N], v: [T; N]) -> [T; N]
+where
+  T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+  let mut r = v;
+
+  for (i, x) in r.iter_mut().enumerate() {
+    *x = (1..N).fold(m[0][i] * v[0], |acc, k| acc + m[k][i] * v[k]);
+  }
+
+  r
+}
+
 macro_rules! matrix {
   ($t:ident, $r:literal, $c:literal) => {
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -230,9 +252,269 @@ macro_rules! matrix {
         $t(array.into())
       }
     }
+
+    /// Matrix multiplication.
+    impl<T> Mul for $t<T>
+    where
+      T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+      type Output = Self;
+
+      fn mul(self, rhs: Self) -> Self::Output {
+        let mut m = rhs.0;
+
+        for col in m.iter_mut() {
+          *col = mat_vec_mul(&self.0, *col);
+        }
+
+        $t(m)
+      }
+    }
   };
 }
 
 matrix!(Mat22, 2, 2);
 matrix!(Mat33, 3, 3);
 matrix!(Mat44, 4, 4);
+
+macro_rules! vector_arith {
+  ($t:ident, $mat:ident) => {
+    /// Component-wise addition.
+    impl<T> Add for $t<T>
+    where
+      T: Copy + Add<Output = T>,
+    {
+      type Output = Self;
+
+      fn add(mut self, rhs: Self) -> Self::Output {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+          *a = *a + b;
+        }
+
+        self
+      }
+    }
+
+    /// Component-wise subtraction.
+    impl<T> Sub for $t<T>
+    where
+      T: Copy + Sub<Output = T>,
+    {
+      type Output = Self;
+
+      fn sub(mut self, rhs: Self) -> Self::Output {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+          *a = *a - b;
+        }
+
+        self
+      }
+    }
+
+    /// Component-wise multiplication.
+    impl<T> Mul for $t<T>
+    where
+      T: Copy + Mul<Output = T>,
+    {
+      type Output = Self;
+
+      fn mul(mut self, rhs: Self) -> Self::Output {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+          *a = *a * b;
+        }
+
+        self
+      }
+    }
+
+    /// Matrix-vector multiplication.
+    impl<T> Mul<$t<T>> for $mat<T>
+    where
+      T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+      type Output = $t<T>;
+
+      fn mul(self, rhs: $t<T>) -> Self::Output {
+        $t(mat_vec_mul(&self.0, rhs.0))
+      }
+    }
+
+    impl<T> $t<T>
+    where
+      T: Copy + Add<Output = T> + Mul<Output = T>,
+    {
+      /// Dot product.
+      pub fn dot(self, rhs: Self) -> T {
+        let (a, b) = (self.0, rhs.0);
+        (1..a.len()).fold(a[0] * b[0], |acc, i| acc + a[i] * b[i])
+      }
+    }
+  };
+}
+
+vector_arith!(Vec2, Mat22);
+vector_arith!(Vec3, Mat33);
+vector_arith!(Vec4, Mat44);
+
+impl<T> Vec3<T>
+where
+  T: Copy + Sub<Output = T> + Mul<Output = T>,
+{
+  /// Cross product.
+  pub fn cross(self, rhs: Self) -> Self {
+    let [ax, ay, az] = self.0;
+    let [bx, by, bz] = rhs.0;
+
+    Vec3([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+  }
+}
+
+macro_rules! float_arith {
+  ($f:ty) => {
+    float_arith!($f, Vec2, Vec3, Vec4);
+
+    impl Mat44<$f> {
+      /// Identity matrix.
+      pub fn identity() -> Self {
+        Mat44([
+          [1., 0., 0., 0.],
+          [0., 1., 0., 0.],
+          [0., 0., 1., 0.],
+          [0., 0., 0., 1.],
+        ])
+      }
+
+      /// Translation matrix.
+      pub fn from_translation(t: Vec3<$f>) -> Self {
+        let mut m = Self::identity();
+        m.0[3] = [t[0], t[1], t[2], 1.];
+        m
+      }
+
+      /// Rotation matrix of `angle` radians around `axis`.
+      ///
+      /// `axis` doesn’t have to be normalized.
+      pub fn from_rotation(axis: Vec3<$f>, angle: $f) -> Self {
+        let [x, y, z] = axis.normalize().0;
+        let (s, c) = angle.sin_cos();
+        let t = 1. - c;
+
+        Mat44([
+          [t * x * x + c, t * x * y + s * z, t * x * z - s * y, 0.],
+          [t * x * y - s * z, t * y * y + c, t * y * z + s * x, 0.],
+          [t * x * z + s * y, t * y * z - s * x, t * z * z + c, 0.],
+          [0., 0., 0., 1.],
+        ])
+      }
+
+      /// Perspective projection matrix.
+      ///
+      /// `fovy` is the vertical field of view, in radians, and `aspect` is the _width / height_ ratio. The resulting
+      /// matrix maps the `[-near; -far]` depth range of a right-handed view space to `[-1; 1]` in normalized device
+      /// coordinates.
+      pub fn from_perspective(fovy: $f, aspect: $f, near: $f, far: $f) -> Self {
+        let f = 1. / (fovy * 0.5).tan();
+        let d = near - far;
+
+        Mat44([
+          [f / aspect, 0., 0., 0.],
+          [0., f, 0., 0.],
+          [0., 0., (far + near) / d, -1.],
+          [0., 0., 2. * far * near / d, 0.],
+        ])
+      }
+    }
+  };
+
+  ($f:ty, $($t:ident),*) => {
+    $(
+      /// Scalar multiplication.
+      impl Mul<$f> for $t<$f> {
+        type Output = Self;
+
+        fn mul(mut self, rhs: $f) -> Self::Output {
+          for a in self.0.iter_mut() {
+            *a *= rhs;
+          }
+
+          self
+        }
+      }
+
+      impl $t<$f> {
+        /// Length (magnitude) of the vector.
+        pub fn length(self) -> $f {
+          self.dot(self).sqrt()
+        }
+
+        /// Normalize the vector so that its length is `1`.
+        pub fn normalize(self) -> Self {
+          self * (1. / self.length())
+        }
+      }
+    )*
+  };
+}
+
+float_arith!(f32);
+float_arith!(f64);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::f32::consts::FRAC_PI_2;
+
+  fn assert_mat44_eq(a: Mat44<f32>, b: Mat44<f32>) {
+    for (col_a, col_b) in a.iter().zip(b.iter()) {
+      for (x, y) in col_a.iter().zip(col_b) {
+        assert!((x - y).abs() < 1e-5, "{:?} != {:?}", a, b);
+      }
+    }
+  }
+
+  #[test]
+  fn cross_product() {
+    let x = Vec3::new(1., 0., 0.);
+    let y = Vec3::new(0., 1., 0.);
+    let z = Vec3::new(0., 0., 1.);
+
+    assert_eq!(x.cross(y), z);
+    assert_eq!(y.cross(x), Vec3::new(0., 0., -1.));
+    assert_eq!(
+      Vec3::new(1, 2, 3).cross(Vec3::new(4, 5, 6)),
+      Vec3::new(-3, 6, -3)
+    );
+  }
+
+  #[test]
+  fn dot_normalize() {
+    assert_eq!(Vec4::new(1, 2, 3, 4).dot(Vec4::new(5, 6, 7, 8)), 70);
+    assert_eq!(Vec2::new(3f32, 4.).length(), 5.);
+    assert_eq!(Vec2::new(3f32, 4.).normalize(), Vec2::new(0.6, 0.8));
+  }
+
+  #[test]
+  fn perspective_view_model() {
+    let proj = Mat44::<f32>::from_perspective(FRAC_PI_2, 2., 1., 10.);
+    let view = Mat44::<f32>::from_translation(Vec3::new(0., 0., -5.));
+    let model = Mat44::<f32>::from_rotation(Vec3::new(0., 0., 2.), FRAC_PI_2);
+    let mvp = proj * view * model;
+
+    assert_mat44_eq(
+      mvp,
+      Mat44([
+        [0., 1., 0., 0.],
+        [-0.5, 0., 0., 0.],
+        [0., 0., -11. / 9., -1.],
+        [0., 0., 35. / 9., 5.],
+      ]),
+    );
+
+    let p = mvp * Vec4::new(1., 0., 0., 1.);
+    let expected = Vec4::new(0., 1., 35. / 9., 5.);
+
+    for (a, b) in p.iter().zip(expected.iter()) {
+      assert!((a - b).abs() < 1e-5, "{:?} != {:?}", p, expected);
+    }
+  }
+}
@@ -22,6 +22,8 @@ use crate::{
 /// [`PipelineGate`]: crate::pipeline::PipelineGate
 pub struct ShadingGate<'a, B> {
   pub(crate) backend: &'a mut B,
+  // framebuffer height to flip scissor regions against, when using a top-left origin
+  pub(crate) flip_height: Option<u32>,
 }
 
 impl<'a, B> ShadingGate<'a, B>
@@ -51,6 +53,7 @@ where
 
     let render_gate = RenderGate {
       backend: self.backend,
+      flip_height: self.flip_height,
     };
     let program_interface = ProgramInterface {
       program: &mut program.repr,
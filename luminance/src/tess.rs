@@ -68,11 +68,13 @@
 
 use crate::{
   backend::tess::{
-    IndexSlice as IndexSliceBackend, InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
+    DynamicTess as DynamicTessBackend, IndexSlice as IndexSliceBackend,
+    InstanceSlice as InstanceSliceBackend, Tess as TessBackend,
+    UpdateVertexAttribute as UpdateVertexAttributeBackend, VertexBytes as VertexBytesBackend,
     VertexSlice as VertexSliceBackend,
   },
   context::GraphicsContext,
-  vertex::{Deinterleave, Vertex, VertexDesc},
+  vertex::{Deinterleave, Vertex, VertexAttribDesc, VertexAttribDim, VertexDesc},
 };
 use std::{
   error, fmt,
@@ -166,6 +168,67 @@ impl fmt::Display for Mode {
   }
 }
 
+impl Mode {
+  /// Number of primitives assembled out of `vert_nb` vertices with this [`Mode`].
+  ///
+  /// Trailing vertices that are not enough to form a complete primitive are ignored. This doesn’t take _primitive
+  /// restart_ into account; see [`Tess::primitive_count`] for that.
+  pub fn primitive_count(self, vert_nb: usize) -> usize {
+    match self {
+      Mode::Point => vert_nb,
+      Mode::Line => vert_nb / 2,
+      Mode::LineStrip => vert_nb.saturating_sub(1),
+      Mode::Triangle => vert_nb / 3,
+      Mode::TriangleFan | Mode::TriangleStrip => vert_nb.saturating_sub(2),
+      Mode::Patch(0) => 0,
+      Mode::Patch(n) => vert_nb / n,
+    }
+  }
+
+  /// Whether this [`Mode`] allows the usage of _primitive restart_.
+  ///
+  /// Only strips and fans can be restarted; other modes already assemble independent primitives.
+  pub fn allows_primitive_restart(self) -> bool {
+    matches!(
+      self,
+      Mode::LineStrip | Mode::TriangleFan | Mode::TriangleStrip
+    )
+  }
+}
+
+/// Expected update frequency of the GPU buffers of a [`Tess`].
+///
+/// This is a hint given to the backend when the buffers are created, so that it can pick the best memory to store
+/// them. It doesn’t prevent from updating a [`Tess`] more often than announced, but doing so might be slower.
+///
+/// The default is [`TessUsage::Stream`], which is what the GPU buffers of a [`Tess`] were always created with before
+/// the hint could be set.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum TessUsage {
+  /// Data is set once and rendered many times.
+  Static,
+  /// Data is updated from time to time and rendered many times.
+  Dynamic,
+  /// Data is updated (almost) every time it’s rendered.
+  Stream,
+}
+
+impl Default for TessUsage {
+  fn default() -> Self {
+    TessUsage::Stream
+  }
+}
+
+impl fmt::Display for TessUsage {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      TessUsage::Static => f.write_str("static"),
+      TessUsage::Dynamic => f.write_str("dynamic"),
+      TessUsage::Stream => f.write_str("stream"),
+    }
+  }
+}
+
 /// Error that can occur while trying to map GPU tessellations to host code.
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
@@ -182,6 +245,13 @@ pub enum TessMapError {
   /// The CPU mapping failed because currently, mapping deinterleaved buffers is not supported via
   /// a single slice.
   ForbiddenDeinterleavedMapping,
+  /// Winding order can only be flipped on indexed tessellations using [`Mode::Triangle`].
+  ForbiddenWindingFlip(Mode),
+  /// The operation requires indices but the tessellation is not indexed.
+  MissingIndices,
+  /// The number of provided attribute values (second field) doesn’t match the number of vertices of the tessellation
+  /// (first field).
+  AttributeLengthMismatch(usize, usize),
 }
 
 impl TessMapError {
@@ -211,6 +281,21 @@ impl TessMapError {
   pub fn forbidden_deinterleaved_mapping() -> Self {
     TessMapError::ForbiddenDeinterleavedMapping
   }
+
+  /// Winding order can only be flipped on indexed tessellations using [`Mode::Triangle`].
+  pub fn forbidden_winding_flip(mode: Mode) -> Self {
+    TessMapError::ForbiddenWindingFlip(mode)
+  }
+
+  /// The operation requires indices but the tessellation is not indexed.
+  pub fn missing_indices() -> Self {
+    TessMapError::MissingIndices
+  }
+
+  /// The number of provided attribute values doesn’t match the number of vertices of the tessellation.
+  pub fn attribute_length_mismatch(expected: usize, provided: usize) -> Self {
+    TessMapError::AttributeLengthMismatch(expected, provided)
+  }
 }
 
 impl fmt::Display for TessMapError {
@@ -237,6 +322,18 @@ impl fmt::Display for TessMapError {
       TessMapError::ForbiddenDeinterleavedMapping => {
         f.write_str("cannot map a deinterleaved buffer as interleaved")
       }
+
+      TessMapError::ForbiddenWindingFlip(ref mode) => {
+        write!(f, "cannot flip winding order of {} primitives", mode)
+      }
+
+      TessMapError::MissingIndices => f.write_str("tessellation has no indices"),
+
+      TessMapError::AttributeLengthMismatch(expected, provided) => write!(
+        f,
+        "cannot update vertex attribute: expected {} values, got {}",
+        expected, provided
+      ),
     }
   }
 }
@@ -257,6 +354,26 @@ pub enum TessError {
   ForbiddenPrimitiveMode(Mode),
   /// No data provided and empty tessellation.
   NoData,
+  /// A raw vertex attribute doesn’t fit in the stride of its vertex buffer.
+  AttributeOutOfStride(usize),
+  /// A deinterleaved attribute stream, identified by its rank, was not provided while other streams were.
+  MissingAttribute(usize),
+  /// Indices of the given type cannot address all of the vertices, which count is given along with the number of
+  /// vertices that can be addressed.
+  ///
+  /// Vertices past the last addressable one could never be rendered. This usually means that indices were silently
+  /// truncated when converted to a too small index type.
+  IndexTypeOverflow(TessIndexType, usize, u64),
+  /// A primitive restart index was set with a [`Mode`] that doesn’t allow _primitive restart_.
+  ///
+  /// See [`Mode::allows_primitive_restart`].
+  PrimitiveRestartUnsupported(Mode),
+  /// Winding order can only be flipped on indexed tessellations using [`Mode::Triangle`].
+  ForbiddenWindingFlip(Mode),
+  /// The operation requires indices but the tessellation is not indexed.
+  MissingIndices,
+  /// Instances were rendered from a non-zero base instance, which is not supported by the backend.
+  UnsupportedBaseInstance(usize),
 }
 
 impl TessError {
@@ -284,6 +401,41 @@ impl TessError {
   pub fn no_data() -> Self {
     TessError::NoData
   }
+
+  /// A raw vertex attribute doesn’t fit in the stride of its vertex buffer.
+  pub fn attribute_out_of_stride(index: usize) -> Self {
+    TessError::AttributeOutOfStride(index)
+  }
+
+  /// A deinterleaved attribute stream, identified by its rank, was not provided while other streams were.
+  pub fn missing_attribute(rank: usize) -> Self {
+    TessError::MissingAttribute(rank)
+  }
+
+  /// Indices of the given type cannot address all of the vertices.
+  pub fn index_type_overflow(index_type: TessIndexType, vert_nb: usize, max_vert_nb: u64) -> Self {
+    TessError::IndexTypeOverflow(index_type, vert_nb, max_vert_nb)
+  }
+
+  /// A primitive restart index was set with a [`Mode`] that doesn’t allow _primitive restart_.
+  pub fn primitive_restart_unsupported(mode: Mode) -> Self {
+    TessError::PrimitiveRestartUnsupported(mode)
+  }
+
+  /// Winding order can only be flipped on indexed tessellations using [`Mode::Triangle`].
+  pub fn forbidden_winding_flip(mode: Mode) -> Self {
+    TessError::ForbiddenWindingFlip(mode)
+  }
+
+  /// The operation requires indices but the tessellation is not indexed.
+  pub fn missing_indices() -> Self {
+    TessError::MissingIndices
+  }
+
+  /// Instances were rendered from a non-zero base instance, which is not supported by the backend.
+  pub fn unsupported_base_instance(base_instance: usize) -> Self {
+    TessError::UnsupportedBaseInstance(base_instance)
+  }
 }
 
 impl fmt::Display for TessError {
@@ -296,6 +448,35 @@ impl fmt::Display for TessError {
       }
       TessError::ForbiddenPrimitiveMode(ref e) => write!(f, "forbidden primitive mode: {}", e),
       TessError::NoData => f.write_str("no data or empty tessellation"),
+      TessError::AttributeOutOfStride(index) => {
+        write!(
+          f,
+          "raw vertex attribute {} doesn’t fit in its stride",
+          index
+        )
+      }
+      TessError::MissingAttribute(rank) => {
+        write!(f, "missing deinterleaved attribute stream of rank {}", rank)
+      }
+      TessError::IndexTypeOverflow(index_type, vert_nb, max_vert_nb) => write!(
+        f,
+        "{:?} indices cannot address {} vertices; at most {} can be addressed",
+        index_type, vert_nb, max_vert_nb
+      ),
+      TessError::PrimitiveRestartUnsupported(mode) => write!(
+        f,
+        "primitive restart index set with {} mode, which cannot be restarted",
+        mode
+      ),
+      TessError::ForbiddenWindingFlip(mode) => {
+        write!(f, "cannot flip winding order of {} primitives", mode)
+      }
+      TessError::MissingIndices => f.write_str("tessellation has no indices"),
+      TessError::UnsupportedBaseInstance(base_instance) => write!(
+        f,
+        "base instance {} is not supported by the backend",
+        base_instance
+      ),
     }
   }
 }
@@ -322,6 +503,17 @@ impl TessIndexType {
       TessIndexType::U32 => 4,
     }
   }
+
+  /// Get the number of vertices indices of this type can address.
+  ///
+  /// With _primitive restart_, `restart_index` doesn’t address a vertex, so only the vertices before it can be
+  /// addressed — e.g. 65535 vertices with [`TessIndexType::U16`] and `u16::MAX` as restart index.
+  pub fn max_vertex_nb(self, restart_index: Option<u32>) -> u64 {
+    let max_vert_nb = 1 << (8 * self.bytes());
+    restart_index.map_or(max_vert_nb, |restart_index| {
+      max_vert_nb.min(restart_index.into())
+    })
+  }
 }
 
 /// Class of tessellation indices.
@@ -389,6 +581,13 @@ pub enum Interleaved {}
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Deinterleaved {}
 
+/// Raw interleaved memory marker.
+///
+/// Vertices are provided as raw bytes, along with [`RawAttribDesc`]s describing where to find each attribute, instead
+/// of being described by a [`Vertex`] type. See [`TessBuilder::set_raw_interleaved`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RawInterleaved {}
+
 /// Vertex input data of a [`TessBuilder`].
 ///
 /// This trait defines the _storage_ of vertices that a [`TessBuilder`] will use to build its internal storage on the
@@ -447,6 +646,13 @@ where
     if data.is_empty() {
       Ok(0)
     } else {
+      // streams that were never set are empty; if others were set, vertices would lack attributes
+      if data.iter().any(|a| a.len != 0) {
+        if let Some(rank) = data.iter().position(|a| a.len == 0) {
+          return Err(TessError::missing_attribute(rank));
+        }
+      }
+
       let len = data[0].len;
 
       if data[1..].iter().any(|a| a.len != len) {
@@ -458,6 +664,69 @@ where
   }
 }
 
+impl<V> TessVertexData<RawInterleaved> for V
+where
+  V: Vertex,
+{
+  type Data = RawVertexData;
+
+  fn coherent_len(data: &Self::Data) -> Result<usize, TessError> {
+    Ok(data.len)
+  }
+}
+
+/// Description of a vertex attribute in a raw vertex buffer.
+///
+/// Contrary to attributes described by a [`Vertex`] type, where strides and offsets are computed from the layout of the
+/// type, raw attributes are located with an explicit `stride` and `offset`. This is typically needed when importing
+/// vertex buffers from external formats (e.g. glTF buffer views).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RawAttribDesc {
+  /// Index of the attribute, which must match the index of the semantics used in vertex shaders.
+  pub index: usize,
+  /// Format of the attribute. The alignment is not used, as the offset is explicit.
+  pub attrib_desc: VertexAttribDesc,
+  /// Number of bytes between two consecutive attributes.
+  pub stride: usize,
+  /// Offset in bytes of the first attribute in the buffer.
+  pub offset: usize,
+}
+
+impl RawAttribDesc {
+  /// Size in bytes of a single attribute.
+  pub fn size(&self) -> usize {
+    attrib_size(&self.attrib_desc)
+  }
+}
+
+/// Raw vertex data.
+///
+/// [`RawVertexData`] represents raw bytes of vertices along with the description of their attributes. End-users
+/// shouldn’t need to know about this type as it’s only used internally.
+#[derive(Debug, Clone)]
+pub struct RawVertexData {
+  raw: Vec<u8>,
+  attributes: Vec<RawAttribDesc>,
+  len: usize,
+}
+
+impl RawVertexData {
+  /// Number of vertices.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether there is no vertex.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Turn the [`RawVertexData`] into its raw representation and the description of its attributes.
+  pub fn into_parts(self) -> (Vec<u8>, Vec<RawAttribDesc>) {
+    (self.raw, self.attributes)
+  }
+}
+
 /// Deinterleaved data.
 ///
 /// [`DeinterleavedData`] represents a collection of one type of attributes of a set of vertices, for each vertex
@@ -556,6 +825,8 @@ where
   render_vert_nb: usize,
   render_inst_nb: usize,
   restart_index: Option<I>,
+  flip_winding: bool,
+  usage: TessUsage,
   _phantom: PhantomData<&'a mut ()>,
 }
 
@@ -596,11 +867,32 @@ where
 
   /// Set the primitive restart index.
   ///
-  /// Calling that function twice replaces the previously set value.
+  /// Calling that function twice replaces the previously set value. Only strip and fan modes can be restarted (see
+  /// [`Mode::allows_primitive_restart`]); [`TessBuilder::build`] fails with other modes.
   pub fn set_primitive_restart_index(mut self, restart_index: I) -> Self {
     self.restart_index = Some(restart_index);
     self
   }
+
+  /// Flip the winding order of the triangles when building the [`Tess`].
+  ///
+  /// This is only valid for indexed tessellations using [`Mode::Triangle`]; [`TessBuilder::build`] fails with
+  /// [`TessError::ForbiddenWindingFlip`] for other modes and [`TessError::MissingIndices`] for non-indexed
+  /// tessellations. See [`Tess::flip_winding`] for further details.
+  ///
+  /// Calling that function twice replaces the previously set value.
+  pub fn flip_winding(mut self, flip: bool) -> Self {
+    self.flip_winding = flip;
+    self
+  }
+
+  /// Set the expected update frequency of the GPU buffers; [`TessUsage::Stream`] by default.
+  ///
+  /// Calling that function twice replaces the previously set value.
+  pub fn set_usage(mut self, usage: TessUsage) -> Self {
+    self.usage = usage;
+    self
+  }
 }
 
 impl<'a, B, V, I, W, S> TessBuilder<'a, B, V, I, W, S>
@@ -631,6 +923,8 @@ where
       render_vert_nb: 0,
       render_inst_nb: 0,
       restart_index: None,
+      flip_winding: false,
+      usage: TessUsage::default(),
       _phantom: PhantomData,
     }
   }
@@ -661,9 +955,76 @@ where
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
       restart_index: None,
+      flip_winding: self.flip_winding,
+      usage: self.usage,
       _phantom: PhantomData,
     }
   }
+
+  /// Generate the indices of a regular grid of `cols` × `rows` vertices.
+  ///
+  /// Vertices are expected in row-major order: the vertex at column `x` and row `y` is the `y * cols + x`-th one.
+  /// Triangles are wound counter-clockwise when columns go along +X and rows along +Y.
+  ///
+  /// If `strip` is `true`, the mode is set to [`Mode::TriangleStrip`] and each band of quads between two rows is a strip,
+  /// strips being separated by a primitive restart index. The restart index is [`u32::MAX`], which is the only one
+  /// supported by some backends (WebGL2), so restarting is used everywhere instead of degenerate triangles. Otherwise,
+  /// the mode is set to [`Mode::Triangle`] and two triangles are generated per quad.
+  ///
+  /// Grids with less than two columns or two rows have no quad and yield no index.
+  pub fn set_grid_indices(
+    self,
+    cols: usize,
+    rows: usize,
+    strip: bool,
+  ) -> TessBuilder<'a, B, V, u32, W, S> {
+    let mode = if strip {
+      Mode::TriangleStrip
+    } else {
+      Mode::Triangle
+    };
+    let builder = self
+      .set_indices(grid_indices(cols, rows, strip))
+      .set_mode(mode);
+
+    if strip {
+      builder.set_primitive_restart_index(u32::MAX)
+    } else {
+      builder
+    }
+  }
+}
+
+// Indices of a grid of cols × rows vertices; see TessBuilder::set_grid_indices.
+fn grid_indices(cols: usize, rows: usize, strip: bool) -> Vec<u32> {
+  if cols < 2 || rows < 2 {
+    return Vec::new();
+  }
+
+  let index = |x: usize, y: usize| (y * cols + x) as u32;
+  let mut indices = Vec::new();
+
+  for y in 0..rows - 1 {
+    if strip {
+      if y > 0 {
+        indices.push(u32::MAX);
+      }
+
+      for x in 0..cols {
+        indices.extend_from_slice(&[index(x, y + 1), index(x, y)]);
+      }
+    } else {
+      for x in 0..cols - 1 {
+        let (top_left, bottom_left) = (index(x, y + 1), index(x, y));
+        let (top_right, bottom_right) = (index(x + 1, y + 1), index(x + 1, y));
+
+        indices.extend_from_slice(&[top_left, bottom_left, top_right]);
+        indices.extend_from_slice(&[top_right, bottom_left, bottom_right]);
+      }
+    }
+  }
+
+  indices
 }
 
 // set_vertices, interleaved version; works only for V = ()
@@ -690,9 +1051,99 @@ where
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
       restart_index: self.restart_index,
+      flip_winding: self.flip_winding,
+      usage: self.usage,
       _phantom: PhantomData,
     }
   }
+
+  /// Add vertices to be bundled in the [`Tess`] by zipping two separate attribute sets.
+  ///
+  /// This is useful when your source data is _deinterleaved_ (e.g. positions and colors live in different arrays)
+  /// but you still want an interleaved [`Tess`]. Each pair of attributes is turned into a vertex with `f`, on the CPU.
+  ///
+  /// Every time you call that function, the set of vertices is replaced by the one you provided.
+  ///
+  /// # Errors
+  ///
+  /// Both attribute sets must have the same length; [`TessError::LengthIncoherency`] is returned otherwise.
+  pub fn set_interleaved_from<V, A, C, F>(
+    self,
+    a: &[A],
+    c: &[C],
+    mut f: F,
+  ) -> Result<TessBuilder<'a, B, V, I, W, Interleaved>, TessError>
+  where
+    V: TessVertexData<Interleaved, Data = Vec<V>>,
+    A: Copy,
+    C: Copy,
+    F: FnMut(A, C) -> V,
+  {
+    if a.len() != c.len() {
+      return Err(TessError::length_incoherency(a.len()));
+    }
+
+    let vertices = a.iter().zip(c).map(|(&a, &c)| f(a, c)).collect::<Vec<_>>();
+
+    Ok(self.set_vertices(vertices))
+  }
+}
+
+// set_raw_interleaved; works only for V = () and W = ()
+impl<'a, B, I> TessBuilder<'a, B, (), I, (), Interleaved>
+where
+  B: ?Sized,
+  I: TessIndex,
+{
+  /// Add raw interleaved vertices to be bundled in the [`Tess`].
+  ///
+  /// `bytes` contains `count` vertices, which attributes are described by `attributes`. Each attribute is located with
+  /// its own stride and offset, so that buffers coming from external formats (e.g. glTF) can be used directly, even if
+  /// their layout doesn’t match any [`Vertex`] type. The resulting [`Tess`] uses the [`RawInterleaved`] storage.
+  ///
+  /// # Errors
+  ///
+  /// - [`TessError::AttributeOutOfStride`] if an attribute doesn’t fit in its stride.
+  /// - [`TessError::LengthIncoherency`] if `bytes` is too short to hold `count` vertices.
+  pub fn set_raw_interleaved(
+    self,
+    bytes: impl Into<Vec<u8>>,
+    attributes: &[RawAttribDesc],
+    count: usize,
+  ) -> Result<TessBuilder<'a, B, (), I, (), RawInterleaved>, TessError> {
+    let raw = bytes.into();
+
+    for attribute in attributes {
+      let end = attribute.offset + attribute.size();
+
+      if end > attribute.stride {
+        return Err(TessError::attribute_out_of_stride(attribute.index));
+      }
+
+      // the last vertex doesn’t need to be padded up to the stride
+      if count > 0 && (count - 1) * attribute.stride + end > raw.len() {
+        return Err(TessError::length_incoherency(count));
+      }
+    }
+
+    Ok(TessBuilder {
+      backend: self.backend,
+      vertex_data: Some(RawVertexData {
+        raw,
+        attributes: attributes.to_vec(),
+        len: count,
+      }),
+      index_data: self.index_data,
+      instance_data: None,
+      mode: self.mode,
+      render_vert_nb: self.render_vert_nb,
+      render_inst_nb: self.render_inst_nb,
+      restart_index: self.restart_index,
+      flip_winding: self.flip_winding,
+      usage: self.usage,
+      _phantom: PhantomData,
+    })
+  }
 }
 
 impl<'a, B, I, V> TessBuilder<'a, B, V, I, (), Interleaved>
@@ -718,6 +1169,8 @@ where
       render_vert_nb: self.render_vert_nb,
       render_inst_nb: self.render_inst_nb,
       restart_index: self.restart_index,
+      flip_winding: self.flip_winding,
+      usage: self.usage,
       _phantom: PhantomData,
     }
   }
@@ -820,11 +1273,47 @@ where
   ///   and/or [`TessBuilder::set_instances`], do not forget that you must submit sets with the
   ///   same size. Otherwise, the GPU will not know what values use for missing attributes in
   ///   vertices.
-  pub fn build(self) -> Result<Tess<B, V, I, W, S>, TessError> {
-    // validate input data before giving it to the backend
+  pub fn build(mut self) -> Result<Tess<B, V, I, W, S>, TessError> {
+    // validate input data before giving it to the backend; vertex data must be coherent even when indices are used
+    // to guess the number of vertices to render
+    if let Some(ref data) = self.vertex_data {
+      V::coherent_len(data)?;
+    }
+
     let render_vert_nb = self.guess_render_vertex_len()?;
     let render_inst_nb = self.guess_render_instance_len()?;
 
+    // vertices that indices cannot address could never be rendered
+    if let (Some(index_type), Some(ref data)) = (I::INDEX_TYPE, &self.vertex_data) {
+      let vert_nb = V::coherent_len(data)?;
+      let max_vert_nb =
+        index_type.max_vertex_nb(self.restart_index.and_then(TessIndex::try_into_u32));
+
+      if !self.index_data.is_empty() && vert_nb as u64 > max_vert_nb {
+        return Err(TessError::index_type_overflow(
+          index_type,
+          vert_nb,
+          max_vert_nb,
+        ));
+      }
+    }
+
+    if self.restart_index.is_some() && !self.mode.allows_primitive_restart() {
+      return Err(TessError::primitive_restart_unsupported(self.mode));
+    }
+
+    if self.flip_winding {
+      if self.mode != Mode::Triangle {
+        return Err(TessError::forbidden_winding_flip(self.mode));
+      }
+
+      if self.index_data.is_empty() {
+        return Err(TessError::missing_indices());
+      }
+
+      flip_triangle_winding(&mut self.index_data);
+    }
+
     unsafe {
       self
         .backend
@@ -834,11 +1323,14 @@ where
           self.instance_data,
           self.mode,
           self.restart_index,
+          self.usage,
         )
         .map(|repr| Tess {
           repr,
+          mode: self.mode,
           render_vert_nb,
           render_inst_nb,
+          restart_index: self.restart_index,
           _phantom: PhantomData,
         })
     }
@@ -934,12 +1426,18 @@ where
   // backend representation of the tessellation
   pub(crate) repr: B::TessRepr,
 
+  // primitive mode the tessellation was built with
+  mode: Mode,
+
   // default number of vertices to render
   render_vert_nb: usize,
 
   // default number of instances to render
   render_inst_nb: usize,
 
+  // primitive restart index the tessellation was built with, if any
+  restart_index: Option<I>,
+
   _phantom: PhantomData<*const S>,
 }
 
@@ -966,6 +1464,26 @@ where
     unsafe { B::tess_instances_nb(&self.repr) }
   }
 
+  /// Primitive [`Mode`] the [`Tess`] was built with.
+  pub fn mode(&self) -> Mode {
+    self.mode
+  }
+
+  /// [`TessUsage`] the GPU buffers of the [`Tess`] were created with.
+  pub fn usage(&self) -> TessUsage {
+    unsafe { B::tess_usage(&self.repr) }
+  }
+
+  /// Number of times the content of the [`Tess`] was updated since its creation.
+  ///
+  /// Every mutable slicing ([`Tess::vertices_mut`], [`Tess::indices_mut`], [`Tess::instances_mut`]) counts as an
+  /// update, whether the data was actually changed or not, as well as every call to [`Tess::update_attribute`] and
+  /// [`DynamicTess::set_data`]. This is mostly useful for diagnostics, such as checking that a [`Tess`] is updated as
+  /// often as its usage announces.
+  pub fn update_count(&self) -> u64 {
+    unsafe { B::tess_update_count(&self.repr) }
+  }
+
   /// Default number of vertices to render.
   ///
   /// This number represents the number of vertices that will be rendered when not explicitly asked to render a given
@@ -982,6 +1500,60 @@ where
     self.render_inst_nb
   }
 
+  /// Stable checksum of the vertex storage.
+  ///
+  /// The vertex buffers are read back and their bytes hashed with 64-bit FNV-1a, which doesn’t depend on the platform
+  /// nor on the version of Rust. Deinterleaved tessellations hash each attribute buffer in rank order. This is mostly
+  /// useful to check that procedurally generated geometry is byte-identical across runs without comparing whole
+  /// buffers.
+  ///
+  /// Attributeless tessellations have no vertex storage; they all share the same checksum.
+  pub fn vertex_checksum(&mut self) -> Result<u64, TessMapError>
+  where
+    B: VertexBytesBackend<V, I, W, S>,
+  {
+    let mut checksum = FNV_OFFSET_BASIS;
+
+    unsafe {
+      B::vertex_bytes(&mut self.repr, &mut |bytes| {
+        // hash the length as well, so that moving bytes from a buffer to the next one changes the checksum
+        checksum = fnv1a(checksum, &(bytes.len() as u64).to_le_bytes());
+        checksum = fnv1a(checksum, bytes);
+      })?;
+    }
+
+    Ok(checksum)
+  }
+
+  /// Number of primitives assembled when rendering the default number of vertices.
+  ///
+  /// For an indexed [`Tess`] built with a _primitive restart index_, the index buffer is mapped and every occurrence of
+  /// the restart index ends the current primitive: each run of indices in between is counted separately with
+  /// [`Mode::primitive_count`]. If the restart index doesn’t appear, or if the index buffer cannot be mapped, this is
+  /// the same as calling [`Mode::primitive_count`] with [`Tess::render_vert_nb`].
+  ///
+  /// This is mostly useful for diagnostics, such as debug overlays.
+  pub fn primitive_count<'a>(&'a mut self) -> usize
+  where
+    B: IndexSliceBackend<'a, V, I, W, S>,
+  {
+    let mode = self.mode;
+    let render_vert_nb = self.render_vert_nb;
+
+    match self.restart_index {
+      Some(restart_index) if I::INDEX_TYPE.is_some() => match self.indices() {
+        Ok(indices) => {
+          let rendered = &indices[..render_vert_nb.min(indices.len())];
+          restart_primitive_count(mode, rendered, restart_index)
+        }
+
+        Err(_) => mode.primitive_count(render_vert_nb),
+      },
+
+      _ => mode.primitive_count(render_vert_nb),
+    }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _index storage_.
@@ -1001,6 +1573,61 @@ where
   {
     unsafe { B::indices_mut(&mut self.repr).map(|repr| IndicesMut { repr }) }
   }
+
+  /// Flip the winding order of every triangle of the [`Tess`].
+  ///
+  /// The index buffer is mapped and the last two indices of each triangle are swapped, turning clockwise triangles
+  /// into counter-clockwise ones and vice versa. Vertices are left untouched.
+  ///
+  /// This is only possible for indexed tessellations using [`Mode::Triangle`]. Any other primitive mode fails with
+  /// [`TessMapError::ForbiddenWindingFlip`] and non-indexed tessellations fail with [`TessMapError::MissingIndices`].
+  pub fn flip_winding<'a>(&'a mut self) -> Result<(), TessMapError>
+  where
+    B: IndexSliceBackend<'a, V, I, W, S>,
+  {
+    if self.mode != Mode::Triangle {
+      return Err(TessMapError::forbidden_winding_flip(self.mode));
+    }
+
+    if I::INDEX_TYPE.is_none() || self.idx_nb() == 0 {
+      return Err(TessMapError::missing_indices());
+    }
+
+    let mut indices = self.indices_mut()?;
+    flip_triangle_winding(&mut indices);
+
+    Ok(())
+  }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// Feed bytes to a 64-bit FNV-1a hash.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+  bytes.iter().fold(hash, |hash, &byte| {
+    (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+  })
+}
+
+// Count the primitives of each run of indices delimited by the restart index.
+fn restart_primitive_count<I>(mode: Mode, indices: &[I], restart_index: I) -> usize
+where
+  I: TessIndex,
+{
+  let restart_index = restart_index.try_into_u32();
+
+  indices
+    .split(|index| index.try_into_u32() == restart_index)
+    .map(|run| mode.primitive_count(run.len()))
+    .sum()
+}
+
+// Swap the last two indices of each triangle; a trailing incomplete triangle is left untouched.
+fn flip_triangle_winding<I>(indices: &mut [I]) {
+  for triangle in indices.chunks_exact_mut(3) {
+    triangle.swap(1, 2);
+  }
 }
 
 impl<B, V, I, W> Tess<B, V, I, W, Interleaved>
@@ -1059,6 +1686,127 @@ where
   }
 }
 
+impl<B, V, I, W> Tess<B, V, I, W, Interleaved>
+where
+  B: ?Sized
+    + TessBackend<V, I, W, Interleaved>
+    + TessBackend<V, I, W, Deinterleaved>
+    + for<'a> VertexSliceBackend<'a, V, I, W, Interleaved, V>
+    + for<'a> IndexSliceBackend<'a, V, I, W, Interleaved>
+    + for<'a> InstanceSliceBackend<'a, V, I, W, Interleaved, W>,
+  V: 'static
+    + TessVertexData<Interleaved, Data = Vec<V>>
+    + TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+  I: 'static + TessIndex,
+  W: 'static
+    + TessVertexData<Interleaved, Data = Vec<W>>
+    + TessVertexData<Deinterleaved, Data = Vec<DeinterleavedData>>,
+{
+  /// Create a deinterleaved copy of the [`Tess`].
+  ///
+  /// The vertices and instances are read back and split into one buffer per attribute, allowing field-wise access with
+  /// [`Tess::vertices`] and [`Tess::instances`] on the deinterleaved [`Tess`]. The indices, the primitive [`Mode`],
+  /// the primitive restart index, the [`TessUsage`] and the default numbers of vertices and instances to render are
+  /// kept, so both tessellations render the same.
+  pub fn to_deinterleaved<C>(
+    &mut self,
+    ctx: &mut C,
+  ) -> Result<Tess<B, V, I, W, Deinterleaved>, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let map_err = |e: TessMapError| TessError::cannot_create(e.to_string());
+
+    // attributeless tessellations have no vertex to read back
+    let vertex_data = if self.vert_nb() == 0 {
+      None
+    } else {
+      Some(deinterleave_raw(&self.vertices().map_err(map_err)?))
+    };
+
+    let index_data = if self.idx_nb() == 0 {
+      Vec::new()
+    } else {
+      self.indices().map_err(map_err)?.to_vec()
+    };
+
+    let instance_data = if self.inst_nb() == 0 {
+      None
+    } else {
+      Some(deinterleave_raw(&self.instances().map_err(map_err)?))
+    };
+
+    let mut builder = TessBuilder::new(ctx);
+    builder.vertex_data = vertex_data;
+    builder.index_data = index_data;
+    builder.instance_data = instance_data;
+    builder.mode = self.mode;
+    builder.render_vert_nb = self.render_vert_nb;
+    builder.render_inst_nb = self.render_inst_nb;
+    builder.restart_index = self.restart_index;
+    builder.usage = self.usage();
+
+    builder.build()
+  }
+}
+
+// Split interleaved vertices into one buffer of raw attributes per attribute of their vertex type, as expected by
+// deinterleaved tessellations.
+fn deinterleave_raw<V>(vertices: &[V]) -> Vec<DeinterleavedData>
+where
+  V: Vertex,
+{
+  let desc = V::vertex_desc();
+  let stride = std::mem::size_of::<V>();
+
+  if stride == 0 {
+    return vec![DeinterleavedData::new(); desc.len()];
+  }
+
+  // the attributes of vertex types are laid out in order, each one aligned on its own alignment
+  let bytes = unsafe {
+    std::slice::from_raw_parts(
+      vertices.as_ptr() as *const u8,
+      std::mem::size_of_val(vertices),
+    )
+  };
+  let mut offset = 0;
+
+  desc
+    .iter()
+    .map(|buffer_desc| {
+      let attrib_desc = &buffer_desc.attrib_desc;
+      let align = attrib_desc.align.max(1);
+      offset = (offset + align - 1) / align * align;
+
+      let size = attrib_size(attrib_desc);
+      let raw = bytes
+        .chunks_exact(stride)
+        .flat_map(|vertex| &vertex[offset..offset + size])
+        .copied()
+        .collect();
+      offset += size;
+
+      DeinterleavedData {
+        raw,
+        len: vertices.len(),
+      }
+    })
+    .collect()
+}
+
+// Size in bytes of a single attribute.
+fn attrib_size(attrib_desc: &VertexAttribDesc) -> usize {
+  let dim = match attrib_desc.dim {
+    VertexAttribDim::Dim1 => 1,
+    VertexAttribDim::Dim2 => 2,
+    VertexAttribDim::Dim3 => 3,
+    VertexAttribDim::Dim4 => 4,
+  };
+
+  dim * attrib_desc.unit_size
+}
+
 impl<B, V, I, W> Tess<B, V, I, W, Deinterleaved>
 where
   B: ?Sized + TessBackend<V, I, W, Deinterleaved>,
@@ -1092,6 +1840,22 @@ where
     unsafe { B::vertices_mut(&mut self.repr).map(|repr| VerticesMut { repr }) }
   }
 
+  /// Overwrite a single vertex attribute, leaving the other ones untouched.
+  ///
+  /// The attribute is selected by its type `T`, like with [`Tess::vertices_mut`], but its storage is replaced directly
+  /// instead of being mapped. This is the preferred way to update an attribute every frame — e.g. the positions of a
+  /// simulated cloth — as the storage of the other attributes is never read nor uploaded again.
+  ///
+  /// `values` must contain exactly one value per vertex, as the storage of the attribute is not resized; otherwise,
+  /// [`TessMapError::AttributeLengthMismatch`] is returned.
+  pub fn update_attribute<T>(&mut self, values: &[T]) -> Result<(), TessMapError>
+  where
+    B: UpdateVertexAttributeBackend<V, I, W, T>,
+    V: Deinterleave<T>,
+  {
+    unsafe { B::update_vertex_attribute(&mut self.repr, values) }
+  }
+
   /// Slice the [`Tess`] in order to read its content via usual slices.
   ///
   /// This method gives access to the underlying _instance storage_.
@@ -1119,6 +1883,121 @@ where
   }
 }
 
+/// A GPU vertex set which data is entirely regenerated every frame.
+///
+/// This is typically useful for immediate-mode renderers (think of UI libraries), which rebuild all of their geometry
+/// each frame. Instead of creating a new [`Tess`] every frame or mapping an existing one (which would synchronize with
+/// the GPU still reading from it), [`DynamicTess::set_data`] _orphans_ the previous storage and uploads the new
+/// vertices and indices, growing the storage capacity when needed.
+///
+/// A [`DynamicTess`] is always interleaved and has no instance data. It’s rendered like a [`Tess`], either directly or
+/// via a [`TessView`].
+pub struct DynamicTess<B, V, I = ()>
+where
+  B: ?Sized + DynamicTessBackend<V, I>,
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+{
+  tess: Tess<B, V, I, (), Interleaved>,
+}
+
+impl<B, V, I> DynamicTess<B, V, I>
+where
+  B: ?Sized + DynamicTessBackend<V, I>,
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+{
+  /// Create a new, empty [`DynamicTess`] that will connect its vertices with the given [`Mode`].
+  ///
+  /// Nothing gets rendered until [`DynamicTess::set_data`] is called.
+  pub fn new<C>(ctx: &mut C, mode: Mode) -> Result<Self, TessError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe { ctx.backend().build_dynamic(mode)? };
+    let tess = Tess {
+      repr,
+      mode,
+      render_vert_nb: 0,
+      render_inst_nb: 0,
+      restart_index: None,
+      _phantom: PhantomData,
+    };
+
+    Ok(DynamicTess { tess })
+  }
+
+  /// Replace the vertices and indices of the [`DynamicTess`].
+  ///
+  /// If `I` is an index type, the number of vertices to render is the number of indices; otherwise, it’s the number of
+  /// vertices.
+  pub fn set_data(&mut self, vertices: &[V], indices: &[I]) -> Result<(), TessError> {
+    if let Some(index_type) = I::INDEX_TYPE {
+      let max_vert_nb =
+        index_type.max_vertex_nb(self.tess.restart_index.and_then(TessIndex::try_into_u32));
+
+      if !indices.is_empty() && vertices.len() as u64 > max_vert_nb {
+        return Err(TessError::index_type_overflow(
+          index_type,
+          vertices.len(),
+          max_vert_nb,
+        ));
+      }
+    }
+
+    unsafe { B::set_dynamic_data(&mut self.tess.repr, vertices, indices)? };
+
+    self.tess.render_vert_nb = if I::INDEX_TYPE.is_some() {
+      indices.len()
+    } else {
+      vertices.len()
+    };
+
+    Ok(())
+  }
+
+  /// Get the number of vertices uploaded by the last call to [`DynamicTess::set_data`].
+  pub fn vert_nb(&self) -> usize {
+    self.tess.vert_nb()
+  }
+
+  /// Get the number of indices uploaded by the last call to [`DynamicTess::set_data`].
+  pub fn idx_nb(&self) -> usize {
+    self.tess.idx_nb()
+  }
+
+  /// Primitive mode the [`DynamicTess`] was created with.
+  pub fn mode(&self) -> Mode {
+    self.tess.mode()
+  }
+
+  /// [`TessUsage`] of the [`DynamicTess`], which is always [`TessUsage::Stream`].
+  pub fn usage(&self) -> TessUsage {
+    self.tess.usage()
+  }
+
+  /// Number of times [`DynamicTess::set_data`] was called.
+  pub fn update_count(&self) -> u64 {
+    self.tess.update_count()
+  }
+
+  /// Borrow the underlying [`Tess`], e.g. to create a [`TessView`] out of it.
+  pub fn as_tess(&self) -> &Tess<B, V, I, (), Interleaved> {
+    &self.tess
+  }
+}
+
+impl<'a, B, V, I> From<&'a DynamicTess<B, V, I>> for TessView<'a, B, V, I, (), Interleaved>
+where
+  B: ?Sized + DynamicTessBackend<V, I>,
+  V: TessVertexData<Interleaved>,
+  I: TessIndex,
+{
+  fn from(tess: &'a DynamicTess<B, V, I>) -> Self {
+    TessView::whole(&tess.tess)
+  }
+}
+
 /// TODO
 #[derive(Debug)]
 pub struct Vertices<'a, B, V, I, W, S, T>
@@ -1341,6 +2220,9 @@ pub enum TessViewError {
     /// Requested number.
     nb: usize,
   },
+
+  /// The view selects a range of indices but the [`Tess`] is not indexed.
+  MissingIndices,
 }
 
 impl fmt::Display for TessViewError {
@@ -1354,6 +2236,10 @@ impl fmt::Display for TessViewError {
         write!(f, "TessView incorrect window error: requested slice size {} starting at {}, but capacity is only {}",
           nb, start, capacity)
       }
+
+      TessViewError::MissingIndices => {
+        f.write_str("TessView index range error: the tessellation is not indexed")
+      }
     }
   }
 }
@@ -1372,12 +2258,16 @@ where
 {
   /// Tessellation to render.
   pub(crate) tess: &'a Tess<B, V, I, W, S>,
-  /// Start index (vertex) in the tessellation.
+  /// Start index (vertex, or index for indexed tessellations) in the tessellation.
   pub(crate) start_index: usize,
   /// Number of vertices to pick from the tessellation.
   pub(crate) vert_nb: usize,
   /// Number of instances to render.
   pub(crate) inst_nb: usize,
+  /// Index of the first instance to render.
+  pub(crate) base_instance: usize,
+  /// Normalization overrides of vertex attributes, as `(location, normalized)` pairs.
+  pub(crate) normalized_attribs: Vec<(usize, bool)>,
 }
 
 impl<'a, B, V, I, W, S> TessView<'a, B, V, I, W, S>
@@ -1395,6 +2285,8 @@ where
       start_index: 0,
       vert_nb: tess.render_vert_nb(),
       inst_nb: tess.render_inst_nb(),
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     }
   }
 
@@ -1405,6 +2297,8 @@ where
       start_index: 0,
       vert_nb: tess.render_vert_nb(),
       inst_nb,
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     }
   }
 
@@ -1426,6 +2320,8 @@ where
       start_index: 0,
       vert_nb,
       inst_nb: tess.render_inst_nb(),
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     })
   }
 
@@ -1451,6 +2347,8 @@ where
       start_index: 0,
       vert_nb,
       inst_nb,
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     })
   }
 
@@ -1476,6 +2374,8 @@ where
       start_index: start,
       vert_nb: nb,
       inst_nb: tess.render_inst_nb(),
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     })
   }
 
@@ -1502,8 +2402,101 @@ where
       start_index: start,
       vert_nb: nb,
       inst_nb,
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
+    })
+  }
+
+  /// Create a view that is using only a contiguous range of the indices of the input [`Tess`], starting at index
+  /// `index_start`, with `index_count` indices.
+  ///
+  /// This is typically useful to render a single _submesh_ out of a [`Tess`] holding several of them. The range is
+  /// checked against the number of indices of the [`Tess`] — not its number of vertices nor its default number of
+  /// vertices to render.
+  ///
+  /// # Errors
+  ///
+  /// [`TessViewError::MissingIndices`] is returned if the [`Tess`] is not indexed.
+  pub fn index_slice(
+    tess: &'a Tess<B, V, I, W, S>,
+    index_start: usize,
+    index_count: usize,
+  ) -> Result<Self, TessViewError> {
+    let capacity = tess.idx_nb();
+
+    if I::INDEX_TYPE.is_none() || capacity == 0 {
+      return Err(TessViewError::MissingIndices);
+    }
+
+    if index_start > capacity || index_count + index_start > capacity {
+      return Err(TessViewError::IncorrectViewWindow {
+        capacity,
+        start: index_start,
+        nb: index_count,
+      });
+    }
+
+    Ok(TessView {
+      tess,
+      start_index: index_start,
+      vert_nb: index_count,
+      inst_nb: tess.render_inst_nb(),
+      base_instance: 0,
+      normalized_attribs: Vec::new(),
     })
   }
+
+  /// Render only the first `vert_nb` vertices (or indices, for indexed tessellations) of this view.
+  ///
+  /// The count is clamped to what the [`Tess`] holds from the start of the view, so that it can grow every frame — e.g.
+  /// to animate a line strip being drawn vertex by vertex — without having to check it. That is the number of built
+  /// vertices or indices, which might be more than [`Tess::render_vert_nb`]; attributeless tessellations hold
+  /// [`Tess::render_vert_nb`] vertices. Contrary to [`TessView::slice`], this never fails.
+  pub fn with_vertex_count(mut self, vert_nb: usize) -> Self {
+    let capacity = if I::INDEX_TYPE.is_some() && self.tess.idx_nb() > 0 {
+      self.tess.idx_nb()
+    } else if self.tess.vert_nb() > 0 {
+      self.tess.vert_nb()
+    } else {
+      self.tess.render_vert_nb()
+    };
+
+    self.vert_nb = vert_nb.min(capacity.saturating_sub(self.start_index));
+    self
+  }
+
+  /// Override whether the integral vertex attribute at `location` is normalized, for the render of this view only.
+  ///
+  /// The normalization of vertex attributes is set when building the [`Tess`] (see the `normalized` argument of the
+  /// `Vertex` derive). This allows to render the same data once normalized — e.g. `[u8; 4]` read as `vec4` in
+  /// `[0; 1]` — and once not normalized — the same `vec4` in `[0; 255]`. Either way, the attribute is read as
+  /// floating-point in shaders. The attribute goes back to its built normalization right after the render.
+  ///
+  /// Overriding the same `location` several times keeps the last override. Locations of disabled attributes are
+  /// ignored, as well as the normalization of floating-point attributes.
+  pub fn with_normalized_attrib(mut self, location: usize, normalized: bool) -> Self {
+    self
+      .normalized_attribs
+      .retain(|&(overridden, _)| overridden != location);
+    self.normalized_attribs.push((location, normalized));
+    self
+  }
+
+  /// Start rendering instances at `base_instance`, for the render of this view only.
+  ///
+  /// The instances `[base_instance, base_instance + inst_nb)` are rendered: instance vertex attributes are read from
+  /// instance `base_instance` onwards. This is typically useful to render a range of a large instance buffer, e.g. the
+  /// visible instances once compacted by culling. The instance index in shaders (e.g. `gl_InstanceID`) still starts at
+  /// `0`.
+  ///
+  /// Backends might not support a non-zero base instance (e.g. OpenGL requires 4.2 or `ARB_base_instance`, and
+  /// WebGL2 doesn’t support it at all); the render is then skipped and an unsupported error is recorded (see the
+  /// [`error_check`](crate::error_check) module). The base instance is ignored by
+  /// [`TessGate::render_feedback`](crate::tess_gate::TessGate::render_feedback).
+  pub fn with_base_instance(mut self, base_instance: usize) -> Self {
+    self.base_instance = base_instance;
+    self
+  }
 }
 
 impl<'a, B, V, I, W, S> From<&'a Tess<B, V, I, W, S>> for TessView<'a, B, V, I, W, S>
@@ -1678,3 +2671,210 @@ where
     TessView::inst_sub(self, to.end + 1, inst_nb)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn mode_primitive_count() {
+    assert_eq!(Mode::Point.primitive_count(5), 5);
+    assert_eq!(Mode::Line.primitive_count(5), 2);
+    assert_eq!(Mode::LineStrip.primitive_count(5), 4);
+    assert_eq!(Mode::LineStrip.primitive_count(0), 0);
+    assert_eq!(Mode::Triangle.primitive_count(7), 2);
+    assert_eq!(Mode::TriangleFan.primitive_count(5), 3);
+    assert_eq!(Mode::TriangleStrip.primitive_count(1), 0);
+    assert_eq!(Mode::Patch(4).primitive_count(9), 2);
+    assert_eq!(Mode::Patch(0).primitive_count(9), 0);
+  }
+
+  #[test]
+  fn index_type_max_vertex_nb() {
+    assert_eq!(TessIndexType::U8.max_vertex_nb(None), 256);
+    assert_eq!(TessIndexType::U16.max_vertex_nb(None), 65536);
+    assert_eq!(TessIndexType::U32.max_vertex_nb(None), 1 << 32);
+
+    // the restart index doesn’t address a vertex
+    assert_eq!(
+      TessIndexType::U16.max_vertex_nb(Some(u16::MAX.into())),
+      65535
+    );
+    assert_eq!(
+      TessIndexType::U32.max_vertex_nb(Some(u32::MAX)),
+      (1 << 32) - 1
+    );
+    assert_eq!(TessIndexType::U16.max_vertex_nb(Some(70000)), 65536);
+  }
+
+  #[test]
+  fn mode_allows_primitive_restart() {
+    assert!(Mode::LineStrip.allows_primitive_restart());
+    assert!(Mode::TriangleFan.allows_primitive_restart());
+    assert!(Mode::TriangleStrip.allows_primitive_restart());
+
+    assert!(!Mode::Point.allows_primitive_restart());
+    assert!(!Mode::Line.allows_primitive_restart());
+    assert!(!Mode::Triangle.allows_primitive_restart());
+    assert!(!Mode::Patch(3).allows_primitive_restart());
+  }
+
+  #[test]
+  fn line_strip_primitive_count_with_restart() {
+    let indices: [u32; 7] = [0, 1, 2, u32::MAX, 3, 4, 5];
+    let two_strips = Mode::LineStrip.primitive_count(3) * 2;
+
+    assert_eq!(
+      restart_primitive_count(Mode::LineStrip, &indices, u32::MAX),
+      two_strips
+    );
+  }
+
+  #[test]
+  fn line_strip_primitive_count_without_restart() {
+    let indices: [u16; 6] = [0, 1, 2, 3, 4, 5];
+
+    assert_eq!(
+      restart_primitive_count(Mode::LineStrip, &indices, u16::MAX),
+      Mode::LineStrip.primitive_count(indices.len())
+    );
+  }
+
+  #[test]
+  fn fnv1a_reference_values() {
+    assert_eq!(fnv1a(FNV_OFFSET_BASIS, b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(fnv1a(FNV_OFFSET_BASIS, b"foobar"), 0x8594_4171_f739_67e8);
+  }
+
+  #[test]
+  fn fnv1a_incremental() {
+    assert_eq!(
+      fnv1a(fnv1a(FNV_OFFSET_BASIS, b"foo"), b"bar"),
+      fnv1a(FNV_OFFSET_BASIS, b"foobar")
+    );
+  }
+
+  #[test]
+  fn grid_indices_strip() {
+    // 6 7 8
+    // 3 4 5
+    // 0 1 2
+    let indices = grid_indices(3, 3, true);
+
+    assert_eq!(indices, [3, 0, 4, 1, 5, 2, u32::MAX, 6, 3, 7, 4, 8, 5]);
+    assert_eq!(
+      restart_primitive_count(Mode::TriangleStrip, &indices, u32::MAX),
+      8
+    );
+  }
+
+  #[test]
+  fn grid_indices_triangles() {
+    let indices = grid_indices(3, 2, false);
+
+    assert_eq!(indices, [3, 0, 4, 4, 0, 1, 4, 1, 5, 5, 1, 2]);
+  }
+
+  #[test]
+  fn deinterleaved_missing_attribute() {
+    let position = DeinterleavedData {
+      raw: vec![0; 24],
+      len: 3,
+    };
+    let data = vec![position, DeinterleavedData::new()];
+
+    assert_eq!(
+      <() as TessVertexData<Deinterleaved>>::coherent_len(&data),
+      Err(TessError::missing_attribute(1))
+    );
+  }
+
+  #[test]
+  fn deinterleaved_no_attribute() {
+    let data = vec![DeinterleavedData::new(), DeinterleavedData::new()];
+
+    assert_eq!(
+      <() as TessVertexData<Deinterleaved>>::coherent_len(&data),
+      Ok(0)
+    );
+  }
+
+  #[test]
+  fn grid_indices_degenerate() {
+    assert!(grid_indices(1, 3, true).is_empty());
+    assert!(grid_indices(3, 1, false).is_empty());
+  }
+
+  #[test]
+  fn deinterleave_raw_vertices() {
+    use crate::vertex::{Normalized, VertexAttribType, VertexBufferDesc, VertexInstancing};
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct PosColor {
+      pos: [f32; 2],
+      color: [u8; 3],
+    }
+
+    unsafe impl Vertex for PosColor {
+      const ATTR_COUNT: usize = 2;
+
+      fn vertex_desc() -> VertexDesc {
+        let attrib = |index, name, ty, dim, unit_size| VertexBufferDesc {
+          index,
+          name,
+          instancing: VertexInstancing::Off,
+          attrib_desc: VertexAttribDesc {
+            ty,
+            dim,
+            unit_size,
+            align: unit_size,
+          },
+        };
+
+        vec![
+          attrib(
+            0,
+            "pos",
+            VertexAttribType::Floating,
+            VertexAttribDim::Dim2,
+            4,
+          ),
+          attrib(
+            1,
+            "color",
+            VertexAttribType::Unsigned(Normalized::Yes),
+            VertexAttribDim::Dim3,
+            1,
+          ),
+        ]
+      }
+    }
+
+    let vertices = [
+      PosColor {
+        pos: [1., 2.],
+        color: [3, 4, 5],
+      },
+      PosColor {
+        pos: [6., 7.],
+        color: [8, 9, 10],
+      },
+    ];
+    let data = deinterleave_raw(&vertices);
+
+    assert_eq!(data.len(), 2);
+    assert_eq!(
+      <PosColor as TessVertexData<Deinterleaved>>::coherent_len(&data),
+      Ok(2)
+    );
+
+    let positions = [1f32, 2., 6., 7.]
+      .iter()
+      .flat_map(|x| x.to_ne_bytes())
+      .collect::<Vec<_>>();
+    assert_eq!(data[0].clone().into_vec(), positions);
+    assert_eq!(data[1].clone().into_vec(), [3, 4, 5, 8, 9, 10]);
+  }
+}
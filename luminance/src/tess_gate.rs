@@ -4,8 +4,81 @@
 //!
 //! [`Tess`]: crate::tess::Tess
 
-use crate::backend::tess_gate::TessGate as TessGateBackend;
+use crate::backend::feedback::TessGateFeedback;
+use crate::backend::tess_gate::{TessGate as TessGateBackend, TessGateDebug};
+use crate::feedback::FeedbackBuffer;
+use crate::pipeline::PipelineError;
 use crate::tess::{TessIndex, TessVertexData, TessView};
+use crate::vertex::Semantics;
+
+/// Debug geometry to render with [`TessGate::render_debug`].
+///
+/// Each mode names the semantics of the vertex attributes it reads from the [`Tess`]. Positions are read as 3D points
+/// (a missing `z` is `0`) and transformed by the matrix passed to [`TessGate::render_debug`].
+///
+/// [`Tess`]: crate::tess::Tess
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DebugMode<S> {
+  /// Render the edges of the primitives.
+  Wireframe {
+    /// Semantics of the vertex positions.
+    position: S,
+  },
+
+  /// Render vertex normals as lines starting at their vertex.
+  Normals {
+    /// Semantics of the vertex positions.
+    position: S,
+    /// Semantics of the vertex normals.
+    normal: S,
+    /// Length of the rendered lines, in the same unit as the positions.
+    length: f32,
+  },
+
+  /// Render vertex tangents as lines starting at their vertex.
+  Tangents {
+    /// Semantics of the vertex positions.
+    position: S,
+    /// Semantics of the vertex tangents.
+    tangent: S,
+    /// Length of the rendered lines, in the same unit as the positions.
+    length: f32,
+  },
+}
+
+impl<S> DebugMode<S> {
+  /// Map the semantics of the mode.
+  pub fn map<F, T>(self, mut f: F) -> DebugMode<T>
+  where
+    F: FnMut(S) -> T,
+  {
+    match self {
+      DebugMode::Wireframe { position } => DebugMode::Wireframe {
+        position: f(position),
+      },
+
+      DebugMode::Normals {
+        position,
+        normal,
+        length,
+      } => DebugMode::Normals {
+        position: f(position),
+        normal: f(normal),
+        length,
+      },
+
+      DebugMode::Tangents {
+        position,
+        tangent,
+        length,
+      } => DebugMode::Tangents {
+        position: f(position),
+        tangent: f(tangent),
+        length,
+      },
+    }
+  }
+}
 
 /// Tessellation gate.
 pub struct TessGate<'a, B>
@@ -28,18 +101,172 @@ where
     I: TessIndex + 'b,
     W: TessVertexData<S> + 'b,
     S: ?Sized + 'b,
+    E: From<PipelineError>,
+  {
+    let tess_view = tess_view.into();
+
+    unsafe {
+      self
+        .backend
+        .render(
+          &tess_view.tess.repr,
+          tess_view.start_index,
+          tess_view.vert_nb,
+          tess_view.inst_nb,
+          tess_view.base_instance,
+          &tess_view.normalized_attribs,
+        )
+        .map_err(PipelineError::tess)?;
+    }
+
+    Ok(())
+  }
+
+  /// Render the same [`TessView`] `times` times.
+  ///
+  /// If the [`Tess`] has no instance data and the view renders a single instance, the repetitions are done with a
+  /// single instanced draw call of `times` instances: repetitions can then be told apart in shaders with the instance
+  /// index (e.g. `gl_InstanceID`). Otherwise, the view is rendered with `times` regular draw calls.
+  ///
+  /// [`Tess`]: crate::tess::Tess
+  pub fn render_repeated<'b, E, T, V, I, W, S>(
+    &'b mut self,
+    tess_view: T,
+    times: usize,
+  ) -> Result<(), E>
+  where
+    B: TessGateBackend<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+    E: From<PipelineError>,
+  {
+    let tess_view = tess_view.into();
+
+    // a zero instance count would be rendered as a single instance by backends
+    if times == 0 {
+      return Ok(());
+    }
+
+    unsafe {
+      let instanced = tess_view.inst_nb <= 1 && B::tess_instances_nb(&tess_view.tess.repr) == 0;
+
+      if instanced {
+        self
+          .backend
+          .render(
+            &tess_view.tess.repr,
+            tess_view.start_index,
+            tess_view.vert_nb,
+            times,
+            tess_view.base_instance,
+            &tess_view.normalized_attribs,
+          )
+          .map_err(PipelineError::tess)?;
+      } else {
+        for _ in 0..times {
+          self
+            .backend
+            .render(
+              &tess_view.tess.repr,
+              tess_view.start_index,
+              tess_view.vert_nb,
+              tess_view.inst_nb,
+              tess_view.base_instance,
+              &tess_view.normalized_attribs,
+            )
+            .map_err(PipelineError::tess)?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Render a [`TessView`] while capturing the feedback varyings of the shader program in use into `buffers`.
+  ///
+  /// The program must have been built with [`ProgramBuilder::with_feedback_varyings`]: a single buffer is needed with
+  /// [`FeedbackMode::Interleaved`], and one buffer per varying with [`FeedbackMode::Separate`]. Primitives are still
+  /// rasterized as usual.
+  ///
+  /// [`ProgramBuilder::with_feedback_varyings`]: crate::shader::ProgramBuilder::with_feedback_varyings
+  /// [`FeedbackMode::Interleaved`]: crate::feedback::FeedbackMode::Interleaved
+  /// [`FeedbackMode::Separate`]: crate::feedback::FeedbackMode::Separate
+  pub fn render_feedback<'b, E, T, V, I, W, S, F>(
+    &'b mut self,
+    tess_view: T,
+    buffers: &mut [&mut FeedbackBuffer<B, F>],
+  ) -> Result<(), E>
+  where
+    B: TessGateFeedback<V, I, W, S, F>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+    F: Copy + Default,
+    E: From<PipelineError>,
+  {
+    let tess_view = tess_view.into();
+    let mut reprs: Vec<_> = buffers.iter_mut().map(|buffer| &mut buffer.repr).collect();
+
+    unsafe {
+      self
+        .backend
+        .render_feedback(
+          &tess_view.tess.repr,
+          tess_view.start_index,
+          tess_view.vert_nb,
+          tess_view.inst_nb,
+          &mut reprs,
+        )
+        .map_err(PipelineError::feedback)?;
+    }
+
+    Ok(())
+  }
+
+  /// Render debug geometry of a [`TessView`] — its wireframe, normals or tangents — with shaders provided by the
+  /// backend.
+  ///
+  /// This doesn’t require any shader on your side: the currently used shader program is left aside for the debug
+  /// render and is used again right after. `transform` is the column-major matrix transforming positions to clip
+  /// space (typically _projection × view × model_). The render state (depth test, blending, etc.) of the enclosing
+  /// [`RenderGate`] applies.
+  ///
+  /// Backends might not be able to honor every mode exactly; see the documentation of their [`TessGateDebug`]
+  /// implementation for their fallbacks.
+  ///
+  /// [`RenderGate`]: crate::render_gate::RenderGate
+  pub fn render_debug<'b, E, T, V, I, W, S, Sem>(
+    &'b mut self,
+    tess_view: T,
+    mode: DebugMode<Sem>,
+    transform: [[f32; 4]; 4],
+  ) -> Result<(), E>
+  where
+    B: TessGateDebug<V, I, W, S>,
+    T: Into<TessView<'b, B, V, I, W, S>>,
+    V: TessVertexData<S> + 'b,
+    I: TessIndex + 'b,
+    W: TessVertexData<S> + 'b,
+    S: ?Sized + 'b,
+    Sem: Semantics,
   {
     let tess_view = tess_view.into();
 
     unsafe {
-      self.backend.render(
+      self.backend.render_debug(
         &tess_view.tess.repr,
         tess_view.start_index,
         tess_view.vert_nb,
-        tess_view.inst_nb,
+        mode.map(|sem| sem.index()),
+        transform,
       );
-
-      Ok(())
     }
+
+    Ok(())
   }
 }
@@ -31,10 +31,14 @@
 //!   feel free to read their documentation.
 
 use crate::{
-  backend::texture::Texture as TextureBackend,
+  backend::texture::{
+    Texture as TextureBackend, TextureBuffer as TextureBufferBackend,
+    TextureSource as TextureSourceBackend,
+  },
   context::GraphicsContext,
   depth_stencil::Comparison,
-  pixel::{Pixel, PixelFormat},
+  pipeline::Origin,
+  pixel::{Format, NormRGBA8UI, Pixel, PixelFormat},
 };
 use std::{error, fmt, marker::PhantomData};
 
@@ -53,6 +57,11 @@ pub enum Wrap {
   Repeat,
   /// Same as `Repeat` but it will alternatively repeat between `[0;1]` and `[1;0]`.
   MirroredRepeat,
+  /// Mirror textures coordinates once around `0` and then clamp them as with `ClampToEdge`, so that `[-1;0]` maps to
+  /// `[1;0]` and anything further away sticks to the edge.
+  ///
+  /// Not every backend supports this mode; the ones that don’t fall back to `MirroredRepeat`.
+  MirrorClampToEdge,
 }
 
 /// Minification filter.
@@ -85,6 +94,25 @@ pub enum MagFilter {
   Linear,
 }
 
+/// Source of a texture channel, used to remap channels when sampling.
+///
+/// See [`Sampler::swizzle`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Swizzle {
+  /// Red channel of the texel.
+  Red,
+  /// Green channel of the texel.
+  Green,
+  /// Blue channel of the texel.
+  Blue,
+  /// Alpha channel of the texel.
+  Alpha,
+  /// Constant `0`.
+  Zero,
+  /// Constant `1`.
+  One,
+}
+
 /// Class of [`Texture`] dimensions.
 ///
 /// This trait provides a simple mapping between the implementor and the [`Dim`] type, which represents a [`Texture`]
@@ -161,6 +189,11 @@ pub enum Dim {
   /// This corresponds to _layered_ 2D textures, i.e. a 2D texture with an extra parameter to tap into the corresponding
   /// layer. Using in a [`Texture`] allows to perform _layered rendering_.
   Dim2Array,
+  /// 2D multisample, with the given number of samples.
+  ///
+  /// Multisample textures cannot be filtered nor uploaded to: they are meant to be rendered into (as framebuffer
+  /// attachments) and then fetched sample by sample in shaders (`sampler2DMS` and `texelFetch`).
+  Dim2Multisample(usize),
 }
 
 impl fmt::Display for Dim {
@@ -172,6 +205,7 @@ impl fmt::Display for Dim {
       Dim::Cubemap => f.write_str("cubemap"),
       Dim::Dim1Array => f.write_str("1D array"),
       Dim::Dim2Array => f.write_str("2D array"),
+      Dim::Dim2Multisample(samples) => write!(f, "2D multisample ({} samples)", samples),
     }
   }
 }
@@ -427,6 +461,43 @@ impl Dimensionable for Dim2Array {
   }
 }
 
+/// 2D multisample dimension.
+///
+/// `SAMPLES` is the number of samples per texel.
+#[derive(Clone, Copy, Debug)]
+pub struct Dim2Multisample<const SAMPLES: usize>;
+
+impl<const SAMPLES: usize> Dimensionable for Dim2Multisample<SAMPLES> {
+  type Offset = [u32; 2];
+  type Size = [u32; 2];
+
+  const ZERO_OFFSET: Self::Offset = [0, 0];
+
+  fn dim() -> Dim {
+    Dim::Dim2Multisample(SAMPLES)
+  }
+
+  fn width(size: Self::Size) -> u32 {
+    size[0]
+  }
+
+  fn height(size: Self::Size) -> u32 {
+    size[1]
+  }
+
+  fn x_offset(off: Self::Offset) -> u32 {
+    off[0]
+  }
+
+  fn y_offset(off: Self::Offset) -> u32 {
+    off[1]
+  }
+
+  fn count([width, height]: Self::Size) -> usize {
+    width as usize * height as usize
+  }
+}
+
 /// A `Sampler` object gives hint on how a `Texture` should be sampled.
 #[derive(Clone, Copy, Debug)]
 pub struct Sampler {
@@ -442,6 +513,11 @@ pub struct Sampler {
   pub mag_filter: MagFilter,
   /// For depth textures, should we perform depth comparison and if so, how?
   pub depth_comparison: Option<Comparison>,
+  /// Source of the red, green, blue and alpha channels seen when sampling.
+  ///
+  /// For instance, `[Swizzle::Red, Swizzle::Red, Swizzle::Red, Swizzle::One]` samples a single-channel texture as an
+  /// opaque grayscale one. Not all backends support remapping channels.
+  pub swizzle: [Swizzle; 4],
 }
 
 /// Default value is as following:
@@ -454,6 +530,42 @@ impl Default for Sampler {
       min_filter: MinFilter::NearestMipmapLinear,
       mag_filter: MagFilter::Linear,
       depth_comparison: None,
+      swizzle: [Swizzle::Red, Swizzle::Green, Swizzle::Blue, Swizzle::Alpha],
+    }
+  }
+}
+
+impl Sampler {
+  /// Sampler for pixel art: texels are never interpolated and coordinates are clamped to the edges.
+  pub fn pixel_art() -> Self {
+    Sampler {
+      min_filter: MinFilter::Nearest,
+      mag_filter: MagFilter::Nearest,
+      ..Sampler::default()
+    }
+  }
+
+  /// Sampler for user interfaces: texels are linearly interpolated, without mipmaps, and coordinates are clamped to the
+  /// edges.
+  pub fn ui() -> Self {
+    Sampler {
+      min_filter: MinFilter::Linear,
+      mag_filter: MagFilter::Linear,
+      ..Sampler::default()
+    }
+  }
+
+  /// Sampler for tiling textures: texels are linearly interpolated between mipmaps and coordinates repeat.
+  ///
+  /// The texture must have mipmaps to be sampled correctly.
+  pub fn tiling() -> Self {
+    Sampler {
+      wrap_r: Wrap::Repeat,
+      wrap_s: Wrap::Repeat,
+      wrap_t: Wrap::Repeat,
+      min_filter: MinFilter::LinearMipmapLinear,
+      mag_filter: MagFilter::Linear,
+      ..Sampler::default()
     }
   }
 }
@@ -562,6 +674,45 @@ pub enum TextureError {
 
   /// Failed to upload texels.
   CannotUploadTexels(String),
+
+  /// The size of compressed texels doesn’t match the size of the texture level they’re uploaded to.
+  ///
+  /// Compressed texels must be provided for whole blocks, so the size must match exactly.
+  CompressedSizeMismatch {
+    /// Expected size in bytes.
+    expected_bytes: usize,
+    /// Provided size in bytes.
+    provided_bytes: usize,
+  },
+
+  /// A texture was accessed with a pixel format different from the one it was created with.
+  ///
+  /// This can only happen with textures which pixel format is picked at runtime, such as the slots of a
+  /// [`DynamicFramebuffer`].
+  ///
+  /// [`DynamicFramebuffer`]: crate::framebuffer::DynamicFramebuffer
+  PixelFormatMismatch {
+    /// Pixel format of the texture.
+    texture: PixelFormat,
+    /// Pixel format the texture was accessed with.
+    requested: PixelFormat,
+  },
+
+  /// A mipmap level that the texture doesn’t have was asked.
+  InvalidMipLevel {
+    /// Asked level.
+    level: usize,
+    /// Number of mipmaps of the texture; valid levels go from `0` to that number, included.
+    mipmaps: usize,
+  },
+
+  /// An invalid pixel row alignment was asked.
+  ///
+  /// Valid alignments are `1`, `2`, `4` and `8`.
+  InvalidPixelAlignment(usize),
+
+  /// Texture buffers are not supported by the backend.
+  UnsupportedTextureBuffer,
 }
 
 impl TextureError {
@@ -592,6 +743,34 @@ impl TextureError {
   pub fn cannot_upload_texels(reason: impl Into<String>) -> Self {
     TextureError::CannotUploadTexels(reason.into())
   }
+
+  /// The size of compressed texels doesn’t match the size of the texture level they’re uploaded to.
+  pub fn compressed_size_mismatch(expected_bytes: usize, provided_bytes: usize) -> Self {
+    TextureError::CompressedSizeMismatch {
+      expected_bytes,
+      provided_bytes,
+    }
+  }
+
+  /// A texture was accessed with a pixel format different from the one it was created with.
+  pub fn pixel_format_mismatch(texture: PixelFormat, requested: PixelFormat) -> Self {
+    TextureError::PixelFormatMismatch { texture, requested }
+  }
+
+  /// A mipmap level that the texture doesn’t have was asked.
+  pub fn invalid_mip_level(level: usize, mipmaps: usize) -> Self {
+    TextureError::InvalidMipLevel { level, mipmaps }
+  }
+
+  /// An invalid pixel row alignment was asked.
+  pub fn invalid_pixel_alignment(alignment: usize) -> Self {
+    TextureError::InvalidPixelAlignment(alignment)
+  }
+
+  /// Texture buffers are not supported by the backend.
+  pub fn unsupported_texture_buffer() -> Self {
+    TextureError::UnsupportedTextureBuffer
+  }
 }
 
 impl fmt::Display for TextureError {
@@ -621,6 +800,38 @@ impl fmt::Display for TextureError {
       TextureError::CannotUploadTexels(ref e) => {
         write!(f, "cannot upload texels to texture: {}", e)
       }
+
+      TextureError::CompressedSizeMismatch {
+        ref expected_bytes,
+        ref provided_bytes,
+      } => write!(
+        f,
+        "compressed texels size mismatch: expected {} bytes, provided {} bytes",
+        expected_bytes, provided_bytes
+      ),
+
+      TextureError::PixelFormatMismatch {
+        ref texture,
+        ref requested,
+      } => write!(
+        f,
+        "pixel format mismatch: texture is {:?}, accessed as {:?}",
+        texture, requested
+      ),
+
+      TextureError::InvalidMipLevel { level, mipmaps } => write!(
+        f,
+        "invalid mipmap level {}: the texture has {} mipmaps",
+        level, mipmaps
+      ),
+
+      TextureError::InvalidPixelAlignment(alignment) => write!(
+        f,
+        "invalid pixel row alignment {}: must be 1, 2, 4 or 8",
+        alignment
+      ),
+
+      TextureError::UnsupportedTextureBuffer => f.write_str("texture buffers are not supported"),
     }
   }
 }
@@ -684,6 +895,31 @@ where
   _phantom: PhantomData<*const P>,
 }
 
+/// A view on a single mipmap level of a [`Texture`].
+///
+/// Views are obtained with [`Texture::mip_view`].
+pub struct TextureView<'a, B, D, P>
+where
+  B: ?Sized + TextureBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  pub(crate) texture: &'a mut Texture<B, D, P>,
+  level: usize,
+}
+
+impl<'a, B, D, P> TextureView<'a, B, D, P>
+where
+  B: ?Sized + TextureBackend<D, P>,
+  D: Dimensionable,
+  P: Pixel,
+{
+  /// Mipmap level the view gives access to.
+  pub fn level(&self) -> usize {
+    self.level
+  }
+}
+
 impl<B, D, P> Texture<B, D, P>
 where
   B: ?Sized + TextureBackend<D, P>,
@@ -760,6 +996,15 @@ where
     }
   }
 
+  /// Wrap an existing backend texture.
+  pub(crate) fn from_repr(repr: B::TextureRepr, size: D::Size) -> Self {
+    Texture {
+      repr,
+      size,
+      _phantom: PhantomData,
+    }
+  }
+
   /// Return the number of mipmaps.
   pub fn mipmaps(&self) -> usize {
     unsafe { B::mipmaps(&self.repr) }
@@ -770,6 +1015,28 @@ where
     self.size
   }
 
+  /// Get a view on a single mipmap level of the texture.
+  ///
+  /// Once bound with [`Pipeline::bind_texture_view`], the view is sampled as if `level` were the only level of the
+  /// texture, whatever the level of detail computed by the sampler. This is useful for effects using a specific level,
+  /// such as a blur level for depth of field.
+  ///
+  /// `level` must be between `0` (the base level) and [`Texture::mipmaps`], included.
+  ///
+  /// [`Pipeline::bind_texture_view`]: crate::pipeline::Pipeline::bind_texture_view
+  pub fn mip_view(&mut self, level: usize) -> Result<TextureView<'_, B, D, P>, TextureError> {
+    let mipmaps = self.mipmaps();
+
+    if level > mipmaps {
+      return Err(TextureError::invalid_mip_level(level, mipmaps));
+    }
+
+    Ok(TextureView {
+      texture: self,
+      level,
+    })
+  }
+
   /// Resize the texture by providing a new size and texels by reusing its GPU resources.
   ///
   /// This function works similarly to [`Texture::new`] but instead of creating a brand new texture, reuses the texture
@@ -829,6 +1096,11 @@ where
   }
 
   /// Get a copy of all the pixels from the texture.
+  ///
+  /// Rows are tightly packed, unless a pack alignment was forced with [`GraphicsContext::set_pixel_alignment`]: rows
+  /// are then padded to that alignment.
+  ///
+  /// [`GraphicsContext::set_pixel_alignment`]: crate::context::GraphicsContext::set_pixel_alignment
   pub fn get_raw_texels(&self) -> Result<Vec<P::RawEncoding>, TextureError>
   where
     P::RawEncoding: Copy + Default,
@@ -836,3 +1108,225 @@ where
     unsafe { B::get_raw_texels(&self.repr, self.size) }
   }
 }
+
+impl<B, P> Texture<B, Dim2, P>
+where
+  B: ?Sized + TextureBackend<Dim2, P>,
+  P: Pixel,
+{
+  /// Get a copy of all the pixels from the texture, with rows ordered according to the given [`Origin`].
+  ///
+  /// [`Texture::get_raw_texels`] returns the bottom row first, which corresponds to [`Origin::BottomLeft`]. With
+  /// [`Origin::TopLeft`], the top row comes first instead.
+  pub fn get_raw_texels_with_origin(
+    &self,
+    origin: Origin,
+  ) -> Result<Vec<P::RawEncoding>, TextureError>
+  where
+    P::RawEncoding: Copy + Default,
+  {
+    let texels = self.get_raw_texels()?;
+    let height = self.size[1] as usize;
+
+    if origin == Origin::BottomLeft || height == 0 || texels.is_empty() {
+      return Ok(texels);
+    }
+
+    let row_len = texels.len() / height;
+    Ok(texels.chunks(row_len).rev().flatten().copied().collect())
+  }
+
+  /// Upload already compressed texels to a mipmap level of the texture.
+  ///
+  /// `P` must be a compressed pixel format (see [`CompressedFormat`]) and `bytes` must hold exactly as many blocks as
+  /// needed to cover the whole level.
+  ///
+  /// [`CompressedFormat`]: crate::pixel::CompressedFormat
+  pub fn upload_compressed(&mut self, level: usize, bytes: &[u8]) -> Result<(), TextureError> {
+    let pf = P::pixel_format();
+    let cf = match pf.format {
+      Format::Compressed(cf) => cf,
+      _ => {
+        return Err(TextureError::cannot_upload_texels(format!(
+          "{:?} is not a compressed pixel format",
+          pf
+        )))
+      }
+    };
+
+    let mipmaps = self.mipmaps();
+    if level > mipmaps {
+      return Err(TextureError::cannot_upload_texels(format!(
+        "mipmap level {} out of range; the texture has {} mipmaps",
+        level, mipmaps
+      )));
+    }
+
+    let size = [
+      (self.size[0] >> level).max(1),
+      (self.size[1] >> level).max(1),
+    ];
+    let expected_bytes = cf.bytes_len(size[0], size[1]);
+    if bytes.len() != expected_bytes {
+      return Err(TextureError::compressed_size_mismatch(
+        expected_bytes,
+        bytes.len(),
+      ));
+    }
+
+    unsafe { B::upload_compressed(&mut self.repr, level, size, bytes) }
+  }
+}
+
+impl<B> Texture<B, Dim2, NormRGBA8UI>
+where
+  B: ?Sized + TextureBackend<Dim2, NormRGBA8UI>,
+{
+  /// Create a new [`Texture`] filled with the current content of an external source, such as an image or a video
+  /// owned by the platform.
+  ///
+  /// If the source has nothing to provide yet, the texture is created empty, with a size of `[0, 0]`, and is filled
+  /// by the first call to [`Texture::update_from_source`] once the source is ready.
+  pub fn from_source<C, S>(ctx: &mut C, source: &S, sampler: Sampler) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+    B: TextureSourceBackend<S>,
+    S: ?Sized,
+  {
+    let repr = unsafe { ctx.backend().new_texture_for_source(sampler)? };
+    let mut texture = Texture::from_repr(repr, [0, 0]);
+    texture.update_from_source(source)?;
+
+    Ok(texture)
+  }
+
+  /// Replace the texels of the texture with the current content of an external source.
+  ///
+  /// This is typically called every frame with a video. The texture is resized if the size of the source changed. If
+  /// the source has nothing to provide yet, such as a video without any decoded frame, the update is skipped and the
+  /// texture is left untouched.
+  pub fn update_from_source<S>(&mut self, source: &S) -> Result<(), TextureError>
+  where
+    B: TextureSourceBackend<S>,
+    S: ?Sized,
+  {
+    let size = match unsafe { B::source_size(source) } {
+      Some(size) if size[0] != 0 && size[1] != 0 => size,
+      _ => return Ok(()),
+    };
+
+    unsafe { B::upload_source(&mut self.repr, source)? };
+    self.size = size;
+
+    Ok(())
+  }
+}
+
+/// A texture buffer.
+///
+/// Texture buffers are one-dimensional arrays of texels stored in a GPU buffer. They are typically used to pass large
+/// amounts of data to shaders — bigger than what a [`Texture`] of the same dimension can hold — such as per-instance
+/// transforms or lookup tables. Shaders access the texels by index with a `samplerBuffer` (or its integral variants)
+/// and `texelFetch`; no [`Sampler`] is involved, so there is no filtering nor mipmaps.
+///
+/// Texture buffers are bound to pipelines with [`Pipeline::bind_texture_buffer`].
+///
+/// # Parametricity
+///
+/// - `B`, which is the backend type. It must implement [`TextureBufferBackend`].
+/// - `P`, the pixel type. It must implement [`Pixel`].
+///
+/// [`Pipeline::bind_texture_buffer`]: crate::pipeline::Pipeline::bind_texture_buffer
+/// [`TextureBufferBackend`]: crate::backend::texture::TextureBuffer
+pub struct TextureBuffer<B, P>
+where
+  B: ?Sized + TextureBufferBackend<P>,
+  P: Pixel,
+{
+  pub(crate) repr: B::TextureBufferRepr,
+  len: usize,
+  _phantom: PhantomData<*const P>,
+}
+
+impl<B, P> TextureBuffer<B, P>
+where
+  B: ?Sized + TextureBufferBackend<P>,
+  P: Pixel,
+{
+  /// Create a new [`TextureBuffer`] holding `texels`.
+  ///
+  /// [`TextureError::UnsupportedTextureBuffer`] is returned by backends without texture buffers, such as WebGL2.
+  pub fn new<C>(ctx: &mut C, texels: &[P::Encoding]) -> Result<Self, TextureError>
+  where
+    C: GraphicsContext<Backend = B>,
+  {
+    let repr = unsafe { ctx.backend().new_texture_buffer(texels)? };
+
+    Ok(TextureBuffer {
+      repr,
+      len: texels.len(),
+      _phantom: PhantomData,
+    })
+  }
+
+  /// Number of texels in the texture buffer.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Whether the texture buffer has no texel.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Replace the texels of the texture buffer.
+  ///
+  /// The number of texels doesn’t have to match the current one.
+  pub fn set_texels(&mut self, texels: &[P::Encoding]) -> Result<(), TextureError> {
+    unsafe { B::set_texture_buffer_texels(&mut self.repr, texels)? };
+    self.len = texels.len();
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_wrap(sampler: &Sampler, wrap: Wrap) {
+    assert_eq!(sampler.wrap_r, wrap);
+    assert_eq!(sampler.wrap_s, wrap);
+    assert_eq!(sampler.wrap_t, wrap);
+  }
+
+  #[test]
+  fn sampler_pixel_art() {
+    let sampler = Sampler::pixel_art();
+
+    assert_wrap(&sampler, Wrap::ClampToEdge);
+    assert_eq!(sampler.min_filter, MinFilter::Nearest);
+    assert_eq!(sampler.mag_filter, MagFilter::Nearest);
+    assert_eq!(sampler.depth_comparison, None);
+  }
+
+  #[test]
+  fn sampler_ui() {
+    let sampler = Sampler::ui();
+
+    assert_wrap(&sampler, Wrap::ClampToEdge);
+    assert_eq!(sampler.min_filter, MinFilter::Linear);
+    assert_eq!(sampler.mag_filter, MagFilter::Linear);
+    assert_eq!(sampler.depth_comparison, None);
+  }
+
+  #[test]
+  fn sampler_tiling() {
+    let sampler = Sampler::tiling();
+
+    assert_wrap(&sampler, Wrap::Repeat);
+    assert_eq!(sampler.min_filter, MinFilter::LinearMipmapLinear);
+    assert_eq!(sampler.mag_filter, MagFilter::Linear);
+    assert_eq!(sampler.depth_comparison, None);
+  }
+}
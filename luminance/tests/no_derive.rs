@@ -0,0 +1,49 @@
+//! Checks that building luminance with `default-features = false` doesn’t pull the derive
+//! proc-macros in.
+//!
+//! Run with `cargo test -p luminance --no-default-features --tests`.
+#![cfg(not(feature = "derive"))]
+
+use luminance::render_state::RenderState;
+
+// Bang macros named after the derive proc-macros. If luminance re-exported luminance-derive, the
+// glob imports below would collide with these and fail to compile (a derive proc-macro cannot be
+// invoked as a bang macro either).
+mod probe {
+  macro_rules! Semantics {
+    () => {
+      false
+    };
+  }
+
+  macro_rules! UniformInterface {
+    () => {
+      false
+    };
+  }
+
+  macro_rules! Vertex {
+    () => {
+      false
+    };
+  }
+
+  pub(crate) use {Semantics, UniformInterface, Vertex};
+}
+
+#[allow(unused_imports)]
+use luminance::*;
+use probe::*;
+
+#[test]
+fn derive_macros_absent() {
+  assert!(!Semantics!());
+  assert!(!UniformInterface!());
+  assert!(!Vertex!());
+}
+
+#[test]
+fn core_without_derive() {
+  let state = RenderState::default();
+  assert!(state.draw_buffers().is_none());
+}